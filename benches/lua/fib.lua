@@ -0,0 +1,11 @@
+-- Recursive calls and integer arithmetic
+local function fib(n)
+    if n < 2 then
+        return n
+    end
+    local a = fib(n - 1)
+    local b = fib(n - 2)
+    return a + b
+end
+
+assert(fib(20) == 6765)
@@ -0,0 +1,12 @@
+-- Nested numeric loops with integer and float arithmetic
+local sum = 0
+local total = 0.0
+for i = 1, 200 do
+    for j = 1, 200 do
+        sum = sum + i * j % 7
+        total = total + j / i
+    end
+end
+
+assert(sum == 103539)
+assert(total > 0)
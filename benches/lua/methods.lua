@@ -0,0 +1,22 @@
+-- Method calls through `SELF`, the methods are fields of the objects
+local function add(self, other)
+    self.x = self.x + other.x
+    self.y = self.y + other.y
+    return self
+end
+
+local function length2(self)
+    return self.x * self.x + self.y * self.y
+end
+
+local function point(x, y)
+    return { x = x, y = y, add = add, length2 = length2 }
+end
+
+local step = point(1, 2)
+local position = point(0, 0)
+for i = 1, 2000 do
+    position:add(step)
+end
+
+assert(position:length2() == 20000000)
@@ -0,0 +1,11 @@
+-- Strings built by concatenation
+local built = 0
+for round = 1, 100 do
+    local s = ""
+    for i = 1, 50 do
+        s = s .. "item" .. tostring(i) .. ","
+    end
+    built = built + #s
+end
+
+assert(built == 34100)
@@ -0,0 +1,17 @@
+-- Tables created, filled, read, and dropped
+local live = 0
+for round = 1, 200 do
+    local t = {}
+    for i = 1, 50 do
+        t[i] = i
+    end
+    local record = { x = round, y = round * 2, name = "point" }
+    t.record = record
+    for i = 1, #t do
+        live = live + t[i]
+    end
+    live = live + t.record.y - t.record.x
+    t = nil
+end
+
+assert(live == 275100)
@@ -0,0 +1,80 @@
+//! Workloads that exercise the parser and the dispatch loop
+//!
+//! ```text
+//! cargo bench --bench workloads
+//! ```
+//!
+//! Each workload of `benches/lua` is parsed and run separately. The
+//! workloads also run on the reference implementation if the `LUA`
+//! environment variable has the path to its interpreter, those timings
+//! include starting the interpreter.
+//!
+//! With the `opcode_counts` feature, the opcodes each workload ran are
+//! printed before the benchmarks.
+use std::{hint::black_box, path::PathBuf, process::Command};
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use no_deps_lua::{Lua, Program};
+
+/// Name and source of each workload
+const WORKLOADS: &[(&str, &str)] = &[
+    ("fib", include_str!("lua/fib.lua")),
+    ("loops", include_str!("lua/loops.lua")),
+    ("tables", include_str!("lua/tables.lua")),
+    ("strings", include_str!("lua/strings.lua")),
+    ("methods", include_str!("lua/methods.lua")),
+];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for (name, source) in WORKLOADS {
+        group.bench_function(*name, |b| {
+            b.iter(|| Program::parse(black_box(source)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn run(c: &mut Criterion) {
+    let mut group = c.benchmark_group("run");
+    for (name, source) in WORKLOADS {
+        let program = Program::parse(source).unwrap();
+        #[cfg(feature = "opcode_counts")]
+        {
+            let mut lua = Lua::default();
+            lua.execute(program.clone()).unwrap();
+            println!("{name}:\n{}\n", lua.opcode_counts());
+        }
+        group.bench_function(*name, |b| {
+            b.iter_batched(
+                || (Lua::default(), program.clone()),
+                |(mut lua, program)| lua.execute(program).unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn reference(c: &mut Criterion) {
+    let Some(lua) = std::env::var_os("LUA") else {
+        return;
+    };
+    let mut group = c.benchmark_group("reference");
+    for (name, _) in WORKLOADS {
+        let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "benches", "lua", name]
+            .iter()
+            .collect::<PathBuf>()
+            .with_extension("lua");
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                let status = Command::new(&lua).arg(&path).status().unwrap();
+                assert!(status.success(), "{name} failed on the reference Lua");
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse, run, reference);
+criterion_main!(benches);
@@ -0,0 +1,370 @@
+//! Generates `src/parser/table.rs`, the action and goto tables of the
+//! parser, from the LR(1) table in `PARSER.md` and the order of the
+//! variants of `TokenType`.
+//!
+//! Rerun it after changing the grammar or `TokenType`, and commit the
+//! table with the change:
+//! ```text
+//! cargo run --example parser_table
+//! ```
+//!
+//! `PARSER.md` keeps the conflicts of the grammar, which are resolved as:
+//! - shift-reduce of `exp :== exp binop exp`, by the precedence of the
+//!   operators while parsing;
+//! - shift-reduce of `exp :== unop exp`, reducing, but on `^`, which binds
+//!   tighter than the unary operators;
+//! - shift-reduce of `exp :== prefixexp` on `(`, shifting, so a call goes on
+//!   across lines;
+//! - reduce-reduce of `stat :== functioncall` and
+//!   `prefixexp :== functioncall` on `(`, reducing the prefix expression, for
+//!   the same reason.
+use std::{fmt::Write, fs};
+
+const PARSER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/PARSER.md");
+const TOKEN: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/parser/token.rs");
+const TABLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/parser/table.rs");
+
+/// Terminals of `TokenType` that are the same terminal as another
+const ALIASES: &[(&str, &str)] = &[("LongString", "String")];
+
+const HEADER: &str = r"//! LR(1) action and goto tables of the Lua grammar
+//!
+//! Generated by the `parser_table` example from the LR(1) table in
+//! `PARSER.md`, rerun it instead of editing the tables by hand:
+//! ```text
+//! cargo run --example parser_table
+//! ```
+//! The shift-reduce conflicts of binary operators are left to be resolved by
+//! precedence while parsing. Rows are indexed by state, columns by the
+//! position of the terminal or non-terminal on [`TokenType`].
+
+use super::TokenType;
+
+/// Number of states of the parser
+pub const STATES: usize = {states};
+/// Number of terminals, the columns of [`ACTION`]
+pub const TERMINALS: usize = {terminals};
+/// Number of non-terminals, the columns of [`GOTO`]
+pub const NON_TERMINALS: usize = {non_terminals};
+
+const KIND_SHIFT: u16 = 13;
+const SHIFT: u16 = 1 << KIND_SHIFT;
+const REDUCE: u16 = 2 << KIND_SHIFT;
+const RESOLVE: u16 = 3 << KIND_SHIFT;
+const ARGUMENT: u16 = SHIFT - 1;
+
+/// Action of the parser
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// Consumes the lookahead and goes to the state
+    Shift(usize),
+    /// Reduces the top of the stack with the production
+    Reduce(usize),
+    /// Shifts to the state, or reduces a binary expression, depending on
+    /// the precedence of the lookahead and of the operator on the stack
+    Resolve(usize),
+}
+
+/// Entry of [`ACTION`], packed in 16 bits, the kind of action on the
+/// highest 3 bits and its argument on the others
+#[derive(Debug, Clone, Copy)]
+pub struct Entry(u16);
+
+impl Entry {
+    fn action(self) -> Option<Action> {
+        let argument = usize::from(self.0 & ARGUMENT);
+        match self.0 & !ARGUMENT {
+            SHIFT => Some(Action::Shift(argument)),
+            REDUCE => Some(Action::Reduce(argument)),
+            RESOLVE => Some(Action::Resolve(argument)),
+            _ => None,
+        }
+    }
+}
+
+/// Action of `state` with `terminal` as lookahead
+pub fn action(state: usize, terminal: usize) -> Option<Action> {
+    ACTION[state][terminal].action()
+}
+
+/// State to go to after reducing `non_terminal` on `state`
+pub fn goto(state: usize, non_terminal: usize) -> Option<usize> {
+    // No production goes back to the initial state
+    match GOTO[state][non_terminal] {
+        0 => None,
+        next => Some(usize::from(next)),
+    }
+}
+
+/// Position of the token's type on the tables, terminals come first,
+/// followed by the non-terminals
+#[allow(clippy::too_many_lines)]
+pub fn symbol(token_type: &TokenType) -> usize {
+    match token_type {
+";
+
+const ENTRIES: &str = r"    }
+}
+
+const __: Entry = Entry(0);
+
+const fn s(state: u16) -> Entry {
+    Entry(SHIFT | state)
+}
+
+const fn r(production: u16) -> Entry {
+    Entry(REDUCE | production)
+}
+
+const fn p(state: u16) -> Entry {
+    Entry(RESOLVE | state)
+}
+
+";
+
+/// Variant of `TokenType`
+struct Variant<'a> {
+    name: &'a str,
+    has_value: bool,
+}
+
+/// Action on a cell of `PARSER.md`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Shift(usize),
+    Reduce(usize),
+    Goto(usize),
+    Accept,
+}
+
+fn main() {
+    let parser = fs::read_to_string(PARSER).unwrap();
+    let token = fs::read_to_string(TOKEN).unwrap();
+
+    let (terminals, non_terminals) = variants(&token);
+    let columns = terminals
+        .iter()
+        .filter(|terminal| !is_alias(terminal.name))
+        .map(|terminal| terminal.name)
+        .collect::<Vec<_>>();
+    let non_terminal_columns = non_terminals
+        .iter()
+        .map(|non_terminal| non_terminal.name)
+        .collect::<Vec<_>>();
+
+    let (header, rows) = lr_table(&parser);
+    let binary = production(&parser, "exp", "[Exp, Binop, Exp]");
+    let unary = production(&parser, "exp", "[Unop, Exp]");
+    let prefix_exp = production(&parser, "exp", "[Prefixexp]");
+    let call_stat = production(&parser, "stat", "[Functioncall]");
+    let call_prefix_exp = production(&parser, "prefixexp", "[Functioncall]");
+
+    let mut actions = Vec::with_capacity(rows.len());
+    let mut gotos = Vec::with_capacity(rows.len());
+    for (state, row) in rows.iter().enumerate() {
+        let cell = |symbol: &str| {
+            let column = header
+                .iter()
+                .position(|name| name == symbol)
+                .unwrap_or_else(|| panic!("`{symbol}` is not on the LR(1) table"));
+            cells(row[column])
+        };
+
+        let action = columns
+            .iter()
+            .map(|&terminal| match cell(terminal).as_slice() {
+                [] => String::from("__"),
+                [Cell::Shift(next)] => format!("s({next})"),
+                [Cell::Reduce(production)] => format!("r({production})"),
+                // The parser reports `Accept` when it reduces the chunk
+                [Cell::Accept] => String::from("r(0)"),
+                [Cell::Shift(next), Cell::Reduce(production)] if *production == binary => {
+                    format!("p({next})")
+                }
+                [Cell::Shift(next), Cell::Reduce(production)]
+                    if *production == unary && terminal == "Pow" =>
+                {
+                    format!("s({next})")
+                }
+                [Cell::Shift(_), Cell::Reduce(production)] if *production == unary => {
+                    format!("r({production})")
+                }
+                [Cell::Shift(next), Cell::Reduce(production)]
+                    if *production == prefix_exp && terminal == "LParen" =>
+                {
+                    format!("s({next})")
+                }
+                [Cell::Reduce(stat), Cell::Reduce(production)]
+                    if *stat == call_stat && *production == call_prefix_exp =>
+                {
+                    format!("r({production})")
+                }
+                cells => panic!("Unresolved conflict on state {state} with {terminal}: {cells:?}"),
+            })
+            .collect::<Vec<_>>();
+        actions.push(action.join(", "));
+
+        let goto = non_terminal_columns
+            .iter()
+            .map(|&non_terminal| match cell(non_terminal).as_slice() {
+                [] => String::from("0"),
+                [Cell::Goto(next)] => next.to_string(),
+                cells => panic!("Invalid goto on state {state} with {non_terminal}: {cells:?}"),
+            })
+            .collect::<Vec<_>>();
+        gotos.push(goto.join(", "));
+    }
+
+    let mut table = HEADER
+        .replace("{states}", &rows.len().to_string())
+        .replace("{terminals}", &columns.len().to_string())
+        .replace("{non_terminals}", &non_terminal_columns.len().to_string());
+    let symbols = terminals
+        .iter()
+        .filter(|terminal| !is_alias(terminal.name))
+        .chain(&non_terminals);
+    for (index, variant) in symbols.enumerate() {
+        let mut patterns = vec![pattern(variant)];
+        patterns.extend(
+            terminals
+                .iter()
+                .filter(|terminal| ALIASES.contains(&(terminal.name, variant.name)))
+                .map(pattern),
+        );
+        writeln!(table, "        {} => {index},", patterns.join(" | ")).unwrap();
+    }
+    table.push_str(ENTRIES);
+
+    writeln!(table, "/// Columns: {}", columns.join(", ")).unwrap();
+    table.push_str("#[rustfmt::skip]\n");
+    table.push_str("static ACTION: [[Entry; TERMINALS]; STATES] = [\n");
+    for (state, action) in actions.iter().enumerate() {
+        writeln!(table, "    /* {state:4} */ [{action}],").unwrap();
+    }
+    table.push_str("];\n\n");
+
+    writeln!(table, "/// Columns: {}", non_terminal_columns.join(", ")).unwrap();
+    table.push_str("#[rustfmt::skip]\n");
+    table.push_str("static GOTO: [[u16; NON_TERMINALS]; STATES] = [\n");
+    for (state, goto) in gotos.iter().enumerate() {
+        writeln!(table, "    /* {state:4} */ [{goto}],").unwrap();
+    }
+    table.push_str("];\n");
+
+    fs::write(TABLE, table).unwrap();
+}
+
+fn is_alias(name: &str) -> bool {
+    ALIASES.iter().any(|(alias, _)| *alias == name)
+}
+
+fn pattern(variant: &Variant) -> String {
+    if variant.has_value {
+        format!("TokenType::{}(_)", variant.name)
+    } else {
+        format!("TokenType::{}", variant.name)
+    }
+}
+
+/// Terminals and non-terminals of `TokenType`, in order
+fn variants(token: &str) -> (Vec<Variant<'_>>, Vec<Variant<'_>>) {
+    let start = token.find("pub enum TokenType").unwrap();
+    let end = start + token[start..].find("\n}\n").unwrap();
+
+    let mut terminals = Vec::new();
+    let mut non_terminals = Vec::new();
+    let mut section = &mut terminals;
+    for line in token[start..end].lines().skip(1).map(str::trim) {
+        if line == "// Non-terminals" {
+            section = &mut non_terminals;
+        }
+        if line.is_empty() || line.starts_with("//") || line.starts_with("#[") {
+            continue;
+        }
+        let name_end = line
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(line.len());
+        section.push(Variant {
+            name: &line[..name_end],
+            has_value: line[name_end..].starts_with('('),
+        });
+    }
+    (terminals, non_terminals)
+}
+
+/// Header, with the names of the variants of `TokenType`, and rows of the
+/// LR(1) table
+fn lr_table(parser: &str) -> (Vec<String>, Vec<Vec<&str>>) {
+    let table = &parser[..parser.find("# States").unwrap()];
+    let mut lines = table.lines().skip(1);
+
+    let header = lines
+        .next()
+        .unwrap()
+        .split('|')
+        .skip(2)
+        .filter(|name| !name.is_empty())
+        .map(|name| match name {
+            "LiteralString" => String::from("String"),
+            name => name.split('_').map(capitalize).collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let rows = lines
+        .filter(|line| line.starts_with('|') && line[1..].starts_with(|c: char| c.is_ascii_digit()))
+        .enumerate()
+        .map(|(state, line)| {
+            let mut cells = line.split('|').skip(1);
+            assert_eq!(cells.next(), Some(state.to_string().as_str()));
+            cells.take(header.len()).collect()
+        })
+        .collect();
+
+    (header, rows)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// Actions of a cell, like `s12`, `r8r69`, or `a`
+fn cells(cell: &str) -> Vec<Cell> {
+    let mut actions = Vec::new();
+    let mut rest = cell;
+    while let Some(kind) = rest.chars().next() {
+        let digits = rest[1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(rest.len(), |end| end + 1);
+        let argument = rest[1..digits].parse();
+        actions.push(match (kind, argument) {
+            ('s', Ok(state)) => Cell::Shift(state),
+            ('r', Ok(production)) => Cell::Reduce(production),
+            ('g', Ok(state)) => Cell::Goto(state),
+            ('a', _) => Cell::Accept,
+            _ => panic!("Invalid cell `{cell}`"),
+        });
+        rest = &rest[digits..];
+    }
+    actions
+}
+
+/// Index of the production on the `Productions` table of `PARSER.md`
+fn production(parser: &str, non_terminal: &str, symbols: &str) -> usize {
+    let productions = &parser[parser.find("# Productions").unwrap()..];
+    productions
+        .lines()
+        .find_map(|line| {
+            let fields = line.split('|').map(str::trim).collect::<Vec<_>>();
+            match fields.as_slice() {
+                ["", index, x, ys, ""] if *x == non_terminal && *ys == symbols => {
+                    index.parse().ok()
+                }
+                _ => None,
+            }
+        })
+        .unwrap_or_else(|| panic!("No production `{non_terminal} :== {symbols}`"))
+}
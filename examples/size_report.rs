@@ -0,0 +1,46 @@
+//! Reports the size of the VM's main types and of the standard library
+//! included in the build.
+//!
+//! Compare the size of the release binary with different features:
+//! ```text
+//! cargo build --release --example size_report
+//! cargo build --release --example size_report --no-default-features --features alloc
+//! ```
+use core::mem::size_of;
+
+use no_deps_lua::{Lua, Program, Table, Value, environment::Environment};
+
+const PROGRAM: &str = r#"
+local a = 1
+local b = a + 2
+"#;
+
+fn main() {
+    println!("Features:");
+    for (feature, enabled) in [
+        ("float", cfg!(feature = "float")),
+        ("io", cfg!(feature = "io")),
+        ("math", cfg!(feature = "math")),
+        ("os", cfg!(feature = "os")),
+        ("package", cfg!(feature = "package")),
+        ("string", cfg!(feature = "string")),
+        ("table", cfg!(feature = "table")),
+    ] {
+        println!("  {feature}: {enabled}");
+    }
+
+    println!("Types:");
+    println!("  Value: {} bytes", size_of::<Value>());
+    println!("  Table: {} bytes", size_of::<Table>());
+    println!("  Program: {} bytes", size_of::<Program>());
+    println!("  Lua: {} bytes", size_of::<Lua>());
+
+    let env = Environment::default();
+    println!("Standard library:");
+    println!("  {} globals", env.borrow().table.len());
+
+    let program = Program::parse(PROGRAM).unwrap();
+    println!("Sample program:");
+    println!("  {} bytes of bytecode", program.to_bytecode().len());
+    Lua::run_program(program).unwrap();
+}
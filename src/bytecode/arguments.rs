@@ -1,5 +1,6 @@
 use core::{fmt::Display, ops::Deref};
 
+#[cfg(feature = "float")]
 use crate::ext::FloatExt;
 
 const A_MASK: u32 = 0xff << A_SHIFT;
@@ -186,6 +187,8 @@ impl From<u8> for B {
     }
 }
 
+/// Signed argument stored with an offset of 127, so it goes from -127
+/// to 128 and `i8::MIN` can't be stored
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Sb(i8);
 
@@ -329,7 +332,10 @@ impl TryFrom<i32> for Sbx {
     type Error = BytecodeArgumentError;
 
     fn try_from(value: i32) -> Result<Self, Self::Error> {
-        if I17_OFFSET.saturating_add_signed(value) < BX_MAX {
+        if I17_OFFSET
+            .checked_add_signed(value)
+            .is_some_and(|bx| bx < BX_MAX)
+        {
             Ok(Self(value))
         } else if value < 0 {
             Err(BytecodeArgumentError::SbxTooSmall(value.into()))
@@ -344,7 +350,10 @@ impl TryFrom<i64> for Sbx {
 
     fn try_from(value: i64) -> Result<Self, Self::Error> {
         if let Ok(value) = i32::try_from(value) {
-            if I17_OFFSET.saturating_add_signed(value) < BX_MAX {
+            if I17_OFFSET
+                .checked_add_signed(value)
+                .is_some_and(|bx| bx < BX_MAX)
+            {
                 Ok(Self(value))
             } else if value < 0 {
                 Err(BytecodeArgumentError::SbxTooSmall(value.into()))
@@ -359,6 +368,7 @@ impl TryFrom<i64> for Sbx {
     }
 }
 
+#[cfg(feature = "float")]
 impl TryFrom<f64> for Sbx {
     type Error = BytecodeArgumentError;
 
@@ -413,6 +423,8 @@ impl TryFrom<usize> for C {
     }
 }
 
+/// Signed argument stored with an offset of 127, so it goes from -127
+/// to 128 and `i8::MIN` can't be stored
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Sc(i8);
 
@@ -533,7 +545,10 @@ impl TryFrom<i32> for Sj {
     type Error = BytecodeArgumentError;
 
     fn try_from(value: i32) -> Result<Self, Self::Error> {
-        if I25_OFFSET.saturating_add_signed(value) < J_MAX {
+        if I25_OFFSET
+            .checked_add_signed(value)
+            .is_some_and(|j| j < J_MAX)
+        {
             Ok(Self(value))
         } else if value < 0 {
             Err(BytecodeArgumentError::SjTooSmall(value))
@@ -0,0 +1,71 @@
+use core::fmt::Debug;
+
+use alloc::{rc::Rc, vec::Vec};
+
+use crate::{Error, Lua};
+
+use super::OpCode;
+
+/// First opcode reserved for instructions implemented by the host
+pub const FIRST_CUSTOM_OPCODE: u8 = OpCode::ExtraArguments as u8 + 1;
+/// Last opcode reserved for instructions implemented by the host,
+/// opcodes are encoded on 7 bits
+pub const LAST_CUSTOM_OPCODE: u8 = 0x7f;
+
+/// Executes instructions that use one of the opcodes reserved for the host,
+/// see [`EnvironmentBuilder::opcode`](crate::environment::EnvironmentBuilder::opcode)
+///
+/// [`Program::parse`](crate::Program::parse) never emits these instructions,
+/// they can only come from binary chunks loaded with
+/// [`Program::from_bytecode`](crate::Program::from_bytecode), which rejects
+/// opcodes outside of the ones implemented by the VM and the reserved range.
+pub trait OpcodeHandler {
+    /// Executes `instruction`, whose lowest 7 bits hold the opcode, the
+    /// layout of the remaining bits is up to the handler
+    fn execute(&self, instruction: u32, vm: &mut Lua) -> Result<(), Error>;
+}
+
+impl<F> OpcodeHandler for F
+where
+    F: Fn(u32, &mut Lua) -> Result<(), Error>,
+{
+    fn execute(&self, instruction: u32, vm: &mut Lua) -> Result<(), Error> {
+        self(instruction, vm)
+    }
+}
+
+/// Handlers registered by the host, sorted by opcode
+#[derive(Clone, Default)]
+pub(crate) struct OpcodeHandlers {
+    handlers: Vec<(u8, Rc<dyn OpcodeHandler>)>,
+}
+
+impl OpcodeHandlers {
+    /// Registers `handler` for `opcode`, replacing the previous one,
+    /// returns `false` if `opcode` is not reserved for the host
+    pub(crate) fn insert(&mut self, opcode: u8, handler: Rc<dyn OpcodeHandler>) -> bool {
+        if !(FIRST_CUSTOM_OPCODE..=LAST_CUSTOM_OPCODE).contains(&opcode) {
+            return false;
+        }
+        match self.handlers.binary_search_by_key(&opcode, |(id, _)| *id) {
+            Ok(index) => self.handlers[index].1 = handler,
+            Err(index) => self.handlers.insert(index, (opcode, handler)),
+        }
+        true
+    }
+
+    pub(crate) fn get(&self, opcode: u8) -> Option<Rc<dyn OpcodeHandler>> {
+        self.handlers
+            .binary_search_by_key(&opcode, |(id, _)| *id)
+            .ok()
+            .map(|index| self.handlers[index].1.clone())
+    }
+}
+
+impl Debug for OpcodeHandlers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set()
+            .entries(self.handlers.iter().map(|(opcode, _)| opcode))
+            .finish()
+    }
+}
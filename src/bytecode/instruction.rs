@@ -0,0 +1,170 @@
+use super::{
+    FIRST_CUSTOM_OPCODE, OpCode,
+    arguments::{A, Ax, B, Bx, BytecodeArgument, C, K, Sb, Sbx, Sc, Sj},
+};
+
+/// Encoded instruction of a program, as read with
+/// [`Program::read_bytecode`](crate::Program::read_bytecode)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction(pub u32);
+
+impl Instruction {
+    /// Splits `instruction` into its opcode and operands, following the
+    /// layout the opcode uses
+    ///
+    /// Every `u32` decodes, opcodes reserved for the host are
+    /// [`DecodedInstruction::Custom`], and their operands are left encoded.
+    pub fn decode(instruction: u32) -> DecodedInstruction {
+        let id = (instruction & 0x7f) as u8;
+        if id >= FIRST_CUSTOM_OPCODE {
+            return DecodedInstruction::Custom {
+                opcode: id,
+                instruction,
+            };
+        }
+
+        let opcode = OpCode::from_id(id);
+        let a = *A::read(instruction);
+        match opcode {
+            OpCode::EqualInteger
+            | OpCode::LessThanInteger
+            | OpCode::LessEqualInteger
+            | OpCode::GreaterThanInteger
+            | OpCode::GreaterEqualInteger
+            | OpCode::MetaMethodInteger => DecodedInstruction::Asbck {
+                opcode,
+                a,
+                sb: *Sb::read(instruction),
+                c: *C::read(instruction),
+                k: *K::read(instruction),
+            },
+            OpCode::AddInteger | OpCode::ShiftRightInteger | OpCode::ShiftLeftInteger => {
+                DecodedInstruction::Absck {
+                    opcode,
+                    a,
+                    b: *B::read(instruction),
+                    sc: *Sc::read(instruction),
+                    k: *K::read(instruction),
+                }
+            }
+            OpCode::LoadConstant
+            | OpCode::LoadConstantExtraArgs
+            | OpCode::ForLoop
+            | OpCode::ForPrepare
+            | OpCode::GenericForPrepare
+            | OpCode::GenericForLoop
+            | OpCode::Closure => DecodedInstruction::Abx {
+                opcode,
+                a,
+                bx: *Bx::read(instruction),
+            },
+            OpCode::LoadInteger | OpCode::LoadFloat => DecodedInstruction::Asbx {
+                opcode,
+                a,
+                sbx: *Sbx::read(instruction),
+            },
+            OpCode::ExtraArguments => DecodedInstruction::Ax {
+                opcode,
+                ax: *Ax::read(instruction),
+            },
+            OpCode::Jump => DecodedInstruction::Sj {
+                opcode,
+                sj: *Sj::read(instruction),
+            },
+            _ => DecodedInstruction::Abck {
+                opcode,
+                a,
+                b: *B::read(instruction),
+                c: *C::read(instruction),
+                k: *K::read(instruction),
+            },
+        }
+    }
+
+    /// Opcode of the instruction, `None` for opcodes reserved for the host
+    pub fn opcode(&self) -> Option<OpCode> {
+        self.decoded().opcode()
+    }
+
+    /// Decodes the instruction, see [`Instruction::decode`]
+    pub fn decoded(&self) -> DecodedInstruction {
+        Self::decode(self.0)
+    }
+}
+
+impl From<u32> for Instruction {
+    fn from(instruction: u32) -> Self {
+        Self(instruction)
+    }
+}
+
+/// Opcode and operands of an instruction, named like on the reference
+/// implementation, `s` operands are signed and `k` is the flag of
+/// instructions that can use a constant or test for `true`
+///
+/// Operands that an opcode doesn't use are still decoded, and are zero on
+/// the instructions emitted by the compiler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodedInstruction {
+    Abck {
+        opcode: OpCode,
+        a: u8,
+        b: u8,
+        c: u8,
+        k: bool,
+    },
+    Asbck {
+        opcode: OpCode,
+        a: u8,
+        sb: i8,
+        c: u8,
+        k: bool,
+    },
+    Absck {
+        opcode: OpCode,
+        a: u8,
+        b: u8,
+        sc: i8,
+        k: bool,
+    },
+    Abx {
+        opcode: OpCode,
+        a: u8,
+        bx: u32,
+    },
+    Asbx {
+        opcode: OpCode,
+        a: u8,
+        sbx: i32,
+    },
+    Ax {
+        opcode: OpCode,
+        ax: u32,
+    },
+    Sj {
+        opcode: OpCode,
+        sj: i32,
+    },
+    /// Opcode reserved for the host, see
+    /// [`OpcodeHandler`](crate::OpcodeHandler)
+    Custom {
+        opcode: u8,
+        instruction: u32,
+    },
+}
+
+impl DecodedInstruction {
+    /// Opcode of the instruction, `None` for opcodes reserved for the host
+    pub fn opcode(&self) -> Option<OpCode> {
+        match self {
+            Self::Abck { opcode, .. }
+            | Self::Asbck { opcode, .. }
+            | Self::Absck { opcode, .. }
+            | Self::Abx { opcode, .. }
+            | Self::Asbx { opcode, .. }
+            | Self::Ax { opcode, .. }
+            | Self::Sj { opcode, .. } => Some(*opcode),
+            Self::Custom { .. } => None,
+        }
+    }
+}
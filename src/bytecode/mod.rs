@@ -1,15 +1,19 @@
+//! Instructions run by the VM, encoded like on the reference implementation
+//!
+//! [`Instruction::decode`] splits an encoded instruction into its opcode and
+//! operands, for tools that inspect programs.
+
 pub mod arguments;
+mod custom;
+mod instruction;
 mod opcode;
 
-use alloc::{
-    rc::Rc,
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::{rc::Rc, vec::Vec};
 use core::{
     cell::RefCell,
     cmp::Ordering,
     fmt::{Debug, Display},
+    mem::size_of,
     ops::Deref,
 };
 
@@ -17,22 +21,57 @@ use crate::{
     Lua,
     closure::{Closure, FunctionType, NativeClosure, Upvalue},
     function::Function,
+    gc::value_size,
     table::Table,
     value::{Value, ValueKey},
 };
 
+#[cfg(feature = "float")]
+use crate::ext::FloatExt;
+
 use super::Error;
 
 use self::arguments::{A, Ax, B, Bx, BytecodeArgument, C, K, Sb, Sbx, Sc, Sj};
-pub use self::opcode::OpCode;
+pub(crate) use self::custom::OpcodeHandlers;
+pub use self::{
+    custom::{FIRST_CUSTOM_OPCODE, LAST_CUSTOM_OPCODE, OpcodeHandler},
+    instruction::{DecodedInstruction, Instruction},
+    opcode::OpCode,
+};
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy)]
 pub struct Bytecode {
     bytecode: u32,
     function: BytecodeFunction,
 }
 
+impl PartialEq for Bytecode {
+    fn eq(&self, other: &Self) -> bool {
+        // The opcode in `bytecode` already determines `function`
+        self.bytecode == other.bytecode
+    }
+}
+
 type BytecodeFunction = fn(bytecode: &Bytecode, vm: &mut Lua) -> Result<(), Error>;
+type BinaryOperation = fn(lhs: &Value, rhs: &Value) -> Result<Value, Error>;
+
+/// Metamethods called by `MMBIN`, `MMBINI` and `MMBINK`, with their id
+/// on the reference implementation and the operation that fails when
+/// the operands have no metamethod
+const METAMETHOD_EVENTS: [(u8, &str, BinaryOperation); 12] = [
+    (6, "__add", Bytecode::add_values),
+    (7, "__sub", Bytecode::sub_values),
+    (8, "__mul", Bytecode::mul_values),
+    (9, "__mod", Bytecode::mod_values),
+    (10, "__pow", Bytecode::pow_values),
+    (11, "__div", Bytecode::div_values),
+    (12, "__idiv", Bytecode::idiv_values),
+    (13, "__band", Bytecode::bit_and_values),
+    (14, "__bor", Bytecode::bit_or_values),
+    (15, "__bxor", Bytecode::bit_xor_values),
+    (16, "__shl", Bytecode::shift_left_values),
+    (17, "__shr", Bytecode::shift_right_values),
+];
 
 impl Bytecode {
     pub fn execute(&self, vm: &mut Lua) -> Result<(), Error> {
@@ -89,6 +128,20 @@ impl Bytecode {
         }
     }
 
+    /// `LOADKX`  
+    /// Loads the value of a constant into the stack, for constants with
+    /// ids too large for `LOADK`
+    ///
+    /// Must be followed by an `EXTRAARG` with the id of the constant.
+    ///
+    /// `dst`: Location on the stack to place constant
+    pub fn load_constant_extra_args(dst: impl Into<A>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abx(OpCode::LoadConstantExtraArgs, dst.into(), Bx::ZERO),
+            function: Self::execute_load_constant_extra_args,
+        }
+    }
+
     /// `LOADFALSE`  
     /// Loads a `false` value into the stack
     ///
@@ -309,6 +362,31 @@ impl Bytecode {
         }
     }
 
+    /// `SETI`  
+    /// Sets a table field to a value using an integer index
+    ///
+    /// `table`: Location of the table on the stack  
+    /// `index`: Index of the item to set  
+    /// `src`: Location of the value  
+    /// `constant`: Whether `src` is a value on `stack` or on `constants`
+    pub fn set_index(
+        table: impl Into<A>,
+        index: impl Into<B>,
+        src: impl Into<C>,
+        constant: impl Into<K>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::SetIndex,
+                table.into(),
+                index.into(),
+                src.into(),
+                constant.into(),
+            ),
+            function: Self::execute_set_index,
+        }
+    }
+
     /// `SETFIELD`  
     /// Sets a table field to a value using a name
     ///
@@ -338,8 +416,8 @@ impl Bytecode {
     /// Creates a new table value
     ///
     /// `dst`: Location on the stack to store the table  
-    /// `array_len`: Amount of items to allocate on the list  
-    /// `table_len`: Amount of items to allocate for the map
+    /// `table_len`: Amount of items to allocate for the map  
+    /// `array_len`: Amount of items to allocate on the list
     pub fn new_table(
         dst: impl Into<A>,
         table_len: impl Into<B>,
@@ -413,6 +491,25 @@ impl Bytecode {
         }
     }
 
+    /// `SUBK`  
+    /// Performs arithmetic subtraction with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn sub_constant(dst: impl Into<A>, lhs: impl Into<B>, constant: impl Into<C>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::SubConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_sub_constant,
+        }
+    }
+
     /// `MULK`  
     /// Performs arithmetic multiplication with a constant.
     ///
@@ -432,6 +529,197 @@ impl Bytecode {
         }
     }
 
+    /// `MODK`  
+    /// Performs arithmetic modulus with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn mod_constant(dst: impl Into<A>, lhs: impl Into<B>, constant: impl Into<C>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::ModConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_mod_constant,
+        }
+    }
+
+    /// `POWK`  
+    /// Performs arithmetic power with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn pow_constant(dst: impl Into<A>, lhs: impl Into<B>, constant: impl Into<C>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::PowConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_pow_constant,
+        }
+    }
+
+    /// `DIVK`  
+    /// Performs arithmetic division with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn div_constant(dst: impl Into<A>, lhs: impl Into<B>, constant: impl Into<C>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::DivConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_div_constant,
+        }
+    }
+
+    /// `IDIVK`  
+    /// Performs arithmetic whole division with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn idiv_constant(dst: impl Into<A>, lhs: impl Into<B>, constant: impl Into<C>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::IDivConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_idiv_constant,
+        }
+    }
+
+    /// `BANDK`  
+    /// Performs bitwise `and` with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn bit_and_constant(
+        dst: impl Into<A>,
+        lhs: impl Into<B>,
+        constant: impl Into<C>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::BitAndConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_bit_and_constant,
+        }
+    }
+
+    /// `BORK`  
+    /// Performs bitwise `or` with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn bit_or_constant(
+        dst: impl Into<A>,
+        lhs: impl Into<B>,
+        constant: impl Into<C>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::BitOrConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_bit_or_constant,
+        }
+    }
+
+    /// `BXORK`  
+    /// Performs bitwise `xor` with a constant.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `constant`: Location on `constant` of right-hand operand
+    pub fn bit_xor_constant(
+        dst: impl Into<A>,
+        lhs: impl Into<B>,
+        constant: impl Into<C>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::BitXorConstant,
+                dst.into(),
+                lhs.into(),
+                constant.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_bit_xor_constant,
+        }
+    }
+
+    /// `SHRI`  
+    /// Performs bitwise shift right by an integer.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `integer`: Integer value to shift by
+    pub fn shift_right_integer(
+        dst: impl Into<A>,
+        lhs: impl Into<B>,
+        integer: impl Into<Sc>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_absck(
+                OpCode::ShiftRightInteger,
+                dst.into(),
+                lhs.into(),
+                integer.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_shift_right_integer,
+        }
+    }
+
+    /// `SHLI`  
+    /// Performs bitwise shift left of an integer.
+    ///
+    /// `dst`: Location on stack to store result of operation  
+    /// `rhs`: Location on stack of right-hand operand  
+    /// `integer`: Integer value to be shifted
+    pub fn shift_left_integer(
+        dst: impl Into<A>,
+        rhs: impl Into<B>,
+        integer: impl Into<Sc>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_absck(
+                OpCode::ShiftLeftInteger,
+                dst.into(),
+                rhs.into(),
+                integer.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_shift_left_integer,
+        }
+    }
+
     /// `ADD`  
     /// Performs arithmetic addition.
     ///
@@ -612,6 +900,78 @@ impl Bytecode {
         }
     }
 
+    /// `MMBIN`  
+    /// Calls the metamethod of the binary bytecode before it, whose
+    /// operands are not numbers.
+    ///
+    /// `lhs`: Location on stack of left-hand operand  
+    /// `rhs`: Location on stack of right-hand operand  
+    /// `event`: Id of the metamethod, numbered like `TMS` on the reference implementation
+    pub fn metamethod(lhs: impl Into<A>, rhs: impl Into<B>, event: impl Into<C>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::MetaMethod,
+                lhs.into(),
+                rhs.into(),
+                event.into(),
+                K::ZERO,
+            ),
+            function: Self::execute_metamethod,
+        }
+    }
+
+    /// `MMBINI`  
+    /// Calls the metamethod of the binary bytecode before it, which had
+    /// an integer operand.
+    ///
+    /// `lhs`: Location on stack of the operand that is not a number  
+    /// `integer`: Integer operand  
+    /// `event`: Id of the metamethod, numbered like `TMS` on the reference implementation  
+    /// `flip`: If the integer was the left-hand operand
+    pub fn metamethod_integer(
+        lhs: impl Into<A>,
+        integer: impl Into<Sb>,
+        event: impl Into<C>,
+        flip: impl Into<K>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_asbck(
+                OpCode::MetaMethodInteger,
+                lhs.into(),
+                integer.into(),
+                event.into(),
+                flip.into(),
+            ),
+            function: Self::execute_metamethod_integer,
+        }
+    }
+
+    /// `MMBINK`  
+    /// Calls the metamethod of the binary bytecode before it, which had
+    /// a constant operand.
+    ///
+    /// `lhs`: Location on stack of the operand that is not a number  
+    /// `constant`: Location on constant list of the other operand  
+    /// `event`: Id of the metamethod, numbered like `TMS` on the reference implementation  
+    /// `flip`: If the constant was the left-hand operand
+    pub fn metamethod_constant(
+        lhs: impl Into<A>,
+        constant: impl Into<B>,
+        event: impl Into<C>,
+        flip: impl Into<K>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::MetaMethodConstant,
+                lhs.into(),
+                constant.into(),
+                event.into(),
+                flip.into(),
+            ),
+            function: Self::execute_metamethod_constant,
+        }
+    }
+
     /// `UNM`  
     /// Performs negation.
     ///
@@ -680,7 +1040,7 @@ impl Bytecode {
     }
 
     /// `CLOSE`  
-    /// Closes an upvalue at the end of a block.
+    /// Closes the upvalues and to-be-closed variables at the end of a block.
     ///
     /// `first`: Location on stack of first register to be closed
     pub fn close(first: impl Into<A>) -> Bytecode {
@@ -690,6 +1050,23 @@ impl Bytecode {
         }
     }
 
+    /// `TBC`  
+    /// Marks a variable to be closed when it goes out of scope.
+    ///
+    /// `register`: Location on stack of the variable
+    pub fn to_be_closed(register: impl Into<A>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::ToBeClosed,
+                register.into(),
+                B::ZERO,
+                C::ZERO,
+                K::ZERO,
+            ),
+            function: Self::execute_to_be_closed,
+        }
+    }
+
     /// `JMP`  
     /// Performs jump.
     ///
@@ -818,6 +1195,29 @@ impl Bytecode {
         }
     }
 
+    /// `LEI`
+    /// Peforms a less or equal (<=) comparison between the register and integer constant.
+    ///
+    /// `register`: Location on stack of left operand  
+    /// `integer`: Integer constant of right operand  
+    /// `test`: If it should test for `true` or `false`
+    pub fn less_equal_integer(
+        lhs: impl Into<A>,
+        rhs: impl Into<Sb>,
+        test: impl Into<K>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_asbck(
+                OpCode::LessEqualInteger,
+                lhs.into(),
+                rhs.into(),
+                C::ZERO,
+                test.into(),
+            ),
+            function: Self::execute_less_equal_integer,
+        }
+    }
+
     /// `GTI`
     /// Peforms a greater than (>) comparison between the register and integer constant.
     ///
@@ -882,6 +1282,25 @@ impl Bytecode {
         }
     }
 
+    /// `TESTSET`  
+    /// Performs test, copying the tested value if the jump after it is taken.
+    ///
+    /// `dst`: Location on stack where the value is copied to  
+    /// `register`: Location on stack of the value that if going to be tested  
+    /// `test`: Test to perform  
+    pub fn test_set(dst: impl Into<A>, register: impl Into<B>, test: impl Into<K>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::TestSet,
+                dst.into(),
+                register.into(),
+                C::ZERO,
+                test.into(),
+            ),
+            function: Self::execute_test_set,
+        }
+    }
+
     /// `CALL`  
     /// Calls a function
     ///
@@ -1042,8 +1461,9 @@ impl Bytecode {
     /// Stores multiple values from the stack into the table
     ///
     /// `table`: Location of the table on the stack  
-    /// `array_len`: Number of items on the stack to store  
-    /// `c`: ?
+    /// `array_len`: Number of items on the stack to store, or 0 to store
+    /// all items up to the top of the stack  
+    /// `c`: Number of items already stored on the table
     pub fn set_list(table: impl Into<A>, array_len: impl Into<B>, c: impl Into<C>) -> Bytecode {
         Bytecode {
             bytecode: Self::encode_abck(
@@ -1057,10 +1477,38 @@ impl Bytecode {
         }
     }
 
-    /// `CLOSURE`
-    /// Puts reference to a local function into the stack
+    /// `SETLIST` with `k`  
+    /// Stores multiple values from the stack into the table, for tables
+    /// with too many items already stored for `c`
     ///
-    /// `dst`: Stack location to store function reference  
+    /// Must be followed by an `EXTRAARG` with the number of items already
+    /// stored divided by 256, `c` has the remainder.
+    ///
+    /// `table`: Location of the table on the stack  
+    /// `array_len`: Number of items on the stack to store, or 0 to store
+    /// all items up to the top of the stack  
+    /// `c`: Remainder of the number of items already stored on the table
+    pub fn set_list_extra_args(
+        table: impl Into<A>,
+        array_len: impl Into<B>,
+        c: impl Into<C>,
+    ) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_abck(
+                OpCode::SetList,
+                table.into(),
+                array_len.into(),
+                c.into(),
+                K::ONE,
+            ),
+            function: Self::execute_set_list,
+        }
+    }
+
+    /// `CLOSURE`
+    /// Puts reference to a local function into the stack
+    ///
+    /// `dst`: Stack location to store function reference  
     /// `func_id`: Id of function
     pub fn closure(dst: impl Into<A>, func_id: impl Into<Bx>) -> Bytecode {
         Bytecode {
@@ -1105,6 +1553,145 @@ impl Bytecode {
         }
     }
 
+    /// `EXTRAARG`  
+    /// Extra operand of the bytecode before it, never runs by itself
+    ///
+    /// `ax`: Value of the operand
+    pub fn extra_arguments(ax: impl Into<Ax>) -> Bytecode {
+        Bytecode {
+            bytecode: Self::encode_ax(OpCode::ExtraArguments, ax.into()),
+            function: Self::execute_extra_arguments,
+        }
+    }
+
+    /// Rebuilds a bytecode from its encoded form
+    ///
+    /// Opcodes reserved for the host are accepted, and run the
+    /// [`OpcodeHandler`] registered for them.
+    pub(crate) fn decode(bytecode: u32) -> Bytecode {
+        let id = (bytecode & 0x7f) as u8;
+        if id >= FIRST_CUSTOM_OPCODE {
+            return Bytecode {
+                bytecode,
+                function: Self::execute_custom,
+            };
+        }
+        let function: BytecodeFunction = match OpCode::from_id(id) {
+            OpCode::Move => Self::execute_move,
+            OpCode::LoadInteger => Self::execute_load_integer,
+            OpCode::LoadFloat => Self::execute_load_float,
+            OpCode::LoadConstant => Self::execute_load_constant,
+            OpCode::LoadFalse => Self::execute_load_false,
+            OpCode::LoadFalseSkip => Self::execute_load_false_skip,
+            OpCode::LoadTrue => Self::execute_load_true,
+            OpCode::LoadNil => Self::execute_load_nil,
+            OpCode::GetUpValue => Self::execute_get_upvalue,
+            OpCode::SetUpValue => Self::execute_set_upvalue,
+            OpCode::GetUpTable => Self::execute_get_uptable,
+            OpCode::GetTable => Self::execute_get_table,
+            OpCode::GetIndex => Self::execute_get_index,
+            OpCode::GetField => Self::execute_get_field,
+            OpCode::SetUpTable => Self::execute_set_uptable,
+            OpCode::SetTable => Self::execute_set_table,
+            OpCode::SetIndex => Self::execute_set_index,
+            OpCode::SetField => Self::execute_set_field,
+            OpCode::NewTable => Self::execute_new_table,
+            OpCode::TableSelf => Self::execute_table_self,
+            OpCode::AddInteger => Self::execute_add_integer,
+            OpCode::AddConstant => Self::execute_add_constant,
+            OpCode::SubConstant => Self::execute_sub_constant,
+            OpCode::MulConstant => Self::execute_mul_constant,
+            OpCode::ModConstant => Self::execute_mod_constant,
+            OpCode::PowConstant => Self::execute_pow_constant,
+            OpCode::DivConstant => Self::execute_div_constant,
+            OpCode::IDivConstant => Self::execute_idiv_constant,
+            OpCode::BitAndConstant => Self::execute_bit_and_constant,
+            OpCode::BitOrConstant => Self::execute_bit_or_constant,
+            OpCode::BitXorConstant => Self::execute_bit_xor_constant,
+            OpCode::ShiftRightInteger => Self::execute_shift_right_integer,
+            OpCode::ShiftLeftInteger => Self::execute_shift_left_integer,
+            OpCode::Add => Self::execute_add,
+            OpCode::Sub => Self::execute_sub,
+            OpCode::Mul => Self::execute_mul,
+            OpCode::Mod => Self::execute_mod,
+            OpCode::Pow => Self::execute_pow,
+            OpCode::Div => Self::execute_div,
+            OpCode::IDiv => Self::execute_idiv,
+            OpCode::BitAnd => Self::execute_bit_and,
+            OpCode::BitOr => Self::execute_bit_or,
+            OpCode::BitXor => Self::execute_bit_xor,
+            OpCode::ShiftLeft => Self::execute_shift_left,
+            OpCode::ShiftRight => Self::execute_shift_right,
+            OpCode::Neg => Self::execute_neg,
+            OpCode::BitNot => Self::execute_bit_not,
+            OpCode::Not => Self::execute_not,
+            OpCode::Len => Self::execute_len,
+            OpCode::Concat => Self::execute_concat,
+            OpCode::Close => Self::execute_close,
+            OpCode::ToBeClosed => Self::execute_to_be_closed,
+            OpCode::Jump => Self::execute_jump,
+            OpCode::Equal => Self::execute_equal,
+            OpCode::LessThan => Self::execute_less_than,
+            OpCode::LessEqual => Self::execute_less_equal,
+            OpCode::EqualConstant => Self::execute_equal_constant,
+            OpCode::EqualInteger => Self::execute_equal_integer,
+            OpCode::LessThanInteger => Self::execute_less_than_integer,
+            OpCode::LessEqualInteger => Self::execute_less_equal_integer,
+            OpCode::GreaterThanInteger => Self::execute_greater_than_integer,
+            OpCode::GreaterEqualInteger => Self::execute_greater_equal_integer,
+            OpCode::Test => Self::execute_test,
+            OpCode::TestSet => Self::execute_test_set,
+            OpCode::Call => Self::execute_call,
+            OpCode::TailCall => Self::execute_tail_call,
+            OpCode::Return => Self::execute_return,
+            OpCode::ZeroReturn => Self::execute_zero_return,
+            OpCode::OneReturn => Self::execute_one_return,
+            OpCode::ForLoop => Self::execute_for_loop,
+            OpCode::ForPrepare => Self::execute_for_prepare,
+            OpCode::GenericForPrepare => Self::execute_generic_for_prepare,
+            OpCode::GenericForCall => Self::execute_generic_for_call,
+            OpCode::GenericForLoop => Self::execute_generic_for_loop,
+            OpCode::SetList => Self::execute_set_list,
+            OpCode::Closure => Self::execute_closure,
+            OpCode::VariadicArguments => Self::execute_variadic_arguments,
+            OpCode::VariadicArgumentsPrepare => Self::execute_variadic_arguments_prepare,
+            OpCode::LoadConstantExtraArgs => Self::execute_load_constant_extra_args,
+            OpCode::ExtraArguments => Self::execute_extra_arguments,
+            OpCode::MetaMethod => Self::execute_metamethod,
+            OpCode::MetaMethodInteger => Self::execute_metamethod_integer,
+            OpCode::MetaMethodConstant => Self::execute_metamethod_constant,
+        };
+        Bytecode { bytecode, function }
+    }
+
+    /// Id of the opcode of the bytecode, including the ones reserved
+    /// for the host
+    pub(crate) fn opcode_id(&self) -> u8 {
+        (self.bytecode & 0x7f) as u8
+    }
+
+    /// Opcode of the bytecode if it is reserved for the host
+    pub(crate) fn custom_opcode(&self) -> Option<u8> {
+        let id = self.opcode_id();
+        (id >= FIRST_CUSTOM_OPCODE).then_some(id)
+    }
+
+    /// Opcode of the bytecode, `None` for the ones reserved for the host
+    pub fn opcode(&self) -> Option<OpCode> {
+        self.custom_opcode()
+            .is_none()
+            .then(|| OpCode::read(self.bytecode))
+    }
+
+    fn execute_custom(&self, vm: &mut Lua) -> Result<(), Error> {
+        let id = self.opcode_id();
+        let handler = vm
+            .opcode_handlers
+            .get(id)
+            .ok_or(Error::MissingOpcodeHandler(id))?;
+        handler.execute(self.bytecode, vm)
+    }
+
     fn execute_move(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, src, _, _) = self.decode_abck();
         let value = vm.get_stack(*src)?.clone();
@@ -1116,16 +1703,32 @@ impl Bytecode {
         vm.set_stack(*dst, Value::Integer(i64::from(*value)))
     }
 
+    #[cfg(feature = "float")]
     fn execute_load_float(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, value) = self.decode_asbx();
         vm.set_stack(*dst, Value::Float(*value as f64))
     }
 
+    /// Builds without floats only find `LOADF` on binary chunks
+    #[cfg(not(feature = "float"))]
+    fn execute_load_float(&self, _vm: &mut Lua) -> Result<(), Error> {
+        Err(Error::FloatsDisabled("LOADF"))
+    }
+
     fn execute_load_constant(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, constant) = self.decode_abx();
 
-        let closure = vm.get_running_closure();
-        let value = closure.constant(usize::try_from(*constant)?)?;
+        let closure = vm.get_running_closure()?;
+        let value = closure.constant(self.convert("constant", *constant)?)?;
+        vm.set_stack(*dst, value)
+    }
+
+    fn execute_load_constant_extra_args(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, _) = self.decode_abx();
+        let constant = vm.extra_argument().ok_or_else(|| self.invalid())?;
+
+        let closure = vm.get_running_closure()?;
+        let value = closure.constant(self.convert("constant", constant)?)?;
         vm.set_stack(*dst, value)
     }
 
@@ -1148,7 +1751,7 @@ impl Bytecode {
     fn execute_load_nil(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, extras, _, _) = self.decode_abck();
         // If `extra` is 0, runs once
-        for dst in *dst..=(*dst + *extras) {
+        for dst in *dst..=self.register(*dst, *extras)? {
             vm.set_stack(dst, Value::Nil)?;
         }
         Ok(())
@@ -1172,11 +1775,12 @@ impl Bytecode {
     fn execute_get_uptable(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, upvalue, key, _) = self.decode_abck();
 
-        let Value::Table(upvalue) = vm.get_upvalue(usize::from(*upvalue))? else {
-            return Err(Error::ExpectedTable);
+        let upvalue = match vm.get_upvalue(usize::from(*upvalue))? {
+            Value::Table(upvalue) => upvalue,
+            other => return Err(Error::ExpectedTable(other.static_type_name())),
         };
 
-        let closure = vm.get_running_closure();
+        let closure = vm.get_running_closure()?;
         let key = closure.constant(usize::from(*key))?;
         let value = upvalue.deref().borrow().get(ValueKey(key.clone())).clone();
 
@@ -1187,30 +1791,14 @@ impl Bytecode {
         let (dst, table, src, _) = self.decode_abck();
 
         if let Value::Table(table) = vm.get_stack(*table)?.clone() {
-            let key = vm.get_stack(*src)?.clone();
-            let value = match key {
-                Value::Integer(index @ 1..) => table
-                    .borrow()
-                    .array
-                    .get(usize::try_from(index - 1)?)
-                    .cloned()
-                    .unwrap_or(Value::Nil),
-                key => {
-                    let key = ValueKey::from(key);
-                    let bin_search = (*table)
-                        .borrow()
-                        .table
-                        .binary_search_by_key(&&key, |a| &a.0);
-
-                    match bin_search {
-                        Ok(i) => (*table).borrow().table[i].1.clone(),
-                        Err(_) => Value::Nil,
-                    }
-                }
-            };
+            let key = ValueKey::from(vm.get_stack(*src)?.clone());
+            let value = table.borrow().get(key).clone();
             vm.set_stack(*dst, value)
         } else {
-            Err(Error::ExpectedTable)
+            let value = vm.get_stack(*table)?.clone();
+            let key = vm.get_stack(*src)?.clone();
+            let value = Self::index_metamethod(vm, value, key)?;
+            vm.set_stack(*dst, value)
         }
     }
 
@@ -1218,26 +1806,15 @@ impl Bytecode {
         let (dst, table, index, _) = self.decode_abck();
 
         if let Value::Table(table) = vm.get_stack(*table)?.clone() {
-            let value = if *index == 0 {
-                let bin_search = (*table)
-                    .borrow()
-                    .table
-                    .binary_search_by_key(&&ValueKey::from(Value::Integer(0)), |a| &a.0);
-                match bin_search {
-                    Ok(i) => (*table).borrow().table[i].1.clone(),
-                    Err(_) => Value::Nil,
-                }
-            } else {
-                (*table)
-                    .borrow()
-                    .array
-                    .get(usize::from(*index) - 1)
-                    .cloned()
-                    .unwrap_or(Value::Nil)
-            };
+            let value = table
+                .borrow()
+                .get(ValueKey(Value::Integer(i64::from(*index))))
+                .clone();
             vm.set_stack(*dst, value)
         } else {
-            Err(Error::ExpectedTable)
+            let value = vm.get_stack(*table)?.clone();
+            let value = Self::index_metamethod(vm, value, Value::Integer(i64::from(*index)))?;
+            vm.set_stack(*dst, value)
         }
     }
 
@@ -1245,7 +1822,7 @@ impl Bytecode {
         let (dst, table, key, _) = self.decode_abck();
 
         if let Value::Table(table) = vm.get_stack(*table)?.clone() {
-            let closure = vm.get_running_closure();
+            let closure = vm.get_running_closure()?;
             let key = ValueKey::from(closure.constant(usize::from(*key))?);
             let bin_search = (*table)
                 .borrow()
@@ -1258,14 +1835,56 @@ impl Bytecode {
             };
             vm.set_stack(*dst, value)
         } else {
-            Err(Error::ExpectedTable)
+            let value = vm.get_stack(*table)?.clone();
+            let key = vm.get_running_closure()?.constant(usize::from(*key))?;
+            let value = Self::index_metamethod(vm, value, key)?;
+            vm.set_stack(*dst, value)
+        }
+    }
+
+    /// Looks `key` up on the `__index` metamethod of a value that is not a
+    /// table, like userdata and strings, which is either a table or a
+    /// function called with the value and the key
+    pub(crate) fn index_metamethod(vm: &mut Lua, value: Value, key: Value) -> Result<Value, Error> {
+        let index = vm
+            .metatable(&value)
+            .map(|metatable| metatable.borrow().get(ValueKey("__index".into())).clone());
+        match index {
+            Some(Value::Table(index)) => Ok(index.borrow().get(ValueKey(key)).clone()),
+            Some(index @ Value::Closure(_)) => Ok(vm
+                .call_value(index, &[value, key])?
+                .into_iter()
+                .next()
+                .unwrap_or(Value::Nil)),
+            _ => Err(Error::ExpectedTable(value.static_type_name())),
+        }
+    }
+
+    /// Scripts can't assign to frozen tables
+    fn check_frozen(table: &RefCell<Table>) -> Result<(), Error> {
+        if table.borrow().is_frozen() {
+            Err(Error::FrozenTable)
+        } else {
+            Ok(())
         }
     }
 
+    /// Prepares the call to the table's observer, which must only be made
+    /// after the assignment, when the table is no longer borrowed
+    fn observe_assignment(
+        table: &RefCell<Table>,
+        key: &Value,
+        value: &Value,
+    ) -> Option<impl FnOnce() + use<>> {
+        let observer = table.borrow().observer()?;
+        let (key, value) = (key.clone(), value.clone());
+        Some(move || observer(&key, &value))
+    }
+
     fn execute_set_uptable(&self, vm: &mut Lua) -> Result<(), Error> {
         let (upvalue, key, src, constant) = self.decode_abck();
 
-        let running_program = vm.get_running_closure();
+        let running_program = vm.get_running_closure()?;
         let key = running_program.constant(usize::from(*key))?;
         let value = if *constant {
             running_program.constant(usize::from(*src))?
@@ -1274,8 +1893,17 @@ impl Bytecode {
         };
 
         match vm.get_upvalue(usize::from(*upvalue))? {
-            Value::Table(upvalue) => upvalue.borrow_mut().set(ValueKey(key), value),
-            _ => Err(Error::ExpectedTable),
+            Value::Table(upvalue) => {
+                Self::check_frozen(&upvalue)?;
+                vm.allocate(Self::entry_size(&value))?;
+                let notification = Self::observe_assignment(&upvalue, &key, &value);
+                upvalue.borrow_mut().set(ValueKey(key), value)?;
+                if let Some(notify) = notification {
+                    notify();
+                }
+                Ok(())
+            }
+            other => Err(Error::ExpectedTable(other.static_type_name())),
         }
     }
 
@@ -1283,62 +1911,88 @@ impl Bytecode {
         let (table, key, src, constant) = self.decode_abck();
 
         if let Value::Table(table) = vm.get_stack(*table)?.clone() {
-            let program = vm.get_running_closure();
+            let program = vm.get_running_closure()?;
             let key = ValueKey::from(vm.get_stack(*key)?.clone());
+            match key.0 {
+                Value::Nil => return Err(Error::InvalidKey("nil")),
+                #[cfg(feature = "float")]
+                Value::Float(float) if float.is_nan() => return Err(Error::InvalidKey("NaN")),
+                _ => (),
+            }
             let value = if *constant {
                 program.constant(usize::from(*src))?
             } else {
                 vm.get_stack(*src)?.clone()
             };
+            Self::check_frozen(&table)?;
+            vm.allocate(Self::entry_size(&value))?;
+            let notification = Self::observe_assignment(&table, &key.0, &value);
 
-            match key {
-                ValueKey(Value::Integer(index)) if index > 0 => {
-                    let array = &mut table.borrow_mut().array;
-                    let index = usize::try_from(index)? - 1;
-                    match index.cmp(&array.len()) {
-                        Ordering::Less => array[index] = value,
-                        Ordering::Equal => array.push(value),
-                        Ordering::Greater => {
-                            array.resize(index, Value::Nil);
-                            array.push(value);
-                        }
-                    }
-                }
-                _ => {
-                    let binary_search = (*table)
-                        .borrow()
-                        .table
-                        .binary_search_by_key(&&key, |a| &a.0);
-                    match binary_search {
-                        Ok(i) => {
-                            let mut table_borrow = table.borrow_mut();
-                            let Some(table_value) = table_borrow.table.get_mut(i) else {
-                                unreachable!("Already tested existence of table value");
-                            };
-                            table_value.1 = value;
-                        }
-                        Err(i) => table.borrow_mut().table.insert(i, (key, value)),
-                    }
-                }
+            self.store(&table, key, value)?;
+
+            if let Some(notify) = notification {
+                notify();
             }
+            Ok(())
+        } else {
+            Err(Error::ExpectedTable(
+                vm.get_stack(*table)?.static_type_name(),
+            ))
+        }
+    }
+
+    fn execute_set_index(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (table, index, src, constant) = self.decode_abck();
+
+        if let Value::Table(table) = vm.get_stack(*table)?.clone() {
+            let key = ValueKey::from(Value::Integer(i64::from(*index)));
+            let value = if *constant {
+                vm.get_running_closure()?.constant(usize::from(*src))?
+            } else {
+                vm.get_stack(*src)?.clone()
+            };
+            Self::check_frozen(&table)?;
+            vm.allocate(Self::entry_size(&value))?;
+            let notification = Self::observe_assignment(&table, &key.0, &value);
+
+            self.store(&table, key, value)?;
 
+            if let Some(notify) = notification {
+                notify();
+            }
             Ok(())
         } else {
-            Err(Error::ExpectedTable)
+            Err(Error::ExpectedTable(
+                vm.get_stack(*table)?.static_type_name(),
+            ))
         }
     }
 
+    /// Estimate of the memory a new entry with `value` takes on a table
+    fn entry_size(value: &Value) -> usize {
+        size_of::<Value>() + value_size(value)
+    }
+
+    /// Stores `value` on `table`, see [`Table::raw_set`]
+    fn store(&self, table: &RefCell<Table>, key: ValueKey, value: Value) -> Result<(), Error> {
+        table.borrow_mut().raw_set(key, value);
+        Ok(())
+    }
+
     fn execute_set_field(&self, vm: &mut Lua) -> Result<(), Error> {
         let (table, key, src, constant) = self.decode_abck();
 
         if let Value::Table(table) = vm.get_stack(*table)?.clone() {
-            let running_program = vm.get_running_closure();
+            let running_program = vm.get_running_closure()?;
             let key = ValueKey::from(running_program.constant(usize::from(*key))?);
             let value = if *constant {
                 running_program.constant(usize::from(*src))?
             } else {
                 vm.get_stack(*src)?.clone()
             };
+            Self::check_frozen(&table)?;
+            vm.allocate(Self::entry_size(&value))?;
+            let notification = Self::observe_assignment(&table, &key.0, &value);
 
             let binary_search = (*table)
                 .borrow()
@@ -1352,33 +2006,50 @@ impl Bytecode {
                     };
                     table_value.1 = value;
                 }
-                Err(i) => table.borrow_mut().table.insert(i, (key, value)),
+                Err(i) => table.borrow_mut().insert_entry(i, key, value),
+            }
+            if let Some(notify) = notification {
+                notify();
             }
             Ok(())
         } else {
-            Err(Error::ExpectedTable)
+            Err(Error::ExpectedTable(
+                vm.get_stack(*table)?.static_type_name(),
+            ))
         }
     }
 
     fn execute_new_table(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, array_initial_size, table_initial_size, _) = self.decode_abck();
-
-        vm.set_stack(
-            *dst,
-            Value::Table(Rc::new(RefCell::new(Table::new(
-                usize::from(*array_initial_size),
-                usize::from(*table_initial_size),
-            )))),
-        )
+        let (dst, table_initial_size, array_initial_size, _) = self.decode_abck();
+        // Chunks written by `luac` follow it with an `EXTRAARG` that has the
+        // high bits of the array size, which is only a hint
+        vm.extra_argument();
+
+        vm.allocate(
+            size_of::<RefCell<Table>>()
+                + (usize::from(*array_initial_size) + 2 * usize::from(*table_initial_size))
+                    * size_of::<Value>(),
+        )?;
+        let table = Rc::new(RefCell::new(Table::new(
+            usize::from(*array_initial_size),
+            usize::from(*table_initial_size),
+        )));
+        vm.gc.track_table(&table);
+        vm.set_stack(*dst, Value::Table(table))?;
+
+        if vm.gc.should_collect() {
+            vm.collect_garbage();
+        }
+        Ok(())
     }
 
     fn execute_table_self(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, table, key, _) = self.decode_abck();
 
         if let Value::Table(table) = vm.get_stack(*table).cloned()? {
-            vm.set_stack(*dst + 1, Value::Table(table.clone()))?;
+            vm.set_stack(self.register(*dst, 1)?, Value::Table(table.clone()))?;
 
-            let program = vm.get_running_closure();
+            let program = vm.get_running_closure()?;
             let key = ValueKey::from(program.constant(usize::from(*key))?);
             let bin_search = (*table)
                 .borrow()
@@ -1391,7 +2062,12 @@ impl Bytecode {
             };
             vm.set_stack(*dst, value)
         } else {
-            Err(Error::ExpectedTable)
+            let receiver = vm.get_stack(*table)?.clone();
+            vm.set_stack(self.register(*dst, 1)?, receiver.clone())?;
+
+            let key = vm.get_running_closure()?.constant(usize::from(*key))?;
+            let value = Self::index_metamethod(vm, receiver, key)?;
+            vm.set_stack(*dst, value)
         }
     }
 
@@ -1399,76 +2075,207 @@ impl Bytecode {
         let (dst, lhs, int, _) = self.decode_absck();
 
         let res = match &vm.get_stack(*lhs)? {
-            Value::Integer(l) => Value::Integer(l + i64::from(*int)),
-            Value::Float(l) => Value::Float(l + *int as f64),
-            lhs => {
-                return Err(Error::ArithmeticOperand(
-                    "add",
-                    lhs.static_type_name(),
-                    "integer",
-                ));
-            }
+            Value::Integer(l) => Ok(Value::Integer(l.wrapping_add(i64::from(*int)))),
+            #[cfg(feature = "float")]
+            Value::Float(l) => Ok(Value::Float(l + *int as f64)),
+            lhs => Self::add_values(lhs, &Value::Integer(i64::from(*int))),
         };
-        vm.set_stack(*dst, res)
+        Self::store_arithmetic(vm, *dst, res)
     }
 
     fn execute_add_constant(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, lhs, constant, _) = self.decode_abck();
 
-        let program = vm.get_running_closure();
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::add_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
 
-        let res = match (
-            &vm.get_stack(*lhs)?,
-            &program.constant(usize::from(*constant))?,
-        ) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l + r),
-            (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 + r),
-            (Value::Float(l), Value::Integer(r)) => Value::Float(l + *r as f64),
-            (Value::Float(l), Value::Float(r)) => Value::Float(l + r),
-            (lhs, rhs) => {
-                return Err(Error::ArithmeticOperand(
-                    "add",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
-            }
-        };
-        vm.set_stack(*dst, res)
+    fn execute_sub_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::sub_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
     }
 
     fn execute_mul_constant(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, lhs, constant, _) = self.decode_abck();
 
-        let program = vm.get_running_closure();
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::mul_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
 
-        let res = match (
-            &vm.get_stack(*lhs)?,
-            &program.constant(usize::from(*constant))?,
-        ) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l * r),
-            (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 * r),
-            (Value::Float(l), Value::Integer(r)) => Value::Float(l * *r as f64),
-            (Value::Float(l), Value::Float(r)) => Value::Float(l * r),
-            (lhs, rhs) => {
-                return Err(Error::ArithmeticOperand(
-                    "mul",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
-            }
-        };
-        vm.set_stack(*dst, res)
+    fn execute_mod_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::mod_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_pow_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::pow_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_div_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::div_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_idiv_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::idiv_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_bit_and_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::bit_and_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_bit_or_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::bit_or_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_bit_xor_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, constant, _) = self.decode_abck();
+
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let res = Self::bit_xor_values(vm.get_stack(*lhs)?, &constant);
+        Self::store_arithmetic(vm, *dst, res)
     }
 
     fn execute_add(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, lhs, rhs, _) = self.decode_abck();
 
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l + r),
+        if let Some((lhs, rhs)) = vm.get_integer_pair(*lhs, *rhs) {
+            return Self::store_arithmetic(vm, *dst, Ok(Value::Integer(lhs.wrapping_add(rhs))));
+        }
+
+        let res = Self::add_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_sub(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        if let Some((lhs, rhs)) = vm.get_integer_pair(*lhs, *rhs) {
+            return Self::store_arithmetic(vm, *dst, Ok(Value::Integer(lhs.wrapping_sub(rhs))));
+        }
+
+        let res = Self::sub_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_mul(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        if let Some((lhs, rhs)) = vm.get_integer_pair(*lhs, *rhs) {
+            return Self::store_arithmetic(vm, *dst, Ok(Value::Integer(lhs.wrapping_mul(rhs))));
+        }
+
+        let res = Self::mul_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_mod(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::mod_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_pow(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::pow_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_div(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::div_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_idiv(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::idiv_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_bit_and(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::bit_and_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_bit_or(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::bit_or_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_bit_xor(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, rhs, _) = self.decode_abck();
+
+        let res = Self::bit_xor_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    /// Stores the result of a binary arithmetic or bitwise bytecode
+    ///
+    /// The compiler and `luac` follow those with a `MMBIN`, `MMBINI` or
+    /// `MMBINK`, which is skipped if the operation succeeded, and tries
+    /// the metamethods of the operands if it didn't.
+    fn store_arithmetic(vm: &mut Lua, dst: u8, result: Result<Value, Error>) -> Result<(), Error> {
+        if !vm.metamethod_follows() {
+            return vm.set_stack(dst, result?);
+        }
+        match result {
+            Ok(value) => {
+                vm.jump(1)?;
+                vm.set_stack(dst, value)
+            }
+            Err(_) => Ok(()),
+        }
+    }
+
+    pub(crate) fn add_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l.wrapping_add(*r)),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Float(r)) => Value::Float(l + r),
+            #[cfg(feature = "float")]
             (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 + r),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Integer(r)) => Value::Float(l + *r as f64),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::add_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "add",
                     lhs.static_type_name(),
@@ -1476,18 +2283,22 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
+        Ok(res)
     }
 
-    fn execute_sub(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
-
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l - r),
+    pub(crate) fn sub_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l.wrapping_sub(*r)),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Float(r)) => Value::Float(l - r),
+            #[cfg(feature = "float")]
             (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 - r),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Integer(r)) => Value::Float(l - *r as f64),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::sub_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "sub",
                     lhs.static_type_name(),
@@ -1495,18 +2306,22 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
+        Ok(res)
     }
 
-    fn execute_mul(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
-
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l * r),
+    pub(crate) fn mul_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l.wrapping_mul(*r)),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Float(r)) => Value::Float(l * r),
+            #[cfg(feature = "float")]
             (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 * r),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Integer(r)) => Value::Float(l * *r as f64),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::mul_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "mul",
                     lhs.static_type_name(),
@@ -1514,18 +2329,22 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
-    }
-
-    fn execute_mod(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
-
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l % r),
-            (Value::Float(l), Value::Float(r)) => Value::Float(l % r),
-            (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 % r),
-            (Value::Float(l), Value::Integer(r)) => Value::Float(l % *r as f64),
+        Ok(res)
+    }
+
+    pub(crate) fn mod_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            (Value::Integer(l), Value::Integer(r)) => Value::Integer(Self::floor_mod(*l, *r)?),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Float(r)) => Value::Float(Self::float_mod(*l, *r)),
+            #[cfg(feature = "float")]
+            (Value::Integer(l), Value::Float(r)) => Value::Float(Self::float_mod(*l as f64, *r)),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Integer(r)) => Value::Float(Self::float_mod(*l, *r as f64)),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::mod_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "mod",
                     lhs.static_type_name(),
@@ -1533,18 +2352,24 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
-    }
-
-    fn execute_pow(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
-
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Float((*l as f64).powf(*r as f64)),
-            (Value::Float(l), Value::Float(r)) => Value::Float(l.powf(*r)),
-            (Value::Integer(l), Value::Float(r)) => Value::Float((*l as f64).powf(*r)),
-            (Value::Float(l), Value::Integer(r)) => Value::Float(l.powf(*r as f64)),
+        Ok(res)
+    }
+
+    #[cfg(feature = "float")]
+    pub(crate) fn pow_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            #[cfg(feature = "float")]
+            (Value::Integer(l), Value::Integer(r)) => Value::Float((*l as f64).power(*r as f64)),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Float(r)) => Value::Float(l.power(*r)),
+            #[cfg(feature = "float")]
+            (Value::Integer(l), Value::Float(r)) => Value::Float((*l as f64).power(*r)),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Integer(r)) => Value::Float(l.power(*r as f64)),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::pow_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "pow",
                     lhs.static_type_name(),
@@ -1552,18 +2377,24 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
+        Ok(res)
     }
 
-    fn execute_div(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
-
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
+    #[cfg(feature = "float")]
+    pub(crate) fn div_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            #[cfg(feature = "float")]
             (Value::Integer(l), Value::Integer(r)) => Value::Float(*l as f64 / *r as f64),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Float(r)) => Value::Float(l / r),
+            #[cfg(feature = "float")]
             (Value::Integer(l), Value::Float(r)) => Value::Float(*l as f64 / r),
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Integer(r)) => Value::Float(l / *r as f64),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::div_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "div",
                     lhs.static_type_name(),
@@ -1571,18 +2402,46 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
+        Ok(res)
     }
 
-    fn execute_idiv(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
+    #[cfg(not(feature = "float"))]
+    pub(crate) fn pow_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        Self::float_operation("pow", lhs, rhs)
+    }
+
+    #[cfg(not(feature = "float"))]
+    pub(crate) fn div_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        Self::float_operation("div", lhs, rhs)
+    }
+
+    /// Error of an operation that always makes a float, on builds
+    /// without floats
+    #[cfg(not(feature = "float"))]
+    fn float_operation(operation: &'static str, lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        match (lhs.to_number(), rhs.to_number()) {
+            (Some(_), Some(_)) => Err(Error::FloatsDisabled(operation)),
+            _ => Err(Error::ArithmeticOperand(
+                operation,
+                lhs.static_type_name(),
+                rhs.static_type_name(),
+            )),
+        }
+    }
 
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l / r),
-            (Value::Float(l), Value::Float(r)) => Value::Float((l / r).trunc()),
-            (Value::Integer(l), Value::Float(r)) => Value::Float((*l as f64 / r).trunc()),
-            (Value::Float(l), Value::Integer(r)) => Value::Float((l / *r as f64).trunc()),
+    pub(crate) fn idiv_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let res = match (lhs, rhs) {
+            (Value::Integer(l), Value::Integer(r)) => Value::Integer(Self::floor_div(*l, *r)?),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Float(r)) => Value::Float((l / r).round_down()),
+            #[cfg(feature = "float")]
+            (Value::Integer(l), Value::Float(r)) => Value::Float((*l as f64 / r).round_down()),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Integer(r)) => Value::Float((l / *r as f64).round_down()),
             (lhs, rhs) => {
+                if let Some((lhs, rhs)) = Self::coerce_operands(lhs, rhs) {
+                    return Self::idiv_values(&lhs, &rhs);
+                }
                 return Err(Error::ArithmeticOperand(
                     "idiv",
                     lhs.static_type_name(),
@@ -1590,99 +2449,241 @@ impl Bytecode {
                 ));
             }
         };
-        vm.set_stack(*dst, res)
+        Ok(res)
+    }
+
+    /// Integer division rounded towards minus infinity, like `luaV_idiv`
+    fn floor_div(lhs: i64, rhs: i64) -> Result<i64, Error> {
+        match rhs {
+            0 => Err(Error::IntegerDivisionByZero("//")),
+            // `i64::MIN // -1` overflows, and wraps back to `i64::MIN`
+            -1 => Ok(lhs.wrapping_neg()),
+            _ => {
+                let quotient = lhs / rhs;
+                // Truncated towards zero, a negative quotient with a
+                // remainder has to be rounded down
+                if lhs % rhs != 0 && (lhs ^ rhs) < 0 {
+                    Ok(quotient - 1)
+                } else {
+                    Ok(quotient)
+                }
+            }
+        }
     }
 
-    fn execute_bit_and(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
-
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l & r),
-            (lhs, rhs) => {
-                return Err(Error::BitwiseOperand(
-                    "and",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
+    /// Remainder of the integer floor division, it has the sign of `rhs`,
+    /// like `luaV_mod`
+    fn floor_mod(lhs: i64, rhs: i64) -> Result<i64, Error> {
+        match rhs {
+            0 => Err(Error::IntegerDivisionByZero("%")),
+            // `i64::MIN % -1` overflows
+            -1 => Ok(0),
+            _ => {
+                let remainder = lhs % rhs;
+                if remainder != 0 && (remainder ^ rhs) < 0 {
+                    Ok(remainder + rhs)
+                } else {
+                    Ok(remainder)
+                }
             }
-        };
-        vm.set_stack(*dst, res)
+        }
     }
 
-    fn execute_bit_or(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
+    /// Remainder of the float floor division, `NaN` when `rhs` is zero
+    #[cfg(feature = "float")]
+    fn float_mod(lhs: f64, rhs: f64) -> f64 {
+        // `%` truncates like C's `fmod`
+        let remainder = lhs % rhs;
+        if remainder != 0.0 && (remainder < 0.0) != (rhs < 0.0) {
+            remainder + rhs
+        } else {
+            remainder
+        }
+    }
 
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l | r),
-            (lhs, rhs) => {
-                return Err(Error::BitwiseOperand(
-                    "or",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
-            }
-        };
-        vm.set_stack(*dst, res)
+    pub(crate) fn bit_and_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let (lhs, rhs) = Self::bitwise_operands("and", lhs, rhs)?;
+        Ok(Value::Integer(lhs & rhs))
     }
 
-    fn execute_bit_xor(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (dst, lhs, rhs, _) = self.decode_abck();
+    pub(crate) fn bit_or_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let (lhs, rhs) = Self::bitwise_operands("or", lhs, rhs)?;
+        Ok(Value::Integer(lhs | rhs))
+    }
 
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l ^ r),
-            (lhs, rhs) => {
-                return Err(Error::BitwiseOperand(
-                    "xor",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
-            }
-        };
-        vm.set_stack(*dst, res)
+    pub(crate) fn bit_xor_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let (lhs, rhs) = Self::bitwise_operands("xor", lhs, rhs)?;
+        Ok(Value::Integer(lhs ^ rhs))
     }
 
     fn execute_shift_left(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, lhs, rhs, _) = self.decode_abck();
 
-        let res = match (&vm.get_stack(*lhs)?, &vm.get_stack(*rhs)?) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l << r),
-            (lhs, rhs) => {
-                return Err(Error::BitwiseOperand(
-                    "shift left",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
-            }
-        };
-        vm.set_stack(*dst, res)
+        let res = Self::shift_left_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
     }
 
     fn execute_shift_right(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, lhs, rhs, _) = self.decode_abck();
 
-        let res = match (
-            &vm.get_stack(*lhs)?.clone().try_int(),
-            &vm.get_stack(*rhs)?.clone().try_int(),
-        ) {
-            (Value::Integer(l), Value::Integer(r)) => Value::Integer(l >> r),
-            (lhs, rhs) => {
-                return Err(Error::BitwiseOperand(
-                    "shift right",
-                    lhs.static_type_name(),
-                    rhs.static_type_name(),
-                ));
-            }
+        let res = Self::shift_right_values(vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    pub(crate) fn shift_left_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let (lhs, rhs) = Self::bitwise_operands("shift left", lhs, rhs)?;
+        Ok(Value::Integer(Self::shift(lhs, rhs)))
+    }
+
+    pub(crate) fn shift_right_values(lhs: &Value, rhs: &Value) -> Result<Value, Error> {
+        let (lhs, rhs) = Self::bitwise_operands("shift right", lhs, rhs)?;
+        Ok(Value::Integer(Self::shift(lhs, rhs.wrapping_neg())))
+    }
+
+    /// Integers a bitwise operation works on, floats and strings with
+    /// numerals are converted if they have an exact integer representation
+    fn bitwise_operands(
+        operation: &'static str,
+        lhs: &Value,
+        rhs: &Value,
+    ) -> Result<(i64, i64), Error> {
+        if let (Value::Integer(lhs), Value::Integer(rhs)) = (lhs, rhs) {
+            return Ok((*lhs, *rhs));
+        }
+        let (Some(lhs_number), Some(rhs_number)) = (lhs.to_number(), rhs.to_number()) else {
+            return Err(Error::BitwiseOperand(
+                operation,
+                lhs.static_type_name(),
+                rhs.static_type_name(),
+            ));
+        };
+        match (lhs_number.try_int(), rhs_number.try_int()) {
+            (Value::Integer(lhs), Value::Integer(rhs)) => Ok((lhs, rhs)),
+            _ => Err(Error::NoIntegerRepresentation),
+        }
+    }
+
+    /// Operands of an arithmetic operation with their strings
+    /// read as numerals, `None` if there are no strings, or they are not
+    /// numerals
+    fn coerce_operands(lhs: &Value, rhs: &Value) -> Option<(Value, Value)> {
+        let is_string = |value: &Value| matches!(value, Value::ShortString(_) | Value::String(_));
+        if !(is_string(lhs) || is_string(rhs)) {
+            return None;
+        }
+        Some((lhs.to_number()?, rhs.to_number()?))
+    }
+
+    /// Logical shift to the left, negative shifts go right, and shifting
+    /// by 64 bits or more results in 0
+    fn shift(value: i64, shift: i64) -> i64 {
+        if shift <= -64 || shift >= 64 {
+            0
+        } else if shift >= 0 {
+            ((value as u64) << shift) as i64
+        } else {
+            ((value as u64) >> -shift) as i64
+        }
+    }
+
+    fn execute_shift_right_integer(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, lhs, int, _) = self.decode_absck();
+
+        let res = Self::shift_right_values(vm.get_stack(*lhs)?, &Value::Integer(i64::from(*int)));
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_shift_left_integer(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, rhs, int, _) = self.decode_absck();
+
+        let res = Self::shift_left_values(&Value::Integer(i64::from(*int)), vm.get_stack(*rhs)?);
+        Self::store_arithmetic(vm, *dst, res)
+    }
+
+    fn execute_metamethod(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (lhs, rhs, event, _) = self.decode_abck();
+
+        let lhs = vm.get_stack(*lhs)?.clone();
+        let rhs = vm.get_stack(*rhs)?.clone();
+        self.binary_metamethod(vm, *event, lhs, rhs)
+    }
+
+    fn execute_metamethod_integer(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (lhs, integer, event, flip) = self.decode_asbck();
+
+        let lhs = vm.get_stack(*lhs)?.clone();
+        let rhs = Value::Integer(i64::from(*integer));
+        if flip == K::ONE {
+            self.binary_metamethod(vm, *event, rhs, lhs)
+        } else {
+            self.binary_metamethod(vm, *event, lhs, rhs)
+        }
+    }
+
+    fn execute_metamethod_constant(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (lhs, constant, event, flip) = self.decode_abck();
+
+        let lhs = vm.get_stack(*lhs)?.clone();
+        let rhs = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        if flip == K::ONE {
+            self.binary_metamethod(vm, *event, rhs, lhs)
+        } else {
+            self.binary_metamethod(vm, *event, lhs, rhs)
+        }
+    }
+
+    /// Calls the metamethod for `event` of either operand, storing its
+    /// first result where the bytecode before the `MMBIN` would have
+    /// stored its own, and fails with the error of that bytecode if
+    /// neither operand has it
+    fn binary_metamethod(
+        &self,
+        vm: &mut Lua,
+        event: u8,
+        lhs: Value,
+        rhs: Value,
+    ) -> Result<(), Error> {
+        let (name, operation) = METAMETHOD_EVENTS
+            .iter()
+            .find(|(id, _, _)| *id == event)
+            .map(|(_, name, operation)| (*name, *operation))
+            .ok_or(Error::OperandConversion(
+                OpCode::read(self.bytecode).name(),
+                "event",
+            ))?;
+
+        let program_counter = vm.get_stack_frame()?.program_counter;
+        let (dst, _, _, _) = program_counter
+            .checked_sub(2)
+            .and_then(|previous| vm.get_running_program().ok()?.byte_codes.get(previous))
+            .ok_or(Error::InvalidJump)?
+            .decode_abck();
+
+        let result = match lhs.metamethod(name).or_else(|| rhs.metamethod(name)) {
+            Some(metamethod) => vm
+                .call_value(metamethod, &[lhs, rhs])?
+                .into_iter()
+                .next()
+                .unwrap_or(Value::Nil),
+            None => operation(&lhs, &rhs)?,
         };
-        vm.set_stack(*dst, res)
+        vm.set_stack(*dst, result)
     }
 
     fn execute_neg(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, rhs, _, _) = self.decode_abck();
 
         let value = match vm.get_stack(*rhs)? {
-            Value::Integer(integer) => Value::Integer(-integer),
+            Value::Integer(integer) => Value::Integer(integer.wrapping_neg()),
+            #[cfg(feature = "float")]
             Value::Float(float) => Value::Float(-float),
-            _ => return Err(Error::InvalidNegOperand),
+            string @ (Value::ShortString(_) | Value::String(_)) => match string.to_number() {
+                Some(Value::Integer(integer)) => Value::Integer(integer.wrapping_neg()),
+                #[cfg(feature = "float")]
+                Some(Value::Float(float)) => Value::Float(-float),
+                _ => return Err(Error::InvalidNegOperand("string")),
+            },
+            other => return Err(Error::InvalidNegOperand(other.static_type_name())),
         };
         vm.set_stack(*dst, value)
     }
@@ -1690,9 +2691,11 @@ impl Bytecode {
     fn execute_bit_not(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, rhs, _, _) = self.decode_abck();
 
-        let value = match vm.get_stack(*rhs)? {
-            Value::Integer(integer) => Value::Integer(!integer),
-            _ => return Err(Error::InvalidBitNotOperand),
+        let operand = vm.get_stack(*rhs)?;
+        let value = match operand.to_number().map(Value::try_int) {
+            Some(Value::Integer(integer)) => Value::Integer(!integer),
+            Some(_) => return Err(Error::NoIntegerRepresentation),
+            None => return Err(Error::InvalidBitNotOperand(operand.static_type_name())),
         };
         vm.set_stack(*dst, value)
     }
@@ -1710,10 +2713,22 @@ impl Bytecode {
     fn execute_len(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, rhs, _, _) = self.decode_abck();
 
-        let value = match &vm.get_stack(*rhs)? {
-            Value::String(string) => Value::Integer(i64::try_from(string.len())?),
-            Value::ShortString(string) => Value::Integer(i64::try_from(string.len())?),
-            _ => return Err(Error::InvalidLenOperand),
+        let value = match vm.get_stack(*rhs)? {
+            Value::String(string) => Value::Integer(self.convert("length", string.len())?),
+            Value::ShortString(string) => Value::Integer(self.convert("length", string.len())?),
+            other => {
+                let operand = other.clone();
+                if let Some(metamethod) = operand.metamethod("__len") {
+                    vm.call_value(metamethod, &[operand.clone(), operand])?
+                        .into_iter()
+                        .next()
+                        .unwrap_or(Value::Nil)
+                } else if let Value::Table(table) = &operand {
+                    Value::Integer(table.borrow().border())
+                } else {
+                    return Err(Error::InvalidLenOperand(operand.static_type_name()));
+                }
+            }
         };
         vm.set_stack(*dst, value)
     }
@@ -1721,33 +2736,105 @@ impl Bytecode {
     fn execute_concat(&self, vm: &mut Lua) -> Result<(), Error> {
         let (first, count, _, _) = self.decode_abck();
 
-        let mut strings = Vec::with_capacity(usize::from(*count));
+        let mut values = (*first..self.register(*first, *count)?)
+            .map(|src| vm.get_stack(src).cloned())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Values are concatenated from right to left, runs of strings and
+        // numbers are joined at once, and other values go through `__concat`
+        // two at a time
+        while values.len() > 1 {
+            let run_start = values
+                .iter()
+                .rposition(|value| !Self::is_concat_operand(value))
+                .map_or(0, |position| position + 1);
+
+            if run_start + 1 < values.len() {
+                // The whole run is written on a single buffer, so joining
+                // `n` values copies each of them once
+                let capacity = values[run_start..].iter().map(Self::concat_len).sum();
+                vm.allocate(capacity)?;
+                let mut concatenated = Vec::with_capacity(capacity);
+                for value in values.drain(run_start..) {
+                    Self::push_concat_bytes(&mut concatenated, &value)?;
+                }
+                values.push(concatenated.into());
+                continue;
+            }
 
-        for src in *first..(*first + *count) {
-            match &vm.get_stack(src)? {
-                Value::Integer(lhs) => strings.push(lhs.to_string()),
-                Value::Float(lhs) => strings.push(lhs.to_string()),
-                Value::ShortString(lhs) => strings.push(lhs.to_string()),
-                Value::String(lhs) => strings.push(lhs.to_string()),
-                other => return Err(Error::ConcatOperand(other.static_type_name())),
+            let rhs = values.pop().unwrap_or(Value::Nil);
+            let lhs = values.pop().unwrap_or(Value::Nil);
+            let Some(metamethod) = lhs
+                .metamethod("__concat")
+                .or_else(|| rhs.metamethod("__concat"))
+            else {
+                let operand = if Self::is_concat_operand(&lhs) {
+                    &rhs
+                } else {
+                    &lhs
+                };
+                return Err(Error::ConcatOperand(operand.static_type_name()));
             };
+            let result = vm.call_value(metamethod, &[lhs, rhs])?;
+            values.push(result.into_iter().next().unwrap_or(Value::Nil));
         }
 
-        let concatenated = strings.into_iter().collect::<String>();
-        vm.set_stack(*first, concatenated.as_str().into())
+        let result = values.pop().unwrap_or(Value::Nil);
+        if *count == 1 {
+            let mut string = Vec::with_capacity(Self::concat_len(&result));
+            Self::push_concat_bytes(&mut string, &result)?;
+            vm.set_stack(*first, string.into())
+        } else {
+            vm.set_stack(*first, result)
+        }
+    }
+
+    /// Strings and numbers can be concatenated without `__concat`
+    fn is_concat_operand(value: &Value) -> bool {
+        match value {
+            Value::Integer(_) | Value::ShortString(_) | Value::String(_) => true,
+            #[cfg(feature = "float")]
+            Value::Float(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Bytes reserved for `value` on a concatenation, numbers reserve
+    /// enough for most of them
+    fn concat_len(value: &Value) -> usize {
+        match value {
+            Value::ShortString(string) => string.len(),
+            Value::String(string) => string.len(),
+            Value::Integer(_) => 24,
+            #[cfg(feature = "float")]
+            Value::Float(_) => 24,
+            _ => 0,
+        }
+    }
+
+    /// Writes the bytes used for `value` on concatenations to `buffer`
+    fn push_concat_bytes(buffer: &mut Vec<u8>, value: &Value) -> Result<(), Error> {
+        if value.push_string_bytes(buffer) {
+            Ok(())
+        } else {
+            Err(Error::ConcatOperand(value.static_type_name()))
+        }
     }
 
     fn execute_close(&self, vm: &mut Lua) -> Result<(), Error> {
         let (first, _, _, _) = self.decode_abck();
+        let (frame_start, variadics) = vm.running_frame_start();
+        // Open upvalues hold positions on the stack, not registers
+        let first = frame_start + variadics + usize::from(*first);
 
         let upvalues_to_close = vm
-            .get_stack_frame_mut()
+            .get_stack_frame_mut()?
             .open_upvalues
             .iter()
             .enumerate()
             .filter_map(|(i, upvalue)| {
                 if let Upvalue::Open(stack) = *upvalue.borrow() {
-                    Some(i).filter(|_| stack > usize::from(*first))
+                    Some(i).filter(|_| stack >= first)
                 } else {
                     None
                 }
@@ -1755,162 +2842,179 @@ impl Bytecode {
             .collect::<Vec<_>>();
 
         for upvalue in upvalues_to_close.into_iter().rev() {
-            vm.get_stack_frame_mut()
+            vm.get_stack_frame_mut()?
                 .open_upvalues
                 .swap_remove(upvalue)
                 .borrow_mut()
-                .close(vm);
+                .close(vm)?;
+        }
+
+        vm.close_to_be_closed(first)
+    }
+
+    fn execute_to_be_closed(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (register, _, _, _) = self.decode_abck();
+
+        // `nil` and `false` don't need to be closed
+        let value = vm.get_stack(*register)?;
+        if matches!(value, Value::Nil | Value::Boolean(false)) {
+            return Ok(());
+        }
+        if value.metamethod("__close").is_none() {
+            return Err(Error::NonClosableValue(value.static_type_name()));
         }
 
+        let (frame_start, variadics) = vm.running_frame_start();
+        vm.get_stack_frame_mut()?
+            .to_be_closed
+            .push(frame_start + variadics + usize::from(*register));
         Ok(())
     }
 
     fn execute_jump(&self, vm: &mut Lua) -> Result<(), Error> {
         let jump = self.decode_sj();
 
-        vm.jump(isize::try_from(*jump)?)
+        vm.jump(self.convert("jump", *jump)?)
     }
 
     fn execute_equal(&self, vm: &mut Lua) -> Result<(), Error> {
         let (lhs, rhs, _, test) = self.decode_abck();
 
-        let lhs = vm.get_stack(*lhs)?;
-        let rhs = vm.get_stack(*rhs)?;
-
-        Self::relational_comparison(lhs, rhs, |ordering| ordering == Ordering::Equal, *test)
-            .and_then(|should_advance_pc| {
-                if should_advance_pc {
-                    vm.jump(1)?;
-                }
-                Ok(())
-            })
+        let (lhs_value, rhs_value) = (vm.get_stack(*lhs)?, vm.get_stack(*rhs)?);
+        let equal = if lhs_value.raw_equal(rhs_value) {
+            true
+        } else if matches!(
+            (lhs_value, rhs_value),
+            (Value::Table(_), Value::Table(_)) | (Value::UserData(_), Value::UserData(_))
+        ) {
+            let lhs = lhs_value.clone();
+            let rhs = vm.get_stack(*rhs)?.clone();
+            Self::comparison_metamethod(vm, "__eq", lhs, rhs)?.unwrap_or(false)
+        } else {
+            false
+        };
+        if equal != *test {
+            vm.jump(1)?;
+        }
+        Ok(())
     }
 
     fn execute_less_than(&self, vm: &mut Lua) -> Result<(), Error> {
         let (lhs, rhs, _, test) = self.decode_abck();
 
-        let lhs = vm.get_stack(*lhs)?;
-        let rhs = vm.get_stack(*rhs)?;
+        if let Some((lhs, rhs)) = vm.get_integer_pair(*lhs, *rhs) {
+            if (lhs < rhs) != *test {
+                vm.jump(1)?;
+            }
+            return Ok(());
+        }
 
-        Self::relational_comparison(lhs, rhs, |ordering| ordering == Ordering::Less, *test)
-            .and_then(|should_advance_pc| {
-                if should_advance_pc {
-                    vm.jump(1)?;
-                }
-                Ok(())
-            })
+        Self::relational_comparison(
+            vm,
+            Operand::Register(*lhs),
+            Operand::Register(*rhs),
+            "__lt",
+            |ordering| ordering == Ordering::Less,
+            *test,
+        )
     }
 
     fn execute_less_equal(&self, vm: &mut Lua) -> Result<(), Error> {
         let (lhs, rhs, _, test) = self.decode_abck();
 
-        let lhs = &vm.get_stack(*lhs)?;
-        let rhs = &vm.get_stack(*rhs)?;
+        if let Some((lhs, rhs)) = vm.get_integer_pair(*lhs, *rhs) {
+            if (lhs <= rhs) != *test {
+                vm.jump(1)?;
+            }
+            return Ok(());
+        }
 
-        Self::relational_comparison(lhs, rhs, |ordering| ordering != Ordering::Greater, *test)
-            .and_then(|should_advance_pc| {
-                if should_advance_pc {
-                    vm.jump(1)?;
-                }
-                Ok(())
-            })
+        Self::relational_comparison(
+            vm,
+            Operand::Register(*lhs),
+            Operand::Register(*rhs),
+            "__le",
+            |ordering| ordering != Ordering::Greater,
+            *test,
+        )
     }
 
     fn execute_equal_constant(&self, vm: &mut Lua) -> Result<(), Error> {
         let (register, constant, _, test) = self.decode_abck();
 
-        let program = vm.get_running_closure();
-
-        let lhs = vm.get_stack(*register)?;
-        let rhs = &program.constant(usize::from(*constant))?;
-
-        Self::relational_comparison(lhs, rhs, |ordering| ordering == Ordering::Equal, *test)
-            .and_then(|should_advance_pc| {
-                if should_advance_pc {
-                    vm.jump(1)?;
-                }
-                Ok(())
-            })
+        // Constants are never tables, so `__eq` is never called
+        let constant = vm.get_running_closure()?.constant(usize::from(*constant))?;
+        let equal = vm.get_stack(*register)?.raw_equal(&constant);
+        if equal != *test {
+            vm.jump(1)?;
+        }
+        Ok(())
     }
 
     fn execute_equal_integer(&self, vm: &mut Lua) -> Result<(), Error> {
         let (register, integer, _, test) = self.decode_asbck();
 
-        let lhs = vm.get_stack(*register)?;
-        let rhs = Value::Integer(i64::from(*integer));
+        let equal = vm
+            .get_stack(*register)?
+            .raw_equal(&Value::Integer(i64::from(*integer)));
+        if equal != (test == K::ONE) {
+            vm.jump(1)?;
+        }
+        Ok(())
+    }
+
+    fn execute_less_than_integer(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (register, integer, _, test) = self.decode_asbck();
 
         Self::relational_comparison(
-            lhs,
-            &rhs,
-            |ordering| ordering == Ordering::Equal,
+            vm,
+            Operand::Register(*register),
+            Operand::Integer(i64::from(*integer)),
+            "__lt",
+            |ordering| ordering == Ordering::Less,
             test == K::ONE,
         )
-        .and_then(|should_advance_pc| {
-            if should_advance_pc {
-                vm.jump(1)?;
-            }
-            Ok(())
-        })
     }
 
-    fn execute_less_than_integer(&self, vm: &mut Lua) -> Result<(), Error> {
+    fn execute_less_equal_integer(&self, vm: &mut Lua) -> Result<(), Error> {
         let (register, integer, _, test) = self.decode_asbck();
 
-        let lhs = vm.get_stack(*register)?;
-        let rhs = Value::Integer(i64::from(*integer));
-
         Self::relational_comparison(
-            lhs,
-            &rhs,
-            |ordering| ordering == Ordering::Less,
+            vm,
+            Operand::Register(*register),
+            Operand::Integer(i64::from(*integer)),
+            "__le",
+            |ordering| ordering != Ordering::Greater,
             test == K::ONE,
         )
-        .and_then(|should_advance_pc| {
-            if should_advance_pc {
-                vm.jump(1)?;
-            }
-            Ok(())
-        })
     }
 
     fn execute_greater_than_integer(&self, vm: &mut Lua) -> Result<(), Error> {
         let (register, integer, _, test) = self.decode_asbck();
 
-        let lhs = vm.get_stack(*register)?;
-        let rhs = Value::Integer(i64::from(*integer));
-
+        // `a > i` is evaluated as `i < a`, which is the order `__lt` receives them
         Self::relational_comparison(
-            lhs,
-            &rhs,
-            |ordering| ordering == Ordering::Greater,
+            vm,
+            Operand::Integer(i64::from(*integer)),
+            Operand::Register(*register),
+            "__lt",
+            |ordering| ordering == Ordering::Less,
             test == K::ONE,
         )
-        .and_then(|should_advance_pc| {
-            if should_advance_pc {
-                vm.jump(1)?;
-            }
-            Ok(())
-        })
     }
 
     fn execute_greater_equal_integer(&self, vm: &mut Lua) -> Result<(), Error> {
         let (register, integer, _, test) = self.decode_asbck();
 
-        let lhs = vm.get_stack(*register)?;
-        let rhs = Value::Integer(i64::from(*integer));
-
+        // `a >= i` is evaluated as `i <= a`, which is the order `__le` receives them
         Self::relational_comparison(
-            lhs,
-            &rhs,
-            |ordering| ordering != Ordering::Less,
+            vm,
+            Operand::Integer(i64::from(*integer)),
+            Operand::Register(*register),
+            "__le",
+            |ordering| ordering != Ordering::Greater,
             test == K::ONE,
         )
-        .and_then(|should_advance_pc| {
-            if should_advance_pc {
-                vm.jump(1)?;
-            }
-            Ok(())
-        })
     }
 
     fn execute_test(&self, vm: &mut Lua) -> Result<(), Error> {
@@ -1927,6 +3031,20 @@ impl Bytecode {
         Ok(())
     }
 
+    fn execute_test_set(&self, vm: &mut Lua) -> Result<(), Error> {
+        let (dst, src, _, test) = self.decode_abck();
+
+        let falsy = matches!(vm.get_stack(*src)?, Value::Nil | Value::Boolean(false));
+        if falsy == (test == K::ONE) {
+            vm.jump(1)?;
+        } else {
+            let cond = vm.get_stack(*src)?.clone();
+            vm.set_stack(*dst, cond)?;
+        }
+
+        Ok(())
+    }
+
     fn execute_call(&self, vm: &mut Lua) -> Result<(), Error> {
         let (func_index, in_items, out, _) = self.decode_abck();
 
@@ -1934,115 +3052,249 @@ impl Bytecode {
         let in_items = usize::from(*in_items);
         let out_params = usize::from(*out);
 
+        vm.profile_call(func_index_usize, in_items);
         let func = vm.get_stack(*func_index)?.clone();
-        Self::run_closure(func, vm, func_index_usize, in_items, out_params)?;
+        Self::run_closure(func, vm, func_index_usize, in_items, out_params, false)?;
 
         // TODO deal with c
         Ok(())
     }
 
     fn execute_tail_call(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (func_index, args, out_params, _) = self.decode_abck();
+        let (func_index, args, _, _) = self.decode_abck();
 
-        let func_index_usize = usize::from(*func_index);
+        let func_index = usize::from(*func_index);
         let args = usize::from(*args);
-        let out_params = usize::from(*out_params);
-
-        let top_stack = vm.get_stack_frame();
-        let tail_start = top_stack.stack_frame + func_index_usize;
-        let prev_func_index = top_stack.function_index;
-        vm.drop_stack_frame(func_index_usize, vm.stack.len() - tail_start);
 
-        let func = vm.get_stack(u8::try_from(prev_func_index)?)?.clone();
-        Self::run_closure(func, vm, prev_func_index, args, out_params)
+        vm.profile_call(func_index, args);
+        // The called function takes the place of the running one, so
+        // tail calls don't grow the stack
+        let (func, prev_func_index, out_params) = vm.drop_stack_frame_for_tail_call(func_index)?;
+        Self::run_closure(func, vm, prev_func_index, args, out_params, true)
     }
 
     fn execute_return(&self, vm: &mut Lua) -> Result<(), Error> {
         // TODO treat out params
         let (return_start, count, _, _) = self.decode_abck();
-        vm.drop_stack_frame(usize::from(*return_start), usize::from(*count - 1));
+        vm.close_frame_to_be_closed()?;
+        let return_start = usize::from(*return_start);
+        let returns = match usize::from(*count) {
+            // Returns everything up to the top of the stack
+            0 => {
+                let (frame_start, variadics) = vm.running_frame_start();
+                vm.stack
+                    .len()
+                    .checked_sub(frame_start + variadics + return_start)
+                    .ok_or_else(|| self.invalid())?
+            }
+            count => count - 1,
+        };
+        vm.hook_return()?;
+        vm.drop_stack_frame(return_start, returns)?;
         Ok(())
     }
 
     fn execute_zero_return(&self, vm: &mut Lua) -> Result<(), Error> {
-        vm.drop_stack_frame(0, 0);
+        vm.close_frame_to_be_closed()?;
+        vm.hook_return()?;
+        vm.drop_stack_frame(0, 0)?;
         Ok(())
     }
 
     fn execute_one_return(&self, vm: &mut Lua) -> Result<(), Error> {
         let (return_loc, _, _, _) = self.decode_abck();
-        vm.drop_stack_frame(usize::from(*return_loc), 1);
+        vm.close_frame_to_be_closed()?;
+        vm.hook_return()?;
+        vm.drop_stack_frame(usize::from(*return_loc), 1)?;
         Ok(())
     }
 
+    /// Registers of the loop hold the internal index, the count of
+    /// remaining iterations for integer loops or the limit for float loops,
+    /// the step, and the control variable
     fn execute_for_loop(&self, vm: &mut Lua) -> Result<(), Error> {
         let (for_stack, jmp) = self.decode_abx();
-
-        if let Value::Integer(counter) = &mut vm.get_stack_mut(*for_stack + 1)? {
-            if counter != &0 {
-                *counter -= 1;
-                Bytecode::add(*for_stack + 3, *for_stack + 3, *for_stack + 2).execute(vm)?;
-                vm.jump(-isize::try_from(*jmp)?)?;
+        let count_register = self.register(*for_stack, 1)?;
+        let step_register = self.register(*for_stack, 2)?;
+        let control_register = self.register(*for_stack, 3)?;
+
+        let index = match (
+            vm.get_stack(*for_stack)?,
+            vm.get_stack(count_register)?,
+            vm.get_stack(step_register)?,
+        ) {
+            (Value::Integer(index), Value::Integer(count), Value::Integer(step)) => {
+                // The count is unsigned, it can go past `i64::MAX`
+                if *count == 0 {
+                    return Ok(());
+                }
+                let (index, count) = (index.wrapping_add(*step), count.wrapping_sub(1));
+                vm.set_stack(count_register, Value::Integer(count))?;
+                Value::Integer(index)
             }
-            Ok(())
-        } else {
-            log::error!("For loop counter should be an Integer.");
-            Err(Error::ForZeroStep)
-        }
+            #[cfg(feature = "float")]
+            (Value::Float(index), Value::Float(limit), Value::Float(step)) => {
+                let index = index + step;
+                let keep_going = if *step > 0.0 {
+                    index <= *limit
+                } else {
+                    *limit <= index
+                };
+                if !keep_going {
+                    return Ok(());
+                }
+                Value::Float(index)
+            }
+            _ => {
+                log::error!("For loop registers should have been prepared by FORPREP.");
+                return Err(self.invalid());
+            }
+        };
+
+        vm.set_stack(*for_stack, index.clone())?;
+        vm.set_stack(control_register, index)?;
+        vm.jump(-self.convert::<isize, _>("jump", *jmp)?)
     }
 
     fn execute_for_prepare(&self, vm: &mut Lua) -> Result<(), Error> {
         let (for_stack, jmp) = self.decode_abx();
+        let count_register = self.register(*for_stack, 1)?;
+        let step_register = self.register(*for_stack, 2)?;
+        let control_register = self.register(*for_stack, 3)?;
 
-        let init = vm.get_stack(*for_stack)?;
-        let limit = vm.get_stack(*for_stack + 1)?;
-        let step = vm.get_stack(*for_stack + 2)?;
+        let init = vm.get_stack(*for_stack)?.clone();
+        let limit = vm.get_stack(count_register)?.clone();
+        let step = vm.get_stack(step_register)?.clone();
 
-        if let (&Value::Integer(init), &Value::Integer(step)) = (init, step) {
+        let skip = if let (Value::Integer(init), Value::Integer(step)) = (&init, &step) {
+            let (init, step) = (*init, *step);
             if step == 0 {
                 return Err(Error::ForZeroStep);
             }
-
-            let count = match limit {
-                Value::Integer(i) => (i - init) / step,
-                Value::Float(i) => (*i as i64 - init) / step,
-                _ => {
-                    log::error!("For loop limit can't be converted to Float.");
-                    return Err(Error::TryFloatConversion);
+            vm.set_stack(control_register, Value::Integer(init))?;
+
+            match Self::for_limit(init, &limit, step)? {
+                Some(limit) => {
+                    // Number of iterations after the first, as an unsigned
+                    // integer so that loops over the whole range of integers
+                    // don't overflow
+                    let count = if step > 0 {
+                        (limit as u64).wrapping_sub(init as u64) / step as u64
+                    } else {
+                        (init as u64).wrapping_sub(limit as u64) / ((-(step + 1)) as u64 + 1)
+                    };
+                    vm.set_stack(count_register, Value::Integer(count as i64))?;
+                    false
                 }
-            };
-
-            vm.set_stack(*for_stack + 1, Value::Integer(count))?;
-            vm.set_stack(*for_stack + 3, Value::Integer(init))?;
-            if count <= 0 {
-                vm.jump(isize::try_from(*jmp)? + 1)?;
+                None => true,
             }
-            Ok(())
         } else {
-            let Some(Value::Float(init)) = init.try_float() else {
-                log::error!("For loop init can't be converted to Float.");
-                return Err(Error::TryFloatConversion);
-            };
-            let Some(Value::Float(limit)) = limit.try_float() else {
-                log::error!("For loop limit can't be converted to Float.");
-                return Err(Error::TryFloatConversion);
-            };
-            let Some(Value::Float(step)) = step.try_float() else {
-                log::error!("For loop step can't be converted to Float.");
-                return Err(Error::TryFloatConversion);
-            };
+            self.prepare_float_loop(vm, init, limit, step)?
+        };
 
-            let count = ((limit - init) / step).trunc();
+        if skip {
+            vm.jump(self.convert::<isize, _>("jump", *jmp)? + 1)?;
+        }
+        Ok(())
+    }
 
-            vm.set_stack(*for_stack, Value::Float(init))?;
-            vm.set_stack(*for_stack + 1, Value::Integer(count as i64))?;
-            vm.set_stack(*for_stack + 2, Value::Float(step))?;
-            vm.set_stack(*for_stack + 3, Value::Float(init))?;
-            if count <= 0.0 {
-                vm.jump(isize::try_from(*jmp)? + 1)?;
-            }
-            Ok(())
+    /// Turns the initial value, limit, and step of a loop that is not over
+    /// integers into floats, returns whether the loop is skipped
+    #[cfg(feature = "float")]
+    fn prepare_float_loop(
+        &self,
+        vm: &mut Lua,
+        init: Value,
+        limit: Value,
+        step: Value,
+    ) -> Result<bool, Error> {
+        let (for_stack, _) = self.decode_abx();
+        let count_register = self.register(*for_stack, 1)?;
+        let step_register = self.register(*for_stack, 2)?;
+        let control_register = self.register(*for_stack, 3)?;
+
+        let Some(limit) = limit.to_number().and_then(|limit| limit.try_float()) else {
+            return Err(Error::ForNotNumber("limit"));
+        };
+        let Some(step) = step.to_number().and_then(|step| step.try_float()) else {
+            return Err(Error::ForNotNumber("step"));
+        };
+        let Some(init) = init.to_number().and_then(|init| init.try_float()) else {
+            return Err(Error::ForNotNumber("initial"));
+        };
+        let (Value::Float(float_limit), Value::Float(float_step), Value::Float(float_init)) =
+            (&limit, &step, &init)
+        else {
+            unreachable!("`try_float` should always return a float.");
+        };
+        if *float_step == 0.0 {
+            return Err(Error::ForZeroStep);
         }
+        let skip = if *float_step > 0.0 {
+            float_limit < float_init
+        } else {
+            float_init < float_limit
+        };
+
+        vm.set_stack(*for_stack, init.clone())?;
+        vm.set_stack(count_register, limit)?;
+        vm.set_stack(step_register, step)?;
+        vm.set_stack(control_register, init)?;
+        Ok(skip)
+    }
+
+    /// Builds without floats only have loops over integers
+    #[cfg(not(feature = "float"))]
+    fn prepare_float_loop(
+        &self,
+        _vm: &mut Lua,
+        init: Value,
+        _limit: Value,
+        _step: Value,
+    ) -> Result<bool, Error> {
+        let name = if matches!(init, Value::Integer(_)) {
+            "step"
+        } else {
+            "initial"
+        };
+        Err(Error::ForNotNumber(name))
+    }
+
+    /// Limit of an integer loop, floats are rounded towards the inside of
+    /// the loop and clipped to the integers, `None` if the loop doesn't run
+    fn for_limit(init: i64, limit: &Value, step: i64) -> Result<Option<i64>, Error> {
+        let limit = match limit.to_number() {
+            Some(Value::Integer(limit)) => limit,
+            #[cfg(feature = "float")]
+            Some(Value::Float(limit)) => {
+                let rounded = if step < 0 {
+                    limit.round_up()
+                } else {
+                    limit.round_down()
+                };
+                match Value::Float(rounded).try_int() {
+                    Value::Integer(limit) => limit,
+                    // Too large or too small, or NaN, which is treated as
+                    // too small
+                    _ if limit > 0.0 => {
+                        if step < 0 {
+                            return Ok(None);
+                        }
+                        i64::MAX
+                    }
+                    _ => {
+                        if step > 0 {
+                            return Ok(None);
+                        }
+                        i64::MIN
+                    }
+                }
+            }
+            _ => return Err(Error::ForNotNumber("limit")),
+        };
+
+        let skip = if step > 0 { init > limit } else { init < limit };
+        Ok((!skip).then_some(limit))
     }
 
     fn execute_generic_for_prepare(&self, vm: &mut Lua) -> Result<(), Error> {
@@ -2050,68 +3302,157 @@ impl Bytecode {
 
         // TODO do whatever it is that the official implementation do to upvalues
 
-        vm.jump(isize::try_from(*jmp).map_err(|_| Error::InvalidJump)?)
+        vm.jump(self.convert("jump", *jmp)?)
     }
 
     fn execute_generic_for_call(&self, vm: &mut Lua) -> Result<(), Error> {
         let (for_stack, _, args_count, _) = self.decode_abck();
+        // The call is made on the registers after the loop's, which must
+        // fit the iterator and its two arguments
+        let call = self.register(*for_stack, 4)?;
+        self.register(call, 2)?;
 
         let iterator = vm.get_stack(*for_stack)?.clone();
-        vm.set_stack(*for_stack + 4, iterator.clone())?;
+        if Self::ipairs_step(vm, &iterator, *for_stack, *args_count)? {
+            return Ok(());
+        }
+        vm.set_stack(call, iterator.clone())?;
         let state = vm.get_stack(*for_stack + 1)?.clone();
-        vm.set_stack(*for_stack + 5, state)?;
+        vm.set_stack(call + 1, state)?;
         let control = vm.get_stack(*for_stack + 2)?.clone();
-        vm.set_stack(*for_stack + 6, control)?;
+        vm.set_stack(call + 2, control)?;
+        // Like `CALL`, counts are one more than the number of arguments and
+        // results, the iterator receives the state and control, and returns
+        // a value for each variable of the loop
         Self::run_closure(
             iterator,
             vm,
-            usize::from(*for_stack + 4),
-            2,
-            usize::from(*args_count),
+            usize::from(call),
+            3,
+            usize::from(*args_count) + 1,
+            false,
         )
     }
 
+    /// Steps a generic `for` over `ipairs` of a table in place of the
+    /// call to its iterator, returning `false` if the loop is not one
+    ///
+    /// The results are left where the call would, and are the same, but
+    /// hooks don't see the call, so loops are only stepped here while
+    /// there are no hooks. Function pointers are not guaranteed to be
+    /// unique, a loop that is not recognized makes the call instead.
+    fn ipairs_step(
+        vm: &mut Lua,
+        iterator: &Value,
+        for_stack: u8,
+        args_count: u8,
+    ) -> Result<bool, Error> {
+        let Value::Closure(closure) = iterator else {
+            return Ok(false);
+        };
+        let FunctionType::Native(native) = closure.closure_type() else {
+            return Ok(false);
+        };
+        if vm.hooks.is_some()
+            || !core::ptr::fn_addr_eq(*native, crate::std::ipairs_next as NativeClosure)
+        {
+            return Ok(false);
+        }
+        let (Value::Table(table), Value::Integer(index)) =
+            (vm.get_stack(for_stack + 1)?, vm.get_stack(for_stack + 2)?)
+        else {
+            return Ok(false);
+        };
+
+        let index = index.wrapping_add(1);
+        let value = table.borrow().get(ValueKey(Value::Integer(index))).clone();
+        let (frame_start, variadics) = vm.running_frame_start();
+        let call = frame_start + variadics + usize::from(for_stack) + 4;
+        vm.stack.set_top(call);
+        if value == Value::Nil {
+            vm.stack.push(Value::Nil);
+        } else {
+            vm.stack.extend([Value::Integer(index), value]);
+        }
+        vm.stack.set_top(call + usize::from(args_count));
+        Ok(true)
+    }
+
     fn execute_generic_for_loop(&self, vm: &mut Lua) -> Result<(), Error> {
         let (for_stack, jmp) = self.decode_abx();
 
-        let test = vm.get_stack(*for_stack + 4)?.clone();
+        let test = vm.get_stack(self.register(*for_stack, 4)?)?.clone();
         vm.set_stack(*for_stack + 2, test.clone())?;
         if test == Value::Nil {
             Ok(())
         } else {
-            vm.jump(-(isize::try_from(*jmp).map_err(|_| Error::InvalidJump)?))
+            vm.jump(-self.convert::<isize, _>("jump", *jmp)?)
         }
     }
 
     fn execute_set_list(&self, vm: &mut Lua) -> Result<(), Error> {
-        let (table, count, _c, _) = self.decode_abck();
+        let (table, count, stored, k) = self.decode_abck();
 
-        let top_stack = vm.get_stack_frame();
+        let stored = if *k {
+            let high = vm.extra_argument().ok_or_else(|| self.invalid())?;
+            self.convert::<usize, _>("extra argument", high)? * (usize::from(u8::MAX) + 1)
+                + usize::from(*stored)
+        } else {
+            usize::from(*stored)
+        };
+
+        let top_stack = vm.get_stack_frame()?;
 
         let table_items_start =
             top_stack.stack_frame + top_stack.variadic_arguments + usize::from(*table) + 1;
         if let Value::Table(table) = vm.get_stack(*table)?.clone() {
-            let values = if *count == 0 {
-                let true_count = vm.stack.len() - table_items_start;
-                vm.stack
-                    .drain(table_items_start..(table_items_start + true_count))
+            let items_end = if *count == 0 {
+                vm.stack.len()
             } else {
-                vm.stack
-                    .drain(table_items_start..(table_items_start + usize::from(*count)))
+                table_items_start + usize::from(*count)
             };
-
-            table.borrow_mut().array.extend(values);
+            if table_items_start > items_end || items_end > vm.stack.len() {
+                return Err(self.invalid());
+            }
+            let size = vm.stack[table_items_start..items_end]
+                .iter()
+                .map(value_size)
+                .sum();
+            vm.allocate(size)?;
+            let values = vm.stack[table_items_start..items_end]
+                .iter_mut()
+                .map(|value| core::mem::replace(value, Value::Nil));
+
+            let mut table = table.borrow_mut();
+            if table.array.len() == stored {
+                table.array.extend(values);
+                table.migrate_to_array();
+            } else {
+                // Fields with keys on the constructor grew the array
+                for (index, value) in (stored..).zip(values) {
+                    let index = self.convert::<i64, _>("index", index)? + 1;
+                    table.raw_set(ValueKey(Value::Integer(index)), value);
+                }
+            }
+            vm.stack.set_top(table_items_start);
             Ok(())
         } else {
-            Err(Error::ExpectedTable)
+            Err(Error::ExpectedTable(
+                vm.get_stack(*table)?.static_type_name(),
+            ))
         }
     }
 
+    fn execute_extra_arguments(&self, _vm: &mut Lua) -> Result<(), Error> {
+        // Read and skipped by the bytecode before it
+        Err(self.invalid())
+    }
+
     fn execute_closure(&self, vm: &mut Lua) -> Result<(), Error> {
         let (dst, func_id) = self.decode_abx();
-        let func_id = usize::try_from(*func_id)?;
+        let func_id: usize = self.convert("function", *func_id)?;
 
-        let program = vm.get_running_closure();
+        let program = vm.get_running_closure()?;
 
         let Ok(func) = program.function(func_id).inspect_err(|err| {
             log::error!("{}", err);
@@ -2121,20 +3462,33 @@ impl Bytecode {
 
         let upvalues = func
             .program()
-            .upvalues
+            .upvalue_descriptors()
             .iter()
-            .map(|upvalue| vm.find_upvalue(upvalue))
+            .map(|descriptor| {
+                if descriptor.in_stack() {
+                    vm.capture_register(descriptor.index())
+                } else {
+                    vm.get_running_closure()?
+                        .upvalue(usize::from(descriptor.index()))
+                }
+            })
             .collect::<Result<Vec<_>, _>>()?;
 
-        let closure = Value::Closure(Rc::new(Closure::new_lua(func, upvalues)));
+        vm.allocate(size_of::<Closure>() + upvalues.len() * size_of::<Rc<()>>())?;
+        let closure = Rc::new(Closure::new_lua(func, upvalues));
+        vm.gc.track_closure(&closure);
+        vm.set_stack(*dst, Value::Closure(closure))?;
 
-        vm.set_stack(*dst, closure)
+        if vm.gc.should_collect() {
+            vm.collect_garbage();
+        }
+        Ok(())
     }
 
     fn execute_variadic_arguments(&self, vm: &mut Lua) -> Result<(), Error> {
         let (register, _, count, _) = self.decode_abck();
 
-        let top_stack = vm.get_stack_frame();
+        let top_stack = vm.get_stack_frame()?;
 
         let variadics = top_stack.variadic_arguments;
 
@@ -2142,25 +3496,19 @@ impl Bytecode {
             let start = top_stack.stack_frame;
             let end = start + variadics;
 
-            let move_vals = vm.stack[start..end].to_vec();
-
-            vm.stack
-                .truncate(start + variadics + usize::from(*register));
-            vm.stack.extend(move_vals);
+            vm.stack.set_top(start + variadics + usize::from(*register));
+            vm.stack.extend_from_within(start..end);
         } else {
             let true_count = usize::from(*count - 1);
             let start = top_stack.stack_frame;
             let end = start + true_count.min(variadics);
 
-            let move_vals = vm.stack[start..end].to_vec();
-
-            vm.stack
-                .truncate(start + variadics + usize::from(*register));
-            vm.stack.extend(move_vals);
+            vm.stack.set_top(start + variadics + usize::from(*register));
+            vm.stack.extend_from_within(start..end);
 
             if true_count > variadics {
                 let remaining = true_count - variadics;
-                vm.stack.resize(vm.stack.len() + remaining, Value::Nil);
+                vm.stack.set_top(vm.stack.len() + remaining);
             }
         }
 
@@ -2181,37 +3529,112 @@ impl Bytecode {
         self.bytecode = Self::encode_abck(op, a, b, c, test.flip());
     }
 
+    /// Compares `lhs` and `rhs`, falling back to the `event` metamethod of
+    /// either of them, and skips the next instruction if the result
+    /// differs from `test`
+    ///
+    /// Numbers are compared by their exact values, whatever their types,
+    /// and NaN is neither less than, equal to, nor greater than any number.
+    /// Strings are compared by their bytes. The operands are only cloned
+    /// if the comparison needs the `event` metamethod.
     fn relational_comparison(
-        lhs: &Value,
-        rhs: &Value,
+        vm: &mut Lua,
+        lhs: Operand,
+        rhs: Operand,
+        event: &str,
         ordering_test: fn(Ordering) -> bool,
         test: bool,
-    ) -> Result<bool, Error> {
-        if let Some(ordering) = lhs.partial_cmp(rhs) {
-            Ok(ordering_test(ordering) != test)
+    ) -> Result<(), Error> {
+        let ordering = match (lhs, rhs) {
+            (Operand::Register(lhs), Operand::Register(rhs)) => {
+                vm.get_stack(lhs)?.partial_cmp(vm.get_stack(rhs)?)
+            }
+            (Operand::Register(lhs), Operand::Integer(rhs)) => {
+                vm.get_stack(lhs)?.partial_cmp(&Value::Integer(rhs))
+            }
+            (Operand::Integer(lhs), Operand::Register(rhs)) => {
+                Value::Integer(lhs).partial_cmp(vm.get_stack(rhs)?)
+            }
+            (Operand::Integer(lhs), Operand::Integer(rhs)) => Some(lhs.cmp(&rhs)),
+        };
+        let result = if let Some(ordering) = ordering {
+            ordering_test(ordering)
         } else {
-            Err(Error::RelationalOperand(
-                lhs.static_type_name(),
-                rhs.static_type_name(),
-            ))
+            let lhs = lhs.value(vm)?;
+            let rhs = rhs.value(vm)?;
+            let lhs_type = lhs.static_type_name();
+            let rhs_type = rhs.static_type_name();
+            match (lhs, rhs) {
+                // Only NaN leaves numbers unordered
+                #[cfg(feature = "float")]
+                (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => false,
+                (lhs, rhs) => Self::comparison_metamethod(vm, event, lhs, rhs)?
+                    .ok_or(Error::RelationalOperand(lhs_type, rhs_type))?,
+            }
+        };
+
+        if result != test {
+            vm.jump(1)?;
         }
+        Ok(())
+    }
+
+    /// Calls the `event` metamethod of `lhs`, or of `rhs` if `lhs` does not
+    /// have one, returning the truthiness of its first result
+    fn comparison_metamethod(
+        vm: &mut Lua,
+        event: &str,
+        lhs: Value,
+        rhs: Value,
+    ) -> Result<Option<bool>, Error> {
+        let Some(metamethod) = lhs.metamethod(event).or_else(|| rhs.metamethod(event)) else {
+            return Ok(None);
+        };
+
+        let results = vm.call_value(metamethod, &[lhs, rhs])?;
+        Ok(Some(!matches!(
+            results.first(),
+            None | Some(Value::Nil | Value::Boolean(false))
+        )))
     }
 
-    fn run_closure(
+    pub(crate) fn run_closure(
         func: Value,
         vm: &mut Lua,
         func_index: usize,
         in_items: usize,
         out_params: usize,
+        tail_call: bool,
     ) -> Result<(), Error> {
+        if vm.stack_frame.len() >= vm.max_call_depth {
+            log::error!("Reached the maximum call depth of {}.", vm.max_call_depth);
+            return Err(Error::StackOverflow);
+        }
+
+        let (func, in_items) = match func {
+            Value::Closure(_) => (func, in_items),
+            other => {
+                let Some(metamethod @ Value::Closure(_)) = other.metamethod("__call") else {
+                    return Err(Error::InvalidFunction(other));
+                };
+                // `__call` takes the place of the called value, which
+                // becomes its first argument
+                let (frame_start, variadics) = vm.running_frame_start();
+                vm.stack
+                    .insert(frame_start + variadics + func_index, metamethod.clone());
+                let in_items = if in_items == 0 { 0 } else { in_items + 1 };
+                (metamethod, in_items)
+            }
+        };
+
         if let Value::Closure(closure) = func {
             match closure.closure_type() {
-                FunctionType::Native(closure) => {
-                    Self::run_native_function(vm, func_index, in_items, out_params, *closure)
-                }
+                FunctionType::Native(closure) => Self::run_native_function(
+                    vm, func_index, in_items, out_params, tail_call, *closure,
+                ),
                 FunctionType::Lua(closure) => {
                     let closure = closure.clone();
-                    Self::setup_closure(vm, func_index, in_items, out_params, closure.as_ref())
+                    Self::setup_closure(vm, func_index, out_params, tail_call, closure.as_ref())
                 }
             }
         } else {
@@ -2224,23 +3647,37 @@ impl Bytecode {
         func_index: usize,
         args: usize,
         out_params: usize,
+        tail_call: bool,
         func: NativeClosure,
     ) -> Result<(), Error> {
         log::trace!("Calling native function");
 
-        let top_stack = vm.get_stack_frame();
+        let (frame_start, variadics) = vm.running_frame_start();
 
         let args = if args == 0 {
-            vm.stack.len() - (top_stack.stack_frame + top_stack.variadic_arguments + func_index) - 1
+            vm.stack.len() - (frame_start + variadics + func_index) - 1
         } else {
             args - 1
         };
 
-        vm.prepare_new_stack_frame(func_index, args, out_params, 0);
+        vm.prepare_new_stack_frame(func_index, 0, args, out_params, 0, tail_call);
+        vm.hook_call()?;
 
         let returns = func(vm)?;
+        // Values created by the host, like the strings of the standard
+        // library, only count towards the memory limit as they are returned
+        let (frame_start, _) = vm.running_frame_start();
+        let size = vm
+            .stack
+            .iter()
+            .skip(frame_start)
+            .take(returns)
+            .map(value_size)
+            .sum();
+        vm.allocate(size)?;
 
-        vm.drop_stack_frame(0, returns);
+        vm.hook_return()?;
+        vm.drop_stack_frame(0, returns)?;
 
         Ok(())
     }
@@ -2248,24 +3685,18 @@ impl Bytecode {
     fn setup_closure(
         vm: &mut Lua,
         func_index: usize,
-        args: usize,
         out_params: usize,
+        tail_call: bool,
         func: &Function,
     ) -> Result<(), Error> {
         log::trace!("Calling closure");
 
-        let top_stack = vm.get_stack_frame();
+        let (frame_start, variadics) = vm.running_frame_start();
 
-        let locals_and_temps_on_function_stack = vm.stack.len()
-            - (top_stack.stack_frame + top_stack.variadic_arguments + func_index)
-            - 1;
+        let locals_and_temps_on_function_stack =
+            vm.stack.len() - (frame_start + variadics + func_index) - 1;
 
-        let (args, var_args) = if args == 0 {
-            (
-                locals_and_temps_on_function_stack - top_stack.variadic_arguments,
-                top_stack.variadic_arguments,
-            )
-        } else if func.variadic_args() {
+        let (args, var_args) = if func.variadic_args() {
             (
                 func.arg_count(),
                 locals_and_temps_on_function_stack.saturating_sub(func.arg_count()),
@@ -2274,21 +3705,18 @@ impl Bytecode {
             (func.arg_count(), 0)
         };
 
-        if args > 0 && var_args > 0 {
-            let variadics = vm
-                .stack
-                .drain((vm.stack.len() - var_args)..)
-                .collect::<Vec<_>>();
-            let fixed = vm
-                .stack
-                .drain((vm.stack.len() - args)..)
-                .collect::<Vec<_>>();
-
-            vm.stack.extend(variadics);
-            vm.stack.extend(fixed);
+        // The fixed arguments are moved above the variadic ones, and the
+        // stack frame starts on the variadic arguments
+        let skipped = if var_args > 0 { args } else { 0 };
+        if skipped > 0 {
+            let arguments = vm.stack.len() - args - var_args;
+            let registers = vm.stack.len();
+            vm.stack.set_top(registers + args);
+            vm.stack.move_values(arguments, registers, args);
         }
 
-        vm.prepare_new_stack_frame(func_index, args, out_params, var_args);
+        vm.prepare_new_stack_frame(func_index, skipped, args, out_params, var_args, tail_call);
+        vm.hook_call()?;
 
         Ok(())
     }
@@ -2339,6 +3767,13 @@ impl Bytecode {
         bytecode
     }
 
+    pub(crate) fn encode_ax(op: OpCode, ax: Ax) -> u32 {
+        let mut bytecode = 0;
+        op.write(&mut bytecode);
+        ax.write(&mut bytecode);
+        bytecode
+    }
+
     pub(crate) fn encode_asj(op: OpCode, j: Sj) -> u32 {
         let mut bytecode = 0;
         op.write(&mut bytecode);
@@ -2346,7 +3781,8 @@ impl Bytecode {
         bytecode
     }
 
-    pub(crate) fn decode_abck(&self) -> (A, B, C, K) {
+    /// Operands of an `iABC` instruction
+    pub fn decode_abck(&self) -> (A, B, C, K) {
         (
             A::read(self.bytecode),
             B::read(self.bytecode),
@@ -2355,7 +3791,8 @@ impl Bytecode {
         )
     }
 
-    pub(crate) fn decode_asbck(&self) -> (A, Sb, C, K) {
+    /// Operands of an `iABC` instruction whose `B` is signed
+    pub fn decode_asbck(&self) -> (A, Sb, C, K) {
         (
             A::read(self.bytecode),
             Sb::read(self.bytecode),
@@ -2364,7 +3801,8 @@ impl Bytecode {
         )
     }
 
-    pub(crate) fn decode_absck(&self) -> (A, B, Sc, K) {
+    /// Operands of an `iABC` instruction whose `C` is signed
+    pub fn decode_absck(&self) -> (A, B, Sc, K) {
         (
             A::read(self.bytecode),
             B::read(self.bytecode),
@@ -2373,21 +3811,60 @@ impl Bytecode {
         )
     }
 
-    pub(crate) fn decode_abx(&self) -> (A, Bx) {
+    /// Operands of an `iABx` instruction
+    pub fn decode_abx(&self) -> (A, Bx) {
         (A::read(self.bytecode), Bx::read(self.bytecode))
     }
 
-    pub(crate) fn decode_asbx(&self) -> (A, Sbx) {
+    /// Operands of an `iAsBx` instruction
+    pub fn decode_asbx(&self) -> (A, Sbx) {
         (A::read(self.bytecode), Sbx::read(self.bytecode))
     }
 
-    pub(crate) fn decode_ax(&self) -> Ax {
+    /// Operand of an `iAx` instruction
+    pub fn decode_ax(&self) -> Ax {
         Ax::read(self.bytecode)
     }
 
-    pub(crate) fn decode_sj(&self) -> Sj {
+    /// Operand of an `isJ` instruction
+    pub fn decode_sj(&self) -> Sj {
         Sj::read(self.bytecode)
     }
+
+    /// Converts `value` into the integer type used by the Vm, naming the
+    /// opcode and operand on failure
+    /// Register `offset` registers above `base`, failing if it is past
+    /// the last register
+    fn register(&self, base: u8, offset: u8) -> Result<u8, Error> {
+        base.checked_add(offset).ok_or_else(|| self.invalid())
+    }
+
+    /// Error for bytecodes whose operands refer to values that don't exist
+    fn invalid(&self) -> Error {
+        Error::InvalidBytecode(OpCode::read(self.bytecode).name())
+    }
+
+    fn convert<T: TryFrom<U>, U>(&self, operand: &'static str, value: U) -> Result<T, Error> {
+        T::try_from(value)
+            .map_err(|_| Error::OperandConversion(OpCode::read(self.bytecode).name(), operand))
+    }
+}
+
+/// Operand of a relational comparison, either a register or the
+/// integer encoded on the bytecode
+#[derive(Clone, Copy)]
+enum Operand {
+    Register(u8),
+    Integer(i64),
+}
+
+impl Operand {
+    fn value(self, vm: &Lua) -> Result<Value, Error> {
+        match self {
+            Operand::Register(register) => vm.get_stack(register).cloned(),
+            Operand::Integer(integer) => Ok(Value::Integer(integer)),
+        }
+    }
 }
 
 impl Deref for Bytecode {
@@ -2398,13 +3875,17 @@ impl Deref for Bytecode {
     }
 }
 
-impl Debug for Bytecode {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+impl Bytecode {
+    /// Writes the operands of the bytecode, `k` marks an operand
+    /// that is a constant
+    pub(crate) fn fmt_operands(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        separator: &str,
+    ) -> core::fmt::Result {
         let op = OpCode::read(self.bytecode);
         match op {
-            OpCode::ZeroReturn => {
-                write!(f, "{:?}", op)
-            }
+            OpCode::ZeroReturn => Ok(()),
             OpCode::LoadConstantExtraArgs
             | OpCode::LoadFalse
             | OpCode::LoadFalseSkip
@@ -2414,11 +3895,15 @@ impl Debug for Bytecode {
             | OpCode::OneReturn
             | OpCode::VariadicArgumentsPrepare => {
                 let (a, _, _, _) = self.decode_abck();
-                write!(f, "{:?}({})", op, *a)
+                write!(f, "{}", *a)
             }
             OpCode::Test => {
                 let (a, _, _, k) = self.decode_abck();
-                write!(f, "{:?}({}, {})", op, *a, k == K::ONE)
+                write!(f, "{}{separator}{}", *a, k == K::ONE)
+            }
+            OpCode::TestSet => {
+                let (a, b, _, k) = self.decode_abck();
+                write!(f, "{}{separator}{}{separator}{}", *a, *b, k == K::ONE)
             }
             OpCode::Move
             | OpCode::LoadNil
@@ -2430,18 +3915,13 @@ impl Debug for Bytecode {
             | OpCode::Len
             | OpCode::Concat => {
                 let (a, b, _, _) = self.decode_abck();
-                write!(f, "{:?}({}, {})", op, *a, *b)
+                write!(f, "{}{separator}{}", *a, *b)
             }
-            OpCode::Equal
-            | OpCode::LessThan
-            | OpCode::LessEqual
-            | OpCode::EqualConstant
-            | OpCode::TestSet => {
+            OpCode::Equal | OpCode::LessThan | OpCode::LessEqual | OpCode::EqualConstant => {
                 let (a, b, _, k) = self.decode_abck();
                 write!(
                     f,
-                    "{:?}({}, {}{})",
-                    op,
+                    "{}{separator}{}{}",
                     *a,
                     *b,
                     if k == K::ONE { "k" } else { "" }
@@ -2455,8 +3935,7 @@ impl Debug for Bytecode {
                 let (a, sb, _, k) = self.decode_asbck();
                 write!(
                     f,
-                    "{:?}({}, {}{})",
-                    op,
+                    "{}{separator}{}{}",
                     *a,
                     *sb,
                     if k == K::ONE { "k" } else { "" }
@@ -2469,11 +3948,11 @@ impl Debug for Bytecode {
             | OpCode::GenericForLoop
             | OpCode::Closure => {
                 let (a, bx) = self.decode_abx();
-                write!(f, "{:?}({}, {})", op, *a, *bx)
+                write!(f, "{}{separator}{}", *a, *bx)
             }
             OpCode::LoadInteger | OpCode::LoadFloat => {
                 let (a, sbx) = self.decode_asbx();
-                write!(f, "{:?}({}, {})", op, *a, *sbx)
+                write!(f, "{}{separator}{}", *a, *sbx)
             }
             OpCode::GetUpTable
             | OpCode::GetTable
@@ -2505,15 +3984,22 @@ impl Debug for Bytecode {
             | OpCode::MetaMethod
             | OpCode::Call => {
                 let (a, b, c, _) = self.decode_abck();
-                write!(f, "{:?}({}, {}, {})", op, *a, *b, *c)
+                write!(f, "{}{separator}{}{separator}{}", *a, *b, *c)
             }
             OpCode::MetaMethodInteger => {
-                let (a, sb, c, _) = self.decode_asbck();
-                write!(f, "{:?}({}, {}, {})", op, *a, *sb, *c)
+                let (a, sb, c, k) = self.decode_asbck();
+                write!(
+                    f,
+                    "{}{separator}{}{separator}{}{}",
+                    *a,
+                    *sb,
+                    *c,
+                    if k == K::ONE { "k" } else { "" }
+                )
             }
             OpCode::AddInteger | OpCode::ShiftRightInteger | OpCode::ShiftLeftInteger => {
                 let (a, b, sc, _) = self.decode_absck();
-                write!(f, "{:?}({}, {}, {})", op, *a, *b, *sc)
+                write!(f, "{}{separator}{}{separator}{}", *a, *b, *sc)
             }
             OpCode::SetUpTable
             | OpCode::SetTable
@@ -2527,8 +4013,7 @@ impl Debug for Bytecode {
                 let (a, b, c, k) = self.decode_abck();
                 write!(
                     f,
-                    "{:?}({}, {}, {}{})",
-                    op,
+                    "{}{separator}{}{separator}{}{}",
                     *a,
                     *b,
                     *c,
@@ -2537,22 +4022,149 @@ impl Debug for Bytecode {
             }
             OpCode::VariadicArguments | OpCode::GenericForCall => {
                 let (a, _, c, _) = self.decode_abck();
-                write!(f, "{:?}({}, {})", op, *a, *c)
+                write!(f, "{}{separator}{}", *a, *c)
             }
             OpCode::ExtraArguments => {
                 let ax = self.decode_ax();
-                write!(f, "{:?}({})", op, *ax)
+                write!(f, "{}", *ax)
             }
             OpCode::Jump => {
                 let sj = self.decode_sj();
-                write!(f, "{:?}({})", op, *sj)
+                write!(f, "{}", *sj)
             }
         }
     }
 }
 
+impl Debug for Bytecode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if let Some(id) = self.custom_opcode() {
+            return write!(f, "Custom({}, {:#010x})", id, self.bytecode);
+        }
+        let op = OpCode::read(self.bytecode);
+        if op == OpCode::ZeroReturn {
+            return write!(f, "{:?}", op);
+        }
+        write!(f, "{:?}(", op)?;
+        self.fmt_operands(f, ", ")?;
+        write!(f, ")")
+    }
+}
+
 impl Display for Bytecode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opcode_names() {
+        assert_eq!(OpCode::from_id(0).name(), "MOVE");
+        assert_eq!(OpCode::GetIndex.name(), "GETI");
+        assert_eq!(OpCode::ShiftLeftInteger.name(), "SHLI");
+        assert_eq!(OpCode::GenericForPrepare.name(), "TFORPREP");
+        assert_eq!(OpCode::from_id(82).name(), "EXTRAARG");
+    }
+
+    #[test]
+    fn decode_instructions() {
+        for (bytecode, decoded) in [
+            (
+                Bytecode::call(1, 2, 0),
+                DecodedInstruction::Abck {
+                    opcode: OpCode::Call,
+                    a: 1,
+                    b: 2,
+                    c: 0,
+                    k: false,
+                },
+            ),
+            (
+                Bytecode::less_than_integer(3, -5i8, true),
+                DecodedInstruction::Asbck {
+                    opcode: OpCode::LessThanInteger,
+                    a: 3,
+                    sb: -5,
+                    c: 0,
+                    k: true,
+                },
+            ),
+            (
+                Bytecode::add_integer(0, 1, -127i8),
+                DecodedInstruction::Absck {
+                    opcode: OpCode::AddInteger,
+                    a: 0,
+                    b: 1,
+                    sc: -127,
+                    k: false,
+                },
+            ),
+            (
+                Bytecode::closure(4, 7u8),
+                DecodedInstruction::Abx {
+                    opcode: OpCode::Closure,
+                    a: 4,
+                    bx: 7,
+                },
+            ),
+            (
+                Bytecode::load_integer(2, -30000i16),
+                DecodedInstruction::Asbx {
+                    opcode: OpCode::LoadInteger,
+                    a: 2,
+                    sbx: -30000,
+                },
+            ),
+            (
+                Bytecode::extra_arguments(Ax::try_from(300_000u32).unwrap()),
+                DecodedInstruction::Ax {
+                    opcode: OpCode::ExtraArguments,
+                    ax: 300_000,
+                },
+            ),
+            (
+                Bytecode::jump(-3i8),
+                DecodedInstruction::Sj {
+                    opcode: OpCode::Jump,
+                    sj: -3,
+                },
+            ),
+        ] {
+            assert_eq!(Instruction::decode(*bytecode), decoded, "{bytecode:?}");
+            assert_eq!(Instruction(*bytecode).opcode(), bytecode.opcode());
+        }
+
+        // Every opcode id decodes, the ones reserved for the host keep
+        // the whole instruction
+        for id in 0..=LAST_CUSTOM_OPCODE {
+            let instruction = u32::from(id) | 0xffff_ff80;
+            match Instruction::decode(instruction) {
+                DecodedInstruction::Custom {
+                    opcode,
+                    instruction: encoded,
+                } => {
+                    assert!(id >= FIRST_CUSTOM_OPCODE);
+                    assert_eq!((opcode, encoded), (id, instruction));
+                }
+                decoded => assert_eq!(decoded.opcode(), Some(OpCode::from_id(id))),
+            }
+        }
+    }
+
+    #[test]
+    fn operand_conversion() {
+        let bytecode = Bytecode::jump(1i8);
+        assert_eq!(bytecode.convert::<isize, _>("jump", 1i64).unwrap(), 1);
+
+        let error = bytecode.convert::<u8, _>("jump", 256usize).unwrap_err();
+        assert!(matches!(error, Error::OperandConversion("JMP", "jump")));
+        assert_eq!(
+            alloc::format!("{error}"),
+            "Operand 'jump' of JMP does not fit into the target's integer types."
+        );
+    }
+}
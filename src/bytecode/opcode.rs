@@ -1,5 +1,94 @@
 use super::arguments::BytecodeArgument;
 
+/// Names of the opcodes as used by the reference implementation
+const NAMES: [&str; 83] = [
+    "MOVE",
+    "LOADI",
+    "LOADF",
+    "LOADK",
+    "LOADKX",
+    "LOADFALSE",
+    "LFALSESKIP",
+    "LOADTRUE",
+    "LOADNIL",
+    "GETUPVAL",
+    "SETUPVAL",
+    "GETTABUP",
+    "GETTABLE",
+    "GETI",
+    "GETFIELD",
+    "SETTABUP",
+    "SETTABLE",
+    "SETI",
+    "SETFIELD",
+    "NEWTABLE",
+    "SELF",
+    "ADDI",
+    "ADDK",
+    "SUBK",
+    "MULK",
+    "MODK",
+    "POWK",
+    "DIVK",
+    "IDIVK",
+    "BANDK",
+    "BORK",
+    "BXORK",
+    "SHRI",
+    "SHLI",
+    "ADD",
+    "SUB",
+    "MUL",
+    "MOD",
+    "POW",
+    "DIV",
+    "IDIV",
+    "BAND",
+    "BOR",
+    "BXOR",
+    "SHL",
+    "SHR",
+    "MMBIN",
+    "MMBINI",
+    "MMBINK",
+    "UNM",
+    "BNOT",
+    "NOT",
+    "LEN",
+    "CONCAT",
+    "CLOSE",
+    "TBC",
+    "JMP",
+    "EQ",
+    "LT",
+    "LE",
+    "EQK",
+    "EQI",
+    "LTI",
+    "LEI",
+    "GTI",
+    "GEI",
+    "TEST",
+    "TESTSET",
+    "CALL",
+    "TAILCALL",
+    "RETURN",
+    "RETURN0",
+    "RETURN1",
+    "FORLOOP",
+    "FORPREP",
+    "TFORPREP",
+    "TFORCALL",
+    "TFORLOOP",
+    "SETLIST",
+    "CLOSURE",
+    "VARARG",
+    "VARARGPREP",
+    "EXTRAARG",
+];
+
+/// Opcodes implemented by the VM, in the order of their ids, which are
+/// the same as on the reference implementation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum OpCode {
@@ -89,6 +178,13 @@ pub enum OpCode {
 }
 
 impl OpCode {
+    /// Opcode with the id `id`
+    ///
+    /// # Panics
+    ///
+    /// If `id` is not the id of an opcode implemented by the VM, see
+    /// [`Instruction::decode`](super::Instruction::decode) for a decoding
+    /// that doesn't panic.
     pub const fn from_id(id: u8) -> Self {
         match id {
             0 => Self::Move,
@@ -178,6 +274,11 @@ impl OpCode {
         }
     }
 
+    /// Name of the opcode as used by the reference implementation
+    pub const fn name(&self) -> &'static str {
+        NAMES[*self as usize]
+    }
+
     pub fn is_relational(&self) -> bool {
         // TODO add missing opcodes
         matches!(
@@ -2,32 +2,156 @@ use core::{cell::RefCell, fmt::Display};
 
 use alloc::{rc::Rc, vec::Vec};
 
-use crate::{Error, Lua, Program, function::Function, value::Value};
+use crate::{
+    Error, FromLua, IntoLua, Lua, Program,
+    function::Function,
+    value::{Value, next_reference_id},
+};
 
+/// Function of the host, which finds its arguments on the registers of
+/// its stack frame and returns how many results it leaves there, see
+/// [`Lua::set_returns`]
 pub type NativeClosure = fn(&mut Lua) -> NativeClosureReturn;
 pub type NativeClosureReturn = Result<usize, Error>;
 
+/// Arguments and results of a call to a native function that takes a
+/// context, registered with [`native_function!`](crate::native_function)
+///
+/// Arguments are counted from 1, as they are on errors, and results are
+/// returned in the order they are pushed.
+///
+/// ```
+/// use no_deps_lua::{Error, Lua, NativeCtx, Program, environment::Environment, native_function};
+///
+/// /// Sum of all arguments
+/// fn sum(ctx: &mut NativeCtx) -> Result<(), Error> {
+///     let mut sum = 0;
+///     for position in 1..=ctx.arg_count() {
+///         sum += ctx.check::<i64>(position)?;
+///     }
+///     ctx.push_return(sum);
+///     Ok(())
+/// }
+///
+/// let env = Environment::builder()
+///     .function("sum", native_function!(sum))
+///     .build()
+///     .unwrap();
+/// let program = Program::parse("return sum(1, 2, 3)\n").unwrap();
+/// let total: i64 = Lua::new(env).call_program(program).unwrap();
+/// assert_eq!(total, 6);
+/// ```
+pub struct NativeCtx<'a> {
+    vm: &'a mut Lua,
+    returns: Vec<Value>,
+}
+
+impl<'a> NativeCtx<'a> {
+    pub(crate) fn new(vm: &'a mut Lua) -> Self {
+        Self {
+            vm,
+            returns: Vec::new(),
+        }
+    }
+
+    /// Moves the results to the stack for the caller, returning how many
+    pub(crate) fn finish(self) -> NativeClosureReturn {
+        self.vm.set_returns(self.returns)
+    }
+
+    /// Number of arguments passed
+    pub fn arg_count(&self) -> usize {
+        self.args().len()
+    }
+
+    /// Argument at `position`, `nil` past the last argument
+    pub fn arg(&self, position: usize) -> &Value {
+        position
+            .checked_sub(1)
+            .and_then(|index| self.args().get(index))
+            .unwrap_or(&Value::Nil)
+    }
+
+    /// All arguments passed
+    pub fn args(&self) -> &[Value] {
+        crate::std::get_args(self.vm)
+    }
+
+    /// Argument at `position` converted with [`FromLua`], failing
+    /// with [`Error::Expected`] if it can't be
+    pub fn check<T: FromLua>(&self, position: usize) -> Result<T, Error> {
+        T::from_lua(self.arg(position).clone())
+            .ok_or_else(|| self.type_error(position, T::TYPE_NAME))
+    }
+
+    /// Adds a value to the results of the call
+    pub fn push_return(&mut self, value: impl IntoLua) {
+        self.returns.push(value.into_lua());
+    }
+
+    /// Error for an argument that is not of the `expected` type
+    pub fn type_error(&self, position: usize, expected: &'static str) -> Error {
+        let got = if position > self.arg_count() {
+            "no value"
+        } else {
+            self.arg(position).static_type_name()
+        };
+        Error::Expected(position, expected, got)
+    }
+
+    /// Error for an argument with an invalid value, like an index that
+    /// is out of range
+    pub fn arg_error(&self, position: usize, message: &'static str) -> Error {
+        Error::BadArgument(position, message)
+    }
+
+    /// Upvalue of the native closure being called
+    pub fn upvalue(&self, upvalue: usize) -> Result<Value, Error> {
+        self.vm.get_upvalue(upvalue)
+    }
+
+    pub fn set_upvalue(&mut self, upvalue: usize, value: impl IntoLua) -> Result<(), Error> {
+        self.vm.set_upvalue(upvalue, value.into_lua())
+    }
+
+    /// VM running the call, to call functions or reach the globals
+    ///
+    /// Results must be pushed with [`NativeCtx::push_return`], as the
+    /// stack of the VM is only written once the function returns.
+    pub fn vm(&mut self) -> &mut Lua {
+        self.vm
+    }
+}
+
 #[derive(Debug)]
 pub struct Closure {
     closure_type: FunctionType,
     upvalues: Vec<Rc<RefCell<Upvalue>>>,
+    id: usize,
 }
 
 impl Closure {
-    pub const fn new_lua(function: Rc<Function>, upvalues: Vec<Rc<RefCell<Upvalue>>>) -> Self {
+    pub fn new_lua(function: Rc<Function>, upvalues: Vec<Rc<RefCell<Upvalue>>>) -> Self {
         Self {
             closure_type: FunctionType::Lua(function),
             upvalues,
+            id: next_reference_id(),
         }
     }
 
-    pub const fn new_native(function: NativeClosure, upvalues: Vec<Rc<RefCell<Upvalue>>>) -> Self {
+    pub fn new_native(function: NativeClosure, upvalues: Vec<Rc<RefCell<Upvalue>>>) -> Self {
         Self {
             closure_type: FunctionType::Native(function),
             upvalues,
+            id: next_reference_id(),
         }
     }
 
+    /// Order of creation of the closure, used to order closures used as keys
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
     pub fn closure_type(&self) -> &FunctionType {
         &self.closure_type
     }
@@ -41,10 +165,14 @@ impl Closure {
         }
     }
 
+    pub(crate) fn upvalues(&self) -> &[Rc<RefCell<Upvalue>>] {
+        &self.upvalues
+    }
+
     pub fn upvalue(&self, upvalue: usize) -> Result<Rc<RefCell<Upvalue>>, Error> {
         self.upvalues
             .get(upvalue)
-            .ok_or(Error::UpvalueDoesNotExist)
+            .ok_or(Error::UpvalueDoesNotExist(upvalue, self.upvalues.len()))
             .cloned()
     }
 
@@ -96,13 +224,16 @@ pub enum Upvalue {
 }
 
 impl Upvalue {
-    pub fn close(&mut self, lua: &Lua) {
+    /// Moves the value of the register out of the stack, fails if the
+    /// register is past the stack, or if the upvalue was already closed
+    pub fn close(&mut self, lua: &Lua) -> Result<(), Error> {
         match self {
             Upvalue::Open(stack) => {
-                let value = lua.stack[*stack].clone();
+                let value = lua.stack.get(*stack).ok_or(Error::CorruptStack)?.clone();
                 *self = Upvalue::Closed(value);
+                Ok(())
             }
-            Upvalue::Closed(_) => unreachable!("Called `close` on a already closed Upvalue."),
+            Upvalue::Closed(_) => Err(Error::CorruptStack),
         }
     }
 }
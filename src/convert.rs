@@ -0,0 +1,405 @@
+//! Conversions between Rust types and Lua values
+//!
+//! [`FromLua`] and [`IntoLua`] convert single values, [`FromLuaMulti`] and
+//! [`IntoLuaMulti`] convert the arguments and the results of calls, with
+//! tuples for multiple values. Rust functions whose arguments and results
+//! can be converted are turned into native functions with
+//! [`native_function!`](crate::native_function).
+
+use core::cell::RefCell;
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{Error, Lua, closure::NativeCtx, table::Table, value::Value};
+
+/// Rust type that can be read from a Lua value
+pub trait FromLua: Sized {
+    /// Name of the type expected, used on the error when the conversion fails
+    const TYPE_NAME: &'static str;
+
+    /// Converts `value`, or `None` if it does not hold this type
+    fn from_lua(value: Value) -> Option<Self>;
+}
+
+/// Rust type that can be turned into a Lua value
+pub trait IntoLua {
+    fn into_lua(self) -> Value;
+}
+
+/// Arguments of a native function, or results of a call made by the host
+pub trait FromLuaMulti: Sized {
+    /// Converts `values`, missing values are read as `nil`, and values past
+    /// the ones expected are ignored.
+    ///
+    /// Fails with [`Error::Expected`] with the position of the first value
+    /// that could not be converted.
+    fn from_lua_multi(values: Vec<Value>) -> Result<Self, Error>;
+}
+
+/// Results of a native function, or arguments of a call made by the host
+pub trait IntoLuaMulti {
+    fn into_lua_multi(self) -> Result<Vec<Value>, Error>;
+}
+
+/// Rust function that can be called as a native function, see
+/// [`native_function!`](crate::native_function)
+///
+/// Implemented for functions of up to 8 arguments that implement
+/// [`FromLua`], returning a value that implements [`IntoLuaMulti`],
+/// which includes `Result<T, Error>` to fail the call, and for functions
+/// that take a [`NativeCtx`], for any number of arguments and results.
+pub trait NativeFunction<Args> {
+    /// Calls the function with the arguments on the stack frame of `vm`,
+    /// and leaves the results for the caller
+    fn call_native(&self, vm: &mut Lua) -> Result<usize, Error>;
+}
+
+/// Turns a Rust function, or a closure that captures nothing, into a
+/// native function that can be registered with
+/// [`EnvironmentBuilder::function`](crate::environment::EnvironmentBuilder::function)
+///
+/// The arguments are converted with [`FromLua`], failing the call with
+/// [`Error::Expected`] if they can't be, and the results with [`IntoLuaMulti`].
+/// Functions that take a variable number of arguments can take a
+/// [`NativeCtx`] instead.
+///
+/// ```
+/// use no_deps_lua::{Error, Lua, Program, environment::Environment, native_function};
+///
+/// fn greet(name: String, times: i64) -> Result<String, Error> {
+///     let times = usize::try_from(times).map_err(|_| Error::BadArgument(2, "negative"))?;
+///     Ok(format!("hello {name}").repeat(times))
+/// }
+///
+/// let env = Environment::builder()
+///     .function("greet", native_function!(greet))
+///     .function("add", native_function!(|a: i64, b: i64| a + b))
+///     .build()
+///     .unwrap();
+/// let program = Program::parse("return greet(\"lua\", 2), add(1, 2)\n").unwrap();
+/// let (greeting, sum): (String, i64) = Lua::new(env).call_program(program).unwrap();
+/// assert_eq!((greeting.as_str(), sum), ("hello luahello lua", 3));
+/// ```
+///
+/// Strings are passed as `String`, as the arguments are moved out of the
+/// stack before the function is called.
+#[macro_export]
+macro_rules! native_function {
+    ($function:expr) => {{
+        fn native(vm: &mut $crate::Lua) -> ::core::result::Result<usize, $crate::Error> {
+            $crate::NativeFunction::call_native(&$function, vm)
+        }
+        native as fn(&mut $crate::Lua) -> ::core::result::Result<usize, $crate::Error>
+    }};
+}
+
+impl Lua {
+    /// Calls `function` like [`Lua::call_value`], converting the
+    /// arguments and the results
+    pub fn call<R: FromLuaMulti>(
+        &mut self,
+        function: Value,
+        args: impl IntoLuaMulti,
+    ) -> Result<R, Error> {
+        let args = args.into_lua_multi()?;
+        R::from_lua_multi(self.call_value(function, &args)?)
+    }
+
+    /// Runs a chunk like [`Lua::execute`], converting the values it returns
+    pub fn call_program<R: FromLuaMulti>(&mut self, program: crate::Program) -> Result<R, Error> {
+        R::from_lua_multi(self.execute(program)?)
+    }
+}
+
+impl FromLua for Value {
+    const TYPE_NAME: &'static str = "value";
+
+    fn from_lua(value: Value) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl IntoLua for Value {
+    fn into_lua(self) -> Value {
+        self
+    }
+}
+
+/// Integers are read from numbers and numerals with an exact integer
+/// representation that fits the type, integers that don't fit an `i64`
+/// are turned into floats, or wrap around on builds without floats
+macro_rules! integer_conversions {
+    ($($integer:ty),*) => {
+        $(
+            impl FromLua for $integer {
+                const TYPE_NAME: &'static str = "integer";
+
+                fn from_lua(value: Value) -> Option<Self> {
+                    match value.to_number()?.try_int() {
+                        Value::Integer(integer) => <$integer>::try_from(integer).ok(),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl IntoLua for $integer {
+                fn into_lua(self) -> Value {
+                    match i64::try_from(self) {
+                        Ok(integer) => Value::Integer(integer),
+                        #[cfg(feature = "float")]
+                        Err(_) => Value::Float(self as f64),
+                        #[cfg(not(feature = "float"))]
+                        Err(_) => Value::Integer(self as i64),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_conversions!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Floats are read from numbers and numerals
+#[cfg(feature = "float")]
+macro_rules! float_conversions {
+    ($($float:ty),*) => {
+        $(
+            impl FromLua for $float {
+                const TYPE_NAME: &'static str = "number";
+
+                fn from_lua(value: Value) -> Option<Self> {
+                    match value.to_number()? {
+                        Value::Integer(integer) => Some(integer as $float),
+                        #[cfg(feature = "float")]
+                        Value::Float(float) => Some(float as $float),
+                        _ => None,
+                    }
+                }
+            }
+
+            impl IntoLua for $float {
+                fn into_lua(self) -> Value {
+                    Value::Float(f64::from(self))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "float")]
+float_conversions!(f32, f64);
+
+/// Any value is read as a boolean, only `nil` and `false` are false
+impl FromLua for bool {
+    const TYPE_NAME: &'static str = "boolean";
+
+    fn from_lua(value: Value) -> Option<Self> {
+        Some(!matches!(value, Value::Nil | Value::Boolean(false)))
+    }
+}
+
+impl IntoLua for bool {
+    fn into_lua(self) -> Value {
+        Value::Boolean(self)
+    }
+}
+
+/// Strings are read from strings and numbers, bytes that are not valid
+/// UTF-8 are replaced by `U+FFFD`, [`Value::as_bytes`] reads them as they are
+impl FromLua for String {
+    const TYPE_NAME: &'static str = "string";
+
+    fn from_lua(value: Value) -> Option<Self> {
+        match value {
+            Value::ShortString(_) | Value::String(_) | Value::Integer(_) => Some(value.to_string()),
+            #[cfg(feature = "float")]
+            Value::Float(_) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl IntoLua for String {
+    fn into_lua(self) -> Value {
+        self.into()
+    }
+}
+
+impl IntoLua for &str {
+    fn into_lua(self) -> Value {
+        self.into()
+    }
+}
+
+/// Lua strings can hold any bytes
+impl IntoLua for &[u8] {
+    fn into_lua(self) -> Value {
+        self.into()
+    }
+}
+
+/// `nil` is read as `None`
+impl<T: FromLua> FromLua for Option<T> {
+    const TYPE_NAME: &'static str = T::TYPE_NAME;
+
+    fn from_lua(value: Value) -> Option<Self> {
+        match value {
+            Value::Nil => Some(None),
+            value => T::from_lua(value).map(Some),
+        }
+    }
+}
+
+impl<T: IntoLua> IntoLua for Option<T> {
+    fn into_lua(self) -> Value {
+        self.map_or(Value::Nil, T::into_lua)
+    }
+}
+
+/// Sequences are read from the values of a table up to its border
+impl<T: FromLua> FromLua for Vec<T> {
+    const TYPE_NAME: &'static str = "table";
+
+    fn from_lua(value: Value) -> Option<Self> {
+        let Value::Table(table) = value else {
+            return None;
+        };
+        let table = table.borrow();
+        let length = usize::try_from(table.border()).ok()?;
+        table.array[..length]
+            .iter()
+            .map(|value| T::from_lua(value.clone()))
+            .collect()
+    }
+}
+
+impl<T: IntoLua> IntoLua for Vec<T> {
+    fn into_lua(self) -> Value {
+        let mut table = Table::new(self.len(), 0);
+        table.array.extend(self.into_iter().map(T::into_lua));
+        Value::Table(Rc::new(RefCell::new(table)))
+    }
+}
+
+impl FromLua for Rc<RefCell<Table>> {
+    const TYPE_NAME: &'static str = "table";
+
+    fn from_lua(value: Value) -> Option<Self> {
+        match value {
+            Value::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+}
+
+impl IntoLua for Rc<RefCell<Table>> {
+    fn into_lua(self) -> Value {
+        Value::Table(self)
+    }
+}
+
+/// Converts the value at `position`, counted from 1, `nil` if there is none
+fn convert_next<T: FromLua>(
+    values: &mut impl Iterator<Item = Value>,
+    position: usize,
+) -> Result<T, Error> {
+    match values.next() {
+        Some(value) => {
+            let got = value.static_type_name();
+            T::from_lua(value).ok_or(Error::Expected(position, T::TYPE_NAME, got))
+        }
+        None => T::from_lua(Value::Nil).ok_or(Error::Expected(position, T::TYPE_NAME, "no value")),
+    }
+}
+
+/// A single value is the first value
+impl<T: FromLua> FromLuaMulti for T {
+    fn from_lua_multi(values: Vec<Value>) -> Result<Self, Error> {
+        convert_next(&mut values.into_iter(), 1)
+    }
+}
+
+impl<T: IntoLua> IntoLuaMulti for T {
+    fn into_lua_multi(self) -> Result<Vec<Value>, Error> {
+        Ok(vec![self.into_lua()])
+    }
+}
+
+/// Native functions fail with the error
+impl<T: IntoLuaMulti> IntoLuaMulti for Result<T, Error> {
+    fn into_lua_multi(self) -> Result<Vec<Value>, Error> {
+        self?.into_lua_multi()
+    }
+}
+
+impl<F> NativeFunction<NativeCtx<'static>> for F
+where
+    F: Fn(&mut NativeCtx<'_>) -> Result<(), Error>,
+{
+    fn call_native(&self, vm: &mut Lua) -> Result<usize, Error> {
+        let mut ctx = NativeCtx::new(vm);
+        self(&mut ctx)?;
+        ctx.finish()
+    }
+}
+
+macro_rules! tuple_conversions {
+    ($(($($value:ident),*)),*) => {
+        $(
+            impl<$($value: FromLua),*> FromLuaMulti for ($($value,)*) {
+                #[allow(unused_variables, unused_mut)]
+                fn from_lua_multi(values: Vec<Value>) -> Result<Self, Error> {
+                    let mut values = values.into_iter();
+                    let mut position = 0;
+                    Ok(($(
+                        {
+                            position += 1;
+                            convert_next::<$value>(&mut values, position)?
+                        },
+                    )*))
+                }
+            }
+
+            impl<$($value: IntoLua),*> IntoLuaMulti for ($($value,)*) {
+                #[allow(non_snake_case)]
+                fn into_lua_multi(self) -> Result<Vec<Value>, Error> {
+                    let ($($value,)*) = self;
+                    Ok(vec![$($value.into_lua()),*])
+                }
+            }
+
+            impl<F, R, $($value),*> NativeFunction<($($value,)*)> for F
+            where
+                F: Fn($($value),*) -> R,
+                R: IntoLuaMulti,
+                $($value: FromLua,)*
+            {
+                #[allow(non_snake_case)]
+                fn call_native(&self, vm: &mut Lua) -> Result<usize, Error> {
+                    let ($($value,)*) =
+                        <($($value,)*) as FromLuaMulti>::from_lua_multi(crate::std::get_args(vm).to_vec())?;
+                    let results = self($($value),*).into_lua_multi()?;
+
+                    vm.set_returns(results)
+                }
+            }
+        )*
+    };
+}
+
+tuple_conversions!(
+    (),
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F1),
+    (A, B, C, D, E, F1, G),
+    (A, B, C, D, E, F1, G, H)
+);
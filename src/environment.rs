@@ -1,88 +1,542 @@
 use core::{
     cell::RefCell,
-    cmp::Ordering,
     fmt::Display,
     num::TryFromIntError,
     ops::{Deref, DerefMut},
 };
 
-use alloc::{rc::Rc, vec};
+use alloc::{rc::Rc, vec, vec::Vec};
+
+mod backend;
+#[cfg(feature = "std")]
+mod system;
+
+pub(crate) use self::backend::Backends;
+pub use self::backend::{Clock, FileSystem, ModuleSource, StdIn, StdOut};
+#[cfg(feature = "std")]
+pub use self::system::{SystemClock, SystemFileSystem, SystemStdIn, SystemStdOut};
 
 use crate::{
+    bytecode::{FIRST_CUSTOM_OPCODE, LAST_CUSTOM_OPCODE, OpcodeHandler, OpcodeHandlers},
     closure::{Closure, NativeClosure, Upvalue},
     std,
     table::Table,
     value::{Value, ValueKey},
 };
 
-pub struct Environment(Rc<RefCell<Table>>);
+/// Globals of the standard library that can't read files, load code, or
+/// reach the globals of the VM, see [`Lua::sandbox`](crate::Lua::sandbox)
+pub const SAFE_GLOBALS: &[&str] = &[
+    "_G",
+    "_VERSION",
+    "assert",
+    "getmetatable",
+    "ipairs",
+    "math",
+    "next",
+    "pairs",
+    "print",
+    "rawlen",
+    "select",
+    "setmetatable",
+    "string",
+    "table",
+    "tonumber",
+    "tostring",
+    "type",
+];
+
+/// Source of entropy used to seed `math.random` when no seed is given,
+/// see [`Environment::set_entropy_source`]
+pub type EntropySource = fn() -> u64;
+
+/// Seed used by `math.random` when the host did not provide an [`EntropySource`]
+pub const DEFAULT_RANDOM_SEED: i64 = 0;
+
+/// Globals and host services used to create a [`Lua`](crate::Lua)
+///
+/// With the basic library, the globals hold themselves as `_G`, this cycle
+/// is broken when the environment is dropped without being used, and by
+/// the collector when the VM created from it is dropped.
+pub struct Environment {
+    globals: Rc<RefCell<Table>>,
+    entropy_source: Option<EntropySource>,
+    backends: Backends,
+    opcode_handlers: OpcodeHandlers,
+    /// Metatable shared by all strings, with the `string` library as its `__index`
+    string_metatable: Option<Rc<RefCell<Table>>>,
+}
 
 impl Environment {
+    /// Starts building an environment, see [`EnvironmentBuilder`]
+    pub fn builder() -> EnvironmentBuilder {
+        EnvironmentBuilder::new()
+    }
+
+    /// Sets the source of entropy used to seed `math.random` at startup
+    /// and on calls to `math.randomseed` without arguments.
+    ///
+    /// `no_std` has no access to the OS's random number generator, so without
+    /// an entropy source the generator is seeded with
+    /// [`DEFAULT_RANDOM_SEED`], producing
+    /// the same sequence on every run.
+    pub fn set_entropy_source(&mut self, entropy_source: EntropySource) {
+        self.entropy_source = Some(entropy_source);
+    }
+
+    #[cfg(feature = "math")]
+    pub(crate) fn entropy_source(&self) -> Option<EntropySource> {
+        self.entropy_source
+    }
+
+    /// Sets the clock used by `os.time`, `os.clock`, and by `os.date`
+    /// when it is called without a time.
+    ///
+    /// `no_std` has no access to the OS's clock, so without a clock
+    /// the current time is the Unix epoch.
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        self.backends.clock = Some(Rc::new(clock));
+    }
+
+    /// Sets where `print`, `warn`, and `io.write` write to, which can
+    /// be changed for each VM with [`Lua::set_stdout`](crate::Lua::set_stdout).
+    ///
+    /// Without it, `print` and `warn` log their output on the
+    /// `no_deps_lua::vm` target and `io.write` fails.
+    pub fn set_stdout(&mut self, stdout: impl StdOut + 'static) {
+        self.backends.stdout = Some(Rc::new(stdout));
+    }
+
+    /// Sets where `io.read` and `io.lines` read from, without it the
+    /// input is always at its end
+    pub fn set_stdin(&mut self, stdin: impl StdIn + 'static) {
+        self.backends.stdin = Some(Rc::new(stdin));
+    }
+
+    /// Sets the files `io.lines` reads, without it opening any file fails
+    pub fn set_file_system(&mut self, file_system: impl FileSystem + 'static) {
+        self.backends.file_system = Some(Rc::new(file_system));
+    }
+
+    /// Sets where `require` finds the source of modules that are not
+    /// on `package.preload`
+    pub fn set_module_source(&mut self, module_source: impl ModuleSource + 'static) {
+        self.backends.module_source = Some(Rc::new(module_source));
+    }
+
+    pub(crate) fn backends(&self) -> Backends {
+        self.backends.clone()
+    }
+
+    /// Registers the handler executed by instructions with `opcode`, which
+    /// must be between [`FIRST_CUSTOM_OPCODE`] and [`LAST_CUSTOM_OPCODE`]
+    pub fn set_opcode_handler(
+        &mut self,
+        opcode: u8,
+        handler: impl OpcodeHandler + 'static,
+    ) -> Result<(), EnvironmentError> {
+        self.insert_opcode_handler(opcode, Rc::new(handler))
+    }
+
+    fn insert_opcode_handler(
+        &mut self,
+        opcode: u8,
+        handler: Rc<dyn OpcodeHandler>,
+    ) -> Result<(), EnvironmentError> {
+        if self.opcode_handlers.insert(opcode, handler) {
+            Ok(())
+        } else {
+            Err(EnvironmentError::NotCustomOpcode(opcode))
+        }
+    }
+
+    pub(crate) fn opcode_handlers(&self) -> OpcodeHandlers {
+        self.opcode_handlers.clone()
+    }
+
+    pub(crate) fn string_metatable(&self) -> Option<Rc<RefCell<Table>>> {
+        self.string_metatable.clone()
+    }
+
     pub fn push(
         &mut self,
         value_key: impl Into<Value>,
         value: impl Into<Value>,
     ) -> Result<(), EnvironmentError> {
-        let mut table = self.borrow_mut();
-        match value_key.into() {
-            Value::Integer(array_item @ 1..) => {
-                let index = usize::try_from(array_item)?;
-                match index.cmp(&table.array.len()) {
-                    Ordering::Greater => {
-                        table.array.resize(index, Value::Nil);
-                        table.array.push(value.into());
-                    }
-                    Ordering::Equal => {
-                        table.array.push(value.into());
-                    }
-                    Ordering::Less => {
-                        table.array[index] = value.into();
-                    }
-                }
-            }
-            table_item => {
-                let key = ValueKey(table_item);
-                match table.table.binary_search_by_key(&&key, |a| &a.0) {
-                    Ok(index) => table.table[index].1 = value.into(),
-                    Err(index) => table.table.insert(index, (key, value.into())),
-                }
+        self.borrow_mut()
+            .raw_set(ValueKey(value_key.into()), value.into());
+        Ok(())
+    }
+}
+
+impl Drop for Environment {
+    fn drop(&mut self) {
+        // Only the environment and `_G` hold the globals
+        if Rc::strong_count(&self.globals) == 2 {
+            let mut globals = self.globals.borrow_mut();
+            let key = ValueKey("_G".into());
+            if let Ok(index) = globals.table.binary_search_by_key(&&key, |a| &a.0)
+                && matches!(&globals.table[index].1, Value::Table(table) if Rc::ptr_eq(table, &self.globals))
+            {
+                globals.table.remove(index);
             }
         }
-        Ok(())
     }
 }
 
 impl Default for Environment {
     fn default() -> Self {
-        let mut table = Table::new(0, 4);
+        EnvironmentBuilder::default()
+            .build()
+            .expect("Standard libraries should always fit in the environment.")
+    }
+}
 
-        table.table.extend([
-            (
-                ValueKey("assert".into()),
-                Value::from(std::lib_assert as NativeClosure),
-            ),
-            (
-                ValueKey("print".into()),
-                Value::from(std::lib_print as NativeClosure),
-            ),
-            (
-                ValueKey("type".into()),
-                Value::from(std::lib_type as NativeClosure),
-            ),
-            (
-                ValueKey("warn".into()),
-                Value::Closure(Rc::new(Closure::new_native(
-                    std::lib_warn,
-                    vec![Rc::new(RefCell::new(Upvalue::Closed(Value::Boolean(
-                        false,
-                    ))))],
-                ))),
-            ),
-        ]);
+/// Groups of the standard library that can be opted in or out of
+/// with [`EnvironmentBuilder::library`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Library {
+    /// `_G`, `_VERSION`, `assert`, `collectgarbage`, `getmetatable`,
+    /// `ipairs`, `load`, `next`, `pairs`, `print`, `rawlen`, `select`,
+    /// `setmetatable`, `tonumber`, `tostring`, `type`, and `warn`
+    Basic,
+    /// The `math` table
+    #[cfg(feature = "math")]
+    Math,
+    /// The `io` table
+    #[cfg(feature = "io")]
+    Io,
+    /// The `os` table
+    #[cfg(feature = "os")]
+    Os,
+    /// The `package` table and `require`
+    #[cfg(feature = "package")]
+    Package,
+    /// The `string` table, and the metatable of strings that lets them
+    /// call its functions as methods
+    #[cfg(feature = "string")]
+    String,
+    /// The `table` table
+    #[cfg(feature = "table")]
+    Table,
+}
+
+/// Builds an [`Environment`] with the selected standard libraries and
+/// globals registered by the host
+///
+/// ```
+/// use no_deps_lua::{
+///     Lua, Program,
+///     environment::{Environment, Library},
+/// };
+///
+/// let env = Environment::builder()
+///     .library(Library::Basic, false)
+///     .global("answer", 42i64)
+///     .build()
+///     .unwrap();
+/// let program = Program::parse("local a = answer\n").unwrap();
+/// Lua::run_program_with_env(program, env).unwrap();
+/// ```
+pub struct EnvironmentBuilder {
+    basic: bool,
+    #[cfg(feature = "io")]
+    io: bool,
+    #[cfg(feature = "math")]
+    math: bool,
+    #[cfg(feature = "os")]
+    os: bool,
+    #[cfg(feature = "package")]
+    package: bool,
+    #[cfg(feature = "string")]
+    string: bool,
+    #[cfg(feature = "table")]
+    table: bool,
+    globals: Vec<(Value, Value)>,
+    entropy_source: Option<EntropySource>,
+    backends: Backends,
+    opcode_handlers: Vec<(u8, Rc<dyn OpcodeHandler>)>,
+}
+
+impl EnvironmentBuilder {
+    /// Builder with all standard libraries enabled
+    pub fn new() -> Self {
+        Self {
+            basic: true,
+            #[cfg(feature = "io")]
+            io: true,
+            #[cfg(feature = "math")]
+            math: true,
+            #[cfg(feature = "os")]
+            os: true,
+            #[cfg(feature = "package")]
+            package: true,
+            #[cfg(feature = "string")]
+            string: true,
+            #[cfg(feature = "table")]
+            table: true,
+            globals: Vec::new(),
+            entropy_source: None,
+            backends: Backends::default(),
+            opcode_handlers: Vec::new(),
+        }
+    }
+
+    /// Builder with no standard libraries enabled
+    pub fn bare() -> Self {
+        Self {
+            basic: false,
+            #[cfg(feature = "io")]
+            io: false,
+            #[cfg(feature = "math")]
+            math: false,
+            #[cfg(feature = "os")]
+            os: false,
+            #[cfg(feature = "package")]
+            package: false,
+            #[cfg(feature = "string")]
+            string: false,
+            #[cfg(feature = "table")]
+            table: false,
+            ..Self::new()
+        }
+    }
+
+    /// Opts in or out of a group of the standard library
+    pub fn library(mut self, library: Library, enabled: bool) -> Self {
+        match library {
+            Library::Basic => self.basic = enabled,
+            #[cfg(feature = "io")]
+            Library::Io => self.io = enabled,
+            #[cfg(feature = "math")]
+            Library::Math => self.math = enabled,
+            #[cfg(feature = "os")]
+            Library::Os => self.os = enabled,
+            #[cfg(feature = "package")]
+            Library::Package => self.package = enabled,
+            #[cfg(feature = "string")]
+            Library::String => self.string = enabled,
+            #[cfg(feature = "table")]
+            Library::Table => self.table = enabled,
+        }
+        self
+    }
+
+    /// Sets a global, replacing standard library values with the same name
+    pub fn global(mut self, key: impl Into<Value>, value: impl Into<Value>) -> Self {
+        self.globals.push((key.into(), value.into()));
+        self
+    }
+
+    /// Registers a native function as a global
+    pub fn function(self, name: &str, function: NativeClosure) -> Self {
+        self.global(name, function)
+    }
+
+    /// See [`Environment::set_entropy_source`]
+    pub fn entropy_source(mut self, entropy_source: EntropySource) -> Self {
+        self.entropy_source = Some(entropy_source);
+        self
+    }
+
+    /// See [`Environment::set_clock`]
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.backends.clock = Some(Rc::new(clock));
+        self
+    }
+
+    /// See [`Environment::set_stdout`]
+    pub fn stdout(mut self, stdout: impl StdOut + 'static) -> Self {
+        self.backends.stdout = Some(Rc::new(stdout));
+        self
+    }
+
+    /// See [`Environment::set_stdin`]
+    pub fn stdin(mut self, stdin: impl StdIn + 'static) -> Self {
+        self.backends.stdin = Some(Rc::new(stdin));
+        self
+    }
+
+    /// See [`Environment::set_file_system`]
+    pub fn file_system(mut self, file_system: impl FileSystem + 'static) -> Self {
+        self.backends.file_system = Some(Rc::new(file_system));
+        self
+    }
+
+    /// See [`Environment::set_module_source`]
+    pub fn module_source(mut self, module_source: impl ModuleSource + 'static) -> Self {
+        self.backends.module_source = Some(Rc::new(module_source));
+        self
+    }
+
+    /// Uses the clock, console, and files of the system, see
+    /// [`SystemClock`], [`SystemStdOut`], [`SystemStdIn`], and
+    /// [`SystemFileSystem`]
+    #[cfg(feature = "std")]
+    pub fn std_backends(self) -> Self {
+        self.clock(SystemClock::new())
+            .stdout(SystemStdOut)
+            .stdin(SystemStdIn)
+            .file_system(SystemFileSystem)
+    }
+
+    /// See [`Environment::set_opcode_handler`], [`EnvironmentBuilder::build`]
+    /// fails if `opcode` is not reserved for the host
+    pub fn opcode(mut self, opcode: u8, handler: impl OpcodeHandler + 'static) -> Self {
+        self.opcode_handlers.push((opcode, Rc::new(handler)));
+        self
+    }
+
+    pub fn build(self) -> Result<Environment, EnvironmentError> {
+        let mut table = Table::new(0, 17 + self.globals.len());
+
+        if self.basic {
+            table.table.extend([
+                (ValueKey("_VERSION".into()), Value::from("Lua 5.4")),
+                (
+                    ValueKey("assert".into()),
+                    Value::from(std::lib_assert as NativeClosure),
+                ),
+                (
+                    ValueKey("collectgarbage".into()),
+                    Value::from(std::lib_collectgarbage as NativeClosure),
+                ),
+                (
+                    ValueKey("getmetatable".into()),
+                    Value::from(std::lib_getmetatable as NativeClosure),
+                ),
+                (
+                    ValueKey("ipairs".into()),
+                    Value::from(std::lib_ipairs as NativeClosure),
+                ),
+                (
+                    ValueKey("load".into()),
+                    Value::from(std::lib_load as NativeClosure),
+                ),
+                (
+                    ValueKey("next".into()),
+                    Value::from(std::lib_next as NativeClosure),
+                ),
+                (
+                    ValueKey("pairs".into()),
+                    Value::from(std::lib_pairs as NativeClosure),
+                ),
+                (
+                    ValueKey("print".into()),
+                    Value::from(std::lib_print as NativeClosure),
+                ),
+                (
+                    ValueKey("rawlen".into()),
+                    Value::from(std::lib_rawlen as NativeClosure),
+                ),
+                (
+                    ValueKey("select".into()),
+                    Value::from(std::lib_select as NativeClosure),
+                ),
+                (
+                    ValueKey("setmetatable".into()),
+                    Value::from(std::lib_setmetatable as NativeClosure),
+                ),
+                (
+                    ValueKey("tonumber".into()),
+                    Value::from(std::lib_tonumber as NativeClosure),
+                ),
+                (
+                    ValueKey("tostring".into()),
+                    Value::from(std::lib_tostring as NativeClosure),
+                ),
+                (
+                    ValueKey("type".into()),
+                    Value::from(std::lib_type as NativeClosure),
+                ),
+                (
+                    ValueKey("warn".into()),
+                    Value::Closure(Rc::new(Closure::new_native(
+                        std::lib_warn,
+                        vec![Rc::new(RefCell::new(Upvalue::Closed(Value::Boolean(
+                            false,
+                        ))))],
+                    ))),
+                ),
+            ]);
+        }
+        #[cfg(feature = "io")]
+        if self.io {
+            table.table.push((
+                ValueKey("io".into()),
+                Value::Table(Rc::new(RefCell::new(std::io_library()))),
+            ));
+        }
+        #[cfg(feature = "math")]
+        if self.math {
+            table.table.push((
+                ValueKey("math".into()),
+                Value::Table(Rc::new(RefCell::new(std::math_library()))),
+            ));
+        }
+        #[cfg(feature = "os")]
+        if self.os {
+            table.table.push((
+                ValueKey("os".into()),
+                Value::Table(Rc::new(RefCell::new(std::os_library()))),
+            ));
+        }
+        #[cfg(feature = "package")]
+        if self.package {
+            let (package, require) = std::package_library();
+            table.table.extend([
+                (ValueKey("package".into()), Value::Table(package)),
+                (ValueKey("require".into()), require),
+            ]);
+        }
+        #[allow(unused_mut)]
+        let mut string_metatable = None;
+        #[cfg(feature = "string")]
+        if self.string {
+            let string = Rc::new(RefCell::new(std::string_library()));
+            let mut metatable = Table::new(0, 1);
+            metatable
+                .table
+                .push((ValueKey("__index".into()), Value::Table(string.clone())));
+            string_metatable = Some(Rc::new(RefCell::new(metatable)));
+            table
+                .table
+                .push((ValueKey("string".into()), Value::Table(string)));
+        }
+        #[cfg(feature = "table")]
+        if self.table {
+            table.table.push((
+                ValueKey("table".into()),
+                Value::Table(Rc::new(RefCell::new(std::table_library()))),
+            ));
+        }
 
         table.table.sort_by_key(|val| val.0.clone());
 
-        Self(Rc::new(RefCell::new(table)))
+        let mut env = Environment {
+            globals: Rc::new(RefCell::new(table)),
+            entropy_source: self.entropy_source,
+            backends: self.backends,
+            opcode_handlers: OpcodeHandlers::default(),
+            string_metatable,
+        };
+        if self.basic {
+            let globals = Value::Table(env.globals.clone());
+            env.push("_G", globals)?;
+        }
+        for (opcode, handler) in self.opcode_handlers {
+            env.insert_opcode_handler(opcode, handler)?;
+        }
+        for (key, value) in self.globals {
+            env.push(key, value)?;
+        }
+        Ok(env)
+    }
+}
+
+impl Default for EnvironmentBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -90,19 +544,20 @@ impl Deref for Environment {
     type Target = Rc<RefCell<Table>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.globals
     }
 }
 
 impl DerefMut for Environment {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.globals
     }
 }
 
 #[derive(Debug)]
 pub enum EnvironmentError {
     ArrayOutOfBounds,
+    NotCustomOpcode(u8),
 }
 
 impl Display for EnvironmentError {
@@ -114,6 +569,11 @@ impl Display for EnvironmentError {
                     "Tried to push a value out of bounds of system's `usize`."
                 )
             }
+            Self::NotCustomOpcode(opcode) => write!(
+                f,
+                "Opcode {} is not between {} and {}.",
+                opcode, FIRST_CUSTOM_OPCODE, LAST_CUSTOM_OPCODE
+            ),
         }
     }
 }
@@ -0,0 +1,151 @@
+//! Services of the host used by the standard library
+//!
+//! `no_std` has no clock, console, or files, so the host provides them,
+//! along with the source of the modules loaded by `require`,
+//! by implementing these traits, functions and closures with the right
+//! signature implement them already.
+//! Without a backend, the libraries fall back to a behavior that needs
+//! none, like the Unix epoch for the current time or logging for `print`.
+
+use core::{
+    cell::RefCell,
+    fmt::{Debug, Write},
+};
+
+use alloc::{rc::Rc, string::String};
+
+/// Source of the time, used by `os.time`, `os.clock`, and `os.date`
+///
+/// Only [`Clock::time`] is required, hosts without a calendar, like
+/// bare-metal targets, can return `0` from it and give scripts a timer
+/// through [`Clock::monotonic_ns`].
+pub trait Clock {
+    /// Current time, in seconds since the Unix epoch
+    fn time(&self) -> i64;
+
+    /// Nanoseconds since an arbitrary point, like the boot of the
+    /// device, that never go back even if [`Clock::time`] is adjusted.
+    ///
+    /// Hosts without a timer are left with the default of `0`.
+    fn monotonic_ns(&self) -> u64 {
+        0
+    }
+
+    /// Processor time used by the program, in seconds, used by `os.clock`.
+    ///
+    /// Defaults to the time of [`Clock::monotonic_ns`], as most `no_std`
+    /// targets run a single program.
+    fn processor_time(&self) -> f64 {
+        self.monotonic_ns() as f64 / 1e9
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> i64,
+{
+    fn time(&self) -> i64 {
+        self()
+    }
+}
+
+/// Destination of `print`, `warn`, and `io.write`
+///
+/// Strings written by scripts can hold any bytes, bytes that are not valid
+/// UTF-8 reach the backend as `U+FFFD`.
+pub trait StdOut {
+    /// Writes `string` as it is, failures are reported to the script
+    /// as the message returned
+    fn write(&self, string: &str) -> Result<(), String>;
+}
+
+impl<F> StdOut for F
+where
+    F: Fn(&str) -> Result<(), String>,
+{
+    fn write(&self, string: &str) -> Result<(), String> {
+        self(string)
+    }
+}
+
+/// Output written to a [`Write`], like a `String` or a serial port, that the
+/// host keeps a handle to
+impl<W> StdOut for Rc<RefCell<W>>
+where
+    W: Write,
+{
+    fn write(&self, string: &str) -> Result<(), String> {
+        self.borrow_mut()
+            .write_str(string)
+            .map_err(|_| String::from("failed to write output"))
+    }
+}
+
+/// Source of `io.read` and `io.lines` without a file name
+pub trait StdIn {
+    /// Next line of the input, with its `\n` if it has one, or `None`
+    /// at the end of the input
+    fn read_line(&self) -> Option<String>;
+}
+
+impl<F> StdIn for F
+where
+    F: Fn() -> Option<String>,
+{
+    fn read_line(&self) -> Option<String> {
+        self()
+    }
+}
+
+/// Files read by `io.lines`
+pub trait FileSystem {
+    /// Contents of the file at `path`, failures are reported to the
+    /// script as the message returned
+    fn read_to_string(&self, path: &str) -> Result<String, String>;
+}
+
+impl<F> FileSystem for F
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    fn read_to_string(&self, path: &str) -> Result<String, String> {
+        self(path)
+    }
+}
+
+/// Source code of the modules loaded by `require`
+pub trait ModuleSource {
+    /// Source of the module `name`, or `None` if there is no such module
+    fn find(&self, name: &str) -> Option<String>;
+}
+
+impl<F> ModuleSource for F
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn find(&self, name: &str) -> Option<String> {
+        self(name)
+    }
+}
+
+/// Backends registered by the host
+#[derive(Clone, Default)]
+pub(crate) struct Backends {
+    pub(crate) clock: Option<Rc<dyn Clock>>,
+    pub(crate) stdout: Option<Rc<dyn StdOut>>,
+    pub(crate) stdin: Option<Rc<dyn StdIn>>,
+    pub(crate) file_system: Option<Rc<dyn FileSystem>>,
+    pub(crate) module_source: Option<Rc<dyn ModuleSource>>,
+}
+
+impl Debug for Backends {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Backends")
+            .field("clock", &self.clock.is_some())
+            .field("stdout", &self.stdout.is_some())
+            .field("stdin", &self.stdin.is_some())
+            .field("file_system", &self.file_system.is_some())
+            .field("module_source", &self.module_source.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,90 @@
+//! Backends implemented with the standard library, for hosts that have it
+
+extern crate std;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use std::{
+    io::Write,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use super::{Clock, FileSystem, StdIn, StdOut};
+
+/// The system's clock
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn time(&self) -> i64 {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX),
+            Err(before_epoch) => {
+                i64::try_from(before_epoch.duration().as_secs()).map_or(i64::MIN, |secs| -secs)
+            }
+        }
+    }
+
+    /// Time since the clock was created, the standard library can't
+    /// measure processor time, so it is also used by `os.clock`
+    fn monotonic_ns(&self) -> u64 {
+        u64::try_from(self.start.elapsed().as_nanos()).unwrap_or(u64::MAX)
+    }
+}
+
+/// The process' standard output
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemStdOut;
+
+impl StdOut for SystemStdOut {
+    fn write(&self, string: &str) -> Result<(), String> {
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(string.as_bytes())
+            .and_then(|()| stdout.flush())
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// The process' standard input
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemStdIn;
+
+impl StdIn for SystemStdIn {
+    fn read_line(&self) -> Option<String> {
+        let mut line = String::new();
+        match std::io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line),
+        }
+    }
+}
+
+/// The system's files
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemFileSystem;
+
+impl FileSystem for SystemFileSystem {
+    fn read_to_string(&self, path: &str) -> Result<String, String> {
+        std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))
+    }
+}
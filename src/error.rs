@@ -1,88 +1,325 @@
 use core::{fmt::Display, num::TryFromIntError};
 
+use alloc::{boxed::Box, rc::Rc, string::String};
+
 use crate::value::Value;
 
+/// Error raised while loading or running a chunk, or by a call made by
+/// the host
+///
+/// Variants carry the type names, positions, or values involved, and are
+/// written as messages for users by `Display`. [`Error::category`] tells
+/// errors on the source apart from the ones raised while running it and
+/// from misuses of the API.
 #[derive(Debug)]
 pub enum Error {
     InvalidGlobalKey(Value),
+    /// Value called is not a function and has no `__call`
     InvalidFunction(Value),
+    /// Argument of a native function, counted from 1, has the wrong type,
+    /// with the type expected and the type it had
     Expected(usize, &'static str, &'static str),
     ExpectedBoolean(&'static str),
     ExpectedName,
-    ExpectedTable,
-    // Unary operators
-    InvalidLenOperand,
-    InvalidNegOperand,
-    InvalidBitNotOperand,
+    /// Value indexed, or assigned to, is not a table and has no `__index`,
+    /// with its type
+    ExpectedTable(&'static str),
+    /// Key assigned on a table is `nil` or NaN, with which of them
+    InvalidKey(&'static str),
+    /// Script tried to change a table frozen with
+    /// [`Table::freeze`](crate::Table::freeze)
+    FrozenTable,
+    // Unary operators, with the type of the operand
+    InvalidLenOperand(&'static str),
+    InvalidNegOperand(&'static str),
+    InvalidBitNotOperand(&'static str),
     // Binary arithmetic operators
     ArithmeticOperand(&'static str, &'static str, &'static str),
+    /// Integer division or modulo by zero, with the operator
+    IntegerDivisionByZero(&'static str),
     // Binary bitwise operators
     BitwiseOperand(&'static str, &'static str, &'static str),
+    /// Float, or numeral, used on a bitwise operation has no exact
+    /// integer representation
+    NoIntegerRepresentation,
+    /// Operation, or opcode, would make a float on a build without the
+    /// `float` feature
+    FloatsDisabled(&'static str),
     // Binary relational operators
     RelationalOperand(&'static str, &'static str),
     // Concat
     ConcatOperand(&'static str),
     // Other
-    TryFloatConversion,
+    /// Initial value, limit, or step of a numeric `for` is not a number
+    ForNotNumber(&'static str),
     IntegerConversion,
+    /// Operand of an opcode didn't fit the integer type used by the Vm
+    OperandConversion(&'static str, &'static str),
     ForZeroStep,
     StackOverflow,
+    /// Script went over the limit set with
+    /// [`Lua::set_memory_limit`](crate::Lua::set_memory_limit)
+    MemoryLimit,
     InvalidJump,
-    UpvalueDoesNotExist,
+    /// Bytecode referred to registers or values its function doesn't
+    /// have, with the name of its opcode
+    InvalidBytecode(&'static str),
+    /// The stack of the Vm didn't hold what the running function expected,
+    /// only reachable by running corrupt bytecode
+    CorruptStack,
+    /// Upvalue used by a bytecode, and how many upvalues its closure has
+    UpvalueDoesNotExist(usize, usize),
+    /// Constant used by a bytecode, and how many constants its function has
     ConstantDoesNotExist(usize, usize),
-    Assertion,
+    Assertion(Value),
+    MissingReturn,
+    // Standard library
+    BadArgument(usize, &'static str),
+    ProtectedMetatable,
+    InvalidToString(&'static str),
+    NonClosableValue(&'static str),
+    /// Key given to `next` is not on the table
+    InvalidNextKey(Value),
+    /// Traversal of a table went on after a key was added to it
+    KeyAddedDuringTraversal,
+    /// Failure reported by one of the host's
+    /// [backends](crate::environment::StdOut)
+    Io(String),
+    /// `require` did not find the module, with what each searcher tried
+    ModuleNotFound(String, String),
+    /// The source of a module given to `require` failed to compile
+    ModuleLoad(String, String),
+    /// Source given to [`Lua::eval`](crate::Lua::eval) failed to compile
+    Compile(crate::program::Error),
+    /// Field of `package` used by `require` is not a table
+    PackageField(&'static str),
+    // Extensions
+    MissingOpcodeHandler(u8),
+    FuelExhausted,
+    NotSuspended,
+    /// Error raised while running a line of a chunk parsed
+    /// with [`Program::parse`](crate::Program::parse) or
+    /// [`Program::parse_named`](crate::Program::parse_named)
+    Located {
+        chunk: Rc<str>,
+        line: usize,
+        error: Box<Error>,
+    },
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::InvalidGlobalKey(value) => write!(f, "Global {:?} is not a String.", value),
-            Self::InvalidFunction(value) => write!(f, "Value {:?} is not a function.", value),
+            Self::InvalidFunction(value) => write!(f, "Can't call {}.", value.static_type_name()),
             Self::ExpectedBoolean(type_name) => {
                 write!(f, "Expected a boolean, but was {}.", type_name)
             }
-            Self::Expected(loc, expected, was) => write!(
+            Self::Expected(position, expected, was) => write!(
                 f,
-                "Expected a {} at stack {}, but was {}.",
-                expected, loc, was
+                "Bad argument #{} ({} expected, got {}).",
+                position, expected, was
             ),
             Self::ExpectedName => write!(f, "Expected global or local name."),
-            Self::ExpectedTable => write!(f, "Tried accessing a value as a Table."),
-            Self::InvalidLenOperand => write!(f, "Len can only operate over String."),
-            Self::InvalidNegOperand => write!(f, "Neg can only operate over Integers and Floats."),
-            Self::InvalidBitNotOperand => write!(f, "BitNot can only operate over Integers."),
+            Self::ExpectedTable(was) => write!(f, "Can't index {}.", was),
+            Self::InvalidKey(key) => write!(f, "Index is {}.", key),
+            Self::FrozenTable => write!(f, "Attempt to modify a frozen table."),
+            Self::InvalidLenOperand(was) => write!(f, "Can't get the length of {}.", was),
+            Self::InvalidNegOperand(was) => write!(f, "Can't negate {}.", was),
+            Self::InvalidBitNotOperand(was) => write!(f, "Can't bitwise not {}.", was),
             Self::ArithmeticOperand(op, lhs, rhs) => {
                 write!(f, "Can't {} {} with {}.", op, lhs, rhs)
             }
+            Self::IntegerDivisionByZero(op) => write!(f, "Attempt to perform 'n{}0'.", op),
             Self::BitwiseOperand(op, lhs, rhs) => {
                 write!(f, "Can't {} {} with {}.", op, lhs, rhs)
             }
+            Self::NoIntegerRepresentation => write!(f, "Number has no integer representation."),
+            Self::FloatsDisabled(op) => write!(f, "Can't {}, floats are disabled.", op),
             Self::RelationalOperand(lhs, rhs) => {
-                write!(f, "Can't compare {} with {}", lhs, rhs)
+                write!(f, "Can't compare {} with {}.", lhs, rhs)
             }
             Self::ConcatOperand(operand) => {
                 write!(f, "Can't use {} in concatenation.", operand)
             }
-            Self::TryFloatConversion => write!(f, "Failed to convert Value to Value::Float."),
+            Self::ForNotNumber(value) => write!(f, "'for' {} value must be a number.", value),
             Self::IntegerConversion => write!(
                 f,
                 "Tried converting an integer that does not fit into a i64."
             ),
+            Self::OperandConversion(opcode, operand) => write!(
+                f,
+                "Operand '{}' of {} does not fit into the target's integer types.",
+                operand, opcode
+            ),
             Self::ForZeroStep => write!(f, "For loop had a step of zero."),
             Self::StackOverflow => write!(f, "Vm's stack has overflown."),
+            Self::MemoryLimit => write!(f, "Not enough memory."),
             Self::InvalidJump => write!(f, "Vm's program counter became invalid."),
-            Self::UpvalueDoesNotExist => write!(f, "Upvalue does not exist."),
+            Self::InvalidBytecode(opcode) => {
+                write!(
+                    f,
+                    "{} referred to values its function doesn't have.",
+                    opcode
+                )
+            }
+            Self::CorruptStack => write!(f, "Vm's stack has become corrupt."),
+            Self::UpvalueDoesNotExist(upvalue, len) => write!(
+                f,
+                "Closure does not have upvalue at position '{}', it has '{}' upvalues.",
+                upvalue, len
+            ),
             Self::ConstantDoesNotExist(constant, len) => write!(
                 f,
                 "Program does not have constant at position '{}', it has '{}' constants.",
                 constant, len
             ),
-            Self::Assertion => write!(f, "There was an assertion failure."),
+            Self::Assertion(Value::ShortString(message)) => write!(f, "{}", message),
+            Self::Assertion(Value::String(message)) => {
+                write!(f, "{}", String::from_utf8_lossy(message))
+            }
+            Self::Assertion(object) => {
+                write!(f, "(error object is a {} value)", object.static_type_name())
+            }
+            Self::MissingReturn => {
+                write!(f, "Function ended without a return.")
+            }
+            Self::BadArgument(position, reason) => {
+                write!(f, "Bad argument #{} ({}).", position, reason)
+            }
+            Self::ProtectedMetatable => write!(f, "Can't change a protected metatable."),
+            Self::InvalidToString(was) => {
+                write!(
+                    f,
+                    "`__tostring` must return a string, but returned {}.",
+                    was
+                )
+            }
+            Self::NonClosableValue(was) => {
+                write!(
+                    f,
+                    "To-be-closed variable got a {} value without `__close`.",
+                    was
+                )
+            }
+            Self::InvalidNextKey(_) => write!(f, "Invalid key to 'next'."),
+            Self::KeyAddedDuringTraversal => {
+                write!(f, "Key added to the table while it was traversed.")
+            }
+            Self::Io(message) => write!(f, "{}", message),
+            Self::ModuleNotFound(name, tried) => {
+                write!(f, "Module '{}' not found:{}", name, tried)
+            }
+            Self::ModuleLoad(name, reason) => {
+                write!(f, "Error loading module '{}': {}", name, reason)
+            }
+            Self::Compile(error) => write!(f, "{}", error),
+            Self::PackageField(field) => write!(f, "`package.{}` must be a table.", field),
+            Self::MissingOpcodeHandler(opcode) => {
+                write!(f, "No handler was registered for opcode {}.", opcode)
+            }
+            Self::FuelExhausted => write!(f, "Ran out of fuel."),
+            Self::NotSuspended => write!(f, "There is no suspended chunk to resume."),
+            Self::Located { chunk, line, error } => write!(f, "{}:{}: {}", chunk, line, error),
+        }
+    }
+}
+
+/// Broad cause of an [`Error`], see [`Error::category`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// The source of a chunk, or of a module loaded by `require`,
+    /// failed to compile
+    Syntax,
+    /// A chunk failed while running, by calling `error`, by an operation
+    /// on values of the wrong types, or by a failure of the standard
+    /// library or of the host's backends
+    Runtime,
+    /// The host misused the API, like resuming a chunk that is not
+    /// suspended, or ran bytecode that is corrupt
+    Api,
+}
+
+impl Error {
+    /// Error without the chunk and the line it was raised on, unwrapping
+    /// [`Error::Located`] and the located errors of [`Error::Compile`]
+    pub fn unlocated(self) -> Self {
+        match self {
+            Self::Located { error, .. } => error.unlocated(),
+            Self::Compile(error) => Self::Compile(error.unlocated()),
+            error => error,
+        }
+    }
+
+    /// Whether the error comes from the source, from running it, or from
+    /// a misuse of the API, errors located on a line have the category
+    /// of the error they wrap
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::Compile(_) | Self::ModuleLoad(_, _) => ErrorCategory::Syntax,
+            Self::InvalidGlobalKey(_)
+            | Self::InvalidFunction(_)
+            | Self::Expected(_, _, _)
+            | Self::ExpectedBoolean(_)
+            | Self::ExpectedName
+            | Self::ExpectedTable(_)
+            | Self::InvalidKey(_)
+            | Self::FrozenTable
+            | Self::InvalidLenOperand(_)
+            | Self::InvalidNegOperand(_)
+            | Self::InvalidBitNotOperand(_)
+            | Self::ArithmeticOperand(_, _, _)
+            | Self::IntegerDivisionByZero(_)
+            | Self::BitwiseOperand(_, _, _)
+            | Self::NoIntegerRepresentation
+            | Self::FloatsDisabled(_)
+            | Self::RelationalOperand(_, _)
+            | Self::ConcatOperand(_)
+            | Self::ForNotNumber(_)
+            | Self::IntegerConversion
+            | Self::ForZeroStep
+            | Self::StackOverflow
+            | Self::MemoryLimit
+            | Self::Assertion(_)
+            | Self::BadArgument(_, _)
+            | Self::ProtectedMetatable
+            | Self::InvalidToString(_)
+            | Self::NonClosableValue(_)
+            | Self::InvalidNextKey(_)
+            | Self::KeyAddedDuringTraversal
+            | Self::Io(_)
+            | Self::ModuleNotFound(_, _)
+            | Self::PackageField(_)
+            | Self::FuelExhausted => ErrorCategory::Runtime,
+            Self::OperandConversion(_, _)
+            | Self::InvalidJump
+            | Self::InvalidBytecode(_)
+            | Self::CorruptStack
+            | Self::UpvalueDoesNotExist(_, _)
+            | Self::ConstantDoesNotExist(_, _)
+            | Self::MissingReturn
+            | Self::MissingOpcodeHandler(_)
+            | Self::NotSuspended => ErrorCategory::Api,
+            Self::Located { error, .. } => error.category(),
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Located { error, .. } => Some(error.as_ref()),
+            Self::Compile(error) => Some(error),
+            _ => None,
         }
     }
 }
 
-impl core::error::Error for Error {}
+impl From<crate::program::Error> for Error {
+    fn from(value: crate::program::Error) -> Self {
+        Self::Compile(value)
+    }
+}
 
 impl From<TryFromIntError> for Error {
     fn from(value: TryFromIntError) -> Self {
@@ -1,12 +1,263 @@
-use core::num::FpCategory;
+use core::{f64::consts::LN_2, num::FpCategory};
 
+/// Floats with an absolute value greater or equal than this have no fractional part
+const NO_FRACTION_THRESHOLD: f64 = 4_503_599_627_370_496.0;
+/// High bits of `ln(2)`, exactly representable when multiplied by small integers
+const LN_2_HI: f64 = 6.931_471_803_691_238e-1;
+/// Remaining bits of `ln(2)`
+const LN_2_LO: f64 = 1.908_214_929_270_587_7e-10;
+
+/// Floating-point functions that are not available on `core`
+#[cfg_attr(not(feature = "math"), allow(dead_code))]
 pub trait FloatExt {
     /// Checks if the fraction part is zero
     fn zero_frac(&self) -> bool;
+    /// Integer part of the float, rounded towards zero
+    fn truncate(self) -> Self;
+    /// Fractional part of the float
+    fn fraction(self) -> Self;
+    /// Largest integral value less than or equal to the float
+    fn round_down(self) -> Self;
+    /// Smallest integral value greater than or equal to the float
+    fn round_up(self) -> Self;
+    /// Square root of the float, `NaN` for negative numbers
+    fn square_root(self) -> Self;
+    /// Natural logarithm of the float
+    fn natural_log(self) -> Self;
+    /// `e` raised to the float
+    fn exponential(self) -> Self;
+    /// Float raised to the power of `exponent`
+    fn power(self, exponent: Self) -> Self;
 }
 
 impl FloatExt for f64 {
     fn zero_frac(&self) -> bool {
-        self.fract().classify() == FpCategory::Zero
+        self.fraction().classify() == FpCategory::Zero
+    }
+
+    fn truncate(self) -> Self {
+        if self.abs() < NO_FRACTION_THRESHOLD {
+            ((self as i64) as f64).copysign(self)
+        } else {
+            // Big numbers, infinities, and NaN
+            self
+        }
+    }
+
+    fn fraction(self) -> Self {
+        self - self.truncate()
+    }
+
+    fn round_down(self) -> Self {
+        let trunc = self.truncate();
+        if trunc > self { trunc - 1.0 } else { trunc }
+    }
+
+    fn round_up(self) -> Self {
+        let trunc = self.truncate();
+        if trunc < self { trunc + 1.0 } else { trunc }
+    }
+
+    fn square_root(self) -> Self {
+        match self.classify() {
+            FpCategory::Nan | FpCategory::Zero => self,
+            _ if self.is_sign_negative() => f64::NAN,
+            FpCategory::Infinite => self,
+            FpCategory::Normal | FpCategory::Subnormal => {
+                // Split into `mantissa * 2^exponent` with a 53 bits mantissa
+                let bits = self.to_bits();
+                let mut exponent = ((bits >> 52) & 0x7ff) as i32;
+                let mut mantissa = bits & ((1 << 52) - 1);
+                if exponent == 0 {
+                    exponent = 1;
+                    while mantissa & (1 << 52) == 0 {
+                        mantissa <<= 1;
+                        exponent -= 1;
+                    }
+                } else {
+                    mantissa |= 1 << 52;
+                }
+                let mut exponent = exponent - 1075;
+                if exponent % 2 != 0 {
+                    mantissa <<= 1;
+                    exponent -= 1;
+                }
+
+                // The integer square root has 53 bits, rounding to nearest
+                // only needs the remainder
+                let radicand = u128::from(mantissa) << 52;
+                let mut root = radicand.isqrt();
+                if radicand - root * root > root {
+                    root += 1;
+                }
+
+                ldexp(root as f64, (exponent - 52) / 2)
+            }
+        }
+    }
+
+    fn natural_log(self) -> Self {
+        match self.classify() {
+            FpCategory::Nan => self,
+            FpCategory::Zero => f64::NEG_INFINITY,
+            _ if self.is_sign_negative() => f64::NAN,
+            FpCategory::Infinite => self,
+            FpCategory::Normal | FpCategory::Subnormal => {
+                let (mantissa, exponent) = frexp(self);
+                // Move mantissa to [sqrt(0.5), sqrt(2)) so the series converges fast
+                let (mantissa, exponent) = if mantissa < core::f64::consts::FRAC_1_SQRT_2 {
+                    (mantissa * 2.0, exponent - 1)
+                } else {
+                    (mantissa, exponent)
+                };
+
+                // ln(m) = 2 * atanh((m - 1) / (m + 1))
+                let s = (mantissa - 1.0) / (mantissa + 1.0);
+                let s2 = s * s;
+                let mut term = s;
+                let mut sum: f64 = 0.0;
+                let mut divisor = 1.0;
+                while term.abs() > f64::EPSILON * f64::EPSILON {
+                    sum += term / divisor;
+                    term *= s2;
+                    divisor += 2.0;
+                }
+
+                f64::from(exponent) * LN_2_HI + (f64::from(exponent) * LN_2_LO + 2.0 * sum)
+            }
+        }
+    }
+
+    fn exponential(self) -> Self {
+        if self.is_nan() {
+            self
+        } else if self > 709.782_712_893_384 {
+            f64::INFINITY
+        } else if self < -745.133_219_101_941_1 {
+            0.0
+        } else {
+            // e^x = 2^k * e^r, with |r| <= ln(2) / 2
+            let k = (self / LN_2 + 0.5f64.copysign(self)).truncate();
+            let r = (self - k * LN_2_HI) - k * LN_2_LO;
+
+            let mut term: f64 = 1.0;
+            let mut sum = 1.0;
+            let mut n = 1.0;
+            while term.abs() > f64::EPSILON * f64::EPSILON {
+                term *= r / n;
+                sum += term;
+                n += 1.0;
+            }
+
+            ldexp(sum, k as i32)
+        }
+    }
+
+    fn power(self, exponent: Self) -> Self {
+        if exponent == 0.0 || self == 1.0 {
+            1.0
+        } else if self.is_nan() || exponent.is_nan() {
+            f64::NAN
+        } else if exponent.zero_frac() && exponent.abs() <= f64::from(i32::MAX) {
+            powi(self, exponent as i32)
+        } else if self == 0.0 {
+            if exponent > 0.0 { 0.0 } else { f64::INFINITY }
+        } else if self.is_sign_negative() {
+            if exponent.is_infinite() {
+                (-self).power(exponent)
+            } else {
+                f64::NAN
+            }
+        } else {
+            (exponent * self.natural_log()).exponential()
+        }
+    }
+}
+
+/// Splits a positive finite float into a mantissa in `[0.5, 1)` and an exponent
+fn frexp(float: f64) -> (f64, i32) {
+    if float.classify() == FpCategory::Subnormal {
+        let (mantissa, exponent) = frexp(float * 18_014_398_509_481_984.0);
+        (mantissa, exponent - 54)
+    } else {
+        let bits = float.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32 - 1022;
+        let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+        (mantissa, exponent)
+    }
+}
+
+/// Multiplies a float by `2^exponent`
+pub(super) fn ldexp(float: f64, exponent: i32) -> f64 {
+    // Each step keeps the scaling factor a normal float
+    let mut float = float;
+    let mut exponent = exponent;
+    while exponent > 1023 {
+        float *= f64::from_bits(2046 << 52);
+        exponent -= 1023;
+    }
+    while exponent < -1022 {
+        float *= f64::from_bits(1 << 52);
+        exponent += 1022;
+    }
+    float * f64::from_bits(((exponent + 1023) as u64) << 52)
+}
+
+/// Raises a float to an integer power using exponentiation by squaring
+fn powi(base: f64, exponent: i32) -> f64 {
+    let mut result = 1.0;
+    let mut base = base;
+    let mut remaining = exponent.unsigned_abs();
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        remaining >>= 1;
+    }
+    if exponent < 0 { 1.0 / result } else { result }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(lhs: f64, rhs: f64) {
+        assert!(
+            (lhs - rhs).abs() <= rhs.abs() * 1e-14,
+            "{lhs} was not close to {rhs}"
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(3.7_f64.truncate(), 3.0);
+        assert_eq!((-3.7_f64).truncate(), -3.0);
+        assert!((-0.5_f64).truncate().is_sign_negative());
+        assert_eq!(3.7_f64.round_down(), 3.0);
+        assert_eq!((-3.2_f64).round_down(), -4.0);
+        assert_eq!(3.2_f64.round_up(), 4.0);
+        assert_eq!((-3.7_f64).round_up(), -3.0);
+        assert_eq!(1e300_f64.round_down(), 1e300);
+        assert_eq!(f64::INFINITY.round_up(), f64::INFINITY);
+        assert!(f64::NAN.round_down().is_nan());
+        assert_close(2.25_f64.fraction(), 0.25);
+    }
+
+    #[test]
+    fn roots_and_powers() {
+        assert_eq!(4.0_f64.square_root(), 2.0);
+        assert_eq!(2.0_f64.square_root(), core::f64::consts::SQRT_2);
+        assert_eq!(1e-300_f64.square_root(), 1e-150);
+        assert!((-1.0_f64).square_root().is_nan());
+        assert_close(5e-324_f64.square_root(), 2.222_758_749_485_076e-162);
+        assert_close(core::f64::consts::E.natural_log(), 1.0);
+        assert_close(1.0_f64.exponential(), core::f64::consts::E);
+        assert_eq!(2.0_f64.power(10.0), 1024.0);
+        assert_eq!(2.0_f64.power(-1.0), 0.5);
+        assert_close(2.0_f64.power(0.5), core::f64::consts::SQRT_2);
+        assert_close(10.0_f64.power(2.5), 316.227_766_016_837_94);
+        assert!((-8.0_f64).power(1.0 / 3.0).is_nan());
+        assert_eq!(0.0_f64.power(-1.0), f64::INFINITY);
     }
 }
@@ -0,0 +1,131 @@
+//! Floats as Lua writes them, shared by `tostring`, concatenations, and
+//! the output of the standard library
+
+use core::fmt::{Display, Formatter, Write};
+
+/// Significant digits of floats, like the `%.14g` of `LUAI_NUMFFORMAT`
+const PRECISION: usize = 14;
+
+/// Float written like C's `printf("%.14g")`, with `.0` added to floats
+/// that would look like integers, as `tostring` does
+#[derive(Debug, Clone, Copy)]
+pub struct LuaFloat(pub f64);
+
+impl Display for LuaFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let float = self.0;
+        let sign = if float.is_sign_negative() { "-" } else { "" };
+        if float.is_nan() {
+            return write!(f, "{}nan", sign);
+        }
+        if float.is_infinite() {
+            return write!(f, "{}inf", sign);
+        }
+
+        // Rust rounds the digits of the scientific notation correctly,
+        // they are then placed where `%g` would place them
+        let mut scientific = Buffer::default();
+        write!(scientific, "{:.*e}", PRECISION - 1, float.abs())?;
+        let (mantissa, exponent) = scientific
+            .as_str()
+            .split_once('e')
+            .ok_or(core::fmt::Error)?;
+        let exponent: i32 = exponent.parse().map_err(|_| core::fmt::Error)?;
+        let mut digits = Buffer::default();
+        for digit in mantissa.chars().filter(|c| *c != '.') {
+            digits.write_char(digit)?;
+        }
+        let digits = digits.as_str();
+
+        f.write_str(sign)?;
+        if !(-4..PRECISION as i32).contains(&exponent) {
+            let (first, rest) = digits.split_at(1);
+            let rest = rest.trim_end_matches('0');
+            f.write_str(first)?;
+            if !rest.is_empty() {
+                write!(f, ".{}", rest)?;
+            }
+            let exponent_sign = if exponent < 0 { '-' } else { '+' };
+            write!(f, "e{}{:02}", exponent_sign, exponent.unsigned_abs())
+        } else if exponent < 0 {
+            f.write_str("0.")?;
+            for _ in 1..exponent.unsigned_abs() {
+                f.write_char('0')?;
+            }
+            f.write_str(digits.trim_end_matches('0'))
+        } else {
+            let (whole, fraction) = digits.split_at(exponent.unsigned_abs() as usize + 1);
+            let fraction = fraction.trim_end_matches('0');
+            f.write_str(whole)?;
+            if fraction.is_empty() {
+                f.write_str(".0")
+            } else {
+                write!(f, ".{}", fraction)
+            }
+        }
+    }
+}
+
+/// Room for the scientific notation of a float, like `1.2345678901234e-308`
+#[derive(Default)]
+struct Buffer {
+    bytes: [u8; 32],
+    len: usize,
+}
+
+impl Buffer {
+    fn as_str(&self) -> &str {
+        // Only whole `str`s are written
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::LuaFloat;
+
+    #[test]
+    fn like_printf() {
+        for (float, expected) in [
+            (0.0, "0.0"),
+            (-0.0, "-0.0"),
+            (1.0, "1.0"),
+            (-3.0, "-3.0"),
+            (0.5, "0.5"),
+            (0.1 + 0.2, "0.3"),
+            (1.0 / 3.0, "0.33333333333333"),
+            (100.25, "100.25"),
+            (1e13, "10000000000000.0"),
+            (1e14, "1e+14"),
+            (123_456_789_012_345.0, "1.2345678901234e+14"),
+            (9_223_372_036_854_775_808.0, "9.2233720368548e+18"),
+            (1e100, "1e+100"),
+            (0.0001, "0.0001"),
+            (0.00001, "1e-05"),
+            (1.5e-300, "1.5e-300"),
+            (5e-324, "4.9406564584125e-324"),
+            (f64::MAX, "1.7976931348623e+308"),
+            (99_999_999_999_999.5, "1e+14"),
+            (f64::INFINITY, "inf"),
+            (f64::NEG_INFINITY, "-inf"),
+            (f64::NAN, "nan"),
+            (-f64::NAN, "-nan"),
+        ] {
+            assert_eq!(LuaFloat(float).to_string(), expected, "{float:?}");
+        }
+    }
+}
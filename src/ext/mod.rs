@@ -1,7 +1,12 @@
 mod float;
+#[cfg(feature = "float")]
+mod format;
+mod numeral;
+#[cfg(feature = "alloc")]
 mod string;
 
-pub use self::{
-    float::FloatExt,
-    string::{Unescape, UnescapeError},
-};
+pub use self::numeral::{Numeral, ParseNumeral};
+#[cfg(feature = "alloc")]
+pub use self::string::{Unescape, UnescapeError};
+#[cfg(feature = "float")]
+pub use self::{float::FloatExt, format::LuaFloat};
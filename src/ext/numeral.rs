@@ -0,0 +1,279 @@
+//! Numerals as Lua reads them, shared by the lexer and the conversions of
+//! strings to numbers
+
+use super::float::ldexp;
+
+/// Hexadecimal digits of a float that are read, like `MAXSIGDIG` of
+/// `lobject.c`, more than a `f64` can hold
+const MAX_SIGNIFICANT_DIGITS: usize = 30;
+
+/// Number read from a numeral
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Numeral {
+    Integer(i64),
+    Float(f64),
+}
+
+pub trait ParseNumeral {
+    /// Reads a decimal or hexadecimal (`0x`) numeral, with an optional sign
+    /// and surrounding whitespace.
+    ///
+    /// Decimal integers that don't fit an `i64` are read as floats, and
+    /// hexadecimal integers wrap around.
+    fn parse_numeral(&self) -> Option<Numeral>;
+
+    /// Reads an integer in `base`, from 2 to 36, with an optional sign and
+    /// surrounding whitespace, wrapping around on overflow
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    fn parse_integer_in_base(&self, base: u32) -> Option<i64>;
+}
+
+impl ParseNumeral for [u8] {
+    fn parse_numeral(&self) -> Option<Numeral> {
+        let (negative, numeral) = split_sign(trim_space(self));
+        let numeral = match numeral {
+            [b'0', b'x' | b'X', hex @ ..] => parse_hexadecimal(hex)?,
+            decimal => parse_decimal(decimal, negative)?,
+        };
+
+        Some(match numeral {
+            Numeral::Integer(integer) if negative => Numeral::Integer(integer.wrapping_neg()),
+            Numeral::Float(float) if negative => Numeral::Float(-float),
+            numeral => numeral,
+        })
+    }
+
+    fn parse_integer_in_base(&self, base: u32) -> Option<i64> {
+        let (negative, digits) = split_sign(trim_space(self));
+        if digits.is_empty() {
+            return None;
+        }
+
+        let integer = digits.iter().try_fold(0i64, |integer, digit| {
+            let digit = char::from(*digit).to_digit(base)?;
+            Some(
+                integer
+                    .wrapping_mul(i64::from(base))
+                    .wrapping_add(i64::from(digit)),
+            )
+        })?;
+        Some(if negative {
+            integer.wrapping_neg()
+        } else {
+            integer
+        })
+    }
+}
+
+impl ParseNumeral for str {
+    fn parse_numeral(&self) -> Option<Numeral> {
+        self.as_bytes().parse_numeral()
+    }
+
+    fn parse_integer_in_base(&self, base: u32) -> Option<i64> {
+        self.as_bytes().parse_integer_in_base(base)
+    }
+}
+
+/// Removes the whitespace around the numeral, as C's `isspace` defines it
+fn trim_space(numeral: &[u8]) -> &[u8] {
+    let is_space = |c: &u8| matches!(c, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c);
+    let start = numeral
+        .iter()
+        .position(|c| !is_space(c))
+        .unwrap_or(numeral.len());
+    let end = numeral
+        .iter()
+        .rposition(|c| !is_space(c))
+        .map_or(start, |last| last + 1);
+    &numeral[start..end]
+}
+
+fn split_sign(numeral: &[u8]) -> (bool, &[u8]) {
+    match numeral {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        rest => (false, rest),
+    }
+}
+
+/// Reads a decimal numeral without its sign, the sign decides if the
+/// integer fits an `i64`, as `-9223372036854775808` does
+fn parse_decimal(numeral: &[u8], negative: bool) -> Option<Numeral> {
+    if !numeral.is_empty() && numeral.iter().all(u8::is_ascii_digit) {
+        let magnitude = numeral.iter().try_fold(0u64, |integer, digit| {
+            integer
+                .checked_mul(10)?
+                .checked_add(u64::from(digit - b'0'))
+        });
+        let integer = magnitude.and_then(|magnitude| match i64::try_from(magnitude) {
+            Ok(integer) => Some(integer),
+            Err(_) if negative && magnitude == i64::MIN.unsigned_abs() => Some(i64::MIN),
+            Err(_) => None,
+        });
+        if let Some(integer) = integer {
+            // The sign is applied by the caller, `i64::MIN` wraps back to itself
+            return Some(Numeral::Integer(integer));
+        }
+    }
+
+    // `str::parse` also accepts `inf` and `nan`, which are not numerals
+    let (mantissa, exponent) = match numeral.iter().position(|c| matches!(c, b'e' | b'E')) {
+        Some(mark) => (&numeral[..mark], Some(&numeral[mark + 1..])),
+        None => (numeral, None),
+    };
+    let mut digits = mantissa.iter().filter(|c| **c != b'.');
+    let valid_mantissa = mantissa.iter().filter(|c| **c == b'.').count() <= 1
+        && digits.clone().count() > 0
+        && digits.all(u8::is_ascii_digit);
+    let valid_exponent = exponent.is_none_or(|exponent| {
+        let (_, digits) = split_sign(exponent);
+        !digits.is_empty() && digits.iter().all(u8::is_ascii_digit)
+    });
+    if !(valid_mantissa && valid_exponent) {
+        return None;
+    }
+
+    core::str::from_utf8(numeral)
+        .ok()?
+        .parse()
+        .ok()
+        .map(Numeral::Float)
+}
+
+/// Reads a hexadecimal numeral after its `0x`, floats have a fractional part,
+/// or a binary exponent after a `p`
+fn parse_hexadecimal(numeral: &[u8]) -> Option<Numeral> {
+    let (mantissa, exponent) = match numeral.iter().position(|c| matches!(c, b'p' | b'P')) {
+        Some(mark) => (&numeral[..mark], Some(&numeral[mark + 1..])),
+        None => (numeral, None),
+    };
+    let (whole, fraction) = match mantissa.iter().position(|c| *c == b'.') {
+        Some(dot) => (&mantissa[..dot], Some(&mantissa[dot + 1..])),
+        None => (mantissa, None),
+    };
+    let digit = |c: &u8| char::from(*c).to_digit(16);
+    if whole.len() + fraction.map_or(0, <[u8]>::len) == 0 {
+        return None;
+    }
+
+    if fraction.is_none() && exponent.is_none() {
+        return whole
+            .iter()
+            .try_fold(0i64, |integer, c| {
+                Some((integer << 4).wrapping_add(i64::from(digit(c)?)))
+            })
+            .map(Numeral::Integer);
+    }
+
+    // Like `lua_strx2number`, digits past the significant ones only move
+    // the exponent, so long mantissas don't overflow before it is applied
+    let mut float = 0.0;
+    let mut scale = 0i32;
+    let mut significant = 0;
+    for c in whole {
+        let digit = digit(c)?;
+        if significant == 0 && digit == 0 {
+            continue;
+        }
+        significant += 1;
+        if significant <= MAX_SIGNIFICANT_DIGITS {
+            float = float * 16.0 + f64::from(digit);
+        } else {
+            scale = scale.saturating_add(4);
+        }
+    }
+    for c in fraction.unwrap_or_default() {
+        let digit = digit(c)?;
+        if significant == 0 && digit == 0 {
+            scale = scale.saturating_sub(4);
+            continue;
+        }
+        significant += 1;
+        if significant <= MAX_SIGNIFICANT_DIGITS {
+            float = float * 16.0 + f64::from(digit);
+            scale = scale.saturating_sub(4);
+        }
+    }
+    if let Some(exponent) = exponent {
+        let (negative, digits) = split_sign(exponent);
+        if digits.is_empty() {
+            return None;
+        }
+        let exponent = digits.iter().try_fold(0i32, |exponent, c| {
+            let digit = char::from(*c).to_digit(10)?;
+            Some(exponent.saturating_mul(10).saturating_add(digit as i32))
+        })?;
+        scale = if negative {
+            scale.saturating_sub(exponent)
+        } else {
+            scale.saturating_add(exponent)
+        };
+    }
+
+    // Past these, every mantissa overflows to infinity or underflows to zero
+    Some(Numeral::Float(ldexp(float, scale.clamp(-2200, 2200))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Numeral, ParseNumeral};
+
+    #[test]
+    fn decimal() {
+        assert_eq!("10".parse_numeral(), Some(Numeral::Integer(10)));
+        assert_eq!(" -7\t\n".parse_numeral(), Some(Numeral::Integer(-7)));
+        assert_eq!("+3".parse_numeral(), Some(Numeral::Integer(3)));
+        assert_eq!("3.5".parse_numeral(), Some(Numeral::Float(3.5)));
+        assert_eq!(".5".parse_numeral(), Some(Numeral::Float(0.5)));
+        assert_eq!("5.".parse_numeral(), Some(Numeral::Float(5.0)));
+        assert_eq!("1e3".parse_numeral(), Some(Numeral::Float(1000.0)));
+        assert_eq!("2.5E-1".parse_numeral(), Some(Numeral::Float(0.25)));
+        assert_eq!(
+            "9223372036854775807".parse_numeral(),
+            Some(Numeral::Integer(i64::MAX))
+        );
+        assert_eq!(
+            "-9223372036854775808".parse_numeral(),
+            Some(Numeral::Integer(i64::MIN))
+        );
+        assert_eq!(
+            "9223372036854775808".parse_numeral(),
+            Some(Numeral::Float(9_223_372_036_854_775_808.0))
+        );
+
+        for malformed in [
+            "", " ", ".", "e3", "1e", "1e+", "1.2.3", "inf", "nan", "1 2", "0b1",
+        ] {
+            assert_eq!(malformed.parse_numeral(), None, "{malformed:?}");
+        }
+    }
+
+    #[test]
+    fn hexadecimal() {
+        assert_eq!("0xff".parse_numeral(), Some(Numeral::Integer(255)));
+        assert_eq!("0XA".parse_numeral(), Some(Numeral::Integer(10)));
+        assert_eq!("-0x10".parse_numeral(), Some(Numeral::Integer(-16)));
+        assert_eq!(
+            "0xffffffffffffffff".parse_numeral(),
+            Some(Numeral::Integer(-1))
+        );
+        assert_eq!("0x1p4".parse_numeral(), Some(Numeral::Float(16.0)));
+        assert_eq!("0x.8".parse_numeral(), Some(Numeral::Float(0.5)));
+        assert_eq!("0xA.8p-1".parse_numeral(), Some(Numeral::Float(5.25)));
+
+        for malformed in ["0x", "0x.", "0xg", "0x1p", "0x1p-", "0x1.2.3"] {
+            assert_eq!(malformed.parse_numeral(), None, "{malformed:?}");
+        }
+    }
+
+    #[test]
+    fn in_base() {
+        assert_eq!("ff".parse_integer_in_base(16), Some(255));
+        assert_eq!(" 1010 ".parse_integer_in_base(2), Some(10));
+        assert_eq!("-zz".parse_integer_in_base(36), Some(-1295));
+        assert_eq!("12".parse_integer_in_base(2), None);
+        assert_eq!("".parse_integer_in_base(10), None);
+        assert_eq!("1.0".parse_integer_in_base(10), None);
+    }
+}
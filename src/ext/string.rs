@@ -1,16 +1,15 @@
 use core::fmt::Display;
 
-use alloc::{
-    string::{String, ToString},
-    vec::Vec,
-};
+use alloc::vec::Vec;
 
 pub trait Unescape {
-    fn unescape(&self) -> Result<String, UnescapeError>;
+    /// Replaces the escape sequences of a short string by the bytes they
+    /// stand for, as the lexer checked them
+    fn unescape(&self) -> Result<Vec<u8>, UnescapeError>;
 }
 
 impl Unescape for &str {
-    fn unescape(&self) -> Result<String, UnescapeError> {
+    fn unescape(&self) -> Result<Vec<u8>, UnescapeError> {
         let mut vec = Vec::with_capacity(self.len());
         let mut iter = self.chars().peekable();
         let mut buffer = [0; 4];
@@ -28,55 +27,67 @@ impl Unescape for &str {
                     Some('\\') => vec.push(b'\\'),
                     Some('"') => vec.push(b'"'),
                     Some('\'') => vec.push(b'\''),
+                    Some(newline @ ('\n' | '\r')) => {
+                        iter.next_if(|next| matches!(next, '\n' | '\r') && *next != newline);
+                        vec.push(b'\n');
+                    }
+                    Some('z') => {
+                        while iter
+                            .next_if(|next| next.is_ascii_whitespace() || *next == '\u{b}')
+                            .is_some()
+                        {}
+                    }
                     Some(d @ '0'..='9') => {
-                        let mut three_digit_escaped = [d].to_vec();
-
-                        if matches!(iter.peek(), Some('0'..='9')) {
-                            let Some(d2) = iter.next() else {
-                                unreachable!("Already checked that it is Some.")
+                        let mut ordinal = d.to_digit(10).unwrap_or_default();
+                        for _ in 0..2 {
+                            let Some(digit) = iter.peek().and_then(|d| d.to_digit(10)) else {
+                                break;
                             };
-                            three_digit_escaped.push(d2);
+                            iter.next();
+                            ordinal = ordinal * 10 + digit;
                         }
-                        if matches!(iter.peek(), Some('0'..='9')) {
-                            let Some(d3) = iter.next() else {
-                                unreachable!("Already checked that it is Some.")
-                            };
-                            three_digit_escaped.push(d3);
-                        }
-
-                        let Ok(ordinal) =
-                            three_digit_escaped.into_iter().collect::<String>().parse()
-                        else {
-                            unreachable!("Vec only has digits.");
-                        };
-
-                        let c = unsafe { char::from_u32_unchecked(ordinal) };
-                        c.encode_utf8(&mut buffer);
-                        vec.extend(&buffer[..c.len_utf8()]);
+                        vec.push(
+                            u8::try_from(ordinal).map_err(|_| UnescapeError::DecimalTooLarge)?,
+                        );
                     }
                     Some('x') => {
-                        let Some(&d1 @ ('A'..='F' | 'a'..='f' | '0'..='9')) = iter.peek() else {
-                            return Err(UnescapeError::MalformedHexChar);
-                        };
-                        iter.next();
-                        let Some(&d2 @ ('A'..='F' | 'a'..='f' | '0'..='9')) = iter.peek() else {
-                            return Err(UnescapeError::MalformedHexChar);
-                        };
-                        iter.next();
-
-                        let Some(d1) = d1.to_digit(16) else {
-                            unreachable!("Already checked that is hex digit.");
-                        };
-                        let Some(d2) = d2.to_digit(16) else {
-                            unreachable!("Already checked that is hex digit.");
-                        };
-
-                        let Ok(ordinal) = u8::try_from(d1 * 16 + d2) else {
+                        let mut ordinal = 0;
+                        for _ in 0..2 {
+                            let digit = iter
+                                .next()
+                                .and_then(|d| d.to_digit(16))
+                                .ok_or(UnescapeError::MalformedHexChar)?;
+                            ordinal = ordinal * 16 + digit;
+                        }
+                        let Ok(ordinal) = u8::try_from(ordinal) else {
                             unreachable!("Sum of 2 hex digits shouldn't overflow a u8.");
                         };
-
                         vec.push(ordinal);
                     }
+                    Some('u') => {
+                        if iter.next() != Some('{') {
+                            return Err(UnescapeError::MalformedUnicode);
+                        }
+                        let mut code_point = 0u32;
+                        let mut digits = 0;
+                        loop {
+                            match iter.next() {
+                                Some('}') if digits > 0 => break,
+                                Some(d) => {
+                                    let digit =
+                                        d.to_digit(16).ok_or(UnescapeError::MalformedUnicode)?;
+                                    code_point = code_point
+                                        .checked_mul(16)
+                                        .map(|code_point| code_point + digit)
+                                        .filter(|code_point| *code_point <= 0x7FFF_FFFF)
+                                        .ok_or(UnescapeError::MalformedUnicode)?;
+                                    digits += 1;
+                                }
+                                None => return Err(UnescapeError::MalformedUnicode),
+                            }
+                        }
+                        utf8_escape(code_point, &mut vec);
+                    }
                     Some(_) => return Err(UnescapeError::UnknownEscapedCharacter),
                     None => return Err(UnescapeError::UnfinishedEscapedCharacter),
                 }
@@ -86,16 +97,39 @@ impl Unescape for &str {
             }
         }
 
-        let result = String::from_utf8_lossy(&vec).to_string();
-        Ok(result)
+        Ok(vec)
     }
 }
 
+/// Encodes `code_point` like `luaO_utf8esc`, which goes past the code
+/// points of Unicode with sequences of up to 6 bytes
+fn utf8_escape(code_point: u32, bytes: &mut Vec<u8>) {
+    if code_point < 0x80 {
+        bytes.push(code_point as u8);
+        return;
+    }
+    let mut continuation = [0; 6];
+    let mut count = 0;
+    let mut rest = code_point;
+    // Largest value that fits the first byte
+    let mut first_byte_limit = 0x3f;
+    while rest > first_byte_limit {
+        continuation[count] = 0x80 | (rest & 0x3f) as u8;
+        count += 1;
+        rest >>= 6;
+        first_byte_limit >>= 1;
+    }
+    bytes.push(((!first_byte_limit << 1) | rest) as u8);
+    bytes.extend(continuation[..count].iter().rev());
+}
+
 #[derive(Debug)]
 pub enum UnescapeError {
     UnfinishedEscapedCharacter,
     UnknownEscapedCharacter,
     MalformedHexChar,
+    DecimalTooLarge,
+    MalformedUnicode,
 }
 
 impl Display for UnescapeError {
@@ -113,6 +147,12 @@ impl Display for UnescapeError {
             Self::MalformedHexChar => {
                 write!(f, "A hex escaped character did not have 2 digits.")
             }
+            Self::DecimalTooLarge => {
+                write!(f, "A decimal escaped character did not fit a byte.")
+            }
+            Self::MalformedUnicode => {
+                write!(f, "A `\\u{{XXX}}` escaped character was malformed.")
+            }
         }
     }
 }
@@ -0,0 +1,69 @@
+//! Entry points for fuzzing, which take arbitrary bytes and must
+//! never panic, see the targets of `fuzz/`
+
+use crate::{Lua, Program, lex::Lex, parser::Parser};
+
+/// Bytecodes a chunk can run before it is stopped
+const FUEL: u64 = 100_000;
+
+/// Bytes a chunk can allocate before it is stopped
+const MEMORY_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Reads the lexemes of `data` up to the first lexical error, and checks
+/// that the lexemes with trivia follow each other up to the end of the source
+pub fn lex(data: &[u8]) {
+    let Ok(source) = core::str::from_utf8(data) else {
+        return;
+    };
+    for lexeme in Lex::new(source) {
+        if lexeme.is_err() {
+            break;
+        }
+    }
+
+    let mut previous = None;
+    for lexeme in Lex::new(source).with_trivia() {
+        let Ok(lexeme) = lexeme else {
+            return;
+        };
+        assert!(
+            previous.is_none_or(|previous| previous < lexeme.start())
+                && source.is_char_boundary(lexeme.start()),
+            "Lexemes with trivia must start after the previous one."
+        );
+        previous = Some(lexeme.start());
+    }
+    assert_eq!(
+        previous,
+        Some(source.len()),
+        "Lexemes with trivia must cover the source."
+    );
+}
+
+/// Checks the syntax of `data`, and compiles it
+pub fn parse(data: &[u8]) {
+    let Ok(source) = core::str::from_utf8(data) else {
+        return;
+    };
+    let _ = Parser::check(source);
+    let _ = Program::parse(source);
+}
+
+/// Loads `data` as a binary chunk, and writes back the chunks
+/// that are accepted
+pub fn load_chunk(data: &[u8]) {
+    if let Ok(program) = Program::from_bytecode(data) {
+        let _ = program.to_bytecode();
+    }
+}
+
+/// Loads `data` as a binary chunk and runs the chunks that are accepted,
+/// for a limited number of bytecodes and amount of memory
+pub fn run_chunk(data: &[u8]) {
+    if let Ok(program) = Program::from_bytecode(data) {
+        let mut lua = Lua::default();
+        lua.set_fuel(Some(FUEL));
+        lua.set_memory_limit(Some(MEMORY_LIMIT));
+        let _ = lua.execute(program);
+    }
+}
@@ -0,0 +1,378 @@
+//! Cycle collector for reference counted values
+//!
+//! Values are freed by reference counting as soon as they are no longer
+//! used, except for cycles, like a table that holds itself or a closure
+//! that captures the table it is stored on. The collector finds these
+//! cycles with a stop-the-world mark & sweep over the objects it tracks.
+//!
+//! References held by the VM's stack or by the host can't be enumerated,
+//! so instead of marking from a root set, the collector counts how many
+//! references to each object come from other tracked objects, any
+//! reference beyond those comes from outside and makes the object a root.
+//! Objects that are not reachable from a root are only referenced by other
+//! garbage, and are emptied to break their cycles, which lets reference
+//! counting free them.
+
+use core::{cell::RefCell, fmt::Debug, mem::size_of};
+
+use alloc::{
+    collections::BTreeMap,
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+
+use crate::{
+    closure::{Closure, Upvalue},
+    program::ConstantPool,
+    table::Table,
+    userdata::UserData,
+    value::{Value, ValueKey},
+};
+
+/// Minimum number of objects tracked before a collection runs
+const MIN_THRESHOLD: usize = 1024;
+
+pub(crate) struct Collector {
+    /// Objects known to the collector, everything reachable from
+    /// them is tracked as well
+    tracked: Vec<Tracked>,
+    /// Number of tracked objects that triggers a collection
+    threshold: usize,
+    /// Whether collections run automatically
+    running: bool,
+}
+
+enum Tracked {
+    Table(Weak<RefCell<Table>>),
+    Closure(Weak<Closure>),
+}
+
+/// Cap on the memory used by the scripts of a VM, see
+/// [`Lua::set_memory_limit`](crate::Lua::set_memory_limit)
+#[derive(Debug)]
+pub(crate) struct MemoryLimit {
+    /// Most bytes the scripts can use
+    pub(crate) limit: usize,
+    /// Bytes in use when last measured, plus the bytes allocated since
+    pub(crate) estimate: usize,
+}
+
+/// Object that can hold references to other objects
+enum Node {
+    Table(Rc<RefCell<Table>>),
+    Closure(Rc<Closure>),
+    Upvalue(Rc<RefCell<Upvalue>>),
+    UserData(Rc<UserData>),
+}
+
+/// Tracked objects and the references between them
+struct Heap {
+    nodes: Vec<Node>,
+    /// Objects referenced by each object, repeated for each reference
+    edges: Vec<Vec<usize>>,
+    /// Objects that could not be scanned because they were borrowed
+    unscanned: Vec<bool>,
+}
+
+impl Collector {
+    pub(crate) fn new() -> Self {
+        Self {
+            tracked: Vec::new(),
+            threshold: MIN_THRESHOLD,
+            running: true,
+        }
+    }
+
+    pub(crate) fn track_table(&mut self, table: &Rc<RefCell<Table>>) {
+        self.tracked.push(Tracked::Table(Rc::downgrade(table)));
+    }
+
+    pub(crate) fn track_closure(&mut self, closure: &Rc<Closure>) {
+        self.tracked.push(Tracked::Closure(Rc::downgrade(closure)));
+    }
+
+    /// Returns `true` if enough objects were created since the last
+    /// collection for a new one to run
+    pub(crate) fn should_collect(&self) -> bool {
+        self.running && self.tracked.len() >= self.threshold
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub(crate) fn set_running(&mut self, running: bool) {
+        self.running = running;
+    }
+
+    /// Frees every cycle that is no longer reachable, returning how
+    /// many objects were freed
+    pub(crate) fn collect(&mut self) -> usize {
+        let heap = self.scan();
+
+        let mut internal_references = alloc::vec![0usize; heap.nodes.len()];
+        for edges in heap.edges.iter() {
+            for &edge in edges {
+                internal_references[edge] += 1;
+            }
+        }
+
+        // The heap holds one reference to each object
+        let mut marked = heap
+            .nodes
+            .iter()
+            .zip(internal_references)
+            .zip(heap.unscanned.iter())
+            .map(|((node, internal), unscanned)| *unscanned || node.strong_count() > internal + 1)
+            .collect::<Vec<_>>();
+        let mut pending = marked
+            .iter()
+            .enumerate()
+            .filter_map(|(i, root)| root.then_some(i))
+            .collect::<Vec<_>>();
+        while let Some(node) = pending.pop() {
+            for &edge in heap.edges[node].iter() {
+                if !marked[edge] {
+                    marked[edge] = true;
+                    pending.push(edge);
+                }
+            }
+        }
+
+        // Values are only dropped after all garbage is emptied, so
+        // no object is freed while the cycles are being broken
+        let mut released = Vec::new();
+        let mut freed = 0;
+        for (node, marked) in heap.nodes.iter().zip(marked.iter()) {
+            if !marked {
+                node.clear(&mut released);
+                freed += 1;
+            }
+        }
+        drop(released);
+
+        self.tracked = heap
+            .nodes
+            .iter()
+            .zip(marked.iter())
+            .filter(|(_, marked)| **marked)
+            .filter_map(|(node, _)| match node {
+                Node::Table(table) => Some(Tracked::Table(Rc::downgrade(table))),
+                Node::Closure(closure) => Some(Tracked::Closure(Rc::downgrade(closure))),
+                Node::Upvalue(_) | Node::UserData(_) => None,
+            })
+            .collect();
+        self.threshold = MIN_THRESHOLD.max(self.tracked.len() * 2);
+
+        log::trace!("Collected {} objects.", freed);
+        freed
+    }
+
+    /// Estimate of the memory used by the tracked objects, in bytes
+    ///
+    /// Strings are counted once for each reference to them, and
+    /// memory owned by userdata is not counted.
+    pub(crate) fn memory_in_use(&mut self, pool: &ConstantPool) -> usize {
+        self.scan().nodes.iter().map(|node| node.size(pool)).sum()
+    }
+
+    /// Finds all objects reachable from the tracked objects
+    fn scan(&mut self) -> Heap {
+        let mut heap = Heap {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            unscanned: Vec::new(),
+        };
+        let mut indices = BTreeMap::new();
+
+        for tracked in self.tracked.iter() {
+            let node = match tracked {
+                Tracked::Table(table) => table.upgrade().map(Node::Table),
+                Tracked::Closure(closure) => closure.upgrade().map(Node::Closure),
+            };
+            if let Some(node) = node {
+                heap.insert(node, &mut indices);
+            }
+        }
+
+        let mut scanning = 0;
+        while scanning < heap.nodes.len() {
+            let mut children = Vec::new();
+            let scanned = heap.nodes[scanning].children(&mut children);
+            let edges = children
+                .into_iter()
+                .map(|child| heap.insert(child, &mut indices))
+                .collect();
+            heap.edges[scanning] = edges;
+            heap.unscanned[scanning] = !scanned;
+            scanning += 1;
+        }
+
+        heap
+    }
+}
+
+impl Debug for Collector {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Collector")
+            .field("tracked", &self.tracked.len())
+            .field("threshold", &self.threshold)
+            .field("running", &self.running)
+            .finish()
+    }
+}
+
+impl Heap {
+    /// Adds `node` to the heap if it is not there yet, returning its index
+    fn insert(&mut self, node: Node, indices: &mut BTreeMap<usize, usize>) -> usize {
+        *indices.entry(node.address()).or_insert_with(|| {
+            self.nodes.push(node);
+            self.edges.push(Vec::new());
+            self.unscanned.push(false);
+            self.nodes.len() - 1
+        })
+    }
+}
+
+impl Node {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Table(table) => Some(Self::Table(table.clone())),
+            Value::Closure(closure) => Some(Self::Closure(closure.clone())),
+            Value::UserData(userdata) => Some(Self::UserData(userdata.clone())),
+            _ => None,
+        }
+    }
+
+    fn address(&self) -> usize {
+        match self {
+            Self::Table(table) => Rc::as_ptr(table) as *const () as usize,
+            Self::Closure(closure) => Rc::as_ptr(closure) as *const () as usize,
+            Self::Upvalue(upvalue) => Rc::as_ptr(upvalue) as *const () as usize,
+            Self::UserData(userdata) => Rc::as_ptr(userdata) as *const () as usize,
+        }
+    }
+
+    fn strong_count(&self) -> usize {
+        match self {
+            Self::Table(table) => Rc::strong_count(table),
+            Self::Closure(closure) => Rc::strong_count(closure),
+            Self::Upvalue(upvalue) => Rc::strong_count(upvalue),
+            Self::UserData(userdata) => Rc::strong_count(userdata),
+        }
+    }
+
+    /// Pushes one node for each reference held by this object, returns
+    /// `false` if the object is borrowed and could not be scanned
+    fn children(&self, children: &mut Vec<Node>) -> bool {
+        match self {
+            Self::Table(table) => {
+                let Ok(table) = table.try_borrow() else {
+                    return false;
+                };
+                children.extend(table.array.iter().filter_map(Self::from_value));
+                for (ValueKey(key), value) in table.table.iter() {
+                    children.extend(Self::from_value(key));
+                    children.extend(Self::from_value(value));
+                }
+                children.extend(table.metatable().map(Self::Table));
+            }
+            Self::Closure(closure) => {
+                children.extend(closure.upvalues().iter().cloned().map(Self::Upvalue));
+            }
+            Self::Upvalue(upvalue) => {
+                let Ok(upvalue) = upvalue.try_borrow() else {
+                    return false;
+                };
+                if let Upvalue::Closed(value) = &*upvalue {
+                    children.extend(Self::from_value(value));
+                }
+            }
+            Self::UserData(userdata) => {
+                children.extend(userdata.metatable().map(Self::Table));
+            }
+        }
+        true
+    }
+
+    /// Drops the references held by this object into `released`
+    fn clear(&self, released: &mut Vec<Value>) {
+        match self {
+            Self::Table(table) => {
+                let Ok(mut table) = table.try_borrow_mut() else {
+                    unreachable!("Borrowed tables are never garbage.");
+                };
+                released.append(&mut table.array);
+                released.extend(
+                    core::mem::take(&mut table.table)
+                        .into_iter()
+                        .flat_map(|(key, value)| [key.0, value]),
+                );
+                released.extend(table.metatable().map(Value::Table));
+                table.set_metatable(None);
+            }
+            // Closures only hold references through their upvalues, which
+            // are garbage as well
+            Self::Closure(_) => (),
+            Self::Upvalue(upvalue) => {
+                let Ok(mut upvalue) = upvalue.try_borrow_mut() else {
+                    unreachable!("Borrowed upvalues are never garbage.");
+                };
+                if let Upvalue::Closed(value) = &mut *upvalue {
+                    released.push(core::mem::replace(value, Value::Nil));
+                }
+            }
+            Self::UserData(userdata) => {
+                released.extend(userdata.metatable().map(Value::Table));
+                userdata.set_metatable(None);
+            }
+        }
+    }
+
+    /// Estimate of the memory owned by this object, in bytes
+    fn size(&self, pool: &ConstantPool) -> usize {
+        match self {
+            Self::Table(table) => {
+                let Ok(table) = table.try_borrow() else {
+                    return size_of::<RefCell<Table>>();
+                };
+                size_of::<RefCell<Table>>()
+                    + table
+                        .array
+                        .iter()
+                        .map(|value| measured_size(value, pool))
+                        .sum::<usize>()
+                    + table
+                        .table
+                        .iter()
+                        .map(|(key, value)| {
+                            measured_size(&key.0, pool) + measured_size(value, pool)
+                        })
+                        .sum::<usize>()
+            }
+            Self::Closure(closure) => {
+                size_of::<Closure>() + closure.upvalues().len() * size_of::<Rc<()>>()
+            }
+            Self::Upvalue(_) => size_of::<RefCell<Upvalue>>(),
+            Self::UserData(_) => size_of::<UserData>(),
+        }
+    }
+}
+
+/// Estimate of the memory owned by `value`, in bytes, the objects it
+/// references are counted on their own
+pub(crate) fn value_size(value: &Value) -> usize {
+    match value {
+        Value::String(string) => size_of::<Value>() + string.len(),
+        _ => size_of::<Value>(),
+    }
+}
+
+/// Like [`value_size`], but strings from `pool` are not counted, as
+/// the pool counts them once
+pub(crate) fn measured_size(value: &Value, pool: &ConstantPool) -> usize {
+    match value {
+        Value::String(string) if pool.contains(string) => size_of::<Value>(),
+        value => value_size(value),
+    }
+}
@@ -0,0 +1,91 @@
+//! Debug hooks
+//!
+//! Like `lua_sethook`, the host can register a hook that is called when
+//! functions are called or return, when a new line starts running, or after
+//! a number of bytecodes ran. While the hook runs it can inspect the running
+//! function with [`Lua::running_function`], [`Lua::program_counter`],
+//! [`Lua::current_line`], and [`Lua::locals`]. Hooks are not called while
+//! another hook is running.
+
+use core::fmt::Debug;
+
+use alloc::rc::Rc;
+
+use crate::{Error, Lua};
+
+/// Callback registered with [`Lua::set_hook`]
+pub type Hook = Rc<dyn Fn(&mut Lua, HookEvent) -> Result<(), Error>>;
+
+/// Reason the hook was called
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookEvent {
+    /// A function was called, the hook runs before its first bytecode
+    Call,
+    /// A function was called by a tail call, taking the place of its caller
+    TailCall,
+    /// A function is about to return, after closing its variables
+    Return,
+    /// A bytecode from a new line is about to run, or the function jumped
+    /// back to a line that already ran
+    Line(usize),
+    /// [`HookMask::count`] bytecodes ran since the last count event
+    Count,
+}
+
+/// Events that call the hook
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct HookMask {
+    pub call: bool,
+    pub ret: bool,
+    pub line: bool,
+    /// Calls the hook every `count` bytecodes, `0` disables it
+    pub count: usize,
+}
+
+pub(crate) struct Hooks {
+    hook: Hook,
+    pub(crate) mask: HookMask,
+    /// Bytecodes left until the next count event
+    countdown: usize,
+    /// Whether the hook is running
+    pub(crate) running: bool,
+}
+
+impl Hooks {
+    pub(crate) fn new(hook: Hook, mask: HookMask) -> Self {
+        Self {
+            hook,
+            mask,
+            countdown: mask.count,
+            running: false,
+        }
+    }
+
+    pub(crate) fn hook(&self) -> Hook {
+        self.hook.clone()
+    }
+
+    /// Counts a bytecode, returning `true` if it completes a count event
+    pub(crate) fn count(&mut self) -> bool {
+        if self.mask.count == 0 {
+            return false;
+        }
+        self.countdown -= 1;
+        if self.countdown == 0 {
+            self.countdown = self.mask.count;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl Debug for Hooks {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("mask", &self.mask)
+            .field("countdown", &self.countdown)
+            .field("running", &self.running)
+            .finish()
+    }
+}
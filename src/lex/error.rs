@@ -1,5 +1,6 @@
 use core::fmt::Display;
 
+/// Error found by the lexer, which ends the lexemes of the source
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
     pub(crate) kind: ErrorKind,
@@ -10,39 +11,55 @@ pub struct Error {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ErrorKind {
     EofAtString,
-    ParseInt,
-    ParseFloat,
-    ProhibtedControlCharacterOnString,
-    OctalNotSupported,
-    LeadingZero,
-    MalformedFloat,
+    EofAtLongComment,
+    LongStringDelimiter,
+    UnfinishedString,
+    InvalidEscape,
+    HexDigitExpected,
+    DecimalEscapeTooLarge,
+    UnicodeEscapeBraces,
+    UnicodeEscapeTooLarge,
+    MalformedNumber,
+    UnexpectedCharacter,
+}
+
+impl Error {
+    /// Line of the error, counted from 0
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the error
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.kind.message()
+    }
+}
+
+impl ErrorKind {
+    pub(crate) fn message(&self) -> &'static str {
+        match self {
+            Self::EofAtString => "Reached End of File while reading a String.",
+            Self::EofAtLongComment => "Reached End of File while reading a long comment.",
+            Self::LongStringDelimiter => "Invalid long string delimiter.",
+            Self::MalformedNumber => "Number was malformed.",
+            Self::UnfinishedString => "String was not closed before the end of the line.",
+            Self::InvalidEscape => "Invalid escape sequence.",
+            Self::HexDigitExpected => "Hexadecimal digit expected on escape sequence.",
+            Self::DecimalEscapeTooLarge => "Decimal escape too large.",
+            Self::UnicodeEscapeBraces => "Missing braces on `\\u{XXX}` escape sequence.",
+            Self::UnicodeEscapeTooLarge => "UTF-8 value too large.",
+            Self::UnexpectedCharacter => "Character can't start a lexeme.",
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self.kind {
-            ErrorKind::EofAtString => {
-                write!(f, "Reached End of File while reading a String.",)
-            }
-            ErrorKind::ParseInt => {
-                write!(f, "Could not parse an number into an integer.",)
-            }
-            ErrorKind::ParseFloat => {
-                write!(f, "Could not parse an number into an float.",)
-            }
-            ErrorKind::LeadingZero => {
-                write!(f, "Non hexadecimal numbers can't start with leading zeros.",)
-            }
-            ErrorKind::OctalNotSupported => {
-                write!(f, "Octal numbers are not supported.",)
-            }
-            ErrorKind::MalformedFloat => {
-                write!(f, "Floating-point number was malformed.",)
-            }
-            ErrorKind::ProhibtedControlCharacterOnString => {
-                write!(f, "A control character was found in a string.",)
-            }
-        }
+        write!(f, "{}", self.kind.message())
     }
 }
 
@@ -1,3 +1,4 @@
+/// Lexeme read from the source, with its position
 #[derive(Debug, Clone, PartialEq)]
 pub struct Lexeme<'a> {
     pub(crate) line: usize,
@@ -7,6 +8,27 @@ pub struct Lexeme<'a> {
     pub(crate) lexeme_type: LexemeType<'a>,
 }
 
+impl<'a> Lexeme<'a> {
+    /// Line of the lexeme, counted from 0
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column where the lexeme ends
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Position of the first byte of the lexeme on the source
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn lexeme_type(&self) -> &LexemeType<'a> {
+        &self.lexeme_type
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum LexemeType<'a> {
     // Keywords
@@ -106,10 +128,19 @@ pub enum LexemeType<'a> {
     Float(f64),
     /// String
     String(&'a str),
+    /// Long string, without its brackets and the newline that follows the
+    /// opening bracket, newlines are kept as written and escapes are not
+    /// processed
+    LongString(&'a str),
 
     /// Name of value or table key
     Name(&'a str),
 
+    /// Spaces, tabs, and newlines, only with [`Lex::with_trivia`](super::Lex::with_trivia)
+    Whitespace(&'a str),
+    /// Comment, with its `--`, only with [`Lex::with_trivia`](super::Lex::with_trivia)
+    Comment(&'a str),
+
     /// End of file
     Eof,
 }
@@ -1,20 +1,40 @@
+//! Lexer of Lua source code, which splits it into [`Lexeme`]s that borrow
+//! from the source
+//!
+//! The lexer does not allocate, it is available without the `alloc` feature
+//! for tools like syntax highlighters on targets without an allocator.
+//! Whitespace and comments are skipped, unless they are asked for with
+//! [`Lex::with_trivia`], which lets formatters write back the source.
+//!
+//! ```
+//! use no_deps_lua::lex::{Lex, LexemeType};
+//!
+//! let names = Lex::new("local x = y\n")
+//!     .filter_map(Result::ok)
+//!     .filter(|lexeme| matches!(lexeme.lexeme_type(), LexemeType::Name(_)))
+//!     .count();
+//! assert_eq!(names, 2);
+//! ```
+
 mod error;
 mod lexeme;
 mod states;
 #[cfg(test)]
 mod tests;
 
-use alloc::{vec, vec::Vec};
 use core::{iter::Peekable, str::Chars};
 use error::ErrorKind;
 use states::StateError;
 
+use crate::ext::{Numeral, ParseNumeral};
+
 use self::states::State;
 pub use self::{
     error::Error,
     lexeme::{Lexeme, LexemeType},
 };
 
+/// Iterator over the lexemes of a source, ending with [`LexemeType::Eof`]
 pub struct Lex<'a> {
     program: &'a str,
     chars: Peekable<Chars<'a>>,
@@ -23,7 +43,14 @@ pub struct Lex<'a> {
     seek: usize,
     /// Start of lexeme being considered
     start: usize,
-    lines: Vec<usize>,
+    /// Line being read, counted from 0
+    line: usize,
+    /// Bytes read on the current line, a newline counts for the line it ends
+    column: usize,
+    /// Bytes read on the previous line, for lexemes ended by a newline
+    previous_column: usize,
+    /// Whether whitespace and comments are returned as lexemes
+    trivia: bool,
 }
 
 impl<'a> Lex<'a> {
@@ -34,30 +61,53 @@ impl<'a> Lex<'a> {
             state: State::Start,
             seek: 0,
             start: 0,
-            lines: vec![0],
+            line: 0,
+            column: 0,
+            previous_column: 0,
+            trivia: false,
         }
     }
 
+    /// Also returns whitespace and comments, as [`LexemeType::Whitespace`]
+    /// and [`LexemeType::Comment`].
+    ///
+    /// With trivia, every byte of the source is on a lexeme, and each lexeme
+    /// ends where the next one starts, so the source can be written back
+    /// exactly from the [`Lexeme::start`] of each lexeme.
+    pub fn with_trivia(mut self) -> Self {
+        self.trivia = true;
+        self
+    }
+
     #[cfg(test)]
     pub fn remaining(&self) -> usize {
         self.program.len() - self.seek
     }
 
+    /// End of the lexeme being built, which is before the character that
+    /// ended it, unless it was ended by the end of the program
+    fn lexeme_end(&self) -> usize {
+        if self.state == State::Eof {
+            self.seek
+        } else {
+            self.seek
+                - self.program[..self.seek]
+                    .chars()
+                    .next_back()
+                    .map_or(0, char::len_utf8)
+        }
+    }
+
     fn build_lexeme(&self, state: State) -> Option<Result<Lexeme<'a>, Error>> {
-        let mut line = self.lines.len() - 1;
-        let column = {
-            let mut rev = self.lines.iter().rev();
-            if let Some(column) = rev.next().filter(|column| **column != 0) {
-                *column
-            } else {
-                let Some(column) = rev.next() else {
-                    unreachable!(
-                        "Lexer must have read more than one line for it to have the current line at column 0."
-                    );
-                };
-                line -= 1;
-                *column
-            }
+        let (line, column) = if self.column != 0 {
+            (self.line, self.column)
+        } else {
+            let Some(line) = self.line.checked_sub(1) else {
+                unreachable!(
+                    "Lexer must have read more than one line for it to have the current line at column 0."
+                );
+            };
+            (line, self.previous_column)
         };
 
         let make_lexeme = |lexeme_type| Lexeme {
@@ -68,11 +118,53 @@ impl<'a> Lex<'a> {
         };
 
         match state {
-            State::Start | State::ShortComment => None,
+            State::Start => None,
+            State::Whitespace
+            | State::CommentStart
+            | State::LongCommentOpen(_)
+            | State::LongCommentClose(_, _)
+            | State::ShortComment
+                if !self.trivia =>
+            {
+                None
+            }
+            State::Whitespace => Some(Ok(make_lexeme(LexemeType::Whitespace(
+                &self.program[self.start - 1..self.lexeme_end()],
+            )))),
+            State::CommentStart | State::LongCommentOpen(_) | State::ShortComment => {
+                Some(Ok(make_lexeme(LexemeType::Comment(
+                    &self.program[self.start - 1..self.lexeme_end()],
+                ))))
+            }
+            // Long comments end on their closing bracket
+            State::LongCommentClose(_, _) => Some(Ok(make_lexeme(LexemeType::Comment(
+                &self.program[self.start - 1..self.seek],
+            )))),
+            State::LongStringClose(level, _) => {
+                let start = self.start - 1;
+                // Without the brackets, which are ASCII
+                let data = &self.program[start + level + 2..self.seek - level - 2];
+                let data = data
+                    .strip_prefix("\r\n")
+                    .or_else(|| data.strip_prefix("\n\r"))
+                    .or_else(|| data.strip_prefix('\n'))
+                    .or_else(|| data.strip_prefix('\r'))
+                    .unwrap_or(data);
+
+                Some(Ok(Lexeme {
+                    line,
+                    column,
+                    start,
+                    lexeme_type: LexemeType::LongString(data),
+                }))
+            }
             State::Add => Some(Ok(make_lexeme(LexemeType::Add))),
             State::Sub => Some(Ok(make_lexeme(LexemeType::Sub))),
             State::Mul => Some(Ok(make_lexeme(LexemeType::Mul))),
             State::Div => Some(Ok(make_lexeme(LexemeType::Div))),
+            State::Idiv => Some(Ok(make_lexeme(LexemeType::Idiv))),
+            State::Mod => Some(Ok(make_lexeme(LexemeType::Mod))),
+            State::Pow => Some(Ok(make_lexeme(LexemeType::Pow))),
             State::Concat => Some(Ok(make_lexeme(LexemeType::Concat))),
             State::BitAnd => Some(Ok(make_lexeme(LexemeType::BitAnd))),
             State::BitOr => Some(Ok(make_lexeme(LexemeType::BitOr))),
@@ -99,40 +191,26 @@ impl<'a> Lex<'a> {
             State::SemiColon => Some(Ok(make_lexeme(LexemeType::SemiColon))),
             State::Dot => Some(Ok(make_lexeme(LexemeType::Dot))),
             State::Dots => Some(Ok(make_lexeme(LexemeType::Dots))),
-            State::Number => {
+            State::Number | State::NumberExponent | State::HexNumber | State::HexNumberExponent => {
                 let start = self.start - 1;
-                let end = self.seek - 1;
-                let data = &self.program[start..end];
-                let Ok(integer) = data.parse() else {
-                    return Some(Err(Error {
-                        kind: ErrorKind::ParseInt,
-                        line,
-                        column: column - 1,
-                    }));
-                };
+                let end = self.lexeme_end();
 
-                Some(Ok(make_lexeme(LexemeType::Integer(integer))))
-            }
-            State::Float => {
-                let start = self.start - 1;
-                let end = self.seek - 1;
-                let data = &self.program[start..end];
-                let Ok(float) = data.parse() else {
-                    return Some(Err(Error {
-                        kind: ErrorKind::ParseFloat,
+                match self.program[start..end].parse_numeral() {
+                    Some(Numeral::Integer(integer)) => {
+                        Some(Ok(make_lexeme(LexemeType::Integer(integer))))
+                    }
+                    Some(Numeral::Float(float)) => Some(Ok(make_lexeme(LexemeType::Float(float)))),
+                    None => Some(Err(Error {
+                        kind: ErrorKind::MalformedNumber,
                         line,
                         column: column - 1,
-                    }));
-                };
-
-                Some(Ok(make_lexeme(LexemeType::Float(float))))
+                    })),
+                }
             }
-            State::String(quotes)
-            | State::StringAscii(quotes, _, _)
-            | State::StringUtf8(quotes, 2) => {
+            // Strings end on their closing quotes
+            State::String(_) => {
                 let start = self.start - 1;
-                let end = self.seek - 1;
-                let data = &self.program[start..end].trim_matches(quotes);
+                let data = &self.program[start + 1..self.lexeme_end()];
 
                 Some(Ok(Lexeme {
                     line,
@@ -143,11 +221,7 @@ impl<'a> Lex<'a> {
             }
             State::Name => {
                 let start = self.start - 1;
-                let end = if self.state == State::Eof {
-                    self.seek
-                } else {
-                    self.seek - 1
-                };
+                let end = self.lexeme_end();
                 let data = &self.program[start..end];
 
                 let make_lexeme = |lexeme_type| Lexeme {
@@ -205,24 +279,22 @@ impl<'a> Iterator for Lex<'a> {
 
             let consumed = if let Some(c) = self.chars.next() {
                 self.seek += c.len_utf8();
-                if let Some(column) = self.lines.last_mut() {
-                    *column += c.len_utf8();
-                } else {
-                    unreachable!("List of line columns should never be empty.");
-                }
+                self.column += c.len_utf8();
                 if c == '\n' {
-                    self.lines.push(0);
+                    self.previous_column = self.column;
+                    self.column = 0;
+                    self.line += 1;
                 }
                 self.state.consume(c)
-            } else if self.start != self.seek {
+            } else if !matches!(self.state, State::Start | State::Eof) {
                 self.state.consume_eof()
             } else {
                 let start = self.start;
                 self.start = usize::MAX;
 
                 break Some(Ok(Lexeme {
-                    line: self.lines.len() - 1,
-                    column: self.lines.last().copied().unwrap_or_default(),
+                    line: self.line,
+                    column: self.column,
                     start,
                     lexeme_type: LexemeType::Eof,
                 }));
@@ -239,24 +311,31 @@ impl<'a> Iterator for Lex<'a> {
                         }
                     }
                 }
-                Err(StateError::EofAtString) => {
-                    return Some(Err(Error {
-                        kind: ErrorKind::EofAtString,
-                        line: self.lines.len() - 1,
-                        column: self.lines.last().copied().unwrap_or_default(),
-                    }));
-                }
-                Err(
-                    err @ (StateError::EscapedChar(_)
-                    | StateError::HexCharacter(_)
-                    | StateError::AsciiOutOfBounds(_)),
-                ) => {
+                Err(err) => {
                     log::error!("{}", err);
-                    return Some(Err(Error {
-                        kind: ErrorKind::ProhibtedControlCharacterOnString,
-                        line: self.lines.len() - 1,
-                        column: self.lines.last().copied().unwrap_or_default(),
-                    }));
+                    let kind = match err {
+                        StateError::EofAtString => ErrorKind::EofAtString,
+                        StateError::EofAtLongComment => ErrorKind::EofAtLongComment,
+                        StateError::LongStringDelimiter => ErrorKind::LongStringDelimiter,
+                        StateError::NewlineInString => ErrorKind::UnfinishedString,
+                        StateError::EscapedChar(_) => ErrorKind::InvalidEscape,
+                        StateError::HexCharacter(_) => ErrorKind::HexDigitExpected,
+                        StateError::DecimalOutOfBounds(_) => ErrorKind::DecimalEscapeTooLarge,
+                        StateError::UnicodeBraces => ErrorKind::UnicodeEscapeBraces,
+                        StateError::UnicodeOutOfBounds => ErrorKind::UnicodeEscapeTooLarge,
+                        StateError::UnexpectedCharacter(_) => ErrorKind::UnexpectedCharacter,
+                    };
+                    // Strings that are not closed are reported where they start,
+                    // the newline or the end of the file can be lines below
+                    let (line, column) = match kind {
+                        ErrorKind::EofAtString | ErrorKind::UnfinishedString => {
+                            let before = &self.program[..self.start.saturating_sub(1)];
+                            let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+                            (before.matches('\n').count(), before.len() - line_start + 1)
+                        }
+                        _ => (self.line, self.column),
+                    };
+                    return Some(Err(Error { kind, line, column }));
                 }
             }
         }
@@ -2,7 +2,10 @@ use core::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum State {
+    /// Before the first lexeme, and after a string, which ends on its
+    /// closing quotes
     Start,
+    Whitespace,
     Add,
     Sub,
     Mul,
@@ -37,13 +40,40 @@ pub enum State {
     Dot,
     Dots,
     Number,
-    Float,
+    NumberExponent,
+    HexNumber,
+    HexNumberExponent,
+    /// Short string, with the quotes that close it
     String(char),
     StringEscape(char),
-    StringAscii(char, u8, u16),
-    StringUtf8(char, u8),
+    /// Digits of a decimal escape read so far, and their value
+    StringDecimal(char, u8, u16),
+    /// Digits of a `\x` escape read so far
+    StringHex(char, u8),
+    /// `\u`, which must be followed by `{`
+    StringUnicodeOpen(char),
+    /// Digits of a `\u{XXX}` escape read so far, and their value
+    StringUnicode(char, u8, u32),
+    /// Whitespace after `\z`
+    StringSkipSpace(char),
+    /// Escaped newline, which can be followed by the other newline character
+    StringEscapedNewline(char, char),
     Name,
+    /// `[` followed by the `=` of the level of a long string
+    LongStringOpen(usize),
+    LongString(usize),
+    /// `]` followed by the `=` read so far, which close the long string
+    /// once they match its level and are followed by another `]`
+    LongStringClose(usize, usize),
+    /// `--`, which can start a long comment
+    CommentStart,
+    /// `--[` followed by the `=` of the level of a long comment
+    LongCommentOpen(usize),
+    LongComment(usize),
+    LongCommentClose(usize, usize),
     ShortComment,
+    /// Character that can't start a lexeme, fails on the next step
+    Invalid(char),
     Eof,
 }
 
@@ -51,6 +81,7 @@ impl State {
     pub fn consume(&mut self, c: char) -> Result<Option<State>, StateError> {
         match self {
             Self::Start => Ok(Self::start_consume(c)),
+            Self::Whitespace => Ok(Self::whitespace_consume(c)),
             Self::Add => Ok(Self::add_consume(c)),
             Self::Sub => Ok(self.sub_consume(c)),
             Self::Mul => Ok(Self::mul_consume(c)),
@@ -74,7 +105,7 @@ impl State {
             Self::NotEqual => Ok(Self::not_equal_consume(c)),
             Self::LParen => Ok(Self::lparen_consume(c)),
             Self::RParen => Ok(Self::rparen_consume(c)),
-            Self::LSquare => Ok(Self::lsquare_consume(c)),
+            Self::LSquare => Ok(self.lsquare_consume(c)),
             Self::RSquare => Ok(Self::rsquare_consume(c)),
             Self::LCurly => Ok(Self::lcurly_consume(c)),
             Self::RCurly => Ok(Self::rcurly_consume(c)),
@@ -85,28 +116,77 @@ impl State {
             Self::Dot => Ok(self.dot_consume(c)),
             Self::Dots => Ok(Self::dots_consume(c)),
             Self::Number => Ok(self.number_consume(c)),
-            Self::Float => Ok(Self::float_consume(c)),
+            Self::NumberExponent => Ok(self.number_exponent_consume(c)),
+            Self::HexNumber => Ok(self.hex_number_consume(c)),
+            Self::HexNumberExponent => Ok(self.hex_number_exponent_consume(c)),
             Self::String(start_quotes) => {
                 let start_quotes = *start_quotes;
-                Ok(self.string_consume(c, start_quotes))
+                self.string_consume(c, start_quotes)
             }
             Self::StringEscape(start_quotes) => {
                 let start_quotes = *start_quotes;
                 self.string_escape_consume(c, start_quotes)
             }
-            Self::StringAscii(start_quotes, count, sum) => {
+            Self::StringDecimal(start_quotes, count, sum) => {
                 let start_quotes = *start_quotes;
                 let count = *count;
                 let sum = *sum;
-                self.string_ascii_consume(c, start_quotes, count, sum)
+                self.string_decimal_consume(c, start_quotes, count, sum)
             }
-            Self::StringUtf8(start_quotes, count) => {
+            Self::StringHex(start_quotes, count) => {
                 let start_quotes = *start_quotes;
                 let count = *count;
-                self.string_utf8_consume(c, start_quotes, count)
+                self.string_hex_consume(c, start_quotes, count)
+            }
+            Self::StringUnicodeOpen(start_quotes) => {
+                let start_quotes = *start_quotes;
+                self.string_unicode_open_consume(c, start_quotes)
+            }
+            Self::StringUnicode(start_quotes, count, code_point) => {
+                let start_quotes = *start_quotes;
+                let count = *count;
+                let code_point = *code_point;
+                self.string_unicode_consume(c, start_quotes, count, code_point)
+            }
+            Self::StringSkipSpace(start_quotes) => {
+                let start_quotes = *start_quotes;
+                self.string_skip_space_consume(c, start_quotes)
+            }
+            Self::StringEscapedNewline(start_quotes, newline) => {
+                let start_quotes = *start_quotes;
+                let newline = *newline;
+                self.string_escaped_newline_consume(c, start_quotes, newline)
             }
             Self::Name => Ok(Self::name_consume(c)),
+            Self::LongStringOpen(level) => {
+                let level = *level;
+                self.long_string_open_consume(c, level)
+            }
+            Self::LongString(level) => {
+                let level = *level;
+                Ok(self.long_string_consume(c, level))
+            }
+            Self::LongStringClose(level, equals) => {
+                let level = *level;
+                let equals = *equals;
+                Ok(self.long_string_close_consume(c, level, equals))
+            }
+            Self::CommentStart => Ok(self.comment_start_consume(c)),
+            Self::LongCommentOpen(level) => {
+                let level = *level;
+                Ok(self.long_comment_open_consume(c, level))
+            }
+            Self::LongComment(level) => {
+                let level = *level;
+                Ok(self.long_comment_consume(c, level))
+            }
+            Self::LongCommentClose(level, equals) => {
+                let level = *level;
+                let equals = *equals;
+                Ok(self.long_comment_close_consume(c, level, equals))
+            }
             Self::ShortComment => Ok(Self::short_comment_consume(c)),
+            Self::Invalid(invalid) => Err(StateError::UnexpectedCharacter(*invalid)),
             Self::Eof => Ok(None),
         }
         .map(|new_state_opt| new_state_opt.map(|new_state| self.replace_state(new_state)))
@@ -114,7 +194,21 @@ impl State {
 
     pub fn consume_eof(&mut self) -> Result<Option<Self>, StateError> {
         match self {
-            Self::String(_) => Err(StateError::EofAtString),
+            Self::String(_)
+            | Self::StringEscape(_)
+            | Self::StringDecimal(_, _, _)
+            | Self::StringHex(_, _)
+            | Self::StringUnicodeOpen(_)
+            | Self::StringUnicode(_, _, _)
+            | Self::StringSkipSpace(_)
+            | Self::StringEscapedNewline(_, _)
+            | Self::LongString(_)
+            | Self::LongStringClose(_, _) => Err(StateError::EofAtString),
+            Self::LongStringOpen(_) => Err(StateError::LongStringDelimiter),
+            Self::LongComment(_) | Self::LongCommentClose(_, _) => {
+                Err(StateError::EofAtLongComment)
+            }
+            Self::Invalid(invalid) => Err(StateError::UnexpectedCharacter(*invalid)),
             Self::Eof => Ok(None),
             _ => Ok(Some(self.replace_state(Self::Eof))),
         }
@@ -128,7 +222,7 @@ impl State {
 
     fn start_consume(c: char) -> Option<Self> {
         match c {
-            ' ' | '\t' | '\r' | '\n' => Some(Self::Start),
+            ' ' | '\t' | '\r' | '\n' => Some(Self::Whitespace),
             '+' => Some(Self::Add),
             '-' => Some(Self::Sub),
             '*' => Some(Self::Mul),
@@ -155,7 +249,14 @@ impl State {
             '0'..='9' => Some(Self::Number),
             'a'..='z' | 'A'..='Z' | '_' => Some(Self::Name),
             string_start @ ('"' | '\'') => Some(Self::String(string_start)),
-            other => unimplemented!("{:?}", other),
+            other => Some(Self::Invalid(other)),
+        }
+    }
+
+    fn whitespace_consume(c: char) -> Option<Self> {
+        match c {
+            ' ' | '\t' | '\r' | '\n' => None,
+            _ => Self::start_consume(c),
         }
     }
 
@@ -166,7 +267,7 @@ impl State {
     fn sub_consume(&mut self, c: char) -> Option<Self> {
         match c {
             '-' => {
-                self.replace_state(Self::ShortComment);
+                self.replace_state(Self::CommentStart);
                 None
             }
             _ => Self::start_consume(c),
@@ -301,8 +402,18 @@ impl State {
         Self::start_consume(c)
     }
 
-    fn lsquare_consume(c: char) -> Option<Self> {
-        Self::start_consume(c)
+    fn lsquare_consume(&mut self, c: char) -> Option<Self> {
+        match c {
+            '[' => {
+                self.replace_state(Self::LongString(0));
+                None
+            }
+            '=' => {
+                self.replace_state(Self::LongStringOpen(1));
+                None
+            }
+            _ => Self::start_consume(c),
+        }
     }
 
     fn rsquare_consume(c: char) -> Option<Self> {
@@ -345,6 +456,10 @@ impl State {
                 self.replace_state(Self::Concat);
                 None
             }
+            '0'..='9' => {
+                self.replace_state(Self::Number);
+                None
+            }
             _ => Self::start_consume(c),
         }
     }
@@ -353,41 +468,77 @@ impl State {
         Self::start_consume(c)
     }
 
+    /// Numerals are read like Lua does, taking every letter, digit, and dot,
+    /// and the sign of the exponent, malformed numerals are reported when the
+    /// lexeme is built
     fn number_consume(&mut self, c: char) -> Option<Self> {
         match c {
-            '0'..='9' => None,
-            '.' => {
-                self.replace_state(Self::Float);
+            'x' | 'X' => {
+                self.replace_state(Self::HexNumber);
+                None
+            }
+            'e' | 'E' => {
+                self.replace_state(Self::NumberExponent);
                 None
             }
+            '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' | '.' => None,
             _ => Self::start_consume(c),
         }
     }
 
-    fn float_consume(c: char) -> Option<Self> {
+    fn number_exponent_consume(&mut self, c: char) -> Option<Self> {
         match c {
-            '0'..='9' => None,
+            '+' | '-' => {
+                self.replace_state(Self::Number);
+                None
+            }
+            _ => self.number_consume(c),
+        }
+    }
+
+    fn hex_number_consume(&mut self, c: char) -> Option<Self> {
+        match c {
+            'p' | 'P' => {
+                self.replace_state(Self::HexNumberExponent);
+                None
+            }
+            '0'..='9' | 'a'..='z' | 'A'..='Z' | '_' | '.' => None,
             _ => Self::start_consume(c),
         }
     }
 
-    fn string_consume(&mut self, c: char, start_quotes: char) -> Option<Self> {
+    fn hex_number_exponent_consume(&mut self, c: char) -> Option<Self> {
+        match c {
+            '+' | '-' => {
+                self.replace_state(Self::HexNumber);
+                None
+            }
+            _ => self.hex_number_consume(c),
+        }
+    }
+
+    /// Strings can't have newlines that are not escaped
+    fn string_consume(&mut self, c: char, start_quotes: char) -> Result<Option<Self>, StateError> {
         match c {
             quotes @ ('"' | '\'') => {
                 if quotes == start_quotes {
-                    Some(Self::Start)
+                    Ok(Some(Self::Start))
                 } else {
-                    None
+                    Ok(None)
                 }
             }
             '\\' => {
                 self.replace_state(Self::StringEscape(start_quotes));
-                None
+                Ok(None)
             }
-            _ => None,
+            '\n' | '\r' => Err(StateError::NewlineInString),
+            _ => Ok(None),
         }
     }
 
+    /// Escapes that are done with their last character go back to the
+    /// string, the ones that end on the next character go back to the
+    /// string before it is read
     fn string_escape_consume(
         &mut self,
         c: char,
@@ -395,11 +546,24 @@ impl State {
     ) -> Result<Option<Self>, StateError> {
         match c {
             'x' => {
-                self.replace_state(Self::StringUtf8(start_quotes, 0));
+                self.replace_state(Self::StringHex(start_quotes, 0));
                 Ok(None)
             }
-            '0'..='9' => {
-                self.replace_state(Self::StringAscii(start_quotes, 0, 0));
+            'u' => {
+                self.replace_state(Self::StringUnicodeOpen(start_quotes));
+                Ok(None)
+            }
+            'z' => {
+                self.replace_state(Self::StringSkipSpace(start_quotes));
+                Ok(None)
+            }
+            d @ '0'..='9' => {
+                let digit = d as u16 - u16::from(b'0');
+                self.replace_state(Self::StringDecimal(start_quotes, 1, digit));
+                Ok(None)
+            }
+            newline @ ('\n' | '\r') => {
+                self.replace_state(Self::StringEscapedNewline(start_quotes, newline));
                 Ok(None)
             }
             'a' | 'b' | 'f' | 'n' | 'r' | 't' | 'v' | '\\' | '"' | '\'' => {
@@ -410,58 +574,127 @@ impl State {
         }
     }
 
-    fn string_ascii_consume(
+    /// Decimal escapes have up to 3 digits, and must fit a byte
+    fn string_decimal_consume(
         &mut self,
         c: char,
         start_quotes: char,
         count: u8,
         sum: u16,
     ) -> Result<Option<Self>, StateError> {
-        if count == 3 {
-            Ok(self.string_consume(c, start_quotes))
-        } else {
-            match c {
-                quotes @ ('"' | '\'') => {
-                    if quotes == start_quotes {
-                        Ok(Some(Self::Start))
-                    } else {
-                        Ok(None)
-                    }
-                }
-                d @ '0'..='9' => {
-                    let digit = d as u8 - b'0';
-                    let sum = sum * 10 + u16::from(digit);
-                    if sum <= 255 {
-                        self.replace_state(Self::StringAscii(start_quotes, count + 1, sum));
-                        Ok(None)
-                    } else {
-                        Err(StateError::AsciiOutOfBounds(sum))
-                    }
+        match c {
+            d @ '0'..='9' if count < 3 => {
+                let sum = sum * 10 + (d as u16 - u16::from(b'0'));
+                if sum <= 255 {
+                    self.replace_state(Self::StringDecimal(start_quotes, count + 1, sum));
+                    Ok(None)
+                } else {
+                    Err(StateError::DecimalOutOfBounds(sum))
                 }
-                _ => Ok(self.string_consume(c, start_quotes)),
+            }
+            _ => {
+                self.replace_state(Self::String(start_quotes));
+                self.string_consume(c, start_quotes)
             }
         }
     }
 
-    fn string_utf8_consume(
+    /// Hexadecimal escapes have exactly 2 digits
+    fn string_hex_consume(
         &mut self,
         c: char,
         start_quotes: char,
         count: u8,
     ) -> Result<Option<Self>, StateError> {
-        if count == 2 {
-            Ok(self.string_consume(c, start_quotes))
+        if !c.is_ascii_hexdigit() {
+            return Err(StateError::HexCharacter(c));
+        }
+        if count == 1 {
+            self.replace_state(Self::String(start_quotes));
         } else {
-            match c {
-                'a'..='f' | 'A'..='F' | '0'..='9' => {
-                    self.replace_state(Self::StringUtf8(start_quotes, count + 1));
-                    Ok(None)
+            self.replace_state(Self::StringHex(start_quotes, count + 1));
+        }
+        Ok(None)
+    }
+
+    fn string_unicode_open_consume(
+        &mut self,
+        c: char,
+        start_quotes: char,
+    ) -> Result<Option<Self>, StateError> {
+        match c {
+            '{' => {
+                self.replace_state(Self::StringUnicode(start_quotes, 0, 0));
+                Ok(None)
+            }
+            _ => Err(StateError::UnicodeBraces),
+        }
+    }
+
+    /// Code points of `\u{XXX}` can go up to 2^31, like Lua allows
+    fn string_unicode_consume(
+        &mut self,
+        c: char,
+        start_quotes: char,
+        count: u8,
+        code_point: u32,
+    ) -> Result<Option<Self>, StateError> {
+        match c {
+            '}' if count > 0 => {
+                self.replace_state(Self::String(start_quotes));
+                Ok(None)
+            }
+            _ => {
+                let Some(digit) = c.to_digit(16) else {
+                    return Err(if count == 0 {
+                        StateError::HexCharacter(c)
+                    } else {
+                        StateError::UnicodeBraces
+                    });
+                };
+                let code_point = code_point * 16 + digit;
+                if code_point > 0x7FFF_FFFF {
+                    return Err(StateError::UnicodeOutOfBounds);
                 }
-                _ => Err(StateError::HexCharacter(c)),
+                self.replace_state(Self::StringUnicode(
+                    start_quotes,
+                    count.saturating_add(1),
+                    code_point,
+                ));
+                Ok(None)
+            }
+        }
+    }
+
+    /// `\z` skips the whitespace that follows it, newlines included
+    fn string_skip_space_consume(
+        &mut self,
+        c: char,
+        start_quotes: char,
+    ) -> Result<Option<Self>, StateError> {
+        match c {
+            ' ' | '\t' | '\n' | '\r' | '\u{b}' | '\u{c}' => Ok(None),
+            _ => {
+                self.replace_state(Self::String(start_quotes));
+                self.string_consume(c, start_quotes)
             }
         }
     }
 
+    /// `\r\n` and `\n\r` after a `\` are a single newline
+    fn string_escaped_newline_consume(
+        &mut self,
+        c: char,
+        start_quotes: char,
+        newline: char,
+    ) -> Result<Option<Self>, StateError> {
+        self.replace_state(Self::String(start_quotes));
+        match c {
+            '\n' | '\r' if c != newline => Ok(None),
+            _ => self.string_consume(c, start_quotes),
+        }
+    }
+
     fn name_consume(c: char) -> Option<Self> {
         match c {
             'a'..='z' | 'A'..='Z' | '_' | '0'..='9' => None,
@@ -469,9 +702,113 @@ impl State {
         }
     }
 
+    fn long_string_open_consume(
+        &mut self,
+        c: char,
+        level: usize,
+    ) -> Result<Option<Self>, StateError> {
+        match c {
+            '=' => {
+                self.replace_state(Self::LongStringOpen(level + 1));
+                Ok(None)
+            }
+            '[' => {
+                self.replace_state(Self::LongString(level));
+                Ok(None)
+            }
+            _ => Err(StateError::LongStringDelimiter),
+        }
+    }
+
+    fn long_string_consume(&mut self, c: char, level: usize) -> Option<Self> {
+        if c == ']' {
+            self.replace_state(Self::LongStringClose(level, 0));
+        }
+        None
+    }
+
+    /// Long strings end on the closing bracket, like strings end on
+    /// their closing quotes
+    fn long_string_close_consume(&mut self, c: char, level: usize, equals: usize) -> Option<Self> {
+        match c {
+            ']' if equals == level => Some(Self::Start),
+            ']' => {
+                self.replace_state(Self::LongStringClose(level, 0));
+                None
+            }
+            '=' => {
+                self.replace_state(Self::LongStringClose(level, equals + 1));
+                None
+            }
+            _ => {
+                self.replace_state(Self::LongString(level));
+                None
+            }
+        }
+    }
+
+    fn comment_start_consume(&mut self, c: char) -> Option<Self> {
+        match c {
+            '[' => {
+                self.replace_state(Self::LongCommentOpen(0));
+                None
+            }
+            _ => self.fallback_to_short_comment(c),
+        }
+    }
+
+    /// Comments whose brackets are not those of a long comment are short
+    fn long_comment_open_consume(&mut self, c: char, level: usize) -> Option<Self> {
+        match c {
+            '=' => {
+                self.replace_state(Self::LongCommentOpen(level + 1));
+                None
+            }
+            '[' => {
+                self.replace_state(Self::LongComment(level));
+                None
+            }
+            _ => self.fallback_to_short_comment(c),
+        }
+    }
+
+    fn long_comment_consume(&mut self, c: char, level: usize) -> Option<Self> {
+        if c == ']' {
+            self.replace_state(Self::LongCommentClose(level, 0));
+        }
+        None
+    }
+
+    fn long_comment_close_consume(&mut self, c: char, level: usize, equals: usize) -> Option<Self> {
+        match c {
+            ']' if equals == level => Some(Self::Start),
+            ']' => {
+                self.replace_state(Self::LongCommentClose(level, 0));
+                None
+            }
+            '=' => {
+                self.replace_state(Self::LongCommentClose(level, equals + 1));
+                None
+            }
+            _ => {
+                self.replace_state(Self::LongComment(level));
+                None
+            }
+        }
+    }
+
+    fn fallback_to_short_comment(&mut self, c: char) -> Option<Self> {
+        let new_state = Self::short_comment_consume(c);
+        if new_state.is_none() {
+            self.replace_state(Self::ShortComment);
+        }
+        new_state
+    }
+
+    /// Short comments end before the newline, which is whitespace
     fn short_comment_consume(c: char) -> Option<Self> {
         match c {
-            '\n' => Some(Self::Start),
+            '\n' => Self::start_consume(c),
             _ => None,
         }
     }
@@ -480,20 +817,34 @@ impl State {
 #[derive(Debug)]
 pub enum StateError {
     EofAtString,
+    EofAtLongComment,
+    LongStringDelimiter,
+    NewlineInString,
     EscapedChar(char),
     HexCharacter(char),
-    AsciiOutOfBounds(u16),
+    DecimalOutOfBounds(u16),
+    UnicodeBraces,
+    UnicodeOutOfBounds,
+    UnexpectedCharacter(char),
 }
 
 impl Display for StateError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::EofAtString => write!(f, "Reached end of file while parsing a string."),
+            Self::EofAtLongComment => {
+                write!(f, "Reached end of file while parsing a long comment.")
+            }
+            Self::LongStringDelimiter => write!(f, "Invalid long string delimiter."),
             Self::EscapedChar(c) => write!(f, "Invalid escaped character `{}`.", c),
             Self::HexCharacter(c) => write!(f, "Invalid hex character `{}`.", c),
-            Self::AsciiOutOfBounds(sum) => {
-                write!(f, "Escaped number `{}` is not an Ascii character.", sum)
+            Self::NewlineInString => write!(f, "Unescaped newline in a string."),
+            Self::DecimalOutOfBounds(sum) => {
+                write!(f, "Decimal escape `{}` does not fit a byte.", sum)
             }
+            Self::UnicodeBraces => write!(f, "Missing braces around a `\\u` escape."),
+            Self::UnicodeOutOfBounds => write!(f, "Code point of a `\\u` escape is too large."),
+            Self::UnexpectedCharacter(c) => write!(f, "Unexpected character `{}`.", c),
         }
     }
 }
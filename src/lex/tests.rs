@@ -366,7 +366,7 @@ print "hello again...""#,
             lexeme_type: LexemeType::Name("print")
         }))
     );
-    assert_eq!(lex.lines.as_slice(), &[6]);
+    assert_eq!((lex.line, lex.column), (0, 6));
     assert_eq!(
         lex.next(),
         Some(Ok(Lexeme {
@@ -376,7 +376,7 @@ print "hello again...""#,
             lexeme_type: LexemeType::String("hello world")
         }))
     );
-    assert_eq!(lex.lines.as_slice(), &[19]);
+    assert_eq!((lex.line, lex.column), (0, 19));
     assert_eq!(
         lex.next(),
         Some(Ok(Lexeme {
@@ -386,7 +386,7 @@ print "hello again...""#,
             lexeme_type: LexemeType::Name("print")
         }))
     );
-    assert_eq!(lex.lines.as_slice(), &[20, 6]);
+    assert_eq!((lex.line, lex.previous_column, lex.column), (1, 20, 6));
     assert_eq!(
         lex.next(),
         Some(Ok(Lexeme {
@@ -405,7 +405,7 @@ print "hello again...""#,
             lexeme_type: LexemeType::Eof
         }))
     );
-    assert_eq!(lex.lines.as_slice(), &[20, 22]);
+    assert_eq!((lex.line, lex.previous_column, lex.column), (1, 20, 22));
     assert!(lex.next().is_none());
     assert_eq!(lex.remaining(), 0);
 
@@ -424,9 +424,27 @@ print "hello again...""#,
         Some(Err(Error {
             kind: ErrorKind::EofAtString,
             line: 0,
-            column: 18
+            column: 7
         }))
     );
+
+    // Strings that are not closed are reported on the line they start
+    for (source, kind, column) in [
+        ("x = 1\ny = \"abc\nz = 2\n", ErrorKind::UnfinishedString, 5),
+        ("x = 1\ny = 'abc", ErrorKind::EofAtString, 5),
+        ("x = 1\n  y = [[abc\n\nz = 2\n", ErrorKind::EofAtString, 7),
+    ] {
+        let error = Lex::new(source).find_map(Result::err);
+        assert_eq!(
+            error,
+            Some(Error {
+                kind,
+                line: 1,
+                column
+            }),
+            "{source:?}"
+        );
+    }
 }
 
 #[test]
@@ -598,3 +616,169 @@ print('hello' .. true) -- panic
     assert!(lex.next().is_none());
     assert_eq!(lex.remaining(), 0);
 }
+
+#[test]
+fn unexpected_character() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+    let mut lex = Lex::new("a = `");
+    assert_eq!(
+        lex.next(),
+        Some(Ok(Lexeme {
+            line: 0,
+            column: 1,
+            start: 0,
+            lexeme_type: LexemeType::Name("a")
+        }))
+    );
+    assert_eq!(
+        lex.next(),
+        Some(Ok(Lexeme {
+            line: 0,
+            column: 3,
+            start: 2,
+            lexeme_type: LexemeType::Assign
+        }))
+    );
+    assert_eq!(
+        lex.next(),
+        Some(Err(Error {
+            kind: ErrorKind::UnexpectedCharacter,
+            line: 0,
+            column: 5
+        }))
+    );
+
+    // The name ends before the multi-byte character
+    let mut lex = Lex::new("n\u{7d2}");
+    assert_eq!(
+        lex.next()
+            .map(|lexeme| lexeme.map(|lexeme| lexeme.lexeme_type)),
+        Some(Ok(LexemeType::Name("n")))
+    );
+    assert!(matches!(
+        lex.next(),
+        Some(Err(Error {
+            kind: ErrorKind::UnexpectedCharacter,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn trivia() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+    let program = "local a = 1 -- one\n\n  -- two\nreturn a --";
+    let lexemes = Lex::new(program)
+        .with_trivia()
+        .map(|lexeme| lexeme.map(|lexeme| lexeme.lexeme_type))
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        lexemes,
+        Ok(Vec::from([
+            LexemeType::Local,
+            LexemeType::Whitespace(" "),
+            LexemeType::Name("a"),
+            LexemeType::Whitespace(" "),
+            LexemeType::Assign,
+            LexemeType::Whitespace(" "),
+            LexemeType::Integer(1),
+            LexemeType::Whitespace(" "),
+            LexemeType::Comment("-- one"),
+            LexemeType::Whitespace("\n\n  "),
+            LexemeType::Comment("-- two"),
+            LexemeType::Whitespace("\n"),
+            LexemeType::Return,
+            LexemeType::Whitespace(" "),
+            LexemeType::Name("a"),
+            LexemeType::Whitespace(" "),
+            LexemeType::Comment("--"),
+            LexemeType::Eof,
+        ]))
+    );
+
+    // Each lexeme ends where the next one starts
+    for program in [
+        program,
+        "  a.b:c(\"x\", 'y')[1]--x\n",
+        "\n\nx = 0x10 + 1e3 -- end",
+        "",
+    ] {
+        let starts = Lex::new(program)
+            .with_trivia()
+            .map(|lexeme| lexeme.map(|lexeme| lexeme.start))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let mut written = alloc::string::String::new();
+        for window in starts.windows(2) {
+            written.push_str(&program[window[0]..window[1]]);
+        }
+        assert_eq!(written, program);
+        assert_eq!(starts.last(), Some(&program.len()));
+    }
+
+    // The lexemes are the same without trivia
+    assert_eq!(
+        Lex::new(program)
+            .with_trivia()
+            .filter(|lexeme| !matches!(
+                lexeme,
+                Ok(Lexeme {
+                    lexeme_type: LexemeType::Whitespace(_) | LexemeType::Comment(_),
+                    ..
+                })
+            ))
+            .collect::<Vec<_>>(),
+        Lex::new(program).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn long_brackets() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+    let program = "x = [==[\n]] ]=]]==] --[=[ a\n]]]=] y[ [[z]] ] --[x";
+    let lexemes = Lex::new(program)
+        .with_trivia()
+        .map(|lexeme| lexeme.map(|lexeme| (lexeme.start, lexeme.lexeme_type)))
+        .collect::<Result<Vec<_>, _>>();
+    assert_eq!(
+        lexemes,
+        Ok(Vec::from([
+            (0, LexemeType::Name("x")),
+            (1, LexemeType::Whitespace(" ")),
+            (2, LexemeType::Assign),
+            (3, LexemeType::Whitespace(" ")),
+            (4, LexemeType::LongString("]] ]=]")),
+            (19, LexemeType::Whitespace(" ")),
+            (20, LexemeType::Comment("--[=[ a\n]]]=]")),
+            (33, LexemeType::Whitespace(" ")),
+            (34, LexemeType::Name("y")),
+            (35, LexemeType::LSquare),
+            (36, LexemeType::Whitespace(" ")),
+            (37, LexemeType::LongString("z")),
+            (42, LexemeType::Whitespace(" ")),
+            (43, LexemeType::RSquare),
+            (44, LexemeType::Whitespace(" ")),
+            (45, LexemeType::Comment("--[x")),
+            (49, LexemeType::Eof),
+        ]))
+    );
+
+    let mut lex = Lex::new("a = [=\n");
+    assert_eq!(
+        lex.nth(2),
+        Some(Err(Error {
+            kind: ErrorKind::LongStringDelimiter,
+            line: 1,
+            column: 0,
+        }))
+    );
+    let mut lex = Lex::new("--[[ a ]");
+    assert_eq!(
+        lex.next(),
+        Some(Err(Error {
+            kind: ErrorKind::EofAtLongComment,
+            line: 0,
+            column: 8,
+        }))
+    );
+}
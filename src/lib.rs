@@ -1,47 +1,206 @@
 #![no_std]
 
-mod bytecode;
+#[cfg(feature = "alloc")]
+pub mod bytecode;
+#[cfg(feature = "alloc")]
 mod closure;
+#[cfg(feature = "alloc")]
+mod convert;
+#[cfg(feature = "alloc")]
 pub mod environment;
+#[cfg(feature = "alloc")]
 mod error;
 mod ext;
+#[cfg(feature = "alloc")]
 mod function;
-mod lex;
-mod parser;
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod fuzz;
+#[cfg(feature = "alloc")]
+mod gc;
+#[cfg(feature = "alloc")]
+mod hook;
+pub mod lex;
+#[cfg(feature = "alloc")]
+pub mod parser;
+#[cfg(feature = "alloc")]
+mod profile;
+#[cfg(feature = "alloc")]
 mod program;
+#[cfg(feature = "alloc")]
+mod stack;
+#[cfg(feature = "alloc")]
 mod stack_frame;
+#[cfg(feature = "alloc")]
 mod stack_str;
+#[cfg(feature = "alloc")]
 mod std;
+#[cfg(feature = "alloc")]
 mod table;
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "alloc")]
+mod userdata;
+#[cfg(feature = "alloc")]
 mod value;
 
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
-use alloc::{rc::Rc, vec::Vec};
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
 use core::{
     cell::RefCell,
     cmp::Ordering,
+    fmt::Write,
     ops::{Deref, DerefMut},
 };
 
+#[cfg(feature = "opcode_counts")]
+pub use self::profile::OpcodeCounts;
+#[cfg(feature = "alloc")]
 use self::{
-    bytecode::Bytecode,
-    closure::{Closure, Upvalue},
-    environment::Environment,
-    function::Function,
+    bytecode::{Bytecode, OpcodeHandlers},
+    closure::{Closure, FunctionType, Upvalue},
+    environment::{Environment, StdOut},
+    gc::{Collector, MemoryLimit, measured_size},
+    hook::Hooks,
+    profile::Profiler,
+    stack::Stack,
     stack_frame::StackFrame,
+    value::ValueKey,
+};
+#[cfg(feature = "alloc")]
+pub use self::{
+    bytecode::{FIRST_CUSTOM_OPCODE, LAST_CUSTOM_OPCODE, OpcodeHandler},
+    closure::NativeCtx,
+    convert::{FromLua, FromLuaMulti, IntoLua, IntoLuaMulti, NativeFunction},
+    error::{Error, ErrorCategory},
+    function::Function,
+    hook::{Hook, HookEvent, HookMask},
+    parser::{CompileError, CompileErrorKind},
+    profile::{CallProfile, CallSite},
+    program::{ConstantPool, Difference, Local, Program, ProgramDiff},
+    table::{Table, TableObserver},
+    userdata::UserData,
     value::Value,
 };
-pub use self::{error::Error, program::Program};
 
-#[derive(Debug, Default)]
+#[cfg(feature = "alloc")]
+/// Default limit of nested function calls, see [`Lua::set_max_call_depth`]
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 200_000;
+#[cfg(feature = "alloc")]
+/// Values the stack has room for before it has to grow, calls and
+/// returns only move the top of the stack and the values they pass
+const INITIAL_STACK_SIZE: usize = 256;
+#[cfg(feature = "alloc")]
+/// Limit of nested calls to [`Lua::call_value`], which recurse on the
+/// host's stack, like calls made by native functions and metamethods
+const MAX_HOST_CALL_DEPTH: usize = 200;
+
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
 pub struct Lua {
-    stack: Vec<Value>,
+    stack: Stack,
     /// Stack frames
     stack_frame: Vec<StackFrame>,
+    /// Global environment, kept between calls to [`Lua::execute`]
+    globals: Rc<RefCell<Table>>,
+    /// Entropy provided by the host to seed `math.random`
+    #[cfg(feature = "math")]
+    entropy_source: Option<environment::EntropySource>,
+    /// Clock, console, and files provided by the host
+    backends: environment::Backends,
+    /// Handlers for the opcodes reserved for the host
+    opcode_handlers: OpcodeHandlers,
+    /// Collects cycles of tables and closures
+    gc: Collector,
+    /// Cap on the memory used by scripts, unlimited if `None`
+    memory_limit: Option<MemoryLimit>,
+    /// Arguments seen by each call site, only while profiling
+    profiler: Option<Profiler>,
+    /// Times each opcode ran
+    #[cfg(feature = "opcode_counts")]
+    opcode_counts: profile::OpcodeCounts,
+    /// Number of stack frames pushed and popped, the interpreter loop
+    /// looks up the running bytecodes again when it changes
+    frame_changes: usize,
+    /// Maximum number of stack frames
+    max_call_depth: usize,
+    /// Number of calls to [`Lua::call_value`] that have not returned yet
+    host_call_depth: usize,
+    /// Number of bytecodes that can still run, unlimited if `None`
+    fuel: Option<u64>,
+    /// Position of the function of the chunk that ran out of fuel
+    suspended: Option<usize>,
+    /// Debug hook registered by the host
+    hooks: Option<Hooks>,
+    /// Metatable shared by all strings
+    string_metatable: Option<Rc<RefCell<Table>>>,
+    /// String constants shared by the chunks compiled by [`Lua::eval`],
+    /// `load`, and `require`
+    constant_pool: ConstantPool,
 }
 
+#[cfg(feature = "alloc")]
+impl Default for Lua {
+    fn default() -> Self {
+        Self::new(Environment::default())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for Lua {
+    fn drop(&mut self) {
+        // The globals hold themselves as `_G`, once the VM lets go of them
+        // a collection frees them unless the host still holds a reference
+        self.stack.clear();
+        drop(core::mem::replace(
+            &mut self.globals,
+            Rc::new(RefCell::new(Table::new(0, 0))),
+        ));
+        self.gc.collect();
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl Lua {
+    /// Creates a VM that keeps `env` as its globals across
+    /// calls to [`Lua::execute`]
+    pub fn new(env: Environment) -> Self {
+        let mut gc = Collector::new();
+        gc.track_table(&env);
+
+        Self {
+            stack: Stack::with_capacity(INITIAL_STACK_SIZE),
+            stack_frame: Vec::new(),
+            globals: (*env).clone(),
+            #[cfg(feature = "math")]
+            entropy_source: env.entropy_source(),
+            backends: env.backends(),
+            opcode_handlers: env.opcode_handlers(),
+            gc,
+            memory_limit: None,
+            profiler: None,
+            #[cfg(feature = "opcode_counts")]
+            opcode_counts: profile::OpcodeCounts::default(),
+            frame_changes: 0,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            host_call_depth: 0,
+            fuel: None,
+            suspended: None,
+            hooks: None,
+            string_metatable: env.string_metatable(),
+            constant_pool: ConstantPool::new(),
+        }
+    }
+
     /// Runs program with default environment
     pub fn run_program(main_program: Program) -> Result<(), Error> {
         Self::run_program_with_env(main_program, Environment::default())
@@ -49,27 +208,719 @@ impl Lua {
 
     /// Runs program with given environment
     pub fn run_program_with_env(main_program: Program, env: Environment) -> Result<(), Error> {
+        Self::new(env).execute(main_program).map(|_| ())
+    }
+
+    /// Runs a chunk on this VM, returning the values returned by the chunk.
+    ///
+    /// Globals set by the chunk are visible to the next chunks executed.
+    ///
+    /// A chunk that ran out of fuel and was not resumed is abandoned.
+    pub fn execute(&mut self, program: Program) -> Result<Vec<Value>, Error> {
+        let globals = self.globals.clone();
+        self.execute_with_env(program, globals)
+    }
+
+    /// Runs a line typed on an interactive prompt, keeping the globals
+    /// across calls like [`Lua::execute`]
+    ///
+    /// The line is first read as an expression list, whose values are
+    /// returned, and then as a block of statements, as the reference
+    /// interpreter does. Fails with [`Error::Compile`] with the error of
+    /// the block if the line is neither.
+    ///
+    /// ```
+    /// use no_deps_lua::{Lua, Value};
+    ///
+    /// let mut lua = Lua::default();
+    /// assert!(lua.eval("x = 20").unwrap().is_empty());
+    /// assert_eq!(lua.eval("x + 1, 'a'").unwrap(), [Value::Integer(21), "a".into()]);
+    /// ```
+    pub fn eval(&mut self, line: &str) -> Result<Vec<Value>, Error> {
+        let line = line.trim_end();
+        // Both attempts are named after the line as written
+        let chunk_name = Program::default_chunk_name(line);
+        let pool = &mut self.constant_pool;
+        let program = match Program::parse_named_with_pool(
+            &alloc::format!("return {line}\n"),
+            &chunk_name,
+            pool,
+        ) {
+            Ok(program) => program,
+            Err(_) => {
+                Program::parse_named_with_pool(&alloc::format!("{line}\n"), &chunk_name, pool)?
+            }
+        };
+        self.execute(program)
+    }
+
+    /// Runs a chunk with `env` as its `_ENV` instead of the globals of
+    /// the VM, like `load` does with its `env` argument, the chunk can
+    /// only reach what is on `env`, see [`Lua::sandbox`]
+    pub fn execute_with_env(
+        &mut self,
+        program: Program,
+        env: Rc<RefCell<Table>>,
+    ) -> Result<Vec<Value>, Error> {
         log::trace!("Running program");
 
-        let mut vm = Lua::default();
+        if let Some(func_position) = self.suspended.take() {
+            self.unwind(0, func_position, Error::FuelExhausted);
+        }
+
+        if let Some(profiler) = self.profiler.as_mut() {
+            profiler.add_chunk(&program);
+        }
+
+        let main = Value::Closure(Rc::new(Closure::new_lua(
+            Rc::new(Function::new(program, 0, true)),
+            Vec::from_iter([Rc::new(RefCell::new(Upvalue::Closed(Value::Table(env))))]),
+        )));
+        self.call_value(main, &[])
+    }
+
+    /// New environment with the globals of the VM named on `names`, for
+    /// [`Lua::execute_with_env`], [`SAFE_GLOBALS`](environment::SAFE_GLOBALS)
+    /// lists the standard library functions that can't reach the host.
+    ///
+    /// Library tables are copied, so a chunk can't replace the functions
+    /// the VM uses, and `_G` is the new environment itself.
+    pub fn sandbox(&mut self, names: &[&str]) -> Rc<RefCell<Table>> {
+        let sandbox = Rc::new(RefCell::new(Table::new(0, names.len())));
+        self.gc.track_table(&sandbox);
+
+        let globals = self.globals.borrow();
+        let mut table = sandbox.borrow_mut();
+        for name in names {
+            let key = ValueKey((*name).into());
+            let value = match globals.get(key.clone()) {
+                _ if *name == "_G" => Value::Table(sandbox.clone()),
+                Value::Table(library) => {
+                    let library = library.borrow();
+                    let mut copy = Table::new(library.array.len(), library.table.len());
+                    copy.array.extend_from_slice(&library.array);
+                    copy.table.extend_from_slice(&library.table);
+                    Value::Table(Rc::new(RefCell::new(copy)))
+                }
+                Value::Nil => continue,
+                value => value.clone(),
+            };
+            // Names are always valid keys
+            let _ = table.set(key, value);
+        }
+        drop(table);
+        sandbox
+    }
+
+    /// Frees cycles of tables and closures that are no longer reachable
+    /// from the VM or from values held by the host, returning how many
+    /// objects were freed
+    ///
+    /// Collections also run automatically as scripts create tables
+    /// and closures. String constants that no chunk uses anymore are
+    /// dropped from [`Lua::constant_pool`].
+    pub fn collect_garbage(&mut self) -> usize {
+        self.stack.clear_unused();
+        let freed = self.gc.collect();
+        self.constant_pool.shrink();
+        freed
+    }
+
+    /// String constants shared by the chunks compiled by [`Lua::eval`],
+    /// `load`, and `require`, [`Lua::memory_in_use`] counts each of them
+    /// once, however many values reference it
+    pub fn constant_pool(&self) -> &ConstantPool {
+        &self.constant_pool
+    }
+
+    /// Pool to compile chunks with [`Program::parse_with_pool`], so they
+    /// share their string constants with the chunks compiled by the VM
+    pub fn constant_pool_mut(&mut self) -> &mut ConstantPool {
+        &mut self.constant_pool
+    }
+
+    /// Caps how much memory the scripts running on the VM can use, in
+    /// bytes, `None` removes the cap
+    ///
+    /// Creating tables, closures, and strings, and storing values on
+    /// tables, add to an estimate of the memory in use. Once the estimate
+    /// goes over the limit, a collection runs and the memory in use is
+    /// measured again with [`Lua::memory_in_use`], and if it is still over
+    /// the limit the operation fails with [`Error::MemoryLimit`], which
+    /// unwinds the script like any other error.
+    ///
+    /// ```
+    /// use no_deps_lua::{Error, Lua, Program};
+    ///
+    /// let mut lua = Lua::default();
+    /// lua.set_memory_limit(Some(64 * 1024));
+    /// let program = Program::parse("local t = {}\nwhile true do t[#t + 1] = {} end\n").unwrap();
+    /// assert!(matches!(lua.execute(program).map_err(Error::unlocated), Err(Error::MemoryLimit)));
+    /// let program = Program::parse("return 1 + 1\n").unwrap();
+    /// assert_eq!(lua.execute(program).unwrap(), [2i64.into()]);
+    /// ```
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory_limit = limit.map(|limit| MemoryLimit { limit, estimate: 0 });
+        if self.memory_limit.is_some() {
+            let in_use = self.memory_in_use();
+            if let Some(memory_limit) = self.memory_limit.as_mut() {
+                memory_limit.estimate = in_use;
+            }
+        }
+    }
+
+    /// Most memory the scripts running on the VM can use, in bytes,
+    /// see [`Lua::set_memory_limit`]
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory_limit
+            .as_ref()
+            .map(|memory_limit| memory_limit.limit)
+    }
+
+    /// Estimate of the memory used by the values reachable from the VM,
+    /// in bytes
+    ///
+    /// Strings are counted once for each reference to them, except the
+    /// ones on [`Lua::constant_pool`], which are counted once. Memory
+    /// owned by userdata or by the compiled functions is not counted.
+    pub fn memory_in_use(&mut self) -> usize {
+        let pool = &self.constant_pool;
+        self.gc.memory_in_use(pool)
+            + self
+                .stack
+                .iter()
+                .map(|value| measured_size(value, pool))
+                .sum::<usize>()
+            + pool.size()
+    }
+
+    /// Counts `bytes` towards the memory limit, failing if the memory
+    /// in use would go over it, see [`Lua::set_memory_limit`]
+    pub(crate) fn allocate(&mut self, bytes: usize) -> Result<(), Error> {
+        let Some(memory_limit) = self.memory_limit.as_mut() else {
+            return Ok(());
+        };
+        memory_limit.estimate = memory_limit.estimate.saturating_add(bytes);
+        if memory_limit.estimate <= memory_limit.limit {
+            return Ok(());
+        }
+
+        // The estimate never goes down as values are freed, so it is
+        // measured again before failing
+        self.collect_garbage();
+        let in_use = self.memory_in_use();
+        let Some(memory_limit) = self.memory_limit.as_mut() else {
+            unreachable!("Memory limit was set above.");
+        };
+        memory_limit.estimate = in_use;
+        if in_use.saturating_add(bytes) > memory_limit.limit {
+            log::error!(
+                "Allocating {} bytes with {} bytes in use goes over the memory limit of {} bytes.",
+                bytes,
+                in_use,
+                memory_limit.limit
+            );
+            return Err(Error::MemoryLimit);
+        }
+        memory_limit.estimate += bytes;
+        Ok(())
+    }
+
+    /// Starts or stops recording the arguments passed on each call site,
+    /// stopping discards what was recorded
+    pub fn set_profiling(&mut self, enabled: bool) {
+        match (enabled, &self.profiler) {
+            (true, None) => self.profiler = Some(Profiler::default()),
+            (false, _) => self.profiler = None,
+            (true, Some(_)) => (),
+        }
+    }
+
+    /// Arguments passed on each call site since profiling started,
+    /// or `None` if profiling is disabled
+    pub fn call_profile(&self) -> Option<CallProfile> {
+        self.profiler.as_ref().map(Profiler::report)
+    }
+
+    /// Times each opcode ran since the VM was created, or since
+    /// [`Lua::reset_opcode_counts`]
+    #[cfg(feature = "opcode_counts")]
+    pub fn opcode_counts(&self) -> &OpcodeCounts {
+        &self.opcode_counts
+    }
+
+    #[cfg(feature = "opcode_counts")]
+    pub fn reset_opcode_counts(&mut self) {
+        self.opcode_counts = OpcodeCounts::default();
+    }
 
-        vm.stack.push(Value::Closure(Rc::new(Closure::new_lua(
-            Rc::new(Function::new(main_program, 0, true)),
-            Vec::from_iter([Rc::new(RefCell::new(Upvalue::Closed(Value::Table(
-                (*env).clone(),
-            ))))]),
-        ))));
-        vm.prepare_new_stack_frame(0, 0, 0, 0);
+    /// Limits how many functions can be running at the same time, calls
+    /// past the limit fail with [`Error::StackOverflow`]
+    ///
+    /// Tail calls don't count towards the limit. Calls that recurse on the
+    /// host's stack, like calls made by native functions, are also limited
+    /// to 200 nested calls, regardless of this setting.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = depth;
+    }
 
-        while let Some(code) = vm.read_bytecode() {
-            code.execute(&mut vm)?;
+    /// Maximum number of functions that can be running at the same time
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Global environment of the VM
+    pub fn globals(&self) -> &Rc<RefCell<Table>> {
+        &self.globals
+    }
+
+    /// Metatable of `value`, strings share the metatable of the VM, which
+    /// exists if its environment had the `string` library
+    pub fn metatable(&self, value: &Value) -> Option<Rc<RefCell<Table>>> {
+        match value {
+            Value::ShortString(_) | Value::String(_) => self.string_metatable.clone(),
+            value => value.metatable(),
+        }
+    }
+
+    /// Value on `register` of the running function, or `None` if it is
+    /// past the top of the stack
+    ///
+    /// Meant for [`OpcodeHandler`]s, which access the registers encoded
+    /// on their instructions.
+    pub fn register(&self, register: u8) -> Option<&Value> {
+        let (frame_start, variadics) = self.running_frame_start();
+        self.stack
+            .get(frame_start + variadics + usize::from(register))
+    }
+
+    /// Sets `register` of the running function, registers can only be
+    /// set up to one past the top of the stack
+    pub fn set_register(&mut self, register: u8, value: impl Into<Value>) -> Result<(), Error> {
+        self.set_stack(register, value.into())
+    }
+
+    /// Replaces the arguments of the running native function with `values`,
+    /// returning how many there are
+    ///
+    /// Native functions return by leaving their results at the start of
+    /// their stack frame, where the arguments were, and returning the count
+    /// of results, which can be any number, including zero. The caller keeps
+    /// as many as it expects, filling the missing ones with `nil`.
+    ///
+    /// ```
+    /// use no_deps_lua::{Error, Lua, Program, Value, environment::Environment};
+    ///
+    /// /// Arguments in reverse order
+    /// fn reverse(vm: &mut Lua) -> Result<usize, Error> {
+    ///     let mut args = Vec::new();
+    ///     for register in 0..=u8::MAX {
+    ///         let Some(arg) = vm.register(register) else {
+    ///             break;
+    ///         };
+    ///         args.push(arg.clone());
+    ///     }
+    ///     vm.set_returns(args.into_iter().rev())
+    /// }
+    ///
+    /// let env = Environment::builder()
+    ///     .function("reverse", reverse)
+    ///     .build()
+    ///     .unwrap();
+    /// let program = Program::parse("local a, b, c = reverse(1, 2)\nreturn a, b, c\n").unwrap();
+    /// let results = Lua::new(env).execute(program).unwrap();
+    /// assert_eq!(results, [Value::Integer(2), Value::Integer(1), Value::Nil]);
+    /// ```
+    pub fn set_returns(&mut self, values: impl IntoIterator<Item = Value>) -> Result<usize, Error> {
+        let (frame_start, variadics) = self.running_frame_start();
+        let start = frame_start + variadics;
+        if self.stack_frame.is_empty() || start > self.stack.len() {
+            return Err(Error::CorruptStack);
+        }
+        self.stack.set_top(start);
+        self.stack.extend(values);
+        Ok(self.stack.len() - start)
+    }
+
+    /// Calls `function` with `args`, returning all values returned by `function`.
+    ///
+    /// This can be used by native closures to call back into Lua, or by
+    /// embedding code to call functions directly. If the call fails, the stack
+    /// is restored to how it was before the call.
+    pub fn call_value(&mut self, function: Value, args: &[Value]) -> Result<Vec<Value>, Error> {
+        if self.host_call_depth >= MAX_HOST_CALL_DEPTH {
+            log::error!("Too many nested calls from native functions.");
+            return Err(Error::StackOverflow);
+        }
+
+        let (frame_start, variadics) = self.running_frame_start();
+        let func_position = self.stack.len();
+        let func_index = func_position - (frame_start + variadics);
+        let depth = self.stack_frame.len();
+
+        self.stack.push(function.clone());
+        self.stack.extend(args.iter().cloned());
+
+        self.host_call_depth += 1;
+        let result = Bytecode::run_closure(function, self, func_index, args.len() + 1, 0, false)
+            // Native functions return immediately, Lua functions run
+            // until they drop their stack frame
+            .and_then(|()| self.run_stack_frames(depth, func_position));
+        self.host_call_depth -= 1;
+
+        self.finish_call(depth, func_position, result)
+    }
+
+    /// Runs the bytecode of the running functions until only
+    /// `depth` stack frames are left
+    fn run_stack_frames(&mut self, depth: usize, func_position: usize) -> Result<(), Error> {
+        // Bytecodes of the running function, only looked up again
+        // after a call or a return changes the running function
+        let mut running: Option<(usize, Rc<[Bytecode]>)> = None;
+        while self.stack_frame.len() > depth {
+            if let Some(fuel) = self.fuel.as_mut() {
+                if *fuel == 0 {
+                    // Only calls that own the whole stack can be resumed,
+                    // the others would have to return through the host
+                    if depth == 0 {
+                        self.suspended = Some(func_position);
+                    }
+                    return Err(Error::FuelExhausted);
+                }
+                *fuel -= 1;
+            }
+            let byte_codes = match &running {
+                Some((frame_changes, byte_codes)) if *frame_changes == self.frame_changes => {
+                    byte_codes
+                }
+                _ => {
+                    let byte_codes = self.get_running_program()?.byte_codes.clone();
+                    &running.insert((self.frame_changes, byte_codes)).1
+                }
+            };
+            let frame = self.get_stack_frame_mut()?;
+            let pc = frame.program_counter;
+            frame.program_counter += 1;
+            let Some(code) = byte_codes.get(pc).copied() else {
+                log::error!("Function ended without a `RETURN`.");
+                return Err(Error::MissingReturn);
+            };
+            if self.hooks.is_some() {
+                self.hook_bytecode()?;
+            }
+            #[cfg(feature = "opcode_counts")]
+            self.opcode_counts.record(code);
+            #[cfg(feature = "debug_checks")]
+            self.assert_open_upvalues(code);
+            #[cfg(feature = "trace")]
+            if let Some(step) = self.trace_before(pc, code) {
+                let result = code.execute(self);
+                self.trace_after(step, &result);
+                result?;
+                continue;
+            }
+            code.execute(self)?;
+        }
+        Ok(())
+    }
+
+    /// Collects the values returned by the function called at
+    /// `func_position`, or unwinds its stack frames if it failed
+    fn finish_call(
+        &mut self,
+        depth: usize,
+        func_position: usize,
+        result: Result<(), Error>,
+    ) -> Result<Vec<Value>, Error> {
+        match result {
+            Ok(()) => Ok(self.stack.split_off(func_position)),
+            Err(Error::FuelExhausted) if depth == 0 && self.suspended == Some(func_position) => {
+                Err(Error::FuelExhausted)
+            }
+            Err(err) => {
+                let err = self.locate_error(depth, err);
+                Err(self.unwind(depth, func_position, err))
+            }
+        }
+    }
+
+    /// Wraps `err` with the line of the innermost Lua function above
+    /// `depth`, if it comes from a named chunk
+    ///
+    /// Errors raised by native functions are located on the line of
+    /// the Lua function that called them.
+    fn locate_error(&self, depth: usize, err: Error) -> Error {
+        if matches!(err, Error::Located { .. }) {
+            return err;
+        }
+        let location = self.stack_frame[depth..]
+            .iter()
+            .rev()
+            .find_map(|frame| {
+                match self
+                    .get_running_closure_of_stack_frame(frame)
+                    .ok()?
+                    .closure_type()
+                {
+                    FunctionType::Lua(function) => Some((frame, function.program())),
+                    FunctionType::Native(_) => None,
+                }
+            })
+            .and_then(|(frame, program)| {
+                let pc = frame.program_counter.saturating_sub(1);
+                Some((program.chunk_name.clone()?, program.line(pc)?))
+            });
+        match location {
+            Some((chunk, line)) => Error::Located {
+                chunk,
+                line,
+                error: Box::new(err),
+            },
+            None => err,
+        }
+    }
+
+    /// Drops the stack frames above `depth` and the stack above
+    /// `func_position`, closing their variables
+    fn unwind(&mut self, depth: usize, func_position: usize, mut err: Error) -> Error {
+        while self.stack_frame.len() > depth {
+            let Ok(popped_stack) = self.pop_stack_frame() else {
+                break;
+            };
+            // An error raised while closing replaces the original one
+            for open_upvalue in popped_stack.open_upvalues {
+                if let Err(close_err) = open_upvalue.borrow_mut().close(self) {
+                    err = close_err;
+                }
+            }
+            for variable in popped_stack.to_be_closed.into_iter().rev() {
+                let Some(value) = self.stack.get(variable).cloned() else {
+                    err = Error::CorruptStack;
+                    continue;
+                };
+                let message = Value::from(err.to_string().as_str());
+                if let Err(close_err) = self.call_close(value, message) {
+                    err = close_err;
+                }
+            }
+        }
+        self.stack.set_top(func_position);
+        err
+    }
+
+    /// Limits how many bytecodes can run, or removes the limit with `None`
+    ///
+    /// When the fuel runs out, the chunk being run by [`Lua::execute`], or the
+    /// function called by the host with [`Lua::call_value`], fails with
+    /// [`Error::FuelExhausted`], but is kept suspended so it can continue with
+    /// [`Lua::resume`] after refueling. Functions called by native functions
+    /// can't be suspended, and fail as any other error.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel;
+    }
+
+    /// Fuel left, or `None` if the number of bytecodes that can run is unlimited
+    pub fn fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Adds `fuel` to the fuel left, does nothing if the fuel is unlimited
+    pub fn refuel(&mut self, fuel: u64) {
+        if let Some(left) = self.fuel.as_mut() {
+            *left = left.saturating_add(fuel);
+        }
+    }
+
+    /// Returns `true` if a chunk ran out of fuel and can be resumed
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.is_some()
+    }
+
+    /// Continues running the chunk that ran out of fuel, returning the values
+    /// returned by the chunk, as [`Lua::execute`] would
+    pub fn resume(&mut self) -> Result<Vec<Value>, Error> {
+        let Some(func_position) = self.suspended.take() else {
+            return Err(Error::NotSuspended);
+        };
+
+        self.host_call_depth += 1;
+        let result = self.run_stack_frames(0, func_position);
+        self.host_call_depth -= 1;
+
+        self.finish_call(0, func_position, result)
+    }
+
+    /// Registers `hook` to be called on the events of `mask`, replacing
+    /// the previous hook
+    pub fn set_hook(
+        &mut self,
+        mask: HookMask,
+        hook: impl Fn(&mut Lua, HookEvent) -> Result<(), Error> + 'static,
+    ) {
+        self.hooks = Some(Hooks::new(Rc::new(hook) as Hook, mask));
+    }
+
+    /// Sets where `print`, `warn`, and `io.write` write to on this VM,
+    /// replacing the one of the [`Environment`] it was created with, see
+    /// [`Environment::set_stdout`]
+    pub fn set_stdout(&mut self, stdout: impl StdOut + 'static) {
+        self.backends.stdout = Some(Rc::new(stdout));
+    }
+
+    /// Removes the output of the VM, `print` and `warn` go back to logging
+    pub fn clear_stdout(&mut self) {
+        self.backends.stdout = None;
+    }
+
+    pub fn clear_hook(&mut self) {
+        self.hooks = None;
+    }
+
+    /// Function of the innermost stack frame, or `None` if there
+    /// is no function running
+    pub fn running_function(&self) -> Option<&Value> {
+        self.stack_frame
+            .last()
+            .and_then(|frame| self.stack.get(frame.function))
+    }
+
+    /// Index of the bytecode being run by the innermost function, or `None`
+    /// if there is no function running or if it is a native function
+    pub fn program_counter(&self) -> Option<usize> {
+        let frame = self.stack_frame.last()?;
+        match self.get_running_closure().ok()?.closure_type() {
+            FunctionType::Lua(_) => Some(frame.program_counter.saturating_sub(1)),
+            FunctionType::Native(_) => None,
+        }
+    }
+
+    /// Line of the bytecode being run by the innermost function, see
+    /// [`Lua::program_counter`] and [`Program::line`]
+    pub fn current_line(&self) -> Option<usize> {
+        let pc = self.program_counter()?;
+        self.get_running_closure().ok()?.program().line(pc)
+    }
+
+    /// Names and values of the locals of the innermost function that
+    /// are in scope, in order of declaration
+    pub fn locals(&self) -> Vec<(&str, &Value)> {
+        let Some(frame) = self.stack_frame.last() else {
+            return Vec::new();
+        };
+        let Ok(FunctionType::Lua(function)) = self
+            .get_running_closure()
+            .map(|closure| closure.closure_type())
+        else {
+            return Vec::new();
+        };
+        let registers = self
+            .stack
+            .get(frame.stack_frame + frame.variadic_arguments..)
+            .unwrap_or_default();
+        function
+            .program()
+            .locals
+            .iter()
+            .filter(|local| local.active(frame.program_counter))
+            .zip(registers)
+            .map(|(local, value)| (local.name(), value))
+            .collect()
+    }
+
+    /// Renders the stack frames, the registers of the innermost Lua function,
+    /// and the open upvalues in a compact format, to give context to
+    /// errors on host logs
+    pub fn dump_state(&self) -> String {
+        let mut dump = String::new();
+        // Writing into a `String` can't fail
+        let _ = self.write_state(&mut dump);
+        dump
+    }
+
+    fn write_state(&self, f: &mut impl Write) -> core::fmt::Result {
+        if self.stack_frame.is_empty() {
+            return writeln!(f, "No running function.");
+        }
+
+        writeln!(f, "Frames:")?;
+        for (depth, frame) in self.stack_frame.iter().enumerate() {
+            match self
+                .get_running_closure_of_stack_frame(frame)
+                .map(|closure| closure.closure_type())
+            {
+                Ok(FunctionType::Lua(_)) => writeln!(
+                    f,
+                    "  #{depth} lua function at bytecode {}, stack {}",
+                    frame.program_counter.saturating_sub(1),
+                    frame.stack_frame
+                )?,
+                Ok(FunctionType::Native(native)) => writeln!(
+                    f,
+                    "  #{depth} native function {native:?}, stack {}",
+                    frame.stack_frame
+                )?,
+                Err(_) => writeln!(f, "  #{depth} corrupt frame, stack {}", frame.stack_frame)?,
+            }
+        }
+
+        let lua_frame = self
+            .stack_frame
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(depth, frame)| {
+                match self
+                    .get_running_closure_of_stack_frame(frame)
+                    .ok()?
+                    .closure_type()
+                {
+                    FunctionType::Lua(function) => Some((depth, frame, function)),
+                    FunctionType::Native(_) => None,
+                }
+            });
+        if let Some((depth, frame, function)) = lua_frame {
+            writeln!(f, "Registers of #{depth}:")?;
+            let registers_start = frame.stack_frame + frame.variadic_arguments;
+            let registers_end = self
+                .stack_frame
+                .get(depth + 1)
+                .map_or(self.stack.len(), |callee| callee.function);
+            let mut locals = function
+                .program()
+                .locals
+                .iter()
+                .filter(|local| local.active(frame.program_counter));
+            for (register, value) in self
+                .stack
+                .get(registers_start..registers_end)
+                .unwrap_or_default()
+                .iter()
+                .enumerate()
+            {
+                match locals.next() {
+                    Some(local) => writeln!(f, "  [{register}] {} = {value:?}", local.name())?,
+                    None => writeln!(f, "  [{register}] = {value:?}")?,
+                }
+            }
+        }
+
+        writeln!(f, "Open upvalues:")?;
+        for (depth, frame) in self.stack_frame.iter().enumerate() {
+            for upvalue in frame.open_upvalues.iter() {
+                if let Upvalue::Open(slot) = upvalue.borrow().deref() {
+                    match self.stack.get(*slot) {
+                        Some(value) => writeln!(f, "  #{depth} stack[{slot}] = {value:?}")?,
+                        None => writeln!(f, "  #{depth} stack[{slot}] past the stack")?,
+                    }
+                }
+            }
         }
 
         Ok(())
     }
 
     fn jump(&mut self, jump: isize) -> Result<(), Error> {
-        let top_stack = self.get_stack_frame_mut();
+        let top_stack = self.get_stack_frame_mut()?;
 
         let pc = &mut top_stack.program_counter;
         if let Some(new_pc) = pc.checked_add_signed(jump) {
@@ -80,77 +931,177 @@ impl Lua {
         }
     }
 
+    /// Operand of the `EXTRAARG` after the running bytecode, which is
+    /// skipped, `None` if the next bytecode is not an `EXTRAARG`
+    fn extra_argument(&mut self) -> Option<u32> {
+        let pc = self.get_stack_frame().ok()?.program_counter;
+        let extra = *self.get_running_program().ok()?.byte_codes.get(pc)?;
+        if extra.opcode() != Some(bytecode::OpCode::ExtraArguments) {
+            return None;
+        }
+        self.jump(1).ok()?;
+        Some(*extra.decode_ax())
+    }
+
+    /// Whether the bytecode after the running one is a `MMBIN`, `MMBINI`
+    /// or `MMBINK`, which the compiler and `luac` write after the binary
+    /// arithmetic and bitwise bytecodes
+    fn metamethod_follows(&self) -> bool {
+        let Ok(frame) = self.get_stack_frame() else {
+            return false;
+        };
+        self.get_running_program()
+            .ok()
+            .and_then(|program| program.byte_codes.get(frame.program_counter))
+            .and_then(Bytecode::opcode)
+            .is_some_and(|opcode| {
+                matches!(
+                    opcode,
+                    bytecode::OpCode::MetaMethod
+                        | bytecode::OpCode::MetaMethodInteger
+                        | bytecode::OpCode::MetaMethodConstant
+                )
+            })
+    }
+
+    /// Pushes the stack frame of the function on `func_index`, whose
+    /// variadic arguments start `skipped` values after it, and whose
+    /// registers start after them, with its `args` fixed arguments
     fn prepare_new_stack_frame(
         &mut self,
         func_index: usize,
+        skipped: usize,
         args: usize,
         out_params: usize,
         variadic_arguments: usize,
+        tail_call: bool,
     ) {
-        let (last_stack, last_variadics) = if !self.stack_frame.is_empty() {
-            let top_stack = self.get_stack_frame();
-            let last_stack = top_stack.stack_frame;
-            let last_variadics = top_stack.variadic_arguments;
-            (last_stack, last_variadics)
-        } else {
-            (0, 0)
-        };
+        let (last_stack, last_variadics) = self.running_frame_start();
+        let function = last_stack + last_variadics + func_index;
 
         let new_stack = StackFrame {
             function_index: func_index,
+            function,
             program_counter: 0,
-            stack_frame: last_stack + last_variadics + func_index + 1,
+            stack_frame: function + 1 + skipped,
             variadic_arguments,
             out_params,
             open_upvalues: Vec::new(),
+            to_be_closed: Vec::new(),
+            tail_call,
+            line_hooked_at: None,
         };
 
-        self.stack.resize(
-            new_stack.stack_frame + args + variadic_arguments,
-            Value::Nil,
-        );
+        self.stack
+            .set_top(new_stack.stack_frame + variadic_arguments + args);
 
         self.stack_frame.push(new_stack);
+        self.frame_changes = self.frame_changes.wrapping_add(1);
+    }
+
+    /// Start of the running function's stack frame and its count of variadic
+    /// arguments, or zeros if there is no function running
+    fn running_frame_start(&self) -> (usize, usize) {
+        self.stack_frame.last().map_or((0, 0), |top_stack| {
+            (top_stack.stack_frame, top_stack.variadic_arguments)
+        })
+    }
+
+    /// Closes the to-be-closed variables of the running function that are
+    /// at or above `first` on the stack, the last declared first
+    fn close_to_be_closed(&mut self, first: usize) -> Result<(), Error> {
+        while let Some(&variable) = self
+            .get_stack_frame()?
+            .to_be_closed
+            .last()
+            .filter(|variable| **variable >= first)
+        {
+            self.get_stack_frame_mut()?.to_be_closed.pop();
+            let value = self.stack.get(variable).ok_or(Error::CorruptStack)?.clone();
+            self.call_close(value, Value::Nil)?;
+        }
+        Ok(())
+    }
+
+    /// Closes all to-be-closed variables of the running function
+    fn close_frame_to_be_closed(&mut self) -> Result<(), Error> {
+        if self.get_stack_frame()?.to_be_closed.is_empty() {
+            return Ok(());
+        }
+        self.close_to_be_closed(0)
+    }
+
+    fn call_close(&mut self, value: Value, error: Value) -> Result<(), Error> {
+        let Some(metamethod) = value.metamethod("__close") else {
+            return Err(Error::NonClosableValue(value.static_type_name()));
+        };
+        self.call_value(metamethod, &[value, error]).map(|_| ())
     }
 
-    fn drop_stack_frame(&mut self, return_start: usize, returns: usize) {
-        let popped_stack = self.pop_stack_frame();
+    fn drop_stack_frame(&mut self, return_start: usize, returns: usize) -> Result<(), Error> {
+        let popped_stack = self.pop_stack_frame()?;
 
-        let start = popped_stack.stack_frame + return_start;
+        let start = popped_stack.stack_frame + popped_stack.variadic_arguments + return_start;
 
         for open_upvalue in popped_stack.open_upvalues {
-            open_upvalue.borrow_mut().close(self);
+            open_upvalue.borrow_mut().close(self)?;
         }
 
-        let mut return_values = self
-            .stack
-            .drain(start..(start + returns))
-            .collect::<Vec<_>>();
-
-        if popped_stack.out_params == 0 {
-        } else if popped_stack.out_params == 1 {
-            return_values.clear();
-        } else {
-            match returns.cmp(&(popped_stack.out_params - 1)) {
-                Ordering::Greater => return_values.truncate(popped_stack.out_params),
-                Ordering::Equal => (),
-                Ordering::Less => return_values.resize(popped_stack.out_params - 1, Value::Nil),
-            }
+        let kept = match popped_stack.out_params {
+            0 => returns,
+            1 => 0,
+            out_params => out_params - 1,
         };
+        // The returned values take the place of the function, and the
+        // caller's registers end after them. Returning nothing may start
+        // past the top, like the `RETURN` after a `goto` that skipped locals
+        let function = popped_stack.function;
+        if function > start || (returns > 0 && self.stack.len().checked_sub(start) < Some(returns))
+        {
+            return Err(Error::CorruptStack);
+        }
+        let moved = returns.min(kept);
+        self.stack.move_values(start, function, moved);
+        self.stack.set_top(function + moved);
+        self.stack.set_top(function + kept);
+        Ok(())
+    }
 
-        if !self.stack_frame.is_empty() {
-            let top_stack = self.get_stack_frame();
-            self.stack.truncate(
-                top_stack.stack_frame + top_stack.variadic_arguments + popped_stack.function_index,
-            );
-        } else {
-            self.stack.clear();
+    /// Drops the running function's stack frame to make a tail call, moving
+    /// the function on `func_index` and its arguments into the place of the
+    /// running function
+    ///
+    /// Returns the function, its index on the caller's stack frame, and the
+    /// number of values the caller expects, which the tail called function
+    /// returns in place of the running function.
+    fn drop_stack_frame_for_tail_call(
+        &mut self,
+        func_index: usize,
+    ) -> Result<(Value, usize, usize), Error> {
+        let popped_stack = self.pop_stack_frame()?;
+
+        for open_upvalue in popped_stack.open_upvalues {
+            open_upvalue.borrow_mut().close(self)?;
+        }
+
+        let start = popped_stack.stack_frame + popped_stack.variadic_arguments + func_index;
+        let function = popped_stack.function;
+        if function > start || start >= self.stack.len() {
+            return Err(Error::CorruptStack);
         }
-        self.stack.extend(return_values);
+        let moved = self.stack.len() - start;
+        self.stack.move_values(start, function, moved);
+        self.stack.set_top(function + moved);
+
+        Ok((
+            self.stack[function].clone(),
+            popped_stack.function_index,
+            popped_stack.out_params,
+        ))
     }
 
     fn set_stack(&mut self, dst: u8, value: Value) -> Result<(), Error> {
-        let stack_frame = self.get_stack_frame();
+        let stack_frame = self.get_stack_frame()?;
 
         let offset = stack_frame.stack_frame;
         let variadics = stack_frame.variadic_arguments;
@@ -165,73 +1116,76 @@ impl Lua {
                 Ok(())
             }
             Ordering::Less => {
-                log::error!(
-                    "Trying to set a value out of the bounds of the stack. {} {}",
-                    dst,
-                    self.stack.len()
-                );
-                Err(Error::StackOverflow)
+                // Registers that were not written yet are `nil`, as
+                // temporaries can be written out of order
+                self.stack.set_top(dst);
+                self.stack.push(value);
+                Ok(())
             }
         }
     }
 
     fn get_stack(&self, src: u8) -> Result<&Value, Error> {
-        let stack_frame = self.get_stack_frame();
+        let stack_frame = self.get_stack_frame()?;
 
         let offset = stack_frame.stack_frame;
         let variadics = stack_frame.variadic_arguments;
         let src = offset + variadics + usize::from(src);
-        Ok(&self.stack[src])
+        self.stack.get(src).ok_or(Error::CorruptStack)
     }
 
-    fn get_stack_mut(&mut self, src: u8) -> Result<&mut Value, Error> {
-        let stack_frame = self.get_stack_frame();
-
-        let offset = stack_frame.stack_frame;
-        let variadics = stack_frame.variadic_arguments;
-        let src = offset + variadics + usize::from(src);
-        Ok(&mut self.stack[src])
+    /// Reads two registers if both hold integers, used as a fast path
+    /// for arithmetic and comparisons on numeric loops
+    fn get_integer_pair(&self, lhs: u8, rhs: u8) -> Option<(i64, i64)> {
+        let stack_frame = self.stack_frame.last()?;
+        let registers = self
+            .stack
+            .get(stack_frame.stack_frame + stack_frame.variadic_arguments..)?;
+        match (
+            registers.get(usize::from(lhs))?,
+            registers.get(usize::from(rhs))?,
+        ) {
+            (Value::Integer(lhs), Value::Integer(rhs)) => Some((*lhs, *rhs)),
+            _ => None,
+        }
     }
 
-    fn get_stack_frame(&self) -> &StackFrame {
-        let Some(last) = self.stack_frame.last() else {
-            unreachable!("Stack frames should never be empty.");
-        };
-        last
+    fn get_stack_frame(&self) -> Result<&StackFrame, Error> {
+        self.stack_frame.last().ok_or(Error::CorruptStack)
     }
 
-    fn get_stack_frame_mut(&mut self) -> &mut StackFrame {
-        let Some(last) = self.stack_frame.last_mut() else {
-            unreachable!("Stack frames should never be empty.");
-        };
-        last
+    fn get_stack_frame_mut(&mut self) -> Result<&mut StackFrame, Error> {
+        self.stack_frame.last_mut().ok_or(Error::CorruptStack)
     }
 
-    fn pop_stack_frame(&mut self) -> StackFrame {
-        let Some(last) = self.stack_frame.pop() else {
-            unreachable!("Stack frames should never be empty.");
-        };
-        last
+    fn pop_stack_frame(&mut self) -> Result<StackFrame, Error> {
+        let last = self.stack_frame.pop().ok_or(Error::CorruptStack)?;
+        self.frame_changes = self.frame_changes.wrapping_add(1);
+        Ok(last)
     }
 
     fn get_upvalue(&self, upvalue: usize) -> Result<Value, Error> {
-        let closure = self.get_running_closure();
+        let closure = self.get_running_closure()?;
         let upvalue = closure.upvalue(upvalue)?;
         let upvalue_borrow = upvalue.as_ref().borrow();
         match upvalue_borrow.deref() {
-            Upvalue::Open(register) => Ok(self.stack[*register].clone()),
+            Upvalue::Open(register) => self
+                .stack
+                .get(*register)
+                .cloned()
+                .ok_or(Error::CorruptStack),
             Upvalue::Closed(value) => Ok(value).cloned(),
         }
     }
 
     fn set_upvalue(&mut self, upvalue: usize, value: impl Into<Value>) -> Result<(), Error> {
-        let closure = self.get_running_closure();
+        let closure = self.get_running_closure()?;
         let upvalue = closure.upvalue(upvalue)?;
         let value = value.into();
 
         match upvalue.as_ref().borrow_mut().deref_mut() {
             Upvalue::Open(dst) => {
-                self.stack[*dst] = value;
+                *self.stack.get_mut(*dst).ok_or(Error::CorruptStack)? = value;
             }
             Upvalue::Closed(upvalue) => {
                 *upvalue = value;
@@ -240,71 +1194,175 @@ impl Lua {
         Ok(())
     }
 
-    fn read_bytecode(&mut self) -> Option<Bytecode> {
-        if self.stack_frame.is_empty() {
-            return None;
+    /// Calls the hooks for the bytecode that is about to run, the
+    /// program counter already points to the next bytecode
+    fn hook_bytecode(&mut self) -> Result<(), Error> {
+        let Some(hooks) = self.hooks.as_mut().filter(|hooks| !hooks.running) else {
+            return Ok(());
+        };
+        let line_hook = hooks.mask.line;
+        if hooks.count() {
+            self.call_hook(HookEvent::Count)?;
+        }
+
+        if line_hook {
+            let pc = self.get_stack_frame()?.program_counter - 1;
+            let previous = self.get_stack_frame_mut()?.line_hooked_at.replace(pc);
+            let program = self.get_running_program()?;
+            let line = program.line(pc);
+            let new_line = previous.is_none_or(|previous| {
+                // Jumping back repeats the line, as a loop would
+                pc <= previous || program.line(previous) != line
+            });
+            if let Some(line) = line.filter(|_| new_line) {
+                self.call_hook(HookEvent::Line(line))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls the hook for the function that was just called
+    fn hook_call(&mut self) -> Result<(), Error> {
+        if !self.hooks.as_ref().is_some_and(|hooks| hooks.mask.call) {
+            return Ok(());
         }
+        if self.get_stack_frame()?.tail_call {
+            self.call_hook(HookEvent::TailCall)
+        } else {
+            self.call_hook(HookEvent::Call)
+        }
+    }
+
+    /// Calls the hook for the function that is about to return
+    fn hook_return(&mut self) -> Result<(), Error> {
+        if !self.hooks.as_ref().is_some_and(|hooks| hooks.mask.ret) {
+            return Ok(());
+        }
+        self.call_hook(HookEvent::Return)
+    }
 
-        let stack_frame = self.get_stack_frame_mut();
-        let pc = &mut stack_frame.program_counter;
+    fn call_hook(&mut self, event: HookEvent) -> Result<(), Error> {
+        let Some(hooks) = self.hooks.as_mut().filter(|hooks| !hooks.running) else {
+            return Ok(());
+        };
+        hooks.running = true;
+        let hook = hooks.hook();
+        let result = hook(self, event);
+        // The hook may have replaced itself
+        if let Some(hooks) = self.hooks.as_mut() {
+            hooks.running = false;
+        }
+        result
+    }
 
-        let old = *pc;
-        *pc += 1;
+    /// Records the arguments of the call being executed, the function
+    /// is on `func_index` and the arguments follow it
+    fn profile_call(&mut self, func_index: usize, in_items: usize) {
+        let Some(profiler) = self.profiler.as_mut() else {
+            return;
+        };
+        let Some(stack_frame) = self.stack_frame.last() else {
+            return;
+        };
+        let Value::Closure(closure) = &self.stack[stack_frame.function] else {
+            return;
+        };
+        let FunctionType::Lua(function) = closure.closure_type() else {
+            return;
+        };
 
-        let closure = self.get_running_closure();
+        let start = stack_frame.stack_frame + stack_frame.variadic_arguments + func_index + 1;
+        let end = match in_items {
+            0 => self.stack.len(),
+            in_items => (start + in_items - 1).min(self.stack.len()),
+        };
+        profiler.record(
+            function.program(),
+            stack_frame.program_counter - 1,
+            &self.stack[start.min(end)..end],
+        );
+    }
 
-        let program = closure.program();
-        program.read_bytecode(old)
+    fn get_running_closure(&self) -> Result<&Closure, Error> {
+        self.get_running_closure_of_stack_frame(self.get_stack_frame()?)
     }
 
-    fn get_running_closure(&self) -> &Closure {
-        self.get_running_closure_of_stack_frame(self.get_stack_frame())
+    /// Program of the running function, which must be a Lua function
+    fn get_running_program(&self) -> Result<&Program, Error> {
+        match self.get_running_closure()?.closure_type() {
+            FunctionType::Lua(function) => Ok(function.program()),
+            FunctionType::Native(_) => Err(Error::CorruptStack),
+        }
     }
 
-    fn get_running_closure_of_stack_frame(&self, stack_frame: &StackFrame) -> &Closure {
-        let func_index = stack_frame.stack_frame - 1;
+    fn get_running_closure_of_stack_frame(
+        &self,
+        stack_frame: &StackFrame,
+    ) -> Result<&Closure, Error> {
+        let func_index = stack_frame.function;
 
-        match &self.stack[func_index] {
-            Value::Closure(closure) => closure,
-            other => unreachable!(
-                "Value at {} should be a closure, but was {:?}",
-                func_index, other
-            ),
+        match self.stack.get(func_index) {
+            Some(Value::Closure(closure)) => Ok(closure),
+            other => {
+                log::error!(
+                    target: "no_deps_lua::vm",
+                    "Value at {} should be a closure, but was {:?}",
+                    func_index,
+                    other
+                );
+                Err(Error::CorruptStack)
+            }
         }
     }
 
-    fn find_upvalue(&mut self, upvalue: &str) -> Result<Rc<RefCell<Upvalue>>, Error> {
-        let mut upvalue_opt = None;
-        for stack_frame_id in (0..self.stack_frame.len()).rev() {
-            let stack_frame = &self.stack_frame[stack_frame_id];
-            let closure = self.get_running_closure_of_stack_frame(stack_frame);
-            if let Some(local) = closure
-                .program()
-                .locals
-                .iter()
-                .filter(|closure_local| closure_local.active(stack_frame.program_counter))
-                .enumerate()
-                .filter(|(_, closure_local)| closure_local.name() == upvalue)
-                .last()
-                .map(|(i, _)| i)
-            {
-                let open_upvalue =
-                    Rc::new(RefCell::new(Upvalue::Open(stack_frame.stack_frame + local)));
-                self.stack_frame[stack_frame_id]
-                    .open_upvalues
-                    .push(open_upvalue.clone());
-                upvalue_opt = Some(open_upvalue);
-                break;
+    /// Panics if an open upvalue of the running function points past its
+    /// locals in scope, which happens when the compiler misses a `CLOSE`
+    /// on a path that leaves the scope of a captured local
+    #[cfg(feature = "debug_checks")]
+    fn assert_open_upvalues(&self, code: Bytecode) {
+        // `break`s and gotos leave the scope before arriving at the
+        // `CLOSE` that closes their locals
+        if code.opcode() == Some(bytecode::OpCode::Close) {
+            return;
+        }
+        let (Some(frame), Ok(program)) = (self.stack_frame.last(), self.get_running_program())
+        else {
+            return;
+        };
+        let active = program
+            .locals
+            .iter()
+            .filter(|local| local.active(frame.program_counter))
+            .count();
+        let first_free = frame.stack_frame + frame.variadic_arguments + active;
+        for upvalue in frame.open_upvalues.iter() {
+            if let Upvalue::Open(slot) = *upvalue.borrow() {
+                assert!(
+                    slot < first_free,
+                    "Open upvalue of stack[{slot}] outlived its local, before bytecode {} `{code:?}`.",
+                    frame.program_counter - 1,
+                );
             }
         }
+    }
 
-        if let Some(upvalue) = upvalue_opt {
-            Ok(upvalue)
-        } else if upvalue == "_ENV" {
-            self.get_running_closure_of_stack_frame(&self.stack_frame[0])
-                .upvalue(0)
-        } else {
-            Err(Error::UpvalueDoesNotExist)
+    /// Open upvalue of the register of the running function, closures that
+    /// capture the same local share it
+    fn capture_register(&mut self, register: u8) -> Result<Rc<RefCell<Upvalue>>, Error> {
+        let (frame_start, variadics) = self.running_frame_start();
+        // Registers of variadic functions start after their varargs
+        let slot = frame_start + variadics + usize::from(register);
+
+        let stack_frame = self.get_stack_frame_mut()?;
+        if let Some(upvalue) = stack_frame
+            .open_upvalues
+            .iter()
+            .find(|upvalue| matches!(*upvalue.borrow(), Upvalue::Open(open) if open == slot))
+        {
+            return Ok(upvalue.clone());
         }
+        let upvalue = Rc::new(RefCell::new(Upvalue::Open(slot)));
+        stack_frame.open_upvalues.push(upvalue.clone());
+        Ok(upvalue)
     }
 }
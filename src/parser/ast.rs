@@ -0,0 +1,722 @@
+//! Syntax tree of Lua programs
+//!
+//! Built by [`Parser::parse_to_ast`](super::Parser::parse_to_ast) for
+//! tools that work on the source, like linters, formatters, or other code
+//! generators, without compiling it. Names and literals borrow from the
+//! source, and string literals are kept as written, without resolving
+//! their escapes.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use super::{Token, TokenType};
+
+macro_rules! make_deconstruct {
+    ($($name:ident($token:pat$(,)?)),+$(,)?) => {
+        [$($name @ Token {
+            tokens: _,
+            token_type: $token,
+            line: _,
+        },)+]
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk<'a> {
+    pub block: Block<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block<'a> {
+    pub statements: Vec<Statement<'a>>,
+    /// `return` that ends the block
+    pub ret: Option<Return<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Return<'a> {
+    pub values: Vec<Expression<'a>>,
+    /// Line of the `return`, starting at 1
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement<'a> {
+    pub kind: StatementKind<'a>,
+    /// Line where the statement starts, starting at 1
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatementKind<'a> {
+    /// `;`
+    Empty,
+    /// `a, b.c = 1, 2`, the targets are names, indexes, or members
+    Assignment {
+        targets: Vec<Expression<'a>>,
+        values: Vec<Expression<'a>>,
+    },
+    Call(Call<'a>),
+    /// `::name::`
+    Label(&'a str),
+    Break,
+    Goto(&'a str),
+    Do(Block<'a>),
+    While {
+        condition: Expression<'a>,
+        block: Block<'a>,
+    },
+    Repeat {
+        block: Block<'a>,
+        condition: Expression<'a>,
+    },
+    /// `if` and its `elseif`s, in order, followed by the `else`
+    If {
+        branches: Vec<(Expression<'a>, Block<'a>)>,
+        otherwise: Option<Block<'a>>,
+    },
+    /// `for i = start, limit, step do`
+    NumericFor {
+        name: &'a str,
+        start: Expression<'a>,
+        limit: Expression<'a>,
+        step: Option<Expression<'a>>,
+        block: Block<'a>,
+    },
+    /// `for k, v in values do`
+    GenericFor {
+        names: Vec<&'a str>,
+        values: Vec<Expression<'a>>,
+        block: Block<'a>,
+    },
+    /// `function a.b:c() end`
+    Function {
+        name: FunctionName<'a>,
+        body: FunctionBody<'a>,
+    },
+    LocalFunction {
+        name: &'a str,
+        body: FunctionBody<'a>,
+    },
+    /// `local a <const>, b = 1, 2`
+    Local {
+        names: Vec<LocalName<'a>>,
+        values: Vec<Expression<'a>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalName<'a> {
+    pub name: &'a str,
+    /// Attribute between `<` and `>`, like `const` or `close`
+    pub attrib: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionName<'a> {
+    /// Names separated by `.`
+    pub path: Vec<&'a str>,
+    /// Name after `:`
+    pub method: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionBody<'a> {
+    pub parameters: Vec<&'a str>,
+    /// Whether the parameters end with `...`
+    pub variadic: bool,
+    pub block: Block<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call<'a> {
+    pub function: Box<Expression<'a>>,
+    /// Name after `:`, the function is called with `function` as
+    /// its first argument
+    pub method: Option<&'a str>,
+    pub arguments: Vec<Expression<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression<'a> {
+    Nil,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    /// Contents of the string literal, with escapes as written
+    String(&'a str),
+    /// Contents of the long string literal, see [`LexemeType::LongString`](crate::lex::LexemeType::LongString)
+    LongString(&'a str),
+    /// `...`
+    VarArgs,
+    Function(FunctionBody<'a>),
+    Table(Vec<Field<'a>>),
+    Binary {
+        operator: BinaryOperator,
+        lhs: Box<Expression<'a>>,
+        rhs: Box<Expression<'a>>,
+    },
+    Unary {
+        operator: UnaryOperator,
+        operand: Box<Expression<'a>>,
+    },
+    Name(&'a str),
+    /// `table[key]`
+    Index {
+        table: Box<Expression<'a>>,
+        key: Box<Expression<'a>>,
+    },
+    /// `table.name`
+    Member {
+        table: Box<Expression<'a>>,
+        name: &'a str,
+    },
+    Call(Call<'a>),
+    /// `(exp)`, which truncates calls and varargs to a single value
+    Parenthesized(Box<Expression<'a>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field<'a> {
+    /// `[key] = value`
+    Keyed {
+        key: Expression<'a>,
+        value: Expression<'a>,
+    },
+    /// `name = value`
+    Named {
+        name: &'a str,
+        value: Expression<'a>,
+    },
+    /// `value`, stored on the next integer key
+    Positional(Expression<'a>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOperator {
+    Or,
+    And,
+    Less,
+    Greater,
+    Leq,
+    Geq,
+    Eq,
+    Neq,
+    BitOr,
+    BitXor,
+    BitAnd,
+    ShiftL,
+    ShiftR,
+    Concat,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Idiv,
+    Mod,
+    Pow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOperator {
+    /// `not`
+    Not,
+    /// `#`
+    Len,
+    /// `-`
+    Neg,
+    /// `~`
+    BitNot,
+}
+
+impl<'a> Block<'a> {
+    pub(super) fn from_token(block: &Token<'a>) -> Self {
+        match block.tokens.as_slice() {
+            make_deconstruct!(
+                block_stat(TokenType::BlockStat),
+                block_retstat(TokenType::BlockRetstat)
+            ) => {
+                let mut statements = Vec::new();
+                let mut block_stat = block_stat;
+                while let make_deconstruct!(stat(TokenType::Stat), next(TokenType::BlockStat)) =
+                    block_stat.tokens.as_slice()
+                {
+                    statements.push(Statement::from_token(stat));
+                    block_stat = next;
+                }
+                let ret = match block_retstat.tokens.as_slice() {
+                    make_deconstruct!(retstat(TokenType::Retstat)) => {
+                        Some(Return::from_token(retstat))
+                    }
+                    _ => None,
+                };
+                Self { statements, ret }
+            }
+            _ => unreachable!("Block did not match any production."),
+        }
+    }
+}
+
+impl<'a> Return<'a> {
+    fn from_token(retstat: &Token<'a>) -> Self {
+        match retstat.tokens.as_slice() {
+            make_deconstruct!(
+                _return(TokenType::Return),
+                retstat_explist(TokenType::RetstatExplist),
+                _retstat_end(TokenType::RetstatEnd)
+            ) => Self {
+                values: match retstat_explist.tokens.as_slice() {
+                    make_deconstruct!(explist(TokenType::Explist)) => explist_from_token(explist),
+                    _ => Vec::new(),
+                },
+                line: retstat.line,
+            },
+            _ => unreachable!("Retstat did not match any production."),
+        }
+    }
+}
+
+impl<'a> Statement<'a> {
+    pub(super) fn from_token(stat: &Token<'a>) -> Self {
+        let kind = match stat.tokens.as_slice() {
+            make_deconstruct!(_semicolon(TokenType::SemiColon)) => StatementKind::Empty,
+            make_deconstruct!(
+                varlist(TokenType::Varlist),
+                _assign(TokenType::Assign),
+                explist(TokenType::Explist)
+            ) => {
+                let mut targets = Vec::new();
+                let mut list = varlist;
+                while let [var, cont] | [_, var, cont] = list.tokens.as_slice() {
+                    targets.push(Expression::from_var(var));
+                    list = cont;
+                }
+                StatementKind::Assignment {
+                    targets,
+                    values: explist_from_token(explist),
+                }
+            }
+            make_deconstruct!(functioncall(TokenType::Functioncall)) => {
+                StatementKind::Call(Call::from_token(functioncall))
+            }
+            make_deconstruct!(label(TokenType::Label)) => match label.tokens.as_slice() {
+                [_, name, _] => StatementKind::Label(name_from_token(name)),
+                _ => unreachable!("Label did not match any production."),
+            },
+            make_deconstruct!(_break(TokenType::Break)) => StatementKind::Break,
+            make_deconstruct!(_goto(TokenType::Goto), name(TokenType::Name(_))) => {
+                StatementKind::Goto(name_from_token(name))
+            }
+            make_deconstruct!(
+                _do(TokenType::Do),
+                block(TokenType::Block),
+                _end(TokenType::End)
+            ) => StatementKind::Do(Block::from_token(block)),
+            make_deconstruct!(
+                _while(TokenType::While),
+                exp(TokenType::Exp),
+                _do(TokenType::Do),
+                block(TokenType::Block),
+                _end(TokenType::End)
+            ) => StatementKind::While {
+                condition: Expression::from_token(exp),
+                block: Block::from_token(block),
+            },
+            make_deconstruct!(
+                _repeat(TokenType::Repeat),
+                block(TokenType::Block),
+                _until(TokenType::Until),
+                exp(TokenType::Exp)
+            ) => StatementKind::Repeat {
+                block: Block::from_token(block),
+                condition: Expression::from_token(exp),
+            },
+            make_deconstruct!(
+                _if(TokenType::If),
+                exp(TokenType::Exp),
+                _then(TokenType::Then),
+                block(TokenType::Block),
+                stat_if(TokenType::StatIf),
+                _end(TokenType::End)
+            ) => {
+                let mut branches =
+                    [(Expression::from_token(exp), Block::from_token(block))].to_vec();
+                let mut otherwise = None;
+                let mut stat_if = stat_if;
+                loop {
+                    match stat_if.tokens.as_slice() {
+                        [] => break,
+                        make_deconstruct!(
+                            _elseif(TokenType::Elseif),
+                            exp(TokenType::Exp),
+                            _then(TokenType::Then),
+                            block(TokenType::Block),
+                            next(TokenType::StatIf)
+                        ) => {
+                            branches.push((Expression::from_token(exp), Block::from_token(block)));
+                            stat_if = next;
+                        }
+                        make_deconstruct!(_else(TokenType::Else), block(TokenType::Block)) => {
+                            otherwise = Some(Block::from_token(block));
+                            break;
+                        }
+                        _ => unreachable!("StatIf did not match any production."),
+                    }
+                }
+                StatementKind::If {
+                    branches,
+                    otherwise,
+                }
+            }
+            make_deconstruct!(
+                _for(TokenType::For),
+                name(TokenType::Name(_)),
+                _assign(TokenType::Assign),
+                start(TokenType::Exp),
+                _comma(TokenType::Comma),
+                limit(TokenType::Exp),
+                stat_forexp(TokenType::StatForexp),
+                _do(TokenType::Do),
+                block(TokenType::Block),
+                _end(TokenType::End)
+            ) => StatementKind::NumericFor {
+                name: name_from_token(name),
+                start: Expression::from_token(start),
+                limit: Expression::from_token(limit),
+                step: match stat_forexp.tokens.as_slice() {
+                    [_comma, step] => Some(Expression::from_token(step)),
+                    _ => None,
+                },
+                block: Block::from_token(block),
+            },
+            make_deconstruct!(
+                _for(TokenType::For),
+                namelist(TokenType::Namelist),
+                _in(TokenType::In),
+                explist(TokenType::Explist),
+                _do(TokenType::Do),
+                block(TokenType::Block),
+                _end(TokenType::End)
+            ) => {
+                let mut names = Vec::new();
+                let mut list = namelist;
+                while let [name, cont] | [_, name, cont] = list.tokens.as_slice() {
+                    names.push(name_from_token(name));
+                    list = cont;
+                }
+                StatementKind::GenericFor {
+                    names,
+                    values: explist_from_token(explist),
+                    block: Block::from_token(block),
+                }
+            }
+            make_deconstruct!(
+                _function(TokenType::Function),
+                funcname(TokenType::Funcname),
+                funcbody(TokenType::Funcbody)
+            ) => StatementKind::Function {
+                name: FunctionName::from_token(funcname),
+                body: FunctionBody::from_token(funcbody),
+            },
+            make_deconstruct!(
+                _local(TokenType::Local),
+                _function(TokenType::Function),
+                name(TokenType::Name(_)),
+                funcbody(TokenType::Funcbody)
+            ) => StatementKind::LocalFunction {
+                name: name_from_token(name),
+                body: FunctionBody::from_token(funcbody),
+            },
+            make_deconstruct!(
+                _local(TokenType::Local),
+                attnamelist(TokenType::Attnamelist),
+                stat_attexplist(TokenType::StatAttexplist)
+            ) => {
+                let mut names = Vec::new();
+                let mut list = attnamelist;
+                while let [name, attrib, cont] | [_, name, attrib, cont] = list.tokens.as_slice() {
+                    names.push(LocalName {
+                        name: name_from_token(name),
+                        attrib: match attrib.tokens.as_slice() {
+                            [_less, attrib, _greater] => Some(name_from_token(attrib)),
+                            _ => None,
+                        },
+                    });
+                    list = cont;
+                }
+                StatementKind::Local {
+                    names,
+                    values: match stat_attexplist.tokens.as_slice() {
+                        [_assign, explist] => explist_from_token(explist),
+                        _ => Vec::new(),
+                    },
+                }
+            }
+            _ => unreachable!("Stat did not match any production."),
+        };
+        Self {
+            kind,
+            line: stat.line,
+        }
+    }
+}
+
+impl<'a> FunctionName<'a> {
+    fn from_token(funcname: &Token<'a>) -> Self {
+        match funcname.tokens.as_slice() {
+            [name, funcname_cont, funcname_end] => {
+                let mut path = [name_from_token(name)].to_vec();
+                let mut list = funcname_cont;
+                while let [_dot, name, cont] = list.tokens.as_slice() {
+                    path.push(name_from_token(name));
+                    list = cont;
+                }
+                Self {
+                    path,
+                    method: match funcname_end.tokens.as_slice() {
+                        [_colon, name] => Some(name_from_token(name)),
+                        _ => None,
+                    },
+                }
+            }
+            _ => unreachable!("Funcname did not match any production."),
+        }
+    }
+}
+
+impl<'a> FunctionBody<'a> {
+    fn from_token(funcbody: &Token<'a>) -> Self {
+        match funcbody.tokens.as_slice() {
+            [_lparen, funcbody_parlist, _rparen, block, _end] => {
+                let mut parameters = Vec::new();
+                let mut variadic = false;
+                if let [parlist] = funcbody_parlist.tokens.as_slice() {
+                    let mut list = parlist;
+                    loop {
+                        match list.tokens.as_slice() {
+                            [] => break,
+                            [
+                                Token {
+                                    tokens: _,
+                                    token_type: TokenType::Dots,
+                                    line: _,
+                                },
+                            ]
+                            | [
+                                _,
+                                Token {
+                                    tokens: _,
+                                    token_type: TokenType::Dots,
+                                    line: _,
+                                },
+                            ] => {
+                                variadic = true;
+                                break;
+                            }
+                            [name, cont] | [_, name, cont] => {
+                                parameters.push(name_from_token(name));
+                                list = cont;
+                            }
+                            _ => unreachable!("Parlist did not match any production."),
+                        }
+                    }
+                }
+                Self {
+                    parameters,
+                    variadic,
+                    block: Block::from_token(block),
+                }
+            }
+            _ => unreachable!("Funcbody did not match any production."),
+        }
+    }
+}
+
+impl<'a> Call<'a> {
+    fn from_token(functioncall: &Token<'a>) -> Self {
+        let (prefixexp, method, args) = match functioncall.tokens.as_slice() {
+            [prefixexp, args] => (prefixexp, None, args),
+            [prefixexp, _colon, name, args] => (prefixexp, Some(name_from_token(name)), args),
+            _ => unreachable!("Functioncall did not match any production."),
+        };
+        let arguments = match args.tokens.as_slice() {
+            [_lparen, args_explist, _rparen] => match args_explist.tokens.as_slice() {
+                [explist] => explist_from_token(explist),
+                _ => Vec::new(),
+            },
+            make_deconstruct!(tableconstructor(TokenType::Tableconstructor)) => {
+                [Expression::from_token_type(tableconstructor)].to_vec()
+            }
+            make_deconstruct!(string(TokenType::String(_) | TokenType::LongString(_))) => {
+                [Expression::from_token_type(string)].to_vec()
+            }
+            _ => unreachable!("Args did not match any production."),
+        };
+        Self {
+            function: Box::new(Expression::from_prefixexp(prefixexp)),
+            method,
+            arguments,
+        }
+    }
+}
+
+impl<'a> Expression<'a> {
+    fn from_token(exp: &Token<'a>) -> Self {
+        match exp.tokens.as_slice() {
+            [single] => Self::from_token_type(single),
+            [lhs, binop, rhs] => Self::Binary {
+                operator: match binop.tokens.as_slice() {
+                    [operator] => BinaryOperator::from_token_type(&operator.token_type),
+                    _ => unreachable!("Binop did not match any production."),
+                },
+                lhs: Box::new(Self::from_token(lhs)),
+                rhs: Box::new(Self::from_token(rhs)),
+            },
+            [unop, operand] => Self::Unary {
+                operator: match unop.tokens.as_slice() {
+                    [operator] => UnaryOperator::from_token_type(&operator.token_type),
+                    _ => unreachable!("Unop did not match any production."),
+                },
+                operand: Box::new(Self::from_token(operand)),
+            },
+            _ => unreachable!("Exp did not match any production."),
+        }
+    }
+
+    /// Expression made of a single token
+    fn from_token_type(token: &Token<'a>) -> Self {
+        match token.token_type {
+            TokenType::Nil => Self::Nil,
+            TokenType::False => Self::Boolean(false),
+            TokenType::True => Self::Boolean(true),
+            TokenType::String(string) => Self::String(string),
+            TokenType::LongString(string) => Self::LongString(string),
+            TokenType::Integer(integer) => Self::Integer(integer),
+            TokenType::Float(float) => Self::Float(float),
+            TokenType::Dots => Self::VarArgs,
+            TokenType::Functiondef => match token.tokens.as_slice() {
+                [_function, funcbody] => Self::Function(FunctionBody::from_token(funcbody)),
+                _ => unreachable!("Functiondef did not match any production."),
+            },
+            TokenType::Prefixexp => Self::from_prefixexp(token),
+            TokenType::Tableconstructor => match token.tokens.as_slice() {
+                [_lcurly, fieldlist, _rcurly] => Self::Table(fields_from_token(fieldlist)),
+                _ => unreachable!("Tableconstructor did not match any production."),
+            },
+            _ => unreachable!("Exp did not match any production."),
+        }
+    }
+
+    fn from_prefixexp(prefixexp: &Token<'a>) -> Self {
+        match prefixexp.tokens.as_slice() {
+            make_deconstruct!(var(TokenType::Var)) => Self::from_var(var),
+            make_deconstruct!(functioncall(TokenType::Functioncall)) => {
+                Self::Call(Call::from_token(functioncall))
+            }
+            [_lparen, exp, _rparen] => Self::Parenthesized(Box::new(Self::from_token(exp))),
+            _ => unreachable!("Prefixexp did not match any production."),
+        }
+    }
+
+    fn from_var(var: &Token<'a>) -> Self {
+        match var.tokens.as_slice() {
+            [name] => Self::Name(name_from_token(name)),
+            [prefixexp, _lsquare, exp, _rsquare] => Self::Index {
+                table: Box::new(Self::from_prefixexp(prefixexp)),
+                key: Box::new(Self::from_token(exp)),
+            },
+            [prefixexp, _dot, name] => Self::Member {
+                table: Box::new(Self::from_prefixexp(prefixexp)),
+                name: name_from_token(name),
+            },
+            _ => unreachable!("Var did not match any production."),
+        }
+    }
+}
+
+impl BinaryOperator {
+    fn from_token_type(token_type: &TokenType) -> Self {
+        match token_type {
+            TokenType::Or => Self::Or,
+            TokenType::And => Self::And,
+            TokenType::Less => Self::Less,
+            TokenType::Greater => Self::Greater,
+            TokenType::Leq => Self::Leq,
+            TokenType::Geq => Self::Geq,
+            TokenType::Eq => Self::Eq,
+            TokenType::Neq => Self::Neq,
+            TokenType::BitOr => Self::BitOr,
+            TokenType::BitXor => Self::BitXor,
+            TokenType::BitAnd => Self::BitAnd,
+            TokenType::ShiftL => Self::ShiftL,
+            TokenType::ShiftR => Self::ShiftR,
+            TokenType::Concat => Self::Concat,
+            TokenType::Add => Self::Add,
+            TokenType::Sub => Self::Sub,
+            TokenType::Mul => Self::Mul,
+            TokenType::Div => Self::Div,
+            TokenType::Idiv => Self::Idiv,
+            TokenType::Mod => Self::Mod,
+            TokenType::Pow => Self::Pow,
+            _ => unreachable!("Binop did not match any production."),
+        }
+    }
+}
+
+impl UnaryOperator {
+    fn from_token_type(token_type: &TokenType) -> Self {
+        match token_type {
+            TokenType::Not => Self::Not,
+            TokenType::Len => Self::Len,
+            TokenType::Sub => Self::Neg,
+            TokenType::BitXor => Self::BitNot,
+            _ => unreachable!("Unop did not match any production."),
+        }
+    }
+}
+
+fn name_from_token<'a>(name: &Token<'a>) -> &'a str {
+    match name.token_type {
+        TokenType::Name(name) => name,
+        _ => unreachable!("Expected a name, got {:?}.", name.token_type),
+    }
+}
+
+fn explist_from_token<'a>(explist: &Token<'a>) -> Vec<Expression<'a>> {
+    let mut expressions = Vec::new();
+    let mut list = explist;
+    while let [exp, cont] | [_, exp, cont] = list.tokens.as_slice() {
+        expressions.push(Expression::from_token(exp));
+        list = cont;
+    }
+    expressions
+}
+
+fn fields_from_token<'a>(tableconstructor_fieldlist: &Token<'a>) -> Vec<Field<'a>> {
+    let mut fields = Vec::new();
+    let [fieldlist] = tableconstructor_fieldlist.tokens.as_slice() else {
+        return fields;
+    };
+    let mut field_and_cont = match fieldlist.tokens.as_slice() {
+        [field, cont] => Some((field, cont)),
+        _ => unreachable!("Fieldlist did not match any production."),
+    };
+    while let Some((field, cont)) = field_and_cont {
+        fields.push(match field.tokens.as_slice() {
+            [_lsquare, key, _rsquare, _assign, value] => Field::Keyed {
+                key: Expression::from_token(key),
+                value: Expression::from_token(value),
+            },
+            [name, _assign, value] => Field::Named {
+                name: name_from_token(name),
+                value: Expression::from_token(value),
+            },
+            [value] => Field::Positional(Expression::from_token(value)),
+            _ => unreachable!("Field did not match any production."),
+        });
+        field_and_cont = match cont.tokens.as_slice() {
+            [_fieldsep, field, cont] => Some((field, cont)),
+            _ => None,
+        };
+    }
+    fields
+}
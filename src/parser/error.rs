@@ -1,10 +1,15 @@
 use core::fmt::Display;
 
+/// Failure to parse a program, see [`Parser::check`](super::Parser::check)
+/// for where it happened
 #[derive(Debug)]
 pub enum Error {
     Accept,
     Reduction,
     Lex,
+    UnexpectedToken,
+    /// Expressions, blocks, and functions are nested too deeply
+    TooManyLevels,
 }
 
 impl Display for Error {
@@ -19,6 +24,12 @@ impl Display for Error {
             Self::Lex => {
                 write!(f, "Could not parse program due to lexical error.")
             }
+            Self::UnexpectedToken => {
+                write!(f, "Could not parse program due to unexpected token.")
+            }
+            Self::TooManyLevels => {
+                write!(f, "Could not parse program due to too many syntax levels.")
+            }
         }
     }
 }
@@ -31,3 +42,39 @@ impl From<crate::lex::Error> for Error {
         Self::Lex
     }
 }
+
+/// Syntax error found by [`Program::check`](crate::Program::check)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompileError {
+    pub kind: CompileErrorKind,
+    /// Line of the error, starting at 1
+    pub line: usize,
+    /// Column of the error, in bytes, starting at 1
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompileErrorKind {
+    /// The source could not be split into tokens
+    Lexical(&'static str),
+    /// A token is not allowed where it appears
+    UnexpectedToken,
+    /// The source ended before a statement was complete
+    UnexpectedEof,
+    /// Expressions, blocks, and functions are nested too deeply
+    TooManyLevels,
+}
+
+impl Display for CompileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match self.kind {
+            CompileErrorKind::Lexical(message) => write!(f, "{}", message),
+            CompileErrorKind::UnexpectedToken => write!(f, "Unexpected token."),
+            CompileErrorKind::UnexpectedEof => write!(f, "Unexpected end of file."),
+            CompileErrorKind::TooManyLevels => write!(f, "Too many syntax levels."),
+        }
+    }
+}
+
+impl core::error::Error for CompileError {}
@@ -0,0 +1,3147 @@
+//! LR(1) action and goto tables of the Lua grammar
+//!
+//! Generated by the `parser_table` example from the LR(1) table in
+//! `PARSER.md`, rerun it instead of editing the tables by hand:
+//! ```text
+//! cargo run --example parser_table
+//! ```
+//! The shift-reduce conflicts of binary operators are left to be resolved by
+//! precedence while parsing. Rows are indexed by state, columns by the
+//! position of the terminal or non-terminal on [`TokenType`].
+
+use super::TokenType;
+
+/// Number of states of the parser
+pub const STATES: usize = 1472;
+/// Number of terminals, the columns of [`ACTION`]
+pub const TERMINALS: usize = 60;
+/// Number of non-terminals, the columns of [`GOTO`]
+pub const NON_TERMINALS: usize = 43;
+
+const KIND_SHIFT: u16 = 13;
+const SHIFT: u16 = 1 << KIND_SHIFT;
+const REDUCE: u16 = 2 << KIND_SHIFT;
+const RESOLVE: u16 = 3 << KIND_SHIFT;
+const ARGUMENT: u16 = SHIFT - 1;
+
+/// Action of the parser
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    /// Consumes the lookahead and goes to the state
+    Shift(usize),
+    /// Reduces the top of the stack with the production
+    Reduce(usize),
+    /// Shifts to the state, or reduces a binary expression, depending on
+    /// the precedence of the lookahead and of the operator on the stack
+    Resolve(usize),
+}
+
+/// Entry of [`ACTION`], packed in 16 bits, the kind of action on the
+/// highest 3 bits and its argument on the others
+#[derive(Debug, Clone, Copy)]
+pub struct Entry(u16);
+
+impl Entry {
+    fn action(self) -> Option<Action> {
+        let argument = usize::from(self.0 & ARGUMENT);
+        match self.0 & !ARGUMENT {
+            SHIFT => Some(Action::Shift(argument)),
+            REDUCE => Some(Action::Reduce(argument)),
+            RESOLVE => Some(Action::Resolve(argument)),
+            _ => None,
+        }
+    }
+}
+
+/// Action of `state` with `terminal` as lookahead
+pub fn action(state: usize, terminal: usize) -> Option<Action> {
+    ACTION[state][terminal].action()
+}
+
+/// State to go to after reducing `non_terminal` on `state`
+pub fn goto(state: usize, non_terminal: usize) -> Option<usize> {
+    // No production goes back to the initial state
+    match GOTO[state][non_terminal] {
+        0 => None,
+        next => Some(usize::from(next)),
+    }
+}
+
+/// Position of the token's type on the tables, terminals come first,
+/// followed by the non-terminals
+#[allow(clippy::too_many_lines)]
+pub fn symbol(token_type: &TokenType) -> usize {
+    match token_type {
+        TokenType::And => 0,
+        TokenType::Break => 1,
+        TokenType::Do => 2,
+        TokenType::Else => 3,
+        TokenType::Elseif => 4,
+        TokenType::End => 5,
+        TokenType::False => 6,
+        TokenType::For => 7,
+        TokenType::Function => 8,
+        TokenType::Goto => 9,
+        TokenType::If => 10,
+        TokenType::In => 11,
+        TokenType::Local => 12,
+        TokenType::Nil => 13,
+        TokenType::Not => 14,
+        TokenType::Or => 15,
+        TokenType::Repeat => 16,
+        TokenType::Return => 17,
+        TokenType::Then => 18,
+        TokenType::True => 19,
+        TokenType::Until => 20,
+        TokenType::While => 21,
+        TokenType::Add => 22,
+        TokenType::Sub => 23,
+        TokenType::Mul => 24,
+        TokenType::Div => 25,
+        TokenType::Mod => 26,
+        TokenType::Pow => 27,
+        TokenType::Len => 28,
+        TokenType::BitAnd => 29,
+        TokenType::BitOr => 30,
+        TokenType::BitXor => 31,
+        TokenType::ShiftL => 32,
+        TokenType::ShiftR => 33,
+        TokenType::Idiv => 34,
+        TokenType::Eq => 35,
+        TokenType::Neq => 36,
+        TokenType::Leq => 37,
+        TokenType::Geq => 38,
+        TokenType::Less => 39,
+        TokenType::Greater => 40,
+        TokenType::Assign => 41,
+        TokenType::LParen => 42,
+        TokenType::RParen => 43,
+        TokenType::LCurly => 44,
+        TokenType::RCurly => 45,
+        TokenType::LSquare => 46,
+        TokenType::RSquare => 47,
+        TokenType::SemiColon => 48,
+        TokenType::Colon => 49,
+        TokenType::DoubleColon => 50,
+        TokenType::Comma => 51,
+        TokenType::Dot => 52,
+        TokenType::Concat => 53,
+        TokenType::Dots => 54,
+        TokenType::Integer(_) => 55,
+        TokenType::Float(_) => 56,
+        TokenType::String(_) | TokenType::LongString(_) => 57,
+        TokenType::Name(_) => 58,
+        TokenType::Eof => 59,
+        TokenType::Chunk => 60,
+        TokenType::Block => 61,
+        TokenType::BlockStat => 62,
+        TokenType::BlockRetstat => 63,
+        TokenType::Stat => 64,
+        TokenType::StatIf => 65,
+        TokenType::StatForexp => 66,
+        TokenType::StatAttexplist => 67,
+        TokenType::Attnamelist => 68,
+        TokenType::AttnamelistCont => 69,
+        TokenType::Attrib => 70,
+        TokenType::Retstat => 71,
+        TokenType::RetstatExplist => 72,
+        TokenType::RetstatEnd => 73,
+        TokenType::Label => 74,
+        TokenType::Funcname => 75,
+        TokenType::FuncnameCont => 76,
+        TokenType::FuncnameEnd => 77,
+        TokenType::Varlist => 78,
+        TokenType::VarlistCont => 79,
+        TokenType::Var => 80,
+        TokenType::Namelist => 81,
+        TokenType::NamelistCont => 82,
+        TokenType::Explist => 83,
+        TokenType::ExplistCont => 84,
+        TokenType::Exp => 85,
+        TokenType::Prefixexp => 86,
+        TokenType::Functioncall => 87,
+        TokenType::Args => 88,
+        TokenType::ArgsExplist => 89,
+        TokenType::Functiondef => 90,
+        TokenType::Funcbody => 91,
+        TokenType::FuncbodyParlist => 92,
+        TokenType::Parlist => 93,
+        TokenType::ParlistCont => 94,
+        TokenType::Tableconstructor => 95,
+        TokenType::TableconstructorFieldlist => 96,
+        TokenType::Fieldlist => 97,
+        TokenType::FieldlistCont => 98,
+        TokenType::Field => 99,
+        TokenType::Fieldsep => 100,
+        TokenType::Binop => 101,
+        TokenType::Unop => 102,
+    }
+}
+
+const __: Entry = Entry(0);
+
+const fn s(state: u16) -> Entry {
+    Entry(SHIFT | state)
+}
+
+const fn r(production: u16) -> Entry {
+    Entry(REDUCE | production)
+}
+
+const fn p(state: u16) -> Entry {
+    Entry(RESOLVE | state)
+}
+
+/// Columns: And, Break, Do, Else, Elseif, End, False, For, Function, Goto, If, In, Local, Nil, Not, Or, Repeat, Return, Then, True, Until, While, Add, Sub, Mul, Div, Mod, Pow, Len, BitAnd, BitOr, BitXor, ShiftL, ShiftR, Idiv, Eq, Neq, Leq, Geq, Less, Greater, Assign, LParen, RParen, LCurly, RCurly, LSquare, RSquare, SemiColon, Colon, DoubleColon, Comma, Dot, Concat, Dots, Integer, Float, String, Name, Eof
+#[rustfmt::skip]
+static ACTION: [[Entry; TERMINALS]; STATES] = [
+    /*    0 */ [__, s(4), s(5), __, __, __, __, s(6), s(7), s(8), s(9), __, s(10), __, __, __, s(11), r(2), __, __, __, s(12), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(13), __, s(17), __, __, __, __, __, __, __, s(19), r(2)],
+    /*    1 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(0)],
+    /*    2 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(4)],
+    /*    3 */ [__, s(4), s(5), __, __, __, __, s(6), s(7), s(8), s(9), __, s(10), __, __, __, s(11), r(2), __, __, __, s(12), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(13), __, s(17), __, __, __, __, __, __, __, s(19), r(2)],
+    /*    4 */ [__, r(10), r(10), __, __, __, __, r(10), r(10), r(10), r(10), __, r(10), __, __, __, r(10), r(10), __, __, __, r(10), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(10), __, __, __, __, __, r(10), __, r(10), __, __, __, __, __, __, __, r(10), r(10)],
+    /*    5 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*    6 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(30), __],
+    /*    7 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(36), __],
+    /*    8 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(29), __],
+    /*    9 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*   10 */ [__, __, __, __, __, __, __, __, s(24), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(34), __],
+    /*   11 */ [__, s(46), s(48), __, __, __, __, s(66), s(68), s(69), s(71), __, s(101), __, __, __, s(103), r(2), __, __, r(2), s(106), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(115), __, s(119), __, __, __, __, __, __, __, s(19), __],
+    /*   12 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /*   13 */ [__, r(6), r(6), __, __, __, __, r(6), r(6), r(6), r(6), __, r(6), __, __, __, r(6), r(6), __, __, __, r(6), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(6), __, __, __, __, __, r(6), __, r(6), __, __, __, __, __, __, __, r(6), r(6)],
+    /*   14 */ [__, r(9), r(9), __, __, __, __, r(9), r(9), r(9), r(9), __, r(9), __, __, __, r(9), r(9), __, __, __, r(9), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(9), __, __, __, __, __, r(9), __, r(9), __, __, __, __, __, __, __, r(9), r(9)],
+    /*   15 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(50), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   16 */ [__, r(8), r(8), __, __, __, __, r(8), r(8), r(8), r(8), __, r(8), __, __, __, r(8), r(8), __, __, __, r(8), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, r(69), __, r(69), __, r(8), r(69), r(8), __, r(69), __, __, __, __, r(69), r(8), r(8)],
+    /*   17 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(31), __],
+    /*   18 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(45), r(68), __, r(68), __, r(68), __, __, r(68), __, s(35), r(68), __, __, __, __, r(68), __, __],
+    /*   19 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), r(47), __, r(47), __, r(47), __, __, r(47), __, r(47), r(47), __, __, __, __, r(47), __, __],
+    /*   20 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(87), __, s(108), __, s(47), __, __, s(73), __, __, s(72), __, __, __, __, s(75), __, __],
+    /*   21 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*   22 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(1)],
+    /*   23 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(5)],
+    /*   24 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(144), __],
+    /*   25 */ [__, r(26), r(26), __, __, __, __, r(26), r(26), r(26), r(26), __, r(26), __, __, __, r(26), r(26), __, __, __, r(26), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(184), r(26), __, __, __, __, __, r(26), __, r(26), __, __, __, __, __, __, __, r(26), r(26)],
+    /*   26 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(186), __, __, r(4), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   27 */ [__, __, __, __, __, r(4), __, __, __, __, __, __, __, __, __, __, __, s(185), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   28 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(148), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   29 */ [__, r(11), r(11), __, __, __, __, r(11), r(11), r(11), r(11), __, r(11), __, __, __, r(11), r(11), __, __, __, r(11), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(11), __, __, __, __, __, r(11), __, r(11), __, __, __, __, __, __, __, r(11), r(11)],
+    /*   30 */ [__, __, __, __, __, __, __, __, __, __, __, r(51), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(177), __, __, __, __, __, __, __, __, __, s(250), __, __, __, __, __, __, __, __],
+    /*   31 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(140), __, __, __, __, __, __, __, __, __],
+    /*   32 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(44), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   33 */ [__, __, __, __, __, __, __, __, __, __, __, s(179), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   34 */ [__, r(31), r(31), __, __, __, __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, __, r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(165), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), r(31)],
+    /*   35 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(19), __],
+    /*   36 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(40), __, __, __, __, __, __, r(40), __, __, s(142), __, __, __, __, __, __, __],
+    /*   37 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), __, r(47), __, __, r(47), __, __, r(47), r(47), __, __, __, r(47), __, __],
+    /*   38 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*   39 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, s(173), __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*   40 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(319), r(64), s(353), __, s(180), __, __, s(315), __, __, s(314), r(64), __, __, __, s(317), __, __],
+    /*   41 */ [s(283), __, s(151), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*   42 */ [__, s(46), s(48), __, __, __, __, s(66), s(68), s(69), s(71), __, s(101), __, __, __, s(103), r(2), __, __, r(2), s(106), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(115), __, s(119), __, __, __, __, __, __, __, s(19), __],
+    /*   43 */ [__, r(10), r(10), __, __, r(10), __, r(10), r(10), r(10), r(10), __, r(10), __, __, __, r(10), r(10), __, __, __, r(10), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(10), __, __, __, __, __, r(10), __, r(10), __, __, __, __, __, __, __, r(10), __],
+    /*   44 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(3), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(3)],
+    /*   45 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*   46 */ [__, r(10), r(10), __, __, __, __, r(10), r(10), r(10), r(10), __, r(10), __, __, __, r(10), r(10), __, __, r(10), r(10), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(10), __, __, __, __, __, r(10), __, r(10), __, __, __, __, __, __, __, r(10), __],
+    /*   47 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*   48 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*   49 */ [__, __, __, __, __, __, s(203), __, s(347), __, __, __, __, s(204), s(136), __, __, __, __, s(205), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(329), __, s(351), __, __, __, r(34), __, __, __, __, __, s(206), s(200), s(202), s(198), s(158), r(34)],
+    /*   50 */ [__, __, __, __, __, __, s(291), __, s(370), __, __, __, __, s(292), s(136), __, __, __, __, s(293), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(364), __, s(371), __, __, __, __, __, __, __, __, __, s(296), s(280), s(281), s(279), s(160), __],
+    /*   51 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, r(59), __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __],
+    /*   52 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, r(60), __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __],
+    /*   53 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, r(61), __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __],
+    /*   54 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, r(57), __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __],
+    /*   55 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, r(56), __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __],
+    /*   56 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, r(58), __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __],
+    /*   57 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, r(62), __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __],
+    /*   58 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(282), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*   59 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, __, r(47), __, __, r(47), r(47), __, __, __, r(47), __, __],
+    /*   60 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(312), __, s(337), __, s(188), __, __, s(305), __, __, s(304), r(64), __, __, __, s(307), __, __],
+    /*   61 */ [r(47), __, r(47), __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, __, r(47), __, __, r(47), r(47), __, __, __, r(47), __, __],
+    /*   62 */ [r(64), __, r(64), __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(324), __, s(343), __, s(190), __, __, s(321), __, __, s(320), r(64), __, __, __, s(323), __, __],
+    /*   63 */ [__, __, __, __, __, s(161), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*   64 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(162), __],
+    /*   65 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(36), __],
+    /*   66 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(166), __],
+    /*   67 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(167), __],
+    /*   68 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(36), __],
+    /*   69 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(170), __],
+    /*   70 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*   71 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*   72 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(174), __],
+    /*   73 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(176), __],
+    /*   74 */ [__, r(71), r(71), __, __, __, __, r(71), r(71), r(71), r(71), __, r(71), __, __, __, r(71), r(71), __, __, __, r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), __, __, __, __, r(71), r(71), r(71)],
+    /*   75 */ [__, r(77), r(77), __, __, __, __, r(77), r(77), r(77), r(77), __, r(77), __, __, __, r(77), r(77), __, __, __, r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), __, __, __, __, r(77), r(77), r(77)],
+    /*   76 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __],
+    /*   77 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __],
+    /*   78 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __],
+    /*   79 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __],
+    /*   80 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __],
+    /*   81 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __],
+    /*   82 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __],
+    /*   83 */ [r(59), __, r(59), __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __],
+    /*   84 */ [r(60), __, r(60), __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __],
+    /*   85 */ [r(61), __, r(61), __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __],
+    /*   86 */ [r(57), __, r(57), __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __],
+    /*   87 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*   88 */ [r(56), __, r(56), __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __],
+    /*   89 */ [r(58), __, r(58), __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __],
+    /*   90 */ [r(62), __, r(62), __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __],
+    /*   91 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, r(63), __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __],
+    /*   92 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, r(65), __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __],
+    /*   93 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*   94 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __],
+    /*   95 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __],
+    /*   96 */ [r(63), __, r(63), __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __],
+    /*   97 */ [r(65), __, r(65), __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __],
+    /*   98 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*   99 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /*  100 */ [__, __, __, __, __, __, __, __, s(232), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(234), __],
+    /*  101 */ [__, __, __, __, __, __, __, __, s(193), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(195), __],
+    /*  102 */ [__, s(46), s(48), __, __, __, __, s(66), s(68), s(69), s(71), __, s(101), __, __, __, s(103), r(2), __, __, r(2), s(106), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(115), __, s(119), __, __, __, __, __, __, __, s(19), __],
+    /*  103 */ [__, s(46), s(48), __, __, __, __, s(66), s(68), s(69), s(71), __, s(101), __, __, __, s(103), r(2), __, __, r(2), s(106), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(115), __, s(119), __, __, __, __, __, __, __, s(19), __],
+    /*  104 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(199), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  105 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /*  106 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /*  107 */ [__, r(76), r(76), __, __, __, __, r(76), r(76), r(76), r(76), __, r(76), __, __, __, r(76), r(76), __, __, __, r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), __, __, __, __, r(76), r(76), r(76)],
+    /*  108 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  109 */ [__, r(6), r(6), __, __, r(6), __, r(6), r(6), r(6), r(6), __, r(6), __, __, __, r(6), r(6), __, __, __, r(6), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(6), __, __, __, __, __, r(6), __, r(6), __, __, __, __, __, __, __, r(6), __],
+    /*  110 */ [__, r(9), r(9), __, __, r(9), __, r(9), r(9), r(9), r(9), __, r(9), __, __, __, r(9), r(9), __, __, __, r(9), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(9), __, __, __, __, __, r(9), __, r(9), __, __, __, __, __, __, __, r(9), __],
+    /*  111 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(252), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  112 */ [__, r(8), r(8), __, __, r(8), __, r(8), r(8), r(8), r(8), __, r(8), __, __, __, r(8), r(8), __, __, __, r(8), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, r(69), __, r(69), __, r(8), r(69), r(8), __, r(69), __, __, __, __, r(69), r(8), __],
+    /*  113 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(235), __],
+    /*  114 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(278), __, s(335), __, s(47), __, __, s(275), __, __, s(72), __, __, __, __, s(277), __, __],
+    /*  115 */ [__, r(6), r(6), __, __, __, __, r(6), r(6), r(6), r(6), __, r(6), __, __, __, r(6), r(6), __, __, r(6), r(6), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(6), __, __, __, __, __, r(6), __, r(6), __, __, __, __, __, __, __, r(6), __],
+    /*  116 */ [__, r(9), r(9), __, __, __, __, r(9), r(9), r(9), r(9), __, r(9), __, __, __, r(9), r(9), __, __, r(9), r(9), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(9), __, __, __, __, __, r(9), __, r(9), __, __, __, __, __, __, __, r(9), __],
+    /*  117 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(253), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  118 */ [__, r(8), r(8), __, __, __, __, r(8), r(8), r(8), r(8), __, r(8), __, __, __, r(8), r(8), __, __, r(8), r(8), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, r(69), __, r(69), __, r(8), r(69), r(8), __, r(69), __, __, __, __, r(69), r(8), __],
+    /*  119 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(251), __],
+    /*  120 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(270), __, s(326), __, s(47), __, __, s(267), __, __, s(72), __, __, __, __, s(269), __, __],
+    /*  121 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  122 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  123 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  124 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), __, r(68), __, __, r(68), __, __, r(68), r(68), __, __, __, r(68), __, __],
+    /*  125 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), __, r(69), __, __, r(69), __, __, r(69), r(69), __, __, __, r(69), __, __],
+    /*  126 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, __, r(68), __, __, r(68), r(68), __, __, __, r(68), __, __],
+    /*  127 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, __, r(69), __, __, r(69), r(69), __, __, __, r(69), __, __],
+    /*  128 */ [r(68), __, r(68), __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, __, r(68), __, __, r(68), r(68), __, __, __, r(68), __, __],
+    /*  129 */ [r(69), __, r(69), __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, __, r(69), __, __, r(69), r(69), __, __, __, r(69), __, __],
+    /*  130 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(238), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  131 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  132 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(239), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  133 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(263), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  134 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  135 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  136 */ [__, __, __, __, __, __, r(120), __, r(120), __, __, __, __, r(120), r(120), __, __, __, __, r(120), __, __, __, r(120), __, __, __, __, r(120), __, __, r(120), __, __, __, __, __, __, __, __, __, __, r(120), __, r(120), __, __, __, __, __, __, __, __, __, r(120), r(120), r(120), r(120), r(120), __],
+    /*  137 */ [__, __, __, __, __, __, r(122), __, r(122), __, __, __, __, r(122), r(122), __, __, __, __, r(122), __, __, __, r(122), __, __, __, __, r(122), __, __, r(122), __, __, __, __, __, __, __, __, __, __, r(122), __, r(122), __, __, __, __, __, __, __, __, __, r(122), r(122), r(122), r(122), r(122), __],
+    /*  138 */ [__, __, __, __, __, __, r(121), __, r(121), __, __, __, __, r(121), r(121), __, __, __, __, r(121), __, __, __, r(121), __, __, __, __, r(121), __, __, r(121), __, __, __, __, __, __, __, __, __, __, r(121), __, r(121), __, __, __, __, __, __, __, __, __, r(121), r(121), r(121), r(121), r(121), __],
+    /*  139 */ [__, __, __, __, __, __, r(123), __, r(123), __, __, __, __, r(123), r(123), __, __, __, __, r(123), __, __, __, r(123), __, __, __, __, r(123), __, __, r(123), __, __, __, __, __, __, __, __, __, __, r(123), __, r(123), __, __, __, __, __, __, __, __, __, r(123), r(123), r(123), r(123), r(123), __],
+    /*  140 */ [__, r(38), r(38), __, __, __, __, r(38), r(38), r(38), r(38), __, r(38), __, __, __, r(38), r(38), __, __, __, r(38), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(38), __, __, __, __, __, r(38), __, r(38), __, __, __, __, __, __, __, r(38), r(38)],
+    /*  141 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(42), __, __, __, __, __, __, s(373), __, __, __, __, __, __, __, __, __, __],
+    /*  142 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(374), __],
+    /*  143 */ [__, r(25), r(25), __, __, __, __, r(25), r(25), r(25), r(25), __, r(25), __, __, __, r(25), r(25), __, __, __, r(25), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(25), __, __, __, __, __, r(25), __, r(25), __, __, __, __, __, __, __, r(25), r(25)],
+    /*  144 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(148), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  145 */ [__, r(23), r(23), __, __, __, __, r(23), r(23), r(23), r(23), __, r(23), __, __, __, r(23), r(23), __, __, __, r(23), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(23), __, __, __, __, __, r(23), __, r(23), __, __, __, __, __, __, __, r(23), r(23)],
+    /*  146 */ [__, __, __, __, __, r(1), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  147 */ [__, __, __, __, __, r(5), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  148 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  149 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(1), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  150 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(5), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  151 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  152 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(45), r(68), __, r(68), __, r(68), __, __, r(68), __, s(35), r(68), __, __, __, __, r(68), __, __],
+    /*  153 */ [__, r(29), r(29), __, __, __, __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, __, r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(380), __, __, __, __, __, __, r(29), r(29)],
+    /*  154 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(497), __, s(526), __, s(47), __, __, s(489), __, __, s(72), __, __, __, __, s(493), __, __],
+    /*  155 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), r(47), __, r(47), __, __, r(47), r(47), __, __, __, r(47), __, __],
+    /*  156 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(386), __, __, __, __, __, __, __, __, __, __, r(36)],
+    /*  157 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(35), __, __, __, __, __, __, __, __, __, __, r(35)],
+    /*  158 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, r(47)],
+    /*  159 */ [__, r(7), r(7), __, __, __, __, r(7), r(7), r(7), r(7), __, r(7), __, __, __, r(7), r(7), __, __, __, r(7), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(7), __, __, __, __, __, r(7), __, r(7), __, __, __, __, __, __, __, r(7), r(7)],
+    /*  160 */ [r(47), r(47), r(47), __, __, __, __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), __, __, __, r(47), r(47), r(47)],
+    /*  161 */ [__, r(12), r(12), __, __, __, __, r(12), r(12), r(12), r(12), __, r(12), __, __, __, r(12), r(12), __, __, __, r(12), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(12), __, __, __, __, __, r(12), __, r(12), __, __, __, __, __, __, __, r(12), r(12)],
+    /*  162 */ [__, __, __, __, __, __, __, __, __, __, __, r(51), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(417), __, __, __, __, __, __, __, __, __, s(250), __, __, __, __, __, __, __, __],
+    /*  163 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(387), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  164 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(565), __, s(629), __, s(419), r(64), __, s(561), __, __, s(553), r(64), __, __, __, s(563), __, __],
+    /*  165 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(384), __],
+    /*  166 */ [__, __, __, __, __, __, __, __, __, __, __, r(51), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(418), __, __, __, __, __, __, __, __, __, s(250), __, __, __, __, __, __, __, __],
+    /*  167 */ [__, r(11), r(11), __, __, r(11), __, r(11), r(11), r(11), r(11), __, r(11), __, __, __, r(11), r(11), __, __, __, r(11), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(11), __, __, __, __, __, r(11), __, r(11), __, __, __, __, __, __, __, r(11), __],
+    /*  168 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(389), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  169 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(564), __, s(620), __, s(416), __, r(64), s(551), __, r(64), s(548), r(64), __, __, __, s(554), __, r(64)],
+    /*  170 */ [__, r(11), r(11), __, __, __, __, r(11), r(11), r(11), r(11), __, r(11), __, __, __, r(11), r(11), __, __, r(11), r(11), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(11), __, __, __, __, __, r(11), __, r(11), __, __, __, __, __, __, __, r(11), __],
+    /*  171 */ [__, __, __, __, __, __, __, __, __, __, __, s(421), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  172 */ [__, __, __, __, __, __, __, __, __, __, __, s(420), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  173 */ [__, s(433), s(436), r(2), r(2), r(2), __, s(485), s(487), s(488), s(502), __, s(550), __, __, __, s(555), r(2), __, __, __, s(603), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(634), __, s(638), __, __, __, __, __, __, __, s(19), __],
+    /*  174 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), r(49), __, r(49), __, r(49), __, __, r(49), __, r(49), r(49), __, __, __, __, r(49), __, __],
+    /*  175 */ [r(64), r(64), r(64), __, __, __, __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(615), __, s(658), __, s(423), __, r(64), s(610), r(64), r(64), s(608), r(64), __, __, __, s(614), r(64), r(64)],
+    /*  176 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(87), __, s(108), __, __, __, __, __, __, __, __, __, __, __, __, s(75), __, __],
+    /*  177 */ [__, __, __, __, __, __, s(473), __, s(630), __, __, __, __, s(474), s(136), __, __, __, __, s(475), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(577), __, s(631), __, __, __, __, __, __, __, __, __, s(476), s(471), s(472), s(469), s(453), __],
+    /*  178 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, s(413), __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  179 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  180 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  181 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(3), __, __, r(3), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  182 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, s(415), __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  183 */ [__, __, __, __, __, r(3), __, __, __, __, __, __, __, __, __, __, __, r(3), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  184 */ [__, __, __, __, __, __, s(291), __, s(370), __, __, __, __, s(292), s(136), __, __, __, __, s(293), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(364), __, s(371), __, __, __, __, __, __, __, __, __, s(296), s(280), s(281), s(279), s(160), __],
+    /*  185 */ [__, __, __, __, __, r(34), s(461), __, s(659), __, __, __, __, s(462), s(136), __, __, __, __, s(463), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(613), __, s(660), __, __, __, r(34), __, __, __, __, __, s(465), s(459), s(460), s(458), s(397), __],
+    /*  186 */ [__, __, __, __, __, __, s(484), __, s(669), __, __, __, __, s(495), s(136), __, __, __, __, s(496), r(34), __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(646), __, s(670), __, __, __, r(34), __, __, __, __, __, s(498), s(481), s(483), s(480), s(401), __],
+    /*  187 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), __, r(47), __, __, r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  188 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  189 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(587), r(64), s(645), __, s(422), __, __, s(583), __, r(64), s(582), r(64), __, __, __, s(585), __, __],
+    /*  190 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  191 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  192 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(400), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  193 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(402), __],
+    /*  194 */ [__, r(26), r(26), __, __, __, __, r(26), r(26), r(26), r(26), __, r(26), __, __, __, r(26), r(26), __, __, r(26), r(26), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(439), r(26), __, __, __, __, __, r(26), __, r(26), __, __, __, __, __, __, __, r(26), __],
+    /*  195 */ [__, r(31), r(31), __, __, __, __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, r(31), r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(409), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), __],
+    /*  196 */ [s(283), __, s(412), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  197 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(426), __, s(311), __, __, __, __, __, r(54)],
+    /*  198 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, r(59), __, __, r(59), __, r(59), __, __, __, __, __, r(59)],
+    /*  199 */ [__, __, __, __, __, __, s(591), __, s(688), __, __, __, __, s(592), s(136), __, __, __, __, s(593), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(676), __, s(689), __, __, __, __, __, __, __, __, __, s(594), s(589), s(590), s(588), s(505), __],
+    /*  200 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, r(60), __, __, r(60), __, r(60), __, __, __, __, __, r(60)],
+    /*  201 */ [s(283), __, s(494), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  202 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, r(61), __, __, r(61), __, r(61), __, __, __, __, __, r(61)],
+    /*  203 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, r(57), __, __, r(57), __, r(57), __, __, __, __, __, r(57)],
+    /*  204 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, r(56), __, __, r(56), __, r(56), __, __, __, __, __, r(56)],
+    /*  205 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, r(58), __, __, r(58), __, r(58), __, __, __, __, __, r(58)],
+    /*  206 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, r(62), __, __, r(62), __, r(62), __, __, __, __, __, r(62)],
+    /*  207 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, r(54), __, __, __, __, __, __, __, s(435), __, s(311), __, __, __, __, __, __],
+    /*  208 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, r(59), __, __, __, __, __, __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /*  209 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, r(60), __, __, __, __, __, __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /*  210 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, r(61), __, __, __, __, __, __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /*  211 */ [__, __, __, __, __, s(429), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  212 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, r(57), __, __, __, __, __, __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /*  213 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, r(56), __, __, __, __, __, __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /*  214 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, r(58), __, __, __, __, __, __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /*  215 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, r(62), __, __, __, __, __, __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /*  216 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), s(441), r(47), __, r(47), r(47), r(47), __, r(47), r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  217 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __],
+    /*  218 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, r(67), __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __],
+    /*  219 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(572), __, s(605), r(64), s(438), __, r(64), s(568), __, r(64), s(567), r(64), __, __, __, s(570), __, __],
+    /*  220 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /*  221 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, r(69), __, r(69), __, __, r(69), __, __, r(69), __, __, __, __, r(69), __, __],
+    /*  222 */ [__, __, __, __, __, s(428), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  223 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, r(59), __, __, __, __, __, r(59), __, __, __, __, __, __],
+    /*  224 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, r(60), __, __, __, __, __, r(60), __, __, __, __, __, __],
+    /*  225 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, r(61), __, __, __, __, __, r(61), __, __, __, __, __, __],
+    /*  226 */ [r(67), __, r(67), __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __],
+    /*  227 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, r(57), __, __, __, __, __, r(57), __, __, __, __, __, __],
+    /*  228 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, r(56), __, __, __, __, __, r(56), __, __, __, __, __, __],
+    /*  229 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, r(58), __, __, __, __, __, r(58), __, __, __, __, __, __],
+    /*  230 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, r(62), __, __, __, __, __, r(62), __, __, __, __, __, __],
+    /*  231 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*  232 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(414), __],
+    /*  233 */ [__, r(26), r(26), __, __, r(26), __, r(26), r(26), r(26), r(26), __, r(26), __, __, __, r(26), r(26), __, __, __, r(26), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(504), r(26), __, __, __, __, __, r(26), __, r(26), __, __, __, __, __, __, __, r(26), __],
+    /*  234 */ [__, r(31), r(31), __, __, r(31), __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, __, r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(432), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), __],
+    /*  235 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(430), __, __, __, __, __, __, __, __, __],
+    /*  236 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, r(78), __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __],
+    /*  237 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __],
+    /*  238 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  239 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  240 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, r(59), __, __, r(59), __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /*  241 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, r(60), __, __, r(60), __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /*  242 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, r(61), __, __, r(61), __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /*  243 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, r(57), __, __, r(57), __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /*  244 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, r(56), __, __, r(56), __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /*  245 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, r(58), __, __, r(58), __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /*  246 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, r(62), __, __, r(62), __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /*  247 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, r(96), __, __, r(96), __, __, r(96), __, s(311), __, __, __, __, __, __],
+    /*  248 */ [s(283), r(54), r(54), __, __, __, __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, __, r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(538), __, s(311), __, __, __, __, r(54), r(54)],
+    /*  249 */ [__, __, __, __, __, __, __, __, __, __, __, r(50), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  250 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(442), __],
+    /*  251 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(470), __, __, __, __, __, __, __, __, __],
+    /*  252 */ [__, __, __, __, __, __, s(625), __, s(692), __, __, __, __, s(626), s(136), __, __, __, __, s(627), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(684), __, s(693), __, __, __, __, __, __, __, __, __, s(628), s(623), s(624), s(622), s(519), __],
+    /*  253 */ [__, __, __, __, __, __, s(599), __, s(680), __, __, __, __, s(600), s(136), __, __, __, __, s(601), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(667), __, s(681), __, __, __, __, __, __, __, __, __, s(602), s(597), s(598), s(595), s(479), __],
+    /*  254 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(578), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  255 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(606), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  256 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, r(63), __, __, r(63), __, r(63), __, __, __, __, __, r(63)],
+    /*  257 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, r(65), __, __, r(65), __, r(65), __, __, __, __, __, r(65)],
+    /*  258 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, r(63), __, __, __, __, __, __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /*  259 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, r(65), __, __, __, __, __, __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /*  260 */ [__, __, __, __, __, __, s(203), __, s(347), __, __, __, __, s(204), s(136), __, __, __, __, s(205), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(329), __, s(351), __, __, __, __, __, __, __, __, __, s(206), s(200), s(202), s(198), s(158), __],
+    /*  261 */ [r(78), __, r(78), __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __],
+    /*  262 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, r(63), __, __, __, __, __, r(63), __, __, __, __, __, __],
+    /*  263 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  264 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, r(65), __, __, __, __, __, r(65), __, __, __, __, __, __],
+    /*  265 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), __, s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  266 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  267 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(514), __],
+    /*  268 */ [__, r(71), r(71), __, __, __, __, r(71), r(71), r(71), r(71), __, r(71), __, __, __, r(71), r(71), __, __, r(71), r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), __, __, __, __, r(71), r(71), __],
+    /*  269 */ [__, r(77), r(77), __, __, __, __, r(77), r(77), r(77), r(77), __, r(77), __, __, __, r(77), r(77), __, __, r(77), r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), __, __, __, __, r(77), r(77), __],
+    /*  270 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  271 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, r(63), __, __, r(63), __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /*  272 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, r(65), __, __, r(65), __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /*  273 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(573), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  274 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), __, __, __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(467), __],
+    /*  275 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(468), __],
+    /*  276 */ [__, r(71), r(71), __, __, r(71), __, r(71), r(71), r(71), r(71), __, r(71), __, __, __, r(71), r(71), __, __, __, r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), __, __, __, __, r(71), r(71), __],
+    /*  277 */ [__, r(77), r(77), __, __, r(77), __, r(77), r(77), r(77), r(77), __, r(77), __, __, __, r(77), r(77), __, __, __, r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), __, __, __, __, r(77), r(77), __],
+    /*  278 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  279 */ [r(59), r(59), r(59), __, __, __, __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), r(59), __, r(59), __, __, __, __, r(59), r(59)],
+    /*  280 */ [r(60), r(60), r(60), __, __, __, __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), r(60), __, r(60), __, __, __, __, r(60), r(60)],
+    /*  281 */ [r(61), r(61), r(61), __, __, __, __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), r(61), __, r(61), __, __, __, __, r(61), r(61)],
+    /*  282 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, r(70), __, r(70), __, __, r(70), __, __, r(70), __, __, __, __, r(70), __, __],
+    /*  283 */ [__, __, __, __, __, __, r(100), __, r(100), __, __, __, __, r(100), r(100), __, __, __, __, r(100), __, __, __, r(100), __, __, __, __, r(100), __, __, r(100), __, __, __, __, __, __, __, __, __, __, r(100), __, r(100), __, __, __, __, __, __, __, __, __, r(100), r(100), r(100), r(100), r(100), __],
+    /*  284 */ [__, __, __, __, __, __, r(99), __, r(99), __, __, __, __, r(99), r(99), __, __, __, __, r(99), __, __, __, r(99), __, __, __, __, r(99), __, __, r(99), __, __, __, __, __, __, __, __, __, __, r(99), __, r(99), __, __, __, __, __, __, __, __, __, r(99), r(99), r(99), r(99), r(99), __],
+    /*  285 */ [__, __, __, __, __, __, r(113), __, r(113), __, __, __, __, r(113), r(113), __, __, __, __, r(113), __, __, __, r(113), __, __, __, __, r(113), __, __, r(113), __, __, __, __, __, __, __, __, __, __, r(113), __, r(113), __, __, __, __, __, __, __, __, __, r(113), r(113), r(113), r(113), r(113), __],
+    /*  286 */ [__, __, __, __, __, __, r(114), __, r(114), __, __, __, __, r(114), r(114), __, __, __, __, r(114), __, __, __, r(114), __, __, __, __, r(114), __, __, r(114), __, __, __, __, __, __, __, __, __, __, r(114), __, r(114), __, __, __, __, __, __, __, __, __, r(114), r(114), r(114), r(114), r(114), __],
+    /*  287 */ [__, __, __, __, __, __, r(115), __, r(115), __, __, __, __, r(115), r(115), __, __, __, __, r(115), __, __, __, r(115), __, __, __, __, r(115), __, __, r(115), __, __, __, __, __, __, __, __, __, __, r(115), __, r(115), __, __, __, __, __, __, __, __, __, r(115), r(115), r(115), r(115), r(115), __],
+    /*  288 */ [__, __, __, __, __, __, r(116), __, r(116), __, __, __, __, r(116), r(116), __, __, __, __, r(116), __, __, __, r(116), __, __, __, __, r(116), __, __, r(116), __, __, __, __, __, __, __, __, __, __, r(116), __, r(116), __, __, __, __, __, __, __, __, __, r(116), r(116), r(116), r(116), r(116), __],
+    /*  289 */ [__, __, __, __, __, __, r(117), __, r(117), __, __, __, __, r(117), r(117), __, __, __, __, r(117), __, __, __, r(117), __, __, __, __, r(117), __, __, r(117), __, __, __, __, __, __, __, __, __, __, r(117), __, r(117), __, __, __, __, __, __, __, __, __, r(117), r(117), r(117), r(117), r(117), __],
+    /*  290 */ [__, __, __, __, __, __, r(118), __, r(118), __, __, __, __, r(118), r(118), __, __, __, __, r(118), __, __, __, r(118), __, __, __, __, r(118), __, __, r(118), __, __, __, __, __, __, __, __, __, __, r(118), __, r(118), __, __, __, __, __, __, __, __, __, r(118), r(118), r(118), r(118), r(118), __],
+    /*  291 */ [r(57), r(57), r(57), __, __, __, __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), r(57), __, r(57), __, __, __, __, r(57), r(57)],
+    /*  292 */ [r(56), r(56), r(56), __, __, __, __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), r(56), __, r(56), __, __, __, __, r(56), r(56)],
+    /*  293 */ [r(58), r(58), r(58), __, __, __, __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), r(58), __, r(58), __, __, __, __, r(58), r(58)],
+    /*  294 */ [__, __, __, __, __, __, r(119), __, r(119), __, __, __, __, r(119), r(119), __, __, __, __, r(119), __, __, __, r(119), __, __, __, __, r(119), __, __, r(119), __, __, __, __, __, __, __, __, __, __, r(119), __, r(119), __, __, __, __, __, __, __, __, __, r(119), r(119), r(119), r(119), r(119), __],
+    /*  295 */ [__, __, __, __, __, __, r(109), __, r(109), __, __, __, __, r(109), r(109), __, __, __, __, r(109), __, __, __, r(109), __, __, __, __, r(109), __, __, r(109), __, __, __, __, __, __, __, __, __, __, r(109), __, r(109), __, __, __, __, __, __, __, __, __, r(109), r(109), r(109), r(109), r(109), __],
+    /*  296 */ [r(62), r(62), r(62), __, __, __, __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), r(62), __, r(62), __, __, __, __, r(62), r(62)],
+    /*  297 */ [__, __, __, __, __, __, r(108), __, r(108), __, __, __, __, r(108), r(108), __, __, __, __, r(108), __, __, __, r(108), __, __, __, __, r(108), __, __, r(108), __, __, __, __, __, __, __, __, __, __, r(108), __, r(108), __, __, __, __, __, __, __, __, __, r(108), r(108), r(108), r(108), r(108), __],
+    /*  298 */ [__, __, __, __, __, __, r(107), __, r(107), __, __, __, __, r(107), r(107), __, __, __, __, r(107), __, __, __, r(107), __, __, __, __, r(107), __, __, r(107), __, __, __, __, __, __, __, __, __, __, r(107), __, r(107), __, __, __, __, __, __, __, __, __, r(107), r(107), r(107), r(107), r(107), __],
+    /*  299 */ [__, __, __, __, __, __, r(110), __, r(110), __, __, __, __, r(110), r(110), __, __, __, __, r(110), __, __, __, r(110), __, __, __, __, r(110), __, __, r(110), __, __, __, __, __, __, __, __, __, __, r(110), __, r(110), __, __, __, __, __, __, __, __, __, r(110), r(110), r(110), r(110), r(110), __],
+    /*  300 */ [__, __, __, __, __, __, r(111), __, r(111), __, __, __, __, r(111), r(111), __, __, __, __, r(111), __, __, __, r(111), __, __, __, __, r(111), __, __, r(111), __, __, __, __, __, __, __, __, __, __, r(111), __, r(111), __, __, __, __, __, __, __, __, __, r(111), r(111), r(111), r(111), r(111), __],
+    /*  301 */ [__, __, __, __, __, __, r(105), __, r(105), __, __, __, __, r(105), r(105), __, __, __, __, r(105), __, __, __, r(105), __, __, __, __, r(105), __, __, r(105), __, __, __, __, __, __, __, __, __, __, r(105), __, r(105), __, __, __, __, __, __, __, __, __, r(105), r(105), r(105), r(105), r(105), __],
+    /*  302 */ [__, __, __, __, __, __, r(106), __, r(106), __, __, __, __, r(106), r(106), __, __, __, __, r(106), __, __, __, r(106), __, __, __, __, r(106), __, __, r(106), __, __, __, __, __, __, __, __, __, __, r(106), __, r(106), __, __, __, __, __, __, __, __, __, r(106), r(106), r(106), r(106), r(106), __],
+    /*  303 */ [__, __, __, __, __, __, r(103), __, r(103), __, __, __, __, r(103), r(103), __, __, __, __, r(103), __, __, __, r(103), __, __, __, __, r(103), __, __, r(103), __, __, __, __, __, __, __, __, __, __, r(103), __, r(103), __, __, __, __, __, __, __, __, __, r(103), r(103), r(103), r(103), r(103), __],
+    /*  304 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(499), __],
+    /*  305 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(443), __],
+    /*  306 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, __, r(71), __, __, r(71), r(71), __, __, __, r(71), __, __],
+    /*  307 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, __, r(77), __, __, r(77), r(77), __, __, __, r(77), __, __],
+    /*  308 */ [__, __, __, __, __, __, r(104), __, r(104), __, __, __, __, r(104), r(104), __, __, __, __, r(104), __, __, __, r(104), __, __, __, __, r(104), __, __, r(104), __, __, __, __, __, __, __, __, __, __, r(104), __, r(104), __, __, __, __, __, __, __, __, __, r(104), r(104), r(104), r(104), r(104), __],
+    /*  309 */ [__, __, __, __, __, __, r(101), __, r(101), __, __, __, __, r(101), r(101), __, __, __, __, r(101), __, __, __, r(101), __, __, __, __, r(101), __, __, r(101), __, __, __, __, __, __, __, __, __, __, r(101), __, r(101), __, __, __, __, __, __, __, __, __, r(101), r(101), r(101), r(101), r(101), __],
+    /*  310 */ [__, __, __, __, __, __, r(102), __, r(102), __, __, __, __, r(102), r(102), __, __, __, __, r(102), __, __, __, r(102), __, __, __, __, r(102), __, __, r(102), __, __, __, __, __, __, __, __, __, __, r(102), __, r(102), __, __, __, __, __, __, __, __, __, r(102), r(102), r(102), r(102), r(102), __],
+    /*  311 */ [__, __, __, __, __, __, r(112), __, r(112), __, __, __, __, r(112), r(112), __, __, __, __, r(112), __, __, __, r(112), __, __, __, __, r(112), __, __, r(112), __, __, __, __, __, __, __, __, __, __, r(112), __, r(112), __, __, __, __, __, __, __, __, __, r(112), r(112), r(112), r(112), r(112), __],
+    /*  312 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  313 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(544), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  314 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(528), __],
+    /*  315 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(531), __],
+    /*  316 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), __, r(71), __, __, r(71), __, __, r(71), r(71), __, __, __, r(71), __, __],
+    /*  317 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), __, r(77), __, __, r(77), __, __, r(77), r(77), __, __, __, r(77), __, __],
+    /*  318 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(535), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  319 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  320 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(522), __],
+    /*  321 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(523), __],
+    /*  322 */ [r(71), __, r(71), __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, __, r(71), __, __, r(71), r(71), __, __, __, r(71), __, __],
+    /*  323 */ [r(77), __, r(77), __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, __, r(77), __, __, r(77), r(77), __, __, __, r(77), __, __],
+    /*  324 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  325 */ [__, r(76), r(76), __, __, __, __, r(76), r(76), r(76), r(76), __, r(76), __, __, __, r(76), r(76), __, __, r(76), r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), __, __, __, __, r(76), r(76), __],
+    /*  326 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  327 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  328 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  329 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  330 */ [r(63), r(63), r(63), __, __, __, __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), r(63), __, r(63), __, __, __, __, r(63), r(63)],
+    /*  331 */ [r(65), r(65), r(65), __, __, __, __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), r(65), __, r(65), __, __, __, __, r(65), r(65)],
+    /*  332 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  333 */ [__, __, __, __, __, __, s(291), __, s(370), __, __, __, __, s(292), s(136), __, __, __, __, s(293), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(364), __, s(371), __, __, __, __, __, __, __, __, __, s(296), s(280), s(281), s(279), s(160), __],
+    /*  334 */ [__, r(76), r(76), __, __, r(76), __, r(76), r(76), r(76), r(76), __, r(76), __, __, __, r(76), r(76), __, __, __, r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), __, __, __, __, r(76), r(76), __],
+    /*  335 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  336 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, __, r(76), __, __, r(76), r(76), __, __, __, r(76), __, __],
+    /*  337 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  338 */ [r(76), __, r(76), __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, __, r(76), __, __, r(76), r(76), __, __, __, r(76), __, __],
+    /*  339 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, r(68)],
+    /*  340 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), __, r(68), __, __, r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /*  341 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, r(69)],
+    /*  342 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), __, r(69), __, __, r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /*  343 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  344 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(540), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  345 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(75), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  346 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(558), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  347 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(560), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  348 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), r(68), __, r(68), __, __, r(68), r(68), __, __, __, r(68), __, __],
+    /*  349 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), r(69), __, r(69), __, __, r(69), r(69), __, __, __, r(69), __, __],
+    /*  350 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  351 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  352 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), __, r(76), __, __, r(76), __, __, r(76), r(76), __, __, __, r(76), __, __],
+    /*  353 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  354 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(559), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  355 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), r(68), r(68), __, r(68), r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /*  356 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), r(69), r(69), __, r(69), r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /*  357 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  358 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(557), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  359 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  360 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(536), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  361 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(89), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  362 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(91), __, __, s(542), __, __, s(543), __, __, __, __, __, __, __, __],
+    /*  363 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  364 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  365 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(530), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  366 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(546), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  367 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(534), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  368 */ [r(68), r(68), r(68), __, __, __, __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), __, __, __, r(68), r(68), r(68)],
+    /*  369 */ [r(69), r(69), r(69), __, __, __, __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), __, __, __, r(69), r(69), r(69)],
+    /*  370 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(525), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  371 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  372 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(39), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  373 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(694), __],
+    /*  374 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(40), __, __, __, __, __, __, r(40), __, __, s(142), __, __, __, __, __, __, __],
+    /*  375 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(715), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  376 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(81), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  377 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(83), __, __, __, __, __, __, __, s(697), __, __, __, __, __, __, __, __],
+    /*  378 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(86), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  379 */ [__, r(28), r(28), __, __, __, __, r(28), r(28), r(28), r(28), __, r(28), __, __, __, r(28), r(28), __, __, __, r(28), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(28), r(28), __, __, __, __, __, r(28), __, r(28), __, __, __, __, __, __, __, r(28), r(28)],
+    /*  380 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(698), __],
+    /*  381 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(46), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  382 */ [__, r(24), r(24), __, __, __, __, r(24), r(24), r(24), r(24), __, r(24), __, __, __, r(24), r(24), __, __, __, r(24), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(24), __, __, __, __, __, r(24), __, r(24), __, __, __, __, __, __, __, r(24), r(24)],
+    /*  383 */ [__, r(23), r(23), __, __, r(23), __, r(23), r(23), r(23), r(23), __, r(23), __, __, __, r(23), r(23), __, __, __, r(23), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(23), __, __, __, __, __, r(23), __, r(23), __, __, __, __, __, __, __, r(23), __],
+    /*  384 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(701), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  385 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(33)],
+    /*  386 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(37)],
+    /*  387 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  388 */ [__, r(23), r(23), __, __, __, __, r(23), r(23), r(23), r(23), __, r(23), __, __, __, r(23), r(23), __, __, r(23), r(23), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(23), __, __, __, __, __, r(23), __, r(23), __, __, __, __, __, __, __, r(23), __],
+    /*  389 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  390 */ [__, __, __, r(4), r(4), r(4), __, __, __, __, __, __, __, __, __, __, __, s(726), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  391 */ [__, r(72), r(72), __, __, __, __, r(72), r(72), r(72), r(72), __, r(72), __, __, __, r(72), r(72), __, __, __, r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), __, __, __, __, r(72), r(72), r(72)],
+    /*  392 */ [__, __, s(716), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  393 */ [__, r(27), r(27), __, __, __, __, r(27), r(27), r(27), r(27), __, r(27), __, __, __, r(27), r(27), __, __, __, r(27), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(27), __, __, __, __, __, r(27), __, r(27), __, __, __, __, __, __, __, r(27), r(27)],
+    /*  394 */ [__, __, __, __, __, r(36), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(702), __, __, __, __, __, __, __, __, __, __, __],
+    /*  395 */ [__, __, __, __, __, r(35), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(35), __, __, __, __, __, __, __, __, __, __, __],
+    /*  396 */ [r(47), __, r(47), __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, __, r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  397 */ [r(47), __, __, __, __, r(47), __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  398 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(36), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(707), __, __, __, __, __, __, __, __, __, __, __],
+    /*  399 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(35), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(35), __, __, __, __, __, __, __, __, __, __, __],
+    /*  400 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), r(48), __, r(48), __, r(48), __, __, r(48), __, r(48), r(48), __, __, __, __, r(48), __, __],
+    /*  401 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  402 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(389), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  403 */ [__, r(25), r(25), __, __, __, __, r(25), r(25), r(25), r(25), __, r(25), __, __, __, r(25), r(25), __, __, r(25), r(25), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(25), __, __, __, __, __, r(25), __, r(25), __, __, __, __, __, __, __, r(25), __],
+    /*  404 */ [__, r(29), r(29), __, __, __, __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, r(29), r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(711), __, __, __, __, __, __, r(29), __],
+    /*  405 */ [r(64), __, r(64), __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(903), __, s(933), __, s(729), __, __, s(897), __, r(64), s(881), r(64), __, __, __, s(899), __, __],
+    /*  406 */ [r(64), __, __, __, __, r(64), __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(915), __, s(939), __, s(728), __, r(64), s(905), __, r(64), s(904), r(64), __, __, __, s(907), __, __],
+    /*  407 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, r(53)],
+    /*  408 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(895), __, s(930), __, s(725), __, r(64), s(891), __, r(64), s(882), r(64), __, __, __, s(893), __, __],
+    /*  409 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(709), __],
+    /*  410 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(727), __, s(311), __, __, __, __, __, __],
+    /*  411 */ [__, s(433), s(436), r(2), r(2), r(2), __, s(485), s(487), s(488), s(502), __, s(550), __, __, __, s(555), r(2), __, __, __, s(603), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(634), __, s(638), __, __, __, __, __, __, __, s(19), __],
+    /*  412 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  413 */ [__, s(433), s(436), r(2), r(2), r(2), __, s(485), s(487), s(488), s(502), __, s(550), __, __, __, s(555), r(2), __, __, __, s(603), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(634), __, s(638), __, __, __, __, __, __, __, s(19), __],
+    /*  414 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(387), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  415 */ [__, s(433), s(436), r(2), r(2), r(2), __, s(485), s(487), s(488), s(502), __, s(550), __, __, __, s(555), r(2), __, __, __, s(603), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(634), __, s(638), __, __, __, __, __, __, __, s(19), __],
+    /*  416 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  417 */ [__, __, __, __, __, __, s(473), __, s(630), __, __, __, __, s(474), s(136), __, __, __, __, s(475), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(577), __, s(631), __, __, __, __, __, __, __, __, __, s(476), s(471), s(472), s(469), s(453), __],
+    /*  418 */ [__, __, __, __, __, __, s(473), __, s(630), __, __, __, __, s(474), s(136), __, __, __, __, s(475), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(577), __, s(631), __, __, __, __, __, __, __, __, __, s(476), s(471), s(472), s(469), s(453), __],
+    /*  419 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  420 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  421 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  422 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  423 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  424 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(723), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  425 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  426 */ [__, __, __, __, __, __, s(203), __, s(347), __, __, __, __, s(204), s(136), __, __, __, __, s(205), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(329), __, s(351), __, __, __, __, __, __, __, __, __, s(206), s(200), s(202), s(198), s(158), __],
+    /*  427 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  428 */ [__, r(12), r(12), __, __, r(12), __, r(12), r(12), r(12), r(12), __, r(12), __, __, __, r(12), r(12), __, __, __, r(12), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(12), __, __, __, __, __, r(12), __, r(12), __, __, __, __, __, __, __, r(12), __],
+    /*  429 */ [__, r(12), r(12), __, __, __, __, r(12), r(12), r(12), r(12), __, r(12), __, __, __, r(12), r(12), __, __, r(12), r(12), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(12), __, __, __, __, __, r(12), __, r(12), __, __, __, __, __, __, __, r(12), __],
+    /*  430 */ [__, r(38), r(38), __, __, r(38), __, r(38), r(38), r(38), r(38), __, r(38), __, __, __, r(38), r(38), __, __, __, r(38), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(38), __, __, __, __, __, r(38), __, r(38), __, __, __, __, __, __, __, r(38), __],
+    /*  431 */ [__, r(29), r(29), __, __, r(29), __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, __, r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(719), __, __, __, __, __, __, r(29), __],
+    /*  432 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(718), __],
+    /*  433 */ [__, r(10), r(10), r(10), r(10), r(10), __, r(10), r(10), r(10), r(10), __, r(10), __, __, __, r(10), r(10), __, __, __, r(10), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(10), __, __, __, __, __, r(10), __, r(10), __, __, __, __, __, __, __, r(10), __],
+    /*  434 */ [s(283), r(14), r(14), __, __, __, __, r(14), r(14), r(14), r(14), __, r(14), __, __, s(284), r(14), r(14), __, __, __, r(14), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(14), __, __, __, __, __, r(14), __, r(14), __, __, s(311), __, __, __, __, r(14), r(14)],
+    /*  435 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), __, s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  436 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  437 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(724), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  438 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  439 */ [__, __, __, __, __, __, s(599), __, s(680), __, __, __, __, s(600), s(136), __, __, __, __, s(601), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(667), __, s(681), __, __, __, __, __, __, __, __, __, s(602), s(597), s(598), s(595), s(479), __],
+    /*  440 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(749), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  441 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), __, __, __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(467), __],
+    /*  442 */ [__, __, __, __, __, __, __, __, __, __, __, r(51), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(250), __, __, __, __, __, __, __, __],
+    /*  443 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(312), __, s(337), __, __, __, __, __, __, __, __, __, __, __, __, s(307), __, __],
+    /*  444 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, r(66), __, __, __, __, __, __, __, __, __, p(311), __, __, __, __, __, __],
+    /*  445 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), __, s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  446 */ [s(283), __, r(54), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(730), __, s(311), __, __, __, __, __, __],
+    /*  447 */ [r(59), __, r(59), __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, __, __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /*  448 */ [r(60), __, r(60), __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, __, __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /*  449 */ [r(61), __, r(61), __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, __, __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /*  450 */ [r(57), __, r(57), __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, __, __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /*  451 */ [r(56), __, r(56), __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, __, __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /*  452 */ [r(58), __, r(58), __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, __, __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /*  453 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, __, r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  454 */ [s(283), __, __, __, __, r(54), __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(757), __, s(311), __, __, __, __, __, __],
+    /*  455 */ [r(62), __, r(62), __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, __, __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /*  456 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(753), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  457 */ [r(64), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(900), __, s(936), __, s(754), __, __, s(884), __, r(64), s(883), r(64), __, __, __, s(886), __, __],
+    /*  458 */ [r(59), __, __, __, __, r(59), __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, r(59), __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /*  459 */ [r(60), __, __, __, __, r(60), __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, r(60), __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /*  460 */ [r(61), __, __, __, __, r(61), __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, r(61), __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /*  461 */ [r(57), __, __, __, __, r(57), __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, r(57), __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /*  462 */ [r(56), __, __, __, __, r(56), __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, r(56), __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /*  463 */ [r(58), __, __, __, __, r(58), __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, r(58), __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /*  464 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, r(66), __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, __, __, __, __, __, p(311), __, __, __, __, __, __],
+    /*  465 */ [r(62), __, __, __, __, r(62), __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, r(62), __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /*  466 */ [__, __, __, __, __, __, s(203), __, s(347), __, __, __, __, s(204), s(136), __, __, __, __, s(205), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(329), __, s(351), __, __, __, __, __, __, __, __, __, s(206), s(200), s(202), s(198), s(158), __],
+    /*  467 */ [r(47), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), r(47), r(47), __, r(47), r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  468 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(278), __, s(335), __, __, __, __, __, __, __, __, __, __, __, __, s(277), __, __],
+    /*  469 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, __, __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /*  470 */ [__, r(38), r(38), __, __, __, __, r(38), r(38), r(38), r(38), __, r(38), __, __, __, r(38), r(38), __, __, r(38), r(38), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(38), __, __, __, __, __, r(38), __, r(38), __, __, __, __, __, __, __, r(38), __],
+    /*  471 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, __, __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /*  472 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, __, __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /*  473 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, __, __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /*  474 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, __, __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /*  475 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, __, __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /*  476 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, __, __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /*  477 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, r(54), __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(758), __, s(311), __, __, __, __, __, __],
+    /*  478 */ [__, r(7), r(7), __, __, __, __, r(7), r(7), r(7), r(7), __, r(7), __, __, __, r(7), r(7), __, __, r(7), r(7), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(7), __, __, __, __, __, r(7), __, r(7), __, __, __, __, __, __, __, r(7), __],
+    /*  479 */ [r(47), r(47), r(47), __, __, __, __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), __, __, __, r(47), r(47), __],
+    /*  480 */ [r(59), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, r(59), __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /*  481 */ [r(60), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, r(60), __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /*  482 */ [r(64), r(64), r(64), __, __, __, __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(922), __, s(941), __, s(770), __, r(64), s(916), r(64), r(64), s(914), r(64), __, __, __, s(920), r(64), __],
+    /*  483 */ [r(61), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, r(61), __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /*  484 */ [r(57), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, r(57), __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /*  485 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(751), __],
+    /*  486 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, r(67), __, __, __, __, __, r(67), __, __, __, __, __, __],
+    /*  487 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(36), __],
+    /*  488 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(756), __],
+    /*  489 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(759), __],
+    /*  490 */ [__, r(25), r(25), __, __, r(25), __, r(25), r(25), r(25), r(25), __, r(25), __, __, __, r(25), r(25), __, __, __, r(25), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(25), __, __, __, __, __, r(25), __, r(25), __, __, __, __, __, __, __, r(25), __],
+    /*  491 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(755), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  492 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, r(71), __, r(71), __, __, r(71), __, __, r(71), __, __, __, __, r(71), __, __],
+    /*  493 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, r(77), __, r(77), __, __, r(77), __, __, r(77), __, __, __, __, r(77), __, __],
+    /*  494 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  495 */ [r(56), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, r(56), __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /*  496 */ [r(58), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, r(58), __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /*  497 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  498 */ [r(62), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, r(62), __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /*  499 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, __, r(49), __, __, r(49), r(49), __, __, __, r(49), __, __],
+    /*  500 */ [r(63), __, r(63), __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, __, __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /*  501 */ [r(65), __, r(65), __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, __, __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /*  502 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*  503 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(766), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  504 */ [__, __, __, __, __, __, s(625), __, s(692), __, __, __, __, s(626), s(136), __, __, __, __, s(627), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(684), __, s(693), __, __, __, __, __, __, __, __, __, s(628), s(623), s(624), s(622), s(519), __],
+    /*  505 */ [r(47), r(47), r(47), __, __, __, __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), __, r(47), r(47), __, __, __, r(47), r(47), r(47)],
+    /*  506 */ [r(63), __, __, __, __, r(63), __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, r(63), __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /*  507 */ [r(65), __, __, __, __, r(65), __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, r(65), __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /*  508 */ [r(64), r(64), r(64), __, __, __, __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(894), __, s(945), __, s(773), __, r(64), s(888), r(64), __, s(887), r(64), __, __, __, s(890), r(64), r(64)],
+    /*  509 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  510 */ [__, __, __, __, __, __, s(461), __, s(659), __, __, __, __, s(462), s(136), __, __, __, __, s(463), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(613), __, s(660), __, __, __, __, __, __, __, __, __, s(465), s(459), s(460), s(458), s(397), __],
+    /*  511 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, __, __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /*  512 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, __, __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /*  513 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, r(67), __, __, r(67), __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /*  514 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(270), __, s(326), __, __, __, __, __, __, __, __, __, __, __, __, s(269), __, __],
+    /*  515 */ [__, __, __, __, __, __, s(473), __, s(630), __, __, __, __, s(474), s(136), __, __, __, __, s(475), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(577), __, s(631), __, __, __, __, __, __, __, __, __, s(476), s(471), s(472), s(469), s(453), __],
+    /*  516 */ [p(283), __, r(66), __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, __, __, __, __, __, p(311), __, __, __, __, __, __],
+    /*  517 */ [__, r(7), r(7), __, __, r(7), __, r(7), r(7), r(7), r(7), __, r(7), __, __, __, r(7), r(7), __, __, __, r(7), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(7), __, __, __, __, __, r(7), __, r(7), __, __, __, __, __, __, __, r(7), __],
+    /*  518 */ [__, r(53), r(53), __, __, __, __, r(53), r(53), r(53), r(53), __, r(53), __, __, __, r(53), r(53), __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, r(53), __, r(53), __, __, __, __, __, __, __, r(53), r(53)],
+    /*  519 */ [r(47), r(47), r(47), __, __, r(47), __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), __, __, __, r(47), r(47), __],
+    /*  520 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, r(76), __, r(76), __, __, r(76), __, __, r(76), __, __, __, __, r(76), __, __],
+    /*  521 */ [r(64), r(64), r(64), __, __, r(64), __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(918), __, s(943), __, s(801), __, r(64), s(910), r(64), r(64), s(909), r(64), __, __, __, s(912), r(64), __],
+    /*  522 */ [r(49), __, r(49), __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, __, r(49), __, __, r(49), r(49), __, __, __, r(49), __, __],
+    /*  523 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(324), __, s(343), __, __, __, __, __, __, __, __, __, __, __, __, s(323), __, __],
+    /*  524 */ [r(78), r(78), r(78), __, __, __, __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), r(78), __, r(78), __, __, __, __, r(78), r(78)],
+    /*  525 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  526 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  527 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), __, __, __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(467), __],
+    /*  528 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), __, r(49), __, __, r(49), __, __, r(49), r(49), __, __, __, r(49), __, __],
+    /*  529 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, r(78), __, __, r(78), __, r(78), __, __, __, __, __, r(78)],
+    /*  530 */ [r(87), __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __],
+    /*  531 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(319), __, s(353), __, __, __, __, __, __, __, __, __, __, __, __, s(317), __, __],
+    /*  532 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, r(78), __, __, __, __, __, __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /*  533 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, r(78), __, __, __, __, __, r(78), __, __, __, __, __, __],
+    /*  534 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __],
+    /*  535 */ [__, __, __, __, __, __, s(877), __, s(969), __, __, __, __, s(878), s(136), __, __, __, __, s(879), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(953), __, s(971), __, __, __, __, __, __, __, __, __, s(880), s(875), s(876), s(872), s(837), __],
+    /*  536 */ [__, r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, __, r(87), r(87), __, __, __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), __, __, __, __, r(87), r(87), r(87)],
+    /*  537 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(90), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  538 */ [__, __, __, __, __, __, s(291), __, s(370), __, __, __, __, s(292), s(136), __, __, __, __, s(293), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(364), __, s(371), __, __, __, __, __, __, __, __, __, s(296), s(280), s(281), s(279), s(160), __],
+    /*  539 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(93), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  540 */ [__, r(73), r(73), __, __, __, __, r(73), r(73), r(73), r(73), __, r(73), __, __, __, r(73), r(73), __, __, __, r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), __, __, __, __, r(73), r(73), r(73)],
+    /*  541 */ [r(63), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, r(63), __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /*  542 */ [__, __, __, __, __, __, r(98), __, r(98), __, __, __, __, r(98), r(98), __, __, __, __, r(98), __, __, __, r(98), __, __, __, __, r(98), __, __, r(98), __, __, __, __, __, __, __, __, __, __, r(98), __, r(98), r(98), r(98), __, __, __, __, __, __, __, r(98), r(98), r(98), r(98), r(98), __],
+    /*  543 */ [__, __, __, __, __, __, r(97), __, r(97), __, __, __, __, r(97), r(97), __, __, __, __, r(97), __, __, __, r(97), __, __, __, __, r(97), __, __, r(97), __, __, __, __, __, __, __, __, __, __, r(97), __, r(97), r(97), r(97), __, __, __, __, __, __, __, r(97), r(97), r(97), r(97), r(97), __],
+    /*  544 */ [__, __, __, __, __, __, s(867), __, s(970), __, __, __, __, s(868), s(136), __, __, __, __, s(869), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(952), __, s(972), __, __, __, __, __, __, __, __, __, s(870), s(865), s(866), s(864), s(845), __],
+    /*  545 */ [r(65), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, r(65), __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /*  546 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, r(87), __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __],
+    /*  547 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(856), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  548 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(800), __],
+    /*  549 */ [__, __, __, s(740), s(798), r(16), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  550 */ [__, __, __, __, __, __, __, __, s(775), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(777), __],
+    /*  551 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(745), __],
+    /*  552 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, r(71)],
+    /*  553 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(743), __],
+    /*  554 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, r(77)],
+    /*  555 */ [__, s(46), s(48), __, __, __, __, s(66), s(68), s(69), s(71), __, s(101), __, __, __, s(103), r(2), __, __, r(2), s(106), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(115), __, s(119), __, __, __, __, __, __, __, s(19), __],
+    /*  556 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, r(78), __, __, r(78), __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /*  557 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  558 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  559 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  560 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  561 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(747), __],
+    /*  562 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), r(71), __, r(71), __, __, r(71), r(71), __, __, __, r(71), __, __],
+    /*  563 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), r(77), __, r(77), __, __, r(77), r(77), __, __, __, r(77), __, __],
+    /*  564 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  565 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  566 */ [__, __, __, __, __, __, s(484), __, s(669), __, __, __, __, s(495), s(136), __, __, __, __, s(496), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(646), __, s(670), __, __, __, __, __, __, __, __, __, s(498), s(481), s(483), s(480), s(401), __],
+    /*  567 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(790), __],
+    /*  568 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(774), __],
+    /*  569 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /*  570 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /*  571 */ [s(283), r(54), r(54), __, __, __, __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, r(54), r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(810), __, s(311), __, __, __, __, r(54), __],
+    /*  572 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  573 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), __, r(70), __, __, r(70), __, __, r(70), r(70), __, __, __, r(70), __, __],
+    /*  574 */ [r(67), r(67), r(67), __, __, __, __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), r(67), __, r(67), __, __, __, __, r(67), r(67)],
+    /*  575 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, r(67), __, __, r(67), __, r(67), __, __, __, __, __, r(67)],
+    /*  576 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(857), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  577 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  578 */ [r(70), __, r(70), __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, __, r(70), __, __, r(70), r(70), __, __, __, r(70), __, __],
+    /*  579 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(844), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  580 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(873), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  581 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(858), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  582 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(782), __],
+    /*  583 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(772), __],
+    /*  584 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), __, r(71), __, __, r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /*  585 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), __, r(77), __, __, r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /*  586 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(852), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  587 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  588 */ [r(59), r(59), r(59), __, __, __, __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), __, __, r(59), __, __, __, __, r(59), r(59)],
+    /*  589 */ [r(60), r(60), r(60), __, __, __, __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), __, __, r(60), __, __, __, __, r(60), r(60)],
+    /*  590 */ [r(61), r(61), r(61), __, __, __, __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), __, __, r(61), __, __, __, __, r(61), r(61)],
+    /*  591 */ [r(57), r(57), r(57), __, __, __, __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), __, __, r(57), __, __, __, __, r(57), r(57)],
+    /*  592 */ [r(56), r(56), r(56), __, __, __, __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), __, __, r(56), __, __, __, __, r(56), r(56)],
+    /*  593 */ [r(58), r(58), r(58), __, __, __, __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), __, __, r(58), __, __, __, __, r(58), r(58)],
+    /*  594 */ [r(62), r(62), r(62), __, __, __, __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), __, __, r(62), __, __, __, __, r(62), r(62)],
+    /*  595 */ [r(59), r(59), r(59), __, __, __, __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), r(59), __, r(59), __, __, __, __, r(59), __],
+    /*  596 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, r(67), __, __, __, __, __, __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /*  597 */ [r(60), r(60), r(60), __, __, __, __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), r(60), __, r(60), __, __, __, __, r(60), __],
+    /*  598 */ [r(61), r(61), r(61), __, __, __, __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), r(61), __, r(61), __, __, __, __, r(61), __],
+    /*  599 */ [r(57), r(57), r(57), __, __, __, __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), r(57), __, r(57), __, __, __, __, r(57), __],
+    /*  600 */ [r(56), r(56), r(56), __, __, __, __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), r(56), __, r(56), __, __, __, __, r(56), __],
+    /*  601 */ [r(58), r(58), r(58), __, __, __, __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), r(58), __, r(58), __, __, __, __, r(58), __],
+    /*  602 */ [r(62), r(62), r(62), __, __, __, __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), r(62), __, r(62), __, __, __, __, r(62), __],
+    /*  603 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /*  604 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /*  605 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  606 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, __, r(70), __, __, r(70), r(70), __, __, __, r(70), __, __],
+    /*  607 */ [s(283), r(54), r(54), __, __, r(54), __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, __, r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(853), __, s(311), __, __, __, __, r(54), __],
+    /*  608 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(779), __],
+    /*  609 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  610 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(791), __],
+    /*  611 */ [__, __, __, __, __, s(799), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  612 */ [r(71), r(71), r(71), __, __, __, __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), __, __, __, r(71), r(71), r(71)],
+    /*  613 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  614 */ [r(77), r(77), r(77), __, __, __, __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), __, __, __, r(77), r(77), r(77)],
+    /*  615 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  616 */ [__, __, __, __, __, __, s(291), __, s(370), __, __, __, __, s(292), s(136), __, __, __, __, s(293), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(364), __, s(371), __, __, __, __, __, __, __, __, __, s(296), s(280), s(281), s(279), s(160), __],
+    /*  617 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, __, r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /*  618 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, __, r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /*  619 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, r(76)],
+    /*  620 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  621 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), r(76), __, r(76), __, __, r(76), r(76), __, __, __, r(76), __, __],
+    /*  622 */ [r(59), r(59), r(59), __, __, r(59), __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), r(59), __, r(59), __, __, __, __, r(59), __],
+    /*  623 */ [r(60), r(60), r(60), __, __, r(60), __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), r(60), __, r(60), __, __, __, __, r(60), __],
+    /*  624 */ [r(61), r(61), r(61), __, __, r(61), __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), r(61), __, r(61), __, __, __, __, r(61), __],
+    /*  625 */ [r(57), r(57), r(57), __, __, r(57), __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), r(57), __, r(57), __, __, __, __, r(57), __],
+    /*  626 */ [r(56), r(56), r(56), __, __, r(56), __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), r(56), __, r(56), __, __, __, __, r(56), __],
+    /*  627 */ [r(58), r(58), r(58), __, __, r(58), __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), r(58), __, r(58), __, __, __, __, r(58), __],
+    /*  628 */ [r(62), r(62), r(62), __, __, r(62), __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), r(62), __, r(62), __, __, __, __, r(62), __],
+    /*  629 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  630 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(789), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  631 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  632 */ [r(63), r(63), r(63), __, __, __, __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), r(63), __, r(63), __, __, __, __, r(63), __],
+    /*  633 */ [r(65), r(65), r(65), __, __, __, __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), r(65), __, r(65), __, __, __, __, r(65), __],
+    /*  634 */ [__, r(6), r(6), r(6), r(6), r(6), __, r(6), r(6), r(6), r(6), __, r(6), __, __, __, r(6), r(6), __, __, __, r(6), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(6), __, __, __, __, __, r(6), __, r(6), __, __, __, __, __, __, __, r(6), __],
+    /*  635 */ [__, r(9), r(9), r(9), r(9), r(9), __, r(9), r(9), r(9), r(9), __, r(9), __, __, __, r(9), r(9), __, __, __, r(9), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(9), __, __, __, __, __, r(9), __, r(9), __, __, __, __, __, __, __, r(9), __],
+    /*  636 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(804), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  637 */ [__, r(8), r(8), r(8), r(8), r(8), __, r(8), r(8), r(8), r(8), __, r(8), __, __, __, r(8), r(8), __, __, __, r(8), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, r(69), __, r(69), __, r(8), r(69), r(8), __, r(69), __, __, __, __, r(69), r(8), __],
+    /*  638 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(809), __],
+    /*  639 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(855), __, s(921), __, s(47), __, __, s(850), __, __, s(72), __, __, __, __, s(854), __, __],
+    /*  640 */ [__, __, __, __, __, __, s(599), __, s(680), __, __, __, __, s(600), s(136), __, __, __, __, s(601), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(667), __, s(681), __, __, __, __, __, __, __, __, __, s(602), s(597), s(598), s(595), s(479), __],
+    /*  641 */ [r(63), r(63), r(63), __, __, __, __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), __, __, r(63), __, __, __, __, r(63), r(63)],
+    /*  642 */ [r(65), r(65), r(65), __, __, __, __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), __, __, r(65), __, __, __, __, r(65), r(65)],
+    /*  643 */ [__, __, __, __, __, __, s(591), __, s(688), __, __, __, __, s(592), s(136), __, __, __, __, s(593), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(676), __, s(689), __, __, __, __, __, __, __, __, __, s(594), s(589), s(590), s(588), s(505), __],
+    /*  644 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), __, r(76), __, __, r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /*  645 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  646 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  647 */ [r(68), __, r(68), __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, __, r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /*  648 */ [r(69), __, r(69), __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, __, r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /*  649 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(820), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  650 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  651 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(832), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  652 */ [r(68), __, __, __, __, r(68), __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /*  653 */ [r(69), __, __, __, __, r(69), __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /*  654 */ [r(63), r(63), r(63), __, __, r(63), __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), r(63), __, r(63), __, __, __, __, r(63), __],
+    /*  655 */ [r(65), r(65), r(65), __, __, r(65), __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), r(65), __, r(65), __, __, __, __, r(65), __],
+    /*  656 */ [r(76), r(76), r(76), __, __, __, __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), __, __, __, r(76), r(76), r(76)],
+    /*  657 */ [__, __, __, __, __, __, s(625), __, s(692), __, __, __, __, s(626), s(136), __, __, __, __, s(627), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(684), __, s(693), __, __, __, __, __, __, __, __, __, s(628), s(623), s(624), s(622), s(519), __],
+    /*  658 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  659 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(797), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  660 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  661 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(794), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  662 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(795), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  663 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(808), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  664 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(815), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  665 */ [r(68), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /*  666 */ [r(69), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /*  667 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  668 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(847), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  669 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(822), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  670 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  671 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(812), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  672 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(780), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  673 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(814), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  674 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(803), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  675 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(802), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  676 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  677 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(806), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  678 */ [r(68), r(68), r(68), __, __, __, __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), __, __, __, r(68), r(68), __],
+    /*  679 */ [r(69), r(69), r(69), __, __, __, __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), __, __, __, r(69), r(69), __],
+    /*  680 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(821), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  681 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  682 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(833), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  683 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(823), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  684 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  685 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(817), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  686 */ [r(68), r(68), r(68), __, __, __, __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), __, r(68), r(68), __, __, __, r(68), r(68), r(68)],
+    /*  687 */ [r(69), r(69), r(69), __, __, __, __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), __, r(69), r(69), __, __, __, r(69), r(69), r(69)],
+    /*  688 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(829), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  689 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  690 */ [r(68), r(68), r(68), __, __, r(68), __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), __, __, __, r(68), r(68), __],
+    /*  691 */ [r(69), r(69), r(69), __, __, r(69), __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), __, __, __, r(69), r(69), __],
+    /*  692 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(831), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  693 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  694 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(43), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  695 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(41), __, __, __, __, __, __, r(41), __, __, __, __, __, __, __, __, __, __],
+    /*  696 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(82), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  697 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(976), __, __, __, s(975), __],
+    /*  698 */ [__, r(31), r(31), __, __, __, __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, __, r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(165), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), r(31)],
+    /*  699 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(990), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  700 */ [__, __, __, __, __, r(33), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  701 */ [__, r(32), r(32), __, __, __, __, r(32), r(32), r(32), r(32), __, r(32), __, __, __, r(32), r(32), __, __, __, r(32), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(32), r(32), __, __, __, __, __, r(32), __, r(32), r(32), __, __, __, __, __, __, r(32), r(32)],
+    /*  702 */ [__, __, __, __, __, r(37), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  703 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(981), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  704 */ [__, __, __, r(1), r(1), r(1), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  705 */ [__, __, __, r(5), r(5), r(5), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  706 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(33), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  707 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(37), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  708 */ [__, r(24), r(24), __, __, __, __, r(24), r(24), r(24), r(24), __, r(24), __, __, __, r(24), r(24), __, __, r(24), r(24), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(24), __, __, __, __, __, r(24), __, r(24), __, __, __, __, __, __, __, r(24), __],
+    /*  709 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(980), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  710 */ [__, r(28), r(28), __, __, __, __, r(28), r(28), r(28), r(28), __, r(28), __, __, __, r(28), r(28), __, __, r(28), r(28), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(28), r(28), __, __, __, __, __, r(28), __, r(28), __, __, __, __, __, __, __, r(28), __],
+    /*  711 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(977), __],
+    /*  712 */ [__, __, s(987), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  713 */ [__, r(24), r(24), __, __, r(24), __, r(24), r(24), r(24), r(24), __, r(24), __, __, __, r(24), r(24), __, __, __, r(24), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(24), __, __, __, __, __, r(24), __, r(24), __, __, __, __, __, __, __, r(24), __],
+    /*  714 */ [__, __, s(993), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  715 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  716 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  717 */ [__, r(28), r(28), __, __, r(28), __, r(28), r(28), r(28), r(28), __, r(28), __, __, __, r(28), r(28), __, __, __, r(28), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(28), r(28), __, __, __, __, __, r(28), __, r(28), __, __, __, __, __, __, __, r(28), __],
+    /*  718 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(978), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  719 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(979), __],
+    /*  720 */ [__, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  721 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(998), __, s(311), __, __, __, __, __, __],
+    /*  722 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(997), __, s(311), __, __, __, __, __, __],
+    /*  723 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  724 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), __, r(48), __, __, r(48), __, __, r(48), r(48), __, __, __, r(48), __, __],
+    /*  725 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  726 */ [__, __, __, r(34), r(34), r(34), s(1026), __, s(1157), __, __, __, __, s(1027), s(136), __, __, __, __, s(1028), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1121), __, s(1158), __, __, __, r(34), __, __, __, __, __, s(1029), s(1024), s(1025), s(1023), s(985), __],
+    /*  727 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  728 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  729 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  730 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  731 */ [__, r(27), r(27), __, __, __, __, r(27), r(27), r(27), r(27), __, r(27), __, __, __, r(27), r(27), __, __, r(27), r(27), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(27), __, __, __, __, __, r(27), __, r(27), __, __, __, __, __, __, __, r(27), __],
+    /*  732 */ [__, __, __, r(3), r(3), r(3), __, __, __, __, __, __, __, __, __, __, __, r(3), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  733 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  734 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(984), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  735 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(986), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  736 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(989), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  737 */ [__, __, __, __, __, __, s(591), __, s(688), __, __, __, __, s(592), s(136), __, __, __, __, s(593), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(676), __, s(689), __, __, __, __, __, __, __, __, __, s(594), s(589), s(590), s(588), s(505), __],
+    /*  738 */ [__, __, __, __, __, s(988), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  739 */ [__, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __],
+    /*  740 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  741 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __],
+    /*  742 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), __, r(72), __, __, r(72), __, __, r(72), r(72), __, __, __, r(72), __, __],
+    /*  743 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), r(49), __, r(49), __, __, r(49), r(49), __, __, __, r(49), __, __],
+    /*  744 */ [__, r(72), r(72), __, __, __, __, r(72), r(72), r(72), r(72), __, r(72), __, __, __, r(72), r(72), __, __, r(72), r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), __, __, __, __, r(72), r(72), __],
+    /*  745 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(564), __, s(620), __, __, __, __, __, __, __, __, __, __, __, __, s(554), __, __],
+    /*  746 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, s(996), __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  747 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(565), __, s(629), __, __, __, __, __, __, __, __, __, __, __, __, s(563), __, __],
+    /*  748 */ [__, __, __, __, __, __, __, __, __, __, __, r(52), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  749 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, __, r(48), __, __, r(48), r(48), __, __, __, r(48), __, __],
+    /*  750 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(995), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  751 */ [__, __, __, __, __, __, __, __, __, __, __, r(51), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(999), __, __, __, __, __, __, __, __, __, s(250), __, __, __, __, __, __, __, __],
+    /*  752 */ [__, __, __, __, __, __, __, __, __, __, __, s(1000), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  753 */ [r(48), __, r(48), __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, __, r(48), __, __, r(48), r(48), __, __, __, r(48), __, __],
+    /*  754 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  755 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  756 */ [__, r(11), r(11), r(11), r(11), r(11), __, r(11), r(11), r(11), r(11), __, r(11), __, __, __, r(11), r(11), __, __, __, r(11), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(11), __, __, __, __, __, r(11), __, r(11), __, __, __, __, __, __, __, r(11), __],
+    /*  757 */ [__, __, __, __, __, __, s(461), __, s(659), __, __, __, __, s(462), s(136), __, __, __, __, s(463), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(613), __, s(660), __, __, __, __, __, __, __, __, __, s(465), s(459), s(460), s(458), s(397), __],
+    /*  758 */ [__, __, __, __, __, __, s(484), __, s(669), __, __, __, __, s(495), s(136), __, __, __, __, s(496), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(646), __, s(670), __, __, __, __, __, __, __, __, __, s(498), s(481), s(483), s(480), s(401), __],
+    /*  759 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(497), __, s(526), __, __, __, __, __, __, __, __, __, __, __, __, s(493), __, __],
+    /*  760 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, r(66), __, __, __, __, __, p(311), __, __, __, __, __, __],
+    /*  761 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1074), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  762 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1050), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  763 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1042), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  764 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, __, r(72), __, __, r(72), r(72), __, __, __, r(72), __, __],
+    /*  765 */ [r(72), __, r(72), __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, __, r(72), __, __, r(72), r(72), __, __, __, r(72), __, __],
+    /*  766 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  767 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(426), __, s(311), __, __, __, __, __, r(54)],
+    /*  768 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1018), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  769 */ [__, __, __, __, __, s(1043), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  770 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  771 */ [__, __, __, __, __, __, s(484), __, s(669), __, __, __, __, s(495), s(136), __, __, __, __, s(496), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(646), __, s(670), __, __, __, __, __, __, __, __, __, s(498), s(481), s(483), s(480), s(401), __],
+    /*  772 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(587), __, s(645), __, __, __, __, __, __, __, __, __, __, __, __, s(585), __, __],
+    /*  773 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  774 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(572), __, s(605), __, __, __, __, __, __, __, __, __, __, __, __, s(570), __, __],
+    /*  775 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1047), __],
+    /*  776 */ [__, r(26), r(26), r(26), r(26), r(26), __, r(26), r(26), r(26), r(26), __, r(26), __, __, __, r(26), r(26), __, __, __, r(26), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1068), r(26), __, __, __, __, __, r(26), __, r(26), __, __, __, __, __, __, __, r(26), __],
+    /*  777 */ [__, r(31), r(31), r(31), r(31), r(31), __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, __, r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1031), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), __],
+    /*  778 */ [s(283), __, s(1049), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  779 */ [r(49), r(49), r(49), __, __, __, __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), __, __, __, r(49), r(49), r(49)],
+    /*  780 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, __, r(73), __, __, r(73), r(73), __, __, __, r(73), __, __],
+    /*  781 */ [__, r(72), r(72), __, __, r(72), __, r(72), r(72), r(72), r(72), __, r(72), __, __, __, r(72), r(72), __, __, __, r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), __, __, __, __, r(72), r(72), __],
+    /*  782 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), __, r(49), __, __, r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /*  783 */ [__, __, __, __, __, __, s(461), __, s(659), __, __, __, __, s(462), s(136), __, __, __, __, s(463), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(613), __, s(660), __, __, __, __, __, __, __, __, __, s(465), s(459), s(460), s(458), s(397), __],
+    /*  784 */ [__, r(27), r(27), __, __, r(27), __, r(27), r(27), r(27), r(27), __, r(27), __, __, __, r(27), r(27), __, __, __, r(27), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(27), __, __, __, __, __, r(27), __, r(27), __, __, __, __, __, __, __, r(27), __],
+    /*  785 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1106), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  786 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1054), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  787 */ [s(283), r(14), r(14), __, __, r(14), __, r(14), r(14), r(14), r(14), __, r(14), __, __, s(284), r(14), r(14), __, __, __, r(14), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(14), __, __, __, __, __, r(14), __, r(14), __, __, s(311), __, __, __, __, r(14), __],
+    /*  788 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, __, __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /*  789 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  790 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), r(49), r(49), __, r(49), r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /*  791 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(615), __, s(658), __, __, __, __, __, __, __, __, __, __, __, __, s(614), __, __],
+    /*  792 */ [__, r(53), r(53), __, __, __, __, r(53), r(53), r(53), r(53), __, r(53), __, __, __, r(53), r(53), __, __, r(53), r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, r(53), __, r(53), __, __, __, __, __, __, __, r(53), __],
+    /*  793 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, r(66), __, __, __, __, __, __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /*  794 */ [r(73), __, r(73), __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, __, r(73), __, __, r(73), r(73), __, __, __, r(73), __, __],
+    /*  795 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, r(87), __, __, r(87), __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /*  796 */ [r(78), __, __, __, __, r(78), __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, r(78), __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /*  797 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  798 */ [__, __, __, __, __, __, s(79), __, s(132), __, __, __, __, s(80), s(136), __, __, __, __, s(81), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(122), __, s(134), __, __, __, __, __, __, __, __, __, s(82), s(77), s(78), s(76), s(59), __],
+    /*  799 */ [__, r(13), r(13), __, __, __, __, r(13), r(13), r(13), r(13), __, r(13), __, __, __, r(13), r(13), __, __, __, r(13), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(13), __, __, __, __, __, r(13), __, r(13), __, __, __, __, __, __, __, r(13), r(13)],
+    /*  800 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, r(49)],
+    /*  801 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /*  802 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, r(87), __, __, __, __, __, __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /*  803 */ [r(87), __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, __, r(87), __, __, r(87), r(87), __, __, __, r(87), __, __],
+    /*  804 */ [__, __, __, __, __, __, s(1114), __, s(1182), __, __, __, __, s(1115), s(136), __, __, __, __, s(1116), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1172), __, s(1183), __, __, __, __, __, __, __, __, __, s(1118), s(1112), s(1113), s(1111), s(1064), __],
+    /*  805 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1100), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  806 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, __, r(87), __, __, r(87), r(87), __, __, __, r(87), __, __],
+    /*  807 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, r(54), __, __, __, __, __, __, __, s(435), __, s(311), __, __, __, __, __, __],
+    /*  808 */ [__, r(73), r(73), __, __, __, __, r(73), r(73), r(73), r(73), __, r(73), __, __, __, r(73), r(73), __, __, r(73), r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), __, __, __, __, r(73), r(73), __],
+    /*  809 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1006), __, __, __, __, __, __, __, __, __],
+    /*  810 */ [__, __, __, __, __, __, s(599), __, s(680), __, __, __, __, s(600), s(136), __, __, __, __, s(601), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(667), __, s(681), __, __, __, __, __, __, __, __, __, s(602), s(597), s(598), s(595), s(479), __],
+    /*  811 */ [s(283), r(14), r(14), __, __, __, __, r(14), r(14), r(14), r(14), __, r(14), __, __, s(284), r(14), r(14), __, __, r(14), r(14), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(14), __, __, __, __, __, r(14), __, r(14), __, __, s(311), __, __, __, __, r(14), __],
+    /*  812 */ [__, r(73), r(73), __, __, r(73), __, r(73), r(73), r(73), r(73), __, r(73), __, __, __, r(73), r(73), __, __, __, r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), __, __, __, __, r(73), r(73), __],
+    /*  813 */ [r(78), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, r(78), __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /*  814 */ [__, r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, __, r(87), r(87), __, __, r(87), r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), __, __, __, __, r(87), r(87), __],
+    /*  815 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, r(87), __, __, __, __, __, r(87), __, __, __, __, __, __],
+    /*  816 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1119), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  817 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, r(87), __, __, r(87), __, r(87), __, __, __, __, __, r(87)],
+    /*  818 */ [r(78), __, r(78), __, __, __, __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, __, __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /*  819 */ [r(78), r(78), r(78), __, __, __, __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), r(78), __, r(78), __, __, __, __, r(78), __],
+    /*  820 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  821 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  822 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  823 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), __, r(87), __, __, r(87), __, __, r(87), r(87), __, __, __, r(87), __, __],
+    /*  824 */ [r(67), __, __, __, __, r(67), __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, r(67), __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /*  825 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, r(66), __, __, r(66), __, p(311), __, __, __, __, __, r(66)],
+    /*  826 */ [r(67), __, r(67), __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, __, __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /*  827 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, r(66), __, __, r(66), __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /*  828 */ [r(78), r(78), r(78), __, __, __, __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), __, __, r(78), __, __, __, __, r(78), r(78)],
+    /*  829 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  830 */ [r(78), r(78), r(78), __, __, r(78), __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), r(78), __, r(78), __, __, __, __, r(78), __],
+    /*  831 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /*  832 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), __, r(73), __, __, r(73), __, __, r(73), r(73), __, __, __, r(73), __, __],
+    /*  833 */ [__, r(87), r(87), __, __, r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, __, r(87), r(87), __, __, __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), __, __, __, __, r(87), r(87), __],
+    /*  834 */ [__, r(53), r(53), __, __, r(53), __, r(53), r(53), r(53), r(53), __, r(53), __, __, __, r(53), r(53), __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, r(53), __, r(53), __, __, __, __, __, __, __, r(53), __],
+    /*  835 */ [__, __, __, __, __, __, s(473), __, s(630), __, __, __, __, s(474), s(136), __, __, __, __, s(475), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(577), __, s(631), __, __, __, __, __, __, __, __, __, s(476), s(471), s(472), s(469), s(453), __],
+    /*  836 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1120), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  837 */ [r(47), r(47), r(47), __, __, r(47), __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), __, r(47), r(47), __, __, __, r(47), r(47), __],
+    /*  838 */ [r(64), r(64), r(64), __, __, r(64), __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(1101), __, s(1144), __, s(1046), __, r(64), s(1094), r(64), __, s(1090), r(64), __, __, __, s(1097), r(64), __],
+    /*  839 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1145), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  840 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1110), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  841 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, r(95), __, __, r(95), __, __, r(95), __, s(311), __, __, __, __, __, __],
+    /*  842 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, __, __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /*  843 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1151), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  844 */ [r(70), r(70), r(70), __, __, __, __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), __, __, __, r(70), r(70), r(70)],
+    /*  845 */ [r(47), r(47), r(47), __, __, __, __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), __, r(47), r(47), __, __, __, r(47), r(47), __],
+    /*  846 */ [r(64), r(64), r(64), __, __, __, __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(1135), __, s(1160), __, s(1051), __, r(64), s(1126), r(64), __, s(1125), r(64), __, __, __, s(1128), r(64), __],
+    /*  847 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), r(87), __, r(87), __, __, __, __, r(87), r(87)],
+    /*  848 */ [r(67), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, r(67), __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, r(67), __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /*  849 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1087), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  850 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1093), __],
+    /*  851 */ [__, r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), __, r(71), __, __, __, r(71), r(71), __, __, __, r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), __, __, __, __, r(71), r(71), __],
+    /*  852 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), __, r(70), __, __, r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /*  853 */ [__, __, __, __, __, __, s(625), __, s(692), __, __, __, __, s(626), s(136), __, __, __, __, s(627), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(684), __, s(693), __, __, __, __, __, __, __, __, __, s(628), s(623), s(624), s(622), s(519), __],
+    /*  854 */ [__, r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), __, r(77), __, __, __, r(77), r(77), __, __, __, r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), __, __, __, __, r(77), r(77), __],
+    /*  855 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  856 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), r(70), r(70), __, r(70), r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /*  857 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, r(70)],
+    /*  858 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), r(70), __, r(70), __, __, r(70), r(70), __, __, __, r(70), __, __],
+    /*  859 */ [__, __, __, s(740), s(798), r(16), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  860 */ [__, __, __, __, __, __, s(599), __, s(680), __, __, __, __, s(600), s(136), __, __, __, __, s(601), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(667), __, s(681), __, __, __, __, __, __, __, __, __, s(602), s(597), s(598), s(595), s(479), __],
+    /*  861 */ [s(283), r(54), r(54), __, __, __, __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, __, r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(538), __, s(311), __, __, __, __, r(54), r(54)],
+    /*  862 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1161), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /*  863 */ [p(283), r(66), r(66), __, __, __, __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, __, r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), r(66), __, p(311), __, __, __, __, r(66), r(66)],
+    /*  864 */ [r(59), r(59), r(59), __, __, __, __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), __, __, r(59), __, __, __, __, r(59), __],
+    /*  865 */ [r(60), r(60), r(60), __, __, __, __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), __, __, r(60), __, __, __, __, r(60), __],
+    /*  866 */ [r(61), r(61), r(61), __, __, __, __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), __, __, r(61), __, __, __, __, r(61), __],
+    /*  867 */ [r(57), r(57), r(57), __, __, __, __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), __, __, r(57), __, __, __, __, r(57), __],
+    /*  868 */ [r(56), r(56), r(56), __, __, __, __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), __, __, r(56), __, __, __, __, r(56), __],
+    /*  869 */ [r(58), r(58), r(58), __, __, __, __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), __, __, r(58), __, __, __, __, r(58), __],
+    /*  870 */ [r(62), r(62), r(62), __, __, __, __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), __, __, r(62), __, __, __, __, r(62), __],
+    /*  871 */ [__, __, __, s(740), s(798), r(16), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  872 */ [r(59), r(59), r(59), __, __, r(59), __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), __, __, r(59), __, __, __, __, r(59), __],
+    /*  873 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1007), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  874 */ [r(67), r(67), r(67), __, __, __, __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), __, __, r(67), __, __, __, __, r(67), r(67)],
+    /*  875 */ [r(60), r(60), r(60), __, __, r(60), __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), __, __, r(60), __, __, __, __, r(60), __],
+    /*  876 */ [r(61), r(61), r(61), __, __, r(61), __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), __, __, r(61), __, __, __, __, r(61), __],
+    /*  877 */ [r(57), r(57), r(57), __, __, r(57), __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), __, __, r(57), __, __, __, __, r(57), __],
+    /*  878 */ [r(56), r(56), r(56), __, __, r(56), __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), __, __, r(56), __, __, __, __, r(56), __],
+    /*  879 */ [r(58), r(58), r(58), __, __, r(58), __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), __, __, r(58), __, __, __, __, r(58), __],
+    /*  880 */ [r(62), r(62), r(62), __, __, r(62), __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), __, __, r(62), __, __, __, __, r(62), __],
+    /*  881 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1132), __],
+    /*  882 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1019), __],
+    /*  883 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1032), __],
+    /*  884 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1034), __],
+    /*  885 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, __, r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /*  886 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, __, r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /*  887 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1075), __],
+    /*  888 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1084), __],
+    /*  889 */ [r(71), r(71), r(71), __, __, __, __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), r(71), __, __, __, r(71), r(71), r(71)],
+    /*  890 */ [r(77), r(77), r(77), __, __, __, __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), r(77), __, __, __, r(77), r(77), r(77)],
+    /*  891 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1083), __],
+    /*  892 */ [r(71), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /*  893 */ [r(77), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /*  894 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  895 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  896 */ [r(67), r(67), r(67), __, __, r(67), __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), r(67), __, r(67), __, __, __, __, r(67), __],
+    /*  897 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1040), __],
+    /*  898 */ [r(71), __, r(71), __, __, __, __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, __, r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /*  899 */ [r(77), __, r(77), __, __, __, __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, __, r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /*  900 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  901 */ [r(67), r(67), r(67), __, __, __, __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), r(67), __, r(67), __, __, __, __, r(67), __],
+    /*  902 */ [__, __, __, __, __, __, s(625), __, s(692), __, __, __, __, s(626), s(136), __, __, __, __, s(627), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(684), __, s(693), __, __, __, __, __, __, __, __, __, s(628), s(623), s(624), s(622), s(519), __],
+    /*  903 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  904 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1085), __],
+    /*  905 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1133), __],
+    /*  906 */ [r(71), __, __, __, __, r(71), __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /*  907 */ [r(77), __, __, __, __, r(77), __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /*  908 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1136), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  909 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1076), __],
+    /*  910 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1141), __],
+    /*  911 */ [r(71), r(71), r(71), __, __, r(71), __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), __, __, __, r(71), r(71), __],
+    /*  912 */ [r(77), r(77), r(77), __, __, r(77), __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), __, __, __, r(77), r(77), __],
+    /*  913 */ [__, __, __, __, __, s(1092), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  914 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1108), __],
+    /*  915 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  916 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1011), __],
+    /*  917 */ [__, r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), __, r(76), __, __, __, r(76), r(76), __, __, __, r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), __, __, __, __, r(76), r(76), __],
+    /*  918 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  919 */ [r(71), r(71), r(71), __, __, __, __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), __, __, __, r(71), r(71), __],
+    /*  920 */ [r(77), r(77), r(77), __, __, __, __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), __, __, __, r(77), r(77), __],
+    /*  921 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  922 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /*  923 */ [__, __, __, __, __, s(1147), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  924 */ [r(63), r(63), r(63), __, __, __, __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), __, __, r(63), __, __, __, __, r(63), __],
+    /*  925 */ [r(65), r(65), r(65), __, __, __, __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), __, __, r(65), __, __, __, __, r(65), __],
+    /*  926 */ [__, __, __, __, __, __, s(867), __, s(970), __, __, __, __, s(868), s(136), __, __, __, __, s(869), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(952), __, s(972), __, __, __, __, __, __, __, __, __, s(870), s(865), s(866), s(864), s(845), __],
+    /*  927 */ [r(63), r(63), r(63), __, __, r(63), __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), __, __, r(63), __, __, __, __, r(63), __],
+    /*  928 */ [r(65), r(65), r(65), __, __, r(65), __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), __, __, r(65), __, __, __, __, r(65), __],
+    /*  929 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /*  930 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  931 */ [__, __, __, __, __, __, s(877), __, s(969), __, __, __, __, s(878), s(136), __, __, __, __, s(879), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(953), __, s(971), __, __, __, __, __, __, __, __, __, s(880), s(875), s(876), s(872), s(837), __],
+    /*  932 */ [r(76), __, r(76), __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, __, r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /*  933 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  934 */ [r(76), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, __, r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /*  935 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1103), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  936 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  937 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1140), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  938 */ [r(76), __, __, __, __, r(76), __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /*  939 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  940 */ [r(76), r(76), r(76), __, __, __, __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), __, __, __, r(76), r(76), __],
+    /*  941 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  942 */ [r(76), r(76), r(76), __, __, r(76), __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), __, __, __, r(76), r(76), __],
+    /*  943 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  944 */ [r(76), r(76), r(76), __, __, __, __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), r(76), __, __, __, r(76), r(76), r(76)],
+    /*  945 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  946 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1146), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  947 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1010), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  948 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1102), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  949 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1137), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  950 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1096), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  951 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1131), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  952 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  953 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /*  954 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1015), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  955 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1129), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  956 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1016), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  957 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1134), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  958 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1138), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  959 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1124), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  960 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1105), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  961 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1123), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  962 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1036), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  963 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1037), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  964 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1139), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  965 */ [r(68), r(68), r(68), __, __, r(68), __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), __, r(68), r(68), __, __, __, r(68), r(68), __],
+    /*  966 */ [r(69), r(69), r(69), __, __, r(69), __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), __, r(69), r(69), __, __, __, r(69), r(69), __],
+    /*  967 */ [r(68), r(68), r(68), __, __, __, __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), __, r(68), r(68), __, __, __, r(68), r(68), __],
+    /*  968 */ [r(69), r(69), r(69), __, __, __, __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), __, r(69), r(69), __, __, __, r(69), r(69), __],
+    /*  969 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1048), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  970 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1045), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  971 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  972 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /*  973 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(91), __, __, s(542), __, __, s(543), __, __, __, __, __, __, __, __],
+    /*  974 */ [__, r(29), r(29), __, __, __, __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, __, r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(380), __, __, __, __, __, __, r(29), r(29)],
+    /*  975 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(83), __, __, __, __, __, __, __, s(697), __, __, __, __, __, __, __, __],
+    /*  976 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(85), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /*  977 */ [__, r(31), r(31), __, __, __, __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, r(31), r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(409), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), __],
+    /*  978 */ [__, r(32), r(32), __, __, r(32), __, r(32), r(32), r(32), r(32), __, r(32), __, __, __, r(32), r(32), __, __, __, r(32), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(32), r(32), __, __, __, __, __, r(32), __, r(32), r(32), __, __, __, __, __, __, r(32), __],
+    /*  979 */ [__, r(31), r(31), __, __, r(31), __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, __, r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(432), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), __],
+    /*  980 */ [__, r(32), r(32), __, __, __, __, r(32), r(32), r(32), r(32), __, r(32), __, __, __, r(32), r(32), __, __, r(32), r(32), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(32), r(32), __, __, __, __, __, r(32), __, r(32), r(32), __, __, __, __, __, __, r(32), __],
+    /*  981 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  982 */ [__, __, __, r(36), r(36), r(36), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1190), __, __, __, __, __, __, __, __, __, __, __],
+    /*  983 */ [__, __, __, r(35), r(35), r(35), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(35), __, __, __, __, __, __, __, __, __, __, __],
+    /*  984 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), r(48), __, r(48), __, __, r(48), r(48), __, __, __, r(48), __, __],
+    /*  985 */ [r(47), __, __, r(47), r(47), r(47), __, __, __, __, __, __, __, __, __, r(47), __, __, __, __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), __, r(47), r(47), r(47), __, __, __, r(47), __, __],
+    /*  986 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), __, r(48), __, __, r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /*  987 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  988 */ [__, r(15), r(15), __, __, __, __, r(15), r(15), r(15), r(15), __, r(15), __, __, __, r(15), r(15), __, __, __, r(15), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(15), __, __, __, __, __, r(15), __, r(15), __, __, __, __, __, __, __, r(15), r(15)],
+    /*  989 */ [r(48), r(48), r(48), __, __, __, __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), __, __, __, r(48), r(48), r(48)],
+    /*  990 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  991 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, r(72)],
+    /*  992 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), r(72), __, r(72), __, __, r(72), r(72), __, __, __, r(72), __, __],
+    /*  993 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  994 */ [s(283), __, r(20), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(1208), __, s(311), __, __, __, __, __, __],
+    /*  995 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /*  996 */ [__, s(433), s(436), r(2), r(2), r(2), __, s(485), s(487), s(488), s(502), __, s(550), __, __, __, s(555), r(2), __, __, __, s(603), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(634), __, s(638), __, __, __, __, __, __, __, s(19), __],
+    /*  997 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  998 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /*  999 */ [__, __, __, __, __, __, s(473), __, s(630), __, __, __, __, s(474), s(136), __, __, __, __, s(475), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(577), __, s(631), __, __, __, __, __, __, __, __, __, s(476), s(471), s(472), s(469), s(453), __],
+    /* 1000 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /* 1001 */ [r(64), __, __, r(64), r(64), r(64), __, __, __, __, __, __, __, __, __, r(64), __, __, __, __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(1290), __, s(1315), __, s(1209), __, r(64), s(1286), __, r(64), s(1281), r(64), __, __, __, s(1289), __, __],
+    /* 1002 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1194), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1003 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1195), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1004 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1193), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1005 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1196), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1006 */ [__, r(38), r(38), r(38), r(38), r(38), __, r(38), r(38), r(38), r(38), __, r(38), __, __, __, r(38), r(38), __, __, __, r(38), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(38), __, __, __, __, __, r(38), __, r(38), __, __, __, __, __, __, __, r(38), __],
+    /* 1007 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), __, __, __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(467), __],
+    /* 1008 */ [s(283), __, r(54), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(730), __, s(311), __, __, __, __, __, __],
+    /* 1009 */ [p(283), __, r(66), __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, __, __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /* 1010 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1011 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(922), __, s(941), __, __, __, __, __, __, __, __, __, __, __, __, s(920), __, __],
+    /* 1012 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1199), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1013 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1198), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1014 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, r(72), __, r(72), __, __, r(72), __, __, r(72), __, __, __, __, r(72), __, __],
+    /* 1015 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, r(87), __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /* 1016 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1017 */ [__, __, __, __, __, s(1200), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1018 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), r(48), r(48), __, r(48), r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /* 1019 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /* 1020 */ [s(283), __, __, r(54), r(54), r(54), __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(1211), __, s(311), __, __, __, __, __, __],
+    /* 1021 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1251), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1022 */ [p(283), r(66), r(66), __, __, __, __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, __, r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), __, __, p(311), __, __, __, __, r(66), r(66)],
+    /* 1023 */ [r(59), __, __, r(59), r(59), r(59), __, __, __, __, __, __, __, __, __, r(59), __, __, __, __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, __, __, __, __, __, __, r(59), __, __, r(59), __, r(59), __, __, __, __, __, __],
+    /* 1024 */ [r(60), __, __, r(60), r(60), r(60), __, __, __, __, __, __, __, __, __, r(60), __, __, __, __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, __, __, __, __, __, __, r(60), __, __, r(60), __, r(60), __, __, __, __, __, __],
+    /* 1025 */ [r(61), __, __, r(61), r(61), r(61), __, __, __, __, __, __, __, __, __, r(61), __, __, __, __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, __, __, __, __, __, __, r(61), __, __, r(61), __, r(61), __, __, __, __, __, __],
+    /* 1026 */ [r(57), __, __, r(57), r(57), r(57), __, __, __, __, __, __, __, __, __, r(57), __, __, __, __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, __, __, __, __, __, __, r(57), __, __, r(57), __, r(57), __, __, __, __, __, __],
+    /* 1027 */ [r(56), __, __, r(56), r(56), r(56), __, __, __, __, __, __, __, __, __, r(56), __, __, __, __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, __, __, __, __, __, __, r(56), __, __, r(56), __, r(56), __, __, __, __, __, __],
+    /* 1028 */ [r(58), __, __, r(58), r(58), r(58), __, __, __, __, __, __, __, __, __, r(58), __, __, __, __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, __, __, __, __, __, __, r(58), __, __, r(58), __, r(58), __, __, __, __, __, __],
+    /* 1029 */ [r(62), __, __, r(62), r(62), r(62), __, __, __, __, __, __, __, __, __, r(62), __, __, __, __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, __, __, __, __, __, __, r(62), __, __, r(62), __, r(62), __, __, __, __, __, __],
+    /* 1030 */ [__, r(29), r(29), r(29), r(29), r(29), __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, __, r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(1213), __, __, __, __, __, __, r(29), __],
+    /* 1031 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1203), __],
+    /* 1032 */ [r(49), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, __, r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /* 1033 */ [__, __, __, __, __, s(1210), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1034 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(900), __, s(936), __, __, __, __, __, __, __, __, __, __, __, __, s(886), __, __],
+    /* 1035 */ [s(283), __, __, __, __, r(54), __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(757), __, s(311), __, __, __, __, __, __],
+    /* 1036 */ [r(87), r(87), r(87), __, __, r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), r(87), __, r(87), __, __, __, __, r(87), __],
+    /* 1037 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), __, __, r(87), __, __, __, __, r(87), r(87)],
+    /* 1038 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, r(54), __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(758), __, s(311), __, __, __, __, __, __],
+    /* 1039 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, r(66), __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, r(66), __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /* 1040 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(903), __, s(933), __, __, __, __, __, __, __, __, __, __, __, __, s(899), __, __],
+    /* 1041 */ [r(78), r(78), r(78), __, __, r(78), __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), __, __, r(78), __, __, __, __, r(78), __],
+    /* 1042 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, r(48)],
+    /* 1043 */ [__, r(12), r(12), r(12), r(12), r(12), __, r(12), r(12), r(12), r(12), __, r(12), __, __, __, r(12), r(12), __, __, __, r(12), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(12), __, __, __, __, __, r(12), __, r(12), __, __, __, __, __, __, __, r(12), __],
+    /* 1044 */ [r(78), r(78), r(78), __, __, __, __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), __, __, r(78), __, __, __, __, r(78), __],
+    /* 1045 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /* 1046 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /* 1047 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1054), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1048 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /* 1049 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1050 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1051 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /* 1052 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, r(55)],
+    /* 1053 */ [__, r(23), r(23), r(23), r(23), r(23), __, r(23), r(23), r(23), r(23), __, r(23), __, __, __, r(23), r(23), __, __, __, r(23), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(23), __, __, __, __, __, r(23), __, r(23), __, __, __, __, __, __, __, r(23), __],
+    /* 1054 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /* 1055 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1242), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1056 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1057 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1235), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1058 */ [__, r(25), r(25), r(25), r(25), r(25), __, r(25), r(25), r(25), r(25), __, r(25), __, __, __, r(25), r(25), __, __, __, r(25), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(25), __, __, __, __, __, r(25), __, r(25), __, __, __, __, __, __, __, r(25), __],
+    /* 1059 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, s(1237), __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1060 */ [__, __, __, __, __, __, s(867), __, s(970), __, __, __, __, s(868), s(136), __, __, __, __, s(869), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(952), __, s(972), __, __, __, __, __, __, __, __, __, s(870), s(865), s(866), s(864), s(845), __],
+    /* 1061 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1238), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1062 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1248), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1063 */ [__, r(7), r(7), r(7), r(7), r(7), __, r(7), r(7), r(7), r(7), __, r(7), __, __, __, r(7), r(7), __, __, __, r(7), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(7), __, __, __, __, __, r(7), __, r(7), __, __, __, __, __, __, __, r(7), __],
+    /* 1064 */ [r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), __, __, __, r(47), r(47), __],
+    /* 1065 */ [r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(1305), __, s(1321), __, s(1241), __, r(64), s(1299), r(64), r(64), s(1298), r(64), __, __, __, s(1301), r(64), __],
+    /* 1066 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1222), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1067 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), __, r(72), __, __, r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1068 */ [__, __, __, __, __, __, s(1114), __, s(1182), __, __, __, __, s(1115), s(136), __, __, __, __, s(1116), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1172), __, s(1183), __, __, __, __, __, __, __, __, __, s(1118), s(1112), s(1113), s(1111), s(1064), __],
+    /* 1069 */ [r(63), __, __, r(63), r(63), r(63), __, __, __, __, __, __, __, __, __, r(63), __, __, __, __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, __, __, __, __, __, __, r(63), __, __, r(63), __, r(63), __, __, __, __, __, __],
+    /* 1070 */ [r(65), __, __, r(65), r(65), r(65), __, __, __, __, __, __, __, __, __, r(65), __, __, __, __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, __, __, __, __, __, __, r(65), __, __, r(65), __, r(65), __, __, __, __, __, __],
+    /* 1071 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1231), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1072 */ [s(283), r(54), r(54), __, __, r(54), __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, __, r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(853), __, s(311), __, __, __, __, r(54), __],
+    /* 1073 */ [__, __, __, __, __, __, s(1026), __, s(1157), __, __, __, __, s(1027), s(136), __, __, __, __, s(1028), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1121), __, s(1158), __, __, __, __, __, __, __, __, __, s(1029), s(1024), s(1025), s(1023), s(985), __],
+    /* 1074 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1075 */ [r(49), r(49), r(49), __, __, __, __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), __, r(49), r(49), __, __, __, r(49), r(49), r(49)],
+    /* 1076 */ [r(49), r(49), r(49), __, __, r(49), __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), __, __, __, r(49), r(49), __],
+    /* 1077 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1239), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1078 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1233), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1079 */ [p(283), __, __, __, __, r(66), __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, r(66), __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /* 1080 */ [r(72), r(72), r(72), __, __, __, __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), __, __, __, r(72), r(72), r(72)],
+    /* 1081 */ [p(283), r(66), r(66), __, __, r(66), __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, __, r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), r(66), __, p(311), __, __, __, __, r(66), __],
+    /* 1082 */ [p(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, __, __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /* 1083 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(895), __, s(930), __, __, __, __, __, __, __, __, __, __, __, __, s(893), __, __],
+    /* 1084 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(894), __, s(945), __, __, __, __, __, __, __, __, __, __, __, __, s(890), __, __],
+    /* 1085 */ [r(49), __, __, __, __, r(49), __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /* 1086 */ [s(283), r(54), r(54), __, __, __, __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, r(54), r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(810), __, s(311), __, __, __, __, r(54), __],
+    /* 1087 */ [r(70), r(70), r(70), __, __, __, __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), __, r(70), r(70), __, __, __, r(70), r(70), r(70)],
+    /* 1088 */ [__, __, __, __, __, r(18), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1089 */ [s(283), r(54), r(54), r(54), r(54), r(54), __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, __, r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(1232), __, s(311), __, __, __, __, r(54), __],
+    /* 1090 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1224), __],
+    /* 1091 */ [p(283), r(66), r(66), __, __, __, __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, r(66), r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), r(66), __, p(311), __, __, __, __, r(66), __],
+    /* 1092 */ [__, r(13), r(13), __, __, r(13), __, r(13), r(13), r(13), r(13), __, r(13), __, __, __, r(13), r(13), __, __, __, r(13), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(13), __, __, __, __, __, r(13), __, r(13), __, __, __, __, __, __, __, r(13), __],
+    /* 1093 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(855), __, s(921), __, __, __, __, __, __, __, __, __, __, __, __, s(854), __, __],
+    /* 1094 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1227), __],
+    /* 1095 */ [r(71), r(71), r(71), __, __, r(71), __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), r(71), __, __, __, r(71), r(71), __],
+    /* 1096 */ [r(73), r(73), r(73), __, __, __, __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), __, __, __, r(73), r(73), r(73)],
+    /* 1097 */ [r(77), r(77), r(77), __, __, r(77), __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), r(77), __, __, __, r(77), r(77), __],
+    /* 1098 */ [__, __, __, __, __, s(1228), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1099 */ [__, __, __, __, __, s(1229), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1100 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, __, r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /* 1101 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /* 1102 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, r(87), r(87), r(87)],
+    /* 1103 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), __, r(73), __, __, r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1104 */ [__, __, __, __, __, s(1246), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1105 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), r(87), __, r(87), __, __, __, __, r(87), __],
+    /* 1106 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1107 */ [r(67), r(67), r(67), __, __, r(67), __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), __, __, r(67), __, __, __, __, r(67), __],
+    /* 1108 */ [r(49), r(49), r(49), __, __, __, __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), __, __, __, r(49), r(49), __],
+    /* 1109 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1110 */ [r(70), r(70), r(70), __, __, __, __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), __, __, __, r(70), r(70), __],
+    /* 1111 */ [r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), r(59), __, r(59), __, __, __, __, r(59), __],
+    /* 1112 */ [r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), r(60), __, r(60), __, __, __, __, r(60), __],
+    /* 1113 */ [r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), r(61), __, r(61), __, __, __, __, r(61), __],
+    /* 1114 */ [r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), r(57), __, r(57), __, __, __, __, r(57), __],
+    /* 1115 */ [r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), r(56), __, r(56), __, __, __, __, r(56), __],
+    /* 1116 */ [r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), r(58), __, r(58), __, __, __, __, r(58), __],
+    /* 1117 */ [__, __, __, __, __, s(1279), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1118 */ [r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), r(62), __, r(62), __, __, __, __, r(62), __],
+    /* 1119 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1120 */ [r(70), __, __, __, __, r(70), __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /* 1121 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /* 1122 */ [__, __, __, __, __, __, s(877), __, s(969), __, __, __, __, s(878), s(136), __, __, __, __, s(879), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(953), __, s(971), __, __, __, __, __, __, __, __, __, s(880), s(875), s(876), s(872), s(837), __],
+    /* 1123 */ [r(87), __, __, __, __, r(87), __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, r(87), __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /* 1124 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), __, r(87), __, __, r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1125 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1280), __],
+    /* 1126 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1247), __],
+    /* 1127 */ [r(71), r(71), r(71), __, __, __, __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), r(71), __, __, __, r(71), r(71), __],
+    /* 1128 */ [r(77), r(77), r(77), __, __, __, __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), r(77), __, __, __, r(77), r(77), __],
+    /* 1129 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), r(87), __, r(87), __, __, r(87), r(87), __, __, __, r(87), __, __],
+    /* 1130 */ [__, r(55), r(55), __, __, __, __, r(55), r(55), r(55), r(55), __, r(55), __, __, __, r(55), r(55), __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, r(55), __, r(55), __, __, __, __, __, __, __, r(55), r(55)],
+    /* 1131 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, r(87)],
+    /* 1132 */ [r(49), __, r(49), __, __, __, __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, __, r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /* 1133 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(915), __, s(939), __, __, __, __, __, __, __, __, __, __, __, __, s(907), __, __],
+    /* 1134 */ [r(87), __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /* 1135 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /* 1136 */ [__, __, __, __, __, __, s(1306), __, s(1332), __, __, __, __, s(1307), s(136), __, __, __, __, s(1309), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1329), __, s(1333), __, __, __, __, __, __, __, __, __, s(1310), s(1303), s(1304), s(1302), s(1283), __],
+    /* 1137 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, r(73), __, r(73), __, __, r(73), __, __, r(73), __, __, __, __, r(73), __, __],
+    /* 1138 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, r(87), __, __, r(87), __, __, r(87), __, __, __, __, r(87), __, __],
+    /* 1139 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /* 1140 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), r(73), __, r(73), __, __, r(73), r(73), __, __, __, r(73), __, __],
+    /* 1141 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(918), __, s(943), __, __, __, __, __, __, __, __, __, __, __, __, s(912), __, __],
+    /* 1142 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(92), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1143 */ [r(76), r(76), r(76), __, __, r(76), __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), r(76), __, __, __, r(76), r(76), __],
+    /* 1144 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1145 */ [r(70), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /* 1146 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, r(73)],
+    /* 1147 */ [__, r(13), r(13), __, __, __, __, r(13), r(13), r(13), r(13), __, r(13), __, __, __, r(13), r(13), __, __, r(13), r(13), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(13), __, __, __, __, __, r(13), __, r(13), __, __, __, __, __, __, __, r(13), __],
+    /* 1148 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1295), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1149 */ [r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), r(63), __, r(63), __, __, __, __, r(63), __],
+    /* 1150 */ [r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), r(65), __, r(65), __, __, __, __, r(65), __],
+    /* 1151 */ [r(70), __, r(70), __, __, __, __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, __, r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /* 1152 */ [__, __, __, __, __, s(1243), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1153 */ [__, __, __, __, __, __, s(1114), __, s(1182), __, __, __, __, s(1115), s(136), __, __, __, __, s(1116), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1172), __, s(1183), __, __, __, __, __, __, __, __, __, s(1118), s(1112), s(1113), s(1111), s(1064), __],
+    /* 1154 */ [r(67), r(67), r(67), __, __, __, __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), __, __, r(67), __, __, __, __, r(67), __],
+    /* 1155 */ [r(68), __, __, r(68), r(68), r(68), __, __, __, __, __, __, __, __, __, r(68), __, __, __, __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), __, r(68), r(68), r(68), __, __, __, r(68), __, __],
+    /* 1156 */ [r(69), __, __, r(69), r(69), r(69), __, __, __, __, __, __, __, __, __, r(69), __, __, __, __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), __, r(69), r(69), r(69), __, __, __, r(69), __, __],
+    /* 1157 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1271), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1158 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1159 */ [r(76), r(76), r(76), __, __, __, __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), r(76), __, __, __, r(76), r(76), __],
+    /* 1160 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1161 */ [r(70), r(70), r(70), __, __, r(70), __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), __, __, __, r(70), r(70), __],
+    /* 1162 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1268), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1163 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1272), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1164 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1253), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1165 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1252), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1166 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1273), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1167 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1278), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1168 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1269), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1169 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1260), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1170 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1256), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1171 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1277), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1172 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /* 1173 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1266), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1174 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1261), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1175 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1262), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1176 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1258), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1177 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1259), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1178 */ [r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), __, __, __, r(68), r(68), __],
+    /* 1179 */ [r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), __, __, __, r(69), r(69), __],
+    /* 1180 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1265), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1181 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1264), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1182 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1257), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1183 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1184 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1263), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1185 */ [__, r(29), r(29), __, __, r(29), __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, __, r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(719), __, __, __, __, __, __, r(29), __],
+    /* 1186 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(84), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1187 */ [__, __, __, r(33), r(33), r(33), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1188 */ [__, r(30), r(30), __, __, __, __, r(30), r(30), r(30), r(30), __, r(30), __, __, __, r(30), r(30), __, __, __, r(30), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(30), r(30), __, __, __, __, __, r(30), __, r(30), __, __, __, __, __, __, __, r(30), r(30)],
+    /* 1189 */ [__, r(29), r(29), __, __, __, __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, r(29), r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(711), __, __, __, __, __, __, r(29), __],
+    /* 1190 */ [__, __, __, r(37), r(37), r(37), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1191 */ [__, __, s(1345), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1192 */ [__, __, s(1346), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1193 */ [r(48), __, __, __, __, r(48), __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /* 1194 */ [r(48), __, r(48), __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, __, r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /* 1195 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /* 1196 */ [r(48), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, __, r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /* 1197 */ [r(72), r(72), r(72), __, __, __, __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), __, __, __, r(72), r(72), __],
+    /* 1198 */ [r(48), r(48), r(48), __, __, __, __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), __, __, __, r(48), r(48), __],
+    /* 1199 */ [r(48), r(48), r(48), __, __, r(48), __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), __, __, __, r(48), r(48), __],
+    /* 1200 */ [__, r(22), r(22), __, __, __, __, r(22), r(22), r(22), r(22), __, r(22), __, __, __, r(22), r(22), __, __, __, r(22), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(22), __, __, __, __, __, r(22), __, r(22), __, __, __, __, __, __, __, r(22), r(22)],
+    /* 1201 */ [__, __, __, r(53), r(53), r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __],
+    /* 1202 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, __, r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1203 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1336), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1204 */ [s(283), __, r(20), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(1208), __, s(311), __, __, __, __, __, __],
+    /* 1205 */ [s(283), __, r(20), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(1208), __, s(311), __, __, __, __, __, __],
+    /* 1206 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(1348), __, s(311), __, __, __, __, __, __],
+    /* 1207 */ [__, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1208 */ [__, __, __, __, __, __, s(86), __, s(133), __, __, __, __, s(88), s(136), __, __, __, __, s(89), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(123), __, s(135), __, __, __, __, __, __, __, __, __, s(90), s(84), s(85), s(83), s(61), __],
+    /* 1209 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /* 1210 */ [__, r(15), r(15), __, __, __, __, r(15), r(15), r(15), r(15), __, r(15), __, __, __, r(15), r(15), __, __, r(15), r(15), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(15), __, __, __, __, __, r(15), __, r(15), __, __, __, __, __, __, __, r(15), __],
+    /* 1211 */ [__, __, __, __, __, __, s(1026), __, s(1157), __, __, __, __, s(1027), s(136), __, __, __, __, s(1028), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1121), __, s(1158), __, __, __, __, __, __, __, __, __, s(1029), s(1024), s(1025), s(1023), s(985), __],
+    /* 1212 */ [__, r(28), r(28), r(28), r(28), r(28), __, r(28), r(28), r(28), r(28), __, r(28), __, __, __, r(28), r(28), __, __, __, r(28), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(28), r(28), __, __, __, __, __, r(28), __, r(28), __, __, __, __, __, __, __, r(28), __],
+    /* 1213 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1342), __],
+    /* 1214 */ [__, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __],
+    /* 1215 */ [r(72), __, r(72), __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, __, r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1216 */ [__, __, __, __, __, s(1339), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1217 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, r(94), __, __, r(94), __, __, r(94), __, s(311), __, __, __, __, __, __],
+    /* 1218 */ [__, __, __, __, __, s(1340), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1219 */ [__, __, __, __, __, __, s(1026), __, s(1157), __, __, __, __, s(1027), s(136), __, __, __, __, s(1028), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1121), __, s(1158), __, __, __, __, __, __, __, __, __, s(1029), s(1024), s(1025), s(1023), s(985), __],
+    /* 1220 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __],
+    /* 1221 */ [__, r(24), r(24), r(24), r(24), r(24), __, r(24), r(24), r(24), r(24), __, r(24), __, __, __, r(24), r(24), __, __, __, r(24), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(24), __, __, __, __, __, r(24), __, r(24), __, __, __, __, __, __, __, r(24), __],
+    /* 1222 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1223 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1351), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1224 */ [r(49), r(49), r(49), __, __, r(49), __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), __, r(49), r(49), __, __, __, r(49), r(49), __],
+    /* 1225 */ [r(72), __, __, __, __, r(72), __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1226 */ [__, r(53), r(53), r(53), r(53), r(53), __, r(53), r(53), r(53), r(53), __, r(53), __, __, __, r(53), r(53), __, __, __, r(53), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(53), __, __, __, __, __, r(53), __, r(53), __, __, __, __, __, __, __, r(53), __],
+    /* 1227 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1101), __, s(1144), __, __, __, __, __, __, __, __, __, __, __, __, s(1097), __, __],
+    /* 1228 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, r(79), __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __],
+    /* 1229 */ [__, r(15), r(15), __, __, r(15), __, r(15), r(15), r(15), r(15), __, r(15), __, __, __, r(15), r(15), __, __, __, r(15), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(15), __, __, __, __, __, r(15), __, r(15), __, __, __, __, __, __, __, r(15), __],
+    /* 1230 */ [r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1231 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1232 */ [__, __, __, __, __, __, s(1114), __, s(1182), __, __, __, __, s(1115), s(136), __, __, __, __, s(1116), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1172), __, s(1183), __, __, __, __, __, __, __, __, __, s(1118), s(1112), s(1113), s(1111), s(1064), __],
+    /* 1233 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1234 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1344), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1235 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1236 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1383), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1237 */ [__, s(433), s(436), r(2), r(2), r(2), __, s(485), s(487), s(488), s(502), __, s(550), __, __, __, s(555), r(2), __, __, __, s(603), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(634), __, s(638), __, __, __, __, __, __, __, s(19), __],
+    /* 1238 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1239 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1240 */ [r(72), r(72), r(72), __, __, r(72), __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), __, __, __, r(72), r(72), __],
+    /* 1241 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /* 1242 */ [r(48), r(48), r(48), __, __, __, __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), __, r(48), r(48), __, __, __, r(48), r(48), r(48)],
+    /* 1243 */ [r(79), __, r(79), __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __],
+    /* 1244 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1347), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1245 */ [s(283), r(14), r(14), r(14), r(14), r(14), __, r(14), r(14), r(14), r(14), __, r(14), __, __, s(284), r(14), r(14), __, __, __, r(14), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(14), __, __, __, __, __, r(14), __, r(14), __, __, s(311), __, __, __, __, r(14), __],
+    /* 1246 */ [__, r(79), r(79), __, __, __, __, r(79), r(79), r(79), r(79), __, r(79), __, __, __, r(79), r(79), __, __, __, r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __, __, r(79), r(79)],
+    /* 1247 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1135), __, s(1160), __, __, __, __, __, __, __, __, __, __, __, __, s(1128), __, __],
+    /* 1248 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1249 */ [__, r(27), r(27), r(27), r(27), r(27), __, r(27), r(27), r(27), r(27), __, r(27), __, __, __, r(27), r(27), __, __, __, r(27), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(27), __, __, __, __, __, r(27), __, r(27), __, __, __, __, __, __, __, r(27), __],
+    /* 1250 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1353), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1251 */ [r(70), r(70), r(70), __, __, r(70), __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), __, r(70), r(70), __, __, __, r(70), r(70), __],
+    /* 1252 */ [r(73), __, r(73), __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, __, r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1253 */ [r(73), __, __, __, __, r(73), __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1254 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1352), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1255 */ [r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), r(78), __, r(78), __, __, __, __, r(78), __],
+    /* 1256 */ [r(87), r(87), r(87), __, __, r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), __, __, r(87), __, __, __, __, r(87), __],
+    /* 1257 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /* 1258 */ [r(87), __, __, __, __, r(87), __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1259 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), r(87), __, __, __, r(87), r(87), r(87)],
+    /* 1260 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, r(87), r(87), __],
+    /* 1261 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1262 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), __, __, r(87), __, __, __, __, r(87), __],
+    /* 1263 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, __, r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1264 */ [__, r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, __, r(87), r(87), __, __, __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), __, __, __, __, r(87), r(87), __],
+    /* 1265 */ [r(87), r(87), r(87), __, __, r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, r(87), r(87), __],
+    /* 1266 */ [r(87), __, r(87), __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, __, r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1267 */ [r(72), r(72), r(72), __, __, __, __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), r(72), __, __, __, r(72), r(72), r(72)],
+    /* 1268 */ [__, r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), __, r(73), __, __, __, r(73), r(73), __, __, __, r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), __, __, __, __, r(73), r(73), __],
+    /* 1269 */ [r(73), r(73), r(73), __, __, r(73), __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), __, __, __, r(73), r(73), __],
+    /* 1270 */ [r(78), __, __, r(78), r(78), r(78), __, __, __, __, __, __, __, __, __, r(78), __, __, __, __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, __, __, __, __, __, __, r(78), __, __, r(78), __, r(78), __, __, __, __, __, __],
+    /* 1271 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /* 1272 */ [r(87), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1273 */ [r(73), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, __, r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1274 */ [__, r(55), r(55), __, __, r(55), __, r(55), r(55), r(55), r(55), __, r(55), __, __, __, r(55), r(55), __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, r(55), __, r(55), __, __, __, __, __, __, __, r(55), __],
+    /* 1275 */ [p(283), r(66), r(66), __, __, __, __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, r(66), r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), __, __, p(311), __, __, __, __, r(66), __],
+    /* 1276 */ [r(67), __, __, r(67), r(67), r(67), __, __, __, __, __, __, __, __, __, r(67), __, __, __, __, __, __, r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, __, __, __, __, __, __, r(67), __, __, r(67), __, r(67), __, __, __, __, __, __],
+    /* 1277 */ [r(73), r(73), r(73), __, __, __, __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), __, __, __, r(73), r(73), __],
+    /* 1278 */ [r(73), r(73), r(73), __, __, __, __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), r(73), __, __, __, r(73), r(73), r(73)],
+    /* 1279 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __],
+    /* 1280 */ [r(49), r(49), r(49), __, __, __, __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), __, r(49), r(49), __, __, __, r(49), r(49), __],
+    /* 1281 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1362), __],
+    /* 1282 */ [__, __, __, s(740), s(798), r(16), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1283 */ [r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), __, r(47), __, __, r(47), r(47), r(47), __, __, __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), r(47), __, r(47), __, r(47), __, r(47), __, r(47), r(47), r(47), __, r(47), r(47), __, __, __, r(47), r(47), __],
+    /* 1284 */ [r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), __, r(64), __, __, r(64), r(64), r(64), __, __, __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), r(64), __, s(1396), __, s(1404), __, s(1376), __, r(64), s(1393), r(64), __, s(1391), r(64), __, __, __, s(1395), r(64), __],
+    /* 1285 */ [r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), r(67), __, r(67), __, __, __, __, r(67), __],
+    /* 1286 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1367), __],
+    /* 1287 */ [r(71), __, __, r(71), r(71), r(71), __, __, __, __, __, __, __, __, __, r(71), __, __, __, __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), __, r(71), r(71), r(71), __, __, __, r(71), __, __],
+    /* 1288 */ [__, r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), __, r(72), __, __, __, r(72), r(72), __, __, __, r(72), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), __, __, __, __, r(72), r(72), __],
+    /* 1289 */ [r(77), __, __, r(77), r(77), r(77), __, __, __, __, __, __, __, __, __, r(77), __, __, __, __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), __, r(77), r(77), r(77), __, __, __, r(77), __, __],
+    /* 1290 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /* 1291 */ [__, r(55), r(55), __, __, __, __, r(55), r(55), r(55), r(55), __, r(55), __, __, __, r(55), r(55), __, __, r(55), r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, r(55), __, r(55), __, __, __, __, __, __, __, r(55), __],
+    /* 1292 */ [p(283), r(66), r(66), __, __, r(66), __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, __, r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), __, __, p(311), __, __, __, __, r(66), __],
+    /* 1293 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1387), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1294 */ [__, __, __, __, __, __, s(1114), __, s(1182), __, __, __, __, s(1115), s(136), __, __, __, __, s(1116), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1172), __, s(1183), __, __, __, __, __, __, __, __, __, s(1118), s(1112), s(1113), s(1111), s(1064), __],
+    /* 1295 */ [r(70), r(70), r(70), __, __, __, __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), __, r(70), r(70), __, __, __, r(70), r(70), __],
+    /* 1296 */ [__, __, __, __, __, s(1377), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1297 */ [__, __, __, __, __, s(1366), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1298 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1375), __],
+    /* 1299 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1358), __],
+    /* 1300 */ [r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), __, __, __, r(71), r(71), __],
+    /* 1301 */ [r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), __, __, __, r(77), r(77), __],
+    /* 1302 */ [r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), __, r(59), __, __, r(59), r(59), r(59), __, __, __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), r(59), __, r(59), __, __, __, __, __, r(59), __, r(59), __, __, r(59), __, __, __, __, r(59), __],
+    /* 1303 */ [r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), __, r(60), __, __, r(60), r(60), r(60), __, __, __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), r(60), __, r(60), __, __, __, __, __, r(60), __, r(60), __, __, r(60), __, __, __, __, r(60), __],
+    /* 1304 */ [r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), __, r(61), __, __, r(61), r(61), r(61), __, __, __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), r(61), __, r(61), __, __, __, __, __, r(61), __, r(61), __, __, r(61), __, __, __, __, r(61), __],
+    /* 1305 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /* 1306 */ [r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), __, r(57), __, __, r(57), r(57), r(57), __, __, __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), r(57), __, r(57), __, __, __, __, __, r(57), __, r(57), __, __, r(57), __, __, __, __, r(57), __],
+    /* 1307 */ [r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), __, r(56), __, __, r(56), r(56), r(56), __, __, __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), r(56), __, r(56), __, __, __, __, __, r(56), __, r(56), __, __, r(56), __, __, __, __, r(56), __],
+    /* 1308 */ [__, __, __, __, __, s(1360), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1309 */ [r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), __, r(58), __, __, r(58), r(58), r(58), __, __, __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), r(58), __, r(58), __, __, __, __, __, r(58), __, r(58), __, __, r(58), __, __, __, __, r(58), __],
+    /* 1310 */ [r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), __, r(62), __, __, r(62), r(62), r(62), __, __, __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), r(62), __, r(62), __, __, __, __, __, r(62), __, r(62), __, __, r(62), __, __, __, __, r(62), __],
+    /* 1311 */ [__, __, __, __, __, s(1361), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1312 */ [__, __, __, __, __, s(1363), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1313 */ [__, __, __, __, __, s(1372), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1314 */ [r(76), __, __, r(76), r(76), r(76), __, __, __, __, __, __, __, __, __, r(76), __, __, __, __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), __, r(76), r(76), r(76), __, __, __, r(76), __, __],
+    /* 1315 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1316 */ [__, __, __, __, __, s(1378), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1317 */ [r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), __, r(63), __, __, r(63), r(63), r(63), __, __, __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), r(63), __, r(63), __, __, __, __, __, r(63), __, r(63), __, __, r(63), __, __, __, __, r(63), __],
+    /* 1318 */ [r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), __, r(65), __, __, r(65), r(65), r(65), __, __, __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), r(65), __, r(65), __, __, __, __, __, r(65), __, r(65), __, __, r(65), __, __, __, __, r(65), __],
+    /* 1319 */ [__, __, __, __, __, __, s(1306), __, s(1332), __, __, __, __, s(1307), s(136), __, __, __, __, s(1309), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1329), __, s(1333), __, __, __, __, __, __, __, __, __, s(1310), s(1303), s(1304), s(1302), s(1283), __],
+    /* 1320 */ [r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), __, __, __, r(76), r(76), __],
+    /* 1321 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1322 */ [__, __, __, __, __, s(1379), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1323 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1374), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1324 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1370), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1325 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1365), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1326 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1373), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1327 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1369), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1328 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1371), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1329 */ [__, __, __, __, __, __, s(54), __, s(130), __, __, __, __, s(55), s(136), __, __, __, __, s(56), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(121), __, s(131), __, __, __, __, __, __, __, __, __, s(57), s(52), s(53), s(51), s(37), __],
+    /* 1330 */ [r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), __, r(68), __, __, r(68), r(68), r(68), __, __, __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), r(68), __, r(68), __, r(68), __, r(68), __, r(68), r(68), r(68), __, r(68), r(68), __, __, __, r(68), r(68), __],
+    /* 1331 */ [r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), __, r(69), __, __, r(69), r(69), r(69), __, __, __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), r(69), __, r(69), __, r(69), __, r(69), __, r(69), r(69), r(69), __, r(69), r(69), __, __, __, r(69), r(69), __],
+    /* 1332 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1355), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1333 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1334 */ [__, r(30), r(30), __, __, r(30), __, r(30), r(30), r(30), r(30), __, r(30), __, __, __, r(30), r(30), __, __, __, r(30), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(30), r(30), __, __, __, __, __, r(30), __, r(30), __, __, __, __, __, __, __, r(30), __],
+    /* 1335 */ [__, r(30), r(30), __, __, __, __, r(30), r(30), r(30), r(30), __, r(30), __, __, __, r(30), r(30), __, __, r(30), r(30), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(30), r(30), __, __, __, __, __, r(30), __, r(30), __, __, __, __, __, __, __, r(30), __],
+    /* 1336 */ [__, r(32), r(32), r(32), r(32), r(32), __, r(32), r(32), r(32), r(32), __, r(32), __, __, __, r(32), r(32), __, __, __, r(32), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(32), r(32), __, __, __, __, __, r(32), __, r(32), r(32), __, __, __, __, __, __, r(32), __],
+    /* 1337 */ [__, __, s(1419), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1338 */ [__, __, s(1417), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1339 */ [__, r(22), r(22), __, __, r(22), __, r(22), r(22), r(22), r(22), __, r(22), __, __, __, r(22), r(22), __, __, __, r(22), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(22), __, __, __, __, __, r(22), __, r(22), __, __, __, __, __, __, __, r(22), __],
+    /* 1340 */ [__, r(22), r(22), __, __, __, __, r(22), r(22), r(22), r(22), __, r(22), __, __, __, r(22), r(22), __, __, r(22), r(22), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(22), __, __, __, __, __, r(22), __, r(22), __, __, __, __, __, __, __, r(22), __],
+    /* 1341 */ [s(283), __, r(21), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1342 */ [__, r(31), r(31), r(31), r(31), r(31), __, r(31), r(31), r(31), r(31), __, r(31), __, __, __, r(31), r(31), __, __, __, r(31), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1031), __, r(31), r(31), __, __, __, __, __, r(31), __, r(31), r(31), __, __, __, __, __, __, r(31), __],
+    /* 1343 */ [r(72), r(72), r(72), __, __, r(72), __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), r(72), __, __, __, r(72), r(72), __],
+    /* 1344 */ [r(48), r(48), r(48), __, __, __, __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), __, r(48), r(48), __, __, __, r(48), r(48), __],
+    /* 1345 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1346 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1347 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1348 */ [__, __, __, __, __, __, s(450), __, s(649), __, __, __, __, s(451), s(136), __, __, __, __, s(452), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(609), __, s(650), __, __, __, __, __, __, __, __, __, s(455), s(448), s(449), s(447), s(396), __],
+    /* 1349 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1409), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1350 */ [r(72), r(72), r(72), __, __, __, __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), r(72), __, __, __, r(72), r(72), __],
+    /* 1351 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1352 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1353 */ [r(48), r(48), r(48), __, __, r(48), __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), __, r(48), r(48), __, __, __, r(48), r(48), __],
+    /* 1354 */ [r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), __, r(78), __, __, r(78), r(78), r(78), __, __, __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), r(78), __, r(78), __, __, __, __, __, r(78), __, r(78), __, __, r(78), __, __, __, __, r(78), __],
+    /* 1355 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(80), __, __, __, __, __, __, __, __, __, __, s(378), __, __, __, s(377), __],
+    /* 1356 */ [__, __, __, __, __, __, s(1306), __, s(1332), __, __, __, __, s(1307), s(136), __, __, __, __, s(1309), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(1329), __, s(1333), __, __, __, __, __, __, __, __, __, s(1310), s(1303), s(1304), s(1302), s(1283), __],
+    /* 1357 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1412), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1358 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1305), __, s(1321), __, __, __, __, __, __, __, __, __, __, __, __, s(1301), __, __],
+    /* 1359 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1422), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1360 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, r(79), __, __, r(79), __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1361 */ [__, r(79), r(79), __, __, r(79), __, r(79), r(79), r(79), r(79), __, r(79), __, __, __, r(79), r(79), __, __, __, r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __, __, r(79), __],
+    /* 1362 */ [r(49), __, __, r(49), r(49), r(49), __, __, __, __, __, __, __, __, __, r(49), __, __, __, __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), __, r(49), r(49), r(49), __, __, __, r(49), __, __],
+    /* 1363 */ [__, r(13), r(13), r(13), r(13), r(13), __, r(13), r(13), r(13), r(13), __, r(13), __, __, __, r(13), r(13), __, __, __, r(13), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(13), __, __, __, __, __, r(13), __, r(13), __, __, __, __, __, __, __, r(13), __],
+    /* 1364 */ [__, __, __, __, __, s(1413), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1365 */ [r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), r(87), __, r(87), __, __, __, __, r(87), __],
+    /* 1366 */ [r(79), r(79), r(79), __, __, __, __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), r(79), __, r(79), __, __, __, __, r(79), r(79)],
+    /* 1367 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1290), __, s(1315), __, __, __, __, __, __, __, __, __, __, __, __, s(1289), __, __],
+    /* 1368 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1425), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1369 */ [r(87), r(87), r(87), __, __, __, __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), r(87), __, __, __, r(87), r(87), __],
+    /* 1370 */ [r(73), r(73), r(73), __, __, __, __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), r(73), __, __, __, r(73), r(73), __],
+    /* 1371 */ [r(87), __, __, r(87), r(87), r(87), __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, __, __, __, __, r(87), __, __, r(87), __, r(87), __, __, __, __, __, __],
+    /* 1372 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, r(79), __, __, __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1373 */ [r(87), r(87), r(87), __, __, r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), r(87), __, __, __, r(87), r(87), __],
+    /* 1374 */ [r(73), r(73), r(73), __, __, r(73), __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), r(73), __, __, __, r(73), r(73), __],
+    /* 1375 */ [r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), __, __, __, r(49), r(49), __],
+    /* 1376 */ [__, __, __, __, __, __, s(227), __, s(354), __, __, __, __, s(228), s(136), __, __, __, __, s(229), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(327), __, s(357), __, __, __, __, __, __, __, __, __, s(230), s(224), s(225), s(223), s(155), __],
+    /* 1377 */ [__, r(79), r(79), __, __, __, __, r(79), r(79), r(79), r(79), __, r(79), __, __, __, r(79), r(79), __, __, r(79), r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __, __, r(79), __],
+    /* 1378 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, r(79), __, __, r(79), __, r(79), __, __, __, __, __, r(79)],
+    /* 1379 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, r(79), __, __, __, __, __, r(79), __, __, __, __, __, __],
+    /* 1380 */ [p(283), __, __, r(66), r(66), r(66), __, __, __, __, __, __, __, __, __, p(284), __, __, __, __, __, __, p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, __, __, __, __, __, __, r(66), __, __, r(66), __, p(311), __, __, __, __, __, __],
+    /* 1381 */ [s(283), __, __, r(54), r(54), r(54), __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, r(54), __, __, s(1211), __, s(311), __, __, __, __, __, __],
+    /* 1382 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, s(1440), __, __, __, __, __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1383 */ [r(70), __, __, r(70), r(70), r(70), __, __, __, __, __, __, __, __, __, r(70), __, __, __, __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), __, r(70), r(70), r(70), __, __, __, r(70), __, __],
+    /* 1384 */ [s(283), r(54), r(54), r(54), r(54), r(54), __, r(54), r(54), r(54), r(54), __, r(54), __, __, s(284), r(54), r(54), __, __, __, r(54), s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, r(54), __, __, __, __, __, r(54), __, r(54), s(1232), __, s(311), __, __, __, __, r(54), __],
+    /* 1385 */ [r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), r(67), r(67), r(67), __, r(67), __, __, r(67), r(67), r(67), __, __, __, r(67), r(67), r(67), r(67), r(67), r(67), s(294), __, r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), r(67), __, r(67), __, __, __, __, __, r(67), __, r(67), __, __, r(67), __, __, __, __, r(67), __],
+    /* 1386 */ [p(283), r(66), r(66), r(66), r(66), r(66), __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, __, r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), r(66), __, p(311), __, __, __, __, r(66), __],
+    /* 1387 */ [r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), __, __, __, r(70), r(70), __],
+    /* 1388 */ [__, __, __, __, __, s(1415), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1389 */ [__, __, __, __, __, s(1416), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1390 */ [__, __, __, __, __, s(1431), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1391 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1427), __],
+    /* 1392 */ [__, __, __, __, __, s(1426), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1393 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1433), __],
+    /* 1394 */ [r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), __, r(71), __, __, r(71), r(71), r(71), __, __, __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), r(71), __, r(71), __, r(71), __, r(71), __, r(71), r(71), r(71), __, r(71), r(71), __, __, __, r(71), r(71), __],
+    /* 1395 */ [r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), __, r(77), __, __, r(77), r(77), r(77), __, __, __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), r(77), __, r(77), __, r(77), __, r(77), __, r(77), r(77), r(77), __, r(77), r(77), __, __, __, r(77), r(77), __],
+    /* 1396 */ [__, __, __, __, __, __, s(212), __, s(346), __, __, __, __, s(213), s(136), __, __, __, __, s(214), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(328), r(74), s(350), __, __, __, __, __, __, __, __, __, s(215), s(209), s(210), s(208), s(187), __],
+    /* 1397 */ [__, __, __, __, __, s(1420), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1398 */ [__, __, __, __, __, s(1432), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1399 */ [__, __, __, s(740), s(798), r(16), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1400 */ [__, __, __, __, __, s(1421), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1401 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1424), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1402 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1423), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1403 */ [r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), __, r(76), __, __, r(76), r(76), r(76), __, __, __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), r(76), __, r(76), __, r(76), __, r(76), __, r(76), r(76), r(76), __, r(76), r(76), __, __, __, r(76), r(76), __],
+    /* 1404 */ [__, __, __, __, __, __, s(243), __, s(358), __, __, __, __, s(244), s(136), __, __, __, __, s(245), __, __, __, s(137), __, __, __, __, s(138), __, __, s(139), __, __, __, __, __, __, __, __, __, __, s(332), __, s(359), r(88), s(363), __, __, __, __, __, __, __, s(246), s(241), s(242), s(240), s(216), __],
+    /* 1405 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1430), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1406 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1428), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1407 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1429), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1408 */ [__, r(29), r(29), r(29), r(29), r(29), __, r(29), r(29), r(29), r(29), __, r(29), __, __, __, r(29), r(29), __, __, __, r(29), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(29), r(29), __, __, __, __, __, r(29), __, r(29), s(1213), __, __, __, __, __, __, r(29), __],
+    /* 1409 */ [r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), __, __, __, r(48), r(48), __],
+    /* 1410 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1458), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1411 */ [r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), __, __, __, r(72), r(72), __],
+    /* 1412 */ [r(48), __, __, r(48), r(48), r(48), __, __, __, __, __, __, __, __, __, r(48), __, __, __, __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), __, r(48), r(48), r(48), __, __, __, r(48), __, __],
+    /* 1413 */ [__, r(15), r(15), r(15), r(15), r(15), __, r(15), r(15), r(15), r(15), __, r(15), __, __, __, r(15), r(15), __, __, __, r(15), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(15), __, __, __, __, __, r(15), __, r(15), __, __, __, __, __, __, __, r(15), __],
+    /* 1414 */ [r(72), __, __, r(72), r(72), r(72), __, __, __, __, __, __, __, __, __, r(72), __, __, __, __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), __, r(72), r(72), r(72), __, __, __, r(72), __, __],
+    /* 1415 */ [r(79), r(79), r(79), __, __, __, __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, r(79), __, __, __, __, r(79), r(79)],
+    /* 1416 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, r(79), __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1417 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1418 */ [s(283), __, r(20), __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, __, __, __, __, s(1208), __, s(311), __, __, __, __, __, __],
+    /* 1419 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1420 */ [r(79), r(79), r(79), __, __, __, __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), r(79), __, r(79), __, __, __, __, r(79), __],
+    /* 1421 */ [r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1422 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1423 */ [r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), __, __, __, r(73), r(73), __],
+    /* 1424 */ [r(73), __, __, r(73), r(73), r(73), __, __, __, __, __, __, __, __, __, r(73), __, __, __, __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), __, r(73), r(73), r(73), __, __, __, r(73), __, __],
+    /* 1425 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1426 */ [r(79), __, r(79), __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1427 */ [r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), __, r(49), __, __, r(49), r(49), r(49), __, __, __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), r(49), __, r(49), __, r(49), __, r(49), __, r(49), r(49), r(49), __, r(49), r(49), __, __, __, r(49), r(49), __],
+    /* 1428 */ [r(87), __, __, r(87), r(87), r(87), __, __, __, __, __, __, __, __, __, r(87), __, __, __, __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), __, r(87), r(87), r(87), __, __, __, r(87), __, __],
+    /* 1429 */ [r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, __, __, __, __, r(87), __, r(87), __, __, r(87), __, __, __, __, r(87), __],
+    /* 1430 */ [r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), __, __, __, r(87), r(87), __],
+    /* 1431 */ [r(79), __, __, __, __, r(79), __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, r(79), __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1432 */ [r(79), r(79), r(79), __, __, r(79), __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), r(79), __, r(79), __, __, __, __, r(79), __],
+    /* 1433 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1396), __, s(1404), __, __, __, __, __, __, __, __, __, __, __, __, s(1395), __, __],
+    /* 1434 */ [__, __, __, __, __, r(17), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1435 */ [s(283), __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(284), __, __, __, __, __, __, s(285), s(286), s(287), s(288), s(290), s(294), __, s(295), s(298), s(297), s(299), s(300), s(289), s(301), s(302), s(303), s(308), s(309), s(310), __, __, __, __, __, __, s(1449), __, __, __, __, __, s(311), __, __, __, __, __, __],
+    /* 1436 */ [__, __, __, __, __, s(1452), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1437 */ [__, __, __, r(55), r(55), r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __],
+    /* 1438 */ [__, __, __, __, __, s(1451), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1439 */ [p(283), r(66), r(66), r(66), r(66), r(66), __, r(66), r(66), r(66), r(66), __, r(66), __, __, p(284), r(66), r(66), __, __, __, r(66), p(285), p(286), p(287), p(288), p(290), p(294), __, p(295), p(298), p(297), p(299), p(300), p(289), p(301), p(302), p(303), p(308), p(309), p(310), __, r(66), __, __, __, __, __, r(66), __, r(66), __, __, p(311), __, __, __, __, r(66), __],
+    /* 1440 */ [r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), __, r(70), __, __, r(70), r(70), r(70), __, __, __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), r(70), __, r(70), __, r(70), __, r(70), __, r(70), r(70), r(70), __, r(70), r(70), __, __, __, r(70), r(70), __],
+    /* 1441 */ [__, __, __, __, __, s(1453), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1442 */ [__, r(55), r(55), r(55), r(55), r(55), __, r(55), r(55), r(55), r(55), __, r(55), __, __, __, r(55), r(55), __, __, __, r(55), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(55), __, __, __, __, __, r(55), __, r(55), __, __, __, __, __, __, __, r(55), __],
+    /* 1443 */ [__, __, __, __, __, s(1455), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1444 */ [__, __, __, __, __, s(1454), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1445 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1457), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1446 */ [__, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(1456), __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1447 */ [__, __, s(1467), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1448 */ [__, r(30), r(30), r(30), r(30), r(30), __, r(30), r(30), r(30), r(30), __, r(30), __, __, __, r(30), r(30), __, __, __, r(30), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(30), r(30), __, __, __, __, __, r(30), __, r(30), __, __, __, __, __, __, __, r(30), __],
+    /* 1449 */ [r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), __, r(48), __, __, r(48), r(48), r(48), __, __, __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), r(48), __, r(48), __, r(48), __, r(48), __, r(48), r(48), r(48), __, r(48), r(48), __, __, __, r(48), r(48), __],
+    /* 1450 */ [r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), __, r(72), __, __, r(72), r(72), r(72), __, __, __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), r(72), __, r(72), __, r(72), __, r(72), __, r(72), r(72), r(72), __, r(72), r(72), __, __, __, r(72), r(72), __],
+    /* 1451 */ [__, r(22), r(22), r(22), r(22), r(22), __, r(22), r(22), r(22), r(22), __, r(22), __, __, __, r(22), r(22), __, __, __, r(22), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(22), __, __, __, __, __, r(22), __, r(22), __, __, __, __, __, __, __, r(22), __],
+    /* 1452 */ [__, r(19), r(19), __, __, __, __, r(19), r(19), r(19), r(19), __, r(19), __, __, __, r(19), r(19), __, __, __, r(19), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(19), __, __, __, __, __, r(19), __, r(19), __, __, __, __, __, __, __, r(19), r(19)],
+    /* 1453 */ [r(79), r(79), r(79), __, __, r(79), __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, r(79), __, __, __, __, r(79), __],
+    /* 1454 */ [r(79), r(79), r(79), __, __, __, __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, r(79), __, __, __, __, r(79), __],
+    /* 1455 */ [__, r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), __, r(79), __, __, __, r(79), r(79), __, __, __, r(79), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, __, __, __, __, __, r(79), __],
+    /* 1456 */ [r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), __, r(87), __, __, r(87), r(87), r(87), __, __, __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), r(87), __, r(87), __, r(87), __, r(87), __, r(87), r(87), r(87), __, r(87), r(87), __, __, __, r(87), r(87), __],
+    /* 1457 */ [r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), __, r(73), __, __, r(73), r(73), r(73), __, __, __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), r(73), __, r(73), __, r(73), __, r(73), __, r(73), r(73), r(73), __, r(73), r(73), __, __, __, r(73), r(73), __],
+    /* 1458 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1459 */ [__, __, __, __, __, s(1463), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1460 */ [__, __, __, __, __, s(1466), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1461 */ [__, __, __, __, __, s(1465), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1462 */ [__, __, __, __, __, s(1464), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1463 */ [__, r(19), r(19), __, __, r(19), __, r(19), r(19), r(19), r(19), __, r(19), __, __, __, r(19), r(19), __, __, __, r(19), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(19), __, __, __, __, __, r(19), __, r(19), __, __, __, __, __, __, __, r(19), __],
+    /* 1464 */ [r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), r(79), __, r(79), __, __, __, __, r(79), __],
+    /* 1465 */ [r(79), __, __, r(79), r(79), r(79), __, __, __, __, __, __, __, __, __, r(79), __, __, __, __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, __, __, __, __, __, __, r(79), __, __, r(79), __, r(79), __, __, __, __, __, __],
+    /* 1466 */ [__, r(19), r(19), __, __, __, __, r(19), r(19), r(19), r(19), __, r(19), __, __, __, r(19), r(19), __, __, r(19), r(19), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(19), __, __, __, __, __, r(19), __, r(19), __, __, __, __, __, __, __, r(19), __],
+    /* 1467 */ [__, s(43), s(45), __, __, r(2), __, s(64), s(65), s(67), s(70), __, s(100), __, __, __, s(102), r(2), __, __, __, s(105), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, s(21), __, __, __, __, __, s(109), __, s(113), __, __, __, __, __, __, __, s(19), __],
+    /* 1468 */ [__, __, __, __, __, s(1469), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1469 */ [r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), __, r(79), __, __, r(79), r(79), r(79), __, __, __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), r(79), __, r(79), __, __, __, __, __, r(79), __, r(79), __, __, r(79), __, __, __, __, r(79), __],
+    /* 1470 */ [__, __, __, __, __, s(1471), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __],
+    /* 1471 */ [__, r(19), r(19), r(19), r(19), r(19), __, r(19), r(19), r(19), r(19), __, r(19), __, __, __, r(19), r(19), __, __, __, r(19), __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, r(19), __, __, __, __, __, r(19), __, r(19), __, __, __, __, __, __, __, r(19), __],
+];
+
+/// Columns: Chunk, Block, BlockStat, BlockRetstat, Stat, StatIf, StatForexp, StatAttexplist, Attnamelist, AttnamelistCont, Attrib, Retstat, RetstatExplist, RetstatEnd, Label, Funcname, FuncnameCont, FuncnameEnd, Varlist, VarlistCont, Var, Namelist, NamelistCont, Explist, ExplistCont, Exp, Prefixexp, Functioncall, Args, ArgsExplist, Functiondef, Funcbody, FuncbodyParlist, Parlist, ParlistCont, Tableconstructor, TableconstructorFieldlist, Fieldlist, FieldlistCont, Field, Fieldsep, Binop, Unop
+#[rustfmt::skip]
+static GOTO: [[u16; NON_TERMINALS]; STATES] = [
+    /*    0 */ [0, 1, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 15, 0, 18, 0, 0, 0, 0, 0, 20, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    1 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    2 */ [0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    3 */ [0, 0, 44, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 15, 0, 18, 0, 0, 0, 0, 0, 20, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    4 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    5 */ [0, 63, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    6 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    7 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    8 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*    9 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 39, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*   10 */ [0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   11 */ [0, 104, 26, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 117, 0, 18, 0, 0, 0, 0, 0, 120, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   12 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 41, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /*   13 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   14 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   15 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   16 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   17 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   18 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   19 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   20 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0],
+    /*   21 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 58, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*   22 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   23 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   24 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   25 */ [0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   26 */ [0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   27 */ [0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   28 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   29 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   30 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   31 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   32 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   33 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   34 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   35 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 154, 221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   36 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   37 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   38 */ [0, 0, 183, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   39 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /*   40 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 316, 0, 0, 0, 0, 0, 0, 352, 0, 0, 0, 0, 0, 0, 0],
+    /*   41 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /*   42 */ [0, 0, 181, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 117, 0, 18, 0, 0, 0, 0, 0, 120, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   43 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   44 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   45 */ [0, 222, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   46 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   47 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 192, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*   48 */ [0, 211, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   49 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 339, 0, 0, 157, 0, 197, 169, 341, 0, 0, 256, 0, 0, 0, 0, 257, 0, 0, 0, 0, 0, 0, 260],
+    /*   50 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 368, 0, 0, 159, 0, 248, 175, 369, 0, 0, 330, 0, 0, 0, 0, 331, 0, 0, 0, 0, 0, 0, 333],
+    /*   51 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   52 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   53 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   54 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   55 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   56 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   57 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   58 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*   59 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   60 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 306, 0, 0, 0, 0, 0, 0, 336, 0, 0, 0, 0, 0, 0, 0],
+    /*   61 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   62 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 322, 0, 0, 0, 0, 0, 0, 338, 0, 0, 0, 0, 0, 0, 0],
+    /*   63 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   64 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   65 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   66 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   67 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   68 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   69 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   70 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 182, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*   71 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 178, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*   72 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   73 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   74 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   75 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   76 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   77 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   78 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   79 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   80 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   81 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   82 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   83 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   84 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   85 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   86 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   87 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 344, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*   88 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   89 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   90 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   91 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   92 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   93 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 218, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*   94 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   95 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   96 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   97 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*   98 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 217, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*   99 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 226, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /*  100 */ [0, 0, 0, 0, 0, 0, 0, 0, 233, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  101 */ [0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  102 */ [0, 318, 26, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 117, 0, 18, 0, 0, 0, 0, 0, 120, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  103 */ [0, 313, 26, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 117, 0, 18, 0, 0, 0, 0, 0, 120, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  104 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  105 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 196, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /*  106 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 201, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /*  107 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  108 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 360, 361, 0, 362, 0, 0, 274],
+    /*  109 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  110 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  111 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  112 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  113 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  114 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 276, 0, 0, 0, 0, 0, 0, 334, 0, 0, 0, 0, 0, 0, 0],
+    /*  115 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  116 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  117 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  118 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  119 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  120 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 268, 0, 0, 0, 0, 0, 0, 325, 0, 0, 0, 0, 0, 0, 0],
+    /*  121 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 273, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  122 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 255, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  123 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 254, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  124 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  125 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  126 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  127 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  128 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  129 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  130 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  131 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 366, 361, 0, 362, 0, 0, 274],
+    /*  132 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  133 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  134 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 367, 361, 0, 362, 0, 0, 274],
+    /*  135 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 365, 361, 0, 362, 0, 0, 274],
+    /*  136 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  137 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  138 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  139 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  140 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  141 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 372, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  142 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  143 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  144 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 382, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  145 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  146 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  147 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  148 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 375, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  149 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  150 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  151 */ [0, 611, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  152 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 381, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  153 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 379, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  154 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 492, 0, 0, 0, 0, 0, 0, 520, 0, 0, 0, 0, 0, 0, 0],
+    /*  155 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  156 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 385, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  157 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  158 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  159 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  160 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  161 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  162 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  163 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 383, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  164 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 562, 0, 0, 0, 0, 0, 0, 621, 0, 0, 0, 0, 0, 0, 0],
+    /*  165 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  166 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  167 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  168 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 388, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  169 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 552, 0, 0, 0, 0, 0, 0, 619, 0, 0, 0, 0, 0, 0, 0],
+    /*  170 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  171 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  172 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  173 */ [0, 549, 390, 0, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 635, 0, 0, 0, 636, 0, 18, 0, 0, 0, 0, 0, 639, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  174 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  175 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 612, 0, 0, 0, 0, 0, 0, 656, 0, 0, 0, 0, 0, 0, 0],
+    /*  176 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 391, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0],
+    /*  177 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 617, 0, 0, 0, 0, 410, 457, 618, 0, 0, 511, 0, 0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 515],
+    /*  178 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /*  179 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 392, 0, 446, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  180 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 437, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  181 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  182 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /*  183 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  184 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 368, 0, 0, 393, 0, 248, 175, 369, 0, 0, 330, 0, 0, 0, 0, 331, 0, 0, 0, 0, 0, 0, 333],
+    /*  185 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 394, 0, 0, 0, 0, 0, 0, 0, 652, 0, 0, 395, 0, 454, 406, 653, 0, 0, 506, 0, 0, 0, 0, 507, 0, 0, 0, 0, 0, 0, 510],
+    /*  186 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 398, 0, 0, 0, 0, 0, 0, 0, 665, 0, 0, 399, 0, 477, 408, 666, 0, 0, 541, 0, 0, 0, 0, 545, 0, 0, 0, 0, 0, 0, 566],
+    /*  187 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  188 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 440, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  189 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 584, 0, 0, 0, 0, 0, 0, 644, 0, 0, 0, 0, 0, 0, 0],
+    /*  190 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 456, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  191 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 444, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  192 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  193 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  194 */ [0, 0, 0, 0, 0, 0, 0, 403, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  195 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 404, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  196 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /*  197 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 407, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 466, 0],
+    /*  198 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  199 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 686, 0, 0, 0, 0, 434, 508, 687, 0, 0, 641, 0, 0, 0, 0, 642, 0, 0, 0, 0, 0, 0, 643],
+    /*  200 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  201 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /*  202 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  203 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  204 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  205 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  206 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  207 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 425, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 445, 0],
+    /*  208 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  209 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  210 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  211 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  212 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  213 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  214 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  215 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  216 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  217 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /*  218 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  219 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 569, 0, 0, 0, 0, 0, 0, 604, 0, 0, 0, 0, 0, 0, 0],
+    /*  220 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 516, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /*  221 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  222 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  223 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  224 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  225 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  226 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /*  227 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  228 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  229 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  230 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  231 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 464, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*  232 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  233 */ [0, 0, 0, 0, 0, 0, 0, 490, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  234 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 431, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  235 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  236 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  237 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  238 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 424, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  239 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 491, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  240 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  241 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  242 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  243 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  244 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  245 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  246 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  247 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 527, 0],
+    /*  248 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 518, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 616, 0],
+    /*  249 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  250 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  251 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  252 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 690, 0, 0, 517, 0, 607, 521, 691, 0, 0, 654, 0, 0, 0, 0, 655, 0, 0, 0, 0, 0, 0, 657],
+    /*  253 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 678, 0, 0, 478, 0, 571, 482, 679, 0, 0, 632, 0, 0, 0, 0, 633, 0, 0, 0, 0, 0, 0, 640],
+    /*  254 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  255 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  256 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  257 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  258 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  259 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  260 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 339, 0, 0, 0, 0, 575, 169, 341, 0, 0, 256, 0, 0, 0, 0, 257, 0, 0, 0, 0, 0, 0, 260],
+    /*  261 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  262 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  263 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 503, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  264 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  265 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 0, 0, 596, 189, 342, 0, 0, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  266 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 486, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  267 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  268 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  269 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  270 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 663, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  271 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  272 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  273 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  274 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 513, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 0, 0, 0, 0, 0, 0, 274],
+    /*  275 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  276 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  277 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  278 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 671, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  279 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  280 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  281 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  282 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  283 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  284 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  285 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  286 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  287 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  288 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  289 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  290 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  291 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  292 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  293 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  294 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  295 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  296 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  297 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  298 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  299 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  300 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  301 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  302 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  303 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  304 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  305 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  306 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  307 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  308 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  309 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  310 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  311 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  312 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 672, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  313 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  314 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  315 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  316 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  317 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  318 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  319 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 651, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  320 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  321 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  322 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  323 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  324 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 661, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  325 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  326 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 673, 361, 0, 362, 0, 0, 274],
+    /*  327 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 581, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  328 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 586, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  329 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 576, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  330 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  331 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  332 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 547, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  333 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 368, 0, 0, 0, 0, 574, 175, 369, 0, 0, 330, 0, 0, 0, 0, 331, 0, 0, 0, 0, 0, 0, 333],
+    /*  334 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  335 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 682, 361, 0, 362, 0, 0, 274],
+    /*  336 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  337 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 677, 361, 0, 362, 0, 0, 274],
+    /*  338 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  339 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  340 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  341 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  342 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  343 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 674, 361, 0, 362, 0, 0, 274],
+    /*  344 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  345 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  346 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 532, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  347 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 529, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  348 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  349 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  350 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 675, 361, 0, 362, 0, 0, 274],
+    /*  351 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 685, 361, 0, 362, 0, 0, 274],
+    /*  352 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  353 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 683, 361, 0, 362, 0, 0, 274],
+    /*  354 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 533, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  355 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  356 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  357 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 664, 361, 0, 362, 0, 0, 274],
+    /*  358 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 556, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  359 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 662, 361, 0, 362, 0, 0, 274],
+    /*  360 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  361 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  362 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 537, 0, 539, 0, 0],
+    /*  363 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 580, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  364 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 579, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  365 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  366 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  367 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  368 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  369 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  370 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 524, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  371 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 668, 361, 0, 362, 0, 0, 274],
+    /*  372 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  373 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  374 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 695, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  375 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  376 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  377 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 696, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  378 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  379 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  380 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  381 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  382 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  383 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  384 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  385 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  386 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  387 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 699, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  388 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  389 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 703, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  390 */ [0, 0, 0, 704, 0, 0, 0, 0, 0, 0, 0, 705, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  391 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  392 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  393 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  394 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 700, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  395 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  396 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  397 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  398 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 706, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  399 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  400 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  401 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  402 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 708, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  403 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  404 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 710, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  405 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 898, 0, 0, 0, 0, 0, 0, 932, 0, 0, 0, 0, 0, 0, 0],
+    /*  406 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 906, 0, 0, 0, 0, 0, 0, 938, 0, 0, 0, 0, 0, 0, 0],
+    /*  407 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  408 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 892, 0, 0, 0, 0, 0, 0, 929, 0, 0, 0, 0, 0, 0, 0],
+    /*  409 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  410 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0],
+    /*  411 */ [0, 0, 732, 0, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 635, 0, 0, 0, 636, 0, 18, 0, 0, 0, 0, 0, 639, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  412 */ [0, 913, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  413 */ [0, 871, 390, 0, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 635, 0, 0, 0, 636, 0, 18, 0, 0, 0, 0, 0, 639, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  414 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 713, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  415 */ [0, 859, 390, 0, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 635, 0, 0, 0, 636, 0, 18, 0, 0, 0, 0, 0, 639, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  416 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 763, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  417 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 617, 0, 0, 0, 0, 722, 457, 618, 0, 0, 511, 0, 0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 515],
+    /*  418 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 617, 0, 0, 0, 0, 721, 457, 618, 0, 0, 511, 0, 0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 515],
+    /*  419 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 734, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  420 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 712, 0, 446, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  421 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 714, 0, 446, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  422 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 735, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  423 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 736, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  424 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  425 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  426 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 339, 0, 0, 0, 0, 767, 169, 341, 0, 0, 256, 0, 0, 0, 0, 257, 0, 0, 0, 0, 0, 0, 260],
+    /*  427 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 760, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  428 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  429 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  430 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  431 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 717, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  432 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  433 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  434 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 737, 0],
+    /*  435 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 0, 0, 807, 189, 342, 0, 0, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  436 */ [0, 769, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  437 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  438 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 768, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  439 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 678, 0, 0, 731, 0, 571, 482, 679, 0, 0, 632, 0, 0, 0, 0, 633, 0, 0, 0, 0, 0, 0, 640],
+    /*  440 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  441 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 841, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 0, 0, 0, 0, 0, 0, 274],
+    /*  442 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 748, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  443 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 764, 0, 0, 0, 0, 0, 0, 336, 0, 0, 0, 0, 0, 0, 0],
+    /*  444 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  445 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 0, 0, 793, 189, 342, 0, 0, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  446 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 720, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /*  447 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  448 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  449 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  450 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  451 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  452 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  453 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  454 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 739, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 783, 0],
+    /*  455 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  456 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  457 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 885, 0, 0, 0, 0, 0, 0, 934, 0, 0, 0, 0, 0, 0, 0],
+    /*  458 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  459 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  460 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  461 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  462 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  463 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  464 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /*  465 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  466 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 339, 0, 0, 0, 0, 825, 169, 341, 0, 0, 256, 0, 0, 0, 0, 257, 0, 0, 0, 0, 0, 0, 260],
+    /*  467 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  468 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 781, 0, 0, 0, 0, 0, 0, 334, 0, 0, 0, 0, 0, 0, 0],
+    /*  469 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  470 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  471 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  472 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  473 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  474 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  475 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  476 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  477 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 741, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 771, 0],
+    /*  478 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  479 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  480 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  481 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  482 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 919, 0, 0, 0, 0, 0, 0, 940, 0, 0, 0, 0, 0, 0, 0],
+    /*  483 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  484 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  485 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 752, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  486 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  487 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 786, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  488 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  489 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  490 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  491 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  492 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  493 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  494 */ [0, 923, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  495 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  496 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  497 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 949, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  498 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  499 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  500 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  501 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  502 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 746, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*  503 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  504 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 690, 0, 0, 784, 0, 607, 521, 691, 0, 0, 654, 0, 0, 0, 0, 655, 0, 0, 0, 0, 0, 0, 657],
+    /*  505 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  506 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  507 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  508 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 889, 0, 0, 0, 0, 0, 0, 944, 0, 0, 0, 0, 0, 0, 0],
+    /*  509 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 826, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  510 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 652, 0, 0, 0, 0, 824, 406, 653, 0, 0, 506, 0, 0, 0, 0, 507, 0, 0, 0, 0, 0, 0, 510],
+    /*  511 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  512 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  513 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 527, 0],
+    /*  514 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 744, 0, 0, 0, 0, 0, 0, 325, 0, 0, 0, 0, 0, 0, 0],
+    /*  515 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 617, 0, 0, 0, 0, 842, 457, 618, 0, 0, 511, 0, 0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 515],
+    /*  516 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /*  517 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  518 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  519 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  520 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  521 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 911, 0, 0, 0, 0, 0, 0, 942, 0, 0, 0, 0, 0, 0, 0],
+    /*  522 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  523 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 765, 0, 0, 0, 0, 0, 0, 338, 0, 0, 0, 0, 0, 0, 0],
+    /*  524 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  525 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 750, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  526 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 958, 361, 0, 362, 0, 0, 274],
+    /*  527 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 827, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 0, 0, 0, 0, 0, 0, 274],
+    /*  528 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  529 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  530 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  531 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 742, 0, 0, 0, 0, 0, 0, 352, 0, 0, 0, 0, 0, 0, 0],
+    /*  532 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  533 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  534 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  535 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 965, 0, 0, 0, 0, 787, 838, 966, 0, 0, 927, 0, 0, 0, 0, 928, 0, 0, 0, 0, 0, 0, 931],
+    /*  536 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  537 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  538 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 368, 0, 0, 0, 0, 861, 175, 369, 0, 0, 330, 0, 0, 0, 0, 331, 0, 0, 0, 0, 0, 0, 333],
+    /*  539 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 0, 0, 0, 973, 0, 0, 274],
+    /*  540 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  541 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  542 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  543 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  544 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 967, 0, 0, 0, 0, 811, 846, 968, 0, 0, 924, 0, 0, 0, 0, 925, 0, 0, 0, 0, 0, 0, 926],
+    /*  545 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  546 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  547 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  548 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  549 */ [0, 0, 0, 0, 0, 738, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  550 */ [0, 0, 0, 0, 0, 0, 0, 0, 776, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  551 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  552 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  553 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  554 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  555 */ [0, 908, 26, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 117, 0, 18, 0, 0, 0, 0, 0, 120, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  556 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  557 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 816, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  558 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 761, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  559 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 762, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  560 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 785, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  561 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  562 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  563 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  564 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 946, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  565 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 937, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  566 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 665, 0, 0, 0, 0, 848, 408, 666, 0, 0, 541, 0, 0, 0, 0, 545, 0, 0, 0, 0, 0, 0, 566],
+    /*  567 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  568 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  569 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  570 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  571 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 792, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 860, 0],
+    /*  572 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 947, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  573 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  574 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 616, 0],
+    /*  575 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 466, 0],
+    /*  576 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  577 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 805, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  578 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  579 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  580 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  581 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  582 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  583 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  584 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  585 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  586 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  587 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 935, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  588 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  589 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  590 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  591 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  592 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  593 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  594 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  595 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  596 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 445, 0],
+    /*  597 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  598 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  599 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  600 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  601 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  602 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  603 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 778, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /*  604 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  605 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 956, 361, 0, 362, 0, 0, 274],
+    /*  606 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  607 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 834, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 902, 0],
+    /*  608 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  609 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 843, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  610 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  611 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  612 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  613 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 836, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  614 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  615 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 950, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  616 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 368, 0, 0, 0, 0, 863, 175, 369, 0, 0, 330, 0, 0, 0, 0, 331, 0, 0, 0, 0, 0, 0, 333],
+    /*  617 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  618 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  619 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  620 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 951, 361, 0, 362, 0, 0, 274],
+    /*  621 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  622 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  623 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  624 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  625 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  626 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  627 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  628 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  629 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 955, 361, 0, 362, 0, 0, 274],
+    /*  630 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 788, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  631 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 964, 361, 0, 362, 0, 0, 274],
+    /*  632 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  633 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  634 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  635 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  636 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  637 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  638 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  639 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 851, 0, 0, 0, 0, 0, 0, 917, 0, 0, 0, 0, 0, 0, 0],
+    /*  640 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 678, 0, 0, 0, 0, 901, 482, 679, 0, 0, 632, 0, 0, 0, 0, 633, 0, 0, 0, 0, 0, 0, 640],
+    /*  641 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  642 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  643 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 686, 0, 0, 0, 0, 874, 508, 687, 0, 0, 641, 0, 0, 0, 0, 642, 0, 0, 0, 0, 0, 0, 643],
+    /*  644 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  645 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 959, 361, 0, 362, 0, 0, 274],
+    /*  646 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 839, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  647 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  648 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  649 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 818, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  650 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 957, 361, 0, 362, 0, 0, 274],
+    /*  651 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  652 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  653 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  654 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  655 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  656 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  657 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 690, 0, 0, 0, 0, 896, 521, 691, 0, 0, 654, 0, 0, 0, 0, 655, 0, 0, 0, 0, 0, 0, 657],
+    /*  658 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 948, 361, 0, 362, 0, 0, 274],
+    /*  659 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 796, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  660 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 961, 361, 0, 362, 0, 0, 274],
+    /*  661 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  662 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  663 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  664 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  665 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  666 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  667 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 840, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  668 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  669 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 813, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  670 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 954, 361, 0, 362, 0, 0, 274],
+    /*  671 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  672 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  673 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  674 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  675 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  676 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 849, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  677 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  678 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  679 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  680 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 819, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  681 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 960, 361, 0, 362, 0, 0, 274],
+    /*  682 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  683 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  684 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 862, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  685 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  686 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  687 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  688 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 828, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  689 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 963, 361, 0, 362, 0, 0, 274],
+    /*  690 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  691 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  692 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 830, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  693 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 962, 361, 0, 362, 0, 0, 274],
+    /*  694 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  695 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  696 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  697 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  698 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 974, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  699 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  700 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  701 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  702 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  703 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  704 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  705 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  706 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  707 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  708 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  709 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  710 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  711 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  712 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  713 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  714 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  715 */ [0, 1104, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  716 */ [0, 1017, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  717 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  718 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  719 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  720 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  721 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0],
+    /*  722 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0],
+    /*  723 */ [0, 1098, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  724 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  725 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1003, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  726 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 982, 0, 0, 0, 0, 0, 0, 0, 1155, 0, 0, 983, 0, 1020, 1001, 1156, 0, 0, 1069, 0, 0, 0, 0, 1070, 0, 0, 0, 0, 0, 0, 1073],
+    /*  727 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 994, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  728 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1004, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  729 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1002, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  730 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 1008, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  731 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  732 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  733 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 1009, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  734 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  735 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  736 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  737 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 686, 0, 0, 0, 0, 1022, 508, 687, 0, 0, 641, 0, 0, 0, 0, 642, 0, 0, 0, 0, 0, 0, 643],
+    /*  738 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  739 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  740 */ [0, 1088, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  741 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  742 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  743 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  744 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  745 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 991, 0, 0, 0, 0, 0, 0, 619, 0, 0, 0, 0, 0, 0, 0],
+    /*  746 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /*  747 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 992, 0, 0, 0, 0, 0, 0, 621, 0, 0, 0, 0, 0, 0, 0],
+    /*  748 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  749 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  750 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  751 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  752 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  753 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  754 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1005, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  755 */ [0, 1117, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  756 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  757 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 652, 0, 0, 0, 0, 1035, 406, 653, 0, 0, 506, 0, 0, 0, 0, 507, 0, 0, 0, 0, 0, 0, 510],
+    /*  758 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 665, 0, 0, 0, 0, 1038, 408, 666, 0, 0, 541, 0, 0, 0, 0, 545, 0, 0, 0, 0, 0, 0, 566],
+    /*  759 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1014, 0, 0, 0, 0, 0, 0, 520, 0, 0, 0, 0, 0, 0, 0],
+    /*  760 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  761 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  762 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  763 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  764 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  765 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  766 */ [0, 1152, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  767 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1052, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 466, 0],
+    /*  768 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /*  769 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  770 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1013, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  771 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 665, 0, 0, 0, 0, 1039, 408, 666, 0, 0, 541, 0, 0, 0, 0, 545, 0, 0, 0, 0, 0, 0, 566],
+    /*  772 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1067, 0, 0, 0, 0, 0, 0, 644, 0, 0, 0, 0, 0, 0, 0],
+    /*  773 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1055, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  774 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1109, 0, 0, 0, 0, 0, 0, 604, 0, 0, 0, 0, 0, 0, 0],
+    /*  775 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  776 */ [0, 0, 0, 0, 0, 0, 0, 1058, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  777 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1030, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  778 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /*  779 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  780 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  781 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  782 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  783 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 652, 0, 0, 0, 0, 1079, 406, 653, 0, 0, 506, 0, 0, 0, 0, 507, 0, 0, 0, 0, 0, 0, 510],
+    /*  784 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  785 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  786 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1053, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  787 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1122, 0],
+    /*  788 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  789 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1062, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  790 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  791 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1080, 0, 0, 0, 0, 0, 0, 656, 0, 0, 0, 0, 0, 0, 0],
+    /*  792 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  793 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 445, 0],
+    /*  794 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  795 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  796 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  797 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1071, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  798 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 1059, 60, 127, 0, 0, 94, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 98],
+    /*  799 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  800 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  801 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1012, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /*  802 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  803 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  804 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1178, 0, 0, 1063, 0, 1089, 1065, 1179, 0, 0, 1149, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 1153],
+    /*  805 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  806 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  807 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1056, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 445, 0],
+    /*  808 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  809 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  810 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 678, 0, 0, 0, 0, 1086, 482, 679, 0, 0, 632, 0, 0, 0, 0, 633, 0, 0, 0, 0, 0, 0, 640],
+    /*  811 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1060, 0],
+    /*  812 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  813 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  814 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  815 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  816 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  817 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  818 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  819 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  820 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1078, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  821 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1057, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  822 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1061, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  823 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  824 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 783, 0],
+    /*  825 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 466, 0],
+    /*  826 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /*  827 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 527, 0],
+    /*  828 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  829 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1077, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  830 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  831 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1066, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  832 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  833 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  834 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  835 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 617, 0, 0, 0, 0, 1082, 457, 618, 0, 0, 511, 0, 0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 515],
+    /*  836 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  837 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  838 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1095, 0, 0, 0, 0, 0, 0, 1143, 0, 0, 0, 0, 0, 0, 0],
+    /*  839 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  840 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  841 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 527, 0],
+    /*  842 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0],
+    /*  843 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  844 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  845 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  846 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1127, 0, 0, 0, 0, 0, 0, 1159, 0, 0, 0, 0, 0, 0, 0],
+    /*  847 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  848 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 771, 0],
+    /*  849 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  850 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  851 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  852 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  853 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 690, 0, 0, 0, 0, 1072, 521, 691, 0, 0, 654, 0, 0, 0, 0, 655, 0, 0, 0, 0, 0, 0, 657],
+    /*  854 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  855 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1162, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  856 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  857 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  858 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  859 */ [0, 0, 0, 0, 0, 1099, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  860 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 678, 0, 0, 0, 0, 1091, 482, 679, 0, 0, 632, 0, 0, 0, 0, 633, 0, 0, 0, 0, 0, 0, 640],
+    /*  861 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 616, 0],
+    /*  862 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /*  863 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 616, 0],
+    /*  864 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  865 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  866 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  867 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  868 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  869 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  870 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  871 */ [0, 0, 0, 0, 0, 1033, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  872 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  873 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  874 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 737, 0],
+    /*  875 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  876 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  877 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  878 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  879 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  880 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  881 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  882 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  883 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  884 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  885 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  886 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  887 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  888 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  889 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  890 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  891 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  892 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  893 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  894 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1167, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  895 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1174, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  896 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 902, 0],
+    /*  897 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  898 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  899 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  900 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1166, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  901 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 860, 0],
+    /*  902 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 690, 0, 0, 0, 0, 1081, 521, 691, 0, 0, 654, 0, 0, 0, 0, 655, 0, 0, 0, 0, 0, 0, 657],
+    /*  903 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1165, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  904 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  905 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  906 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  907 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  908 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  909 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  910 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  911 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  912 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  913 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  914 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  915 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1164, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  916 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  917 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  918 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1168, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  919 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  920 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  921 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1181, 361, 0, 362, 0, 0, 274],
+    /*  922 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1171, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /*  923 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  924 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  925 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  926 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 967, 0, 0, 0, 0, 1154, 846, 968, 0, 0, 924, 0, 0, 0, 0, 925, 0, 0, 0, 0, 0, 0, 926],
+    /*  927 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  928 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  929 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  930 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1163, 361, 0, 362, 0, 0, 274],
+    /*  931 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 965, 0, 0, 0, 0, 1107, 838, 966, 0, 0, 927, 0, 0, 0, 0, 928, 0, 0, 0, 0, 0, 0, 931],
+    /*  932 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  933 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1173, 361, 0, 362, 0, 0, 274],
+    /*  934 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  935 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  936 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1184, 361, 0, 362, 0, 0, 274],
+    /*  937 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  938 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  939 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1176, 361, 0, 362, 0, 0, 274],
+    /*  940 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  941 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1169, 361, 0, 362, 0, 0, 274],
+    /*  942 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  943 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1180, 361, 0, 362, 0, 0, 274],
+    /*  944 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  945 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1177, 361, 0, 362, 0, 0, 274],
+    /*  946 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  947 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  948 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  949 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  950 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  951 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  952 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 1148, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  953 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 1021, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /*  954 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  955 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  956 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  957 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  958 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  959 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  960 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  961 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  962 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  963 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  964 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  965 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  966 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  967 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  968 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  969 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1041, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  970 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1044, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  971 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1170, 361, 0, 362, 0, 0, 274],
+    /*  972 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1175, 361, 0, 362, 0, 0, 274],
+    /*  973 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1142, 0, 539, 0, 0],
+    /*  974 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  975 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1186, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  976 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  977 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  978 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  979 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  980 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  981 */ [0, 1296, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  982 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  983 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  984 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  985 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  986 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  987 */ [0, 1218, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  988 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  989 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  990 */ [0, 1311, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  991 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  992 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  993 */ [0, 1216, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  994 */ [0, 0, 0, 0, 0, 0, 1191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /*  995 */ [0, 1297, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  996 */ [0, 1282, 390, 0, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 635, 0, 0, 0, 636, 0, 18, 0, 0, 0, 0, 0, 639, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /*  997 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 1204, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  998 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 1205, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /*  999 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 617, 0, 0, 0, 0, 1206, 457, 618, 0, 0, 511, 0, 0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 515],
+    /* 1000 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 1192, 0, 446, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /* 1001 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1287, 0, 0, 0, 0, 0, 0, 1314, 0, 0, 0, 0, 0, 0, 0],
+    /* 1002 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1003 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1004 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1005 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1006 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1007 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 1217, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 0, 0, 0, 0, 0, 0, 274],
+    /* 1008 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /* 1009 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /* 1010 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1011 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1197, 0, 0, 0, 0, 0, 0, 940, 0, 0, 0, 0, 0, 0, 0],
+    /* 1012 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1013 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1014 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1015 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1016 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1017 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1018 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1019 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1020 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1219, 0],
+    /* 1021 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /* 1022 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 737, 0],
+    /* 1023 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1024 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1025 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1026 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1027 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1028 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1029 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1030 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 1212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1031 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1032 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1033 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1034 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1202, 0, 0, 0, 0, 0, 0, 934, 0, 0, 0, 0, 0, 0, 0],
+    /* 1035 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 783, 0],
+    /* 1036 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1037 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1038 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 771, 0],
+    /* 1039 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 771, 0],
+    /* 1040 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1215, 0, 0, 0, 0, 0, 0, 932, 0, 0, 0, 0, 0, 0, 0],
+    /* 1041 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1042 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1043 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1044 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1045 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1254, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1046 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1250, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /* 1047 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1048 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1223, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1049 */ [0, 1312, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1050 */ [0, 1322, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1051 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1234, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /* 1052 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1053 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1054 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1244, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1055 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1056 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1057 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1058 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1059 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0],
+    /* 1060 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 967, 0, 0, 0, 0, 1275, 846, 968, 0, 0, 924, 0, 0, 0, 0, 925, 0, 0, 0, 0, 0, 0, 926],
+    /* 1061 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1062 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1063 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1064 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1065 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1300, 0, 0, 0, 0, 0, 0, 1320, 0, 0, 0, 0, 0, 0, 0],
+    /* 1066 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1067 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1068 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1178, 0, 0, 1249, 0, 1089, 1065, 1179, 0, 0, 1149, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 1153],
+    /* 1069 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1070 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1071 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1072 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1274, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 902, 0],
+    /* 1073 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1155, 0, 0, 0, 0, 1276, 1001, 1156, 0, 0, 1069, 0, 0, 0, 0, 1070, 0, 0, 0, 0, 0, 0, 1073],
+    /* 1074 */ [0, 1313, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1075 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1076 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1077 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1078 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1079 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 783, 0],
+    /* 1080 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1081 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 902, 0],
+    /* 1082 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0],
+    /* 1083 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1230, 0, 0, 0, 0, 0, 0, 929, 0, 0, 0, 0, 0, 0, 0],
+    /* 1084 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1267, 0, 0, 0, 0, 0, 0, 944, 0, 0, 0, 0, 0, 0, 0],
+    /* 1085 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1086 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1291, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 860, 0],
+    /* 1087 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1088 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1089 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1294, 0],
+    /* 1090 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1091 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 860, 0],
+    /* 1092 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1093 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1288, 0, 0, 0, 0, 0, 0, 917, 0, 0, 0, 0, 0, 0, 0],
+    /* 1094 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1095 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1096 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1097 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1098 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1099 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1100 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1101 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1323, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /* 1102 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1103 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1104 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1105 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1106 */ [0, 1316, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1107 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1122, 0],
+    /* 1108 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1109 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1110 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1111 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1112 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1113 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1114 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1115 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1116 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1117 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1118 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1119 */ [0, 1308, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1120 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1121 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 1236, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /* 1122 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 965, 0, 0, 0, 0, 1292, 838, 966, 0, 0, 927, 0, 0, 0, 0, 928, 0, 0, 0, 0, 0, 0, 931],
+    /* 1123 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1124 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1125 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1126 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1127 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1128 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1129 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1130 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1131 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1132 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1133 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1225, 0, 0, 0, 0, 0, 0, 938, 0, 0, 0, 0, 0, 0, 0],
+    /* 1134 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1135 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1324, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /* 1136 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1330, 0, 0, 0, 0, 1245, 1284, 1331, 0, 0, 1317, 0, 0, 0, 0, 1318, 0, 0, 0, 0, 0, 0, 1319],
+    /* 1137 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1138 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1139 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1140 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1141 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1240, 0, 0, 0, 0, 0, 0, 942, 0, 0, 0, 0, 0, 0, 0],
+    /* 1142 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1143 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1144 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1326, 361, 0, 362, 0, 0, 274],
+    /* 1145 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1146 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1147 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1148 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /* 1149 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1150 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1151 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1152 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1153 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1178, 0, 0, 0, 0, 1285, 1065, 1179, 0, 0, 1149, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 1153],
+    /* 1154 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1060, 0],
+    /* 1155 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1156 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1157 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1270, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1158 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1328, 361, 0, 362, 0, 0, 274],
+    /* 1159 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1160 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1327, 361, 0, 362, 0, 0, 274],
+    /* 1161 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1162 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1163 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1164 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1165 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1166 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1167 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1168 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1169 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1170 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1171 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1172 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 1293, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /* 1173 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1174 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1175 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1176 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1177 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1178 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1179 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1180 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1181 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1182 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1183 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1325, 361, 0, 362, 0, 0, 274],
+    /* 1184 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1185 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 1334, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1186 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1187 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1188 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1189 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 1335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1190 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1191 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1192 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1193 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1194 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1195 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1196 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1197 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1198 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1199 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1200 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1201 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1202 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1203 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1204 */ [0, 0, 0, 0, 0, 0, 1337, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /* 1205 */ [0, 0, 0, 0, 0, 0, 1338, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /* 1206 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 835, 0],
+    /* 1207 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1208 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 1341, 62, 129, 0, 0, 96, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 99],
+    /* 1209 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1357, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /* 1210 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1211 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1155, 0, 0, 0, 0, 1381, 1001, 1156, 0, 0, 1069, 0, 0, 0, 0, 1070, 0, 0, 0, 0, 0, 0, 1073],
+    /* 1212 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1213 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1214 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1215 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1216 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1217 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 527, 0],
+    /* 1218 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1219 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1155, 0, 0, 0, 0, 1380, 1001, 1156, 0, 0, 1069, 0, 0, 0, 0, 1070, 0, 0, 0, 0, 0, 0, 1073],
+    /* 1220 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1221 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1222 */ [0, 1398, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1223 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1224 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1225 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1226 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1227 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1343, 0, 0, 0, 0, 0, 0, 1143, 0, 0, 0, 0, 0, 0, 0],
+    /* 1228 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1229 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1230 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1231 */ [0, 1390, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1232 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1178, 0, 0, 0, 0, 1384, 1065, 1179, 0, 0, 1149, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 1153],
+    /* 1233 */ [0, 1392, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1234 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1235 */ [0, 1397, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1236 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /* 1237 */ [0, 1399, 390, 0, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 635, 0, 0, 0, 636, 0, 18, 0, 0, 0, 0, 0, 639, 637, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1238 */ [0, 1389, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1239 */ [0, 1388, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1240 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1241 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1349, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /* 1242 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1243 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1244 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1245 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1356, 0],
+    /* 1246 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1247 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1350, 0, 0, 0, 0, 0, 0, 1159, 0, 0, 0, 0, 0, 0, 0],
+    /* 1248 */ [0, 1400, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1249 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1250 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1251 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1252 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1253 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1254 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1255 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1256 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1257 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1359, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1258 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1259 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1260 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1261 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1262 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1263 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1264 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1265 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1266 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1267 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1268 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1269 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1270 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1271 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1368, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1272 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1273 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1274 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1275 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1060, 0],
+    /* 1276 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1219, 0],
+    /* 1277 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1278 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1279 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1280 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1281 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1282 */ [0, 0, 0, 0, 0, 1364, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1283 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1284 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1394, 0, 0, 0, 0, 0, 0, 1403, 0, 0, 0, 0, 0, 0, 0],
+    /* 1285 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1294, 0],
+    /* 1286 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1287 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1288 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1289 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1290 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1401, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /* 1291 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1292 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1122, 0],
+    /* 1293 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /* 1294 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1178, 0, 0, 0, 0, 1386, 1065, 1179, 0, 0, 1149, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 1153],
+    /* 1295 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1296 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1297 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1298 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1299 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1300 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1301 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1302 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1303 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1304 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1305 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1402, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /* 1306 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1307 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1308 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1309 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1310 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1311 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1312 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1313 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1314 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1315 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1406, 361, 0, 362, 0, 0, 274],
+    /* 1316 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1317 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1318 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1319 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1330, 0, 0, 0, 0, 1385, 1284, 1331, 0, 0, 1317, 0, 0, 0, 0, 1318, 0, 0, 0, 0, 0, 0, 1319],
+    /* 1320 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1321 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1405, 361, 0, 362, 0, 0, 274],
+    /* 1322 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1323 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1324 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1325 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1326 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1327 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1328 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1329 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 1382, 40, 125, 0, 0, 91, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 93],
+    /* 1330 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1331 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1332 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1354, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1333 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1407, 361, 0, 362, 0, 0, 274],
+    /* 1334 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1335 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1336 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1337 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1338 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1339 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1340 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1341 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0],
+    /* 1342 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1408, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1343 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1344 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1345 */ [0, 1436, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1346 */ [0, 1438, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1347 */ [0, 1443, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1348 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 647, 0, 0, 0, 0, 1418, 405, 648, 0, 0, 500, 0, 0, 0, 0, 501, 0, 0, 0, 0, 0, 0, 509],
+    /* 1349 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1350 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1351 */ [0, 1441, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1352 */ [0, 1444, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1353 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1354 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1355 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1410, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1356 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1330, 0, 0, 0, 0, 1439, 1284, 1331, 0, 0, 1317, 0, 0, 0, 0, 1318, 0, 0, 0, 0, 0, 0, 1319],
+    /* 1357 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1358 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1411, 0, 0, 0, 0, 0, 0, 1320, 0, 0, 0, 0, 0, 0, 0],
+    /* 1359 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1360 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1361 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1362 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1363 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1364 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1365 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1366 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1367 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1414, 0, 0, 0, 0, 0, 0, 1314, 0, 0, 0, 0, 0, 0, 0],
+    /* 1368 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1369 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1370 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1371 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1372 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1373 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1374 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1375 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1376 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 1435, 164, 349, 0, 0, 262, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 266],
+    /* 1377 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1378 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1379 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1380 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1219, 0],
+    /* 1381 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1437, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1219, 0],
+    /* 1382 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0],
+    /* 1383 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1384 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1442, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1294, 0],
+    /* 1385 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1356, 0],
+    /* 1386 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1294, 0],
+    /* 1387 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1388 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1389 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1390 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1391 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1392 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1393 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1394 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1395 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1396 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 340, 0, 0, 345, 0, 207, 189, 342, 0, 1445, 258, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 265],
+    /* 1397 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1398 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1399 */ [0, 0, 0, 0, 0, 1434, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1400 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1401 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1402 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1403 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1404 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 247, 219, 356, 0, 0, 271, 0, 0, 0, 0, 272, 1446, 361, 0, 362, 0, 0, 274],
+    /* 1405 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1406 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1407 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1408 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 1448, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1409 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1410 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1411 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1412 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1413 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1414 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1415 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1416 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1417 */ [0, 1460, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1418 */ [0, 0, 0, 0, 0, 0, 1447, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 733, 0],
+    /* 1419 */ [0, 1459, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1420 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1421 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1422 */ [0, 1462, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1423 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1424 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1425 */ [0, 1461, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1426 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1427 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1428 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1429 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1430 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1431 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1432 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1433 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1450, 0, 0, 0, 0, 0, 0, 1403, 0, 0, 0, 0, 0, 0, 0],
+    /* 1434 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1435 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 427, 0],
+    /* 1436 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1437 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1438 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1439 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1356, 0],
+    /* 1440 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1441 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1442 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1443 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1444 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1445 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1446 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1447 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1448 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1449 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1450 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1451 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1452 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1453 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1454 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1455 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1456 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1457 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1458 */ [0, 1468, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1459 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1460 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1461 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1462 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1463 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1464 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1465 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1466 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1467 */ [0, 1470, 27, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 111, 0, 18, 0, 0, 0, 0, 0, 114, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1468 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1469 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1470 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    /* 1471 */ [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+];
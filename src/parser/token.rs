@@ -4,12 +4,26 @@ use alloc::vec::Vec;
 
 use crate::lex::{Lexeme, LexemeType};
 
-use super::{Error, Parser};
-
 #[derive(Debug, Clone)]
 pub struct Token<'a> {
     pub(crate) tokens: Vec<Token<'a>>,
     pub(crate) token_type: TokenType<'a>,
+    /// Line where the token starts, starting at 1, or 0 if the token
+    /// is empty
+    pub(crate) line: usize,
+}
+
+impl Drop for Token<'_> {
+    fn drop(&mut self) {
+        // Lists like the fields of a table constructor nest a token for
+        // each element, and dropping them recursively can overflow the
+        // host's stack, so nested tokens are emptied into a work stack
+        // before being dropped
+        let mut pending = core::mem::take(&mut self.tokens);
+        while let Some(mut token) = pending.pop() {
+            pending.append(&mut token.tokens);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -73,6 +87,8 @@ pub enum TokenType<'a> {
     Integer(i64),
     Float(f64),
     String(&'a str),
+    /// Long string, same terminal as `String`
+    LongString(&'a str),
     Name(&'a str),
     Eof,
 
@@ -277,6 +293,46 @@ pub enum TokenType<'a> {
 }
 
 impl TokenType<'_> {
+    /// Whether the terminal opens a syntax level, which nests the
+    /// expressions, blocks, or functions that follow it
+    pub(crate) fn opens_level(&self) -> bool {
+        matches!(
+            self,
+            TokenType::Do
+                | TokenType::If
+                | TokenType::Repeat
+                | TokenType::Function
+                | TokenType::LParen
+                | TokenType::LCurly
+                | TokenType::LSquare
+                | TokenType::Not
+                | TokenType::Len
+                | TokenType::Or
+                | TokenType::And
+                | TokenType::Less
+                | TokenType::Greater
+                | TokenType::Leq
+                | TokenType::Geq
+                | TokenType::Eq
+                | TokenType::Neq
+                | TokenType::BitOr
+                | TokenType::BitXor
+                | TokenType::BitAnd
+                | TokenType::ShiftL
+                | TokenType::ShiftR
+                | TokenType::Concat
+                | TokenType::Add
+                | TokenType::Sub
+                | TokenType::Mul
+                | TokenType::Div
+                | TokenType::Idiv
+                | TokenType::Mod
+                | TokenType::Pow
+                | TokenType::Binop
+                | TokenType::Unop
+        )
+    }
+
     fn binop_strength(&self) -> u8 {
         match self {
             TokenType::Or => 0,
@@ -396,7 +452,7 @@ impl Display for TokenType<'_> {
             Self::Dots => write!(f, "..."),
             Self::Integer(i) => write!(f, "{}", i),
             Self::Float(float) => write!(f, "{}", float),
-            Self::String(s) => write!(f, "{}", s),
+            Self::String(s) | Self::LongString(s) => write!(f, "{}", s),
             Self::Name(n) => write!(f, "{}", n),
             Self::Eof => write!(f, "eof"),
             // Non-terminals
@@ -450,247 +506,77 @@ impl Display for TokenType<'_> {
 impl<'a, T: Borrow<Lexeme<'a>>> From<T> for Token<'a> {
     #[allow(clippy::too_many_lines)]
     fn from(value: T) -> Self {
-        match value.borrow().lexeme_type {
-            LexemeType::And => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::And,
-            },
-            LexemeType::Break => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Break,
-            },
-            LexemeType::Do => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Do,
-            },
-            LexemeType::Else => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Else,
-            },
-            LexemeType::Elseif => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Elseif,
-            },
-            LexemeType::End => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::End,
-            },
-            LexemeType::False => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::False,
-            },
-            LexemeType::For => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::For,
-            },
-            LexemeType::Function => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Function,
-            },
-            LexemeType::Goto => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Goto,
-            },
-            LexemeType::If => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::If,
-            },
-            LexemeType::In => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::In,
-            },
-            LexemeType::Local => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Local,
-            },
-            LexemeType::Nil => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Nil,
-            },
-            LexemeType::Not => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Not,
-            },
-            LexemeType::Or => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Or,
-            },
-            LexemeType::Repeat => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Repeat,
-            },
-            LexemeType::Return => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Return,
-            },
-            LexemeType::Then => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Then,
-            },
-            LexemeType::True => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::True,
-            },
-            LexemeType::Until => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Until,
-            },
-            LexemeType::While => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::While,
-            },
-            LexemeType::Add => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Add,
-            },
-            LexemeType::Sub => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Sub,
-            },
-            LexemeType::Mul => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Mul,
-            },
-            LexemeType::Div => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Div,
-            },
-            LexemeType::Mod => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Mod,
-            },
-            LexemeType::Pow => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Pow,
-            },
-            LexemeType::Len => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Len,
-            },
-            LexemeType::BitAnd => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::BitAnd,
-            },
-            LexemeType::BitOr => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::BitOr,
-            },
-            LexemeType::BitXor => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::BitXor,
-            },
-            LexemeType::ShiftL => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::ShiftL,
-            },
-            LexemeType::ShiftR => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::ShiftR,
-            },
-            LexemeType::Idiv => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Idiv,
-            },
-            LexemeType::Eq => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Eq,
-            },
-            LexemeType::Neq => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Neq,
-            },
-            LexemeType::Leq => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Leq,
-            },
-            LexemeType::Geq => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Geq,
-            },
-            LexemeType::Less => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Less,
-            },
-            LexemeType::Greater => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Greater,
-            },
-            LexemeType::Assign => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Assign,
-            },
-            LexemeType::LParen => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::LParen,
-            },
-            LexemeType::RParen => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::RParen,
-            },
-            LexemeType::LCurly => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::LCurly,
-            },
-            LexemeType::RCurly => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::RCurly,
-            },
-            LexemeType::LSquare => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::LSquare,
-            },
-            LexemeType::RSquare => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::RSquare,
-            },
-            LexemeType::SemiColon => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::SemiColon,
-            },
-            LexemeType::Colon => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Colon,
-            },
-            LexemeType::DoubleColon => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::DoubleColon,
-            },
-            LexemeType::Comma => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Comma,
-            },
-            LexemeType::Dot => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Dot,
-            },
-            LexemeType::Concat => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Concat,
-            },
-            LexemeType::Dots => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Dots,
-            },
-            LexemeType::Integer(i) => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Integer(i),
-            },
-            LexemeType::Float(f) => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Float(f),
-            },
-            LexemeType::String(s) => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::String(s),
-            },
-            LexemeType::Name(n) => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Name(n),
-            },
-            LexemeType::Eof => Token {
-                tokens: [].to_vec(),
-                token_type: TokenType::Eof,
-            },
+        let lexeme = value.borrow();
+        let token_type = match lexeme.lexeme_type {
+            LexemeType::And => TokenType::And,
+            LexemeType::Break => TokenType::Break,
+            LexemeType::Do => TokenType::Do,
+            LexemeType::Else => TokenType::Else,
+            LexemeType::Elseif => TokenType::Elseif,
+            LexemeType::End => TokenType::End,
+            LexemeType::False => TokenType::False,
+            LexemeType::For => TokenType::For,
+            LexemeType::Function => TokenType::Function,
+            LexemeType::Goto => TokenType::Goto,
+            LexemeType::If => TokenType::If,
+            LexemeType::In => TokenType::In,
+            LexemeType::Local => TokenType::Local,
+            LexemeType::Nil => TokenType::Nil,
+            LexemeType::Not => TokenType::Not,
+            LexemeType::Or => TokenType::Or,
+            LexemeType::Repeat => TokenType::Repeat,
+            LexemeType::Return => TokenType::Return,
+            LexemeType::Then => TokenType::Then,
+            LexemeType::True => TokenType::True,
+            LexemeType::Until => TokenType::Until,
+            LexemeType::While => TokenType::While,
+            LexemeType::Add => TokenType::Add,
+            LexemeType::Sub => TokenType::Sub,
+            LexemeType::Mul => TokenType::Mul,
+            LexemeType::Div => TokenType::Div,
+            LexemeType::Mod => TokenType::Mod,
+            LexemeType::Pow => TokenType::Pow,
+            LexemeType::Len => TokenType::Len,
+            LexemeType::BitAnd => TokenType::BitAnd,
+            LexemeType::BitOr => TokenType::BitOr,
+            LexemeType::BitXor => TokenType::BitXor,
+            LexemeType::ShiftL => TokenType::ShiftL,
+            LexemeType::ShiftR => TokenType::ShiftR,
+            LexemeType::Idiv => TokenType::Idiv,
+            LexemeType::Eq => TokenType::Eq,
+            LexemeType::Neq => TokenType::Neq,
+            LexemeType::Leq => TokenType::Leq,
+            LexemeType::Geq => TokenType::Geq,
+            LexemeType::Less => TokenType::Less,
+            LexemeType::Greater => TokenType::Greater,
+            LexemeType::Assign => TokenType::Assign,
+            LexemeType::LParen => TokenType::LParen,
+            LexemeType::RParen => TokenType::RParen,
+            LexemeType::LCurly => TokenType::LCurly,
+            LexemeType::RCurly => TokenType::RCurly,
+            LexemeType::LSquare => TokenType::LSquare,
+            LexemeType::RSquare => TokenType::RSquare,
+            LexemeType::SemiColon => TokenType::SemiColon,
+            LexemeType::Colon => TokenType::Colon,
+            LexemeType::DoubleColon => TokenType::DoubleColon,
+            LexemeType::Comma => TokenType::Comma,
+            LexemeType::Dot => TokenType::Dot,
+            LexemeType::Concat => TokenType::Concat,
+            LexemeType::Dots => TokenType::Dots,
+            LexemeType::Integer(i) => TokenType::Integer(i),
+            LexemeType::Float(f) => TokenType::Float(f),
+            LexemeType::String(s) => TokenType::String(s),
+            LexemeType::LongString(s) => TokenType::LongString(s),
+            LexemeType::Name(n) => TokenType::Name(n),
+            LexemeType::Eof => TokenType::Eof,
+            LexemeType::Whitespace(_) | LexemeType::Comment(_) => {
+                unreachable!("The parser reads the lexemes without trivia.")
+            }
+        };
+        Token {
+            tokens: [].to_vec(),
+            token_type,
+            line: lexeme.line + 1,
         }
     }
 }
@@ -703,19 +589,6 @@ pub enum Precedence {
     Reduce,
 }
 
-impl Precedence {
-    pub fn resolve<const REDUCE: usize>(
-        self,
-        parser: &mut Parser,
-        shift: usize,
-    ) -> Result<(), Error> {
-        match self {
-            Self::Shift => parser.shift(shift),
-            Self::Reduce => parser.reduce::<REDUCE>(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
 
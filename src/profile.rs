@@ -0,0 +1,264 @@
+//! Call site profiling
+//!
+//! While profiling is enabled, every `CALL` and `TAILCALL` records how many
+//! arguments it passed and the type of each of them. Call sites that see
+//! more than one type on an argument are polymorphic, and are the ones that
+//! would not benefit from specializing the call for a single type.
+//!
+//! With the `opcode_counts` feature, the VM also counts how many times each
+//! opcode runs, to measure the dispatch loop.
+
+use core::fmt::Display;
+
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+
+#[cfg(feature = "opcode_counts")]
+use crate::bytecode::{Bytecode, FIRST_CUSTOM_OPCODE, LAST_CUSTOM_OPCODE, OpCode};
+use crate::{Program, value::Value};
+
+#[derive(Debug, Default)]
+pub(crate) struct Profiler {
+    /// Chunks executed while profiling, used to find where
+    /// each call site is
+    chunks: Vec<Program>,
+    /// Call sites, by the address of their bytecode and the
+    /// index of the call on it
+    sites: BTreeMap<(usize, usize), SiteRecord>,
+}
+
+#[derive(Debug)]
+struct SiteRecord {
+    /// Keeps the function alive so its address is not reused
+    program: Program,
+    calls: usize,
+    argument_counts: BTreeMap<usize, usize>,
+    argument_types: Vec<BTreeMap<&'static str, usize>>,
+}
+
+impl Profiler {
+    pub(crate) fn add_chunk(&mut self, program: &Program) {
+        if !self
+            .chunks
+            .iter()
+            .any(|chunk| same_function(chunk, program))
+        {
+            self.chunks.push(program.clone());
+        }
+    }
+
+    pub(crate) fn record(&mut self, program: &Program, bytecode: usize, args: &[Value]) {
+        let record = self
+            .sites
+            .entry((
+                Rc::as_ptr(&program.byte_codes) as *const () as usize,
+                bytecode,
+            ))
+            .or_insert_with(|| SiteRecord {
+                program: program.clone(),
+                calls: 0,
+                argument_counts: BTreeMap::new(),
+                argument_types: Vec::new(),
+            });
+
+        record.calls += 1;
+        *record.argument_counts.entry(args.len()).or_default() += 1;
+        if record.argument_types.len() < args.len() {
+            record.argument_types.resize_with(args.len(), BTreeMap::new);
+        }
+        for (types, arg) in record.argument_types.iter_mut().zip(args) {
+            *types.entry(arg.static_type_name()).or_default() += 1;
+        }
+    }
+
+    pub(crate) fn report(&self) -> CallProfile {
+        let mut sites = self
+            .sites
+            .iter()
+            .filter_map(|((_, bytecode), record)| {
+                let (chunk, path) = self.chunks.iter().enumerate().find_map(|(i, chunk)| {
+                    find_path(chunk, &record.program).map(|path| (i, path))
+                })?;
+                Some(CallSite {
+                    chunk,
+                    path,
+                    bytecode: *bytecode,
+                    calls: record.calls,
+                    argument_counts: record.argument_counts.clone().into_iter().collect(),
+                    argument_types: record
+                        .argument_types
+                        .iter()
+                        .map(|types| types.clone().into_iter().collect())
+                        .collect(),
+                })
+            })
+            .collect::<Vec<_>>();
+        sites.sort_by(|lhs, rhs| {
+            rhs.calls.cmp(&lhs.calls).then_with(|| {
+                (lhs.chunk, &lhs.path, lhs.bytecode).cmp(&(rhs.chunk, &rhs.path, rhs.bytecode))
+            })
+        });
+        CallProfile { sites }
+    }
+}
+
+/// Programs are cloned when functions are created, but they
+/// keep sharing their bytecode
+fn same_function(lhs: &Program, rhs: &Program) -> bool {
+    Rc::ptr_eq(&lhs.byte_codes, &rhs.byte_codes)
+}
+
+/// Position of `function` inside of `chunk`
+fn find_path(chunk: &Program, function: &Program) -> Option<Vec<usize>> {
+    if same_function(chunk, function) {
+        return Some(Vec::new());
+    }
+    chunk.functions.iter().enumerate().find_map(|(i, nested)| {
+        find_path(nested.program(), function).map(|mut path| {
+            path.insert(0, i);
+            path
+        })
+    })
+}
+
+/// Call sites observed while profiling, see [`Lua::set_profiling`](crate::Lua::set_profiling)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallProfile {
+    sites: Vec<CallSite>,
+}
+
+impl CallProfile {
+    /// Call sites, the most called first
+    pub fn sites(&self) -> &[CallSite] {
+        &self.sites
+    }
+
+    /// Call sites that received more than one type on the same argument
+    pub fn polymorphic(&self) -> impl Iterator<Item = &CallSite> {
+        self.sites.iter().filter(|site| site.is_polymorphic())
+    }
+}
+
+impl Display for CallProfile {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for site in self.sites.iter() {
+            writeln!(f, "{}", site)?;
+        }
+        Ok(())
+    }
+}
+
+/// Arguments passed by a `CALL` or `TAILCALL`
+///
+/// `path` is the position of the calling function, as a list of indexes
+/// into the nested functions, starting from the main function of chunk
+/// number `chunk`, in the order the chunks were first run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallSite {
+    pub chunk: usize,
+    pub path: Vec<usize>,
+    /// Index of the call on the function's bytecode
+    pub bytecode: usize,
+    pub calls: usize,
+    /// Number of calls made with each argument count
+    pub argument_counts: Vec<(usize, usize)>,
+    /// Number of calls that received each type, for each argument
+    pub argument_types: Vec<Vec<(&'static str, usize)>>,
+}
+
+impl CallSite {
+    /// Returns `true` if any argument received more than one type
+    pub fn is_polymorphic(&self) -> bool {
+        self.argument_types.iter().any(|types| types.len() > 1)
+    }
+}
+
+impl Display for CallSite {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "chunk {} main", self.chunk)?;
+        for index in self.path.iter() {
+            write!(f, ".functions[{index}]")?;
+        }
+        write!(
+            f,
+            " byte_codes[{}]: {} calls, arguments",
+            self.bytecode, self.calls
+        )?;
+        for (count, calls) in self.argument_counts.iter() {
+            write!(f, " {count}x{calls}")?;
+        }
+        for (i, types) in self.argument_types.iter().enumerate() {
+            write!(f, "\n  #{}:", i + 1)?;
+            for (name, calls) in types.iter() {
+                write!(f, " {name}x{calls}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Number of times each opcode ran, see [`Lua::opcode_counts`](crate::Lua::opcode_counts)
+#[cfg(feature = "opcode_counts")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcodeCounts {
+    /// Indexed by the id of the opcode
+    counts: [u64; LAST_CUSTOM_OPCODE as usize + 1],
+}
+
+#[cfg(feature = "opcode_counts")]
+impl Default for OpcodeCounts {
+    fn default() -> Self {
+        Self {
+            counts: [0; LAST_CUSTOM_OPCODE as usize + 1],
+        }
+    }
+}
+
+#[cfg(feature = "opcode_counts")]
+impl OpcodeCounts {
+    pub(crate) fn record(&mut self, bytecode: Bytecode) {
+        self.counts[usize::from(bytecode.opcode_id())] += 1;
+    }
+
+    /// Times the opcode named `name`, as the reference implementation
+    /// names it, ran
+    pub fn count(&self, name: &str) -> u64 {
+        self.iter()
+            .find(|(opcode, _)| *opcode == name)
+            .map_or(0, |(_, count)| count)
+    }
+
+    /// Opcodes that ran, with the times they ran, the most run first
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        let mut counts = self.counts[..usize::from(FIRST_CUSTOM_OPCODE)]
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count != 0)
+            .map(|(id, count)| (OpCode::from_id(id as u8).name(), *count))
+            .collect::<Vec<_>>();
+        counts.sort_by(|lhs, rhs| rhs.1.cmp(&lhs.1).then_with(|| lhs.0.cmp(rhs.0)));
+        counts.into_iter()
+    }
+
+    /// Times the opcodes reserved for the host ran
+    pub fn custom(&self) -> u64 {
+        self.counts[usize::from(FIRST_CUSTOM_OPCODE)..].iter().sum()
+    }
+
+    /// Number of bytecodes that ran
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+}
+
+#[cfg(feature = "opcode_counts")]
+impl Display for OpcodeCounts {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (name, count) in self.iter() {
+            writeln!(f, "{name:<10}\t{count}")?;
+        }
+        if self.custom() != 0 {
+            writeln!(f, "{:<10}\t{}", "(custom)", self.custom())?;
+        }
+        write!(f, "{:<10}\t{}", "(total)", self.total())
+    }
+}
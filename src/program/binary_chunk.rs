@@ -0,0 +1,597 @@
+use alloc::{boxed::Box, format, rc::Rc, vec::Vec};
+
+use crate::{bytecode::Bytecode, function::Function, value::Value};
+
+use super::{Error, Local, Program, UpvalueDescriptor};
+
+const SIGNATURE: &[u8] = b"\x1bLua";
+const VERSION: u8 = 0x54;
+const FORMAT: u8 = 0;
+const DATA: &[u8] = b"\x19\x93\r\n\x1a\n";
+const INSTRUCTION_SIZE: u8 = 4;
+const INTEGER_SIZE: u8 = 8;
+const NUMBER_SIZE: u8 = 8;
+const TEST_INTEGER: i64 = 0x5678;
+const TEST_NUMBER: f64 = 370.5;
+
+// Constant tags, the type on the lower 4 bits and the variant on the upper bits
+const NIL: u8 = 0x00;
+const FALSE: u8 = 0x01;
+const TRUE: u8 = 0x11;
+const INTEGER: u8 = 0x03;
+const FLOAT: u8 = 0x13;
+const SHORT_STRING: u8 = 0x04;
+const LONG_STRING: u8 = 0x14;
+
+/// Line difference marking that the line of an instruction is on the
+/// absolute line information
+const ABSOLUTE_LINE: i8 = i8::MIN;
+/// Most instructions between two absolute lines, so finding the line of
+/// an instruction does not have to go through the whole function
+const MAX_INSTRUCTIONS_WITHOUT_ABSOLUTE_LINE: usize = 128;
+/// Most levels of functions nested on a chunk, like the `LUAI_MAXCCALLS`
+/// of `luac`, reading, writing, and dropping the functions recurse once
+/// for each level
+const MAX_NESTING: usize = 200;
+
+impl Program {
+    /// Checks if `chunk` starts with the signature of a binary chunk
+    pub fn is_binary_chunk(chunk: &[u8]) -> bool {
+        chunk.starts_with(SIGNATURE)
+    }
+
+    /// Loads a binary chunk in the format produced by `luac` 5.4
+    ///
+    /// Upvalues of chunks stripped of their debug information are named `?`,
+    /// and their bytecodes have no lines.
+    pub fn from_bytecode(chunk: &[u8]) -> Result<Self, Error> {
+        Function::from_bytecode(chunk).map(|function| function.program().clone())
+    }
+
+    /// Writes the program as a main function in the binary chunk format
+    /// of `luac` 5.4, on the endianness of the target
+    ///
+    /// The chunk name is written as the source, and the lines of the
+    /// bytecodes, local names, and upvalue names as debug information. The
+    /// lines where functions are defined are not kept by [`Program`], so
+    /// they are written as `0`.
+    pub fn to_bytecode(&self) -> Vec<u8> {
+        Writer::default().main_function(self, 0, true)
+    }
+}
+
+impl Function {
+    /// Loads a binary chunk like [`Program::from_bytecode`], keeping the
+    /// parameters of the function written as its main function
+    pub fn from_bytecode(chunk: &[u8]) -> Result<Self, Error> {
+        let mut reader = Reader::new(chunk);
+        reader.header()?;
+        // Upvalues of the main function
+        reader.byte()?;
+        let (program, arg_count, variadic_args) = reader.function(None)?;
+        if reader.position != chunk.len() {
+            return Err(Error::BinaryChunk("trailing bytes after main function"));
+        }
+        Ok(Function::new(program, arg_count, variadic_args))
+    }
+
+    /// Writes the function as the main function of a binary chunk, like
+    /// [`Program::to_bytecode`] but with its parameters, as `string.dump`
+    /// does
+    ///
+    /// Stripped chunks don't have the chunk name, the lines of the bytecodes,
+    /// and the names of locals and upvalues.
+    pub fn to_bytecode(&self, strip: bool) -> Vec<u8> {
+        Writer {
+            chunk: Vec::new(),
+            strip,
+        }
+        .main_function(self.program(), self.arg_count(), self.variadic_args())
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    chunk: Vec<u8>,
+    /// Whether the debug information is left out
+    strip: bool,
+}
+
+impl Writer {
+    /// Writes a binary chunk with `program` as its main function
+    fn main_function(
+        mut self,
+        program: &Program,
+        arg_count: usize,
+        variadic_args: bool,
+    ) -> Vec<u8> {
+        self.header();
+        self.bytes(&[u8::try_from(program.upvalues.len()).unwrap_or(u8::MAX)]);
+        let source = program.chunk_name.as_ref().map(|name| format!("={name}"));
+        self.function(program, source.as_deref(), arg_count, variadic_args);
+        self.chunk
+    }
+
+    fn header(&mut self) {
+        self.bytes(SIGNATURE);
+        self.bytes(&[VERSION, FORMAT]);
+        self.bytes(DATA);
+        self.bytes(&[INSTRUCTION_SIZE, INTEGER_SIZE, NUMBER_SIZE]);
+        self.bytes(&TEST_INTEGER.to_ne_bytes());
+        self.bytes(&TEST_NUMBER.to_ne_bytes());
+    }
+
+    /// Writes a function prototype, functions without `source` have the
+    /// source of the function that declares them
+    fn function(
+        &mut self,
+        program: &Program,
+        source: Option<&str>,
+        arg_count: usize,
+        variadic_args: bool,
+    ) {
+        // Source name, line defined, and last line defined
+        self.string(source.filter(|_| !self.strip));
+        self.size(0);
+        self.size(0);
+        self.bytes(&[
+            u8::try_from(arg_count).unwrap_or(u8::MAX),
+            u8::from(variadic_args),
+            u8::MAX,
+        ]);
+
+        self.size(program.byte_codes.len());
+        for bytecode in program.byte_codes.iter() {
+            self.bytes(&bytecode.to_ne_bytes());
+        }
+
+        self.size(program.constants.len());
+        for constant in program.constants.iter() {
+            self.constant(constant);
+        }
+
+        self.size(program.upvalue_descriptors.len());
+        for descriptor in program.upvalue_descriptors.iter() {
+            // Upvalues are always regular variables
+            self.bytes(&[u8::from(descriptor.in_stack()), descriptor.index(), 0]);
+        }
+
+        self.size(program.functions.len());
+        for function in program.functions.iter() {
+            self.function(
+                function.program(),
+                None,
+                function.arg_count(),
+                function.variadic_args(),
+            );
+        }
+
+        if self.strip {
+            for _ in 0..4 {
+                self.size(0);
+            }
+            return;
+        }
+
+        self.line_info(program);
+
+        self.size(program.locals.len());
+        for local in program.locals.iter() {
+            self.string(Some(local.name()));
+            self.size(local.scope_start());
+            self.size(local.scope_end());
+        }
+
+        self.size(program.upvalues.len());
+        for upvalue in program.upvalues.iter() {
+            self.string(Some(upvalue));
+        }
+    }
+
+    /// Writes the line of each bytecode as the difference from the line
+    /// before it, starting from the line the function is defined, with
+    /// absolute lines for big differences and every now and then
+    ///
+    /// Bytecodes before the first line, which are only emitted when the
+    /// function starts, are on the line the function is defined.
+    fn line_info(&mut self, program: &Program) {
+        if program.lines.is_empty() {
+            self.size(0);
+            self.size(0);
+            return;
+        }
+
+        let mut differences = Vec::with_capacity(program.byte_codes.len());
+        let mut absolute_lines = Vec::new();
+        let mut previous = 0;
+        let mut without_absolute_line = 0;
+        for index in 0..program.byte_codes.len() {
+            let line = program.line(index).unwrap_or(previous);
+            let difference = i8::try_from(line as isize - previous as isize)
+                .ok()
+                .filter(|difference| *difference != ABSOLUTE_LINE);
+            match difference {
+                Some(difference)
+                    if without_absolute_line < MAX_INSTRUCTIONS_WITHOUT_ABSOLUTE_LINE =>
+                {
+                    differences.push(difference as u8);
+                    without_absolute_line += 1;
+                }
+                _ => {
+                    differences.push(ABSOLUTE_LINE as u8);
+                    absolute_lines.push((index, line));
+                    without_absolute_line = 1;
+                }
+            }
+            previous = line;
+        }
+
+        self.size(differences.len());
+        self.bytes(&differences);
+        self.size(absolute_lines.len());
+        for (index, line) in absolute_lines {
+            self.size(index);
+            self.size(line);
+        }
+    }
+
+    fn constant(&mut self, constant: &Value) {
+        match constant {
+            Value::Nil => self.bytes(&[NIL]),
+            Value::Boolean(false) => self.bytes(&[FALSE]),
+            Value::Boolean(true) => self.bytes(&[TRUE]),
+            Value::Integer(integer) => {
+                self.bytes(&[INTEGER]);
+                self.bytes(&integer.to_ne_bytes());
+            }
+            #[cfg(feature = "float")]
+            Value::Float(float) => {
+                self.bytes(&[FLOAT]);
+                self.bytes(&float.to_ne_bytes());
+            }
+            Value::ShortString(string) => {
+                self.bytes(&[SHORT_STRING]);
+                self.string_bytes(&string[..string.len()]);
+            }
+            Value::String(string) => {
+                self.bytes(&[LONG_STRING]);
+                self.string_bytes(string);
+            }
+            Value::Table(_) | Value::Closure(_) | Value::UserData(_) => {
+                unreachable!("Tables, closures and userdata can't be constants.")
+            }
+        }
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        self.chunk.extend_from_slice(bytes);
+    }
+
+    fn size(&mut self, size: usize) {
+        let mut encoded = [0; usize::BITS.div_ceil(7) as usize];
+        let mut start = encoded.len() - 1;
+        encoded[start] = (size & 0x7f) as u8 | 0x80;
+        let mut size = size >> 7;
+        while size != 0 {
+            start -= 1;
+            encoded[start] = (size & 0x7f) as u8;
+            size >>= 7;
+        }
+        self.bytes(&encoded[start..]);
+    }
+
+    fn string(&mut self, string: Option<&str>) {
+        match string {
+            None => self.size(0),
+            Some(string) => self.string_bytes(string.as_bytes()),
+        }
+    }
+
+    fn string_bytes(&mut self, string: &[u8]) {
+        self.size(string.len() + 1);
+        self.bytes(string);
+    }
+}
+
+struct Reader<'a> {
+    chunk: &'a [u8],
+    position: usize,
+    big_endian: bool,
+    /// Levels of functions being read
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(chunk: &'a [u8]) -> Self {
+        Self {
+            chunk,
+            position: 0,
+            big_endian: false,
+            depth: 0,
+        }
+    }
+
+    fn header(&mut self) -> Result<(), Error> {
+        if self.bytes(SIGNATURE.len())? != SIGNATURE {
+            return Err(Error::BinaryChunk("missing signature"));
+        }
+        if self.byte()? != VERSION {
+            return Err(Error::BinaryChunk("version is not 5.4"));
+        }
+        if self.byte()? != FORMAT {
+            return Err(Error::BinaryChunk("format is not the official format"));
+        }
+        if self.bytes(DATA.len())? != DATA {
+            return Err(Error::BinaryChunk("chunk is corrupted"));
+        }
+        if self.byte()? != INSTRUCTION_SIZE {
+            return Err(Error::BinaryChunk("instructions are not 4 bytes"));
+        }
+        if self.byte()? != INTEGER_SIZE {
+            return Err(Error::BinaryChunk("integers are not 8 bytes"));
+        }
+        if self.byte()? != NUMBER_SIZE {
+            return Err(Error::BinaryChunk("floats are not 8 bytes"));
+        }
+
+        // Chunks are dumped on the endianness of the machine that produced them
+        let test_integer = self.bytes(8)?;
+        if test_integer == TEST_INTEGER.to_le_bytes() {
+            self.big_endian = false;
+        } else if test_integer == TEST_INTEGER.to_be_bytes() {
+            self.big_endian = true;
+        } else {
+            return Err(Error::BinaryChunk("integer format mismatch"));
+        }
+        if self.float()? != TEST_NUMBER {
+            return Err(Error::BinaryChunk("float format mismatch"));
+        }
+        Ok(())
+    }
+
+    /// Reads a function prototype, returning its program, number of fixed
+    /// arguments, and if it is variadic
+    ///
+    /// `parent` is the chunk name of the function that declares it, or
+    /// `None` for the main function.
+    fn function(
+        &mut self,
+        parent: Option<&Option<Rc<str>>>,
+    ) -> Result<(Program, usize, bool), Error> {
+        // Source name, line defined, and last line defined
+        let chunk_name = match self.string()? {
+            Some(source) => Some(Self::chunk_name(source)),
+            None => parent.cloned().flatten(),
+        };
+        let line_defined = self.size()?;
+        self.size()?;
+        let arg_count = usize::from(self.byte()?);
+        let variadic_args = self.byte()? != 0;
+        // Max stack size, the stack grows on demand
+        self.byte()?;
+
+        let byte_codes = (0..self.size()?)
+            .map(|_| self.instruction().map(Bytecode::decode))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let constants = (0..self.size()?)
+            .map(|_| self.constant())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let upvalue_descriptors = (0..self.size()?)
+            .map(|_| {
+                // In stack, index, and kind
+                let descriptor = self.bytes(3)?;
+                Ok(if descriptor[0] != 0 {
+                    UpvalueDescriptor::local(descriptor[1])
+                } else {
+                    UpvalueDescriptor::upvalue(descriptor[1])
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let function_count = self.size()?;
+        if function_count > 0 && self.depth == MAX_NESTING {
+            return Err(Error::BinaryChunk("functions are nested too deeply"));
+        }
+        self.depth += 1;
+        let functions = (0..function_count)
+            .map(|_| {
+                self.function(Some(&chunk_name))
+                    .map(|(program, arg_count, variadic_args)| {
+                        Rc::new(Function::new(program, arg_count, variadic_args))
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.depth -= 1;
+
+        let lines = self.line_info(line_defined, byte_codes.len())?;
+
+        let locals = (0..self.size()?)
+            .map(|_| {
+                let name = self
+                    .string()?
+                    .ok_or(Error::BinaryChunk("local without name"))?;
+                let scope_start = self.size()?;
+                let scope_end = self.size()?;
+                Ok(Local::new(name.into(), scope_start, scope_end))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let upvalue_names = (0..self.size()?)
+            .map(|_| {
+                self.string()?
+                    .map(Box::from)
+                    .ok_or(Error::BinaryChunk("upvalue without name"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let upvalues = if upvalue_names.len() == upvalue_descriptors.len() {
+            upvalue_names
+        } else if upvalue_names.is_empty() {
+            // The main function's only upvalue is `_ENV`
+            let main = parent.is_none();
+            let stripped = |index| if main && index == 0 { "_ENV" } else { "?" };
+            (0..upvalue_descriptors.len())
+                .map(|index| Box::from(stripped(index)))
+                .collect()
+        } else {
+            return Err(Error::BinaryChunk("upvalue names don't match the upvalues"));
+        };
+
+        Ok((
+            Program {
+                byte_codes: byte_codes.into(),
+                constants: constants.into(),
+                locals: locals.into(),
+                upvalues: upvalues.into(),
+                upvalue_descriptors: upvalue_descriptors.into(),
+                functions: functions.into(),
+                lines: lines.into(),
+                chunk_name,
+            },
+            arg_count,
+            variadic_args,
+        ))
+    }
+
+    /// Chunk name of a source, sources starting with `=` or `@` are names,
+    /// and other sources are the code that was loaded
+    fn chunk_name(source: &str) -> Rc<str> {
+        match source.strip_prefix(['=', '@']) {
+            Some(name) => Rc::from(name),
+            None => Rc::from(Program::default_chunk_name(source)),
+        }
+    }
+
+    /// Reads the line of each bytecode, returning the first bytecode of
+    /// each line paired with the line
+    ///
+    /// Bytecodes on line `0`, which only come before the first line of the
+    /// main function, are left without a line.
+    fn line_info(
+        &mut self,
+        line_defined: usize,
+        bytecode_count: usize,
+    ) -> Result<Vec<(usize, usize)>, Error> {
+        let size = self.size()?;
+        let differences = self.bytes(size)?;
+        let absolute_lines = (0..self.size()?)
+            .map(|_| Ok((self.size()?, self.size()?)))
+            .collect::<Result<Vec<_>, Error>>()?;
+        if differences.is_empty() {
+            return Ok(Vec::new());
+        }
+        if differences.len() != bytecode_count {
+            return Err(Error::BinaryChunk("line information is corrupted"));
+        }
+
+        let mut absolute_lines = absolute_lines.into_iter();
+        let mut lines: Vec<(usize, usize)> = Vec::new();
+        let mut line = line_defined;
+        for (index, difference) in differences.iter().map(|byte| *byte as i8).enumerate() {
+            line = if difference == ABSOLUTE_LINE {
+                match absolute_lines.next() {
+                    Some((start, line)) if start == index => line,
+                    _ => return Err(Error::BinaryChunk("line information is corrupted")),
+                }
+            } else {
+                line.checked_add_signed(isize::from(difference))
+                    .ok_or(Error::BinaryChunk("line information is corrupted"))?
+            };
+            if line != 0 && lines.last().is_none_or(|(_, last)| *last != line) {
+                lines.push((index, line));
+            }
+        }
+        if absolute_lines.next().is_some() {
+            return Err(Error::BinaryChunk("line information is corrupted"));
+        }
+        Ok(lines)
+    }
+
+    fn constant(&mut self) -> Result<Value, Error> {
+        match self.byte()? {
+            NIL => Ok(Value::Nil),
+            FALSE => Ok(Value::Boolean(false)),
+            TRUE => Ok(Value::Boolean(true)),
+            INTEGER => self.integer().map(Value::Integer),
+            #[cfg(feature = "float")]
+            FLOAT => self.float().map(Value::Float),
+            #[cfg(not(feature = "float"))]
+            FLOAT => Err(Error::BinaryChunk(
+                "float constant without the `float` feature",
+            )),
+            SHORT_STRING | LONG_STRING => self
+                .string_bytes()?
+                .map(Value::from)
+                .ok_or(Error::BinaryChunk("string constant without value")),
+            _ => Err(Error::BinaryChunk("unknown constant type")),
+        }
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        self.bytes(1).map(|bytes| bytes[0])
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .position
+            .checked_add(count)
+            .and_then(|end| self.chunk.get(self.position..end))
+            .ok_or(Error::BinaryChunk("unexpected end of chunk"))?;
+        self.position += count;
+        Ok(bytes)
+    }
+
+    fn fixed<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.bytes(N)?);
+        if self.big_endian {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+
+    fn instruction(&mut self) -> Result<u32, Error> {
+        self.fixed().map(u32::from_le_bytes)
+    }
+
+    fn integer(&mut self) -> Result<i64, Error> {
+        self.fixed().map(i64::from_le_bytes)
+    }
+
+    fn float(&mut self) -> Result<f64, Error> {
+        self.fixed().map(f64::from_le_bytes)
+    }
+
+    /// Sizes are stored with 7 bits per byte, most significant first,
+    /// with the last byte marked by the highest bit
+    fn size(&mut self) -> Result<usize, Error> {
+        let mut size: usize = 0;
+        loop {
+            let byte = self.byte()?;
+            size = size
+                .checked_mul(1 << 7)
+                .map(|size| size | usize::from(byte & 0x7f))
+                .ok_or(Error::BinaryChunk("size overflows"))?;
+            if byte & 0x80 != 0 {
+                return Ok(size);
+            }
+        }
+    }
+
+    /// Strings are stored with their size plus one, `0` means no string
+    /// Names, which must be valid UTF-8
+    fn string(&mut self) -> Result<Option<&'a str>, Error> {
+        self.string_bytes()?
+            .map(|bytes| core::str::from_utf8(bytes).map_err(|_| Error::StringDecode))
+            .transpose()
+    }
+
+    /// String constants, which can hold any bytes
+    fn string_bytes(&mut self) -> Result<Option<&'a [u8]>, Error> {
+        match self.size()? {
+            0 => Ok(None),
+            size => self.bytes(size - 1).map(Some),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use alloc::{boxed::Box, collections::BTreeSet, rc::Rc};
+
+use crate::value::Value;
+
+/// Storage for string constants shared between [`Program`](super::Program)s
+///
+/// Strings short enough to be stored inline on a [`Value`] are not
+/// allocated, so only longer strings are pooled.
+#[derive(Debug, Default, Clone)]
+pub struct ConstantPool {
+    strings: BTreeSet<Rc<Box<[u8]>>>,
+}
+
+impl ConstantPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of strings in the pool
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Bytes used by the strings in the pool
+    pub fn size(&self) -> usize {
+        self.strings.iter().map(|string| string.len()).sum()
+    }
+
+    /// Whether `string` is the one stored on the pool, and not a copy
+    pub(crate) fn contains(&self, string: &Rc<Box<[u8]>>) -> bool {
+        self.strings
+            .get(string)
+            .is_some_and(|pooled| Rc::ptr_eq(pooled, string))
+    }
+
+    /// Removes strings that are not used by any program
+    pub fn shrink(&mut self) {
+        self.strings.retain(|string| Rc::strong_count(string) > 1);
+    }
+
+    /// Replaces a string constant with the same string from the pool,
+    /// adding new strings to the pool
+    pub(crate) fn intern(&mut self, constant: Value) -> Value {
+        match constant {
+            Value::String(string) => Value::String(self.intern_str(string)),
+            other => other,
+        }
+    }
+
+    fn intern_str(&mut self, string: Rc<Box<[u8]>>) -> Rc<Box<[u8]>> {
+        if let Some(pooled) = self.strings.get(&string) {
+            pooled.clone()
+        } else {
+            self.strings.insert(string.clone());
+            string
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt::{Debug, Display};
+
+use crate::{bytecode::Bytecode, value::Value};
+
+use super::{Local, Program, UpvalueDescriptor};
+
+/// Structural differences between two [`Program`]s
+///
+/// Only the first difference of each section of each prototype is
+/// reported, since an inserted or removed item shifts every item after it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProgramDiff {
+    differences: Vec<Difference>,
+}
+
+impl ProgramDiff {
+    /// Checks if the programs are structurally equal
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    pub fn differences(&self) -> &[Difference] {
+        &self.differences
+    }
+}
+
+impl Display for ProgramDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.differences.is_empty() {
+            return write!(f, "Programs are equal.");
+        }
+        for (i, difference) in self.differences.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{difference}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A difference between two prototypes
+///
+/// `path` is the position of the prototype, as a list of indexes into the
+/// nested functions, starting from the main chunk.
+/// A side is `None` when the item is missing from that program.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Difference {
+    /// Nested functions take a different number of arguments or
+    /// disagree on variadic arguments
+    Signature {
+        path: Vec<usize>,
+        lhs: (usize, bool),
+        rhs: (usize, bool),
+    },
+    ByteCode {
+        path: Vec<usize>,
+        index: usize,
+        lhs: Option<Bytecode>,
+        rhs: Option<Bytecode>,
+    },
+    Constant {
+        path: Vec<usize>,
+        index: usize,
+        lhs: Option<Value>,
+        rhs: Option<Value>,
+    },
+    Local {
+        path: Vec<usize>,
+        index: usize,
+        lhs: Option<Local>,
+        rhs: Option<Local>,
+    },
+    Upvalue {
+        path: Vec<usize>,
+        index: usize,
+        lhs: Option<Box<str>>,
+        rhs: Option<Box<str>>,
+    },
+    /// Upvalues are found in a different place when closures are created
+    UpvalueDescriptor {
+        path: Vec<usize>,
+        index: usize,
+        lhs: Option<UpvalueDescriptor>,
+        rhs: Option<UpvalueDescriptor>,
+    },
+    FunctionCount {
+        path: Vec<usize>,
+        lhs: usize,
+        rhs: usize,
+    },
+}
+
+impl Display for Difference {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Signature { path, lhs, rhs } => {
+                write_path(f, path)?;
+                write!(
+                    f,
+                    "signature: (args: {}, variadic: {}) != (args: {}, variadic: {})",
+                    lhs.0, lhs.1, rhs.0, rhs.1
+                )
+            }
+            Self::ByteCode {
+                path,
+                index,
+                lhs,
+                rhs,
+            } => {
+                write_path(f, path)?;
+                write!(f, "bytecode #{index}: ")?;
+                write_sides(f, lhs, rhs)
+            }
+            Self::Constant {
+                path,
+                index,
+                lhs,
+                rhs,
+            } => {
+                write_path(f, path)?;
+                write!(f, "constant #{index}: ")?;
+                write_sides(f, lhs, rhs)
+            }
+            Self::Local {
+                path,
+                index,
+                lhs,
+                rhs,
+            } => {
+                write_path(f, path)?;
+                write!(f, "local #{index}: ")?;
+                write_sides(f, lhs, rhs)
+            }
+            Self::Upvalue {
+                path,
+                index,
+                lhs,
+                rhs,
+            } => {
+                write_path(f, path)?;
+                write!(f, "upvalue #{index}: ")?;
+                write_sides(f, lhs, rhs)
+            }
+            Self::UpvalueDescriptor {
+                path,
+                index,
+                lhs,
+                rhs,
+            } => {
+                write_path(f, path)?;
+                write!(f, "upvalue descriptor #{index}: ")?;
+                write_sides(f, lhs, rhs)
+            }
+            Self::FunctionCount { path, lhs, rhs } => {
+                write_path(f, path)?;
+                write!(f, "function count: {lhs} != {rhs}")
+            }
+        }
+    }
+}
+
+fn write_path(f: &mut core::fmt::Formatter<'_>, path: &[usize]) -> core::fmt::Result {
+    write!(f, "main")?;
+    for index in path {
+        write!(f, ".functions[{index}]")?;
+    }
+    write!(f, " ")
+}
+
+fn write_sides<T: Debug>(
+    f: &mut core::fmt::Formatter<'_>,
+    lhs: &Option<T>,
+    rhs: &Option<T>,
+) -> core::fmt::Result {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => write!(f, "{lhs:?} != {rhs:?}"),
+        (Some(lhs), None) => write!(f, "{lhs:?} only on left"),
+        (None, Some(rhs)) => write!(f, "{rhs:?} only on right"),
+        (None, None) => unreachable!("A difference should have at least one side."),
+    }
+}
+
+impl Program {
+    /// Compares the instructions, constants, locals, upvalues, and nested
+    /// functions of two programs
+    pub fn diff(&self, other: &Program) -> ProgramDiff {
+        let mut diff = ProgramDiff::default();
+        diff_program(&mut diff.differences, &mut Vec::new(), self, other);
+        diff
+    }
+}
+
+fn diff_program(
+    differences: &mut Vec<Difference>,
+    path: &mut Vec<usize>,
+    lhs: &Program,
+    rhs: &Program,
+) {
+    if let Some((index, lhs, rhs)) =
+        first_difference(&lhs.byte_codes, &rhs.byte_codes, |l, r| l == r)
+    {
+        differences.push(Difference::ByteCode {
+            path: path.clone(),
+            index,
+            lhs,
+            rhs,
+        });
+    }
+    if let Some((index, lhs, rhs)) = first_difference(&lhs.constants, &rhs.constants, constant_eq) {
+        differences.push(Difference::Constant {
+            path: path.clone(),
+            index,
+            lhs,
+            rhs,
+        });
+    }
+    if let Some((index, lhs, rhs)) = first_difference(&lhs.locals, &rhs.locals, |l, r| l == r) {
+        differences.push(Difference::Local {
+            path: path.clone(),
+            index,
+            lhs,
+            rhs,
+        });
+    }
+    if let Some((index, lhs, rhs)) = first_difference(&lhs.upvalues, &rhs.upvalues, |l, r| l == r) {
+        differences.push(Difference::Upvalue {
+            path: path.clone(),
+            index,
+            lhs,
+            rhs,
+        });
+    }
+    if let Some((index, lhs, rhs)) = first_difference(
+        &lhs.upvalue_descriptors,
+        &rhs.upvalue_descriptors,
+        |l, r| l == r,
+    ) {
+        differences.push(Difference::UpvalueDescriptor {
+            path: path.clone(),
+            index,
+            lhs,
+            rhs,
+        });
+    }
+    if lhs.functions.len() != rhs.functions.len() {
+        differences.push(Difference::FunctionCount {
+            path: path.clone(),
+            lhs: lhs.functions.len(),
+            rhs: rhs.functions.len(),
+        });
+    }
+
+    // Functions that are present on both sides are still compared
+    for (index, (lhs, rhs)) in lhs.functions.iter().zip(rhs.functions.iter()).enumerate() {
+        path.push(index);
+        let lhs_signature = (lhs.arg_count(), lhs.variadic_args());
+        let rhs_signature = (rhs.arg_count(), rhs.variadic_args());
+        if lhs_signature != rhs_signature {
+            differences.push(Difference::Signature {
+                path: path.clone(),
+                lhs: lhs_signature,
+                rhs: rhs_signature,
+            });
+        }
+        diff_program(differences, path, lhs.program(), rhs.program());
+        path.pop();
+    }
+}
+
+/// Finds the first index where the slices differ, including the
+/// case where one of the slices is shorter
+fn first_difference<T: Clone>(
+    lhs: &[T],
+    rhs: &[T],
+    eq: impl Fn(&T, &T) -> bool,
+) -> Option<(usize, Option<T>, Option<T>)> {
+    (0..lhs.len().max(rhs.len())).find_map(|index| match (lhs.get(index), rhs.get(index)) {
+        (Some(l), Some(r)) if eq(l, r) => None,
+        (l, r) => Some((index, l.cloned(), r.cloned())),
+    })
+}
+
+fn constant_eq(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        // `NaN` constants are the same constant, and `0.0` is not `-0.0`
+        #[cfg(feature = "float")]
+        (Value::Float(lhs), Value::Float(rhs)) => lhs.to_bits() == rhs.to_bits(),
+        (lhs, rhs) => lhs == rhs,
+    }
+}
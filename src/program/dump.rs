@@ -0,0 +1,220 @@
+//! Listing of programs, like `luac -l`
+//!
+//! Each function lists its bytecodes, with their line, opcode name and
+//! operands, followed by its constants, locals, and upvalues, with where
+//! closures find them, and then its nested functions.
+//! Operands that refer to constants and upvalues are followed by
+//! a comment with their value, and jumps by the bytecode they jump to.
+
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+
+use crate::{
+    bytecode::{Bytecode, OpCode},
+    value::Value,
+};
+
+use super::Program;
+
+impl Display for Program {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_function(f, self, &mut Vec::new(), (0, true))
+    }
+}
+
+fn write_function(
+    f: &mut core::fmt::Formatter<'_>,
+    program: &Program,
+    path: &mut Vec<usize>,
+    (arg_count, variadic): (usize, bool),
+) -> core::fmt::Result {
+    // Like `luac -l`, the header of the main chunk is `main` and the
+    // header of the functions it declares is `function`
+    let kind = if path.is_empty() { "main" } else { "function" };
+    write!(f, "{kind} <main")?;
+    for index in path.iter() {
+        write!(f, ".functions[{index}]")?;
+    }
+    writeln!(
+        f,
+        "> ({} bytecodes, {} params{})",
+        program.byte_codes.len(),
+        arg_count,
+        if variadic { ", variadic" } else { "" }
+    )?;
+
+    for (i, bytecode) in program.byte_codes.iter().enumerate() {
+        write!(f, "\t{}\t", i + 1)?;
+        match program.line(i) {
+            Some(line) => write!(f, "[{line}]\t")?,
+            None => write!(f, "[-]\t")?,
+        }
+        let Some(op) = bytecode.opcode() else {
+            writeln!(f, "{bytecode:?}")?;
+            continue;
+        };
+        if op == OpCode::ZeroReturn {
+            writeln!(f, "{}", op.name())?;
+            continue;
+        }
+        write!(f, "{:<9}\t", op.name())?;
+        bytecode.fmt_operands(f, " ")?;
+        write_comment(f, program, i, bytecode, op)?;
+        writeln!(f)?;
+    }
+
+    writeln!(f, "constants ({}):", program.constants.len())?;
+    for (i, constant) in program.constants.iter().enumerate() {
+        write!(f, "\t{i}\t")?;
+        write_constant(f, constant)?;
+        writeln!(f)?;
+    }
+    writeln!(f, "locals ({}):", program.locals.len())?;
+    for (i, local) in program.locals.iter().enumerate() {
+        writeln!(
+            f,
+            "\t{i}\t{}\t{}\t{}",
+            local.name(),
+            local.scope_start(),
+            local.scope_end()
+        )?;
+    }
+    writeln!(f, "upvalues ({}):", program.upvalues.len())?;
+    for (i, (upvalue, descriptor)) in program
+        .upvalues
+        .iter()
+        .zip(program.upvalue_descriptors.iter())
+        .enumerate()
+    {
+        writeln!(
+            f,
+            "\t{i}\t{upvalue}\t{}\t{}",
+            u8::from(descriptor.in_stack()),
+            descriptor.index()
+        )?;
+    }
+
+    for (i, function) in program.functions.iter().enumerate() {
+        writeln!(f)?;
+        path.push(i);
+        write_function(
+            f,
+            function.program(),
+            path,
+            (function.arg_count(), function.variadic_args()),
+        )?;
+        path.pop();
+    }
+    Ok(())
+}
+
+/// Writes the constants and upvalues used by the bytecode, or
+/// where it jumps to
+fn write_comment(
+    f: &mut core::fmt::Formatter<'_>,
+    program: &Program,
+    index: usize,
+    bytecode: &Bytecode,
+    op: OpCode,
+) -> core::fmt::Result {
+    let constant =
+        |f: &mut core::fmt::Formatter<'_>, constant: usize| match program.constants.get(constant) {
+            Some(constant) => write_constant(f, constant),
+            None => write!(f, "?"),
+        };
+    let upvalue =
+        |f: &mut core::fmt::Formatter<'_>, upvalue: usize| match program.upvalues.get(upvalue) {
+            Some(upvalue) => write!(f, "{upvalue}"),
+            None => write!(f, "?"),
+        };
+
+    match op {
+        OpCode::LoadConstant => {
+            let (_, bx) = bytecode.decode_abx();
+            write!(f, "\t; ")?;
+            constant(f, *bx as usize)
+        }
+        // The id of the constant is on the `EXTRAARG` that follows
+        OpCode::LoadConstantExtraArgs => match program.byte_codes.get(index + 1) {
+            Some(extra) if extra.opcode() == Some(OpCode::ExtraArguments) => {
+                write!(f, "\t; ")?;
+                constant(f, *extra.decode_ax() as usize)
+            }
+            _ => Ok(()),
+        },
+        OpCode::GetUpValue | OpCode::SetUpValue => {
+            let (_, b, _, _) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            upvalue(f, usize::from(*b))
+        }
+        OpCode::GetUpTable => {
+            let (_, b, c, _) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            upvalue(f, usize::from(*b))?;
+            write!(f, " ")?;
+            constant(f, usize::from(*c))
+        }
+        OpCode::SetUpTable => {
+            let (a, b, c, k) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            upvalue(f, usize::from(*a))?;
+            write!(f, " ")?;
+            constant(f, usize::from(*b))?;
+            if *k {
+                write!(f, " ")?;
+                constant(f, usize::from(*c))?;
+            }
+            Ok(())
+        }
+        OpCode::GetField => {
+            let (_, _, c, _) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            constant(f, usize::from(*c))
+        }
+        OpCode::SetField => {
+            let (_, b, c, k) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            constant(f, usize::from(*b))?;
+            if *k {
+                write!(f, " ")?;
+                constant(f, usize::from(*c))?;
+            }
+            Ok(())
+        }
+        OpCode::AddConstant
+        | OpCode::SubConstant
+        | OpCode::MulConstant
+        | OpCode::ModConstant
+        | OpCode::PowConstant
+        | OpCode::DivConstant
+        | OpCode::IDivConstant
+        | OpCode::BitAndConstant
+        | OpCode::BitOrConstant
+        | OpCode::BitXorConstant => {
+            let (_, _, c, _) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            constant(f, usize::from(*c))
+        }
+        OpCode::EqualConstant => {
+            let (_, b, _, _) = bytecode.decode_abck();
+            write!(f, "\t; ")?;
+            constant(f, usize::from(*b))
+        }
+        OpCode::Jump => {
+            let sj = bytecode.decode_sj();
+            // Jumps are relative to the next bytecode
+            write!(f, "\t; to {}", index as i64 + 2 + i64::from(*sj))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn write_constant(f: &mut core::fmt::Formatter<'_>, constant: &Value) -> core::fmt::Result {
+    match constant {
+        Value::ShortString(_) | Value::String(_) => write!(f, "\"{constant}\""),
+        #[cfg(feature = "float")]
+        Value::Float(float) => write!(f, "{float:?}"),
+        _ => write!(f, "{constant}"),
+    }
+}
@@ -1,10 +1,14 @@
 use core::{fmt::Display, num::TryFromIntError};
 
+use alloc::{boxed::Box, rc::Rc};
+
 use crate::bytecode::arguments::BytecodeArgumentError;
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
     Parse,
+    /// Expressions, blocks, and functions are nested too deeply
+    TooManyLevels,
     StringDecode,
     OrphanExp,
     IncompatibleConditional,
@@ -34,7 +38,21 @@ pub enum Error {
     UnmatchedGoto,
     IntCoversion,
     GotoIntoScope,
+    UnknownAttribute,
+    MultipleToBeClosed,
+    AssignToConst,
+    /// Float numeral on a build without the `float` feature
+    FloatNumeral,
     BytecodeArgument(BytecodeArgumentError),
+    // Binary chunks
+    BinaryChunk(&'static str),
+    /// Error on a line of a chunk parsed with [`Program::parse`](crate::Program::parse)
+    /// or [`Program::parse_named`](crate::Program::parse_named)
+    Located {
+        chunk: Rc<str>,
+        line: usize,
+        error: Box<Error>,
+    },
 }
 
 impl Display for Error {
@@ -43,6 +61,9 @@ impl Display for Error {
             Self::Parse => {
                 write!(f, "Could not parse program.")
             }
+            Self::TooManyLevels => {
+                write!(f, "Too many syntax levels.")
+            }
             Self::StringDecode => {
                 write!(f, "Failed to decode string.")
             }
@@ -112,6 +133,18 @@ impl Display for Error {
             Self::GotoIntoScope => {
                 write!(f, "Jumping into scope of local.")
             }
+            Self::UnknownAttribute => {
+                write!(f, "Unknown attribute, expected `const` or `close`.")
+            }
+            Self::MultipleToBeClosed => {
+                write!(f, "Multiple to-be-closed variables in local list.")
+            }
+            Self::AssignToConst => {
+                write!(f, "Attempt to assign to const variable.")
+            }
+            Self::FloatNumeral => {
+                write!(f, "Float numerals need the `float` feature.")
+            }
             Self::IntCoversion => {
                 write!(f, "Failed to convert an integer.")
             }
@@ -125,16 +158,43 @@ impl Display for Error {
                     arg
                 )
             }
+            Self::BinaryChunk(reason) => {
+                write!(f, "Invalid binary chunk, {}.", reason)
+            }
+            Self::Located { chunk, line, error } => {
+                write!(f, "{}:{}: {}", chunk, line, error)
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Located { error, .. } => Some(error.as_ref()),
+            _ => None,
         }
     }
 }
 
-impl core::error::Error for Error {}
+impl Error {
+    /// Error without the chunk and the line it was found on, unwrapping
+    /// [`Error::Located`]
+    pub fn unlocated(self) -> Self {
+        match self {
+            Self::Located { error, .. } => *error,
+            error => error,
+        }
+    }
+}
 
 impl From<crate::parser::Error> for Error {
     fn from(value: crate::parser::Error) -> Self {
         log::error!(target: "no_deps_lua::parser", "{:?}", value);
-        Self::Parse
+        match value {
+            crate::parser::Error::TooManyLevels => Self::TooManyLevels,
+            _ => Self::Parse,
+        }
     }
 }
 
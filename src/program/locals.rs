@@ -24,6 +24,16 @@ impl Local {
         (self.scope_start..self.scope_end).contains(&program_counter)
     }
 
+    /// First bytecode where the local is in scope, starting at 1
+    pub fn scope_start(&self) -> usize {
+        self.scope_start
+    }
+
+    /// Bytecode after the last one where the local is in scope
+    pub fn scope_end(&self) -> usize {
+        self.scope_end
+    }
+
     pub(crate) fn new_no_end(name: Box<str>, scope_start: usize) -> Self {
         Self {
             name,
@@ -40,4 +50,11 @@ impl Local {
         );
         self.scope_end = scope_end;
     }
+
+    /// Moves the scope after bytecodes were removed
+    #[cfg(feature = "peephole")]
+    pub(crate) fn relocate(&mut self, scope_start: usize, scope_end: usize) {
+        self.scope_start = scope_start;
+        self.scope_end = scope_end;
+    }
 }
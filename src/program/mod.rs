@@ -1,36 +1,187 @@
+mod binary_chunk;
+mod constant_pool;
+mod diff;
+mod dump;
 mod error;
 mod locals;
 mod proto;
 #[cfg(test)]
 mod tests;
+mod upvalues;
 
-use alloc::{boxed::Box, rc::Rc};
+use alloc::{boxed::Box, format, rc::Rc, string::String, vec::Vec};
 
-use crate::{bytecode::Bytecode, function::Function};
+use crate::{
+    bytecode::Bytecode,
+    function::Function,
+    parser::{CompileError, Parser},
+};
 
 use super::value::Value;
 
+pub use constant_pool::ConstantPool;
+pub use diff::{Difference, ProgramDiff};
 pub use error::Error;
 pub use locals::Local;
 use proto::Proto;
+pub use upvalues::UpvalueDescriptor;
 
+/// Compiled chunk, its [`Display`](core::fmt::Display) lists its
+/// bytecodes like `luac -l`
 #[derive(Debug, Default, Clone)]
 pub struct Program {
     pub(super) byte_codes: Rc<[Bytecode]>,
     pub(super) constants: Rc<[Value]>,
     pub(super) locals: Rc<[Local]>,
     pub(super) upvalues: Rc<[Box<str>]>,
+    /// Where each upvalue is found when a closure of the program is created
+    pub(super) upvalue_descriptors: Rc<[UpvalueDescriptor]>,
     pub(super) functions: Rc<[Rc<Function>]>,
+    /// First bytecode of each line, paired with the line, empty
+    /// if the program has no line information
+    pub(super) lines: Rc<[(usize, usize)]>,
+    /// Name of the chunk the program came from, used to locate errors
+    pub(super) chunk_name: Option<Rc<str>>,
 }
 
+/// Most bytes of a source kept on its default chunk name, so the name
+/// fits the 60 bytes of `LUA_IDSIZE` with its brackets and quotes
+const CHUNK_NAME_SOURCE_LENGTH: usize = 45;
+
 impl Program {
+    /// Parses a program, naming its chunk after its source, see
+    /// [`Program::parse_named`] and [`Program::default_chunk_name`]
+    ///
+    /// Compiling stops on the first error, use [`Program::check`] to
+    /// find all syntax errors of the program.
     pub fn parse(program: &str) -> Result<Self, Error> {
-        Proto::parse(program).map(Program::from)
+        Self::parse_named(program, &Self::default_chunk_name(program))
+    }
+
+    /// Name that the reference implementation gives to chunks loaded
+    /// from strings, `[string "source"]`
+    ///
+    /// Only the first line of the source is kept, and only its first
+    /// bytes if it is long, followed by `...` if anything was left out.
+    pub fn default_chunk_name(source: &str) -> String {
+        let first_line = source.split('\n').next().unwrap_or_default();
+        if first_line.len() == source.len() && source.len() < CHUNK_NAME_SOURCE_LENGTH {
+            return format!("[string \"{source}\"]");
+        }
+        let mut end = first_line.len().min(CHUNK_NAME_SOURCE_LENGTH);
+        while !first_line.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("[string \"{}...\"]", &first_line[..end])
+    }
+
+    /// Parses a program, naming its chunk `chunk_name`
+    ///
+    /// Errors found while compiling the program, and errors raised while
+    /// running it, are wrapped on [`Error::Located`] and
+    /// [`crate::Error::Located`], with messages starting with `chunk_name:line:`.
+    pub fn parse_named(program: &str, chunk_name: &str) -> Result<Self, Error> {
+        Self::parse_named_with_pool(program, chunk_name, &mut ConstantPool::new())
+    }
+
+    /// Name of the chunk the program was parsed from, or `None` if it
+    /// was not parsed, like programs loaded from stripped binary chunks
+    pub fn chunk_name(&self) -> Option<&str> {
+        self.chunk_name.as_deref()
+    }
+
+    /// Checks that `program` is syntactically valid without generating
+    /// bytecode, for editors and hooks that only validate sources
+    ///
+    /// After a syntax error the parser skips to the next statement and
+    /// keeps checking, so all errors are returned, in the order they
+    /// appear. Errors only found while compiling, like `break` outside
+    /// of a loop, are not reported.
+    pub fn check(program: &str) -> Result<(), Vec<CompileError>> {
+        Parser::check(program)
+    }
+
+    /// Parses a program, sharing its string constants with other
+    /// programs parsed with the same [`ConstantPool`]
+    pub fn parse_with_pool(program: &str, pool: &mut ConstantPool) -> Result<Self, Error> {
+        Self::parse_named_with_pool(program, &Self::default_chunk_name(program), pool)
+    }
+
+    /// Parses a program like [`Program::parse_named`], sharing its string
+    /// constants with other programs parsed with the same [`ConstantPool`]
+    pub fn parse_named_with_pool(
+        program: &str,
+        chunk_name: &str,
+        pool: &mut ConstantPool,
+    ) -> Result<Self, Error> {
+        let chunk_name = Rc::from(chunk_name);
+        Proto::parse(program, Some(&chunk_name), pool)
+            .map(Program::from)
+            .map(|program| program.with_chunk_name(&chunk_name))
+    }
+
+    fn with_chunk_name(self, chunk_name: &Rc<str>) -> Program {
+        let functions = self
+            .functions
+            .iter()
+            .map(|function| {
+                Rc::new(Function::new(
+                    function.program().clone().with_chunk_name(chunk_name),
+                    function.arg_count(),
+                    function.variadic_args(),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        Program {
+            functions: functions.into(),
+            chunk_name: Some(chunk_name.clone()),
+            ..self
+        }
     }
 
     pub fn read_bytecode(&self, index: usize) -> Option<Bytecode> {
         self.byte_codes.get(index).copied()
     }
+
+    /// Constants used by the bytecodes, in the order of their indexes
+    pub fn constants(&self) -> impl ExactSizeIterator<Item = &Value> {
+        self.constants.iter()
+    }
+
+    /// Locals declared by the program, with the bytecodes where they
+    /// are in scope
+    pub fn locals(&self) -> &[Local] {
+        &self.locals
+    }
+
+    /// Names of the upvalues captured by the program, in the order of
+    /// their indexes
+    pub fn upvalues(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.upvalues.iter().map(Box::as_ref)
+    }
+
+    /// Where each upvalue is found when a closure of the program is created,
+    /// in the order of their indexes
+    pub fn upvalue_descriptors(&self) -> &[UpvalueDescriptor] {
+        &self.upvalue_descriptors
+    }
+
+    /// Functions declared by the program, in the order of their indexes
+    pub fn functions(&self) -> impl ExactSizeIterator<Item = &Function> {
+        self.functions.iter().map(Rc::as_ref)
+    }
+
+    /// Line of the source that generated the bytecode at `index`, or `None`
+    /// if the program has no line information, like programs loaded from
+    /// stripped binary chunks
+    pub fn line(&self, index: usize) -> Option<usize> {
+        if index >= self.byte_codes.len() {
+            return None;
+        }
+        let after = self.lines.partition_point(|(start, _)| *start <= index);
+        after.checked_sub(1).map(|line| self.lines[line].1)
+    }
 }
 
 impl From<Proto> for Program {
@@ -40,7 +191,10 @@ impl From<Proto> for Program {
             constants: proto.constants.into(),
             locals: proto.locals.into(),
             upvalues: proto.upvalues.into(),
+            upvalue_descriptors: proto.upvalue_descriptors.into(),
             functions: proto.functions.into(),
+            lines: proto.lines.into(),
+            chunk_name: None,
         }
     }
 }
@@ -27,6 +27,29 @@ pub enum Binop {
     NotEqual,
 }
 
+impl Binop {
+    /// Id of the metamethod of an arithmetic or bitwise operation, numbered
+    /// like `TMS` on the reference implementation, for `MMBIN`, `MMBINI`
+    /// and `MMBINK`
+    pub fn metamethod_event(&self) -> u8 {
+        match self {
+            Self::Add => 6,
+            Self::Sub => 7,
+            Self::Mul => 8,
+            Self::Mod => 9,
+            Self::Pow => 10,
+            Self::Div => 11,
+            Self::Idiv => 12,
+            Self::BitAnd => 13,
+            Self::BitOr => 14,
+            Self::BitXor => 15,
+            Self::ShiftLeft => 16,
+            Self::ShiftRight => 17,
+            other => unreachable!("{:?} has no binary metamethod.", other),
+        }
+    }
+}
+
 impl TryFrom<TokenType<'_>> for Binop {
     type Error = Error;
 
@@ -9,12 +9,19 @@ pub struct CompileContext<'a> {
     pub stack_top: u8,
     pub var_args: Option<bool>,
     pub locals: Vec<Box<str>>,
-    pub breaks: Option<Vec<usize>>,
+    pub breaks: Option<Vec<Break>>,
     pub gotos: Vec<GotoLabel<'a>>,
     pub labels: Vec<GotoLabel<'a>>,
     pub jumps_to_block: Vec<usize>,
     pub jumps_to_end: Vec<usize>,
     pub captured_locals: BTreeSet<usize>,
+    /// Locals declared with `<close>` that are still in scope
+    pub to_be_closed: Vec<usize>,
+    /// Locals declared with `<const>` or `<close>`, which can't be assigned to
+    pub read_only_locals: BTreeSet<usize>,
+    /// Locals declared with `<const>` whose value is known at compile time,
+    /// these don't take a register and are replaced by their value
+    pub constants: Vec<CompileTimeConstant<'a>>,
 }
 
 impl<'a> CompileContext<'a> {
@@ -25,14 +32,36 @@ impl<'a> CompileContext<'a> {
         }
     }
 
-    pub fn reserve_stack_top(&mut self) -> (u8, ExpDesc<'a>) {
+    /// Reserves the register on the top of the stack, failing with
+    /// [`Error::StackOverflow`] once all 256 registers are in use
+    pub fn reserve_stack_top(&mut self) -> Result<(u8, ExpDesc<'a>), Error> {
         let top = self.stack_top;
-        self.stack_top += 1;
-        (top, ExpDesc::Local(usize::from(top)))
+        self.stack_top = top.checked_add(1).ok_or(Error::StackOverflow)?;
+        Ok((top, ExpDesc::Local(usize::from(top))))
     }
 
     pub fn find_name(&self, name: &'a str) -> Option<usize> {
-        self.locals.iter().rposition(|local| local.as_ref() == name)
+        let local = self
+            .locals
+            .iter()
+            .rposition(|local| local.as_ref() == name)?;
+        // A constant declared after the local shadows it
+        (!self
+            .constants
+            .iter()
+            .any(|constant| constant.name.as_ref() == name && constant.declared_at > local))
+        .then_some(local)
+    }
+
+    pub fn find_constant(&self, name: &'a str) -> Option<&ExpDesc<'a>> {
+        let constant = self
+            .constants
+            .iter()
+            .rfind(|constant| constant.name.as_ref() == name)?;
+        match self.locals.iter().rposition(|local| local.as_ref() == name) {
+            Some(local) if local >= constant.declared_at => None,
+            _ => Some(&constant.value),
+        }
     }
 
     pub fn push_goto(&mut self, goto_label: GotoLabel<'a>) {
@@ -56,19 +85,16 @@ impl<'a> CompileContext<'a> {
         self.captured_locals.insert(local);
     }
 
-    pub fn clear_captures_above(&mut self, first_local: usize) -> bool {
-        if let Some(max) = self
-            .captured_locals
-            .iter()
-            .max()
-            .filter(|local| **local >= first_local)
-            .copied()
-        {
-            self.captured_locals.retain(|local| *local < max);
-            true
-        } else {
-            false
-        }
+    pub fn clear_captures_above(&mut self, first_local: usize) {
+        self.captured_locals.retain(|local| *local < first_local);
+    }
+
+    /// Whether leaving the scope of the locals from `first_local` onwards
+    /// needs a `CLOSE`, because one of them is captured by a closure or
+    /// was declared with `<close>`
+    pub fn needs_close(&self, first_local: usize) -> bool {
+        self.captured_locals.range(first_local..).next().is_some()
+            || self.to_be_closed.iter().any(|local| *local >= first_local)
     }
 }
 
@@ -77,4 +103,23 @@ pub struct GotoLabel<'a> {
     pub name: &'a str,
     pub bytecode: usize,
     pub nvar: usize,
+    /// For gotos, whether they leave the scope of a local that needs
+    /// a `CLOSE`, for labels, whether they start with that `CLOSE`
+    pub close: bool,
+}
+
+/// `JMP` of a `break`, waiting for the end of its loop
+#[derive(Debug, Clone, Copy)]
+pub struct Break {
+    pub bytecode: usize,
+    /// Whether it leaves the scope of a local that needs a `CLOSE`
+    pub close: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CompileTimeConstant<'a> {
+    pub name: Box<str>,
+    pub value: ExpDesc<'a>,
+    /// Number of locals in scope when the constant was declared
+    pub declared_at: usize,
 }
@@ -5,16 +5,19 @@ use crate::{
         Bytecode, OpCode,
         arguments::{B, Bx, BytecodeArgument, C, Sj},
     },
+    ext::Unescape,
     function::Function,
     parser::{Token, TokenType},
-    program::{Error, Local},
+    program::{ConstantPool, Error, Local, UpvalueDescriptor},
+    value::Value,
 };
 
 use super::{
     Proto,
-    compile_context::{CompileContext, GotoLabel},
+    compile_context::{Break, CompileContext, CompileTimeConstant, GotoLabel},
+    constant_folding,
     exp_desc::ExpDesc,
-    helper_types::{FunctionNameList, ParList, TableFields, TableKey},
+    helper_types::{AttNameList, Attrib, FunctionNameList, ParList, TableFields, TableKey},
     unops,
 };
 
@@ -23,6 +26,7 @@ macro_rules! make_deconstruct {
         [$($name @ Token {
             tokens: _,
             token_type: $token,
+            line: _,
         },)+]
     };
 }
@@ -32,10 +36,22 @@ type NameList<'a> = Vec<Box<str>>;
 
 pub struct CompileStack<'a> {
     pub stack: Vec<CompileFrame<'a>>,
+    /// Pool the string constants of all functions are shared with
+    pub pool: &'a mut ConstantPool,
 }
 
 pub struct CompileStackView<'a, 'b> {
     pub stack: &'b mut [CompileFrame<'a>],
+    pub pool: &'b mut ConstantPool,
+}
+
+/// Gotos, labels, breaks, and locals declared before a block, the ones
+/// after them belong to the block
+pub struct BlockScope {
+    gotos: usize,
+    labels: usize,
+    breaks: usize,
+    locals: usize,
 }
 
 #[derive(Debug)]
@@ -59,6 +75,13 @@ impl<'a> CompileStack<'a> {
         &mut frame.compile_context
     }
 
+    /// Adds a constant to the function being compiled, taking strings
+    /// from the pool
+    pub fn push_constant(&mut self, value: impl Into<Value>) -> Result<u32, Error> {
+        let value = self.pool.intern(value.into());
+        self.proto_mut().push_constant(value)
+    }
+
     pub fn frame_mut(&mut self) -> &mut CompileFrame<'a> {
         let Some(frame) = self.stack.last_mut() else {
             unreachable!("CompileStack should never be empty.");
@@ -69,35 +92,68 @@ impl<'a> CompileStack<'a> {
     pub fn view<'b>(&'b mut self) -> CompileStackView<'a, 'b> {
         CompileStackView {
             stack: self.stack.as_mut_slice(),
+            pool: self.pool,
         }
     }
 
-    // Non-terminals
-    pub fn chunk(&mut self, chunk: &Token<'a>) -> Result<(), Error> {
-        match chunk.tokens.as_slice() {
-            make_deconstruct!(block(TokenType::Block)) => {
-                self.proto_mut().push_upvalue("_ENV");
-
-                self.block(block)?;
-
-                self.proto_mut().byte_codes.push(Bytecode::zero_return());
-                self.fix_up_last_return(0)?;
+    /// Starts compiling the main function, its statements are
+    /// compiled by [`Self::chunk_stat`] as they are parsed, and the
+    /// rest of the chunk by [`Self::chunk`]
+    pub fn chunk_start(&mut self) -> Result<BlockScope, Error> {
+        self.proto_mut()
+            .push_upvalue("_ENV", UpvalueDescriptor::environment());
+        self.open_block()
+    }
 
-                self.close_locals(0);
+    /// Compiles a statement of the main block
+    pub fn chunk_stat(&mut self, stat: &Token<'a>) -> Result<(), Error> {
+        self.stat(stat)
+    }
 
-                if self.compile_context_mut().gotos.is_empty() {
-                    Ok(())
-                } else {
-                    for goto in self.compile_context_mut().gotos.iter() {
-                        log::error!(
-                            target: "no_deps_lua::parser",
-                            "Goto `{}` did not point to a label.",
-                            goto.name
-                        );
+    // Non-terminals
+    pub fn chunk(&mut self, chunk: &Token<'a>, scope: BlockScope) -> Result<(), Error> {
+        match chunk.tokens.as_slice() {
+            make_deconstruct!(block(TokenType::Block)) => match block.tokens.as_slice() {
+                make_deconstruct!(
+                    block_stat(TokenType::BlockStat),
+                    block_retstat(TokenType::BlockRetstat)
+                ) => {
+                    self.block_stat(block_stat)?;
+                    self.block_retstat(block_retstat)?;
+                    self.close_block(scope)?;
+
+                    self.proto_mut().byte_codes.push(Bytecode::zero_return());
+                    self.fix_up_last_return(0)?;
+
+                    // Returning already closes the variables
+                    self.compile_context_mut().to_be_closed.clear();
+                    self.compile_context_mut().captured_locals.clear();
+                    self.close_locals(0)?;
+
+                    if self.compile_context_mut().gotos.is_empty() {
+                        Ok(())
+                    } else {
+                        for goto in self.compile_context_mut().gotos.iter() {
+                            log::error!(
+                                target: "no_deps_lua::parser",
+                                "Goto `{}` did not point to a label.",
+                                goto.name
+                            );
+                        }
+                        Err(Error::UnmatchedGoto)
                     }
-                    Err(Error::UnmatchedGoto)
                 }
-            }
+                _ => {
+                    unreachable!(
+                        "Block did not match any production. Had {:#?}.",
+                        block
+                            .tokens
+                            .iter()
+                            .map(|t| &t.token_type)
+                            .collect::<Vec<_>>()
+                    );
+                }
+            },
             _ => {
                 unreachable!(
                     "Chunk did not match any of the productions. Had {:#?}.",
@@ -112,68 +168,25 @@ impl<'a> CompileStack<'a> {
     }
 
     fn block(&mut self, block: &Token<'a>) -> Result<(), Error> {
+        let constants = self.compile_context_mut().constants.len();
+        self.block_keeping_constants(block)?;
+        self.compile_context_mut().constants.truncate(constants);
+        Ok(())
+    }
+
+    /// Compiles `block` without ending the scope of the compile time
+    /// constants declared on it, so they can be used after the block,
+    /// like on the condition of `repeat ... until`
+    fn block_keeping_constants(&mut self, block: &Token<'a>) -> Result<(), Error> {
         match block.tokens.as_slice() {
             make_deconstruct!(
                 block_stat(TokenType::BlockStat),
                 block_retstat(TokenType::BlockRetstat)
             ) => {
-                let gotos = self.compile_context_mut().gotos.len();
-                let labels = self.compile_context_mut().labels.len();
-                let locals = self.compile_context_mut().locals.len();
-
-                if self.compile_context_mut().var_args.unwrap_or(false) {
-                    self.proto_mut()
-                        .byte_codes
-                        .push(Bytecode::variadic_arguments_prepare(u8::try_from(locals)?));
-                }
-
+                let scope = self.open_block()?;
                 self.block_stat(block_stat)?;
                 self.block_retstat(block_retstat)?;
-
-                let CompileFrame {
-                    proto,
-                    compile_context,
-                } = self.frame_mut();
-
-                let unmatched = compile_context
-                    .gotos
-                    .drain(gotos..)
-                    .filter_map(|goto| {
-                        if let Some(label) = compile_context
-                            .labels
-                            .iter()
-                            .rev()
-                            .find(|label| label.name == goto.name)
-                        {
-                            if label.bytecode != proto.byte_codes.len() && label.nvar > goto.nvar {
-                                return Some(Err(Error::GotoIntoScope));
-                            }
-                            let Ok(label_i) = isize::try_from(label.bytecode) else {
-                                return Some(Err(Error::IntCoversion));
-                            };
-                            let Ok(goto_i) = isize::try_from(goto.bytecode) else {
-                                return Some(Err(Error::IntCoversion));
-                            };
-                            let Ok(jump) = i32::try_from((label_i - 1) - goto_i) else {
-                                return Some(Err(Error::LongJump));
-                            };
-                            match Sj::try_from(jump) {
-                                Ok(jump) => {
-                                    proto.byte_codes[goto.bytecode] = Bytecode::jump(jump);
-                                    None
-                                }
-                                Err(err) => Some(Err(Error::from(err))),
-                            }
-                        } else {
-                            Some(Ok(goto))
-                        }
-                    })
-                    .collect::<Result<Vec<_>, Error>>()?;
-
-                compile_context.gotos.extend(unmatched);
-                compile_context.labels.truncate(labels);
-
-                Ok(())
+                self.close_block(scope)
             }
             _ => {
                 unreachable!(
@@ -188,21 +201,119 @@ impl<'a> CompileStack<'a> {
         }
     }
 
-    fn block_stat(&mut self, block: &Token<'a>) -> Result<(), Error> {
-        match block.tokens.as_slice() {
-            [] => Ok(()),
-            make_deconstruct!(stat(TokenType::Stat), blockstat(TokenType::BlockStat)) => {
-                self.stat(stat).and_then(|()| self.block_stat(blockstat))
+    /// Starts the scope of a block, before its statements
+    fn open_block(&mut self) -> Result<BlockScope, Error> {
+        let scope = BlockScope {
+            gotos: self.compile_context_mut().gotos.len(),
+            labels: self.compile_context_mut().labels.len(),
+            breaks: self
+                .compile_context_mut()
+                .breaks
+                .as_ref()
+                .map_or(0, Vec::len),
+            locals: self.compile_context_mut().locals.len(),
+        };
+
+        if self.compile_context_mut().var_args.unwrap_or(false) {
+            self.proto_mut()
+                .byte_codes
+                .push(Bytecode::variadic_arguments_prepare(u8::try_from(
+                    scope.locals,
+                )?));
+        }
+
+        Ok(scope)
+    }
+
+    /// Ends the scope of a block, matching the gotos to the
+    /// labels declared on it
+    fn close_block(&mut self, scope: BlockScope) -> Result<(), Error> {
+        let CompileFrame {
+            proto,
+            compile_context,
+        } = self.frame_mut();
+
+        let unmatched = compile_context
+            .gotos
+            .drain(scope.gotos..)
+            .filter_map(|goto| {
+                if let Some(label) = compile_context
+                    .labels
+                    .iter()
+                    .rev()
+                    .find(|label| label.name == goto.name)
+                {
+                    let label_end = label.bytecode + usize::from(label.close);
+                    if label_end != proto.byte_codes.len() && label.nvar > goto.nvar {
+                        return Some(Err(Error::GotoIntoScope));
+                    }
+                    let Ok(label_i) = isize::try_from(label.bytecode) else {
+                        return Some(Err(Error::IntCoversion));
+                    };
+                    let Ok(goto_i) = isize::try_from(goto.bytecode) else {
+                        return Some(Err(Error::IntCoversion));
+                    };
+                    let Ok(jump) = i32::try_from((label_i - 1) - goto_i) else {
+                        return Some(Err(Error::LongJump));
+                    };
+                    match Sj::try_from(jump) {
+                        Ok(jump) => {
+                            proto.byte_codes[goto.bytecode] = Bytecode::jump(jump);
+                            None
+                        }
+                        Err(err) => Some(Err(Error::from(err))),
+                    }
+                } else {
+                    Some(Ok(goto))
+                }
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Gotos and breaks that leave the block are no longer in the
+        // scope of its locals, and have to close them if they need it
+        let close = compile_context.needs_close(scope.locals);
+        compile_context
+            .gotos
+            .extend(unmatched.into_iter().map(|goto| GotoLabel {
+                nvar: goto.nvar.min(scope.locals),
+                close: goto.close || (close && goto.nvar > scope.locals),
+                ..goto
+            }));
+        compile_context.labels.truncate(scope.labels);
+        if let Some(breaks) = compile_context
+            .breaks
+            .as_mut()
+            .and_then(|breaks| breaks.get_mut(scope.breaks..))
+        {
+            for jump in breaks {
+                jump.close |= close;
             }
-            _ => {
-                unreachable!(
-                    "BlockStat did not match any production. Had {:#?}.",
-                    block
-                        .tokens
-                        .iter()
-                        .map(|t| &t.token_type)
-                        .collect::<Vec<_>>()
-                );
+        }
+
+        Ok(())
+    }
+
+    /// Compiles the statements of a block one after the other, walking the
+    /// chain of `block_stat` in a loop like [`Self::fieldlist_cont`]
+    fn block_stat(&mut self, block: &Token<'a>) -> Result<(), Error> {
+        let mut block = block;
+        loop {
+            match block.tokens.as_slice() {
+                [] => return Ok(()),
+                make_deconstruct!(stat(TokenType::Stat), blockstat(TokenType::BlockStat)) => {
+                    self.stat(stat)?;
+                    block = blockstat;
+                }
+                _ => {
+                    unreachable!(
+                        "BlockStat did not match any production. Had {:#?}.",
+                        block
+                            .tokens
+                            .iter()
+                            .map(|t| &t.token_type)
+                            .collect::<Vec<_>>()
+                    );
+                }
             }
         }
     }
@@ -225,6 +336,7 @@ impl<'a> CompileStack<'a> {
     }
 
     fn stat(&mut self, stat: &Token<'a>) -> Result<(), Error> {
+        self.proto_mut().mark_line(stat.line);
         match stat.tokens.as_slice() {
             make_deconstruct!(_semicolon(TokenType::SemiColon)) => Ok(()),
             make_deconstruct!(
@@ -233,6 +345,18 @@ impl<'a> CompileStack<'a> {
                 explist(TokenType::Explist)
             ) => {
                 let varlist = self.varlist(varlist)?;
+                for var in varlist.iter() {
+                    if let ExpDesc::Name(name) = var
+                        && self.view().is_read_only(name)
+                    {
+                        log::error!(
+                            target: "no_deps_lua::parser",
+                            "Attempt to assign to const variable `{}`.",
+                            name
+                        );
+                        return Err(Error::AssignToConst);
+                    }
+                }
                 let explist = self.explist(explist)?;
 
                 ExpDesc::ExpList(varlist).discharge(&ExpDesc::ExpList(explist), self)
@@ -240,7 +364,7 @@ impl<'a> CompileStack<'a> {
             make_deconstruct!(functioncall(TokenType::Functioncall)) => {
                 let function_call = self.functioncall(functioncall)?;
 
-                let (_, stack_top) = self.compile_context_mut().reserve_stack_top();
+                let (_, stack_top) = self.compile_context_mut().reserve_stack_top()?;
                 stack_top.discharge(&function_call, self)?;
                 self.compile_context_mut().stack_top -= 1;
 
@@ -255,7 +379,10 @@ impl<'a> CompileStack<'a> {
                 match compile_context.breaks.as_mut() {
                     Some(breaks) => {
                         let bytecode = proto.byte_codes.len();
-                        breaks.push(bytecode);
+                        breaks.push(Break {
+                            bytecode,
+                            close: false,
+                        });
                         proto.byte_codes.push(Bytecode::jump(Sj::ZERO));
                         Ok(())
                     }
@@ -268,18 +395,17 @@ impl<'a> CompileStack<'a> {
                     compile_context,
                 } = self.frame_mut();
 
-                // Close a captured local on a backwards goto
+                // Close captured and to-be-closed locals on a backwards goto
                 if let Some(label) = compile_context
                     .labels
                     .iter()
                     .find(|label| label.name == *name)
                     .cloned()
+                    && compile_context.needs_close(label.nvar)
                 {
-                    if compile_context.clear_captures_above(label.nvar) {
-                        proto
-                            .byte_codes
-                            .push(Bytecode::close(u8::try_from(label.nvar)?));
-                    }
+                    proto
+                        .byte_codes
+                        .push(Bytecode::close(u8::try_from(label.nvar)?));
                 }
 
                 let bytecode = proto.byte_codes.len();
@@ -288,7 +414,8 @@ impl<'a> CompileStack<'a> {
                 compile_context.push_goto(GotoLabel {
                     name,
                     bytecode,
-                    nvar: proto.locals.len(),
+                    nvar: compile_context.locals.len(),
+                    close: false,
                 });
 
                 Ok(())
@@ -305,18 +432,9 @@ impl<'a> CompileStack<'a> {
                 self.block(block)?;
 
                 self.compile_context_mut().var_args = cache_var_args;
-                self.close_locals(locals);
+                self.close_locals(locals)?;
                 self.compile_context_mut().stack_top = rewind_stack_top;
 
-                if self
-                    .compile_context_mut()
-                    .clear_captures_above(usize::from(rewind_stack_top))
-                {
-                    self.proto_mut()
-                        .byte_codes
-                        .push(Bytecode::close(rewind_stack_top));
-                }
-
                 Ok(())
             }
             make_deconstruct!(
@@ -330,10 +448,7 @@ impl<'a> CompileStack<'a> {
                 let jump_to_end_count = self.compile_context_mut().jumps_to_end.len();
                 let locals = self.compile_context_mut().locals.len();
                 let rewind_stack_top = self.compile_context_mut().stack_top;
-                let mut cache_break = self
-                    .compile_context_mut()
-                    .breaks
-                    .replace(Vec::with_capacity(16));
+                let cache_breaks = self.open_loop();
 
                 let start_of_cond = self.proto_mut().byte_codes.len();
                 let cond = self.exp(exp)?;
@@ -351,7 +466,7 @@ impl<'a> CompileStack<'a> {
                 let end_of_cond = proto.byte_codes.len();
                 for jump in compile_context.jumps_to_block.drain(jump_to_block_count..) {
                     proto.byte_codes[jump] = Bytecode::jump(Sj::try_from(
-                        i32::try_from(end_of_cond - jump).map_err(|_| Error::LongJump)?,
+                        i32::try_from(end_of_cond - (jump + 1)).map_err(|_| Error::LongJump)?,
                     )?);
                 }
 
@@ -360,7 +475,7 @@ impl<'a> CompileStack<'a> {
                 self.block(block)?;
 
                 self.compile_context_mut().var_args = cache_var_args;
-                self.close_locals(locals);
+                self.close_locals(locals)?;
                 self.compile_context_mut().stack_top = rewind_stack_top;
 
                 let CompileFrame {
@@ -375,19 +490,6 @@ impl<'a> CompileStack<'a> {
                     )?);
                 }
 
-                core::mem::swap(&mut self.compile_context_mut().breaks, &mut cache_break);
-                let Some(breaks) = cache_break else {
-                    unreachable!(
-                        "Compile Context breaks should only ever be None outside of loops."
-                    );
-                };
-                for break_bytecode in breaks {
-                    self.proto_mut().byte_codes[break_bytecode] = Bytecode::jump(Sj::try_from(
-                        i32::try_from(end_of_block - break_bytecode)
-                            .map_err(|_| Error::LongJump)?,
-                    )?);
-                }
-
                 self.proto_mut()
                     .byte_codes
                     .push(Bytecode::jump(Sj::try_from(
@@ -397,6 +499,9 @@ impl<'a> CompileStack<'a> {
                             .map_err(|_| Error::LongJump)?,
                     )?));
 
+                let breaks = self.close_loop(cache_breaks);
+                self.patch_breaks(breaks, locals)?;
+
                 Ok(())
             }
             make_deconstruct!(
@@ -406,15 +511,18 @@ impl<'a> CompileStack<'a> {
                 exp(TokenType::Exp)
             ) => {
                 let mut jump_cache = core::mem::take(&mut self.compile_context_mut().jumps_to_end);
+                let cache_breaks = self.open_loop();
 
                 let locals = self.compile_context_mut().locals.len();
                 let rewind_stack_top = self.compile_context_mut().stack_top;
                 let repeat_start = self.proto_mut().byte_codes.len();
 
+                let constants = self.compile_context_mut().constants.len();
                 let cache_var_args = self.compile_context_mut().var_args.take();
-                self.block(block)?;
+                self.block_keeping_constants(block)?;
                 self.compile_context_mut().var_args = cache_var_args;
 
+                let jump_to_block_count = self.compile_context_mut().jumps_to_block.len();
                 let cond = self.exp(exp)?;
 
                 ExpDesc::Condition {
@@ -422,24 +530,54 @@ impl<'a> CompileStack<'a> {
                     if_condition: false,
                 }
                 .discharge(&cond, self)?;
-                self.close_locals(locals);
+
+                // Conditions that are already true leave the loop
+                let CompileFrame {
+                    proto,
+                    compile_context,
+                } = self.frame_mut();
+                let end_of_cond = proto.byte_codes.len();
+                for jump in compile_context.jumps_to_block.drain(jump_to_block_count..) {
+                    proto.byte_codes[jump] = Bytecode::jump(Sj::try_from(
+                        i32::try_from(end_of_cond - (jump + 1)).map_err(|_| Error::LongJump)?,
+                    )?);
+                }
+
+                let needs_close = self.compile_context_mut().needs_close(locals);
+                self.close_locals(locals)?;
+                self.compile_context_mut().constants.truncate(constants);
                 self.compile_context_mut().stack_top = rewind_stack_top;
 
                 core::mem::swap(
                     &mut self.compile_context_mut().jumps_to_end,
                     &mut jump_cache,
                 );
-                assert_eq!(
-                    jump_cache.len(),
-                    1,
-                    "Repeat should only ever have 1 conditional jump."
-                );
+                // Going back to the start also leaves the scope of the
+                // locals of the block, so they are closed before the jump
+                let loop_start = if needs_close {
+                    let byte_codes = &mut self.proto_mut().byte_codes;
+                    byte_codes.push(Bytecode::jump(Sj::try_from(2)?));
+                    let close = byte_codes.len();
+                    byte_codes.push(Bytecode::close(u8::try_from(locals)?));
+                    byte_codes.push(Bytecode::jump(Sj::try_from(
+                        i32::try_from(isize::try_from(repeat_start)? - isize::try_from(close + 2)?)
+                            .map_err(|_| Error::LongJump)?,
+                    )?));
+                    close
+                } else {
+                    repeat_start
+                };
 
-                let repeat_end = self.proto_mut().byte_codes.len();
-                self.proto_mut().byte_codes[jump_cache[0]] = Bytecode::jump(Sj::try_from(
-                    i32::try_from(isize::try_from(repeat_start)? - isize::try_from(repeat_end)?)
-                        .map_err(|_| Error::LongJump)?,
-                )?);
+                // Conditions that are false go back to the start
+                for jump in jump_cache {
+                    self.proto_mut().byte_codes[jump] = Bytecode::jump(Sj::try_from(
+                        i32::try_from(isize::try_from(loop_start)? - isize::try_from(jump + 1)?)
+                            .map_err(|_| Error::LongJump)?,
+                    )?);
+                }
+
+                let breaks = self.close_loop(cache_breaks);
+                self.patch_breaks(breaks, locals)?;
 
                 Ok(())
             }
@@ -467,15 +605,15 @@ impl<'a> CompileStack<'a> {
                 let rewind_stack_top = self.compile_context_mut().stack_top;
 
                 let start = self.exp(start)?;
-                let (for_stack, start_stack) = self.compile_context_mut().reserve_stack_top();
+                let (for_stack, start_stack) = self.compile_context_mut().reserve_stack_top()?;
                 start_stack.discharge(&start, self)?;
 
                 let end = self.exp(end)?;
-                let (_, end_stack) = self.compile_context_mut().reserve_stack_top();
+                let (_, end_stack) = self.compile_context_mut().reserve_stack_top()?;
                 end_stack.discharge(&end, self)?;
 
                 let step = self.stat_forexp(stat_forexp)?;
-                let (_, step_stack) = self.compile_context_mut().reserve_stack_top();
+                let (_, step_stack) = self.compile_context_mut().reserve_stack_top()?;
                 step_stack.discharge(&step, self)?;
 
                 // Names can't start with `?`, so using it for internal symbols
@@ -484,8 +622,8 @@ impl<'a> CompileStack<'a> {
                 }
 
                 // Reserve 1 slot for counter
-                let loop_iterator_stack_loc = self.compile_context_mut().stack_top;
-                self.compile_context_mut().stack_top += 1;
+                let (loop_iterator_stack_loc, _) =
+                    self.compile_context_mut().reserve_stack_top()?;
                 let loop_locals_stack_loc = self.compile_context_mut().stack_top;
 
                 let counter_bytecode = self.proto_mut().byte_codes.len();
@@ -495,31 +633,23 @@ impl<'a> CompileStack<'a> {
 
                 self.open_local(name);
 
+                let cache_breaks = self.open_loop();
                 let cache_var_args = self.compile_context_mut().var_args.take();
                 self.block(block)?;
                 self.compile_context_mut().var_args = cache_var_args;
+                // Each iteration goes back to the line of the `for`
+                self.proto_mut().mark_line(stat.line);
 
                 // Close local variables
-                self.close_locals(locals + 4);
-                if self
+                self.close_locals(usize::from(loop_locals_stack_loc))?;
+
+                // Breaks also leave the scope of the loop counter
+                let counter_needs_close = self
                     .compile_context_mut()
-                    .clear_captures_above(usize::from(loop_locals_stack_loc))
-                {
-                    self.proto_mut()
-                        .byte_codes
-                        .push(Bytecode::close(loop_locals_stack_loc));
-                }
+                    .needs_close(usize::from(loop_iterator_stack_loc));
 
                 // Close loop counter
-                self.close_locals(locals + 3);
-                if self
-                    .compile_context_mut()
-                    .clear_captures_above(usize::from(loop_iterator_stack_loc))
-                {
-                    self.proto_mut()
-                        .byte_codes
-                        .push(Bytecode::close(loop_iterator_stack_loc));
-                }
+                self.close_locals(usize::from(loop_iterator_stack_loc))?;
 
                 let end_bytecode = self.proto_mut().byte_codes.len();
                 self.proto_mut().byte_codes.push(Bytecode::for_loop(
@@ -531,8 +661,20 @@ impl<'a> CompileStack<'a> {
                     Bx::try_from(u32::try_from(end_bytecode - (counter_bytecode + 1))?)?,
                 );
 
+                let breaks = self.close_loop(cache_breaks);
+                self.patch_breaks(
+                    breaks
+                        .into_iter()
+                        .map(|jump| Break {
+                            close: jump.close || counter_needs_close,
+                            ..jump
+                        })
+                        .collect(),
+                    usize::from(loop_iterator_stack_loc),
+                )?;
+
                 // Close for states
-                self.close_locals(locals);
+                self.close_locals(locals)?;
                 self.compile_context_mut().stack_top = rewind_stack_top;
 
                 Ok(())
@@ -575,14 +717,17 @@ impl<'a> CompileStack<'a> {
                 let stack_top_after_control = self.compile_context_mut().stack_top;
                 let namelist = self.namelist(namelist)?;
                 for for_var in &namelist {
-                    let _ = self.compile_context_mut().reserve_stack_top();
+                    let _ = self.compile_context_mut().reserve_stack_top()?;
                     self.open_local(for_var);
                 }
 
                 // Discharge block
+                let cache_breaks = self.open_loop();
                 let cache_var_args = self.compile_context_mut().var_args.take();
                 self.block(block)?;
                 self.compile_context_mut().var_args = cache_var_args;
+                // Each iteration goes back to the line of the `for`
+                self.proto_mut().mark_line(stat.line);
 
                 // Update dummy bytecode with proper jump
                 let end_of_block = self.proto_mut().byte_codes.len();
@@ -592,7 +737,7 @@ impl<'a> CompileStack<'a> {
                 );
 
                 // Close iteration variables
-                self.close_locals(usize::from(stack_top_after_control));
+                self.close_locals(usize::from(stack_top_after_control))?;
 
                 // Push for iterator update bytecodes
                 self.proto_mut().byte_codes.push(Bytecode::generic_for_call(
@@ -608,7 +753,21 @@ impl<'a> CompileStack<'a> {
                 ));
 
                 // Close control variables
-                self.close_locals(usize::from(rewind_stack_top));
+                self.close_locals(usize::from(rewind_stack_top))?;
+
+                // Breaks land on the `CLOSE` below, which already closes
+                // the iteration variables
+                let breaks = self.close_loop(cache_breaks);
+                self.patch_breaks(
+                    breaks
+                        .into_iter()
+                        .map(|jump| Break {
+                            close: false,
+                            ..jump
+                        })
+                        .collect(),
+                    usize::from(rewind_stack_top),
+                )?;
 
                 // Close captures
                 // FIXME: Does this always happen?
@@ -638,10 +797,10 @@ impl<'a> CompileStack<'a> {
                 let final_dst = if head.is_empty() {
                     // This is the case where the function is defined as
                     // function f() ... end
-                    let constant = self.proto_mut().push_constant(*tail)?;
+                    let constant = self.push_constant(*tail)?;
                     ExpDesc::Global(usize::try_from(constant)?)
                 } else {
-                    let (stack_loc, stack_top) = self.compile_context_mut().reserve_stack_top();
+                    let (stack_loc, stack_top) = self.compile_context_mut().reserve_stack_top()?;
                     let mut used_stack_top = false;
 
                     let mut table_loc =
@@ -649,7 +808,7 @@ impl<'a> CompileStack<'a> {
                             u8::try_from(local)?
                         } else {
                             used_stack_top = true;
-                            let constant = self.proto_mut().push_constant(head[0])?;
+                            let constant = self.push_constant(head[0])?;
                             self.proto_mut().byte_codes.push(Bytecode::get_uptable(
                                 stack_loc,
                                 B::ZERO,
@@ -685,7 +844,7 @@ impl<'a> CompileStack<'a> {
                     }
                 };
 
-                let (_, funcbody_stack) = self.compile_context_mut().reserve_stack_top();
+                let (_, funcbody_stack) = self.compile_context_mut().reserve_stack_top()?;
                 stacks_used += 1;
 
                 funcbody_stack.discharge(&funcbody, self)?;
@@ -702,9 +861,13 @@ impl<'a> CompileStack<'a> {
                 _name(TokenType::Name(name)),
                 funcbody(TokenType::Funcbody)
             ) => {
+                // The local is already in scope inside of the function's
+                // body, so the function can call itself
+                let (_, function_body) = self.compile_context_mut().reserve_stack_top()?;
+                self.compile_context_mut().locals.push((*name).into());
                 let funcbody = self.funcbody(funcbody, false)?;
+                self.compile_context_mut().locals.pop();
 
-                let (_, function_body) = self.compile_context_mut().reserve_stack_top();
                 function_body.discharge(&funcbody, self)?;
 
                 self.open_local(name);
@@ -716,17 +879,68 @@ impl<'a> CompileStack<'a> {
                 attnamelist(TokenType::Attnamelist),
                 stat_attexplist(TokenType::StatAttexplist)
             ) => {
-                let namelist = self.attnamelist(attnamelist)?;
-                let explist = self.stat_attexplist(stat_attexplist)?;
+                let mut attnamelist = self.attnamelist(attnamelist)?;
+                let mut explist = self.stat_attexplist(stat_attexplist)?;
+
+                // Like on reference Lua, only the last local can become a
+                // compile time constant, and only if nothing is adjusted
+                let constant = match (attnamelist.last(), explist.last()) {
+                    (Some((_, Some(Attrib::Const))), Some(exp))
+                        if attnamelist.len() == explist.len() =>
+                    {
+                        self.compile_time_constant(exp)
+                    }
+                    _ => None,
+                };
+                let constant = constant.and_then(|value| {
+                    explist.pop();
+                    attnamelist.pop().map(|(name, _)| (name, value))
+                });
 
-                ExpDesc::ExpList(vec![ExpDesc::NewLocal; namelist.len()])
-                    .discharge(&ExpDesc::ExpList(explist), self)?;
+                let mut to_be_closed = attnamelist
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, attrib))| *attrib == Some(Attrib::Close))
+                    .map(|(i, _)| i);
+                let close = to_be_closed.next();
+                if to_be_closed.next().is_some() {
+                    return Err(Error::MultipleToBeClosed);
+                }
+
+                if !attnamelist.is_empty() {
+                    ExpDesc::ExpList(vec![ExpDesc::NewLocal; attnamelist.len()])
+                        .discharge(&ExpDesc::ExpList(explist), self)?;
+                }
 
                 // Adding the new names into `locals` to prevent
                 // referencing the new name when you could be trying to shadow a
                 // global or another local
-                for local in namelist {
+                let first_local = self.compile_context_mut().locals.len();
+                for (i, (local, attrib)) in attnamelist.into_iter().enumerate() {
                     self.open_local(local.as_ref());
+                    if attrib.is_some() {
+                        self.compile_context_mut()
+                            .read_only_locals
+                            .insert(first_local + i);
+                    }
+                }
+
+                if let Some(close) = close {
+                    let local = first_local + close;
+                    self.compile_context_mut().to_be_closed.push(local);
+                    self.proto_mut()
+                        .byte_codes
+                        .push(Bytecode::to_be_closed(u8::try_from(local)?));
+                }
+
+                if let Some((name, value)) = constant {
+                    let compile_context = self.compile_context_mut();
+                    let declared_at = compile_context.locals.len();
+                    compile_context.constants.push(CompileTimeConstant {
+                        name,
+                        value,
+                        declared_at,
+                    });
                 }
                 Ok(())
             }
@@ -761,7 +975,7 @@ impl<'a> CompileStack<'a> {
 
                 self.compile_context_mut().var_args = cache_var_args;
                 self.compile_context_mut().stack_top = rewind_stack_top;
-                self.close_locals(locals);
+                self.close_locals(locals)?;
 
                 Ok(())
             }
@@ -814,19 +1028,19 @@ impl<'a> CompileStack<'a> {
         }
     }
 
-    fn attnamelist(&mut self, attnamelist: &Token<'_>) -> Result<NameList, Error> {
+    fn attnamelist(&mut self, attnamelist: &Token<'_>) -> Result<AttNameList, Error> {
         match attnamelist.tokens.as_slice() {
             make_deconstruct!(
                 _name(TokenType::Name(name)),
-                _attrib(TokenType::Attrib),
+                attrib(TokenType::Attrib),
                 attnamelist_cont(TokenType::AttnamelistCont)
             ) => {
-                let mut namelist = NameList::default();
-                namelist.push((*name).into());
+                let mut attnamelist = AttNameList::default();
+                attnamelist.push(((*name).into(), Self::attrib(attrib)?));
 
-                Self::attnamelist_cont(attnamelist_cont, &mut namelist)?;
+                Self::attnamelist_cont(attnamelist_cont, &mut attnamelist)?;
 
-                Ok(namelist)
+                Ok(attnamelist)
             }
             _ => {
                 unreachable!(
@@ -843,7 +1057,7 @@ impl<'a> CompileStack<'a> {
 
     fn attnamelist_cont(
         attnamelist_cont: &Token<'_>,
-        namelist: &mut NameList,
+        attnamelist: &mut AttNameList,
     ) -> Result<(), Error> {
         match attnamelist_cont.tokens.as_slice() {
             [] => Ok(()),
@@ -853,11 +1067,9 @@ impl<'a> CompileStack<'a> {
                 attrib(TokenType::Attrib),
                 attnamelist_cont(TokenType::AttnamelistCont)
             ) => {
-                namelist.push((*name).into());
+                attnamelist.push(((*name).into(), Self::attrib(attrib)?));
 
-                Self::attrib(attrib)?;
-
-                Self::attnamelist_cont(attnamelist_cont, namelist)
+                Self::attnamelist_cont(attnamelist_cont, attnamelist)
             }
             _ => {
                 unreachable!(
@@ -872,14 +1084,21 @@ impl<'a> CompileStack<'a> {
         }
     }
 
-    fn attrib(attrib: &Token) -> Result<(), Error> {
+    fn attrib(attrib: &Token) -> Result<Option<Attrib>, Error> {
         match attrib.tokens.as_slice() {
-            [] => Ok(()),
+            [] => Ok(None),
             make_deconstruct!(
                 _less(TokenType::Less),
-                _name(TokenType::Name(_)),
+                _name(TokenType::Name(name)),
                 _greater(TokenType::Greater)
-            ) => unimplemented!("attrib production"),
+            ) => match *name {
+                "const" => Ok(Some(Attrib::Const)),
+                "close" => Ok(Some(Attrib::Close)),
+                _ => {
+                    log::error!(target: "no_deps_lua::parser", "Unknown attribute `{}`.", name);
+                    Err(Error::UnknownAttribute)
+                }
+            },
             _ => {
                 unreachable!(
                     "Attrib did not match any of the productions. Had {:#?}.",
@@ -894,6 +1113,7 @@ impl<'a> CompileStack<'a> {
     }
 
     fn retstat(&mut self, retstat: &Token<'a>) -> Result<(), Error> {
+        self.proto_mut().mark_line(retstat.line);
         match retstat.tokens.as_slice() {
             make_deconstruct!(
                 _return(TokenType::Return),
@@ -912,7 +1132,8 @@ impl<'a> CompileStack<'a> {
                             );
                         };
 
-                        let (stack_loc, stack_top) = self.compile_context_mut().reserve_stack_top();
+                        let (stack_loc, stack_top) =
+                            self.compile_context_mut().reserve_stack_top()?;
                         if let ExpDesc::Name(_) = last {
                             let dst = last.get_local_or_discharge_at_location(self, stack_loc)?;
 
@@ -921,7 +1142,29 @@ impl<'a> CompileStack<'a> {
                             stack_top.discharge(last, self)?;
 
                             match last {
-                                ExpDesc::FunctionCall(_, _) => {
+                                // The variables are closed after the call returns,
+                                // so it can't be a tail call
+                                ExpDesc::FunctionCall(_, _) | ExpDesc::MethodCall(_, _, _)
+                                    if !self.compile_context_mut().to_be_closed.is_empty() =>
+                                {
+                                    let Some(call) = self.proto_mut().byte_codes.pop() else {
+                                        unreachable!("Last should always be a function call");
+                                    };
+                                    assert_eq!(OpCode::read(*call), OpCode::Call);
+                                    let (func_index, inputs, _, _) = call.decode_abck();
+
+                                    self.proto_mut().byte_codes.push(Bytecode::call(
+                                        func_index,
+                                        inputs,
+                                        C::ZERO,
+                                    ));
+                                    self.proto_mut().byte_codes.push(Bytecode::return_bytecode(
+                                        stack_loc,
+                                        B::ZERO,
+                                        C::ZERO,
+                                    ));
+                                }
+                                ExpDesc::FunctionCall(_, _) | ExpDesc::MethodCall(_, _, _) => {
                                     let Some(call) = self.proto_mut().byte_codes.pop() else {
                                         unreachable!("Last should always be a function call");
                                     };
@@ -943,6 +1186,11 @@ impl<'a> CompileStack<'a> {
                                     .proto_mut()
                                     .byte_codes
                                     .push(Bytecode::return_bytecode(stack_loc, 2, C::ZERO)),
+                                // `VARARG` already copied all of the arguments
+                                ExpDesc::VariadicArguments => self
+                                    .proto_mut()
+                                    .byte_codes
+                                    .push(Bytecode::return_bytecode(stack_loc, B::ZERO, C::ZERO)),
                                 _ => {
                                     self.proto_mut()
                                         .byte_codes
@@ -955,14 +1203,34 @@ impl<'a> CompileStack<'a> {
                     _ => {
                         let return_start = self.compile_context_mut().stack_top;
                         for exp in explist.iter() {
-                            let (_, stack_top) = self.compile_context_mut().reserve_stack_top();
-                            stack_top.discharge(exp, self)?;
+                            let (_, stack_top) = self.compile_context_mut().reserve_stack_top()?;
+                            ExpDesc::discharge_single_value(&stack_top, exp, self)?;
                         }
                         self.compile_context_mut().stack_top -= u8::try_from(explist.len())?;
 
+                        // A call or `...` at the end returns all of its values
+                        let count = match explist.last() {
+                            Some(
+                                ExpDesc::FunctionCall(_, _)
+                                | ExpDesc::MethodCall(_, _, _)
+                                | ExpDesc::VariadicArguments,
+                            ) => {
+                                let Some(last) = self.proto_mut().byte_codes.last_mut() else {
+                                    unreachable!("Last should always be a call or `VARARG`");
+                                };
+                                let (register, inputs, _, _) = last.decode_abck();
+                                *last = if OpCode::read(**last) == OpCode::Call {
+                                    Bytecode::call(register, inputs, C::ZERO)
+                                } else {
+                                    Bytecode::variadic_arguments(register, C::ZERO)
+                                };
+                                0
+                            }
+                            _ => u8::try_from(explist.len())? + 1,
+                        };
                         self.proto_mut().byte_codes.push(Bytecode::return_bytecode(
                             return_start,
-                            u8::try_from(explist.len())? + 1,
+                            count,
                             C::ZERO,
                         ));
                     }
@@ -1030,10 +1298,22 @@ impl<'a> CompileStack<'a> {
                     proto,
                     compile_context,
                 } = self.frame_mut();
+                let nvar = compile_context.locals.len();
+                let bytecode = proto.byte_codes.len();
+                // Gotos that left the scope of locals that need to be
+                // closed close them when they arrive
+                let close = compile_context
+                    .gotos
+                    .iter()
+                    .any(|goto| goto.name == *name && goto.close);
+                if close {
+                    proto.byte_codes.push(Bytecode::close(u8::try_from(nvar)?));
+                }
                 compile_context.push_label(GotoLabel {
                     name,
-                    bytecode: proto.byte_codes.len(),
-                    nvar: proto.locals.len(),
+                    bytecode,
+                    nvar,
+                    close,
                 })
             }
             _ => {
@@ -1302,31 +1582,36 @@ impl<'a> CompileStack<'a> {
         }
     }
 
+    /// Compiles the expressions that follow the first one, walking the
+    /// chain of `explist_cont` in a loop like [`Self::fieldlist_cont`]
     fn explist_cont(
         &mut self,
         explist_cont: &Token<'a>,
         explist: &mut Vec<ExpDesc<'a>>,
     ) -> Result<(), Error> {
-        match explist_cont.tokens.as_slice() {
-            [] => Ok(()),
-            make_deconstruct!(
-                _comma(TokenType::Comma),
-                exp(TokenType::Exp),
-                explist_cont(TokenType::ExplistCont)
-            ) => {
-                let exp = self.exp(exp)?;
-                explist.push(exp);
-                self.explist_cont(explist_cont, explist)
-            }
-            _ => {
-                unreachable!(
-                    "ExplistCont did not match any of the productions. Had {:#?}.",
-                    explist_cont
-                        .tokens
-                        .iter()
-                        .map(|t| &t.token_type)
-                        .collect::<Vec<_>>()
-                );
+        let mut explist_cont = explist_cont;
+        loop {
+            match explist_cont.tokens.as_slice() {
+                [] => return Ok(()),
+                make_deconstruct!(
+                    _comma(TokenType::Comma),
+                    exp(TokenType::Exp),
+                    next(TokenType::ExplistCont)
+                ) => {
+                    let exp = self.exp(exp)?;
+                    explist.push(exp);
+                    explist_cont = next;
+                }
+                _ => {
+                    unreachable!(
+                        "ExplistCont did not match any of the productions. Had {:#?}.",
+                        explist_cont
+                            .tokens
+                            .iter()
+                            .map(|t| &t.token_type)
+                            .collect::<Vec<_>>()
+                    );
+                }
             }
         }
     }
@@ -1336,9 +1621,12 @@ impl<'a> CompileStack<'a> {
             make_deconstruct!(_nil(TokenType::Nil)) => Ok(self.nil()),
             make_deconstruct!(_false(TokenType::False)) => Ok(self.boolean(false)),
             make_deconstruct!(_true(TokenType::True)) => Ok(self.boolean(true)),
-            make_deconstruct!(_string(TokenType::String(string))) => Ok(self.string(string)),
+            make_deconstruct!(_string(TokenType::String(string))) => self.string(string),
+            make_deconstruct!(_string(TokenType::LongString(string))) => {
+                Ok(self.long_string(string))
+            }
             make_deconstruct!(_integer(TokenType::Integer(integer))) => Ok(self.integer(*integer)),
-            make_deconstruct!(_float(TokenType::Float(float))) => Ok(self.float(*float)),
+            make_deconstruct!(_float(TokenType::Float(float))) => self.float(*float),
             make_deconstruct!(_dots(TokenType::Dots)) => Ok(ExpDesc::VariadicArguments),
             make_deconstruct!(functiondef(TokenType::Functiondef)) => self.functiondef(functiondef),
             make_deconstruct!(prefixexp(TokenType::Prefixexp)) => self.prefixexp(prefixexp),
@@ -1355,7 +1643,8 @@ impl<'a> CompileStack<'a> {
                 let rhs = self.exp(rhs)?;
 
                 let binop = op.try_into()?;
-                Ok(ExpDesc::Binop(binop, Box::new(lhs), Box::new(rhs)))
+                Ok(constant_folding::fold_binop(binop, &lhs, &rhs)
+                    .unwrap_or_else(|| ExpDesc::Binop(binop, Box::new(lhs), Box::new(rhs))))
             }
             make_deconstruct!(op(TokenType::Unop), rhs(TokenType::Exp)) => {
                 let op = self.unop(op)?;
@@ -1389,7 +1678,12 @@ impl<'a> CompileStack<'a> {
                 _lparen(TokenType::LParen),
                 exp(TokenType::Exp),
                 _rparen(TokenType::RParen)
-            ) => self.exp(exp),
+            ) => match self.exp(exp)? {
+                exp @ (ExpDesc::FunctionCall(_, _)
+                | ExpDesc::MethodCall(_, _, _)
+                | ExpDesc::VariadicArguments) => Ok(ExpDesc::Parenthesized(Box::new(exp))),
+                exp => Ok(exp),
+            },
             _ => {
                 unreachable!(
                     "Prefixexp did not match any of the productions. Had {:#?}.",
@@ -1447,7 +1741,10 @@ impl<'a> CompileStack<'a> {
                 let table = self.tableconstructor(tableconstructor)?;
                 Ok(vec![table])
             }
-            make_deconstruct!(_string(TokenType::String(string))) => Ok(vec![self.string(string)]),
+            make_deconstruct!(_string(TokenType::String(string))) => Ok(vec![self.string(string)?]),
+            make_deconstruct!(_string(TokenType::LongString(string))) => {
+                Ok(vec![self.long_string(string)])
+            }
             _ => {
                 unreachable!(
                     "Args did not match any of the productions. Had {:#?}.",
@@ -1675,34 +1972,38 @@ impl<'a> CompileStack<'a> {
         }
     }
 
+    /// Compiles the fields that follow the first one, walking the chain
+    /// of `fieldlist_cont` in a loop, as generated data tables can have
+    /// more fields than the host's stack can recurse over
     fn fieldlist_cont(
         &mut self,
         fieldlist_cont: &Token<'a>,
         fields: &mut TableFields<'a>,
     ) -> Result<(), Error> {
-        match fieldlist_cont.tokens.as_slice() {
-            [] => Ok(()),
-            make_deconstruct!(
-                fieldsep(TokenType::Fieldsep),
-                field(TokenType::Field),
-                fieldlist_cont(TokenType::FieldlistCont)
-            ) => {
-                self.fieldsep(fieldsep)?;
-                self.field(field, fields)?;
-                self.fieldlist_cont(fieldlist_cont, fields)?;
-
-                Ok(())
-            }
-            make_deconstruct!(fieldsep(TokenType::Fieldsep)) => self.fieldsep(fieldsep),
-            _ => {
-                unreachable!(
-                    "FieldlistCont did not match any of the productions. Had {:#?}.",
-                    fieldlist_cont
-                        .tokens
-                        .iter()
-                        .map(|t| &t.token_type)
-                        .collect::<Vec<_>>()
-                );
+        let mut fieldlist_cont = fieldlist_cont;
+        loop {
+            match fieldlist_cont.tokens.as_slice() {
+                [] => return Ok(()),
+                make_deconstruct!(
+                    fieldsep(TokenType::Fieldsep),
+                    field(TokenType::Field),
+                    next(TokenType::FieldlistCont)
+                ) => {
+                    self.fieldsep(fieldsep)?;
+                    self.field(field, fields)?;
+                    fieldlist_cont = next;
+                }
+                make_deconstruct!(fieldsep(TokenType::Fieldsep)) => return self.fieldsep(fieldsep),
+                _ => {
+                    unreachable!(
+                        "FieldlistCont did not match any of the productions. Had {:#?}.",
+                        fieldlist_cont
+                            .tokens
+                            .iter()
+                            .map(|t| &t.token_type)
+                            .collect::<Vec<_>>()
+                    );
+                }
             }
         }
     }
@@ -1848,17 +2149,57 @@ impl<'a> CompileStack<'a> {
         ExpDesc::Integer(integer)
     }
 
+    #[cfg(feature = "float")]
+    #[inline(always)]
+    fn float(&mut self, float: f64) -> Result<ExpDesc<'a>, Error> {
+        Ok(ExpDesc::Float(float))
+    }
+
+    #[cfg(not(feature = "float"))]
     #[inline(always)]
-    fn float(&mut self, float: f64) -> ExpDesc<'a> {
-        ExpDesc::Float(float)
+    fn float(&mut self, _float: f64) -> Result<ExpDesc<'a>, Error> {
+        Err(Error::FloatNumeral)
     }
 
     #[inline(always)]
-    fn string(&mut self, string: &'a str) -> ExpDesc<'a> {
-        ExpDesc::String(string)
+    fn string(&mut self, string: &'a str) -> Result<ExpDesc<'a>, Error> {
+        Ok(ExpDesc::String(string.unescape()?.into()))
+    }
+
+    /// Long strings are taken as written, with their newlines read as `\n`
+    fn long_string(&mut self, string: &'a str) -> ExpDesc<'a> {
+        if !string.contains('\r') {
+            return ExpDesc::String(string.as_bytes().into());
+        }
+        let mut contents = Vec::with_capacity(string.len());
+        let mut bytes = string.bytes().peekable();
+        while let Some(byte) = bytes.next() {
+            match byte {
+                b'\r' | b'\n' => {
+                    contents.push(b'\n');
+                    // `\r\n` and `\n\r` are a single newline
+                    bytes.next_if(|next| matches!(next, b'\r' | b'\n') && *next != byte);
+                }
+                byte => contents.push(byte),
+            }
+        }
+        ExpDesc::String(contents.into())
     }
 
     #[inline(always)]
+    /// Value of `exp` if it is known at compile time
+    fn compile_time_constant(&mut self, exp: &ExpDesc<'a>) -> Option<ExpDesc<'a>> {
+        match exp {
+            ExpDesc::Nil | ExpDesc::Boolean(_) | ExpDesc::Integer(_) | ExpDesc::String(_) => {
+                Some(exp.clone())
+            }
+            #[cfg(feature = "float")]
+            ExpDesc::Float(_) => Some(exp.clone()),
+            ExpDesc::Name(name) => self.view().find_constant(name),
+            _ => None,
+        }
+    }
+
     fn name(&mut self, name: &'a str) -> ExpDesc<'a> {
         ExpDesc::Name(name)
     }
@@ -1901,7 +2242,7 @@ impl<'a> CompileStack<'a> {
 
         self.compile_context_mut().var_args = cache_var_args;
         self.compile_context_mut().stack_top = rewind_stack_top;
-        self.close_locals(locals);
+        self.close_locals(locals)?;
 
         let jump_out_of_if = self.proto_mut().byte_codes.len();
         self.proto_mut().byte_codes.push(Bytecode::jump(Sj::ZERO));
@@ -1980,7 +2321,10 @@ impl<'a> CompileStack<'a> {
             self.fix_up_last_return(u8::try_from(parlist_name_count)?)?;
         }
 
-        self.close_locals(0);
+        // Returning already closes the variables
+        self.compile_context_mut().to_be_closed.clear();
+        self.compile_context_mut().captured_locals.clear();
+        self.close_locals(0)?;
 
         let Some(CompileFrame {
             proto,
@@ -1989,6 +2333,12 @@ impl<'a> CompileStack<'a> {
         else {
             unreachable!("CompileStack should never be empty.");
         };
+        #[cfg(feature = "peephole")]
+        let proto = {
+            let mut proto = proto;
+            proto.peephole()?;
+            proto
+        };
 
         Ok(proto)
     }
@@ -2034,34 +2384,79 @@ impl<'a> CompileStack<'a> {
             .push(Local::new_no_end(name.into(), local_loc));
     }
 
-    fn close_locals(&mut self, first_local_of_scope: usize) {
+    /// Ends the scope of the locals from `first_local_of_scope` onwards,
+    /// closing the ones captured by closures or declared with `<close>`
+    fn close_locals(&mut self, first_local_of_scope: usize) -> Result<(), Error> {
         let CompileFrame {
             proto,
             compile_context,
         } = self.frame_mut();
 
         let scope_end = proto.byte_codes.len() + 1;
-        let mut closed_on_this_call = Vec::new();
 
+        compile_context
+            .read_only_locals
+            .retain(|local| *local < first_local_of_scope);
         for local in compile_context.locals.drain(first_local_of_scope..).rev() {
-            let Some((i, local)) =
-                proto
-                    .locals
-                    .iter_mut()
-                    .enumerate()
-                    .rev()
-                    .find(|(i, proto_local)| {
-                        proto_local.name() == local.as_ref() && !closed_on_this_call.contains(i)
-                    })
-            else {
+            // Nested scopes can declare locals with the same name, like the
+            // internal locals of loops, which are already closed
+            let Some(local) = proto.locals.iter_mut().rev().find(|proto_local| {
+                proto_local.name() == local.as_ref() && proto_local.scope_end() == usize::MAX
+            }) else {
                 unreachable!(
                     "The local '{}' on the compile context must exist on the proto function.",
                     local
                 );
             };
-            closed_on_this_call.push(i);
             local.update_scope_end(scope_end);
         }
+
+        if compile_context.needs_close(first_local_of_scope) {
+            compile_context
+                .to_be_closed
+                .retain(|local| *local < first_local_of_scope);
+            compile_context.clear_captures_above(first_local_of_scope);
+            proto
+                .byte_codes
+                .push(Bytecode::close(u8::try_from(first_local_of_scope)?));
+        }
+
+        Ok(())
+    }
+
+    /// Starts collecting the `break`s of a loop, returning the ones of
+    /// the enclosing loop, if any
+    fn open_loop(&mut self) -> Option<Vec<Break>> {
+        self.compile_context_mut()
+            .breaks
+            .replace(Vec::with_capacity(16))
+    }
+
+    /// Stops collecting the `break`s of a loop, restoring the ones of the
+    /// enclosing loop, and returns the `break`s of the loop
+    fn close_loop(&mut self, mut cache_breaks: Option<Vec<Break>>) -> Vec<Break> {
+        core::mem::swap(&mut self.compile_context_mut().breaks, &mut cache_breaks);
+        let Some(breaks) = cache_breaks else {
+            unreachable!("Compile Context breaks should only ever be None outside of loops.");
+        };
+        breaks
+    }
+
+    /// Points the `break`s of a loop to the bytecode after it, closing the
+    /// locals of the loop from `first_local` onwards first if a `break`
+    /// leaves one that needs it
+    fn patch_breaks(&mut self, breaks: Vec<Break>, first_local: usize) -> Result<(), Error> {
+        let byte_codes = &mut self.proto_mut().byte_codes;
+        let target = byte_codes.len();
+        if breaks.iter().any(|jump| jump.close) {
+            byte_codes.push(Bytecode::close(u8::try_from(first_local)?));
+        }
+        for Break { bytecode, .. } in breaks {
+            byte_codes[bytecode] = Bytecode::jump(Sj::try_from(
+                i32::try_from(target - (bytecode + 1)).map_err(|_| Error::LongJump)?,
+            )?);
+        }
+        Ok(())
     }
 }
 
@@ -2082,9 +2477,18 @@ impl<'a> CompileStackView<'a, '_> {
         &mut frame.compile_context
     }
 
+    /// Adds a constant to the innermost function, taking strings
+    /// from the pool
+    fn push_constant(&mut self, value: impl Into<Value>) -> Result<u32, Error> {
+        let value = self.pool.intern(value.into());
+        self.proto_mut().push_constant(value)
+    }
+
     pub fn find_name(&mut self, name: &'a str) -> Option<ExpDesc<'a>> {
         if name.len() > Self::SHORT_STRING_LEN {
             Some(ExpDesc::LongName(name))
+        } else if let Some(constant) = self.compile_context_mut().find_constant(name) {
+            Some(constant.clone())
         } else {
             self.compile_context_mut()
                 .find_name(name)
@@ -2093,62 +2497,112 @@ impl<'a> CompileStackView<'a, '_> {
     }
 
     pub fn capture_name(&mut self, name: &'a str) -> Option<ExpDesc<'a>> {
-        if self.find_name_on_stack(name) {
-            let upvalue = self.proto_mut().push_upvalue(name);
-            Some(ExpDesc::Upvalue(upvalue))
+        if let Some(constant) = self.find_constant(name) {
+            Some(constant)
         } else {
-            None
+            self.capture_upvalue(name).map(ExpDesc::Upvalue)
         }
     }
 
-    fn find_name_on_stack(&mut self, name: &'a str) -> bool {
-        if let [head @ .., tail] = self.stack {
-            if let Some(capture) = tail.compile_context.find_name(name) {
-                tail.compile_context.push_capture(capture);
-                true
-            } else {
-                let mut view = CompileStackView { stack: head };
-                let present_higher_up = view.find_name_on_stack(name);
-                if present_higher_up {
-                    tail.proto.push_upvalue(name);
-                }
-                present_higher_up
-            }
-        } else {
-            false
+    /// Upvalue of the innermost function for the local `name` of one of the
+    /// enclosing functions, capturing it on every function in between
+    fn capture_upvalue(&mut self, name: &'a str) -> Option<usize> {
+        let [head @ .., tail] = &mut *self.stack else {
+            return None;
+        };
+        if let Some(upvalue) = tail.proto.find_upvalue(name) {
+            return Some(upvalue);
         }
+        let [.., parent] = head else {
+            return None;
+        };
+
+        let descriptor = if let Some(register) = parent.compile_context.find_name(name) {
+            parent.compile_context.push_capture(register);
+            UpvalueDescriptor::local(u8::try_from(register).ok()?)
+        } else {
+            let upvalue = CompileStackView {
+                stack: head,
+                pool: self.pool,
+            }
+            .capture_upvalue(name)?;
+            UpvalueDescriptor::upvalue(u8::try_from(upvalue).ok()?)
+        };
+        Some(tail.proto.push_upvalue(name, descriptor))
+    }
+
+    /// Upvalue of the innermost function for `_ENV`, which is the `_ENV`
+    /// of the main function unless a function declared a local `_ENV`
+    pub fn environment_upvalue(&mut self) -> usize {
+        let Some(upvalue) = self.capture_upvalue("_ENV") else {
+            unreachable!("The main function always has `_ENV` as an upvalue.");
+        };
+        upvalue
+    }
+
+    /// Checks if one of the enclosing functions declared a local `name`
+    fn is_local_on_stack(&self, name: &'a str) -> bool {
+        self.stack
+            .iter()
+            .any(|frame| frame.compile_context.find_name(name).is_some())
+    }
+
+    /// Value of the compile time constant `name` refers to, searching
+    /// from the innermost function outwards
+    pub fn find_constant(&self, name: &'a str) -> Option<ExpDesc<'a>> {
+        self.stack
+            .iter()
+            .rev()
+            .find_map(|frame| {
+                if let Some(constant) = frame.compile_context.find_constant(name) {
+                    Some(Some(constant.clone()))
+                } else {
+                    frame.compile_context.find_name(name).map(|_| None)
+                }
+            })
+            .flatten()
+    }
+
+    /// Returns `true` if `name` refers to a local declared
+    /// with `<const>` or `<close>`
+    pub fn is_read_only(&self, name: &'a str) -> bool {
+        self.stack
+            .iter()
+            .rev()
+            .find_map(|frame| {
+                if frame.compile_context.find_constant(name).is_some() {
+                    Some(true)
+                } else {
+                    frame
+                        .compile_context
+                        .find_name(name)
+                        .map(|local| frame.compile_context.read_only_locals.contains(&local))
+                }
+            })
+            .unwrap_or(false)
     }
 
     pub fn capture_environment(&mut self, name: &'a str) -> Option<ExpDesc<'a>> {
         if let Some(local_env) = self.find_name("_ENV") {
             Some(ExpDesc::TableAccess {
                 table: local_env.into(),
-                key: Box::new(ExpDesc::String(name)),
+                key: Box::new(ExpDesc::String(name.as_bytes().into())),
                 record: false,
             })
         } else {
-            let upvalue = self.proto_mut().push_upvalue("_ENV");
-            if self.find_name_on_stack("_ENV") {
+            let upvalue = self.environment_upvalue();
+            if self.is_local_on_stack("_ENV") {
                 Some(ExpDesc::TableAccess {
                     table: Box::new(ExpDesc::Upvalue(upvalue)),
-                    key: Box::new(ExpDesc::String(name)),
+                    key: Box::new(ExpDesc::String(name.as_bytes().into())),
                     record: false,
                 })
             } else {
-                self.add_environment_to_all_stacks();
-                let Ok(global) = self.proto_mut().push_constant(name) else {
+                let Ok(global) = self.push_constant(name) else {
                     unreachable!("Should never overflow u32.");
                 };
                 Some(ExpDesc::Global(usize::try_from(global).unwrap()))
             }
         }
     }
-
-    fn add_environment_to_all_stacks(&mut self) {
-        if let [head @ .., tail] = self.stack {
-            tail.proto.push_upvalue("_ENV");
-            let mut view = CompileStackView { stack: head };
-            view.add_environment_to_all_stacks();
-        }
-    }
 }
@@ -0,0 +1,79 @@
+//! Folding of binary operations on constants, like `constfolding` on
+//! `lcode.c`
+//!
+//! Operations whose operands are known at compile time are replaced by
+//! their result, so `2 * 3 + 1` is loaded with a single `LOADI`, and the
+//! operands never reach the constant table.
+
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{bytecode::Bytecode, value::Value};
+
+use super::{binops::Binop, exp_desc::ExpDesc};
+
+/// Result of `binop` applied to `lhs` and `rhs`, or `None` if it
+/// has to be computed at runtime
+pub fn fold_binop<'a>(binop: Binop, lhs: &ExpDesc<'a>, rhs: &ExpDesc<'a>) -> Option<ExpDesc<'a>> {
+    match (lhs, rhs) {
+        (ExpDesc::String(lhs), ExpDesc::String(rhs)) if binop == Binop::Concat => {
+            fold_concat(lhs, rhs)
+        }
+        _ => fold_arithmetic(binop, number(lhs)?, number(rhs)?),
+    }
+}
+
+fn number(exp: &ExpDesc) -> Option<Value> {
+    match exp {
+        ExpDesc::Integer(integer) => Some(Value::Integer(*integer)),
+        #[cfg(feature = "float")]
+        ExpDesc::Float(float) => Some(Value::Float(*float)),
+        _ => None,
+    }
+}
+
+fn fold_arithmetic<'a>(binop: Binop, lhs: Value, rhs: Value) -> Option<ExpDesc<'a>> {
+    let operation = match binop {
+        Binop::Add => Bytecode::add_values,
+        Binop::Sub => Bytecode::sub_values,
+        Binop::Mul => Bytecode::mul_values,
+        Binop::Mod => Bytecode::mod_values,
+        Binop::Pow => Bytecode::pow_values,
+        Binop::Div => Bytecode::div_values,
+        Binop::Idiv => Bytecode::idiv_values,
+        Binop::BitAnd => Bytecode::bit_and_values,
+        Binop::BitOr => Bytecode::bit_or_values,
+        Binop::BitXor => Bytecode::bit_xor_values,
+        Binop::ShiftLeft => Bytecode::shift_left_values,
+        Binop::ShiftRight => Bytecode::shift_right_values,
+        _ => return None,
+    };
+
+    // Divisions by zero are left to raise their error, or produce
+    // their special values, at runtime
+    let division = matches!(binop, Binop::Mod | Binop::Div | Binop::Idiv);
+    match rhs {
+        Value::Integer(0) if division => return None,
+        Value::Integer(-1) if division && lhs == Value::Integer(i64::MIN) => return None,
+        #[cfg(feature = "float")]
+        Value::Float(float) if division && float == 0.0 => return None,
+        _ => (),
+    }
+
+    // Operations the runtime rejects, like bitwise operations on floats
+    // with a fractional part, are not folded
+    match operation(&lhs, &rhs).ok()? {
+        Value::Integer(integer) => Some(ExpDesc::Integer(integer)),
+        // `NaN` and `-0.0` can't be told apart from other values by
+        // the constant table
+        #[cfg(feature = "float")]
+        Value::Float(float) if !float.is_nan() && float != 0.0 => Some(ExpDesc::Float(float)),
+        _ => None,
+    }
+}
+
+fn fold_concat<'a>(lhs: &[u8], rhs: &[u8]) -> Option<ExpDesc<'a>> {
+    let mut concat = Vec::with_capacity(lhs.len() + rhs.len());
+    concat.extend_from_slice(lhs);
+    concat.extend_from_slice(rhs);
+    Some(ExpDesc::String(Cow::Owned(concat)))
+}
@@ -1,11 +1,11 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 
 use crate::{
     bytecode::{
         OpCode,
-        arguments::{A, B, Bx, BytecodeArgument, C, K, Sbx, Sj},
+        arguments::{A, Ax, B, Bx, BytecodeArgument, C, K, Sbx, Sj},
     },
-    ext::Unescape,
+    value::Value,
 };
 
 use super::{
@@ -16,12 +16,14 @@ use super::{
 };
 
 #[derive(Debug, Clone, PartialEq)]
+#[allow(unpredictable_function_pointer_comparisons)]
 pub enum ExpDesc<'a> {
     Nil,
     Boolean(bool),
     Integer(i64),
+    #[cfg(feature = "float")]
     Float(f64),
-    String(&'a str),
+    String(Cow<'a, [u8]>),
     Name(&'a str),
     LongName(&'a str),
     Unop(fn(A, B) -> Bytecode, Box<ExpDesc<'a>>),
@@ -55,6 +57,8 @@ pub enum ExpDesc<'a> {
     FunctionCall(Box<ExpDesc<'a>>, ExpList<'a>),
     MethodCall(Box<ExpDesc<'a>>, Box<ExpDesc<'a>>, ExpList<'a>),
     VariadicArguments,
+    /// A call or `...` between parentheses, which is adjusted to its first value
+    Parenthesized(Box<ExpDesc<'a>>),
 }
 
 impl<'a> ExpDesc<'a> {
@@ -63,6 +67,10 @@ impl<'a> ExpDesc<'a> {
         src: &ExpDesc<'a>,
         compile_stack: &mut CompileStack<'a>,
     ) -> Result<(), Error> {
+        if let Self::Parenthesized(exp) = src {
+            return Self::discharge_single_value(self, exp, compile_stack);
+        }
+
         match self {
             Self::Name(_) => self.discharge_into_name(src, compile_stack),
             Self::LongName(_) => self.discharge_into_long_name(src, compile_stack),
@@ -125,13 +133,13 @@ impl<'a> ExpDesc<'a> {
             );
         };
 
-        let env = compile_stack.proto_mut().push_upvalue("_ENV");
+        let env = compile_stack.view().environment_upvalue();
 
-        let (_, env_top) = compile_stack.compile_context_mut().reserve_stack_top();
+        let (_, env_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
         env_top.discharge(&Self::Upvalue(env), compile_stack)?;
 
-        let (_, key_top) = compile_stack.compile_context_mut().reserve_stack_top();
-        key_top.discharge(&Self::String(long_name), compile_stack)?;
+        let (_, key_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+        key_top.discharge(&Self::String(long_name.as_bytes().into()), compile_stack)?;
 
         let env_table = Self::TableAccess {
             table: Box::new(env_top),
@@ -157,7 +165,7 @@ impl<'a> ExpDesc<'a> {
             );
         };
 
-        let (_, local) = compile_stack.compile_context_mut().reserve_stack_top();
+        let (_, local) = compile_stack.compile_context_mut().reserve_stack_top()?;
         local.discharge(src, compile_stack)
     }
 
@@ -174,6 +182,24 @@ impl<'a> ExpDesc<'a> {
         };
         let dst = u8::try_from(*dst)?;
 
+        // Calls overwrite the registers from the function onwards before
+        // their arguments are read, so they are made on the top of the
+        // stack if `dst` is a named local or has other values above it
+        let context = compile_stack.compile_context_mut();
+        if matches!(src, Self::FunctionCall(_, _) | Self::MethodCall(_, _, _))
+            && (usize::from(dst) < context.locals.len() || dst + 1 != context.stack_top)
+        {
+            let (top, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(src, compile_stack)?;
+            Self::truncate_to_single_value(compile_stack);
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::move_bytecode(dst, top));
+            compile_stack.compile_context_mut().stack_top -= 1;
+            return Ok(());
+        }
+
         match src {
             Self::Nil => {
                 compile_stack
@@ -198,14 +224,12 @@ impl<'a> ExpDesc<'a> {
                         .byte_codes
                         .push(Bytecode::load_integer(dst, integer));
                 } else {
-                    let constant = compile_stack.proto_mut().push_constant(*integer)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_constant(dst, Bx::try_from(constant)?));
+                    let constant = compile_stack.push_constant(*integer)?;
+                    Self::load_constant(dst, constant, compile_stack)?;
                 }
                 Ok(())
             }
+            #[cfg(feature = "float")]
             Self::Float(float) => {
                 if let Ok(float) = Sbx::try_from(*float) {
                     compile_stack
@@ -214,24 +238,13 @@ impl<'a> ExpDesc<'a> {
                         .push(Bytecode::load_float(dst, float));
                     Ok(())
                 } else {
-                    let constant = compile_stack.proto_mut().push_constant(*float)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_constant(dst, Bx::try_from(constant)?));
-                    Ok(())
+                    let constant = compile_stack.push_constant(*float)?;
+                    Self::load_constant(dst, constant, compile_stack)
                 }
             }
             Self::String(string) => {
-                let unescaped_string = string.unescape()?;
-                let constant = compile_stack
-                    .proto_mut()
-                    .push_constant(unescaped_string.as_str())?;
-                compile_stack
-                    .proto_mut()
-                    .byte_codes
-                    .push(Bytecode::load_constant(dst, Bx::try_from(constant)?));
-                Ok(())
+                let constant = compile_stack.push_constant(string.as_ref())?;
+                Self::load_constant(dst, constant, compile_stack)
             }
             Self::Name(name) => {
                 let Some(name) = compile_stack
@@ -246,11 +259,11 @@ impl<'a> ExpDesc<'a> {
             }
             Self::LongName(long_name) => {
                 // Reaching here already means that it is a global
-                let env = compile_stack.proto_mut().push_upvalue("_ENV");
+                let env = compile_stack.view().environment_upvalue();
 
                 self.discharge(&Self::Upvalue(env), compile_stack)?;
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                stack_top.discharge(&Self::String(long_name), compile_stack)?;
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                stack_top.discharge(&Self::String(long_name.as_bytes().into()), compile_stack)?;
                 self.discharge(
                     &Self::TableAccess {
                         table: Box::new(self.clone()),
@@ -282,13 +295,137 @@ impl<'a> ExpDesc<'a> {
                         .push(op(dst.into(), u8::try_from(*local)?.into()));
                     Ok(())
                 }
-                global @ Self::Global(_) => {
-                    self.discharge(global, compile_stack)?;
+                // Operands are evaluated on the destination first, strings
+                // are coerced to numbers when the operation runs
+                operand => {
+                    Self::discharge_single_value(self, operand, compile_stack)?;
                     self.discharge(&Self::Unop(*op, Box::new(self.clone())), compile_stack)
                 }
-                other => unimplemented!("Can't execute unary operation on {:?}.", other),
             },
             Self::Binop(op, lhs, rhs) => match (op, lhs.as_ref(), rhs.as_ref()) {
+                (Binop::And, lhs, rhs) if lhs.is_comparison() && rhs.is_comparison() => {
+                    let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
+                    let jumps_to_end = compile_stack.compile_context_mut().jumps_to_end.len();
+
+                    let lhs_cond = Self::Condition {
+                        jump_to_end: false,
+                        if_condition: false,
+                    };
+                    lhs_cond.discharge(lhs, compile_stack)?;
+
+                    let rhs_cond = Self::Condition {
+                        jump_to_end: true,
+                        if_condition: true,
+                    };
+                    rhs_cond.discharge(rhs, compile_stack)?;
+
+                    Self::resolve_jumps_to_block(jumps_to_block, compile_stack)?;
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::load_false_skip(dst));
+
+                    Self::resolve_jumps_to_end(jumps_to_end, compile_stack)?;
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::load_true(dst));
+
+                    Ok(())
+                }
+                (Binop::Or | Binop::And, _, _) => {
+                    let (truthy, falsy) = self.discharge_logical(src, compile_stack)?;
+                    Self::resolve_jumps(truthy.into_iter().chain(falsy), compile_stack)
+                }
+                (
+                    Binop::LessThan
+                    | Binop::GreaterThan
+                    | Binop::LessEqual
+                    | Binop::GreaterEqual
+                    | Binop::Equal
+                    | Binop::NotEqual,
+                    _,
+                    _,
+                ) => {
+                    Self::discharge_comparison(*op, lhs, rhs, true, Some(dst), compile_stack)?;
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::jump(1i8));
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::load_false_skip(dst));
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::load_true(dst));
+
+                    Ok(())
+                }
+                (Binop::Concat, lhs, rhs) => {
+                    // `a .. b .. c` is parsed as `a .. (b .. c)`, all operands
+                    // are placed on consecutive registers so a single
+                    // `CONCAT` joins them
+                    let mut operands = Vec::new();
+                    let mut tail = rhs;
+                    while let Self::Binop(Binop::Concat, operand, rest) = tail {
+                        operands.push(operand.as_ref());
+                        tail = rest.as_ref();
+                    }
+                    operands.push(tail);
+
+                    let base = if usize::from(dst) + 1
+                        == usize::from(compile_stack.compile_context_mut().stack_top)
+                    {
+                        dst
+                    } else {
+                        compile_stack.compile_context_mut().reserve_stack_top()?.0
+                    };
+                    for (register, operand) in core::iter::once(lhs)
+                        .chain(operands.iter().copied())
+                        .enumerate()
+                    {
+                        let register = if register == 0 {
+                            Self::Local(usize::from(base))
+                        } else {
+                            compile_stack.compile_context_mut().reserve_stack_top()?.1
+                        };
+                        register.discharge(operand, compile_stack)?;
+                        if matches!(
+                            operand,
+                            Self::FunctionCall(_, _)
+                                | Self::MethodCall(_, _, _)
+                                | Self::VariadicArguments
+                        ) {
+                            Self::truncate_to_single_value(compile_stack);
+                        }
+                    }
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::concat(base, u8::try_from(operands.len() + 1)?));
+                    compile_stack.compile_context_mut().stack_top -= u8::try_from(operands.len())?;
+
+                    if base != dst {
+                        compile_stack
+                            .proto_mut()
+                            .byte_codes
+                            .push(Bytecode::move_bytecode(dst, base));
+                        compile_stack.compile_context_mut().stack_top -= 1;
+                    }
+                    Ok(())
+                }
+                (Binop::ShiftLeft, Self::Integer(lhs), Self::Local(rhs))
+                    if immediate(*lhs).is_some() =>
+                {
+                    let (rhs, lhs) = (u8::try_from(*rhs)?, i8::try_from(*lhs)?);
+                    compile_stack.proto_mut().byte_codes.extend([
+                        Bytecode::shift_left_integer(dst, rhs, lhs),
+                        Bytecode::metamethod_integer(rhs, lhs, op.metamethod_event(), true),
+                    ]);
+                    Ok(())
+                }
                 (
                     Binop::Mul
                     | Binop::Mod
@@ -299,18 +436,10 @@ impl<'a> ExpDesc<'a> {
                     | Binop::BitOr
                     | Binop::BitXor
                     | Binop::ShiftLeft
-                    | Binop::ShiftRight
-                    | Binop::Or
-                    | Binop::And
-                    | Binop::LessThan
-                    | Binop::GreaterThan
-                    | Binop::LessEqual
-                    | Binop::GreaterEqual
-                    | Binop::Equal
-                    | Binop::NotEqual,
+                    | Binop::ShiftRight,
                     lhs @ Self::Integer(_),
                     _,
-                ) => {
+                ) if !matches!((op, rhs.as_ref()), (Binop::ShiftLeft, Self::Name(_))) => {
                     self.discharge(lhs, compile_stack)?;
                     self.discharge(
                         &Self::Binop(*op, Box::new(self.clone()), rhs.clone()),
@@ -362,14 +491,15 @@ impl<'a> ExpDesc<'a> {
                 }
                 (
                     op,
-                    table_access @ Self::TableAccess {
+                    lhs @ (Self::Unop(_, _)
+                    | Self::TableAccess {
                         table: _,
                         key: _,
                         record: _,
-                    },
+                    }),
                     _,
                 ) => {
-                    self.discharge(table_access, compile_stack)?;
+                    self.discharge(lhs, compile_stack)?;
                     self.discharge(
                         &Self::Binop(*op, Box::new(self.clone()), rhs.clone()),
                         compile_stack,
@@ -384,7 +514,7 @@ impl<'a> ExpDesc<'a> {
                         record: _,
                     },
                 ) => {
-                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
+                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
                     stack_top.discharge(table_access, compile_stack)?;
                     self.discharge(
                         &Self::Binop(*op, lhs.clone(), Box::new(stack_top)),
@@ -393,154 +523,88 @@ impl<'a> ExpDesc<'a> {
                     compile_stack.compile_context_mut().stack_top -= 1;
                     Ok(())
                 }
-                (Binop::Add, Self::Integer(_), global @ Self::Global(_)) => {
+                // Constants can only be the right operand, so the operands
+                // of commutative operations are swapped, and the metamethod
+                // is told to swap them back
+                (Binop::Add, lhs @ Self::Integer(_), global @ Self::Global(_)) => {
                     self.discharge(global, compile_stack)?;
-                    self.discharge(
-                        &Self::Binop(*op, Box::new(self.clone()), lhs.clone()),
-                        compile_stack,
-                    )
-                }
-                (Binop::Add, Self::Local(lhs), Self::Integer(rhs)) => {
-                    if let Ok(rhs) = i8::try_from(*rhs) {
-                        compile_stack
-                            .proto_mut()
-                            .byte_codes
-                            .push(Bytecode::add_integer(dst, u8::try_from(*lhs)?, rhs));
-                        Ok(())
-                    } else {
-                        todo!()
-                    }
-                }
-                (Binop::Add, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack.proto_mut().byte_codes.push(Bytecode::add(
-                        dst,
-                        u8::try_from(*lhs)?,
-                        u8::try_from(*rhs)?,
-                    ));
-                    Ok(())
-                }
-                (Binop::Sub, Self::Local(lhs), Self::Integer(rhs)) => {
-                    if let Ok(rhs) = i8::try_from(*rhs) {
-                        compile_stack
-                            .proto_mut()
-                            .byte_codes
-                            .push(Bytecode::add_integer(dst, u8::try_from(*lhs)?, -rhs));
-                        Ok(())
-                    } else {
-                        todo!()
-                    }
-                }
-                (Binop::Sub, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack.proto_mut().byte_codes.push(Bytecode::sub(
-                        dst,
-                        u8::try_from(*lhs)?,
-                        u8::try_from(*rhs)?,
-                    ));
-                    Ok(())
-                }
-                (Binop::Mul, Self::Local(lhs), Self::Integer(rhs)) => {
-                    let rhs = compile_stack.proto_mut().push_constant(*rhs)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::mul_constant(
-                            dst,
-                            u8::try_from(*lhs)?,
-                            u8::try_from(rhs)?,
-                        ));
-                    Ok(())
-                }
-                (Binop::Mul, Self::Local(lhs), Self::Float(rhs)) => {
-                    let rhs = compile_stack.proto_mut().push_constant(*rhs)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::mul_constant(
-                            dst,
-                            u8::try_from(*lhs)?,
-                            u8::try_from(rhs)?,
-                        ));
-                    Ok(())
-                }
-                (Binop::Div, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack.proto_mut().byte_codes.push(Bytecode::div(
-                        dst,
-                        u8::try_from(*lhs)?,
-                        u8::try_from(*rhs)?,
-                    ));
-                    Ok(())
-                }
-                (Binop::ShiftLeft, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::shift_left(
-                            dst,
-                            u8::try_from(*lhs)?,
-                            u8::try_from(*rhs)?,
-                        ));
-                    Ok(())
-                }
-                (Binop::ShiftRight, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::shift_right(
-                            dst,
-                            u8::try_from(*lhs)?,
-                            u8::try_from(*rhs)?,
-                        ));
-                    Ok(())
+                    self.discharge_number_operand(*op, dst, lhs, true, compile_stack)
                 }
                 (
-                    Binop::Concat,
-                    lhs @ (Self::Integer(_)
-                    | Self::Float(_)
-                    | Self::String(_)
-                    | Self::Binop(_, _, _)),
-                    _,
-                ) => {
-                    self.discharge(lhs, compile_stack)?;
-                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                    stack_top.discharge(
-                        &Self::Binop(*op, Box::new(self.clone()), rhs.clone()),
-                        compile_stack,
-                    )?;
-                    compile_stack.compile_context_mut().stack_top -= 1;
-                    Ok(())
-                }
+                    op @ (Binop::Add | Binop::Sub | Binop::ShiftRight | Binop::ShiftLeft),
+                    Self::Local(lhs),
+                    rhs @ Self::Integer(integer),
+                ) if immediate(*integer).is_some() => self.discharge_number_operand(
+                    *op,
+                    u8::try_from(*lhs)?,
+                    rhs,
+                    false,
+                    compile_stack,
+                ),
+                (
+                    op @ (Binop::ShiftLeft | Binop::ShiftRight),
+                    Self::Local(lhs),
+                    rhs @ Self::Integer(_),
+                ) => self.discharge_with_register_operand(
+                    *op,
+                    u8::try_from(*lhs)?,
+                    rhs,
+                    false,
+                    compile_stack,
+                ),
+                (
+                    op @ (Binop::Add
+                    | Binop::Sub
+                    | Binop::Mul
+                    | Binop::Mod
+                    | Binop::Pow
+                    | Binop::Div
+                    | Binop::Idiv),
+                    Self::Local(lhs),
+                    rhs,
+                )
+                | (
+                    op @ (Binop::BitAnd | Binop::BitOr | Binop::BitXor),
+                    Self::Local(lhs),
+                    rhs @ Self::Integer(_),
+                ) if rhs.is_number() => self.discharge_number_operand(
+                    *op,
+                    u8::try_from(*lhs)?,
+                    rhs,
+                    false,
+                    compile_stack,
+                ),
                 (
-                    Binop::Concat,
+                    op @ (Binop::Add
+                    | Binop::Sub
+                    | Binop::Mul
+                    | Binop::Mod
+                    | Binop::Pow
+                    | Binop::Div
+                    | Binop::Idiv
+                    | Binop::BitAnd
+                    | Binop::BitOr
+                    | Binop::BitXor
+                    | Binop::ShiftLeft
+                    | Binop::ShiftRight),
                     Self::Local(lhs),
-                    rhs @ (Self::Integer(_) | Self::String(_) | Self::Local(_)),
+                    Self::Local(rhs),
                 ) => {
-                    self.discharge(rhs, compile_stack)?;
                     compile_stack
                         .proto_mut()
                         .byte_codes
-                        .push(Bytecode::concat(u8::try_from(*lhs)?, 2));
-
-                    Ok(())
-                }
-                (Binop::Concat, Self::Local(lhs), rhs @ Self::Binop(Binop::Concat, _, _)) => {
-                    self.discharge(rhs, compile_stack)?;
-
-                    let Some(last_bytecode) = compile_stack.proto_mut().byte_codes.last_mut()
-                    else {
-                        unreachable!(
-                            "Bytecodes should not be empty after discharging concatenation."
-                        );
-                    };
-                    assert_eq!(OpCode::read(**last_bytecode), OpCode::Concat);
-                    let (_, b, _, _) = last_bytecode.decode_abck();
-                    *last_bytecode = Bytecode::concat(u8::try_from(*lhs)?, *b + 1);
-
+                        .extend(Self::register_arithmetic(
+                            *op,
+                            dst,
+                            u8::try_from(*lhs)?,
+                            u8::try_from(*rhs)?,
+                        ));
                     Ok(())
                 }
                 (op, lhs @ Self::Local(_), rhs @ Self::Binop(_, _, _)) => {
                     let mut used_stacks = 0;
                     let rhs = if self == lhs {
-                        let (_, b) = compile_stack.compile_context_mut().reserve_stack_top();
+                        let (_, b) = compile_stack.compile_context_mut().reserve_stack_top()?;
                         used_stacks += 1;
                         b.discharge(rhs, compile_stack)?;
                         b
@@ -557,256 +621,90 @@ impl<'a> ExpDesc<'a> {
 
                     Ok(())
                 }
-                (Binop::Or, lhs, rhs) => {
-                    self.discharge(lhs, compile_stack)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::test(dst, K::ONE));
-                    let shortcircuit = compile_stack.proto_mut().byte_codes.len();
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-                    compile_stack
-                        .compile_context_mut()
-                        .jumps_to_block
-                        .push(shortcircuit);
-
-                    self.discharge(rhs, compile_stack)
-                }
-                (
-                    Binop::And,
-                    lhs,
-                    rhs @ Self::Binop(
-                        Binop::LessThan
-                        | Binop::GreaterThan
-                        | Binop::LessEqual
-                        | Binop::GreaterEqual
-                        | Binop::Equal
-                        | Binop::NotEqual,
-                        _,
-                        _,
-                    ),
-                ) => {
-                    let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
-                    let jumps_to_end = compile_stack.compile_context_mut().jumps_to_end.len();
-
-                    let lhs_cond = Self::Condition {
-                        jump_to_end: false,
-                        if_condition: false,
-                    };
-                    lhs_cond.discharge(lhs, compile_stack)?;
-
-                    let rhs_cond = Self::Condition {
-                        jump_to_end: true,
-                        if_condition: true,
+                (op, operand, rhs @ (Self::Upvalue(_) | Self::Global(_)))
+                    if matches!(operand, Self::Local(_)) || operand.is_number() =>
+                {
+                    let mut used_stacks = 0;
+                    let rhs = if self == lhs.as_ref() {
+                        let (_, b) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                        used_stacks += 1;
+                        b.discharge(rhs, compile_stack)?;
+                        b
+                    } else {
+                        self.discharge(rhs, compile_stack)?;
+                        self.clone()
                     };
-                    rhs_cond.discharge(rhs, compile_stack)?;
-
-                    Self::resolve_jumps_to_block(jumps_to_block, compile_stack)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
 
-                    Self::resolve_jumps_to_end(jumps_to_end, compile_stack)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
+                    self.discharge(&Self::Binop(*op, lhs.clone(), Box::new(rhs)), compile_stack)?;
+                    compile_stack.compile_context_mut().stack_top -= used_stacks;
 
                     Ok(())
                 }
-                (Binop::And, lhs, rhs) => {
-                    let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
-
-                    self.discharge(lhs, compile_stack)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::test(dst, K::ZERO));
-                    let shortcircuit = compile_stack.proto_mut().byte_codes.len();
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-
-                    Self::resolve_jumps_to_block(jumps_to_block, compile_stack)?;
-                    compile_stack
-                        .compile_context_mut()
-                        .jumps_to_block
-                        .push(shortcircuit);
-
-                    self.discharge(rhs, compile_stack)
-                }
-                (Binop::LessThan, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::less_than(
-                            u8::try_from(*lhs)?,
-                            u8::try_from(*rhs)?,
-                            K::ONE,
-                        ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
-
-                    Ok(())
-                }
-                (Binop::GreaterThan, Self::Local(lhs), Self::Integer(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::greater_than_integer(
-                            u8::try_from(*lhs)?,
-                            i8::try_from(*rhs)?,
-                            K::ONE,
-                        ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
-
-                    Ok(())
-                }
-                (Binop::GreaterThan, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::less_than(
-                            u8::try_from(*rhs)?,
-                            u8::try_from(*lhs)?,
-                            K::ONE,
-                        ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
+                (op @ (Binop::Add | Binop::Mul), lhs, Self::Local(rhs)) if lhs.is_number() => self
+                    .discharge_number_operand(*op, u8::try_from(*rhs)?, lhs, true, compile_stack),
+                (op, lhs, Self::Local(_)) if lhs.is_number() => {
+                    let mut used_stacks = 0;
+                    let lhs = if self == rhs.as_ref() {
+                        let (_, b) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                        used_stacks += 1;
+                        b.discharge(lhs, compile_stack)?;
+                        b
+                    } else {
+                        self.discharge(lhs, compile_stack)?;
+                        self.clone()
+                    };
 
-                    Ok(())
-                }
-                (Binop::LessEqual, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::less_equal(
-                            u8::try_from(*lhs)?,
-                            u8::try_from(*rhs)?,
-                            K::ONE,
-                        ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
+                    self.discharge(&Self::Binop(*op, Box::new(lhs), rhs.clone()), compile_stack)?;
+                    compile_stack.compile_context_mut().stack_top -= used_stacks;
 
                     Ok(())
                 }
-                (Binop::GreaterEqual, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::less_equal(
-                            u8::try_from(*rhs)?,
-                            u8::try_from(*lhs)?,
-                            K::ONE,
-                        ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
+                // Any other operand, like a string that is coerced to a
+                // number when the operation runs, is moved to a register
+                (
+                    op @ (Binop::Add
+                    | Binop::Sub
+                    | Binop::Mul
+                    | Binop::Mod
+                    | Binop::Pow
+                    | Binop::Div
+                    | Binop::Idiv
+                    | Binop::BitAnd
+                    | Binop::BitOr
+                    | Binop::BitXor
+                    | Binop::ShiftLeft
+                    | Binop::ShiftRight),
+                    lhs_operand,
+                    rhs_operand,
+                ) => {
+                    let lhs_is_local = matches!(lhs_operand, Self::Local(_));
+                    let (operand, other) = if lhs_is_local {
+                        (rhs_operand, lhs_operand)
+                    } else {
+                        (lhs_operand, rhs_operand)
+                    };
 
-                    Ok(())
-                }
-                (Binop::Equal, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack.proto_mut().byte_codes.push(Bytecode::equal(
-                        u8::try_from(*lhs)?,
-                        u8::try_from(*rhs)?,
-                        K::ONE,
-                    ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
+                    let mut used_stacks = 0;
+                    let register = if self == other {
+                        let (_, b) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                        used_stacks += 1;
+                        Self::discharge_single_value(&b, operand, compile_stack)?;
+                        b
+                    } else {
+                        Self::discharge_single_value(self, operand, compile_stack)?;
+                        self.clone()
+                    };
 
-                    Ok(())
-                }
-                (Binop::Equal, Self::Local(lhs), Self::String(rhs)) => {
-                    let rhs = compile_stack.proto_mut().push_constant(*rhs)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::equal_constant(
-                            u8::try_from(*lhs)?,
-                            u8::try_from(rhs)?,
-                            K::ONE,
-                        ));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(1i8));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_false_skip(dst));
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::load_true(dst));
+                    let binop = if lhs_is_local {
+                        Self::Binop(*op, lhs.clone(), Box::new(register))
+                    } else {
+                        Self::Binop(*op, Box::new(register), rhs.clone())
+                    };
+                    self.discharge(&binop, compile_stack)?;
+                    compile_stack.compile_context_mut().stack_top -= used_stacks;
 
                     Ok(())
                 }
-                _ => unimplemented!("Can't discharge binary operation {:?}.", src),
             },
             Self::Local(local) => {
                 let local = u8::try_from(*local)?;
@@ -819,16 +717,8 @@ impl<'a> ExpDesc<'a> {
                 Ok(())
             }
             Self::Global(global) => {
-                let env = compile_stack.proto_mut().push_upvalue("_ENV");
-                compile_stack
-                    .proto_mut()
-                    .byte_codes
-                    .push(Bytecode::get_uptable(
-                        dst,
-                        u8::try_from(env)?,
-                        u8::try_from(*global)?,
-                    ));
-                Ok(())
+                let env = compile_stack.view().environment_upvalue();
+                Self::get_uptable(dst, u8::try_from(env)?, *global, compile_stack)
             }
             Self::Upvalue(upvalue) => {
                 compile_stack
@@ -838,35 +728,60 @@ impl<'a> ExpDesc<'a> {
                 Ok(())
             }
             Self::Table(fields) => {
+                // `SETLIST` takes the items from the registers right above
+                // the table, so it is built on the top of the stack if
+                // `dst` is below other locals
+                let stack_top = compile_stack.compile_context_mut().stack_top;
+                if usize::from(dst) + 1 < usize::from(stack_top) {
+                    let (top, top_exp) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                    top_exp.discharge(src, compile_stack)?;
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::move_bytecode(dst, top));
+                    compile_stack.compile_context_mut().stack_top -= 1;
+                    return Ok(());
+                }
+
                 let array_count = fields
                     .iter()
                     .filter(|(field_key, _)| matches!(field_key, TableKey::Array))
                     .count();
-                let last_array_field_is_variadic = fields
-                    .iter()
-                    .filter(|(field_key, _)| matches!(field_key, TableKey::Array))
-                    .next_back()
-                    .filter(|(_, field)| matches!(field, Self::VariadicArguments))
-                    .is_some();
+                // A call or `...` as the last field adds all of its values
+                let last_field_is_multiple = matches!(
+                    fields.last(),
+                    Some((
+                        TableKey::Array,
+                        Self::VariadicArguments
+                            | Self::FunctionCall(_, _)
+                            | Self::MethodCall(_, _, _)
+                    ))
+                );
 
+                // Sizes are only hints, bigger tables grow as they are filled
+                let table_len = fields.len() - array_count;
+                let array_len = array_count - usize::from(last_field_is_multiple);
                 compile_stack
                     .proto_mut()
                     .byte_codes
                     .push(Bytecode::new_table(
                         dst,
-                        u8::try_from(fields.len() - array_count)?,
-                        u8::try_from(array_count)? - (last_array_field_is_variadic as u8),
+                        u8::try_from(table_len).unwrap_or(u8::MAX),
+                        u8::try_from(array_len).unwrap_or(u8::MAX),
                     ));
 
-                let mut used_stack = 0;
-                let mut last_variadic_bytecode = 0;
+                // Items wait on the registers above the table until a
+                // batch is full, and are then stored after the ones
+                // already on the table
+                let mut pending = 0;
+                let mut stored = 0;
 
-                for (key, field) in fields.iter() {
+                for (i, (key, field)) in fields.iter().enumerate() {
                     match key {
                         TableKey::Array => {
                             let (_, stack_top) =
-                                compile_stack.compile_context_mut().reserve_stack_top();
-                            used_stack += 1;
+                                compile_stack.compile_context_mut().reserve_stack_top()?;
+                            pending += 1;
                             stack_top.discharge(field, compile_stack)?;
 
                             let Some(last_bytecode) =
@@ -876,11 +791,29 @@ impl<'a> ExpDesc<'a> {
                                     "Bytecodes should never be empty while discharging table fields."
                                 );
                             };
-                            if OpCode::read(**last_bytecode) == OpCode::VariadicArguments {
-                                let (a, _, _, _) = last_bytecode.decode_abck();
-                                *last_bytecode = Bytecode::variadic_arguments(a, 2);
-                                last_variadic_bytecode =
-                                    compile_stack.proto_mut().byte_codes.len() - 1;
+                            let multiple = OpCode::read(**last_bytecode)
+                                == OpCode::VariadicArguments
+                                || matches!(
+                                    field,
+                                    Self::FunctionCall(_, _) | Self::MethodCall(_, _, _)
+                                );
+                            if multiple && last_field_is_multiple && i == fields.len() - 1 {
+                                let (a, b, _, _) = last_bytecode.decode_abck();
+                                *last_bytecode = if OpCode::read(**last_bytecode) == OpCode::Call {
+                                    Bytecode::call(a, b, C::ZERO)
+                                } else {
+                                    Bytecode::variadic_arguments(a, C::ZERO)
+                                };
+                            } else {
+                                if multiple {
+                                    Self::truncate_to_single_value(compile_stack);
+                                }
+                                if pending == FIELDS_PER_FLUSH {
+                                    Self::set_list(dst, pending, stored, compile_stack)?;
+                                    stored += usize::from(pending);
+                                    compile_stack.compile_context_mut().stack_top -= pending;
+                                    pending = 0;
+                                }
                             }
                         }
                         TableKey::General(key) => {
@@ -902,26 +835,14 @@ impl<'a> ExpDesc<'a> {
                     }
                 }
 
-                let array_count = if last_array_field_is_variadic {
-                    let (a, _, _, _) =
-                        compile_stack.proto_mut().byte_codes[last_variadic_bytecode].decode_abck();
-                    compile_stack.proto_mut().byte_codes[last_variadic_bytecode] =
-                        Bytecode::variadic_arguments(a, C::ZERO);
-                    Some(0)
-                } else if array_count != 0 {
-                    Some(u8::try_from(array_count)?)
-                } else {
-                    None
-                };
-
-                if let Some(array_count) = array_count {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::set_list(dst, array_count, C::ZERO));
+                if last_field_is_multiple {
+                    // Up to the top of the stack
+                    Self::set_list(dst, 0, stored, compile_stack)?;
+                } else if pending != 0 {
+                    Self::set_list(dst, pending, stored, compile_stack)?;
                 }
 
-                compile_stack.compile_context_mut().stack_top -= used_stack;
+                compile_stack.compile_context_mut().stack_top -= pending;
 
                 Ok(())
             }
@@ -967,7 +888,7 @@ impl<'a> ExpDesc<'a> {
                     )
                 }
                 (table @ Self::Upvalue(_), Self::String(key)) => {
-                    let global = compile_stack.proto_mut().push_constant(*key)?;
+                    let global = compile_stack.push_constant(key.as_ref())?;
 
                     self.discharge(
                         &Self::TableAccess {
@@ -979,15 +900,7 @@ impl<'a> ExpDesc<'a> {
                     )
                 }
                 (Self::Upvalue(table), Self::Global(global)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::get_uptable(
-                            dst,
-                            u8::try_from(*table)?,
-                            u8::try_from(*global)?,
-                        ));
-                    Ok(())
+                    Self::get_uptable(dst, u8::try_from(*table)?, *global, compile_stack)
                 }
                 (Self::Local(local_table), Self::Integer(index)) => {
                     if let Ok(index) = u8::try_from(*index) {
@@ -997,32 +910,32 @@ impl<'a> ExpDesc<'a> {
                             .push(Bytecode::get_index(dst, u8::try_from(*local_table)?, index));
                         Ok(())
                     } else {
-                        let (_, stack_top) =
-                            compile_stack.compile_context_mut().reserve_stack_top();
-                        stack_top.discharge(&Self::Integer(*index), compile_stack)?;
-                        self.discharge(
-                            &Self::TableAccess {
-                                table: table.clone(),
-                                key: Box::new(stack_top),
-                                record: false,
-                            },
+                        self.discharge_table_access_through_register(
+                            *local_table,
+                            key,
                             compile_stack,
-                        )?;
-                        compile_stack.compile_context_mut().stack_top -= 1;
-                        Ok(())
+                        )
                     }
                 }
-                (Self::Local(table), Self::String(key)) => {
-                    let key = compile_stack.proto_mut().push_constant(*key)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::get_field(
-                            dst,
-                            u8::try_from(*table)?,
-                            u8::try_from(key)?,
-                        ));
-                    Ok(())
+                (Self::Local(local_table), Self::String(string)) => {
+                    let constant = compile_stack.push_constant(string.as_ref())?;
+                    if let Ok(constant) = u8::try_from(constant) {
+                        compile_stack
+                            .proto_mut()
+                            .byte_codes
+                            .push(Bytecode::get_field(
+                                dst,
+                                u8::try_from(*local_table)?,
+                                constant,
+                            ));
+                        Ok(())
+                    } else {
+                        self.discharge_table_access_through_register(
+                            *local_table,
+                            key,
+                            compile_stack,
+                        )
+                    }
                 }
                 (Self::Local(table), Self::Local(key)) => {
                     compile_stack
@@ -1060,7 +973,36 @@ impl<'a> ExpDesc<'a> {
                     };
                     self.discharge(&table_access, compile_stack)
                 }
-                _ => unimplemented!("Can't access table with configuration {:?}.", src),
+                // t[k + 1] // t[f()]
+                (Self::Local(_), key) => {
+                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                    Self::discharge_single_value(&stack_top, key, compile_stack)?;
+                    self.discharge(
+                        &Self::TableAccess {
+                            table: table.clone(),
+                            key: Box::new(stack_top),
+                            record: false,
+                        },
+                        compile_stack,
+                    )?;
+                    compile_stack.compile_context_mut().stack_top -= 1;
+                    Ok(())
+                }
+                // f().a // up[k]
+                (table, _) => {
+                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                    Self::discharge_single_value(&stack_top, table, compile_stack)?;
+                    self.discharge(
+                        &Self::TableAccess {
+                            table: Box::new(stack_top),
+                            key: key.clone(),
+                            record: false,
+                        },
+                        compile_stack,
+                    )?;
+                    compile_stack.compile_context_mut().stack_top -= 1;
+                    Ok(())
+                }
             },
             Self::TableAccess {
                 table,
@@ -1077,7 +1019,7 @@ impl<'a> ExpDesc<'a> {
                 self.discharge(
                     &Self::TableAccess {
                         table: table.clone(),
-                        key: Box::new(Self::String(key)),
+                        key: Box::new(Self::String(key.as_bytes().into())),
                         record: false,
                     },
                     compile_stack,
@@ -1091,51 +1033,10 @@ impl<'a> ExpDesc<'a> {
                 Ok(())
             }
             Self::FunctionCall(function, args) => {
-                self.discharge(function, compile_stack)?;
-
-                let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
-                for arg in args.iter() {
-                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                    stack_top.discharge(arg, compile_stack)?;
-
-                    match compile_stack.proto_mut().byte_codes.last_mut() {
-                        Some(bytecode) => match OpCode::read(**bytecode) {
-                            OpCode::VariadicArguments => {
-                                let (register, _, _, _) = bytecode.decode_abck();
-                                *bytecode = Bytecode::variadic_arguments(register, 2);
-                            }
-                            OpCode::Call => {
-                                let (func, in_params, _, _) = bytecode.decode_abck();
-                                *bytecode = Bytecode::call(func, in_params, 2);
-                            }
-                            _ => (),
-                        },
-                        None => unreachable!(
-                            "Bytecodes should not be empty after discharging argument."
-                        ),
-                    }
-                }
-                compile_stack.compile_context_mut().stack_top -= u8::try_from(args.len())?;
-
-                let Some(last_bytecode) = compile_stack.proto_mut().byte_codes.last_mut() else {
-                    unreachable!("Bytecodes should not be empty after discharging argument,");
-                };
-                let in_params = match OpCode::read(**last_bytecode) {
-                    OpCode::Call => {
-                        let (func, in_params, _, _) = last_bytecode.decode_abck();
-                        *last_bytecode = Bytecode::call(func, in_params, C::ZERO);
-                        0
-                    }
-                    OpCode::VariadicArguments => {
-                        let (a, _, _, _) = last_bytecode.decode_abck();
-                        *last_bytecode = Bytecode::variadic_arguments(a, C::ZERO);
-                        0
-                    }
-                    _ => u8::try_from(args.len())? + 1,
-                };
-
-                Self::resolve_jumps_to_block(jumps_to_block, compile_stack)?;
+                // The function can itself be the result of a call
+                Self::discharge_single_value(self, function, compile_stack)?;
 
+                let in_params = Self::discharge_arguments(args, 1, compile_stack)?;
                 compile_stack
                     .proto_mut()
                     .byte_codes
@@ -1143,34 +1044,56 @@ impl<'a> ExpDesc<'a> {
 
                 Ok(())
             }
-            Self::MethodCall(table, method_name, exp_list) => {
-                self.discharge(table, compile_stack)?;
+            Self::MethodCall(table_exp, method_name, exp_list) => {
+                // A local is read where it is, anything else, like the
+                // result of a call, is placed on the function's register first
+                let table = match table_exp.as_ref() {
+                    Self::Name(name) => compile_stack.view().find_name(name),
+                    _ => None,
+                };
+                let table = match table {
+                    Some(Self::Local(local)) => u8::try_from(local)?,
+                    _ => {
+                        Self::discharge_single_value(self, table_exp, compile_stack)?;
+                        dst
+                    }
+                };
 
                 let Self::Name(name) = method_name.as_ref() else {
                     unreachable!("Method name should be a Name, but was {:?}.", method_name);
                 };
-                let constant = compile_stack.proto_mut().push_constant(*name)?;
-                compile_stack
-                    .proto_mut()
-                    .byte_codes
-                    .push(Bytecode::table_self(dst, dst, u8::try_from(constant)?));
-
-                // reserve `self`
-                let (_, _) = compile_stack.compile_context_mut().reserve_stack_top();
-                let mut used_stack = 1;
-
-                for exp in exp_list.iter() {
-                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                    stack_top.discharge(exp, compile_stack)?;
-                    used_stack += 1;
+                let constant = compile_stack.push_constant(*name)?;
+                if let Ok(constant) = u8::try_from(constant) {
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::table_self(dst, table, constant));
+                } else {
+                    // `self` is copied first, as the table may be on `dst`
+                    let receiver = u8::try_from(usize::from(dst) + 1)?;
+                    let _ = compile_stack.compile_context_mut().reserve_stack_top()?;
+                    let (key, _) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::move_bytecode(receiver, table));
+                    Self::load_constant(key, constant, compile_stack)?;
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .push(Bytecode::get_table(dst, receiver, key));
+                    compile_stack.compile_context_mut().stack_top -= 2;
                 }
 
-                compile_stack.compile_context_mut().stack_top -= used_stack;
+                // reserve `self`
+                let (_, _) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                let in_params = Self::discharge_arguments(exp_list, 2, compile_stack)?;
+                compile_stack.compile_context_mut().stack_top -= 1;
 
                 compile_stack
                     .proto_mut()
                     .byte_codes
-                    .push(Bytecode::call(dst, used_stack + 1, 1));
+                    .push(Bytecode::call(dst, in_params, 1));
 
                 Ok(())
             }
@@ -1196,37 +1119,58 @@ impl<'a> ExpDesc<'a> {
                 self
             );
         };
-        let global = u8::try_from(*global)?;
+        let Ok(global) = u8::try_from(*global) else {
+            // The key doesn't fit `SETTABUP`, so the environment and the
+            // key are placed on registers
+            let env = compile_stack.view().environment_upvalue();
+            let (env_register, env_local) =
+                compile_stack.compile_context_mut().reserve_stack_top()?;
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::get_upvalue(env_register, u8::try_from(env)?));
+            let (key_register, key_local) =
+                compile_stack.compile_context_mut().reserve_stack_top()?;
+            Self::load_constant(key_register, u32::try_from(*global)?, compile_stack)?;
+            Self::TableAccess {
+                table: Box::new(env_local),
+                key: Box::new(key_local),
+                record: false,
+            }
+            .discharge(src, compile_stack)?;
+            compile_stack.compile_context_mut().stack_top -= 2;
+
+            return Ok(());
+        };
+
+        let constant = match src {
+            Self::Nil => Some(Value::Nil),
+            Self::Boolean(boolean) => Some(Value::Boolean(*boolean)),
+            Self::Integer(integer) => Some(Value::Integer(*integer)),
+            #[cfg(feature = "float")]
+            Self::Float(float) => Some(Value::Float(*float)),
+            Self::String(string) => Some(Value::from(string.as_ref())),
+            _ => None,
+        };
+        if let Some(constant) = constant {
+            let env = compile_stack.view().environment_upvalue();
+            let constant = compile_stack.push_constant(constant)?;
+            let Ok(constant) = u8::try_from(constant) else {
+                return self.discharge_through_register(src, compile_stack);
+            };
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::set_uptable(
+                    u8::try_from(env)?,
+                    global,
+                    constant,
+                    K::ONE,
+                ));
+            return Ok(());
+        }
 
         match src {
-            Self::Integer(integer) => {
-                let env = compile_stack.proto_mut().push_upvalue("_ENV");
-                let constant = compile_stack.proto_mut().push_constant(*integer)?;
-                compile_stack
-                    .proto_mut()
-                    .byte_codes
-                    .push(Bytecode::set_uptable(
-                        u8::try_from(env)?,
-                        global,
-                        u8::try_from(constant)?,
-                        K::ONE,
-                    ));
-                Ok(())
-            }
-            Self::String(string) => {
-                let env = compile_stack.proto_mut().push_upvalue("_ENV");
-                let constant = compile_stack.proto_mut().push_constant(*string)?;
-                compile_stack
-                    .proto_mut()
-                    .byte_codes
-                    .push(Bytecode::set_uptable(
-                        u8::try_from(env)?,
-                        global,
-                        u8::try_from(constant)?,
-                        K::ONE,
-                    ));
-                Ok(())
-            }
             Self::Name(name) => {
                 let Some(name) = compile_stack
                     .view()
@@ -1239,7 +1183,7 @@ impl<'a> ExpDesc<'a> {
                 self.discharge(&name, compile_stack)
             }
             Self::Local(local) => {
-                let env = compile_stack.proto_mut().push_upvalue("_ENV");
+                let env = compile_stack.view().environment_upvalue();
                 compile_stack
                     .proto_mut()
                     .byte_codes
@@ -1251,18 +1195,14 @@ impl<'a> ExpDesc<'a> {
                     ));
                 Ok(())
             }
-            exp @ (Self::Global(_)
-            | Self::Upvalue(_)
-            | Self::Table(_)
-            | Self::FunctionCall(_, _)) => {
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
+            exp => {
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
                 stack_top.discharge(exp, compile_stack)?;
                 self.discharge(&stack_top, compile_stack)?;
                 compile_stack.compile_context_mut().stack_top -= 1;
 
                 Ok(())
             }
-            other => unreachable!("Can't discharge {:?} into Global.", other),
         }
     }
 
@@ -1287,7 +1227,7 @@ impl<'a> ExpDesc<'a> {
                     u8::try_from(*upvalue)?,
                 ));
         } else {
-            let (stack_loc, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
+            let (stack_loc, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
             stack_top.discharge(src, compile_stack)?;
             compile_stack
                 .proto_mut()
@@ -1321,7 +1261,7 @@ impl<'a> ExpDesc<'a> {
                     );
                     let dst = compile_stack.compile_context_mut().stack_top;
                     for _ in destinations.iter() {
-                        compile_stack.compile_context_mut().reserve_stack_top();
+                        compile_stack.compile_context_mut().reserve_stack_top()?;
                     }
                     compile_stack
                         .proto_mut()
@@ -1331,174 +1271,15 @@ impl<'a> ExpDesc<'a> {
                             u8::try_from(destinations.len() - 1)?,
                         ));
                     Ok(())
+                } else if let ([dst], [src]) = (destinations.as_slice(), src_explist.as_slice()) {
+                    Self::discharge_single_value(dst, src, compile_stack)
+                } else if destinations
+                    .iter()
+                    .all(|dst| matches!(dst, ExpDesc::NewLocal))
+                {
+                    Self::discharge_new_locals(destinations, src_explist, compile_stack)
                 } else {
-                    let mut used_stack = 0;
-                    let mut reverse_sets = Vec::new();
-
-                    if destinations.len() == 1 && src_explist.len() == 1 {
-                        destinations[0].discharge(&src_explist[0], compile_stack)?;
-                    } else {
-                        for lhs_exp in destinations.iter() {
-                            if let Self::Name(name) = lhs_exp {
-                                if compile_stack
-                                    .view()
-                                    .find_name(name)
-                                    .or_else(|| compile_stack.view().capture_name(name))
-                                    .or_else(|| compile_stack.view().capture_environment(name))
-                                    .is_none()
-                                {
-                                    unreachable!("Should always fallback to Global.");
-                                };
-                            }
-                        }
-
-                        let mut first = true;
-                        for (dst, src) in destinations.iter().zip(src_explist.iter()) {
-                            match dst {
-                                ExpDesc::Name(name) => {
-                                    let Some(dst_name) = compile_stack
-                                        .view()
-                                        .find_name(name)
-                                        .or_else(|| compile_stack.view().capture_name(name))
-                                        .or_else(|| compile_stack.view().capture_environment(name))
-                                    else {
-                                        unreachable!("Should always fallback to Global.");
-                                    };
-                                    let src = if let Self::Name(src) = src {
-                                        let Some(src) = compile_stack
-                                            .view()
-                                            .find_name(src)
-                                            .or_else(|| compile_stack.view().capture_name(src))
-                                            .or_else(|| {
-                                                compile_stack.view().capture_environment(src)
-                                            })
-                                        else {
-                                            unreachable!("Should always fallback to Global.");
-                                        };
-                                        src
-                                    } else {
-                                        src.clone()
-                                    };
-
-                                    if first
-                                        || matches!(
-                                            src,
-                                            Self::Upvalue(_) | Self::FunctionCall(_, _)
-                                        )
-                                        || matches!(dst_name, Self::Upvalue(_))
-                                    {
-                                        let (_, stack_top) =
-                                            compile_stack.compile_context_mut().reserve_stack_top();
-                                        stack_top.discharge(&src, compile_stack)?;
-                                        reverse_sets.push((dst_name.clone(), stack_top));
-                                        used_stack += 1;
-                                    } else {
-                                        dst_name.discharge(&src, compile_stack)?;
-                                    }
-                                }
-                                ExpDesc::NewLocal => {
-                                    let (_, local) =
-                                        compile_stack.compile_context_mut().reserve_stack_top();
-                                    local.discharge(src, compile_stack)?;
-                                }
-                                ExpDesc::TableAccess {
-                                    table: _,
-                                    key: _,
-                                    record: _,
-                                } => dst.discharge(src, compile_stack)?,
-                                _ => unreachable!(
-                                    "Varlist expressions should always be Name, TableAccess, or a new local initialization, but was {:?}.",
-                                    dst
-                                ),
-                            }
-                            first = false;
-                        }
-                    }
-
-                    match src_explist.last() {
-                        Some(ExpDesc::FunctionCall(_, _)) => {
-                            for remaining in destinations[src_explist.len()..].iter() {
-                                match remaining {
-                                    Self::Name(_) => {
-                                        let (_, stack_top) =
-                                            compile_stack.compile_context_mut().reserve_stack_top();
-                                        reverse_sets.push((remaining.clone(), stack_top));
-                                        used_stack += 1;
-                                    }
-                                    Self::NewLocal => {
-                                        compile_stack.compile_context_mut().reserve_stack_top();
-                                    }
-                                    _ => unreachable!(),
-                                }
-                            }
-
-                            let Some(last_bytecode) =
-                                compile_stack.proto_mut().byte_codes.last_mut()
-                            else {
-                                unreachable!("Bytecodes should not be empty while discharging.");
-                            };
-                            assert_eq!(OpCode::read(**last_bytecode), OpCode::Call);
-
-                            let (function, in_params, _, _) = last_bytecode.decode_abck();
-                            *last_bytecode = Bytecode::call(
-                                function,
-                                in_params,
-                                u8::try_from(destinations.len() - src_explist.len() + 2)?,
-                            );
-                        }
-                        Some(ExpDesc::VariadicArguments) => {
-                            for remaining in destinations[src_explist.len()..].iter() {
-                                match remaining {
-                                    Self::Name(_) => {
-                                        let (_, stack_top) =
-                                            compile_stack.compile_context_mut().reserve_stack_top();
-                                        reverse_sets.push((remaining.clone(), stack_top));
-                                        used_stack += 1;
-                                    }
-                                    Self::NewLocal => {
-                                        compile_stack.compile_context_mut().reserve_stack_top();
-                                    }
-                                    _ => unreachable!(),
-                                }
-                            }
-
-                            let Some(last_bytecode) =
-                                compile_stack.proto_mut().byte_codes.last_mut()
-                            else {
-                                unreachable!("Bytecodes should not be empty while discharging.");
-                            };
-                            assert_eq!(OpCode::read(**last_bytecode), OpCode::VariadicArguments);
-
-                            let (register, _, _, _) = last_bytecode.decode_abck();
-                            *last_bytecode = Bytecode::variadic_arguments(
-                                register,
-                                u8::try_from(destinations.len() - src_explist.len() + 2)?,
-                            );
-                        }
-                        Some(_) => {
-                            for dst in destinations[src_explist.len()..].iter() {
-                                if matches!(dst, Self::Global(_)) {
-                                    let (_, stack_top) =
-                                        compile_stack.compile_context_mut().reserve_stack_top();
-                                    stack_top.discharge(&ExpDesc::Nil, compile_stack)?;
-                                    reverse_sets.push((dst.clone(), stack_top));
-                                    used_stack += 1;
-                                } else {
-                                    dst.discharge(&ExpDesc::Nil, compile_stack)?;
-                                }
-                            }
-                        }
-                        None => {
-                            unreachable!("src_explist should never be empty")
-                        }
-                    }
-
-                    for (dst, src) in reverse_sets.into_iter().rev() {
-                        dst.discharge(&src, compile_stack)?;
-                    }
-
-                    compile_stack.compile_context_mut().stack_top -= used_stack;
-                    Ok(())
+                    Self::discharge_assignment(destinations, src_explist, compile_stack)
                 }
             }
             _ => unimplemented!(
@@ -1508,149 +1289,731 @@ impl<'a> ExpDesc<'a> {
         }
     }
 
-    fn discharge_into_table_access(
-        &self,
-        src: &ExpDesc<'a>,
+    /// Initializes `local` declarations with more than one name or value,
+    /// each local takes the next register
+    fn discharge_new_locals(
+        destinations: &[ExpDesc<'a>],
+        src_explist: &[ExpDesc<'a>],
         compile_stack: &mut CompileStack<'a>,
     ) -> Result<(), Error> {
-        let Self::TableAccess { table, key, record } = self else {
-            unreachable!(
-                "Destination of `discharge_into_table_access` must be `ExpDesc::TableAccess`, but was {:?}.",
-                self
-            );
-        };
-
-        match (table.as_ref(), key.as_ref(), record, src) {
-            (_, _, _, src @ ExpDesc::Upvalue(_)) => {
-                let (_, stack_exp) = compile_stack.compile_context_mut().reserve_stack_top();
-                stack_exp.discharge(src, compile_stack)?;
-                compile_stack.compile_context_mut().stack_top -= 1;
+        let mut used_stack = 0;
 
-                self.discharge(&stack_exp, compile_stack)
+        for (i, src) in src_explist.iter().enumerate() {
+            let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(src, compile_stack)?;
+            // Only the last expression can produce multiple values, and only
+            // if there are locals left, extra expressions are evaluated for
+            // their side effects and then discarded
+            if i + 1 != src_explist.len() || i + 1 >= destinations.len() {
+                Self::truncate_to_single_value(compile_stack);
             }
-            (_, _, _, src @ ExpDesc::Closure(_)) => {
-                let (_, stack_exp) = compile_stack.compile_context_mut().reserve_stack_top();
-                stack_exp.discharge(src, compile_stack)?;
-                compile_stack.compile_context_mut().stack_top -= 1;
+            if i >= destinations.len() {
+                used_stack += 1;
+            }
+        }
+
+        if let Some(missing) = destinations.len().checked_sub(src_explist.len()) {
+            Self::fill_missing_values(src_explist, missing, compile_stack)?;
+        }
+
+        compile_stack.compile_context_mut().stack_top -= used_stack;
+        Ok(())
+    }
 
-                self.discharge(&stack_exp, compile_stack)
+    /// Assigns to a list of variables, all values are evaluated before
+    /// any of the variables is assigned
+    ///
+    /// Like on reference Lua, the values are stored on temporary registers and
+    /// assigned in reverse order, except for the last value, which is assigned
+    /// directly if there is one value for each variable.
+    fn discharge_assignment(
+        destinations: &[ExpDesc<'a>],
+        src_explist: &[ExpDesc<'a>],
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let first_temporary = compile_stack.compile_context_mut().stack_top;
+
+        // Resolving the names before the values keeps the order of upvalues
+        let mut assigned_locals = Vec::new();
+        for (i, dst) in destinations.iter().enumerate() {
+            if let Self::Name(name) = dst {
+                match compile_stack
+                    .view()
+                    .find_name(name)
+                    .or_else(|| compile_stack.view().capture_name(name))
+                    .or_else(|| compile_stack.view().capture_environment(name))
+                {
+                    Some(Self::Local(local)) => assigned_locals.push((i, local)),
+                    Some(_) => (),
+                    None => unreachable!("Should always fallback to Global."),
+                }
             }
-            (_, Self::Name(key), true, _) => {
-                // Rewrite all access in the form `t.x` as `t["x"]`
-                let table_access = Self::TableAccess {
-                    table: table.clone(),
-                    key: Box::new(ExpDesc::String(key)),
-                    record: false,
-                };
-                table_access.discharge(src, compile_stack)
+        }
+        let destinations =
+            Self::copy_conflicting_locals(destinations, &assigned_locals, compile_stack)?;
+
+        let assign_last_directly = destinations.len() == src_explist.len();
+        let mut values = Vec::new();
+        for (i, src) in src_explist.iter().enumerate() {
+            if assign_last_directly && i + 1 == src_explist.len() {
+                break;
             }
-            (_, key, true, _) => {
-                unreachable!(
-                    "Record table access must be keyd by Name, but was {:?}.",
-                    key
-                );
+            let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(src, compile_stack)?;
+            if i + 1 != src_explist.len() || i + 1 >= destinations.len() {
+                Self::truncate_to_single_value(compile_stack);
             }
-            (table @ Self::Global(_), _, false, _) => {
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                stack_top.discharge(table, compile_stack)?;
-                let table_access = Self::TableAccess {
-                    table: Box::new(stack_top),
-                    key: key.clone(),
-                    record: false,
-                };
-                table_access.discharge(src, compile_stack)?;
-                compile_stack.compile_context_mut().stack_top -= 1;
+            values.push(stack_top);
+        }
 
-                Ok(())
-            }
-            (Self::Name(table), _, false, _) => {
-                let Some(table) = compile_stack
-                    .view()
-                    .find_name(table)
-                    .or_else(|| compile_stack.view().capture_name(table))
-                    .or_else(|| compile_stack.view().capture_environment(table))
-                else {
-                    unreachable!("Should always fallback to Global.");
+        if assign_last_directly {
+            let (Some(dst), Some(src)) = (destinations.last(), src_explist.last()) else {
+                unreachable!("Assignments always have at least one variable and value.");
+            };
+            Self::discharge_single_value(dst, src, compile_stack)?;
+        } else if let Some(missing) = destinations.len().checked_sub(src_explist.len()) {
+            let first_missing = compile_stack.compile_context_mut().stack_top;
+            Self::fill_missing_values(src_explist, missing, compile_stack)?;
+            values.extend((0..missing).map(|i| Self::Local(usize::from(first_missing) + i)));
+        }
+
+        for (dst, value) in destinations.iter().zip(values).rev() {
+            dst.discharge(&value, compile_stack)?;
+        }
+
+        compile_stack.compile_context_mut().stack_top = first_temporary;
+        Ok(())
+    }
+
+    /// Copies the locals that are used as the table or key of a variable, and
+    /// assigned by a variable after it, as those are assigned first
+    fn copy_conflicting_locals(
+        destinations: &[ExpDesc<'a>],
+        assigned_locals: &[(usize, usize)],
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<Vec<ExpDesc<'a>>, Error> {
+        let mut copies: Vec<(usize, ExpDesc<'a>)> = Vec::new();
+        let mut destinations = destinations.to_vec();
+
+        for (i, dst) in destinations.iter_mut().enumerate() {
+            let Self::TableAccess { table, key, record } = dst else {
+                continue;
+            };
+            let operands = if *record {
+                [Some(table), None]
+            } else {
+                [Some(table), Some(key)]
+            };
+            for operand in operands.into_iter().flatten() {
+                let Self::Name(name) = operand.as_ref() else {
+                    continue;
                 };
-                let table_access = Self::TableAccess {
-                    table: Box::new(table),
-                    key: key.clone(),
-                    record: false,
+                let Some(local) = compile_stack.compile_context_mut().find_name(name) else {
+                    continue;
                 };
-                table_access.discharge(src, compile_stack)
+                if !assigned_locals
+                    .iter()
+                    .any(|(assigned, assigned_local)| *assigned > i && *assigned_local == local)
+                {
+                    continue;
+                }
+
+                let copy =
+                    if let Some((_, copy)) = copies.iter().find(|(copied, _)| *copied == local) {
+                        copy.clone()
+                    } else {
+                        let (_, copy) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                        copy.discharge(&Self::Local(local), compile_stack)?;
+                        copies.push((local, copy.clone()));
+                        copy
+                    };
+                **operand = copy;
             }
-            (_, Self::Name(key), false, _) => {
-                let Some(name) = compile_stack
-                    .view()
-                    .find_name(key)
-                    .or_else(|| compile_stack.view().capture_name(key))
-                    .or_else(|| compile_stack.view().capture_environment(key))
-                else {
-                    unreachable!("Should always fallback to Global.");
-                };
+        }
 
-                let table_access = Self::TableAccess {
-                    table: table.clone(),
-                    key: Box::new(name),
-                    record: false,
-                };
-                table_access.discharge(src, compile_stack)?;
+        Ok(destinations)
+    }
 
-                Ok(())
-            }
-            (_, Self::String(key), false, Self::Name(name)) => {
-                // Storing the key into constants early to match the ordering
-                // of the official compiler
-                let _ = compile_stack.proto_mut().push_constant(*key)?;
-                let Some(name) = compile_stack
-                    .view()
-                    .find_name(name)
-                    .or_else(|| compile_stack.view().capture_name(name))
-                    .or_else(|| compile_stack.view().capture_environment(name))
-                else {
-                    unreachable!("Should always fallback to Global.");
+    /// Reserves registers for `missing` values after the ones of `src_explist`,
+    /// filling them with the extra results of a trailing call or variadic
+    /// arguments, or with `nil`
+    fn fill_missing_values(
+        src_explist: &[ExpDesc<'a>],
+        missing: usize,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        if missing == 0 {
+            return Ok(());
+        }
+
+        let first_missing = compile_stack.compile_context_mut().stack_top;
+        for _ in 0..missing {
+            compile_stack.compile_context_mut().reserve_stack_top()?;
+        }
+
+        match src_explist.last() {
+            Some(ExpDesc::FunctionCall(_, _) | ExpDesc::MethodCall(_, _, _)) => {
+                let Some(last_bytecode) = compile_stack.proto_mut().byte_codes.last_mut() else {
+                    unreachable!("Bytecodes should not be empty while discharging.");
                 };
-                self.discharge(&name, compile_stack)
+                assert_eq!(OpCode::read(**last_bytecode), OpCode::Call);
+
+                let (function, in_params, _, _) = last_bytecode.decode_abck();
+                *last_bytecode = Bytecode::call(function, in_params, u8::try_from(missing + 2)?);
             }
-            (_, _, false, Self::Name(name)) => {
-                let Some(name) = compile_stack
-                    .view()
-                    .find_name(name)
-                    .or_else(|| compile_stack.view().capture_name(name))
-                    .or_else(|| compile_stack.view().capture_environment(name))
-                else {
-                    unreachable!("Should always fallback to Global.");
+            Some(ExpDesc::VariadicArguments) => {
+                let Some(last_bytecode) = compile_stack.proto_mut().byte_codes.last_mut() else {
+                    unreachable!("Bytecodes should not be empty while discharging.");
                 };
-                self.discharge(&name, compile_stack)
+                assert_eq!(OpCode::read(**last_bytecode), OpCode::VariadicArguments);
+
+                let (register, _, _, _) = last_bytecode.decode_abck();
+                *last_bytecode = Bytecode::variadic_arguments(register, u8::try_from(missing + 2)?);
             }
-            // local t, k
-            // t[k] = 1
-            (Self::Local(table), Self::Local(key), false, Self::Integer(integer)) => {
-                let constant = compile_stack.proto_mut().push_constant(*integer)?;
+            Some(_) => {
                 compile_stack
                     .proto_mut()
                     .byte_codes
-                    .push(Bytecode::set_table(
-                        u8::try_from(*table)?,
-                        u8::try_from(*key)?,
-                        u8::try_from(constant)?,
-                        K::ONE,
+                    .push(Bytecode::load_nil(
+                        first_missing,
+                        u8::try_from(missing - 1)?,
                     ));
-                Ok(())
             }
-            // local t, k
-            // t[k] = "a"
-            (Self::Local(table), Self::Local(key), false, Self::String(string)) => {
-                let constant = compile_stack.proto_mut().push_constant(*string)?;
-                compile_stack
-                    .proto_mut()
-                    .byte_codes
-                    .push(Bytecode::set_table(
-                        u8::try_from(*table)?,
-                        u8::try_from(*key)?,
-                        u8::try_from(constant)?,
-                        K::ONE,
-                    ));
+            None => unreachable!("src_explist should never be empty"),
+        }
+
+        Ok(())
+    }
+
+    /// Discharges `src` into `dst`, keeping only the first value if
+    /// `src` produces multiple values
+    pub fn discharge_single_value(
+        dst: &ExpDesc<'a>,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        if !matches!(
+            src,
+            Self::FunctionCall(_, _) | Self::MethodCall(_, _, _) | Self::VariadicArguments
+        ) {
+            return dst.discharge(src, compile_stack);
+        }
+
+        let dst = if let Self::Name(name) = dst {
+            let Some(dst) = compile_stack
+                .view()
+                .find_name(name)
+                .or_else(|| compile_stack.view().capture_name(name))
+                .or_else(|| compile_stack.view().capture_environment(name))
+            else {
+                unreachable!("Should always fallback to Global.");
+            };
+            dst
+        } else {
+            dst.clone()
+        };
+
+        if matches!(dst, Self::Local(_) | Self::NewLocal) {
+            dst.discharge(src, compile_stack)?;
+            Self::truncate_to_single_value(compile_stack);
+        } else {
+            // The call has to be adjusted before the value is stored
+            let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(src, compile_stack)?;
+            Self::truncate_to_single_value(compile_stack);
+            dst.discharge(&stack_top, compile_stack)?;
+            compile_stack.compile_context_mut().stack_top -= 1;
+        }
+        Ok(())
+    }
+
+    /// Discharges the arguments of a call to the registers after the
+    /// function and the `fixed` values before them, returns the `B` of
+    /// the `CALL`, which is 0 when the last argument is a call or `...`
+    /// that passes all of its values
+    fn discharge_arguments(
+        args: &ExpList<'a>,
+        fixed: u8,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<u8, Error> {
+        let open = matches!(
+            args.last(),
+            Some(Self::FunctionCall(_, _) | Self::MethodCall(_, _, _) | Self::VariadicArguments)
+        );
+
+        let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
+        for (i, arg) in args.iter().enumerate() {
+            let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(arg, compile_stack)?;
+            if !open || i + 1 < args.len() {
+                Self::truncate_to_single_value(compile_stack);
+            }
+        }
+        compile_stack.compile_context_mut().stack_top -= u8::try_from(args.len())?;
+
+        let in_params = if open {
+            let Some(last_bytecode) = compile_stack.proto_mut().byte_codes.last_mut() else {
+                unreachable!("Bytecodes should not be empty after discharging argument.");
+            };
+            match OpCode::read(**last_bytecode) {
+                OpCode::Call => {
+                    let (func, in_params, _, _) = last_bytecode.decode_abck();
+                    *last_bytecode = Bytecode::call(func, in_params, C::ZERO);
+                }
+                OpCode::VariadicArguments => {
+                    let (a, _, _, _) = last_bytecode.decode_abck();
+                    *last_bytecode = Bytecode::variadic_arguments(a, C::ZERO);
+                }
+                other => unreachable!("Open argument should end on a call, but was {:?}.", other),
+            }
+            0
+        } else {
+            u8::try_from(args.len())? + fixed
+        };
+
+        Self::resolve_jumps_to_block(jumps_to_block, compile_stack)?;
+        Ok(in_params)
+    }
+
+    /// Reads `table[key]` into `self` with the key on a register, for
+    /// keys that don't fit the operand of `GETI` or `GETFIELD`
+    fn discharge_table_access_through_register(
+        &self,
+        table: usize,
+        key: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let Self::Local(dst) = self else {
+            unreachable!(
+                "Table access should be read into a Local, but was {:?}.",
+                self
+            );
+        };
+        if *dst != table {
+            // The key is loaded on the destination, as the register
+            // above it might be past the top of the stack
+            let dst = u8::try_from(*dst)?;
+            self.discharge(key, compile_stack)?;
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::get_table(dst, u8::try_from(table)?, dst));
+        } else {
+            let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(key, compile_stack)?;
+            self.discharge(
+                &Self::TableAccess {
+                    table: Box::new(Self::Local(table)),
+                    key: Box::new(stack_top),
+                    record: false,
+                },
+                compile_stack,
+            )?;
+            compile_stack.compile_context_mut().stack_top -= 1;
+        }
+
+        Ok(())
+    }
+
+    /// Stores `src` into `self` from a register, for constants with ids
+    /// too large for the operand of the store
+    fn discharge_through_register(
+        &self,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+        stack_top.discharge(src, compile_stack)?;
+        self.discharge(&stack_top, compile_stack)?;
+        compile_stack.compile_context_mut().stack_top -= 1;
+
+        Ok(())
+    }
+
+    /// Stores `src` into `table[key]` with the key on a register
+    fn discharge_key_through_register(
+        table: &ExpDesc<'a>,
+        key: &ExpDesc<'a>,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+        stack_top.discharge(key, compile_stack)?;
+        if matches!(
+            key,
+            Self::VariadicArguments | Self::FunctionCall(_, _) | Self::MethodCall(_, _, _)
+        ) {
+            Self::truncate_to_single_value(compile_stack);
+        }
+        let table_access = Self::TableAccess {
+            table: Box::new(table.clone()),
+            key: Box::new(stack_top),
+            record: false,
+        };
+        table_access.discharge(src, compile_stack)?;
+        compile_stack.compile_context_mut().stack_top -= 1;
+
+        Ok(())
+    }
+
+    /// Stores `src` on the table access `self` from a register
+    fn discharge_src_through_register(
+        &self,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let (_, stack_exp) = compile_stack.compile_context_mut().reserve_stack_top()?;
+        stack_exp.discharge(src, compile_stack)?;
+        Self::truncate_to_single_value(compile_stack);
+        self.discharge(&stack_exp, compile_stack)?;
+        compile_stack.compile_context_mut().stack_top -= 1;
+
+        Ok(())
+    }
+
+    /// Reads the field of upvalue `table` keyed by constant `key` into
+    /// `dst`, with the key on a register if it doesn't fit `GETTABUP`
+    fn get_uptable(
+        dst: u8,
+        table: u8,
+        key: usize,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        if let Ok(key) = u8::try_from(key) {
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::get_uptable(dst, table, key));
+        } else {
+            let (key_register, _) = compile_stack.compile_context_mut().reserve_stack_top()?;
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::get_upvalue(dst, table));
+            Self::load_constant(key_register, u32::try_from(key)?, compile_stack)?;
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .push(Bytecode::get_table(dst, dst, key_register));
+            compile_stack.compile_context_mut().stack_top -= 1;
+        }
+
+        Ok(())
+    }
+
+    /// Stores the `count` items above the table at `table` after the
+    /// `stored` items already on it, a `count` of 0 stores all items up
+    /// to the top of the stack
+    fn set_list(
+        table: u8,
+        count: u8,
+        stored: usize,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let byte_codes = &mut compile_stack.proto_mut().byte_codes;
+        match u8::try_from(stored) {
+            Ok(stored) => byte_codes.push(Bytecode::set_list(table, count, stored)),
+            Err(_) => {
+                // `C` has the remainder, and the `EXTRAARG` the rest
+                let remainder = stored % (usize::from(u8::MAX) + 1);
+                let quotient = stored / (usize::from(u8::MAX) + 1);
+                byte_codes.push(Bytecode::set_list_extra_args(
+                    table,
+                    count,
+                    u8::try_from(remainder)?,
+                ));
+                byte_codes.push(Bytecode::extra_arguments(Ax::try_from(u32::try_from(
+                    quotient,
+                )?)?));
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads `constant` into `dst`, with `LOADKX` if its id doesn't fit `LOADK`
+    fn load_constant(
+        dst: u8,
+        constant: u32,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let byte_codes = &mut compile_stack.proto_mut().byte_codes;
+        match Bx::try_from(constant) {
+            Ok(constant) => byte_codes.push(Bytecode::load_constant(dst, constant)),
+            Err(_) => {
+                byte_codes.push(Bytecode::load_constant_extra_args(dst));
+                byte_codes.push(Bytecode::extra_arguments(Ax::try_from(constant)?));
+            }
+        }
+        Ok(())
+    }
+
+    /// Adjusts a call or variadic arguments that were just discharged
+    /// to produce exactly one value
+    fn truncate_to_single_value(compile_stack: &mut CompileStack<'a>) {
+        let Some(last_bytecode) = compile_stack.proto_mut().byte_codes.last_mut() else {
+            unreachable!("Bytecodes should not be empty while discharging.");
+        };
+        match OpCode::read(**last_bytecode) {
+            OpCode::Call => {
+                let (function, in_params, _, _) = last_bytecode.decode_abck();
+                *last_bytecode = Bytecode::call(function, in_params, 2);
+            }
+            OpCode::VariadicArguments => {
+                let (register, _, _, _) = last_bytecode.decode_abck();
+                *last_bytecode = Bytecode::variadic_arguments(register, 2);
+            }
+            _ => (),
+        }
+    }
+
+    fn discharge_into_table_access(
+        &self,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let Self::TableAccess { table, key, record } = self else {
+            unreachable!(
+                "Destination of `discharge_into_table_access` must be `ExpDesc::TableAccess`, but was {:?}.",
+                self
+            );
+        };
+
+        match (table.as_ref(), key.as_ref(), record, src) {
+            (_, _, _, src @ ExpDesc::Upvalue(_)) => {
+                let (_, stack_exp) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                stack_exp.discharge(src, compile_stack)?;
+                // The value is kept reserved, so computing the key
+                // does not overwrite it
+                self.discharge(&stack_exp, compile_stack)?;
+                compile_stack.compile_context_mut().stack_top -= 1;
+
+                Ok(())
+            }
+            (
+                _,
+                _,
+                _,
+                src @ (ExpDesc::Nil
+                | ExpDesc::Boolean(_)
+                | ExpDesc::Closure(_)
+                | ExpDesc::Unop(_, _)
+                | ExpDesc::Binop(_, _, _)
+                | ExpDesc::FunctionCall(_, _)
+                | ExpDesc::MethodCall(_, _, _)
+                | ExpDesc::VariadicArguments),
+            ) => self.discharge_src_through_register(src, compile_stack),
+            #[cfg(feature = "float")]
+            (_, _, _, src @ ExpDesc::Float(_)) => {
+                self.discharge_src_through_register(src, compile_stack)
+            }
+            (_, Self::Name(key), true, _) => {
+                // Rewrite all access in the form `t.x` as `t["x"]`
+                let table_access = Self::TableAccess {
+                    table: table.clone(),
+                    key: Box::new(ExpDesc::String(key.as_bytes().into())),
+                    record: false,
+                };
+                table_access.discharge(src, compile_stack)
+            }
+            (_, key, true, _) => {
+                unreachable!(
+                    "Record table access must be keyd by Name, but was {:?}.",
+                    key
+                );
+            }
+            // t.a.b = x
+            // f().a = x
+            (
+                table @ (Self::Global(_)
+                | Self::Upvalue(_)
+                | Self::TableAccess {
+                    table: _,
+                    key: _,
+                    record: _,
+                }
+                | Self::FunctionCall(_, _)
+                | Self::MethodCall(_, _, _)
+                | Self::Parenthesized(_)),
+                _,
+                false,
+                _,
+            ) => {
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                Self::discharge_single_value(&stack_top, table, compile_stack)?;
+                let table_access = Self::TableAccess {
+                    table: Box::new(stack_top),
+                    key: key.clone(),
+                    record: false,
+                };
+                table_access.discharge(src, compile_stack)?;
+                compile_stack.compile_context_mut().stack_top -= 1;
+
+                Ok(())
+            }
+            (Self::Name(table), _, false, _) => {
+                let Some(table) = compile_stack
+                    .view()
+                    .find_name(table)
+                    .or_else(|| compile_stack.view().capture_name(table))
+                    .or_else(|| compile_stack.view().capture_environment(table))
+                else {
+                    unreachable!("Should always fallback to Global.");
+                };
+                let table_access = Self::TableAccess {
+                    table: Box::new(table),
+                    key: key.clone(),
+                    record: false,
+                };
+                table_access.discharge(src, compile_stack)
+            }
+            (_, Self::Name(key), false, _) => {
+                let Some(name) = compile_stack
+                    .view()
+                    .find_name(key)
+                    .or_else(|| compile_stack.view().capture_name(key))
+                    .or_else(|| compile_stack.view().capture_environment(key))
+                else {
+                    unreachable!("Should always fallback to Global.");
+                };
+
+                let table_access = Self::TableAccess {
+                    table: table.clone(),
+                    key: Box::new(name),
+                    record: false,
+                };
+                table_access.discharge(src, compile_stack)?;
+
+                Ok(())
+            }
+            (_, Self::String(key), false, Self::Name(name)) => {
+                // Storing the key into constants early to match the ordering
+                // of the official compiler
+                let _ = compile_stack.push_constant(key.as_ref())?;
+                let Some(name) = compile_stack
+                    .view()
+                    .find_name(name)
+                    .or_else(|| compile_stack.view().capture_name(name))
+                    .or_else(|| compile_stack.view().capture_environment(name))
+                else {
+                    unreachable!("Should always fallback to Global.");
+                };
+                self.discharge(&name, compile_stack)
+            }
+            (_, _, false, Self::Name(name)) => {
+                let Some(name) = compile_stack
+                    .view()
+                    .find_name(name)
+                    .or_else(|| compile_stack.view().capture_name(name))
+                    .or_else(|| compile_stack.view().capture_environment(name))
+                else {
+                    unreachable!("Should always fallback to Global.");
+                };
+                self.discharge(&name, compile_stack)
+            }
+            // local t, a
+            // t[1] = a
+            (Self::Local(table), Self::Integer(index), false, Self::Local(src))
+                if u8::try_from(*index).is_ok() =>
+            {
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::set_index(
+                        u8::try_from(*table)?,
+                        u8::try_from(*index)?,
+                        u8::try_from(*src)?,
+                        K::ZERO,
+                    ));
+                Ok(())
+            }
+            // local t
+            // t[1] = "a"
+            (
+                Self::Local(table),
+                Self::Integer(index),
+                false,
+                src @ (Self::Integer(_) | Self::String(_)),
+            ) if u8::try_from(*index).is_ok() => {
+                let constant = match src {
+                    Self::Integer(integer) => compile_stack.push_constant(*integer),
+                    Self::String(string) => compile_stack.push_constant(string.as_ref()),
+                    _ => unreachable!("Constant source should be an integer or a string."),
+                }?;
+                let Ok(constant) = u8::try_from(constant) else {
+                    return self.discharge_through_register(src, compile_stack);
+                };
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::set_index(
+                        u8::try_from(*table)?,
+                        u8::try_from(*index)?,
+                        constant,
+                        K::ONE,
+                    ));
+                Ok(())
+            }
+            // local t
+            // t[#t + 1] = a
+            // t[256] = a
+            // t[nil] = a, fails when it runs
+            (
+                Self::Local(_),
+                key @ (Self::Nil
+                | Self::VariadicArguments
+                | Self::Table(_)
+                | Self::Closure(_)
+                | Self::Boolean(_)
+                | Self::Integer(_)
+                | Self::Unop(_, _)
+                | Self::Binop(_, _, _)
+                | Self::Global(_)
+                | Self::Upvalue(_)
+                | Self::TableAccess { .. }
+                | Self::FunctionCall(_, _)
+                | Self::MethodCall(_, _, _)
+                | Self::Parenthesized(_)),
+                false,
+                _,
+            ) => Self::discharge_key_through_register(table, key, src, compile_stack),
+            #[cfg(feature = "float")]
+            (Self::Local(_), key @ Self::Float(_), false, _) => {
+                Self::discharge_key_through_register(table, key, src, compile_stack)
+            }
+            // local t, k
+            // t[k] = 1
+            (Self::Local(table), Self::Local(key), false, Self::Integer(integer)) => {
+                let constant = compile_stack.push_constant(*integer)?;
+                let Ok(constant) = u8::try_from(constant) else {
+                    return self.discharge_through_register(src, compile_stack);
+                };
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::set_table(
+                        u8::try_from(*table)?,
+                        u8::try_from(*key)?,
+                        constant,
+                        K::ONE,
+                    ));
+                Ok(())
+            }
+            // local t, k
+            // t[k] = "a"
+            (Self::Local(table), Self::Local(key), false, Self::String(string)) => {
+                let constant = compile_stack.push_constant(string.as_ref())?;
+                let Ok(constant) = u8::try_from(constant) else {
+                    return self.discharge_through_register(src, compile_stack);
+                };
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::set_table(
+                        u8::try_from(*table)?,
+                        u8::try_from(*key)?,
+                        constant,
+                        K::ONE,
+                    ));
                 Ok(())
             }
             // local t, k, a
@@ -1669,47 +2032,62 @@ impl<'a> ExpDesc<'a> {
             }
             // local t
             // t["x"] = 1
-            (Self::Local(table), Self::String(key), false, Self::Integer(integer)) => {
-                let key_constant = compile_stack.proto_mut().push_constant(*key)?;
-                let constant = compile_stack.proto_mut().push_constant(*integer)?;
+            (Self::Local(table_local), Self::String(key_string), false, Self::Integer(integer)) => {
+                let key_constant = compile_stack.push_constant(key_string.as_ref())?;
+                let Ok(key_constant) = u8::try_from(key_constant) else {
+                    return Self::discharge_key_through_register(table, key, src, compile_stack);
+                };
+                let constant = compile_stack.push_constant(*integer)?;
+                let Ok(constant) = u8::try_from(constant) else {
+                    return self.discharge_through_register(src, compile_stack);
+                };
                 compile_stack
                     .proto_mut()
                     .byte_codes
                     .push(Bytecode::set_field(
-                        u8::try_from(*table)?,
-                        u8::try_from(key_constant)?,
-                        u8::try_from(constant)?,
+                        u8::try_from(*table_local)?,
+                        key_constant,
+                        constant,
                         K::ONE,
                     ));
                 Ok(())
             }
             // local t
             // t["x"] = "y"
-            (Self::Local(table), Self::String(key), false, Self::String(string)) => {
-                let key_constant = compile_stack.proto_mut().push_constant(*key)?;
-                let constant = compile_stack.proto_mut().push_constant(*string)?;
+            (Self::Local(table_local), Self::String(key_string), false, Self::String(string)) => {
+                let key_constant = compile_stack.push_constant(key_string.as_ref())?;
+                let Ok(key_constant) = u8::try_from(key_constant) else {
+                    return Self::discharge_key_through_register(table, key, src, compile_stack);
+                };
+                let constant = compile_stack.push_constant(string.as_ref())?;
+                let Ok(constant) = u8::try_from(constant) else {
+                    return self.discharge_through_register(src, compile_stack);
+                };
                 compile_stack
                     .proto_mut()
                     .byte_codes
                     .push(Bytecode::set_field(
-                        u8::try_from(*table)?,
-                        u8::try_from(key_constant)?,
-                        u8::try_from(constant)?,
+                        u8::try_from(*table_local)?,
+                        key_constant,
+                        constant,
                         K::ONE,
                     ));
                 Ok(())
             }
             // local t, a
             // t["x"] = a
-            (Self::Local(table), Self::String(key), false, Self::Local(src)) => {
-                let key_constant = compile_stack.proto_mut().push_constant(*key)?;
+            (Self::Local(table_local), Self::String(key_string), false, Self::Local(src_local)) => {
+                let key_constant = compile_stack.push_constant(key_string.as_ref())?;
+                let Ok(key_constant) = u8::try_from(key_constant) else {
+                    return Self::discharge_key_through_register(table, key, src, compile_stack);
+                };
                 compile_stack
                     .proto_mut()
                     .byte_codes
                     .push(Bytecode::set_field(
-                        u8::try_from(*table)?,
-                        u8::try_from(key_constant)?,
-                        u8::try_from(*src)?,
+                        u8::try_from(*table_local)?,
+                        key_constant,
+                        u8::try_from(*src_local)?,
                         K::ZERO,
                     ));
                 Ok(())
@@ -1717,7 +2095,7 @@ impl<'a> ExpDesc<'a> {
             // local t
             // t["x"] = a
             (_, _, false, global @ Self::Global(_)) => {
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
 
                 stack_top.discharge(global, compile_stack)?;
                 self.discharge(&stack_top, compile_stack)?;
@@ -1726,7 +2104,7 @@ impl<'a> ExpDesc<'a> {
                 Ok(())
             }
             (_, _, false, table @ Self::Table(_)) => {
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
                 stack_top.discharge(table, compile_stack)?;
                 self.discharge(&stack_top, compile_stack)?;
                 compile_stack.compile_context_mut().stack_top -= 1;
@@ -1743,7 +2121,7 @@ impl<'a> ExpDesc<'a> {
                     record: _,
                 },
             ) => {
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
                 stack_top.discharge(table_access, compile_stack)?;
                 self.discharge(&stack_top, compile_stack)?;
                 compile_stack.compile_context_mut().stack_top -= 1;
@@ -1751,320 +2129,93 @@ impl<'a> ExpDesc<'a> {
                 Ok(())
             }
             _ => unimplemented!("Can't discharge {:?} into {:?}", src, self),
-        }
-    }
-
-    fn discharge_into_condition(
-        &self,
-        src: &ExpDesc<'a>,
-        compile_stack: &mut CompileStack<'a>,
-    ) -> Result<(), Error> {
-        let Self::Condition {
-            jump_to_end,
-            if_condition,
-        } = self
-        else {
-            unreachable!(
-                "Destination of `discharge_into_table_access` must be `ExpDesc::TableAccess`, but was {:?}.",
-                self
-            );
-        };
-
-        match src {
-            Self::Name(name) => {
-                let Some(name) = compile_stack
-                    .view()
-                    .find_name(name)
-                    .or_else(|| compile_stack.view().capture_name(name))
-                    .or_else(|| compile_stack.view().capture_environment(name))
-                else {
-                    unreachable!("Should always fallback to Global.");
-                };
-                self.discharge(&name, compile_stack)
-            }
-            Self::Binop(Binop::Or, lhs, rhs) => {
-                Self::Condition {
-                    jump_to_end: false,
-                    if_condition: true,
-                }
-                .discharge(lhs, compile_stack)?;
-                Self::Condition {
-                    jump_to_end: true,
-                    if_condition: false,
-                }
-                .discharge(rhs, compile_stack)?;
-                Ok(())
-            }
-            Self::Binop(Binop::And, lhs, rhs) => {
-                let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
-                Self::Condition {
-                    jump_to_end: true,
-                    if_condition: false,
-                }
-                .discharge(lhs, compile_stack)?;
-
-                Self::resolve_jumps_to_block(jumps_to_block, compile_stack)?;
-
-                Self::Condition {
-                    jump_to_end: true,
-                    if_condition: false,
-                }
-                .discharge(rhs, compile_stack)?;
-                Ok(())
-            }
-            Self::Binop(
-                op @ (Binop::LessThan
-                | Binop::GreaterThan
-                | Binop::LessEqual
-                | Binop::GreaterEqual
-                | Binop::Equal
-                | Binop::NotEqual),
-                lhs,
-                rhs,
-            ) => match (op, lhs.as_ref(), rhs.as_ref()) {
-                (_, Self::Name(name), _) => {
-                    let Some(name) = compile_stack
-                        .view()
-                        .find_name(name)
-                        .or_else(|| compile_stack.view().capture_name(name))
-                        .or_else(|| compile_stack.view().capture_environment(name))
-                    else {
-                        unreachable!("Should always fallback to Global.");
-                    };
-                    self.discharge(
-                        &Self::Binop(*op, Box::new(name), rhs.clone()),
-                        compile_stack,
-                    )
-                }
-                (_, _, Self::Name(name)) => {
-                    let Some(name) = compile_stack
-                        .view()
-                        .find_name(name)
-                        .or_else(|| compile_stack.view().capture_name(name))
-                        .or_else(|| compile_stack.view().capture_environment(name))
-                    else {
-                        unreachable!("Should always fallback to Global.");
-                    };
-                    self.discharge(
-                        &Self::Binop(*op, lhs.clone(), Box::new(name)),
-                        compile_stack,
-                    )
-                }
-                (Binop::LessThan, Self::Local(local), Self::Integer(integer)) => {
-                    if let Ok(integer) = i8::try_from(*integer) {
-                        compile_stack
-                            .proto_mut()
-                            .byte_codes
-                            .push(Bytecode::less_than_integer(
-                                u8::try_from(*local)?,
-                                integer,
-                                *if_condition,
-                            ));
-
-                        let jump = compile_stack.proto_mut().byte_codes.len();
-                        compile_stack
-                            .proto_mut()
-                            .byte_codes
-                            .push(Bytecode::jump(Sj::ZERO));
-                        if *jump_to_end {
-                            compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                        } else {
-                            compile_stack
-                                .compile_context_mut()
-                                .jumps_to_block
-                                .push(jump);
-                        }
-
-                        Ok(())
-                    } else {
-                        let (_, stack_top) =
-                            compile_stack.compile_context_mut().reserve_stack_top();
-                        stack_top.discharge(rhs.as_ref(), compile_stack)?;
-                        self.discharge(
-                            &Self::Binop(*op, lhs.clone(), Box::new(stack_top)),
-                            compile_stack,
-                        )?;
-                        compile_stack.compile_context_mut().stack_top -= 1;
-                        Ok(())
-                    }
-                }
-                (Binop::GreaterThan, Self::Local(local), Self::Integer(integer)) => {
-                    if let Ok(integer) = i8::try_from(*integer) {
-                        compile_stack
-                            .proto_mut()
-                            .byte_codes
-                            .push(Bytecode::greater_than_integer(
-                                u8::try_from(*local)?,
-                                integer,
-                                *if_condition,
-                            ));
-
-                        let jump = compile_stack.proto_mut().byte_codes.len();
-                        compile_stack
-                            .proto_mut()
-                            .byte_codes
-                            .push(Bytecode::jump(Sj::ZERO));
-                        if *jump_to_end {
-                            compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                        } else {
-                            compile_stack
-                                .compile_context_mut()
-                                .jumps_to_block
-                                .push(jump);
-                        }
-
-                        Ok(())
-                    } else {
-                        let (_, stack_top) =
-                            compile_stack.compile_context_mut().reserve_stack_top();
-                        stack_top.discharge(rhs.as_ref(), compile_stack)?;
-                        self.discharge(
-                            &Self::Binop(*op, lhs.clone(), Box::new(stack_top)),
-                            compile_stack,
-                        )?;
-                        compile_stack.compile_context_mut().stack_top -= 1;
-                        Ok(())
-                    }
-                }
-                (Binop::GreaterThan, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::less_than(
-                            u8::try_from(*rhs)?,
-                            u8::try_from(*lhs)?,
-                            *if_condition,
-                        ));
-                    let jump = compile_stack.proto_mut().byte_codes.len();
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-                    if *jump_to_end {
-                        compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                    } else {
-                        compile_stack
-                            .compile_context_mut()
-                            .jumps_to_block
-                            .push(jump);
-                    }
-
-                    Ok(())
-                }
-                (Binop::LessEqual, Self::Local(_), string @ Self::String(_)) => {
-                    let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                    stack_top.discharge(string, compile_stack)?;
-                    self.discharge(
-                        &Self::Binop(*op, lhs.clone(), Box::new(stack_top)),
-                        compile_stack,
-                    )?;
-                    compile_stack.compile_context_mut().stack_top -= 1;
-                    Ok(())
-                }
-                (Binop::LessEqual, Self::Local(lhs), Self::Local(rhs)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::less_equal(
-                            u8::try_from(*lhs)?,
-                            u8::try_from(*rhs)?,
-                            *if_condition,
-                        ));
-                    let jump = compile_stack.proto_mut().byte_codes.len();
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-                    if *jump_to_end {
-                        compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                    } else {
-                        compile_stack
-                            .compile_context_mut()
-                            .jumps_to_block
-                            .push(jump);
-                    }
+        }
+    }
 
-                    Ok(())
-                }
-                (Binop::GreaterEqual, Self::Local(lhs), Self::Integer(integer)) => {
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::greater_equal_integer(
-                            u8::try_from(*lhs)?,
-                            i8::try_from(*integer)?,
-                            *if_condition,
-                        ));
-                    let jump = compile_stack.proto_mut().byte_codes.len();
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-                    if *jump_to_end {
-                        compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                    } else {
-                        compile_stack
-                            .compile_context_mut()
-                            .jumps_to_block
-                            .push(jump);
-                    }
+    fn discharge_into_condition(
+        &self,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let Self::Condition {
+            jump_to_end,
+            if_condition,
+        } = self
+        else {
+            unreachable!(
+                "Destination of `discharge_into_table_access` must be `ExpDesc::TableAccess`, but was {:?}.",
+                self
+            );
+        };
 
-                    Ok(())
+        match src {
+            Self::Name(name) => {
+                let Some(name) = compile_stack
+                    .view()
+                    .find_name(name)
+                    .or_else(|| compile_stack.view().capture_name(name))
+                    .or_else(|| compile_stack.view().capture_environment(name))
+                else {
+                    unreachable!("Should always fallback to Global.");
+                };
+                self.discharge(&name, compile_stack)
+            }
+            // Jumping when `lhs or rhs` is `true` is jumping when either is,
+            // and likewise for `and` and `false`
+            Self::Binop(Binop::Or, lhs, rhs) if *if_condition => {
+                self.discharge(lhs, compile_stack)?;
+                self.discharge(rhs, compile_stack)
+            }
+            Self::Binop(Binop::And, lhs, rhs) if !*if_condition => {
+                self.discharge(lhs, compile_stack)?;
+                self.discharge(rhs, compile_stack)
+            }
+            // Otherwise the value of `lhs` that decides the result skips the
+            // test of `rhs`, using the other list of jumps
+            Self::Binop(op @ (Binop::Or | Binop::And), lhs, rhs) => {
+                let jumps_to_block = compile_stack.compile_context_mut().jumps_to_block.len();
+                let jumps_to_end = compile_stack.compile_context_mut().jumps_to_end.len();
+
+                Self::Condition {
+                    jump_to_end: !*jump_to_end,
+                    if_condition: *op == Binop::Or,
                 }
-                (Binop::Equal, Self::Local(lhs), Self::String(name)) => {
-                    let constant = compile_stack.proto_mut().push_constant(*name)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::equal_constant(
-                            u8::try_from(*lhs)?,
-                            u8::try_from(constant)?,
-                            *if_condition,
-                        ));
-                    let jump = compile_stack.proto_mut().byte_codes.len();
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-                    if *jump_to_end {
-                        compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                    } else {
-                        compile_stack
-                            .compile_context_mut()
-                            .jumps_to_block
-                            .push(jump);
-                    }
+                .discharge(lhs, compile_stack)?;
+                self.discharge(rhs, compile_stack)?;
 
-                    Ok(())
+                if *jump_to_end {
+                    Self::resolve_jumps_to_block(jumps_to_block, compile_stack)
+                } else {
+                    Self::resolve_jumps_to_end(jumps_to_end, compile_stack)
                 }
-                (Binop::Equal, Self::Local(lhs), Self::Integer(integer)) => {
-                    let constant = compile_stack.proto_mut().push_constant(*integer)?;
-                    compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::equal_constant(
-                            u8::try_from(*lhs)?,
-                            u8::try_from(constant)?,
-                            *if_condition,
-                        ));
-                    let jump = compile_stack.proto_mut().byte_codes.len();
+            }
+            Self::Binop(
+                op @ (Binop::LessThan
+                | Binop::GreaterThan
+                | Binop::LessEqual
+                | Binop::GreaterEqual
+                | Binop::Equal
+                | Binop::NotEqual),
+                lhs,
+                rhs,
+            ) => {
+                Self::discharge_comparison(*op, lhs, rhs, *if_condition, None, compile_stack)?;
+                let jump = compile_stack.proto_mut().byte_codes.len();
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::jump(Sj::ZERO));
+                if *jump_to_end {
+                    compile_stack.compile_context_mut().jumps_to_end.push(jump);
+                } else {
                     compile_stack
-                        .proto_mut()
-                        .byte_codes
-                        .push(Bytecode::jump(Sj::ZERO));
-                    if *jump_to_end {
-                        compile_stack.compile_context_mut().jumps_to_end.push(jump);
-                    } else {
-                        compile_stack
-                            .compile_context_mut()
-                            .jumps_to_block
-                            .push(jump);
-                    }
-
-                    Ok(())
+                        .compile_context_mut()
+                        .jumps_to_block
+                        .push(jump);
                 }
-                _ => unimplemented!("Can't discharge binary operation {:?}.", src),
-            },
+
+                Ok(())
+            }
             Self::Local(local) => {
                 compile_stack
                     .proto_mut()
@@ -2085,14 +2236,14 @@ impl<'a> ExpDesc<'a> {
                 }
                 Ok(())
             }
-            global @ Self::Global(_) => {
-                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top();
-                stack_top.discharge(global, compile_stack)?;
+            // Any other value is tested on a register
+            exp => {
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                Self::discharge_single_value(&stack_top, exp, compile_stack)?;
                 self.discharge(&stack_top, compile_stack)?;
                 compile_stack.compile_context_mut().stack_top -= 1;
                 Ok(())
             }
-            _ => unimplemented!("Can't make condition out of {:?}.", src),
         }
     }
 
@@ -2118,6 +2269,459 @@ impl<'a> ExpDesc<'a> {
         }
     }
 
+    /// Emits the comparison between `lhs` and `rhs`, skipping the next
+    /// bytecode if the result is different from `test`
+    ///
+    /// Operands that can't be encoded on the comparison are discharged into
+    /// `scratch`, if available, or into temporary registers.
+    fn discharge_comparison(
+        op: Binop,
+        lhs: &Self,
+        rhs: &Self,
+        test: bool,
+        scratch: Option<u8>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        if op == Binop::NotEqual {
+            return Self::discharge_comparison(
+                Binop::Equal,
+                lhs,
+                rhs,
+                !test,
+                scratch,
+                compile_stack,
+            );
+        }
+
+        let lhs = Self::resolve_operand(lhs, compile_stack);
+        let rhs = Self::resolve_operand(rhs, compile_stack);
+
+        let bytecode = match (op, &lhs, &rhs) {
+            (Binop::Equal, Self::Local(lhs), Self::Local(rhs)) => Some(Bytecode::equal(
+                u8::try_from(*lhs)?,
+                u8::try_from(*rhs)?,
+                test,
+            )),
+            (Binop::Equal, Self::Local(local), Self::Integer(integer))
+            | (Binop::Equal, Self::Integer(integer), Self::Local(local))
+                if immediate(*integer).is_some() =>
+            {
+                Some(Bytecode::equal_integer(
+                    u8::try_from(*local)?,
+                    i8::try_from(*integer)?,
+                    test,
+                ))
+            }
+            (Binop::Equal, Self::Local(local), constant)
+            | (Binop::Equal, constant, Self::Local(local))
+                if constant.is_number() || matches!(constant, Self::String(_)) =>
+            {
+                let constant = match constant {
+                    Self::Integer(integer) => compile_stack.push_constant(*integer),
+                    #[cfg(feature = "float")]
+                    Self::Float(float) => compile_stack.push_constant(*float),
+                    Self::String(string) => compile_stack.push_constant(string.as_ref()),
+                    _ => unreachable!("Constant operand should be a number or string."),
+                }?;
+                match u8::try_from(constant) {
+                    Ok(constant) => Some(Bytecode::equal_constant(
+                        u8::try_from(*local)?,
+                        constant,
+                        test,
+                    )),
+                    Err(_) => None,
+                }
+            }
+            (Binop::LessThan, Self::Local(lhs), Self::Local(rhs)) => Some(Bytecode::less_than(
+                u8::try_from(*lhs)?,
+                u8::try_from(*rhs)?,
+                test,
+            )),
+            (Binop::GreaterThan, Self::Local(lhs), Self::Local(rhs)) => Some(Bytecode::less_than(
+                u8::try_from(*rhs)?,
+                u8::try_from(*lhs)?,
+                test,
+            )),
+            (Binop::LessEqual, Self::Local(lhs), Self::Local(rhs)) => Some(Bytecode::less_equal(
+                u8::try_from(*lhs)?,
+                u8::try_from(*rhs)?,
+                test,
+            )),
+            (Binop::GreaterEqual, Self::Local(lhs), Self::Local(rhs)) => Some(
+                Bytecode::less_equal(u8::try_from(*rhs)?, u8::try_from(*lhs)?, test),
+            ),
+            (op, Self::Local(local), Self::Integer(integer)) if immediate(*integer).is_some() => {
+                let local = u8::try_from(*local)?;
+                let integer = i8::try_from(*integer)?;
+                Some(match op {
+                    Binop::LessThan => Bytecode::less_than_integer(local, integer, test),
+                    Binop::LessEqual => Bytecode::less_equal_integer(local, integer, test),
+                    Binop::GreaterThan => Bytecode::greater_than_integer(local, integer, test),
+                    Binop::GreaterEqual => Bytecode::greater_equal_integer(local, integer, test),
+                    _ => unreachable!("Equality was matched by previous arms."),
+                })
+            }
+            (op, Self::Integer(integer), Self::Local(local)) if immediate(*integer).is_some() => {
+                // The immediate comparisons only take the integer on the
+                // right, so the comparison is mirrored
+                let local = u8::try_from(*local)?;
+                let integer = i8::try_from(*integer)?;
+                Some(match op {
+                    Binop::LessThan => Bytecode::greater_than_integer(local, integer, test),
+                    Binop::LessEqual => Bytecode::greater_equal_integer(local, integer, test),
+                    Binop::GreaterThan => Bytecode::less_than_integer(local, integer, test),
+                    Binop::GreaterEqual => Bytecode::less_equal_integer(local, integer, test),
+                    _ => unreachable!("Equality was matched by previous arms."),
+                })
+            }
+            _ => None,
+        };
+        if let Some(bytecode) = bytecode {
+            compile_stack.proto_mut().byte_codes.push(bytecode);
+            return Ok(());
+        }
+
+        // Load one of the operands into a register and try again, the left
+        // operand goes first so that constants on the right can still be
+        // encoded on the comparison
+        let (operand, other) = if matches!(lhs, Self::Local(_)) {
+            (&rhs, &lhs)
+        } else {
+            (&lhs, &rhs)
+        };
+        // Calls need to be made at the top of the stack, so the scratch
+        // register can only take them if it is the last register reserved
+        let scratch_on_top = scratch.is_some_and(|scratch| {
+            usize::from(scratch) + 1 == usize::from(compile_stack.compile_context_mut().stack_top)
+        });
+        let makes_call = !operand.is_number()
+            && !matches!(
+                operand,
+                Self::Nil | Self::Boolean(_) | Self::String(_) | Self::Global(_) | Self::Upvalue(_)
+            );
+        let (register, scratch, temporaries) = match scratch {
+            Some(scratch)
+                if *other != Self::Local(usize::from(scratch))
+                    && (scratch_on_top || !makes_call) =>
+            {
+                (Self::Local(usize::from(scratch)), None, 0)
+            }
+            _ => {
+                let (_, stack_top) = compile_stack.compile_context_mut().reserve_stack_top()?;
+                (stack_top, scratch, 1)
+            }
+        };
+        register.discharge(operand, compile_stack)?;
+        Self::truncate_to_single_value(compile_stack);
+
+        let (lhs, rhs) = if matches!(lhs, Self::Local(_)) {
+            (lhs.clone(), register)
+        } else {
+            (register, rhs.clone())
+        };
+        Self::discharge_comparison(op, &lhs, &rhs, test, scratch, compile_stack)?;
+        compile_stack.compile_context_mut().stack_top -= temporaries;
+
+        Ok(())
+    }
+
+    /// Resolves names into locals, upvalues, or globals
+    fn resolve_operand(operand: &Self, compile_stack: &mut CompileStack<'a>) -> Self {
+        match operand {
+            Self::Name(name) => {
+                let Some(name) = compile_stack
+                    .view()
+                    .find_name(name)
+                    .or_else(|| compile_stack.view().capture_name(name))
+                    .or_else(|| compile_stack.view().capture_environment(name))
+                else {
+                    unreachable!("Should always fallback to Global.");
+                };
+                name
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Applies the operation to the register `lhs` and the numeral `rhs`,
+    /// as an immediate or a constant operand if it can be encoded in the
+    /// bytecode
+    ///
+    /// `flip` is set when the numeral was the left-hand operand, so the
+    /// metamethod gets the operands in their original order.
+    fn discharge_number_operand(
+        &self,
+        op: Binop,
+        lhs: u8,
+        rhs: &Self,
+        flip: bool,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let ExpDesc::Local(dst) = &self else {
+            unreachable!("Destination of operation must be `ExpDesc::Local`.");
+        };
+        let dst = u8::try_from(*dst)?;
+
+        match (op, rhs) {
+            (
+                Binop::Add | Binop::Sub | Binop::ShiftRight | Binop::ShiftLeft,
+                Self::Integer(integer),
+            ) if immediate(*integer).is_some() => {
+                let integer = i8::try_from(*integer)?;
+                // Lua has no immediate subtraction or shift left by an
+                // integer, so they are an addition or shift right by the
+                // negated amount, while the metamethod gets the integer
+                let bytecode = match op {
+                    Binop::Add => Bytecode::add_integer(dst, lhs, integer),
+                    Binop::Sub => Bytecode::add_integer(dst, lhs, -integer),
+                    Binop::ShiftRight => Bytecode::shift_right_integer(dst, lhs, integer),
+                    _ => Bytecode::shift_right_integer(dst, lhs, -integer),
+                };
+                compile_stack.proto_mut().byte_codes.extend([
+                    bytecode,
+                    Bytecode::metamethod_integer(lhs, integer, op.metamethod_event(), flip),
+                ]);
+                Ok(())
+            }
+            (Binop::ShiftLeft | Binop::ShiftRight, _) => {
+                self.discharge_with_register_operand(op, lhs, rhs, flip, compile_stack)
+            }
+            _ => {
+                let constant = match rhs {
+                    Self::Integer(integer) => compile_stack.push_constant(*integer),
+                    #[cfg(feature = "float")]
+                    Self::Float(float) => compile_stack.push_constant(*float),
+                    _ => unreachable!("Constant operand should be a number."),
+                }?;
+                if let Ok(constant) = u8::try_from(constant) {
+                    compile_stack
+                        .proto_mut()
+                        .byte_codes
+                        .extend(Self::constant_arithmetic(op, dst, lhs, constant, flip));
+                    Ok(())
+                } else {
+                    // Constants past the 256th can't be used as operands
+                    self.discharge_with_register_operand(op, lhs, rhs, flip, compile_stack)
+                }
+            }
+        }
+    }
+
+    /// Discharges `rhs` into a register before applying the operation, for
+    /// operands that can't be encoded in the bytecode, `flip` swaps the
+    /// operands back if they were swapped
+    fn discharge_with_register_operand(
+        &self,
+        op: Binop,
+        lhs: u8,
+        rhs: &Self,
+        flip: bool,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let ExpDesc::Local(dst) = &self else {
+            unreachable!("Destination of operation must be `ExpDesc::Local`.");
+        };
+        let dst = u8::try_from(*dst)?;
+        let operands = |lhs, rhs| if flip { (rhs, lhs) } else { (lhs, rhs) };
+        if lhs != dst {
+            self.discharge(rhs, compile_stack)?;
+            let (lhs, rhs) = operands(lhs, dst);
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .extend(Self::register_arithmetic(op, dst, lhs, rhs));
+        } else {
+            let (rhs_register, stack_top) =
+                compile_stack.compile_context_mut().reserve_stack_top()?;
+            stack_top.discharge(rhs, compile_stack)?;
+            let (lhs, rhs) = operands(lhs, rhs_register);
+            compile_stack
+                .proto_mut()
+                .byte_codes
+                .extend(Self::register_arithmetic(op, dst, lhs, rhs));
+            compile_stack.compile_context_mut().stack_top -= 1;
+        }
+        Ok(())
+    }
+
+    /// Arithmetic or bitwise operation between two registers, followed
+    /// by the `MMBIN` that calls the metamethod of the operands when they
+    /// are not numbers
+    fn register_arithmetic(op: Binop, dst: u8, lhs: u8, rhs: u8) -> [Bytecode; 2] {
+        let bytecode = match op {
+            Binop::Add => Bytecode::add(dst, lhs, rhs),
+            Binop::Sub => Bytecode::sub(dst, lhs, rhs),
+            Binop::Mul => Bytecode::mul(dst, lhs, rhs),
+            Binop::Mod => Bytecode::mod_bytecode(dst, lhs, rhs),
+            Binop::Pow => Bytecode::pow(dst, lhs, rhs),
+            Binop::Div => Bytecode::div(dst, lhs, rhs),
+            Binop::Idiv => Bytecode::idiv(dst, lhs, rhs),
+            Binop::BitAnd => Bytecode::bit_and(dst, lhs, rhs),
+            Binop::BitOr => Bytecode::bit_or(dst, lhs, rhs),
+            Binop::BitXor => Bytecode::bit_xor(dst, lhs, rhs),
+            Binop::ShiftLeft => Bytecode::shift_left(dst, lhs, rhs),
+            Binop::ShiftRight => Bytecode::shift_right(dst, lhs, rhs),
+            other => unreachable!("{:?} is not an arithmetic operation.", other),
+        };
+        [
+            bytecode,
+            Bytecode::metamethod(lhs, rhs, op.metamethod_event()),
+        ]
+    }
+
+    /// Arithmetic or bitwise operation between a register and a constant,
+    /// followed by the `MMBINK` that calls the metamethod of the register
+    /// when it is not a number, with the constant first if `flip` is set
+    fn constant_arithmetic(op: Binop, dst: u8, lhs: u8, constant: u8, flip: bool) -> [Bytecode; 2] {
+        let bytecode = match op {
+            Binop::Add => Bytecode::add_constant(dst, lhs, constant),
+            Binop::Sub => Bytecode::sub_constant(dst, lhs, constant),
+            Binop::Mul => Bytecode::mul_constant(dst, lhs, constant),
+            Binop::Mod => Bytecode::mod_constant(dst, lhs, constant),
+            Binop::Pow => Bytecode::pow_constant(dst, lhs, constant),
+            Binop::Div => Bytecode::div_constant(dst, lhs, constant),
+            Binop::Idiv => Bytecode::idiv_constant(dst, lhs, constant),
+            Binop::BitAnd => Bytecode::bit_and_constant(dst, lhs, constant),
+            Binop::BitOr => Bytecode::bit_or_constant(dst, lhs, constant),
+            Binop::BitXor => Bytecode::bit_xor_constant(dst, lhs, constant),
+            other => unreachable!("{:?} has no constant operand variant.", other),
+        };
+        [
+            bytecode,
+            Bytecode::metamethod_constant(lhs, constant, op.metamethod_event(), flip),
+        ]
+    }
+
+    /// Whether the expression is a numeral
+    pub fn is_number(&self) -> bool {
+        match self {
+            Self::Integer(_) => true,
+            #[cfg(feature = "float")]
+            Self::Float(_) => true,
+            _ => false,
+        }
+    }
+
+    fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Binop(
+                Binop::LessThan
+                    | Binop::GreaterThan
+                    | Binop::LessEqual
+                    | Binop::GreaterEqual
+                    | Binop::Equal
+                    | Binop::NotEqual,
+                _,
+                _,
+            )
+        )
+    }
+
+    /// Discharges an `and`/`or` expression into the local `self`
+    ///
+    /// Returns the jumps that short-circuit with a truthy and with a falsy
+    /// value, the value is already on `self` when any of them is taken.
+    fn discharge_logical(
+        &self,
+        src: &ExpDesc<'a>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(Vec<usize>, Vec<usize>), Error> {
+        let Self::Local(dst) = self else {
+            unreachable!(
+                "Destination of `discharge_logical` must be `ExpDesc::Local`, but was {:?}.",
+                self
+            );
+        };
+        let dst = u8::try_from(*dst)?;
+
+        let (op, lhs, rhs) = match src {
+            Self::Binop(Binop::And, lhs, rhs) if lhs.is_comparison() && rhs.is_comparison() => {
+                self.discharge(src, compile_stack)?;
+                return Ok((Vec::new(), Vec::new()));
+            }
+            Self::Binop(op @ (Binop::Or | Binop::And), lhs, rhs) => (op, lhs, rhs),
+            other => {
+                self.discharge(other, compile_stack)?;
+                Self::truncate_to_single_value(compile_stack);
+                return Ok((Vec::new(), Vec::new()));
+            }
+        };
+        let is_or = matches!(op, Binop::Or);
+
+        let lhs = if let Self::Name(name) = lhs.as_ref() {
+            let Some(name) = compile_stack
+                .view()
+                .find_name(name)
+                .or_else(|| compile_stack.view().capture_name(name))
+                .or_else(|| compile_stack.view().capture_environment(name))
+            else {
+                unreachable!("Should always fallback to Global.");
+            };
+            name
+        } else {
+            lhs.as_ref().clone()
+        };
+        let (mut truthy, mut falsy) = match lhs {
+            Self::Local(register) if register == usize::from(dst) => {
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::test(dst, is_or));
+                (Vec::new(), Vec::new())
+            }
+            Self::Local(register) => {
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::test_set(dst, u8::try_from(register)?, is_or));
+                (Vec::new(), Vec::new())
+            }
+            lhs => {
+                let lists = self.discharge_logical(&lhs, compile_stack)?;
+                compile_stack
+                    .proto_mut()
+                    .byte_codes
+                    .push(Bytecode::test(dst, is_or));
+                lists
+            }
+        };
+        let shortcircuit = compile_stack.proto_mut().byte_codes.len();
+        compile_stack
+            .proto_mut()
+            .byte_codes
+            .push(Bytecode::jump(Sj::ZERO));
+
+        // The jumps that can't short-circuit this expression continue on `rhs`
+        if is_or {
+            truthy.push(shortcircuit);
+            Self::resolve_jumps(falsy.drain(..), compile_stack)?;
+        } else {
+            falsy.push(shortcircuit);
+            Self::resolve_jumps(truthy.drain(..), compile_stack)?;
+        }
+
+        let (rhs_truthy, rhs_falsy) = self.discharge_logical(rhs, compile_stack)?;
+        truthy.extend(rhs_truthy);
+        falsy.extend(rhs_falsy);
+        Ok((truthy, falsy))
+    }
+
+    /// Points `jumps` to the next bytecode
+    fn resolve_jumps(
+        jumps: impl IntoIterator<Item = usize>,
+        compile_stack: &mut CompileStack<'a>,
+    ) -> Result<(), Error> {
+        let byte_codes = &mut compile_stack.proto_mut().byte_codes;
+        let jump_dst = byte_codes.len();
+        for jump in jumps {
+            byte_codes[jump] = Bytecode::jump(Sj::try_from(
+                i32::try_from(jump_dst - jump - 1).map_err(|_| Error::LongJump)?,
+            )?);
+        }
+        Ok(())
+    }
+
     fn resolve_jumps_to_block(
         start_of_jumps_to_resolve: usize,
         compile_stack: &mut CompileStack<'a>,
@@ -2162,3 +2766,15 @@ impl<'a> ExpDesc<'a> {
         Ok(())
     }
 }
+
+/// Array items of a table constructor stored by each `SETLIST`, like the
+/// reference implementation, so constructors of any size fit the registers
+const FIELDS_PER_FLUSH: u8 = 50;
+
+/// Integer that fits the signed arguments of 8 bits, `sB` and `sC`,
+/// which can't hold `i8::MIN`
+fn immediate(integer: i64) -> Option<i8> {
+    i8::try_from(integer)
+        .ok()
+        .filter(|integer| *integer != i8::MIN)
+}
@@ -3,6 +3,14 @@ use alloc::{boxed::Box, vec::Vec};
 use super::exp_desc::ExpDesc;
 
 pub type TableFields<'a> = Vec<(TableKey<'a>, ExpDesc<'a>)>;
+pub type AttNameList = Vec<(Box<str>, Option<Attrib>)>;
+
+/// Attribute of a local variable, as in `local x <const> = 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Attrib {
+    Const,
+    Close,
+}
 
 #[must_use = "Contains a list of names that need to be added to constants"]
 #[derive(Debug, Default)]
@@ -1,8 +1,11 @@
 mod binops;
 mod compile_context;
 mod compile_stack;
+mod constant_folding;
 mod exp_desc;
 mod helper_types;
+#[cfg(feature = "peephole")]
+mod peephole;
 mod unops;
 
 use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
@@ -10,7 +13,7 @@ use compile_stack::{CompileFrame, CompileStack};
 
 use crate::{bytecode::Bytecode, function::Function, parser::Parser, program::Error, value::Value};
 
-use super::Local;
+use super::{ConstantPool, Local, UpvalueDescriptor};
 
 use compile_context::CompileContext;
 
@@ -20,12 +23,28 @@ pub struct Proto {
     pub constants: Vec<Value>,
     pub locals: Vec<Local>,
     pub upvalues: Vec<Box<str>>,
+    pub upvalue_descriptors: Vec<UpvalueDescriptor>,
     pub functions: Vec<Rc<Function>>,
+    /// First bytecode of each line, paired with the line
+    pub lines: Vec<(usize, usize)>,
 }
 
 impl Proto {
-    pub fn parse(program: &str) -> Result<Proto, Error> {
-        let chunk = Parser::parse(program)?;
+    /// Compiles `program`, errors are located on the chunk
+    /// if it has a name, and string constants are taken from `pool`
+    pub fn parse(
+        program: &str,
+        chunk_name: Option<&Rc<str>>,
+        pool: &mut ConstantPool,
+    ) -> Result<Proto, Error> {
+        let located = |error: Error, line: Option<usize>| match (chunk_name, line) {
+            (Some(chunk), Some(line)) => Error::Located {
+                chunk: chunk.clone(),
+                line,
+                error: Box::new(error),
+            },
+            _ => error,
+        };
 
         let compile_context = CompileContext::new_with_var_args(true);
         let proto = Self::default();
@@ -34,8 +53,36 @@ impl Proto {
                 proto,
                 compile_context,
             }],
+            pool,
+        };
+        // Errors are found while compiling the last statement seen
+        let compile_error = |compile_stack: &CompileStack, err: Error| {
+            let line = compile_stack
+                .stack
+                .last()
+                .and_then(|frame| frame.proto.lines.last())
+                .map(|(_, line)| *line);
+            located(err, line)
+        };
+
+        let scope = compile_stack
+            .chunk_start()
+            .map_err(|err| compile_error(&compile_stack, err))?;
+        // Statements of the main block are compiled as they are parsed
+        let chunk = match Parser::parse(program, |stat| compile_stack.chunk_stat(&stat)) {
+            Ok(chunk) => chunk,
+            Err(Error::Parse) => {
+                // The parser only finds where it stopped when checking
+                let line = chunk_name
+                    .and_then(|_| Parser::check(program).err())
+                    .and_then(|errors| errors.first().map(|err| err.line));
+                return Err(located(Error::Parse, line));
+            }
+            Err(err) => return Err(compile_error(&compile_stack, err)),
         };
-        compile_stack.chunk(&chunk)?;
+        if let Err(err) = compile_stack.chunk(&chunk, scope) {
+            return Err(compile_error(&compile_stack, err));
+        }
 
         assert_eq!(
             compile_stack.stack.len(),
@@ -52,6 +99,15 @@ impl Proto {
             unreachable!();
         };
 
+        #[cfg(feature = "peephole")]
+        let proto = {
+            let mut proto = proto;
+            proto
+                .peephole()
+                .map_err(|err| compile_error(&compile_stack, err))?;
+            proto
+        };
+
         Ok(proto)
     }
 
@@ -75,15 +131,25 @@ impl Proto {
         new_position
     }
 
-    pub(super) fn push_upvalue(&mut self, upvalue: &str) -> usize {
-        let value = upvalue.into();
-        self.upvalues
-            .iter()
-            .position(|v| v == &value)
-            .unwrap_or_else(|| {
-                self.upvalues.push(value);
-                self.upvalues.len() - 1
-            })
+    /// Adds an upvalue found at `descriptor` when the closure is created,
+    /// or returns the upvalue with the same name
+    pub(super) fn push_upvalue(&mut self, upvalue: &str, descriptor: UpvalueDescriptor) -> usize {
+        self.find_upvalue(upvalue).unwrap_or_else(|| {
+            self.upvalues.push(upvalue.into());
+            self.upvalue_descriptors.push(descriptor);
+            self.upvalues.len() - 1
+        })
+    }
+
+    /// Marks the next bytecodes as coming from `line`
+    pub(super) fn mark_line(&mut self, line: usize) {
+        let start = self.byte_codes.len();
+        match self.lines.last_mut() {
+            Some((_, last)) if *last == line => (),
+            // Nothing was emitted for the previous line
+            Some((last_start, last)) if *last_start == start => *last = line,
+            _ => self.lines.push((start, line)),
+        }
     }
 
     pub fn find_upvalue(&self, name: &str) -> Option<usize> {
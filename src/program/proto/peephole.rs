@@ -0,0 +1,292 @@
+//! Peephole optimizations over the bytecodes of a finished [`Proto`]
+//!
+//! - jumps to a `JMP` go straight to where that `JMP` leads
+//! - `JMP 0` and `MOVE a a` are removed
+//! - `LFALSESKIP a` followed by a bytecode that nothing jumps to
+//!   becomes `LOADFALSE a`, and the unreachable bytecode is removed
+//!
+//! Removing bytecodes moves the ones after them, so jumps, lines, and
+//! scopes of locals are updated to the new positions.
+
+use alloc::vec::Vec;
+
+use crate::bytecode::{
+    OpCode,
+    arguments::{Bx, Sj},
+};
+
+use super::{Bytecode, Error, Proto};
+
+/// Limit of `JMP`s followed when collapsing a chain, cycles of
+/// jumps are left as they are
+const MAX_CHAIN: usize = 100;
+
+impl Proto {
+    pub fn peephole(&mut self) -> Result<(), Error> {
+        self.collapse_jump_chains()?;
+
+        let targets = self.jump_targets();
+        let mut removed = alloc::vec![false; self.byte_codes.len()];
+        for pc in 0..self.byte_codes.len() {
+            // A bytecode after a skip is where the skip lands if it does
+            // not happen, removing it would change what is skipped
+            if removed[pc] || (pc > 0 && skips_next(&self.byte_codes[pc - 1])) {
+                continue;
+            }
+            let bytecode = self.byte_codes[pc];
+            match bytecode.opcode() {
+                Some(OpCode::Move) => {
+                    let (dst, src, _, _) = bytecode.decode_abck();
+                    removed[pc] = *dst == *src;
+                }
+                Some(OpCode::Jump) => removed[pc] = *bytecode.decode_sj() == 0,
+                Some(OpCode::LoadFalseSkip)
+                    if pc + 1 < self.byte_codes.len() && !targets[pc + 1] =>
+                {
+                    let (dst, _, _, _) = bytecode.decode_abck();
+                    self.byte_codes[pc] = Bytecode::load_false(dst);
+                    removed[pc + 1] = true;
+                }
+                _ => (),
+            }
+        }
+
+        if removed.contains(&true) {
+            self.remove(&removed)?;
+        }
+        Ok(())
+    }
+
+    /// Retargets `JMP`s that land on another `JMP`
+    fn collapse_jump_chains(&mut self) -> Result<(), Error> {
+        for pc in 0..self.byte_codes.len() {
+            if self.byte_codes[pc].opcode() != Some(OpCode::Jump) {
+                continue;
+            }
+            let mut target = jump_target(pc, &self.byte_codes[pc]);
+            for _ in 0..MAX_CHAIN {
+                match self.byte_codes.get(target) {
+                    Some(next) if next.opcode() == Some(OpCode::Jump) && target != pc => {
+                        target = jump_target(target, next);
+                    }
+                    _ => break,
+                }
+            }
+            self.byte_codes[pc] = Bytecode::jump(Sj::try_from(offset(pc, target)?)?);
+        }
+        Ok(())
+    }
+
+    /// Bytecodes that are reached by a jump or a skip
+    fn jump_targets(&self) -> Vec<bool> {
+        let mut targets = alloc::vec![false; self.byte_codes.len() + 1];
+        for (pc, bytecode) in self.byte_codes.iter().enumerate() {
+            let skip = skips_next(bytecode).then_some(pc + 2);
+            for target in skip.into_iter().chain(any_jump_target(pc, bytecode)) {
+                if let Some(target) = targets.get_mut(target) {
+                    *target = true;
+                }
+            }
+        }
+        targets
+    }
+
+    /// Removes the bytecodes marked on `removed`, moving jumps, lines,
+    /// and locals to the new positions of their bytecodes
+    fn remove(&mut self, removed: &[bool]) -> Result<(), Error> {
+        // New position of each bytecode, removed bytecodes take the
+        // position of the next bytecode that is kept
+        let mut positions = Vec::with_capacity(removed.len() + 1);
+        let mut kept = 0;
+        for is_removed in removed {
+            positions.push(kept);
+            kept += usize::from(!is_removed);
+        }
+        positions.push(kept);
+
+        let mut byte_codes = Vec::with_capacity(kept);
+        for (pc, bytecode) in self.byte_codes.iter().enumerate() {
+            if removed[pc] {
+                continue;
+            }
+            let new_pc = positions[pc];
+            let bytecode = match (bytecode.opcode(), any_jump_target(pc, bytecode)) {
+                (Some(op), Some(target)) => relocate(op, bytecode, new_pc, positions[target])?,
+                _ => *bytecode,
+            };
+            byte_codes.push(bytecode);
+        }
+        self.byte_codes = byte_codes;
+
+        let mut lines: Vec<(usize, usize)> = Vec::with_capacity(self.lines.len());
+        for (start, line) in self.lines.iter() {
+            let start = positions[*start];
+            match lines.last_mut() {
+                // All bytecodes of the previous line were removed
+                Some((last_start, last)) if *last_start == start => *last = *line,
+                _ => lines.push((start, *line)),
+            }
+        }
+        self.lines = lines;
+
+        // Scopes of locals start at 1
+        let position = |pc: usize| {
+            pc.checked_sub(1)
+                .and_then(|pc| positions.get(pc))
+                .map_or(pc, |new_pc| new_pc + 1)
+        };
+        for local in self.locals.iter_mut() {
+            local.relocate(position(local.scope_start()), position(local.scope_end()));
+        }
+        Ok(())
+    }
+}
+
+/// Whether `bytecode` may skip the bytecode that follows it
+fn skips_next(bytecode: &Bytecode) -> bool {
+    matches!(
+        bytecode.opcode(),
+        Some(
+            OpCode::LoadFalseSkip
+                | OpCode::Equal
+                | OpCode::LessThan
+                | OpCode::LessEqual
+                | OpCode::EqualConstant
+                | OpCode::EqualInteger
+                | OpCode::LessThanInteger
+                | OpCode::LessEqualInteger
+                | OpCode::GreaterThanInteger
+                | OpCode::GreaterEqualInteger
+                | OpCode::Test
+                | OpCode::TestSet
+        )
+    )
+}
+
+fn jump_target(pc: usize, jump: &Bytecode) -> usize {
+    pc.saturating_add(1)
+        .saturating_add_signed(*jump.decode_sj() as isize)
+}
+
+/// Bytecode `bytecode` at `pc` jumps to, if it jumps
+fn any_jump_target(pc: usize, bytecode: &Bytecode) -> Option<usize> {
+    let bx = || *bytecode.decode_abx().1 as usize;
+    match bytecode.opcode()? {
+        OpCode::Jump => Some(jump_target(pc, bytecode)),
+        OpCode::ForPrepare => Some(pc + 2 + bx()),
+        OpCode::GenericForPrepare => Some(pc + 1 + bx()),
+        OpCode::ForLoop | OpCode::GenericForLoop => Some(pc + 1 - bx()),
+        _ => None,
+    }
+}
+
+fn offset(pc: usize, target: usize) -> Result<i32, Error> {
+    Ok(i32::try_from(target)? - i32::try_from(pc)? - 1)
+}
+
+/// `bytecode` moved to `pc`, jumping to `target`
+fn relocate(op: OpCode, bytecode: &Bytecode, pc: usize, target: usize) -> Result<Bytecode, Error> {
+    let (register, _) = bytecode.decode_abx();
+    Ok(match op {
+        OpCode::Jump => Bytecode::jump(Sj::try_from(offset(pc, target)?)?),
+        OpCode::ForPrepare => Bytecode::for_prepare(register, Bx::try_from(target - pc - 2)?),
+        OpCode::GenericForPrepare => {
+            Bytecode::generic_for_prepare(register, Bx::try_from(target - pc - 1)?)
+        }
+        OpCode::ForLoop => Bytecode::for_loop(register, Bx::try_from(pc + 1 - target)?),
+        OpCode::GenericForLoop => {
+            Bytecode::generic_for_loop(register, Bx::try_from(pc + 1 - target)?)
+        }
+        _ => unreachable!("{op:?} does not jump."),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::program::Local;
+
+    use super::*;
+
+    #[test]
+    fn test_jump_chains() {
+        let mut proto = Proto {
+            byte_codes: vec![
+                Bytecode::test(0, false),
+                Bytecode::jump(1i8),
+                Bytecode::jump(1i8),
+                Bytecode::jump(-4i8),
+                Bytecode::zero_return(),
+            ],
+            ..Proto::default()
+        };
+        proto.peephole().unwrap();
+        assert_eq!(
+            proto.byte_codes,
+            [
+                Bytecode::test(0, false),
+                Bytecode::jump(-2i8),
+                Bytecode::jump(1i8),
+                Bytecode::jump(-4i8),
+                Bytecode::zero_return(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_removed_bytecodes() {
+        let mut proto = Proto {
+            byte_codes: vec![
+                Bytecode::load_integer(0, 1i16),
+                Bytecode::move_bytecode(0, 0),
+                Bytecode::jump(0i8),
+                Bytecode::test(0, false),
+                Bytecode::move_bytecode(1, 1),
+                Bytecode::jump(-6i8),
+                Bytecode::zero_return(),
+            ],
+            locals: vec![Local::new("a".into(), 2, 8)],
+            lines: vec![(0, 1), (1, 2), (3, 3)],
+            ..Proto::default()
+        };
+        proto.peephole().unwrap();
+        assert_eq!(
+            proto.byte_codes,
+            [
+                Bytecode::load_integer(0, 1i16),
+                // Skipped by the test
+                Bytecode::test(0, false),
+                Bytecode::move_bytecode(1, 1),
+                Bytecode::jump(-4i8),
+                Bytecode::zero_return(),
+            ]
+        );
+        assert_eq!(proto.locals, [Local::new("a".into(), 2, 6)]);
+        assert_eq!(proto.lines, [(0, 1), (1, 3)]);
+    }
+
+    #[test]
+    fn test_load_false_skip() {
+        let mut proto = Proto {
+            byte_codes: vec![
+                Bytecode::load_false_skip(0),
+                Bytecode::load_true(0),
+                Bytecode::load_false_skip(1),
+                Bytecode::load_true(1),
+                Bytecode::jump(-2i8),
+            ],
+            ..Proto::default()
+        };
+        proto.peephole().unwrap();
+        assert_eq!(
+            proto.byte_codes,
+            [
+                Bytecode::load_false(0),
+                Bytecode::load_false_skip(1),
+                Bytecode::load_true(1),
+                Bytecode::jump(-2i8),
+            ]
+        );
+    }
+}
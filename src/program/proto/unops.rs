@@ -1,5 +1,8 @@
 use alloc::boxed::Box;
 
+#[cfg(feature = "float")]
+use crate::value::Value;
+
 use super::{Bytecode, Error, exp_desc::ExpDesc};
 
 // TODO compile time optimizations
@@ -21,8 +24,10 @@ pub fn unop_len<'a>(rhs: &ExpDesc<'a>) -> Result<ExpDesc<'a>, Error> {
 
 pub fn unop_neg<'a>(rhs: &ExpDesc<'a>) -> Result<ExpDesc<'a>, Error> {
     match rhs {
-        ExpDesc::Integer(int) => Ok(ExpDesc::Integer(-int)),
-        ExpDesc::Float(float) => Ok(ExpDesc::Float(-float)),
+        ExpDesc::Integer(int) => Ok(ExpDesc::Integer(int.wrapping_neg())),
+        // `-0.0` can't be told apart from `0.0` by the constant table
+        #[cfg(feature = "float")]
+        ExpDesc::Float(float) if !float.is_nan() && *float != 0.0 => Ok(ExpDesc::Float(-float)),
         other => Ok(ExpDesc::Unop(Bytecode::neg, Box::new(other.clone()))),
     }
 }
@@ -30,6 +35,12 @@ pub fn unop_neg<'a>(rhs: &ExpDesc<'a>) -> Result<ExpDesc<'a>, Error> {
 pub fn unop_bitnot<'a>(rhs: &ExpDesc<'a>) -> Result<ExpDesc<'a>, Error> {
     match rhs {
         ExpDesc::Integer(int) => Ok(ExpDesc::Integer(!int)),
+        #[cfg(feature = "float")]
+        ExpDesc::Float(float) => match Value::Float(*float).try_int() {
+            Value::Integer(int) => Ok(ExpDesc::Integer(!int)),
+            // Raises its error at runtime
+            _ => Ok(ExpDesc::Unop(Bytecode::bit_not, Box::new(rhs.clone()))),
+        },
         other => Ok(ExpDesc::Unop(Bytecode::bit_not, Box::new(other.clone()))),
     }
 }
@@ -0,0 +1,370 @@
+use crate::{Lua, Program, bytecode::Bytecode, program::Local};
+
+#[test]
+fn local_declaration() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a, b = 1
+local c = 3, 4
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_integer(0, 1i16),
+            Bytecode::load_nil(1, 0),
+            Bytecode::load_integer(2, 3i16),
+            Bytecode::load_integer(3, 4i16),
+            Bytecode::return_bytecode(3, 1, 1),
+        ],
+        &[],
+        &[
+            Local::new("a".into(), 4, 7),
+            Local::new("b".into(), 4, 7),
+            Local::new("c".into(), 6, 7),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn fewer_values() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local one = 1
+local a, b, c = 1
+assert(a == one)
+local b_type = type(b)
+assert(b_type == "nil")
+local c_type = type(c)
+assert(c_type == "nil")
+local d, e
+d, e = 2
+local two = 2
+assert(d == two)
+local e_type = type(e)
+assert(e_type == "nil")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn extra_values() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local count = 0
+local function bump()
+    count = count + 1
+    return count
+end
+local one, two, five = 1, 2, 5
+local a = 5, bump()
+assert(a == five)
+assert(count == one)
+local b, c = 1, 2, bump(), bump()
+assert(b == one)
+assert(c == two)
+local three = 3
+assert(count == three)
+local d, e
+d, e = 2, 1, bump()
+assert(d == two)
+assert(e == one)
+local four = 4
+assert(count == four)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn multiple_results() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function three()
+    return 1, 2, 3
+end
+local zero, one, two, ten = 0, 1, 2, 10
+local a, b, c, d = 0, three()
+assert(a == zero)
+assert(b == one)
+local e, f = three()
+assert(e == one)
+assert(f == two)
+local g, h, i = three(), 10
+assert(g == one)
+assert(h == ten)
+local i_type = type(i)
+assert(i_type == "nil")
+local j, k, l, m = three()
+local m_type = type(m)
+assert(m_type == "nil")
+local n, o
+n, o = three()
+assert(n == one)
+assert(o == two)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn variadic_arguments() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function first(...)
+    local a = ...
+    return a
+end
+local function pad(...)
+    local a, b, c = ...
+    return c
+end
+local function skip(...)
+    local a, b = 0, ...
+    return b
+end
+local function single(...)
+    local a, b = ..., 5
+    return a, b
+end
+local function parenthesized(...)
+    local a, b = (...)
+    local t = {(...)}
+    return select('#', (...)), #t, b
+end
+local one, five = 1, 5
+local a = first(1, 2, 3)
+assert(a == one)
+local b = pad(1, 2)
+local b_type = type(b)
+assert(b_type == "nil")
+local c = skip(1, 2)
+assert(c == one)
+local d, e = single(1, 2)
+assert(d == one)
+assert(e == five)
+local f, g, h = parenthesized(1, 2)
+assert(f == one)
+assert(g == one)
+local h_type = type(h)
+assert(h_type == "nil")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn assignment() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a, b, c = 1, 2, 3
+a, b, c = c, a, b
+"#,
+    )
+    .unwrap();
+    // Values are stored on temporaries and assigned in reverse,
+    // the last one is assigned directly
+    assert_eq!(
+        &program.byte_codes[4..9],
+        &[
+            Bytecode::move_bytecode(3, 2),
+            Bytecode::move_bytecode(4, 0),
+            Bytecode::move_bytecode(2, 1),
+            Bytecode::move_bytecode(1, 4),
+            Bytecode::move_bytecode(0, 3),
+        ]
+    );
+
+    let program = Program::parse(
+        r#"
+local function three()
+    return 1, 2, 3
+end
+local one, two, three_, ten = 1, 2, 3, 10
+local a, b, c = 1, 2, 3
+a, b, c = c, a, b
+assert(a == three_)
+assert(b == one)
+assert(c == two)
+local t = {}
+t.x, t.y, t.z = three()
+assert(t.x == one)
+assert(t.z == three_)
+t.x = three()
+assert(t.x == one)
+t[1], t[2], t[3] = 10, three()
+assert(t[1] == ten)
+assert(t[3] == two)
+g1, g2 = three()
+assert(g1 == one)
+assert(g2 == two)
+g1 = three()
+assert(g1 == one)
+local function set()
+    a, b = three()
+end
+set()
+assert(a == one)
+assert(b == two)
+a, b = 10
+assert(a == ten)
+local b_type = type(b)
+assert(b_type == "nil")
+a, b = 1, 2, three()
+assert(b == two)
+local i = 1
+i, t[i] = i + 1, 20
+assert(i == two)
+assert(t[1] == 20)
+a, b = (three())
+assert(a == one)
+b_type = type(b)
+assert(b_type == "nil")
+t.x, t.y = 10, (three())
+assert(t.y == one)
+g1, g2 = (three())
+local g2_type = type(g2)
+assert(g2_type == "nil")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn multiple_returns() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function three()
+    return 1, 2, 3
+end
+local function forward(...)
+    return ...
+end
+local function prefixed(...)
+    return 0, ...
+end
+local function last_call()
+    return 0, three()
+end
+local function middle_call()
+    return three(), 10
+end
+local function first_call()
+    return 0, (three())
+end
+local function first_vararg(...)
+    return 0, (...)
+end
+local function count(...)
+    return select('#', ...)
+end
+local zero, one, two, three_, ten = 0, 1, 2, 3, 10
+local a, b, c = forward(1, 2, 3)
+assert(a == one)
+assert(c == three_)
+local d, e, f, g = prefixed(1, 2, 3)
+assert(d == zero)
+assert(g == three_)
+local h, i, j, k = last_call()
+assert(h == zero)
+assert(k == three_)
+local l, m, n = middle_call()
+assert(l == one)
+assert(m == ten)
+local n_type = type(n)
+assert(n_type == "nil")
+local packed = {three()}
+assert(#packed == 3)
+local varargs = {0, forward(1, 2)}
+assert(#varargs == 3)
+local o, p, q = first_call()
+assert(p == one)
+local q_type = type(q)
+assert(q_type == "nil")
+local r = count(first_vararg(1, 2, 3))
+assert(r == two)
+local s = count(first_vararg())
+assert(s == two)
+local u = count((three()))
+assert(u == one)
+local v = count(0, (forward(1, 2)))
+assert(v == two)
+local w = count((forward()))
+assert(w == one)
+local single = {(three())}
+assert(#single == 1)
+local single_varargs = {0, (forward(1, 2))}
+assert(#single_varargs == 2)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn parenthesized() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function two()
+    return 1, 2
+end
+local function none()
+end
+local function first_call()
+    return (none())
+end
+local function first_vararg(...)
+    return (...)
+end
+local one = 1
+local a, b = (two())
+assert(a == one)
+local b_type = type(b)
+assert(b_type == "nil")
+local c = select('#', first_call())
+assert(c == one)
+local d = select('#', first_vararg())
+assert(d == one)
+local e = select('#', first_vararg(1, 2))
+assert(e == one)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
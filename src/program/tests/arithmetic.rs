@@ -0,0 +1,561 @@
+use alloc::{format, string::String};
+
+#[cfg(feature = "float")]
+use crate::{Error, value::Value};
+use crate::{Lua, Program, bytecode::Bytecode, program::Local};
+
+#[cfg(feature = "float")]
+#[test]
+fn constant_operands() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a = 10
+local b = a - 1000
+local c = a % 3
+local d = a ^ 2
+local e = a / 4
+local f = a // 3
+local g = a & 6
+local h = a | 5
+local i = a ~ 3
+local j = a - 2.5
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_integer(0, 10i16),
+            Bytecode::sub_constant(1, 0, 0),
+            Bytecode::metamethod_constant(0, 0, 7, false),
+            Bytecode::mod_constant(2, 0, 1),
+            Bytecode::metamethod_constant(0, 1, 9, false),
+            Bytecode::pow_constant(3, 0, 2),
+            Bytecode::metamethod_constant(0, 2, 10, false),
+            Bytecode::div_constant(4, 0, 3),
+            Bytecode::metamethod_constant(0, 3, 11, false),
+            Bytecode::idiv_constant(5, 0, 1),
+            Bytecode::metamethod_constant(0, 1, 12, false),
+            Bytecode::bit_and_constant(6, 0, 4),
+            Bytecode::metamethod_constant(0, 4, 13, false),
+            Bytecode::bit_or_constant(7, 0, 5),
+            Bytecode::metamethod_constant(0, 5, 14, false),
+            Bytecode::bit_xor_constant(8, 0, 1),
+            Bytecode::metamethod_constant(0, 1, 15, false),
+            Bytecode::sub_constant(9, 0, 6),
+            Bytecode::metamethod_constant(0, 6, 7, false),
+            Bytecode::return_bytecode(10, 1, 1),
+        ],
+        &[
+            Value::Integer(1000),
+            Value::Integer(3),
+            Value::Integer(2),
+            Value::Integer(4),
+            Value::Integer(6),
+            Value::Integer(5),
+            Value::Float(2.5),
+        ],
+        &[
+            Local::new("a".into(), 3, 22),
+            Local::new("b".into(), 5, 22),
+            Local::new("c".into(), 7, 22),
+            Local::new("d".into(), 9, 22),
+            Local::new("e".into(), 11, 22),
+            Local::new("f".into(), 13, 22),
+            Local::new("g".into(), 15, 22),
+            Local::new("h".into(), 17, 22),
+            Local::new("i".into(), 19, 22),
+            Local::new("j".into(), 21, 22),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+
+    Lua::run_program(program).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn constant_operand_values() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a, x = 10, -7
+local thousand, three, two, four, six, five, half = 1000, 3, 2, 4, 6, 5, 2.5
+local r, k
+r, k = a - thousand, a - 1000
+assert(r == k)
+r, k = x % three, x % 3
+assert(r == k)
+r, k = a ^ two, a ^ 2
+assert(r == k)
+r, k = a / four, a / 4
+assert(r == k)
+r, k = x // three, x // 3
+assert(r == k)
+r, k = a & six, a & 6
+assert(r == k)
+r, k = a | five, a | 5
+assert(r == k)
+r, k = x ~ three, x ~ 3
+assert(r == k)
+r, k = a - half, a - 2.5
+assert(r == k)
+r, k = a * half, a * 2.5
+assert(r == k)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn constant_operand_past_limit() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Fill the first 256 constant slots so the operand can't be encoded in `C`
+    let mut source = String::from("local check = assert\nlocal s\n");
+    for i in 0..256 {
+        source.push_str(&format!("s = \"constant {i}\"\n"));
+    }
+    source.push_str(
+        r#"local a = 10
+local b = a % 3
+local one = 1
+check(b == one)
+a = a % 3
+check(a == one)
+"#,
+    );
+
+    let program = Program::parse(&source).unwrap();
+    assert!(
+        program
+            .byte_codes
+            .contains(&Bytecode::mod_bytecode(3, 2, 3))
+    );
+    assert!(
+        program
+            .byte_codes
+            .contains(&Bytecode::mod_bytecode(2, 2, 5))
+    );
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn immediate_shifts() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a = 5
+local b = a >> 1
+local c = a << 3
+local d = 1 << a
+local e = a << 1000
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_integer(0, 5i16),
+            Bytecode::shift_right_integer(1, 0, 1),
+            Bytecode::metamethod_integer(0, 1, 17, false),
+            Bytecode::shift_right_integer(2, 0, -3),
+            Bytecode::metamethod_integer(0, 3, 16, false),
+            Bytecode::shift_left_integer(3, 0, 1),
+            Bytecode::metamethod_integer(0, 1, 16, true),
+            Bytecode::load_integer(4, 1000i16),
+            Bytecode::shift_left(4, 0, 4),
+            Bytecode::metamethod(0, 4, 16),
+            Bytecode::return_bytecode(5, 1, 1),
+        ],
+        &[],
+        &[
+            Local::new("a".into(), 3, 13),
+            Local::new("b".into(), 5, 13),
+            Local::new("c".into(), 7, 13),
+            Local::new("d".into(), 9, 13),
+            Local::new("e".into(), 12, 13),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn immediate_limits() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Immediate operands hold -127 to 128 and `LOADI` -65535 to 65536,
+    // values past them have to be constants
+    let program = Program::parse(
+        r#"
+local a, b = 0, -128
+assert(a + -128 == -128)
+assert(a + -127 == -127)
+assert(b == -128 and -128 == b)
+assert(b < -127 and b <= -128 and b > -129 and b >= -128)
+assert(-128 < a and -128 <= a)
+assert(-2 << 15 == -65536)
+local c = -65536
+local d = -65535
+assert(c == d - 1)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn shift_semantics() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local x, minus_one, sixty_four, minus_two = 5, -1, 64, -2
+local zero, one, two, forty, twenty, fifteen = 0, 1, 2, 40, 20, 15
+local r
+r = x >> 1
+assert(r == two)
+r = x << 3
+assert(r == forty)
+r = x << 64
+assert(r == zero)
+r = x >> 64
+assert(r == zero)
+r = x << sixty_four
+assert(r == zero)
+r = x >> -2
+assert(r == twenty)
+r = x << minus_two
+assert(r == one)
+r = minus_one >> 60
+assert(r == fifteen)
+r = 1 << sixty_four
+assert(r == zero)
+r = 80 >> x
+assert(r == two)
+local min = 1 << 63
+r = min >> 63
+assert(r == one)
+local two_float = 2.0
+r = two_float << x
+assert(r == sixty_four)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn constant_folding() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a = 2 * 3 + 1
+local b = "a" .. "b" .. "c"
+local c = 7 // 2 + 2 ^ 3 + (1 << 4) + 10 / 4
+local d = a + 2 * 3
+local e = 1 // 0
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_integer(0, 7i16),
+            Bytecode::load_constant(1, 0u8),
+            Bytecode::load_constant(2, 1u8),
+            Bytecode::add_integer(3, 0, 6),
+            Bytecode::metamethod_integer(0, 6, 6, false),
+            Bytecode::load_integer(4, 1i16),
+            Bytecode::idiv_constant(4, 4, 2),
+            Bytecode::metamethod_constant(4, 2, 12, false),
+            Bytecode::return_bytecode(5, 1, 1),
+        ],
+        &[Value::from("abc"), Value::Float(29.5), Value::Integer(0)],
+        &[
+            Local::new("a".into(), 3, 11),
+            Local::new("b".into(), 4, 11),
+            Local::new("c".into(), 5, 11),
+            Local::new("d".into(), 7, 11),
+            Local::new("e".into(), 10, 11),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn negative_zero_folding() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+assert(tostring(-0.0) == "-0.0")
+assert(tostring(-(-0.0)) == "0.0")
+assert(tostring(-1.5) == "-1.5")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn constant_folding_values() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local seven, six, minus_one, half = 7, 6, -1, 0.5
+assert(2 * 3 + 1 == seven)
+assert(3 - 4 == minus_one)
+assert(1 / 2 == half)
+assert(6.0 // 1 == six)
+assert(255 ~ 249 == six)
+assert(-1 >> 63 == 1)
+assert(2 | 1 == 3)
+assert(9223372036854775807 + 1 == 1 << 63)
+assert("ab" .. "c" == "abc")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn numerals() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local ten, half, sixteen = 10, 0.5, 16
+assert(0xA == ten)
+assert(0Xa == ten)
+assert(.5 == half)
+assert(5e-1 == half)
+assert(0x.8 == half)
+assert(0x1p4 == sixteen)
+assert(0x1P+4 == sixteen)
+assert(1E1 == ten)
+assert(0xffffffffffffffff == -1)
+assert(0x1e+1 == 31)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    for malformed in [
+        "local a = 3..2\n",
+        "local a = 1e\n",
+        "local a = 0x\n",
+        "local a = 12abc\n",
+    ] {
+        assert!(Program::parse(malformed).is_err(), "{malformed:?}");
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn string_coercion() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local eleven, thirty_two, three_and_half, zero = 11, 32, 3.5, 0.0
+local seven, minus_two, three, five = 7, -2, 3, 5
+local a = "10" + 1
+assert(a == eleven)
+local s = "0x10"
+local b = s * 2
+assert(b == thirty_two)
+local c = " 2.5 " + 1
+assert(c == three_and_half)
+local d = 10 - "1e1"
+assert(d == zero)
+local e = "3" | 4
+assert(e == seven)
+local f = -"2"
+assert(f == minus_two)
+local g = "7" // "2"
+assert(g == three)
+local h = "1" << 2 | "1.0"
+assert(h == five)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    for failing in [
+        "local a = \"abc\" + 1\n",
+        "local a = 1 + \"0x\"\n",
+        "local a = \"1.5\" | 1\n",
+    ] {
+        let program = Program::parse(failing).unwrap();
+        match Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(
+                Error::ArithmeticOperand(..)
+                | Error::BitwiseOperand(..)
+                | Error::NoIntegerRepresentation,
+            ) => (),
+            Err(err) => panic!(
+                "Should fail with ArithmeticOperand, BitwiseOperand, or NoIntegerRepresentation, but failed with `{}`.",
+                err
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn float_bitwise() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local three, five, minus_four, sixteen = 3, 5, -4, 16
+local f, g = 3.0, 6.0
+assert(f & g == 2)
+assert(f | g == 7)
+assert(f ~ g == five)
+assert(f << 2.0 == 12)
+assert(g >> 1.0 == three)
+assert(~f == minus_four)
+assert(tostring(f | 0) == "3")
+assert(~3.0 == minus_four)
+assert(2.0 << 3 == sixteen)
+assert("3.0" | 0 == three)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    for failing in [
+        "local f = 1.5\nlocal a = f | 1\n",
+        "local f = 1.5\nlocal a = ~f\n",
+        "local a = 1 << 2.5\n",
+        "local a = ~0.5\n",
+        "local f = 2^63\nlocal a = f & 1\n",
+        "local a = \"0.5\" ~ 1\n",
+    ] {
+        let program = Program::parse(failing).unwrap();
+        match Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::NoIntegerRepresentation) => (),
+            Err(err) => panic!(
+                "Should fail with NoIntegerRepresentation, but failed with `{}`.",
+                err
+            ),
+        }
+    }
+
+    let program = Program::parse("local t = {}\nlocal a = ~t\n").unwrap();
+    assert!(matches!(
+        Lua::run_program(program).map_err(Error::unlocated),
+        Err(Error::InvalidBitNotOperand("table"))
+    ));
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn concat_numbers() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local one, three, big, tiny, third = 1, 3.0, 1e100, 0.00001, 1 / 3
+assert(one .. 2 == "12")
+assert(three .. "" == "3.0")
+assert(-three .. "" == "-3.0")
+assert(big .. "" == "1e+100")
+assert(tiny .. "" == "1e-05")
+assert(third .. "" == "0.33333333333333")
+assert(0.1 + 0.2 .. "" == "0.3")
+assert(2^53 .. "" == "9.007199254741e+15")
+assert("a" .. one .. 2.5 .. "b" .. three == "a12.5b3.0")
+assert(tostring(third) == third .. "")
+local zero = 0.0
+assert(tostring(-zero) == "-0.0")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[cfg(not(feature = "float"))]
+#[test]
+fn without_floats() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    assert_eq!(
+        Program::parse("local a = 1.5\n").unwrap_err().unlocated(),
+        crate::program::Error::FloatNumeral
+    );
+
+    let program = Program::parse(
+        r#"
+local a, b = 7, "2"
+assert(a // b == 3)
+assert(a % b == 1)
+assert(-b == -2)
+for i = 1, "3" do
+    a = a + i
+end
+assert(a == 13)
+"#,
+    )
+    .unwrap();
+    Lua::run_program(program).unwrap();
+
+    for (source, operation) in [
+        ("local a, b = 7, 2\nreturn a / b\n", "div"),
+        ("local a = 7\nreturn a ^ 2\n", "pow"),
+        ("return 2 ^ 2\n", "pow"),
+    ] {
+        let program = Program::parse(source).unwrap();
+        match Lua::run_program(program).map_err(crate::Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(crate::Error::FloatsDisabled(op)) => assert_eq!(op, operation),
+            Err(err) => panic!(
+                "Should fail with FloatsDisabled, but failed with `{}`.",
+                err
+            ),
+        }
+    }
+
+    let program = Program::parse("local a = {}\nreturn a / 2\n").unwrap();
+    assert!(matches!(
+        Lua::run_program(program).map_err(crate::Error::unlocated),
+        Err(crate::Error::ArithmeticOperand("div", "table", "integer"))
+    ));
+}
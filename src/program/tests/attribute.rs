@@ -0,0 +1,93 @@
+use crate::{Lua, Program, bytecode::Bytecode, program, value::Value};
+
+#[test]
+fn const_locals() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local K <const> = 300
+local s <const> = "key"
+local t = {}
+local x = 2
+t[s] = x * K
+t.y = K - x
+"#,
+    )
+    .unwrap();
+    // Constants don't take a register, and are used as `K` operands
+    assert_eq!(
+        &program.byte_codes[1..10],
+        &[
+            // local t = {}
+            Bytecode::new_table(0, 0, 0),
+            // local x = 2
+            Bytecode::load_integer(1, 2i16),
+            // t[s] = x * K
+            Bytecode::mul_constant(2, 1, 0),
+            Bytecode::metamethod_constant(1, 0, 8, false),
+            Bytecode::set_field(0, 1, 2, false),
+            // t.y = K - x
+            Bytecode::load_integer(2, 300i16),
+            Bytecode::sub(2, 2, 1),
+            Bytecode::metamethod(2, 1, 7),
+            Bytecode::set_field(0, 2, 2, false),
+        ]
+    );
+    assert_eq!(
+        program.constants.as_ref(),
+        &[Value::Integer(300), "key".into(), "y".into()]
+    );
+    assert_eq!(program.locals.len(), 2);
+
+    let program = Program::parse(
+        r#"
+local a <const> = 10
+local b <const> = a
+local x = 5
+local function f()
+    return a + x
+end
+assert(f() == 15)
+do
+    local a = 3
+    assert(a == 3)
+end
+assert(a == b)
+local u, v <const> = 1, {}
+assert(u == 1)
+repeat
+    local k <const> = 4
+until k == 4
+"#,
+    )
+    .unwrap();
+    Lua::run_program(program).unwrap();
+
+    assert_eq!(
+        Program::parse("local x <const> = 1\nx = 2\n")
+            .unwrap_err()
+            .unlocated(),
+        program::Error::AssignToConst
+    );
+    assert_eq!(
+        Program::parse("local t <const> = {}\nt = {}\n")
+            .unwrap_err()
+            .unlocated(),
+        program::Error::AssignToConst
+    );
+    assert_eq!(
+        Program::parse("local x <close> = nil\nx = 2\n")
+            .unwrap_err()
+            .unlocated(),
+        program::Error::AssignToConst
+    );
+    assert_eq!(
+        Program::parse("local x <const> = 1\nlocal function f()\n    x = 2\nend\n")
+            .unwrap_err()
+            .unlocated(),
+        program::Error::AssignToConst
+    );
+    // Shadowing makes the name assignable again
+    Program::parse("local x <const> = 1\nlocal x = x\nx = 2\n").unwrap();
+}
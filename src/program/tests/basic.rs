@@ -1,4 +1,6 @@
-use crate::{Error, bytecode::Bytecode, program::Local};
+use alloc::string::ToString;
+
+use crate::{Error, bytecode::Bytecode, program::Local, value::Value};
 
 #[test]
 fn print_and_warn() {
@@ -63,6 +65,106 @@ warn "hello, warn!"
     crate::Lua::run_program(program).unwrap();
 }
 
+#[test]
+fn print_stdout() {
+    use alloc::{rc::Rc, string::String};
+    use core::cell::RefCell;
+
+    use crate::environment::Environment;
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let output = Rc::new(RefCell::new(String::new()));
+    let stdout = output.clone();
+    let env = Environment::builder()
+        .stdout(move |string: &str| {
+            stdout.borrow_mut().push_str(string);
+            Ok(())
+        })
+        .build()
+        .unwrap();
+    let program = crate::Program::parse(
+        r#"
+print("hello", 1, nil)
+print()
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+    assert_eq!(output.borrow().as_str(), "hello\t1\tnil\n\n");
+
+    let env = Environment::builder()
+        .stdout(|_: &str| Err(String::from("closed")))
+        .build()
+        .unwrap();
+    let program = crate::Program::parse("print(\"hello\")\n").unwrap();
+    match crate::Lua::run_program_with_env(program, env).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::Io(message)) => assert_eq!(message, "closed"),
+        Err(err) => panic!("Should fail with Io, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn output_per_vm() {
+    use alloc::{rc::Rc, string::String};
+    use core::cell::RefCell;
+
+    use crate::Lua;
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+print("a", 1)
+warn("@on")
+warn("careful")
+"#,
+    )
+    .unwrap();
+
+    let first = Rc::new(RefCell::new(String::new()));
+    let second = Rc::new(RefCell::new(String::new()));
+    let mut lua = Lua::default();
+    lua.set_stdout(first.clone());
+    lua.execute(program.clone()).unwrap();
+    let mut other = Lua::default();
+    other.set_stdout(second.clone());
+    other
+        .execute(crate::Program::parse("print(\"b\")\n").unwrap())
+        .unwrap();
+
+    assert_eq!(first.borrow().as_str(), "a\t1\nLua warning: careful\n");
+    assert_eq!(second.borrow().as_str(), "b\n");
+
+    // Without an output, printing goes back to logging
+    lua.clear_stdout();
+    lua.execute(program).unwrap();
+    assert_eq!(first.borrow().as_str(), "a\t1\nLua warning: careful\n");
+}
+
+#[test]
+fn globals() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+assert(_G._G == _G)
+assert(_G.print == print)
+_G.x = 1
+local one = 1
+assert(x == one)
+y = 2
+local two = 2
+assert(_G.y == two)
+assert(_VERSION == "Lua 5.4")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
 #[test]
 fn assert() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -99,7 +201,7 @@ assert(a > b, "a was smaller than b")
             // local c,d,e = assert(b == a * 10, "b was different from 10 times a", "something else")
             Bytecode::get_uptable(2, 0, 0),
             Bytecode::mul_constant(3, 0, 2),
-            // TODO MMBINK
+            Bytecode::metamethod_constant(0, 2, 8, false),
             Bytecode::equal(1, 3, true),
             Bytecode::jump(1i8),
             Bytecode::load_false_skip(3),
@@ -145,20 +247,559 @@ assert(a > b, "a was smaller than b")
             "a was smaller than b".into(),
         ],
         &[
-            Local::new("a".into(), 4, 43),
-            Local::new("b".into(), 4, 43),
-            Local::new("c".into(), 20, 43),
-            Local::new("d".into(), 20, 43),
-            Local::new("e".into(), 20, 43),
+            Local::new("a".into(), 4, 44),
+            Local::new("b".into(), 4, 44),
+            Local::new("c".into(), 21, 44),
+            Local::new("d".into(), 21, 44),
+            Local::new("e".into(), 21, 44),
         ],
         &["_ENV".into()],
         0,
     );
 
-    // TODO improve this when there is better error handling
-    match crate::Lua::run_program(program) {
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::Assertion(message)) => {
+            assert_eq!(message, Value::from("a was smaller than b"))
+        }
+        Err(err) => panic!("Should fail with Assertion, but failed with `{}`.", err),
+    }
+
+    let program = crate::Program::parse("local t = {}\nassert(false, t)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
         Ok(_) => panic!("Should fail."),
-        Err(Error::Assertion) => (),
+        Err(err @ Error::Assertion(Value::Table(_))) => {
+            assert_eq!(err.to_string(), "(error object is a table value)")
+        }
         Err(err) => panic!("Should fail with Assertion, but failed with `{}`.", err),
     }
+
+    let program = crate::Program::parse("assert(nil)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(err @ Error::Assertion(_)) => assert_eq!(err.to_string(), "assertion failed!"),
+        Err(err) => panic!("Should fail with Assertion, but failed with `{}`.", err),
+    }
+
+    let program = crate::Program::parse("assert()\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::Expected(1, _, _)) => (),
+        Err(err) => panic!("Should fail with Expected, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn load() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local three, seven, forty_two = 3, 7, 42
+local f = load("local x = 1\nlocal y = 2\nreturn x + y")
+local a = f()
+assert(a == three)
+answer = 42
+local g = load("return answer")
+local b = g()
+assert(b == forty_two)
+local env = { answer = 7 }
+local h = load("local function k() return answer end\nreturn k()", "chunk", "t", env)
+local c = h()
+assert(c == seven)
+local i = load("value = 5", "chunk", "t", env)
+i()
+local value_type = type(value)
+assert(value_type == "nil")
+local env_value_type = type(env.value)
+assert(env_value_type == "integer")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn load_reader() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local pieces = { "local a = 2\n", "return a ", "+ 3" }
+local position = 0
+local function reader()
+    position = position + 1
+    local current, all = position, pieces
+    return all[current]
+end
+local f = load(reader)
+local five = 5
+local a = f()
+assert(a == five)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn load_failure() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local a, a_message = load("return \"unterminated")
+local a_type = type(a)
+assert(a_type == "nil")
+local a_message_type = type(a_message)
+assert(a_message_type == "string")
+local b, b_message = load("return 1", "chunk", "b")
+local b_type = type(b)
+assert(b_type == "nil")
+assert(b_message == "attempt to load a text chunk (mode is 'b')")
+local c, c_message = load("local x = 1\nbreak", "chunk")
+assert(c_message == "chunk:2: Break outside of loop.")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse("load(1)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn metatables() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local mt = {}
+local protected = { __metatable = "locked" }
+local t = {}
+local r = setmetatable(t, mt)
+assert(r == t)
+r = getmetatable(t)
+assert(r == mt)
+setmetatable(t, nil)
+r = getmetatable(t)
+assert(r == nil)
+r = getmetatable(42)
+assert(r == nil)
+setmetatable(t, protected)
+r = getmetatable(t)
+assert(r == "locked")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse(
+        r#"
+local t = setmetatable({}, { __metatable = "locked" })
+setmetatable(t, {})
+"#,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::ProtectedMetatable) => (),
+        Err(err) => panic!(
+            "Should fail with ProtectedMetatable, but failed with `{}`.",
+            err
+        ),
+    }
+
+    let program = crate::Program::parse("setmetatable({}, 1)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(2, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn select() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r##"
+local function count(...)
+    return select("#", ...)
+end
+local zero, one, two, three = 0, 1, 2, 3
+assert(count() == zero)
+assert(count(nil, nil) == two)
+assert(select(2, "a", "b", "c") == "b")
+assert(select("3", "a", "b", "c") == "c")
+assert(select(-1, "a", "b", "c") == "c")
+local a, b = select(-2, 1, 2, 3)
+assert(a == two)
+assert(b == three)
+local c = select(4, 1, 2, 3)
+assert(c == nil)
+local function rest(...)
+    return select(2, ...)
+end
+local d, e = rest(1, 2, 3)
+assert(d == two)
+assert(e == three)
+local f = count(rest(1, 2, 3))
+assert(f == two)
+local g = count(select(-3, 1, 2, 3))
+assert(g == three)
+"##,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    for (source, position) in [
+        ("select()\n", 1),
+        ("select(0, 1)\n", 1),
+        ("select(-2, 1)\n", 1),
+        ("select(\"a\", 1)\n", 1),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match crate::Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::BadArgument(arg, _) | Error::Expected(arg, _, _)) if arg == position => (),
+            Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn next_and_pairs() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r##"
+local t = {10, 20, 30, x = 1, y = 2, [2.5] = "f", [true] = "b"}
+t[5] = 50
+local count, sum = 0, 0
+for k, v in pairs(t) do
+    count = count + 1
+    assert(t[k] == v)
+    if tostring(v) ~= v then
+        sum = sum + v
+    end
+end
+assert(count == 8 and sum == 113)
+assert(next({}) == nil)
+assert(select("#", next({})) == 1)
+local k, v = next(t)
+assert(k == 1 and v == 10)
+k, v = next(t, 3)
+assert(v ~= nil and k ~= 3)
+
+-- Fields can be changed or cleared while the table is traversed
+local u = {1, 2, 3}
+for i = 1, 100 do
+    u["k" .. i] = i
+end
+local seen = 0
+for k, v in next, u do
+    seen = seen + 1
+    if tostring(k) ~= k then
+        u[k] = v * 2
+    else
+        u[k] = nil
+    end
+end
+assert(seen == 103)
+assert(next(u, 3) == nil)
+assert(u[1] == 2 and u[3] == 6)
+
+-- Cleared keys are dropped when new keys need room
+local w = {a = 1, b = 2}
+w.a = nil
+assert(next(w, "a") == "b")
+for i = 1, 10 do
+    w["n" .. i] = i
+end
+return next(w, "a")
+"##,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::InvalidNextKey(key)) => assert_eq!(key, "a".into()),
+        Err(err) => panic!(
+            "Should fail with InvalidNextKey, but failed with `{}`.",
+            err
+        ),
+    }
+
+    // Keys added to the map would move the keys after them, so the
+    // traversal can't go on
+    let program = crate::Program::parse(
+        r#"
+local t = {m = 1, n = 2, o = 3}
+local visited = {}
+for k in pairs(t) do
+    visited[#visited + 1] = k
+    if k == "n" then
+        t.a = 4
+        t.z = 5
+    end
+end
+"#,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::KeyAddedDuringTraversal) => (),
+        Err(err) => panic!(
+            "Should fail with KeyAddedDuringTraversal, but failed with `{}`.",
+            err
+        ),
+    }
+
+    let program = crate::Program::parse(
+        r#"
+-- Once a traversal ends, or a new one starts, keys can be added again
+local t = {m = 1, n = 2}
+for k in pairs(t) do
+end
+t.a = 3
+assert(next(t, "a") == "m")
+local first = next(t)
+t.z = 4
+for k in pairs(t) do
+    t[k] = nil
+end
+assert(next(t) == nil)
+-- Keys on the array part don't move the map
+local u = {x = 1}
+local count = 0
+for k in pairs(u) do
+    count = count + 1
+    u[#u + 1] = k
+end
+assert(count == 1 and u[1] == "x")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse(
+        r#"
+local t = setmetatable({}, {__pairs = function(t)
+    return function(_, k)
+        if k < 3 then
+            return k + 1, k * 10
+        end
+    end, t, 0
+end})
+local keys = 0
+for k, v in pairs(t) do
+    keys = keys + k
+    assert(v == (k - 1) * 10)
+end
+assert(keys == 6)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    for (source, expected) in [
+        ("next()\n", "Expected(1, \"table\", \"no value\")"),
+        ("next(1)\n", "Expected(1, \"table\", \"integer\")"),
+        ("next({}, 1)\n", "InvalidNextKey(Integer(1))"),
+        ("next({x = 1}, \"y\")\n", "InvalidNextKey(ShortString(y))"),
+        ("pairs()\n", "Expected(1, \"table\", \"no value\")"),
+        ("pairs(\"s\")\n", "Expected(1, \"table\", \"string\")"),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match crate::Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(err) => assert_eq!(alloc::format!("{err:?}"), expected),
+        }
+    }
+}
+
+#[test]
+fn ipairs() {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::RefCell;
+
+    use crate::{HookMask, Lua, Program};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let source = r#"
+local t = {10, 20, 30, nil, 50}
+local visited = {}
+for i, v in ipairs(t) do
+    visited[#visited + 1] = i * 100 + v
+end
+assert(#visited == 3 and visited[3] == 330)
+
+local count = 0
+for i in ipairs({1, 2}) do
+    count = count + i
+end
+assert(count == 3)
+
+for i, v, extra in ipairs({7}) do
+    assert(i == 1 and v == 7 and extra == nil)
+end
+
+-- Fields changed by the loop are seen by the next step
+local grow = {1}
+for i, v in ipairs(grow) do
+    if i < 5 then
+        grow[i + 1] = v * 2
+    end
+end
+assert(#grow == 5 and grow[5] == 16)
+
+local f, state, start = ipairs(t)
+assert(state == t and start == 0)
+local i, v = f(t, 1)
+assert(i == 2 and v == 20)
+assert(f(t, 3) == nil)
+return visited[1], count
+"#;
+
+    let mut lua = Lua::default();
+    let results = lua.execute(Program::parse(source).unwrap()).unwrap();
+    assert_eq!(results, [Value::Integer(110), Value::Integer(3)]);
+
+    // Hooks see the calls to the iterator, with the same results
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let recorded = calls.clone();
+    lua.set_hook(
+        HookMask {
+            call: true,
+            ..Default::default()
+        },
+        move |vm, _| {
+            recorded.borrow_mut().push(vm.running_function().cloned());
+            Ok(())
+        },
+    );
+    let results = lua.execute(Program::parse(source).unwrap()).unwrap();
+    assert_eq!(results, [Value::Integer(110), Value::Integer(3)]);
+    assert!(calls.borrow().len() > 15);
+
+    for (source, expected) in [
+        ("ipairs()\n", "Expected(1, \"table\", \"no value\")"),
+        ("for _ in ipairs(1) do end\n", "ExpectedTable(\"integer\")"),
+        (
+            "local f = ipairs({})\nf({}, \"a\")\n",
+            "Expected(2, \"integer\", \"string\")",
+        ),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match crate::Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(err) => assert_eq!(alloc::format!("{err:?}"), expected),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn tonumber() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local ten, thirty_one, hundred, half = 10, 31, 100.0, 0.5
+assert(tonumber("10") == ten)
+assert(tonumber(ten) == ten)
+assert(tonumber("  0x1F\t") == thirty_one)
+assert(tonumber("1e2") == hundred)
+assert(tonumber("0x1p-1") == half)
+assert(tonumber("abc") == nil)
+assert(tonumber("1 0") == nil)
+assert(tonumber({}) == nil)
+assert(tonumber("1010", 2) == ten)
+assert(tonumber(" -zz ", 36) == -1295)
+assert(tonumber("1F", "16") == thirty_one)
+assert(tonumber("8", 8) == nil)
+assert(tonumber("1.0", 10) == nil)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    for (source, position) in [
+        ("tonumber()\n", 1),
+        ("tonumber(\"10\", 37)\n", 2),
+        ("tonumber(10, 16)\n", 1),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match crate::Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::BadArgument(arg, _) | Error::Expected(arg, _, _)) if arg == position => (),
+            Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn expressions_in_any_position() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local t = { 10, 20, 30, x = { y = 5 } }
+local k = 1
+local function f(v) return v end
+local function get() return t end
+
+-- Conditions on constants, fields, calls, and negations
+local out = {}
+if 2 then out[1] = true end
+if nil then out[2] = true end
+if t.x then out[3] = true end
+if f(false) then out[4] = true end
+if not t.z then out[5] = true end
+assert(out[1] and not out[2] and out[3] and not out[4] and out[5])
+
+-- Keys and tables that are not names or constants
+assert(t[k + 1] == 20)
+assert(get().x.y == 5)
+assert(get()[3] == 30)
+local function up() return t[k * 2], t.x["y"] end
+local a, b = up()
+assert(a == 20 and b == 5)
+
+-- Operands that are calls or fields
+local function fact(n)
+    if n <= 1 then return 1 end
+    return n * fact(n - 1)
+end
+assert(fact(5) == 120)
+assert(-t.x.y == -5)
+assert(#get() == 3)
+
+-- Assignments to globals and nested fields
+g_float, g_nil = 1.5, nil
+assert(g_float == 1.5 and g_nil == nil)
+t.x.y = 6
+get().x.z = 7
+assert(t.x.y == 6 and t.x.z == 7)
+local function set() t.x.y = 8 end
+set()
+assert(t.x.y == 8)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
 }
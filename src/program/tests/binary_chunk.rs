@@ -0,0 +1,354 @@
+use crate::{
+    FIRST_CUSTOM_OPCODE, Lua, Program,
+    bytecode::{Bytecode, OpCode},
+    environment::{Environment, EnvironmentError},
+    program::Error,
+    value::Value,
+};
+
+/// Position of the main function's first instruction on chunks made
+/// by [`Program::to_bytecode`] with less than 128 instructions, for
+/// programs with a chunk name shorter than 126 bytes
+fn code_start(program: &Program) -> usize {
+    // Chunk names are written after a `=`
+    39 + program.chunk_name().map_or(0, |name| name.len() + 1)
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn load_chunk() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a = 10
+local long = "a string that is too long to be stored inline"
+local function f(x, ...)
+    return x, a, 2.5, true
+end
+print(f(1), long)
+"#,
+    )
+    .unwrap();
+
+    let chunk = program.to_bytecode();
+    let loaded = Program::from_bytecode(&chunk).unwrap();
+    assert!(
+        program.diff(&loaded).is_empty(),
+        "{}",
+        program.diff(&loaded)
+    );
+
+    Lua::run_program(loaded).unwrap();
+}
+
+#[test]
+fn line_info() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Lines far apart and long lines need absolute lines
+    let source = alloc::format!(
+        "local t = {{}}\n{}local function f(x)\n    return x + nil\nend\n{}f(1)\n",
+        "\n".repeat(300),
+        "t[1] = 1 ".repeat(200)
+    );
+    let program = Program::parse_named(&source, "lines.lua").unwrap();
+    let loaded = Program::from_bytecode(&program.to_bytecode()).unwrap();
+    assert_eq!(loaded.chunk_name(), Some("lines.lua"));
+    for (program, loaded) in [(&program, &loaded)].into_iter().chain(
+        program
+            .functions()
+            .zip(loaded.functions())
+            .map(|(function, loaded)| (function.program(), loaded.program())),
+    ) {
+        assert_eq!(loaded.chunk_name(), Some("lines.lua"));
+        assert_eq!(
+            (0..program.byte_codes.len())
+                .map(|index| program.line(index))
+                .collect::<alloc::vec::Vec<_>>(),
+            (0..loaded.byte_codes.len())
+                .map(|index| loaded.line(index))
+                .collect::<alloc::vec::Vec<_>>()
+        );
+    }
+    assert!(matches!(
+        Lua::run_program(loaded),
+        Err(crate::Error::Located { chunk, line: 303, .. }) if chunk.as_ref() == "lines.lua"
+    ));
+
+    let stripped = crate::function::Function::new(program, 0, true).to_bytecode(true);
+    let stripped = Program::from_bytecode(&stripped).unwrap();
+    assert_eq!(stripped.chunk_name(), None);
+    assert_eq!(stripped.line(0), None);
+}
+
+#[test]
+fn big_endian_chunk() {
+    let program = Program::parse("local a = 300\nlocal b = 2\n").unwrap();
+    assert!(program.constants.is_empty());
+
+    let chunk = program.to_bytecode();
+    let code_start = code_start(&program);
+    let code_end = code_start + program.byte_codes.len() * 4;
+    let mut swapped = chunk[..15].to_vec();
+    swapped.extend(0x5678i64.to_be_bytes());
+    swapped.extend(370.5f64.to_be_bytes());
+    swapped.extend(&chunk[31..code_start]);
+    for instruction in chunk[code_start..code_end].chunks(4) {
+        swapped.extend(instruction.iter().rev());
+    }
+    swapped.extend(&chunk[code_end..]);
+
+    let loaded = Program::from_bytecode(&swapped).unwrap();
+    assert!(
+        program.diff(&loaded).is_empty(),
+        "{}",
+        program.diff(&loaded)
+    );
+}
+
+#[test]
+fn invalid_chunks() {
+    let program = Program::parse("local a = 1\n").unwrap();
+    let chunk = program.to_bytecode();
+    let code_start = code_start(&program);
+
+    assert_eq!(
+        Program::from_bytecode(b"local a = 1").unwrap_err(),
+        Error::BinaryChunk("missing signature")
+    );
+
+    let mut wrong_version = chunk.clone();
+    wrong_version[4] = 0x53;
+    assert_eq!(
+        Program::from_bytecode(&wrong_version).unwrap_err(),
+        Error::BinaryChunk("version is not 5.4")
+    );
+
+    assert_eq!(
+        Program::from_bytecode(&chunk[..chunk.len() - 1]).unwrap_err(),
+        Error::BinaryChunk("unexpected end of chunk")
+    );
+
+    let mut trailing = chunk.clone();
+    trailing.push(0);
+    assert_eq!(
+        Program::from_bytecode(&trailing).unwrap_err(),
+        Error::BinaryChunk("trailing bytes after main function")
+    );
+
+    // `MMBIN` needs the bytecode before it, but loads anywhere
+    let mut metamethod = chunk.clone();
+    metamethod[code_start..code_start + 4].copy_from_slice(&46u32.to_le_bytes());
+    assert!(Program::from_bytecode(&metamethod).is_ok());
+
+    // `EXTRAARG` is, and loads even where it would fail to run
+    let mut extra_arguments = chunk.clone();
+    extra_arguments[code_start..code_start + 4].copy_from_slice(&82u32.to_le_bytes());
+    assert!(Program::from_bytecode(&extra_arguments).is_ok());
+}
+
+#[test]
+fn nested_functions() {
+    // Stripped functions without bytecodes, each declaring the next
+    let nested = |levels: usize| {
+        let mut chunk = Program::parse("").unwrap().to_bytecode()[..31].to_vec();
+        chunk.push(0);
+        for level in 0..=levels {
+            let functions = if level == levels { 0x80 } else { 0x81 };
+            chunk.extend_from_slice(&[0x80, 0x80, 0x80, 0, 0, 2, 0x80, 0x80, 0x80, functions]);
+        }
+        for _ in 0..=levels {
+            chunk.extend_from_slice(&[0x80; 4]);
+        }
+        chunk
+    };
+
+    let program = Program::from_bytecode(&nested(200)).unwrap();
+    assert_eq!(program.functions().count(), 1);
+    let loaded = Program::from_bytecode(&program.to_bytecode()).unwrap();
+    assert!(program.diff(&loaded).is_empty());
+    for levels in [201, 100_000] {
+        assert_eq!(
+            Program::from_bytecode(&nested(levels)).unwrap_err(),
+            Error::BinaryChunk("functions are nested too deeply")
+        );
+        crate::fuzz::load_chunk(&nested(levels));
+    }
+}
+
+#[test]
+fn custom_opcodes() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    /// `R[A] := R[B] * R[C] + 1`, encoded like `ADD`
+    fn multiply_increment(instruction: u32, vm: &mut Lua) -> Result<(), crate::Error> {
+        let [a, b, c] = [7, 16, 24].map(|shift| ((instruction >> shift) & 0xff) as u8);
+        let (Some(Value::Integer(lhs)), Some(Value::Integer(rhs))) =
+            (vm.register(b), vm.register(c))
+        else {
+            return Err(crate::Error::ArithmeticOperand(
+                "mul",
+                "non-integer",
+                "value",
+            ));
+        };
+        let result = lhs * rhs + 1;
+        vm.set_register(a, result)
+    }
+
+    let program = Program::parse("local a, b = 3, 4\nlocal c = a + b\nreturn c\n").unwrap();
+    let add = program
+        .byte_codes
+        .iter()
+        .position(|bytecode| *bytecode == Bytecode::add(2, 0, 1))
+        .unwrap();
+    let custom = (*Bytecode::add(2, 0, 1) & !0x7f) | u32::from(FIRST_CUSTOM_OPCODE);
+
+    // The `MMBIN` after it would redo the addition, so it becomes a `JMP 0`
+    let mut chunk = program.to_bytecode();
+    let position = code_start(&program) + add * 4;
+    chunk[position..position + 4].copy_from_slice(&custom.to_le_bytes());
+    chunk[position + 4..position + 8].copy_from_slice(&(*Bytecode::jump(0i8)).to_le_bytes());
+    let loaded = Program::from_bytecode(&chunk).unwrap();
+    assert_eq!(
+        alloc::format!("{:?}", loaded.byte_codes[add]),
+        alloc::format!("Custom({FIRST_CUSTOM_OPCODE}, {custom:#010x})")
+    );
+
+    let env = Environment::builder()
+        .opcode(FIRST_CUSTOM_OPCODE, multiply_increment)
+        .build()
+        .unwrap();
+    let results = Lua::new(env).execute(loaded.clone()).unwrap();
+    assert_eq!(results, [Value::Integer(13)]);
+
+    // Handlers are looked up when the instruction runs
+    assert!(matches!(
+        Lua::run_program(loaded).map_err(crate::Error::unlocated),
+        Err(crate::Error::MissingOpcodeHandler(FIRST_CUSTOM_OPCODE))
+    ));
+
+    assert!(matches!(
+        Environment::builder().opcode(1, multiply_increment).build(),
+        Err(EnvironmentError::NotCustomOpcode(1))
+    ));
+}
+
+#[test]
+fn metamethod_source() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // The compiler emits the same `MMBIN`, `MMBINI` and `MMBINK` as `luac`
+    let program = Program::parse(include_str!("binary_chunk/metamethods.lua")).unwrap();
+    let luac = Program::from_bytecode(include_bytes!("binary_chunk/metamethods.luac")).unwrap();
+    let metamethods = |program: &Program| {
+        program
+            .byte_codes
+            .windows(2)
+            .filter(|pair| {
+                matches!(
+                    pair[1].opcode(),
+                    Some(
+                        OpCode::MetaMethod | OpCode::MetaMethodInteger | OpCode::MetaMethodConstant
+                    )
+                )
+            })
+            .map(|pair| (pair[0], pair[1]))
+            .collect::<alloc::vec::Vec<_>>()
+    };
+    assert_eq!(metamethods(&program), metamethods(&luac));
+    let results = Lua::default().execute(program).unwrap();
+    assert_eq!(
+        results,
+        [
+            Value::from("add"),
+            Value::from("add"),
+            Value::from("shl"),
+            Value::from("band"),
+            Value::Integer(5),
+        ]
+    );
+
+    let source = include_str!("binary_chunk/metamethods.lua").replace("__band", "__bnot");
+    let program = Program::parse(&source).unwrap();
+    assert!(matches!(
+        Lua::run_program(program),
+        Err(crate::Error::Located { line: 7, error, .. })
+            if matches!(*error, crate::Error::BitwiseOperand("and", "table", "integer"))
+    ));
+}
+
+#[test]
+fn metamethod_bytecodes() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // `binary_chunk/metamethods.lua` compiled to the chunks that
+    // `luac5.4 -s -o metamethods.luac metamethods.lua` and
+    // `luac5.4 -o metamethods_debug.luac metamethods.lua` would write, every
+    // arithmetic and bitwise bytecode on them is followed by a `MMBIN`,
+    // `MMBINI` or `MMBINK`. No `luac` was available when they were written,
+    // so they were assembled by hand following `lcode.c` and `ldump.c`, and
+    // should be replaced by the output of those commands.
+    let program = Program::from_bytecode(include_bytes!("binary_chunk/metamethods.luac")).unwrap();
+    let results = Lua::default().execute(program).unwrap();
+    assert_eq!(
+        results,
+        [
+            Value::from("add"),
+            Value::from("add"),
+            Value::from("shl"),
+            Value::from("band"),
+            Value::Integer(5),
+        ]
+    );
+
+    // Without metamethods they fail like the bytecode before them
+    let mut chunk = include_bytes!("binary_chunk/metamethods.luac").to_vec();
+    let position = chunk.windows(6).position(|name| name == b"__band").unwrap();
+    chunk[position..position + 6].copy_from_slice(b"__bnot");
+    let program = Program::from_bytecode(&chunk).unwrap();
+    assert!(matches!(
+        Lua::run_program(program),
+        Err(crate::Error::BitwiseOperand("and", "table", "integer"))
+    ));
+
+    // The debug information locates the errors on the source
+    let program =
+        Program::from_bytecode(include_bytes!("binary_chunk/metamethods_debug.luac")).unwrap();
+    assert_eq!(program.chunk_name(), Some("metamethods.lua"));
+    assert_eq!(
+        (0..program.byte_codes.len())
+            .map(|index| program.line(index).unwrap())
+            .collect::<alloc::vec::Vec<_>>(),
+        [
+            1, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 5, 5, 5, 6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7
+        ]
+    );
+    assert_eq!(
+        program
+            .locals()
+            .iter()
+            .map(|local| (local.name(), local.scope_start(), local.scope_end()))
+            .collect::<alloc::vec::Vec<_>>(),
+        [("mt", 3, 27), ("v", 14, 27), ("x", 15, 27)]
+    );
+    for (line, function) in (2..).zip(program.functions()) {
+        assert_eq!(function.program().line(0), Some(line));
+        assert_eq!(function.program().chunk_name(), Some("metamethods.lua"));
+    }
+    assert_eq!(program.upvalues().collect::<alloc::vec::Vec<_>>(), ["_ENV"]);
+    let results = Lua::default().execute(program).unwrap();
+    assert_eq!(results[2], Value::from("shl"));
+
+    let mut chunk = include_bytes!("binary_chunk/metamethods_debug.luac").to_vec();
+    let position = chunk.windows(6).position(|name| name == b"__band").unwrap();
+    chunk[position..position + 6].copy_from_slice(b"__bnot");
+    let program = Program::from_bytecode(&chunk).unwrap();
+    assert!(matches!(
+        Lua::run_program(program),
+        Err(crate::Error::Located { chunk, line: 7, error })
+            if chunk.as_ref() == "metamethods.lua"
+                && matches!(*error, crate::Error::BitwiseOperand("and", "table", "integer"))
+    ));
+}
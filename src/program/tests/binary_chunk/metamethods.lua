@@ -0,0 +1,7 @@
+local mt = {}
+function mt.__add(a, b) return "add" end
+function mt.__shl(a, b) return "shl" end
+function mt.__band(a, b) return "band" end
+local v = setmetatable({}, mt)
+local x = 2
+return v + x, v + 1, 1 << v, v & 3, x + 3
@@ -1,5 +1,6 @@
 use crate::{Program, bytecode::Bytecode, program::Local};
 
+#[cfg(feature = "float")]
 #[test]
 fn types() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
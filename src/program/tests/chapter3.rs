@@ -1,6 +1,4 @@
-use alloc::string::String;
-
-use crate::{Program, bytecode::Bytecode, ext::Unescape, program::Local};
+use crate::{Program, bytecode::Bytecode, program::Local, value::Value};
 
 #[test]
 fn escape() {
@@ -47,18 +45,8 @@ print "null: \0." -- '\0'
         &[
             "print".into(),
             "tab:\thi".into(),
-            String::from_utf8_lossy(b"\xE4\xBD\xA0\xE5\xA5\xBD")
-                .as_ref()
-                .unescape()
-                .unwrap()
-                .as_str()
-                .into(),
-            String::from_utf8_lossy(b"\xE4\xBD")
-                .as_ref()
-                .unescape()
-                .unwrap()
-                .as_str()
-                .into(),
+            "你好".into(),
+            b"\xE4\xBD".as_slice().into(),
             "Hello".into(),
             "null: \0.".into(),
         ],
@@ -160,3 +148,131 @@ print(long_string_long_string_long_string_long_string_long_string)
 
     crate::Lua::run_program(program).unwrap();
 }
+
+#[test]
+fn long_strings() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+    let program = Program::parse(
+        "--[==[ a long comment
+with ]] and ]=] ]==] local a = [[
+first line\\n]]
+local b = [==[a]]b]=]c]==]
+--[ a short comment
+--[=x another short comment
+local t = {[ [=[key]=] ] = [=[]=]}
+return a, b, t.key, #\"a\\tb\", \"x\\ty\" .. [[\r\n\n\r\r]]\n",
+    )
+    .unwrap();
+
+    let values = crate::Lua::default().execute(program).unwrap();
+    assert_eq!(
+        values,
+        [
+            Value::from("first line\\n"),
+            Value::from("a]]b]=]c"),
+            Value::from(""),
+            Value::Integer(3),
+            Value::from("x\ty\n\n"),
+        ]
+    );
+
+    for (source, error) in [
+        ("local a = [=x", "Invalid long string delimiter."),
+        (
+            "local a = [==[ a ]=]",
+            "Reached End of File while reading a String.",
+        ),
+        (
+            "--[[ a ]=]",
+            "Reached End of File while reading a long comment.",
+        ),
+    ] {
+        let errors = Program::check(source).unwrap_err();
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [crate::CompileError {
+                    kind: crate::CompileErrorKind::Lexical(message),
+                    ..
+                }] if *message == error
+            ),
+            "{source}: {errors:?}"
+        );
+    }
+}
+
+#[test]
+fn escape_sequences() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+    let program = Program::parse(
+        r#"return "a\z
+      b", "\65\066\0671", "\x41\x62", "\u{48}\u{e9}\u{4F60}\u{1F600}", "x\
+y", "q\"q", 'q\'q', "\u{0041}\u{7FF}", "\r\
+\n", "1\z 2"
+"#,
+    )
+    .unwrap();
+
+    let values = crate::Lua::default().execute(program).unwrap();
+    assert_eq!(
+        values,
+        [
+            Value::from("ab"),
+            Value::from("ABC1"),
+            Value::from("Ab"),
+            Value::from("Hé你😀"),
+            Value::from("x\ny"),
+            Value::from("q\"q"),
+            Value::from("q'q"),
+            Value::from("A\u{7ff}"),
+            Value::from("\r\n\n"),
+            Value::from("12"),
+        ]
+    );
+
+    for (source, error) in [
+        ("local a = \"\\q\"", "Invalid escape sequence."),
+        (
+            "local a = \"\\xg0\"",
+            "Hexadecimal digit expected on escape sequence.",
+        ),
+        (
+            "local a = \"\\x1\"",
+            "Hexadecimal digit expected on escape sequence.",
+        ),
+        ("local a = \"\\256\"", "Decimal escape too large."),
+        (
+            "local a = \"\\u48\"",
+            "Missing braces on `\\u{XXX}` escape sequence.",
+        ),
+        (
+            "local a = \"\\u{48\"",
+            "Missing braces on `\\u{XXX}` escape sequence.",
+        ),
+        (
+            "local a = \"\\u{}\"",
+            "Hexadecimal digit expected on escape sequence.",
+        ),
+        ("local a = \"\\u{80000000}\"", "UTF-8 value too large."),
+        (
+            "local a = \"a\nb\"",
+            "String was not closed before the end of the line.",
+        ),
+        (
+            "local a = \"\\",
+            "Reached End of File while reading a String.",
+        ),
+    ] {
+        let errors = Program::check(source).unwrap_err();
+        assert!(
+            matches!(
+                errors.as_slice(),
+                [crate::CompileError {
+                    kind: crate::CompileErrorKind::Lexical(message),
+                    ..
+                }] if *message == error
+            ),
+            "{source}: {errors:?}"
+        );
+    }
+}
@@ -179,3 +179,133 @@ t.f(t[1000])
 
     crate::Lua::run_program(program).unwrap();
 }
+
+#[test]
+fn integer_keys() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local t = {}
+local a = "x"
+t[1] = a
+t[2] = 20
+t[0] = "zero"
+t[255] = a
+t[256] = a
+t[-1] = 3
+assert(t[1] == "x")
+assert(t[2] == 20)
+assert(t[0] == "zero")
+local b = t[255]
+assert(b == "x")
+local c = t[256]
+assert(c == "x")
+local d = t[-1]
+assert(d == 3)
+"#,
+    )
+    .unwrap();
+
+    let byte_codes = &program.byte_codes[..11];
+    assert_eq!(
+        byte_codes,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            // local t = {}
+            Bytecode::new_table(0, 0, 0),
+            // local a = "x"
+            Bytecode::load_constant(1, 0u8),
+            // t[1] = a
+            Bytecode::set_index(0, 1, 1, false),
+            // t[2] = 20
+            Bytecode::set_index(0, 2, 1, true),
+            // t[0] = "zero"
+            Bytecode::set_index(0, 0, 2, true),
+            // t[255] = a
+            Bytecode::set_index(0, 255, 1, false),
+            // t[256] = a
+            Bytecode::load_integer(2, 256i16),
+            Bytecode::set_table(0, 2, 1, false),
+            // t[-1] = 3
+            Bytecode::load_integer(2, -1i16),
+            Bytecode::set_table(0, 2, 3, true),
+        ]
+    );
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn array_and_hash_parts() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+-- Sparse keys don't fill the array part
+local sparse = {}
+sparse[1e9] = 1
+sparse[1000000] = 2
+assert(#sparse == 0)
+assert(sparse[1000000000] == 1 and sparse[1e6] == 2)
+
+-- Floats with an integer representation are the same key as the integer
+local same = {}
+same[1.0] = "a"
+same[2] = "b"
+assert(same[1] == "a" and same[2.0] == "b")
+assert(#same == 2)
+
+-- Filling in reverse ends with a sequence
+local reverse = {}
+for i = 100, 1, -1 do
+    reverse[i] = i
+end
+assert(#reverse == 100)
+for i = 1, 100 do
+    assert(reverse[i] == i)
+end
+
+-- Fields after the list are moved next to it
+local mixed = {[5] = 5, [6] = 6, 1, 2, 3, 4}
+assert(#mixed == 6)
+
+-- Erasing the end of the sequence
+reverse[100] = nil
+assert(#reverse == 99)
+reverse[101] = nil
+assert(#reverse == 99)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn new_table_hints() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let items = (1..=250)
+        .map(|i| match i {
+            1..=100 => alloc::format!("{i}"),
+            _ => alloc::format!("k{i} = true"),
+        })
+        .collect::<alloc::vec::Vec<_>>()
+        .join(", ");
+    let program = Program::parse(&alloc::format!(
+        "local t = {{{items}}}\nreturn #t, t.k250\n"
+    ))
+    .unwrap();
+    assert_eq!(program.byte_codes[1], Bytecode::new_table(0, 150, 100));
+
+    let values = crate::Lua::default().execute(program).unwrap();
+    assert_eq!(
+        values,
+        [
+            crate::value::Value::Integer(100),
+            crate::value::Value::Boolean(true)
+        ]
+    );
+}
@@ -1,5 +1,8 @@
-use crate::{Error, Program, bytecode::Bytecode, program::Local};
+#[cfg(feature = "float")]
+use crate::{Error, program::Local};
+use crate::{Program, bytecode::Bytecode};
 
+#[cfg(feature = "float")]
 #[test]
 fn unops() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -95,6 +98,7 @@ print(not print)
     crate::Lua::run_program(program).unwrap();
 }
 
+#[cfg(feature = "float")]
 #[test]
 fn binops() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -127,51 +131,55 @@ print(100>>a) -- panic
             Bytecode::get_uptable(3, 0, 3),
             Bytecode::get_uptable(4, 0, 0),
             Bytecode::add_integer(4, 4, 100),
-            // TODO MMBINI
+            Bytecode::metamethod_integer(4, 100, 6, true),
             Bytecode::call(3, 2, 1),
             // print(a-1)
             Bytecode::get_uptable(3, 0, 3),
             Bytecode::add_integer(4, 0, -1i8),
-            // TODO MMBINI
+            Bytecode::metamethod_integer(0, 1, 7, false),
             Bytecode::call(3, 2, 1),
             // print(100/c) -- result is float
             Bytecode::get_uptable(3, 0, 3),
             Bytecode::load_integer(4, 100i8),
             Bytecode::div(4, 4, 2),
-            // TODO MMBINI
+            Bytecode::metamethod(4, 2, 11),
             Bytecode::call(3, 2, 1),
             // print(100>>b) -- 2.0 will be convert to int 2
             Bytecode::get_uptable(3, 0, 3),
             Bytecode::load_integer(4, 100i8),
             Bytecode::shift_right(4, 4, 1),
-            // TODO MMBINI
+            Bytecode::metamethod(4, 1, 17),
             Bytecode::call(3, 2, 1),
             // print(100>>a) -- panic
             Bytecode::get_uptable(3, 0, 3),
             Bytecode::load_integer(4, 100i8),
             Bytecode::shift_right(4, 4, 0),
-            // TODO MMBINI
+            Bytecode::metamethod(4, 0, 17),
             Bytecode::call(3, 2, 1),
             // EOF
             Bytecode::return_bytecode(3, 1, 1),
         ],
         &["g".into(), 10i64.into(), 1.1f64.into(), "print".into()],
         &[
-            Local::new("a".into(), 6, 26),
-            Local::new("b".into(), 6, 26),
-            Local::new("c".into(), 6, 26),
+            Local::new("a".into(), 6, 31),
+            Local::new("b".into(), 6, 31),
+            Local::new("c".into(), 6, 31),
         ],
         &["_ENV".into()],
         0,
     );
 
-    match crate::Lua::run_program(program) {
-        Err(err @ Error::BitwiseOperand(_, _, _)) => log::error!("{}", err),
-        Err(err) => panic!("Expected `BitwiseOperand` error, but got {:?}.", err),
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Err(err @ Error::NoIntegerRepresentation) => log::error!("{}", err),
+        Err(err) => panic!(
+            "Expected `NoIntegerRepresentation` error, but got {:?}.",
+            err
+        ),
         Ok(_) => panic!("Last print should fail"),
     }
 }
 
+#[cfg(feature = "float")]
 #[test]
 fn concat() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -194,12 +202,10 @@ print('hello' .. a) -- panic
             // print('hello, '..'world')
             Bytecode::get_uptable(0, 0, 0),
             Bytecode::load_constant(1, 1u8),
-            Bytecode::load_constant(2, 2u8),
-            Bytecode::concat(1, 2),
             Bytecode::call(0, 2, 1),
             // print('hello, ' .. 123)
             Bytecode::get_uptable(0, 0, 0),
-            Bytecode::load_constant(1, 1u8),
+            Bytecode::load_constant(1, 2u8),
             Bytecode::load_integer(2, 123i8),
             Bytecode::concat(1, 2),
             Bytecode::call(0, 2, 1),
@@ -221,18 +227,18 @@ print('hello' .. a) -- panic
         ],
         &[
             "print".into(),
+            "hello, world".into(),
             "hello, ".into(),
-            "world".into(),
             #[allow(clippy::approx_constant)]
             3.14f64.into(),
             "hello".into(),
         ],
-        &[Local::new("a".into(), 18, 24)],
+        &[Local::new("a".into(), 16, 22)],
         &["_ENV".into()],
         0,
     );
 
-    match crate::Lua::run_program(program) {
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
         Err(err @ Error::ConcatOperand(_)) => log::error!("{}", err),
         Err(err) => panic!("Expected `ConcatOperand` error, but got {:?}.", err),
         Ok(_) => panic!("Last print should fail"),
@@ -258,9 +264,7 @@ print(('hello, '..'world').." "..("3." .. 14 .. 15))
             Bytecode::get_uptable(0, 0, 0),
             Bytecode::load_constant(1, 1u8),
             Bytecode::load_constant(2, 2u8),
-            Bytecode::concat(1, 2),
-            Bytecode::load_constant(2, 3u8),
-            Bytecode::load_constant(3, 4u8),
+            Bytecode::load_constant(3, 3u8),
             Bytecode::load_integer(4, 14i8),
             Bytecode::load_integer(5, 15i8),
             Bytecode::concat(1, 5),
@@ -270,8 +274,7 @@ print(('hello, '..'world').." "..("3." .. 14 .. 15))
         ],
         &[
             "print".into(),
-            "hello, ".into(),
-            "world".into(),
+            "hello, world".into(),
             " ".into(),
             "3.".into(),
         ],
@@ -1,3 +1,7 @@
+use alloc::format;
+
+#[cfg(feature = "float")]
+use crate::Error;
 use crate::{Program, bytecode::Bytecode, program::Local};
 
 #[test]
@@ -334,6 +338,7 @@ until a
     crate::Lua::run_program(program).expect("Should run");
 }
 
+#[cfg(feature = "float")]
 #[test]
 fn for_statement() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -422,7 +427,7 @@ end
             // for i = max, max*10.0, -1 do
             Bytecode::move_bytecode(1, 0),
             Bytecode::mul_constant(2, 0, 3),
-            // TODO MMBINK
+            Bytecode::metamethod_constant(0, 3, 8, false),
             Bytecode::load_integer(3, -1i8),
             Bytecode::for_prepare(1, 3u8),
             //     print (i)
@@ -441,7 +446,6 @@ end
             10.0f64.into(),
         ],
         &[
-            // TODO update when implementing MMBINK
             Local::new("?for_start".into(), 5, 10),
             Local::new("?for_end".into(), 5, 10),
             Local::new("?for_step".into(), 5, 10),
@@ -458,11 +462,11 @@ end
             Local::new("?for_end".into(), 29, 34),
             Local::new("?for_step".into(), 29, 34),
             Local::new("i".into(), 30, 33),
-            Local::new("max".into(), 35, 44),
-            Local::new("?for_start".into(), 38, 43),
-            Local::new("?for_end".into(), 38, 43),
-            Local::new("?for_step".into(), 38, 43),
-            Local::new("i".into(), 39, 42),
+            Local::new("max".into(), 35, 45),
+            Local::new("?for_start".into(), 39, 44),
+            Local::new("?for_end".into(), 39, 44),
+            Local::new("?for_step".into(), 39, 44),
+            Local::new("i".into(), 40, 43),
         ],
         &["_ENV".into()],
         0,
@@ -470,6 +474,42 @@ end
     crate::Lua::run_program(program).expect("Should run");
 }
 
+#[test]
+fn nested_for_statement() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local sum = 0
+for i = 1, 3 do
+    for j = 1, 2 do
+        sum = sum + i * j
+    end
+end
+assert(sum == 18)
+"#,
+    )
+    .unwrap();
+
+    // The inner loop's states are closed before the outer loop's, which
+    // have the same names
+    assert_eq!(
+        &program.locals()[1..],
+        [
+            Local::new("?for_start".into(), 6, 17),
+            Local::new("?for_end".into(), 6, 17),
+            Local::new("?for_step".into(), 6, 17),
+            Local::new("i".into(), 7, 16),
+            Local::new("?for_start".into(), 10, 16),
+            Local::new("?for_end".into(), 10, 16),
+            Local::new("?for_step".into(), 10, 16),
+            Local::new("j".into(), 11, 15),
+        ]
+    );
+
+    crate::Lua::run_program(program).expect("Should run");
+}
+
 #[test]
 fn goto() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -555,6 +595,93 @@ end
     crate::Lua::run_program(program).expect("Should run");
 }
 
+#[test]
+fn goto_scope() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+goto skip
+do
+    local x = 1
+end
+::skip::
+local n = 0
+do
+    local a = 1
+    ::top::
+    n = n + 1
+    if n < 3 then
+        goto top
+    end
+end
+local sum = 0
+for i = 1, 4 do
+    if i == 2 then
+        goto continue
+    end
+    sum = sum + i
+    ::continue::
+end
+assert(n == 3)
+assert(sum == 8)
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        &program.byte_codes[1..10],
+        &[
+            // goto skip
+            Bytecode::jump(1i8),
+            // local x = 1
+            Bytecode::load_integer(0, 1i16),
+            // local n = 0
+            Bytecode::load_integer(0, 0i16),
+            // local a = 1
+            Bytecode::load_integer(1, 1i16),
+            // n = n + 1
+            Bytecode::add_integer(0, 0, 1),
+            Bytecode::metamethod_integer(0, 1, 6, false),
+            // if n < 3 then
+            Bytecode::less_than_integer(0, 3, false),
+            Bytecode::jump(1i8),
+            //     goto top
+            Bytecode::jump(-5i8),
+        ]
+    );
+    crate::Lua::run_program(program).expect("Should run");
+
+    // Labels at the end of a block are outside the scope of its locals
+    for source in [
+        "goto l\nlocal a\n::l::\n",
+        "goto l\nlocal a = 1\n::l::\n",
+        "do goto l end\nlocal a\n::l::\n",
+        "local function f()\n    goto l\n    local a\n    ::l::\nend\nf()\n",
+    ] {
+        let program = Program::parse(source).unwrap();
+        crate::Lua::run_program(program).expect("Should run");
+    }
+
+    assert_eq!(
+        Program::parse("goto l\nlocal x = 1\n::l::\nprint(x)\n")
+            .unwrap_err()
+            .unlocated(),
+        crate::program::Error::GotoIntoScope
+    );
+    assert_eq!(
+        Program::parse("goto l\ndo\n    ::l::\nend\n")
+            .unwrap_err()
+            .unlocated(),
+        crate::program::Error::UnmatchedGoto
+    );
+    assert_eq!(
+        Program::parse("::l::\ndo\n    ::l::\nend\n")
+            .unwrap_err()
+            .unlocated(),
+        crate::program::Error::LabelRedefinition
+    );
+}
+
 #[test]
 fn local_in_blocks() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -665,3 +792,286 @@ local c = 10
 
     crate::Lua::run_program(program).expect("Should run");
 }
+
+#[test]
+fn break_closes_loop_counter() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local fs = {}
+for i = 1, 3 do
+  fs[i] = function() return i end
+  if i == 2 then break end
+end
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            // local fs = {}
+            Bytecode::new_table(0, 0, 0),
+            // for i = 1, 3 do
+            Bytecode::load_integer(1, 1i8),
+            Bytecode::load_integer(2, 3i8),
+            Bytecode::load_integer(3, 1i8),
+            Bytecode::for_prepare(1, 6u8),
+            //   fs[i] = function() return i end
+            Bytecode::closure(5, 0u8),
+            Bytecode::set_table(0, 4, 5, false),
+            //   if i == 2 then break end
+            Bytecode::equal_integer(4, 2, false),
+            Bytecode::jump(1i8),
+            Bytecode::jump(2i8),
+            // end
+            Bytecode::close(4),
+            Bytecode::for_loop(1, 7u8),
+            Bytecode::close(4),
+            // EOF
+            Bytecode::return_bytecode(1, 1, 1),
+        ],
+        &[],
+        &[
+            Local::new("fs".into(), 3, 16),
+            Local::new("?for_start".into(), 6, 15),
+            Local::new("?for_end".into(), 6, 15),
+            Local::new("?for_step".into(), 6, 15),
+            Local::new("i".into(), 7, 12),
+        ],
+        &["_ENV".into()],
+        1,
+    );
+
+    crate::Lua::run_program(program).expect("Should run");
+}
+
+#[test]
+fn loop_shapes() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Loops that run until broken out of, with `i` as a local of the body
+    // going from 1 up
+    let loops = [
+        ("local n = 0 while true do n = n + 1 local i = n", "end"),
+        ("local n = 0 repeat n = n + 1 local i = n", "until false"),
+        ("for i = 1, 10 do", "end"),
+        ("for i in upto(10) do", "end"),
+    ];
+    // Loops that run twice around the broken loop
+    let outer_loops = [
+        ("do", "end"),
+        ("local m = 0 while m < 2 do m = m + 1", "end"),
+        ("local m = 0 repeat m = m + 1", "until m == 2"),
+        ("for m = 1, 2 do", "end"),
+        ("for m in upto(2) do", "end"),
+    ];
+    // Ways of reaching the `break` of the third iteration
+    let breaks = [
+        "if i == 3 then break end",
+        "do if i == 3 then break end end",
+        "do local pad = i do if pad == 3 then do break end end end end",
+        "if i >= 3 then local f = function() return i end if f() == 3 then break end end",
+        "while true do if i == 3 then goto out end break end",
+    ];
+
+    for (outer_head, outer_tail) in outer_loops {
+        for (head, tail) in loops {
+            for jump in breaks {
+                let outer_iterations = if outer_head == "do" { 1 } else { 2 };
+                let label = if jump.contains("goto") {
+                    "goto skip ::out:: break ::skip::"
+                } else {
+                    ""
+                };
+                let source = format!(
+                    r#"
+local function upto(n)
+  return function(_, i) if i < n then return i + 1 end end, nil, 0
+end
+local fs = {{}}
+local count = 0
+{outer_head}
+  {head}
+    local v = i * 10
+    fs[#fs + 1] = function() return i + v end
+    count = count + 1
+    {jump}
+    {label}
+  {tail}
+{outer_tail}
+assert(count == 3 * {outer_iterations})
+for k = 1, #fs do assert(fs[k]() == ((k - 1) % 3 + 1) * 11) end
+"#
+                );
+                let program = Program::parse(&source)
+                    .unwrap_or_else(|err| panic!("{source} failed to parse with {err:?}"));
+                crate::Lua::run_program(program)
+                    .unwrap_or_else(|err| panic!("{source} failed with {err:?}"));
+            }
+        }
+    }
+}
+
+#[test]
+fn loop_conditions() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Each condition is used on the loops and compared to evaluating it as a
+    // value, which takes a different path
+    let conditions = [
+        "i < 3",
+        "not (i >= 3)",
+        "i < 3 and (i ~= 1 or true)",
+        "i < 3 and (i ~= 1 or false)",
+        "(i ~= 1 or false) and i < 3",
+        "i < 2 or i == 3",
+        "i == 0 or i == 1 or i == 2",
+        "i < 3 and i < 4 and i < 5",
+        "(i < 1 or i < 3) and not (i == 5)",
+        "(i < 3) == true",
+        "not (i >= 3 or i == 10)",
+        "i < 3 and (false or i ~= 4) and (true and i ~= 5)",
+        "i < 4 and (i ~= 1 and i ~= 2 or i == 0)",
+        "i < 2 or (i < 4 and i ~= 3)",
+    ];
+
+    for condition in conditions {
+        let source = format!(
+            r#"
+local function expected()
+  local i = 0
+  while true do
+    local c = {condition}
+    if not c then return i end
+    i = i + 1
+  end
+end
+
+local i = 0
+while {condition} do i = i + 1 end
+assert(i == expected(), "while")
+
+i = 0
+repeat
+  local j = i
+  local stop = not ({condition})
+  i = i + 1
+until stop and j == i - 1
+assert(i - 1 == expected(), "repeat")
+
+local n = 0
+repeat
+  local i = n
+  n = n + 1
+until not ({condition})
+assert(n - 1 == expected(), "repeat with body local")
+
+i = 0
+while true do
+  do
+    if not ({condition}) then break end
+  end
+  i = i + 1
+end
+assert(i == expected(), "break")
+"#
+        );
+        let program = Program::parse(&source)
+            .unwrap_or_else(|err| panic!("{source} failed to parse with {err:?}"));
+        crate::Lua::run_program(program)
+            .unwrap_or_else(|err| panic!("{condition} failed with {err:?}"));
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn numeric_for_semantics() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local max, min = 9223372036854775807, -9223372036854775807 - 1
+
+local function collect(init, limit, step)
+  local values = {}
+  for i = init, limit, step do
+    values[#values + 1] = i
+    if #values > 10 then break end
+  end
+  return values
+end
+
+local function check(values, ...)
+  local expected = {...}
+  assert(#values == #expected)
+  for i = 1, #expected do
+    -- Floats are written with a fraction, so this also compares the types
+    assert(tostring(values[i]) == tostring(expected[i]))
+  end
+end
+
+-- Integers at the edges of their range don't overflow
+check(collect(max - 2, max, 1), max - 2, max - 1, max)
+check(collect(min + 2, min, -1), min + 2, min + 1, min)
+check(collect(max, max - 5, -2), max, max - 2, max - 4)
+check(collect(min, max, max), min, -1, max - 1)
+check(collect(max - 1, max, 10), max - 1)
+
+-- Float limits are rounded towards the inside of the loop
+check(collect(1, 3.7, 1), 1, 2, 3)
+check(collect(3, 0.5, -1), 3, 2, 1)
+check(collect(1, 1 / 0, 1), 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+check(collect(1, -1 / 0, 1))
+check(collect(-1, 1 / 0, -1))
+check(collect(1, 0 / 0, 1))
+check(collect(1, 0, 1))
+
+-- Any float makes a float loop
+check(collect(1.0, 3, 1), 1.0, 2.0, 3.0)
+check(collect(1, 2, 0.5), 1.0, 1.5, 2.0)
+check(collect(3, 1, -1.0), 3.0, 2.0, 1.0)
+check(collect(1, 0, 1.0))
+check(collect("1", 2, 1), 1.0, 2.0)
+
+-- Changing the control variable doesn't change the loop
+local count = 0
+for i = 1, 3 do
+  i = i * 10
+  count = count + 1
+end
+assert(count == 3)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).expect("Should run");
+
+    for source in [
+        "for i = 1, 2, 0 do end",
+        "for i = 1.0, 2, 0 do end",
+        "for i = 1, 2, 0.0 do end",
+    ] {
+        let program = Program::parse(source).unwrap();
+        assert!(
+            matches!(
+                crate::Lua::run_program(program).map_err(Error::unlocated),
+                Err(Error::ForZeroStep)
+            ),
+            "{source}"
+        );
+    }
+    for (source, value) in [
+        ("for i = 1, {} do end", "limit"),
+        ("for i = 1, 2, {} do end", "step"),
+        ("for i = {}, 2 do end", "initial"),
+        ("for i = 1.0, 2, {} do end", "step"),
+    ] {
+        let program = Program::parse(source).unwrap();
+        assert!(
+            matches!(crate::Lua::run_program(program).map_err(Error::unlocated), Err(Error::ForNotNumber(got)) if got == value),
+            "{source}"
+        );
+    }
+}
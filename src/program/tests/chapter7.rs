@@ -325,9 +325,97 @@ print (a>=b)
         0,
     );
 
-    match crate::Lua::run_program(program) {
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
         Err(err @ Error::RelationalOperand(_, _)) => log::error!("{}", err),
         Err(err) => panic!("Expected `RelationalOperand` error, but got {:?}.", err),
         Ok(_) => panic!("Last print should fail"),
     }
 }
+
+#[test]
+fn and_or_values() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local b, c = nil, 2
+local a = b or c
+a = a and b
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            // local b, c = nil, 2
+            Bytecode::load_nil(0, 0),
+            Bytecode::load_integer(1, 2i8),
+            // local a = b or c
+            Bytecode::test_set(2, 0, true),
+            Bytecode::jump(1i8),
+            Bytecode::move_bytecode(2, 1),
+            // a = a and b
+            Bytecode::test(2, false),
+            Bytecode::jump(1i8),
+            Bytecode::move_bytecode(2, 0),
+            // EOF
+            Bytecode::return_bytecode(3, 1, 1),
+        ],
+        &[],
+        &[
+            Local::new("b".into(), 4, 11),
+            Local::new("c".into(), 4, 11),
+            Local::new("a".into(), 7, 11),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+
+    crate::Lua::run_program(program).expect("Should run");
+
+    let program = Program::parse(
+        r#"
+local none, one, two, three = nil, 1, 2, 3
+local a = none or two
+assert(a == two)
+local b = one or two
+assert(b == one)
+local c = one and two
+assert(c == two)
+local d = false and two
+local d_type = type(d)
+assert(d_type == "boolean")
+local e = none and one or three
+assert(e == three)
+local f = one and false or three
+assert(f == three)
+local g = (none or one) and (false or two)
+assert(g == two)
+local h = none and one < two
+local h_type = type(h)
+assert(h_type == "nil")
+local calls = 0
+local function count()
+    calls = calls + 1
+    return calls
+end
+local i = one or count()
+local zero = 0
+assert(calls == zero)
+local j = none or count()
+assert(j == one)
+global = none or two
+local k = global
+assert(k == two)
+local t = {}
+t.x = one and three
+local l = t.x
+assert(l == three)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).expect("Should run");
+}
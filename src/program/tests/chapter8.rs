@@ -1,4 +1,11 @@
-use crate::{Error, Program, bytecode::Bytecode, program::Local};
+use crate::{
+    Error, Lua, Program,
+    bytecode::Bytecode,
+    closure::{NativeClosure, NativeClosureReturn},
+    environment::Environment,
+    program::Local,
+    value::Value,
+};
 
 #[test]
 fn base_function() {
@@ -372,22 +379,21 @@ f(1)
             // print(a+b)
             Bytecode::get_uptable(2, 0, 0),
             Bytecode::add(3, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 6),
             Bytecode::call(2, 2, 1),
             // end
             Bytecode::zero_return(),
         ],
         &["print".into()],
-        &[
-            // TODO update when implementing MMBIN
-            Local::new("a".into(), 1, 5),
-            Local::new("b".into(), 1, 5),
-        ],
+        &[Local::new("a".into(), 1, 6), Local::new("b".into(), 1, 6)],
         &["_ENV".into()],
         0,
     );
 
-    match crate::Lua::run_program(program).inspect_err(|err| log::error!("{err}")) {
+    match crate::Lua::run_program(program)
+        .inspect_err(|err| log::error!("{err}"))
+        .map_err(Error::unlocated)
+    {
         Ok(_) => panic!("Program should fail"),
         Err(Error::ArithmeticOperand("add", "integer", "nil")) => (),
         Err(err) => panic!("Program raised wrong error `{err}`."),
@@ -446,17 +452,13 @@ print(f(100,200))
             // local function f(a, b)
             //     return a+b
             Bytecode::add(2, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 6),
             Bytecode::one_return(2),
             // end
             Bytecode::zero_return(),
         ],
         &[],
-        &[
-            // TODO update when implementing MMBIN
-            Local::new("a".into(), 1, 4),
-            Local::new("b".into(), 1, 4),
-        ],
+        &[Local::new("a".into(), 1, 5), Local::new("b".into(), 1, 5)],
         &[],
         0,
     );
@@ -464,6 +466,7 @@ print(f(100,200))
     crate::Lua::run_program(program).expect("Should work");
 }
 
+#[cfg(feature = "float")]
 #[test]
 fn rustf() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -586,17 +589,14 @@ print(f(0))
             //     return f(n+1)
             Bytecode::get_uptable(1, 0, 0),
             Bytecode::add_integer(2, 0, 1),
-            // TODO MMBINI
+            Bytecode::metamethod_integer(0, 1, 6, false),
             Bytecode::tail_call(1, 2, 0),
             Bytecode::return_bytecode(1, 0, 0),
             // end
             Bytecode::zero_return(),
         ],
         &["f".into()],
-        &[
-            // TODO update when implementing MMBINI
-            Local::new("n".into(), 1, 10),
-        ],
+        &[Local::new("n".into(), 1, 11)],
         &["_ENV".into()],
         0,
     );
@@ -604,6 +604,99 @@ print(f(0))
     crate::Lua::run_program(program).expect("Should work");
 }
 
+#[test]
+fn tailcall_constant_stack() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function loop(n, frames)
+    if n == 0 then
+        return frames
+    end
+    return loop(n - 1, depth())
+end
+
+local is_even
+local function is_odd(n)
+    if n == 0 then
+        return false
+    end
+    return is_even(n - 1)
+end
+is_even = function(n)
+    if n == 0 then
+        return depth()
+    end
+    return is_odd(n - 1)
+end
+
+local frames = loop(1000000, 0)
+local mutual = is_even(100000)
+return frames, mutual
+"#,
+    )
+    .unwrap();
+
+    /// Returns the number of running functions
+    fn depth(vm: &mut Lua) -> NativeClosureReturn {
+        let frames = vm.stack_frame.len() as i64;
+        vm.set_stack(0, Value::Integer(frames))?;
+        Ok(1)
+    }
+
+    let mut env = Environment::default();
+    env.push("depth", depth as NativeClosure).unwrap();
+
+    let mut vm = Lua::new(env);
+    // The main chunk, the recursive function, and `depth`, which
+    // takes the place of `is_even` when it is tail called
+    assert_eq!(
+        vm.execute(program).unwrap(),
+        [Value::Integer(3), Value::Integer(2)]
+    );
+    assert!(vm.stack.is_empty());
+}
+
+#[test]
+fn returns_move_down_the_stack() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function three() return 1, 2, 3 end
+local function args(a, ...)
+    local b, c = ...
+    return a, b, c
+end
+local x, y = three()
+local p, q, r, s = three()
+local one = three()
+local d, e, f = args(4, 5, 6)
+return x, y, p, q, r, s, one, d, e, f
+"#,
+    )
+    .unwrap();
+
+    let mut vm = Lua::new(Environment::default());
+    assert_eq!(
+        vm.execute(program).unwrap(),
+        [
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(3),
+            Value::Nil,
+            Value::Integer(1),
+            Value::Integer(4),
+            Value::Integer(5),
+            Value::Integer(6),
+        ]
+    );
+    assert!(vm.stack.is_empty());
+}
+
 #[test]
 fn print() {
     let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
@@ -998,19 +1091,15 @@ print(y)
             // function f1(a, b)
             //     return a+b, a-b
             Bytecode::add(2, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 6),
             Bytecode::sub(3, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 7),
             Bytecode::return_bytecode(2, 3, 0),
             // end
             Bytecode::zero_return(),
         ],
         &[],
-        &[
-            // TODO update when implementing MMBIN
-            Local::new("a".into(), 1, 5),
-            Local::new("b".into(), 1, 5),
-        ],
+        &[Local::new("a".into(), 1, 7), Local::new("b".into(), 1, 7)],
         &[],
         0,
     );
@@ -1023,16 +1112,16 @@ print(y)
             //     return f1(a+b, a-b) -- return MULTRET
             Bytecode::get_uptable(2, 0, 0),
             Bytecode::add(3, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 6),
             Bytecode::sub(4, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 7),
             Bytecode::tail_call(2, 3, 0),
             Bytecode::return_bytecode(2, 0, 0),
             // end
             Bytecode::zero_return(),
         ],
         &["f1".into()],
-        &[Local::new("a".into(), 1, 7), Local::new("b".into(), 1, 7)],
+        &[Local::new("a".into(), 1, 9), Local::new("b".into(), 1, 9)],
         &["_ENV".into()],
         0,
     );
@@ -1126,17 +1215,13 @@ t.methods.bar(t, 100, 200)
             //     print(a+b)
             Bytecode::get_uptable(2, 0, 0),
             Bytecode::add(3, 0, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(0, 1, 6),
             Bytecode::call(2, 2, 1),
             // end
             Bytecode::zero_return(),
         ],
         &["print".into()],
-        &[
-            // TODO update when implementing MMBIN
-            Local::new("a".into(), 1, 5),
-            Local::new("b".into(), 1, 5),
-        ],
+        &[Local::new("a".into(), 1, 6), Local::new("b".into(), 1, 6)],
         &["_ENV".into()],
         0,
     );
@@ -1151,25 +1236,104 @@ t.methods.bar(t, 100, 200)
             Bytecode::get_index(4, 0, 1),
             Bytecode::get_index(5, 0, 2),
             Bytecode::add(4, 4, 5),
-            // TODO MMBIN
+            Bytecode::metamethod(4, 5, 6),
             Bytecode::add(4, 4, 1),
-            // TODO MMBIN
+            Bytecode::metamethod(4, 1, 6),
             Bytecode::add(4, 4, 2),
-            // TODO MMBIN
+            Bytecode::metamethod(4, 2, 6),
             Bytecode::call(3, 2, 1),
             // end
             Bytecode::zero_return(),
         ],
         &["print".into()],
         &[
-            // TODO update when implementing MMBIN
-            Local::new("self".into(), 1, 9),
-            Local::new("a".into(), 1, 9),
-            Local::new("b".into(), 1, 9),
+            Local::new("self".into(), 1, 12),
+            Local::new("a".into(), 1, 12),
+            Local::new("b".into(), 1, 12),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+
+    crate::Lua::run_program(program).expect("Should run");
+}
+
+#[test]
+fn method_call_receivers() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local obj = {}
+local y = 1
+y = obj:me():get(y)
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            // local obj = {}
+            Bytecode::new_table(0, 0, 0),
+            // local y = 1
+            Bytecode::load_integer(1, 1i8),
+            // y = obj:me():get(y)
+            Bytecode::table_self(2, 0, 0),
+            Bytecode::call(2, 2, 2),
+            Bytecode::table_self(2, 2, 1),
+            Bytecode::move_bytecode(4, 1),
+            Bytecode::call(2, 3, 2),
+            Bytecode::move_bytecode(1, 2),
+            // EOF
+            Bytecode::return_bytecode(2, 1, 1),
+        ],
+        &["me".into(), "get".into()],
+        &[
+            Local::new("obj".into(), 3, 11),
+            Local::new("y".into(), 4, 11),
         ],
         &["_ENV".into()],
         0,
     );
 
+    let program = Program::parse(
+        r#"
+local obj = {n = 1}
+function obj:get(x) return self.n + (x or 0) end
+function obj:me() return self end
+function obj:two() return 1, 2 end
+local t = {a = {b = obj}}
+local function f() return obj end
+
+assert((obj):get(1) == 2)
+assert(t.a.b:get(2) == 3)
+assert(t["a"]["b"]:me():get(3) == 4)
+assert(obj:me():me():get(4) == 5)
+assert(f():get(5) == 6)
+assert(f():me():get(f():get(5)) == 7)
+assert(({obj})[1]:get(7) == 8)
+
+local y = 1
+y = obj:get(y)
+assert(y == 2)
+obj.n = obj:get(obj.n)
+assert(obj.n == 2)
+obj.n = 1
+
+local function forward(...) return obj:get(...) end
+assert(forward(2) == 3)
+local a, b = obj:two()
+assert(a == 1 and b == 2)
+local list = {obj:me():two()}
+assert(#list == 2)
+local function tail() return obj:two() end
+local c, d = tail()
+assert(c == 1 and d == 2)
+"#,
+    )
+    .unwrap();
+
     crate::Lua::run_program(program).expect("Should run");
 }
@@ -7,7 +7,7 @@ use crate::{
     bytecode::Bytecode,
     closure::{Closure, NativeClosureReturn, Upvalue},
     environment::Environment,
-    program::Local,
+    program::{Local, UpvalueDescriptor},
     value::Value,
 };
 
@@ -287,7 +287,7 @@ f1()
             //     i = i + 1
             Bytecode::get_upvalue(0, 1),
             Bytecode::add_integer(0, 0, 1),
-            // MMBINI
+            Bytecode::metamethod_integer(0, 1, 6, false),
             Bytecode::set_upvalue(0, 1),
             // end
             Bytecode::zero_return(),
@@ -703,7 +703,7 @@ print(bars[1](), bars[1](), "|", bars[4](), bars[4]())
             //     up = up + 1
             Bytecode::get_upvalue(0, 0),
             Bytecode::add_integer(0, 0, 1),
-            // TODO MMBINI
+            Bytecode::metamethod_integer(0, 1, 6, false),
             Bytecode::set_upvalue(0, 0),
             //     return up
             Bytecode::get_upvalue(0, 0),
@@ -725,7 +725,7 @@ print(bars[1](), bars[1](), "|", bars[4](), bars[4]())
             //     i = i + 1
             Bytecode::get_upvalue(0, 0),
             Bytecode::add_integer(0, 0, 1),
-            // TODO MMBINI
+            Bytecode::metamethod_integer(0, 1, 6, false),
             Bytecode::set_upvalue(0, 0),
             //     return i
             Bytecode::get_upvalue(0, 0),
@@ -794,7 +794,7 @@ c1()
 
     fn new_counter(vm: &mut Lua) -> NativeClosureReturn {
         let Value::Integer(start_count) = vm.get_upvalue(0)? else {
-            return Err(Error::ExpectedTable);
+            return Err(Error::CorruptStack);
         };
         vm.set_stack(
             0,
@@ -811,7 +811,7 @@ c1()
 
     fn count(vm: &mut Lua) -> NativeClosureReturn {
         let Value::Integer(count) = vm.get_upvalue(0)? else {
-            return Err(Error::ExpectedTable);
+            return Err(Error::CorruptStack);
         };
         log::info!("Counted to {}", count);
         vm.set_upvalue(0, count + 1)?;
@@ -943,7 +943,7 @@ f('after')
             Bytecode::get_upvalue(1, 0),
             //     i = i + 1
             Bytecode::add_integer(1, 1, 1),
-            // MMBINI
+            Bytecode::metamethod_integer(1, 1, 6, false),
             Bytecode::set_upvalue(1, 0),
             //     print(prefix, i)
             Bytecode::get_uptable(1, 1, 0),
@@ -954,8 +954,7 @@ f('after')
             Bytecode::zero_return(),
         ],
         &["print".into()],
-        // TODO Update when MMBINI is implementend
-        &[Local::new("prefix".into(), 1, 9)],
+        &[Local::new("prefix".into(), 1, 10)],
         &["i".into(), "_ENV".into()],
         0,
     );
@@ -1046,7 +1045,7 @@ end
             Bytecode::move_bytecode(3, 1),
             Bytecode::move_bytecode(4, 2),
             Bytecode::call(3, 2, 5),
-            Bytecode::generic_for_prepare(3, 5u8),
+            Bytecode::generic_for_prepare(3, 6u8),
             // 	print(i, v)
             Bytecode::get_uptable(9, 0, 1),
             Bytecode::move_bytecode(10, 7),
@@ -1054,20 +1053,19 @@ end
             Bytecode::call(9, 3, 1),
             // 	i = i+1 -- update ctrl-var during loop
             Bytecode::add_integer(7, 7, 1),
-            // MMBINI
+            Bytecode::metamethod_integer(7, 1, 6, false),
             // end
             Bytecode::generic_for_call(3, 2),
-            Bytecode::generic_for_loop(3, 7u8),
+            Bytecode::generic_for_loop(3, 8u8),
             Bytecode::close(3),
             // EOF
             Bytecode::return_bytecode(3, 1, 1),
         ],
         &["hello".into(), "print".into()],
-        // TODO Update when MMBINI is implementend
         &[
-            Local::new("iter".into(), 3, 45),
-            Local::new("my_ipairs".into(), 4, 45),
-            Local::new("z".into(), 9, 45),
+            Local::new("iter".into(), 3, 46),
+            Local::new("my_ipairs".into(), 4, 46),
+            Local::new("z".into(), 9, 46),
             Local::new("?for_iterator".into(), 12, 19),
             Local::new("?for_state".into(), 12, 19),
             Local::new("?for_control".into(), 12, 19),
@@ -1080,12 +1078,12 @@ end
             Local::new("?for_closing_value".into(), 24, 31),
             Local::new("i".into(), 25, 29),
             Local::new("v".into(), 25, 29),
-            Local::new("?for_iterator".into(), 35, 43),
-            Local::new("?for_state".into(), 35, 43),
-            Local::new("?for_control".into(), 35, 43),
-            Local::new("?for_closing_value".into(), 35, 43),
-            Local::new("i".into(), 36, 41),
-            Local::new("v".into(), 36, 41),
+            Local::new("?for_iterator".into(), 35, 44),
+            Local::new("?for_state".into(), 35, 44),
+            Local::new("?for_control".into(), 35, 44),
+            Local::new("?for_closing_value".into(), 35, 44),
+            Local::new("i".into(), 36, 42),
+            Local::new("v".into(), 36, 42),
         ],
         &["_ENV".into()],
         2,
@@ -1098,7 +1096,7 @@ end
             // local function iter(t, i)
             //     i = i + 1
             Bytecode::add_integer(1, 1, 1),
-            // MMBINI
+            Bytecode::metamethod_integer(1, 1, 6, false),
             //     local v = t[i]
             Bytecode::get_table(2, 0, 1),
             //     if v then
@@ -1113,11 +1111,10 @@ end
             Bytecode::zero_return(),
         ],
         &[],
-        // TODO Update when MMBINI is implementend
         &[
-            Local::new("t".into(), 1, 9),
-            Local::new("i".into(), 1, 9),
-            Local::new("v".into(), 3, 9),
+            Local::new("t".into(), 1, 10),
+            Local::new("i".into(), 1, 10),
+            Local::new("v".into(), 4, 10),
         ],
         &[],
         0,
@@ -1144,3 +1141,160 @@ end
 
     crate::Lua::run_program(program).unwrap();
 }
+
+#[test]
+fn shared_upvalues() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local function counter()
+    local count = 0
+    local function inc()
+        count = count + 1
+        return count
+    end
+    local function get()
+        return count
+    end
+    return inc, get
+end
+local inc, get = counter()
+inc()
+inc()
+assert(get() == 2)
+
+local x = 1
+local x = function() return x end
+assert(x() == 1)
+
+local function fact(n)
+    if n <= 1 then
+        return 1
+    end
+    local r = fact(n - 1)
+    return r * n
+end
+assert(fact(5) == 120)
+
+local function nested()
+    local v = 1
+    local function outer()
+        return function()
+            v = v + 1
+        end
+    end
+    return outer, function() return v end
+end
+local outer, read = nested()
+outer()()
+outer()()
+assert(read() == 3)
+"#,
+    )
+    .unwrap();
+
+    let counter = super::get_closure_program(&program, 0);
+    for function in counter.functions() {
+        assert_eq!(
+            function.program().upvalue_descriptors(),
+            &[UpvalueDescriptor::local(0)]
+        );
+    }
+    let nested = super::get_closure_program(&program, 3);
+    let outer = super::get_closure_program(nested, 0);
+    assert_eq!(
+        outer
+            .functions()
+            .next()
+            .unwrap()
+            .program()
+            .upvalue_descriptors(),
+        &[UpvalueDescriptor::upvalue(0)]
+    );
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn close_on_scope_exit() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+-- Closing a block keeps the upvalues of the locals still in scope
+local function counter()
+    local count = 0
+    local function inc() count = count + 1 end
+    do
+        local x = 1
+        local function get() return x end
+    end
+    inc()
+    count = count + 10
+    inc()
+    return count
+end
+assert(counter() == 12)
+
+-- Each iteration has its own locals
+local fns = {}
+local i = 0
+while i < 3 do
+    i = i + 1
+    local x = i
+    fns[i] = function() return x end
+end
+assert(fns[1]() == 1 and fns[2]() == 2 and fns[3]() == 3)
+
+-- `break` closes the locals it leaves
+i = 0
+while true do
+    i = i + 1
+    local y = i * 10
+    do
+        local z = y + 1
+        fns[i] = function() return y, z end
+        if i == 2 then break end
+    end
+end
+local reused1, reused2, reused3 = 1, 2, 3
+local y1, z1 = fns[1]()
+local y2, z2 = fns[2]()
+assert(y1 == 10 and z1 == 11 and y2 == 20 and z2 == 21)
+
+-- Going back to the start of `repeat` closes the locals
+local k = 0
+repeat
+    k = k + 1
+    local w = k
+    fns[k] = function() return w end
+until w == 3
+local reused4 = 4
+assert(fns[1]() == 1 and fns[2]() == 2 and fns[3]() == 3)
+
+-- Gotos close the locals of the blocks they leave
+do
+    local v = 5
+    fns[1] = function() return v end
+    goto out
+end
+::out::
+local reused5 = 6
+assert(fns[1]() == 5)
+
+local n = 0
+::again::
+do
+    local u = n
+    fns[n + 1] = function() return u end
+    n = n + 1
+    if n < 3 then goto again end
+end
+assert(fns[1]() == 0 and fns[2]() == 1 and fns[3]() == 2)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
@@ -0,0 +1,240 @@
+use alloc::{string::ToString, vec, vec::Vec};
+
+use crate::{
+    Program,
+    parser::{CompileError, CompileErrorKind},
+    program::Error,
+};
+
+#[test]
+fn valid_program() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let source = r#"
+local function f(a, ...)
+    for i = 1, a do
+        print(i, ...)
+    end
+    return {a = a, [1] = "one"}
+end
+f(2, "x")
+"#;
+    assert_eq!(Program::check(source), Ok(()));
+    // Errors found by the compiler are not reported
+    assert_eq!(Program::check("break\n"), Ok(()));
+}
+
+#[test]
+fn syntax_errors() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let errors = Program::check("local a = 1\nlocal = 2\n").unwrap_err();
+    assert_eq!(
+        errors,
+        vec![CompileError {
+            kind: CompileErrorKind::UnexpectedToken,
+            line: 2,
+            column: 7,
+        }]
+    );
+    assert_eq!(errors[0].to_string(), "2:7: Unexpected token.");
+
+    let errors = Program::check("if a then\n    print(a)\n").unwrap_err();
+    assert!(matches!(
+        errors.as_slice(),
+        [CompileError {
+            kind: CompileErrorKind::UnexpectedEof,
+            line: 3,
+            ..
+        }]
+    ));
+
+    let errors = Program::check("local s = \"unfinished").unwrap_err();
+    assert!(matches!(
+        errors.as_slice(),
+        [CompileError {
+            kind: CompileErrorKind::Lexical(_),
+            line: 1,
+            ..
+        }]
+    ));
+
+    // Parsing reports the failure instead of panicking
+    assert_eq!(
+        Program::parse("local a = 1\nlocal = 2\n")
+            .unwrap_err()
+            .unlocated(),
+        Error::Parse
+    );
+}
+
+#[test]
+fn multiple_errors() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let source = r#"
+local a = = 1
+local b = 2
+function f(x)
+    x = x + * 2
+    return x
+end
+print(b +)
+local c = 3
+"#;
+    let errors = Program::check(source).unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|err| (err.kind.clone(), err.line, err.column))
+            .collect::<Vec<_>>(),
+        vec![
+            (CompileErrorKind::UnexpectedToken, 2, 11),
+            (CompileErrorKind::UnexpectedToken, 5, 13),
+            (CompileErrorKind::UnexpectedToken, 8, 10),
+        ]
+    );
+
+    // Statements that follow `end` and `until` are still checked
+    let source = r#"
+repeat
+    local x = )
+until x
+while true do
+    y = 1 2
+end
+local = 1
+"#;
+    let errors = Program::check(source).unwrap_err();
+    assert_eq!(
+        errors.iter().map(|err| err.line).collect::<Vec<_>>(),
+        vec![3, 6, 8]
+    );
+
+    // Statements that start with a name are found after an expression
+    let errors = Program::check("x = {1,2,,3} y = 1 z = = 2").unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|err| (err.kind.clone(), err.line, err.column))
+            .collect::<Vec<_>>(),
+        vec![
+            (CompileErrorKind::UnexpectedToken, 1, 10),
+            (CompileErrorKind::UnexpectedToken, 1, 24),
+        ]
+    );
+    assert_eq!(
+        Program::parse("x = {1,2,,3} y = 1 z = = 2")
+            .unwrap_err()
+            .unlocated(),
+        Error::Parse
+    );
+
+    // The end of the program stops the check
+    let errors = Program::check("local a = )\nif a then\n").unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|err| err.kind.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            CompileErrorKind::UnexpectedToken,
+            CompileErrorKind::UnexpectedEof
+        ]
+    );
+}
+
+#[test]
+fn fuzz_entry_points() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Inputs that used to panic, they must fail without panicking
+    for data in [
+        &b"a = `"[..],
+        b"n\xdf\x92u",
+        b"\xff\xfe",
+        b"if 2 then end",
+        b"local t = {} t[1.5] = t.x.y + f().z",
+        b"\"",
+        b"local t = {} t[nil] = 1",
+        b"local t = {[...] = 1, [function() end] = 2}",
+    ] {
+        crate::fuzz::lex(data);
+        crate::fuzz::parse(data);
+        crate::fuzz::load_chunk(data);
+    }
+
+    let chunk = Program::parse("local a = {1, 2}\nprint(a[1])\n")
+        .unwrap()
+        .to_bytecode();
+    for end in 0..chunk.len() {
+        crate::fuzz::load_chunk(&chunk[..end]);
+    }
+    // Chunks with corrupt operands fail without panicking
+    for byte in 0..chunk.len() {
+        let mut corrupt = chunk.clone();
+        corrupt[byte] ^= 0xff;
+        crate::fuzz::run_chunk(&corrupt);
+    }
+}
+
+#[test]
+fn nesting_limit() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let nested = |open: &str, inner: &str, close: &str, levels: usize| {
+        alloc::format!(
+            "local a = {}{}{}\n",
+            open.repeat(levels),
+            inner,
+            close.repeat(levels)
+        )
+    };
+    for source in [
+        nested("(", "1", ")", 100_000),
+        nested("{", "", "}", 100_000),
+        nested("- ", "1", "", 100_000),
+        nested("2 ^ ", "2", "", 100_000),
+        nested("function() return ", "1", " end", 5000),
+        alloc::format!(
+            "{}{}",
+            "local function f() ".repeat(5000),
+            "end ".repeat(5000)
+        ),
+    ] {
+        let errors = Program::check(&source).unwrap_err();
+        assert!(matches!(
+            errors.as_slice(),
+            [CompileError {
+                kind: CompileErrorKind::TooManyLevels,
+                line: 1,
+                ..
+            }]
+        ));
+        assert_eq!(
+            Program::parse(&source).unwrap_err().unlocated(),
+            Error::TooManyLevels
+        );
+        crate::fuzz::parse(source.as_bytes());
+    }
+
+    let source = nested("(", "1", ")", 150);
+    assert_eq!(Program::check(&source), Ok(()));
+    let program = Program::parse(&source).unwrap();
+    assert_eq!(crate::Lua::default().execute(program).unwrap(), []);
+}
+
+#[test]
+fn long_lists() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Each element nests a token on the syntax tree, which is dropped
+    // without recursing
+    for source in [
+        alloc::format!("print({}1)\n", "1, ".repeat(100_000)),
+        alloc::format!("local t = {{{}}}\n", "1, ".repeat(100_000)),
+        alloc::format!("do\n{}end\n", "a = 1\n".repeat(100_000)),
+    ] {
+        assert_eq!(Program::check(&source), Ok(()));
+    }
+}
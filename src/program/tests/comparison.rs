@@ -0,0 +1,435 @@
+use core::cmp::Ordering;
+
+use alloc::{format, string::String};
+
+use crate::{Error, Lua, Program, bytecode::Bytecode, program::Local, value::Value};
+
+#[test]
+fn operand_shapes() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a, b = 1, 2.5
+local c = a == b
+local d = a <= 3
+local e = 3 <= a
+local f = a ~= "x"
+local g = a > b
+local h = 1000 < a
+"#,
+    )
+    .unwrap();
+
+    super::compare_program(
+        &program,
+        &[
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_integer(0, 1i16),
+            Bytecode::load_constant(1, 0u8),
+            Bytecode::equal(0, 1, true),
+            Bytecode::jump(1i8),
+            Bytecode::load_false_skip(2),
+            Bytecode::load_true(2),
+            Bytecode::less_equal_integer(0, 3i8, true),
+            Bytecode::jump(1i8),
+            Bytecode::load_false_skip(3),
+            Bytecode::load_true(3),
+            Bytecode::greater_equal_integer(0, 3i8, true),
+            Bytecode::jump(1i8),
+            Bytecode::load_false_skip(4),
+            Bytecode::load_true(4),
+            Bytecode::equal_constant(0, 1, false),
+            Bytecode::jump(1i8),
+            Bytecode::load_false_skip(5),
+            Bytecode::load_true(5),
+            Bytecode::less_than(1, 0, true),
+            Bytecode::jump(1i8),
+            Bytecode::load_false_skip(6),
+            Bytecode::load_true(6),
+            Bytecode::load_integer(7, 1000i16),
+            Bytecode::less_than(7, 0, true),
+            Bytecode::jump(1i8),
+            Bytecode::load_false_skip(7),
+            Bytecode::load_true(7),
+            Bytecode::return_bytecode(8, 1, 1),
+        ],
+        &[Value::Float(2.5), "x".into()],
+        &[
+            Local::new("a".into(), 4, 30),
+            Local::new("b".into(), 4, 30),
+            Local::new("c".into(), 8, 30),
+            Local::new("d".into(), 12, 30),
+            Local::new("e".into(), 16, 30),
+            Local::new("f".into(), 20, 30),
+            Local::new("g".into(), 24, 30),
+            Local::new("h".into(), 29, 30),
+        ],
+        &["_ENV".into()],
+        0,
+    );
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn equality() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local one, two, onef, big = 1, 2, 1.0, 1000
+local r = one == onef
+assert(r)
+r = onef == 1
+assert(r)
+r = big == 1000
+assert(r)
+r = one ~= two
+assert(r)
+r = nil == one
+assert(not r)
+r = true == true
+assert(r)
+local s = "abc"
+r = "abc" == s
+assert(r)
+r = s ~= "abd"
+assert(r)
+local t = {}
+local u = t
+r = t == u
+assert(r)
+r = t == {}
+assert(not r)
+local function f() return 3 end
+r = f == f
+assert(r)
+r = f() == f()
+assert(r)
+g = 5
+r = g == 5
+assert(r)
+r = type(g) == "integer"
+assert(r)
+local zero = 0.0
+local nan = zero / zero
+r = nan == nan
+assert(not r)
+local huge, hugef = 9007199254740993, 9007199254740992.0
+r = huge == hugef
+assert(not r)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn ordering() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local one, two, half = 1, 2, 0.5
+local r = one <= 1
+assert(r)
+r = 3 <= two
+assert(not r)
+r = 2 >= one
+assert(r)
+r = 0 < one
+assert(r)
+r = two >= 1000
+assert(not r)
+r = half < one
+assert(r)
+r = one > half
+assert(r)
+r = "a" < "b"
+assert(r)
+local s = "abc"
+r = s <= "abd"
+assert(r)
+local function f() return 3 end
+r = f() > two
+assert(r)
+local huge, hugef = 9007199254740993, 9007199254740992.0
+r = huge > hugef
+assert(r)
+r = hugef < huge
+assert(r)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn conditions() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local one, two, onef, s = 1, 2, 1.0, "abc"
+local t = {}
+local u = t
+g = 5
+local reached = false
+if one <= 1 and two >= 2 and one ~= two and g == 5 and s ~= "x" and 1 < two and one == onef then
+    reached = true
+end
+assert(reached)
+if nil == one then assert(false) end
+if t ~= u then assert(false) end
+if 1000 < one then assert(false) end
+if s > "b" then assert(false) end
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn metamethods() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local function unwrap(v)
+    if type(v) == "table" then return v.x end
+    return v
+end
+local mt = {}
+mt.__eq = function(a, b) return a.x == b.x end
+mt.__lt = function(a, b) return unwrap(a) < unwrap(b) end
+mt.__le = function(a, b) return unwrap(a) <= unwrap(b) end
+local a = setmetatable({x = 1}, mt)
+local b = setmetatable({x = 2}, mt)
+local c = setmetatable({x = 1}, mt)
+local plain = {x = 1}
+local r = a == c
+assert(r)
+r = a ~= b
+assert(r)
+r = plain == a
+assert(r)
+r = a == 1
+assert(not r)
+r = a < b
+assert(r)
+r = b <= a
+assert(not r)
+r = a > b
+assert(not r)
+r = b >= a
+assert(r)
+r = a < 2
+assert(r)
+r = a <= 1
+assert(r)
+r = a > 0
+assert(r)
+r = a >= 2
+assert(not r)
+r = 0 < a
+assert(r)
+if a < b and a <= c and a ~= b then r = "ok" end
+assert(r == "ok")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse("local a, b = {}, {}\nlocal r = a < b\n").unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::RelationalOperand("table", "table")) => (),
+        Err(err) => panic!(
+            "Should fail with RelationalOperand, but failed with `{}`.",
+            err
+        ),
+    }
+}
+
+/// Operand of the property tests, with its source and its reference
+/// ordering against other operands
+#[derive(Clone, Copy)]
+enum Operand {
+    Integer(i64),
+    Float(f64),
+    String(&'static [u8]),
+}
+
+impl Operand {
+    fn source(&self) -> String {
+        match self {
+            // `9223372036854775808` would be read as a float
+            Self::Integer(i64::MIN) => String::from("(-9223372036854775807 - 1)"),
+            Self::Integer(integer) => format!("({integer})"),
+            Self::Float(float) if float.is_nan() => String::from("(0.0 / 0.0)"),
+            Self::Float(float) if float.is_infinite() && *float > 0.0 => {
+                String::from("(1.0 / 0.0)")
+            }
+            Self::Float(float) if float.is_infinite() => String::from("(-1.0 / 0.0)"),
+            Self::Float(float) => format!("({float:?})"),
+            Self::String(bytes) => {
+                let mut source = String::from("\"");
+                for byte in bytes.iter() {
+                    source.push_str(&format!("\\{byte:03}"));
+                }
+                source.push('"');
+                source
+            }
+        }
+    }
+
+    /// Ordering computed without going through floats, `None` if the
+    /// operands are unordered
+    fn reference(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Integer(lhs), Self::Integer(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Float(lhs), Self::Float(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Integer(lhs), Self::Float(rhs)) => integer_float_reference(*lhs, *rhs),
+            (Self::Float(lhs), Self::Integer(rhs)) => {
+                integer_float_reference(*rhs, *lhs).map(Ordering::reverse)
+            }
+            (Self::String(lhs), Self::String(rhs)) => Some(lhs.cmp(rhs)),
+            _ => None,
+        }
+    }
+}
+
+/// Compares through 128 bits integers, where the integral part of every
+/// float under 2^64 fits exactly
+fn integer_float_reference(integer: i64, float: f64) -> Option<Ordering> {
+    const LIMIT: f64 = 18446744073709551616.0;
+    if float.is_nan() {
+        None
+    } else if float >= LIMIT {
+        Some(Ordering::Less)
+    } else if float <= -LIMIT {
+        Some(Ordering::Greater)
+    } else {
+        let floor = float.floor();
+        #[expect(clippy::cast_possible_truncation, reason = "Floor is under 2^64")]
+        match i128::from(integer).cmp(&(floor as i128)) {
+            Ordering::Equal if float > floor => Some(Ordering::Less),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+#[test]
+fn mixed_operands() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let numbers = [
+        Operand::Integer(0),
+        Operand::Integer(1),
+        Operand::Integer(-1),
+        Operand::Integer(9007199254740992),
+        Operand::Integer(9007199254740993),
+        Operand::Integer(-9007199254740993),
+        Operand::Integer(i64::MAX),
+        Operand::Integer(i64::MAX - 1),
+        Operand::Integer(i64::MIN),
+        Operand::Float(0.0),
+        Operand::Float(-0.0),
+        Operand::Float(0.5),
+        Operand::Float(-1.5),
+        Operand::Float(9007199254740992.0),
+        Operand::Float(9007199254740994.0),
+        Operand::Float(9223372036854775808.0),
+        Operand::Float(9223372036854774784.0),
+        Operand::Float(-9223372036854775808.0),
+        Operand::Float(-9223372036854777856.0),
+        Operand::Float(f64::INFINITY),
+        Operand::Float(f64::NEG_INFINITY),
+        Operand::Float(f64::NAN),
+    ];
+    let strings = [
+        Operand::String(b""),
+        Operand::String(b"a"),
+        Operand::String(b"a\0"),
+        Operand::String(b"a\0b"),
+        Operand::String(b"ab"),
+        Operand::String(b"B"),
+        Operand::String(b"b"),
+        Operand::String(b"\xff"),
+    ];
+    // Immediate operands are compiled to `LTI`, `LEI`, `GTI` and `GEI`
+    let immediates = [-1i64, 0, 1, 127];
+
+    let mut source = String::from("local v = {}\n");
+    for (i, operand) in numbers.iter().chain(strings.iter()).enumerate() {
+        source.push_str(&format!("v[{}] = {}\n", i + 1, operand.source()));
+    }
+    let mut check = |lhs: String, rhs: String, ordering: Option<Ordering>| {
+        let less = ordering == Some(Ordering::Less);
+        let less_equal = matches!(ordering, Some(Ordering::Less | Ordering::Equal));
+        let greater = ordering == Some(Ordering::Greater);
+        let greater_equal = matches!(ordering, Some(Ordering::Greater | Ordering::Equal));
+        source.push_str(&format!(
+            "assert(({lhs} < {rhs}) == {less}, \"{lhs} < {rhs}\")\n\
+             assert(({lhs} <= {rhs}) == {less_equal}, \"{lhs} <= {rhs}\")\n\
+             assert(({lhs} > {rhs}) == {greater}, \"{lhs} > {rhs}\")\n\
+             assert(({lhs} >= {rhs}) == {greater_equal}, \"{lhs} >= {rhs}\")\n"
+        ));
+    };
+    for (offset, group) in [(0, &numbers[..]), (numbers.len(), &strings[..])] {
+        for (i, lhs) in group.iter().enumerate() {
+            for (j, rhs) in group.iter().enumerate() {
+                check(
+                    format!("v[{}]", offset + i + 1),
+                    format!("v[{}]", offset + j + 1),
+                    lhs.reference(rhs),
+                );
+            }
+        }
+    }
+    for (i, lhs) in numbers.iter().enumerate() {
+        for immediate in immediates {
+            check(
+                format!("v[{}]", i + 1),
+                format!("{immediate}"),
+                lhs.reference(&Operand::Integer(immediate)),
+            );
+            check(
+                format!("{immediate}"),
+                format!("v[{}]", i + 1),
+                Operand::Integer(immediate).reference(lhs),
+            );
+        }
+    }
+
+    let program = Program::parse(&source).unwrap();
+    Lua::run_program(program).unwrap();
+
+    // Numbers and strings don't mix, and other types have no order
+    // without metamethods
+    let failures = [
+        ("1 < \"2\"", "integer", "string"),
+        ("\"1\" <= 2", "string", "integer"),
+        ("nil < 1", "nil", "integer"),
+        ("{} <= {}", "table", "table"),
+        ("true < false", "boolean", "boolean"),
+        ("(0.0 / 0.0) < \"a\"", "float", "string"),
+        ("print < 0", "closure", "integer"),
+        // `a > b` is evaluated as `b < a`
+        ("\"a\" > 1", "integer", "string"),
+    ];
+    for (comparison, lhs, rhs) in failures {
+        let program = Program::parse(&format!("local r = {comparison}\n")).unwrap();
+        match Lua::run_program(program).map_err(Error::unlocated) {
+            Err(Error::RelationalOperand(found_lhs, found_rhs)) => {
+                assert_eq!((found_lhs, found_rhs), (lhs, rhs), "{comparison}")
+            }
+            other => panic!("`{comparison}` should fail, but returned {other:?}."),
+        }
+    }
+}
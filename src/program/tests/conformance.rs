@@ -0,0 +1,341 @@
+//! Arithmetic as defined by the Lua 5.4 manual, §3.4.1, numerals, §3.1, and
+//! the precedence of operators, §3.4.8
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{Error, Lua, Program, environment::Environment, value::Value};
+
+fn run(source: &str) -> Vec<Value> {
+    let program = Program::parse(source).unwrap();
+    Lua::new(Environment::default()).execute(program).unwrap()
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn division() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let results = run(r#"
+local seven, two, minus_seven, zero = 7, 2, -7, 0
+return seven / two, 4 / two, seven / zero, minus_seven / zero, zero / zero
+"#);
+    assert_eq!(
+        results[..4],
+        [
+            Value::Float(3.5),
+            Value::Float(2.0),
+            Value::Float(f64::INFINITY),
+            Value::Float(f64::NEG_INFINITY),
+        ]
+    );
+    assert!(matches!(results[4], Value::Float(nan) if nan.is_nan()));
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn floor_division() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let results = run(r#"
+local seven, two, minus_seven, minus_two, zero = 7, 2, -7, -2, 0
+return seven // two, minus_seven // two, seven // minus_two, minus_seven // minus_two,
+    7.5 // two, minus_seven // 2.0, seven // 0.0, minus_seven // 0.0,
+    7 // -2, -7.5 // 2
+"#);
+    assert_eq!(
+        results,
+        [
+            Value::Integer(3),
+            Value::Integer(-4),
+            Value::Integer(-4),
+            Value::Integer(3),
+            Value::Float(3.0),
+            Value::Float(-4.0),
+            Value::Float(f64::INFINITY),
+            Value::Float(f64::NEG_INFINITY),
+            Value::Integer(-4),
+            Value::Float(-4.0),
+        ]
+    );
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn modulo() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let results = run(r#"
+local seven, three, minus_seven, minus_three, two, zero = 7, 3, -7, -3, 2, 0
+return seven % three, minus_seven % three, seven % minus_three, minus_seven % minus_three,
+    5.5 % two, -5.5 % two, 5.5 % -2, -5.5 % -2.0,
+    -7 % 3, seven % 0.0
+"#);
+    assert_eq!(
+        results[..9],
+        [
+            Value::Integer(1),
+            Value::Integer(2),
+            Value::Integer(-2),
+            Value::Integer(-1),
+            Value::Float(1.5),
+            Value::Float(0.5),
+            Value::Float(-0.5),
+            Value::Float(-1.5),
+            Value::Integer(2),
+        ]
+    );
+    assert!(matches!(results[9], Value::Float(nan) if nan.is_nan()));
+}
+
+#[test]
+fn overflow_wraps() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let results = run(r#"
+local max = 9223372036854775807
+local min = -9223372036854775807 - 1
+local one, two, minus_one = 1, 2, -1
+return max + 1, max + one, min - 1, min - one, max * 2, max * two, -min,
+    min // -1, min // minus_one, min % -1, min % minus_one
+"#);
+    assert_eq!(
+        results,
+        [
+            Value::Integer(i64::MIN),
+            Value::Integer(i64::MIN),
+            Value::Integer(i64::MAX),
+            Value::Integer(i64::MAX),
+            Value::Integer(-2),
+            Value::Integer(-2),
+            Value::Integer(i64::MIN),
+            Value::Integer(i64::MIN),
+            Value::Integer(i64::MIN),
+            Value::Integer(0),
+            Value::Integer(0),
+        ]
+    );
+}
+
+#[test]
+fn integer_division_by_zero() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    for (source, operator) in [
+        ("local zero = 0\nlocal a = 1 // zero\n", "//"),
+        ("local zero = 0\nlocal a = 1 % zero\n", "%"),
+        ("local one = 1\nlocal a = one // 0\n", "//"),
+        ("local one = 1\nlocal a = one % 0\n", "%"),
+    ] {
+        let program = Program::parse(source).unwrap();
+        match Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::IntegerDivisionByZero(op)) if op == operator => (),
+            Err(err) => panic!(
+                "Should fail with IntegerDivisionByZero, but failed with `{}`.",
+                err
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn numerals() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Numeral, and what the reference implementation writes for it and for
+    // `tonumber` of it, which reads the sign as part of the numeral
+    let long_hexadecimal = format!("0x{}p-100", "1".repeat(40));
+    let overflowing_hexadecimal = format!("0x{}p-1100", "1".repeat(300));
+    let leading_zeros = format!("0x0.{}1p80", "0".repeat(20));
+    for (numeral, literal, converted) in [
+        ("0x1p-3", "0.125", "0.125"),
+        ("0x.8", "0.5", "0.5"),
+        ("0xA.8p1", "21.0", "21.0"),
+        ("0x10P-1", "8.0", "8.0"),
+        ("0x0.1E", "0.1171875", "0.1171875"),
+        ("0x1P-1074", "4.9406564584125e-324", "4.9406564584125e-324"),
+        ("0x1p1024", "inf", "inf"),
+        (
+            &long_hexadecimal,
+            "7.6861433640456e+16",
+            "7.6861433640456e+16",
+        ),
+        (
+            &overflowing_hexadecimal,
+            "8.4510040015215e+28",
+            "8.4510040015215e+28",
+        ),
+        (&leading_zeros, "0.0625", "0.0625"),
+        ("0xFFFFFFFFFFFFFFFF", "-1", "-1"),
+        ("0x10000000000000000", "0", "0"),
+        (
+            "-0x8000000000000000",
+            "-9223372036854775808",
+            "-9223372036854775808",
+        ),
+        (
+            "9223372036854775807",
+            "9223372036854775807",
+            "9223372036854775807",
+        ),
+        (
+            "9223372036854775808",
+            "9.2233720368548e+18",
+            "9.2233720368548e+18",
+        ),
+        (
+            "-9223372036854775808",
+            "-9.2233720368548e+18",
+            "-9223372036854775808",
+        ),
+        (
+            "18446744073709551615",
+            "1.844674407371e+19",
+            "1.844674407371e+19",
+        ),
+        ("00012", "12", "12"),
+        ("3.", "3.0", "3.0"),
+        (".5", "0.5", "0.5"),
+        ("1E+2", "100.0", "100.0"),
+        ("2.5e-3", "0.0025", "0.0025"),
+        ("0e0", "0.0", "0.0"),
+        ("1e308", "1e+308", "1e+308"),
+        ("1e309", "inf", "inf"),
+        ("1e-400", "0.0", "0.0"),
+    ] {
+        let results = run(&format!(
+            "return tostring({numeral}), tostring(tonumber(\"{numeral}\"))\n"
+        ));
+        assert_eq!(
+            results.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [literal, converted],
+            "{numeral}"
+        );
+    }
+}
+
+/// Binary operators with their left and right priorities, as on `lparser.c`,
+/// a higher right priority than left makes the operator left associative
+const BINARY_OPERATORS: &[(&str, u8, u8)] = &[
+    ("or", 1, 1),
+    ("and", 2, 2),
+    ("<", 3, 3),
+    (">", 3, 3),
+    ("<=", 3, 3),
+    (">=", 3, 3),
+    ("~=", 3, 3),
+    ("==", 3, 3),
+    ("|", 4, 4),
+    ("~", 5, 5),
+    ("&", 6, 6),
+    ("<<", 7, 7),
+    (">>", 7, 7),
+    ("..", 9, 8),
+    ("+", 10, 10),
+    ("-", 10, 10),
+    ("*", 11, 11),
+    ("/", 11, 11),
+    ("//", 11, 11),
+    ("%", 11, 11),
+    ("^", 14, 13),
+];
+
+const UNARY_OPERATORS: &[&str] = &["-", "not", "~"];
+
+/// Priority of the operand of unary operators
+const UNARY_PRIORITY: u8 = 12;
+
+/// Writes the expression of `tokens` with every operation in parentheses,
+/// following `subexpr` of `lparser.c`
+fn parenthesize(tokens: &[&str], position: &mut usize, limit: u8) -> String {
+    let token = tokens[*position];
+    *position += 1;
+    let mut expression = if UNARY_OPERATORS.contains(&token) {
+        let operand = parenthesize(tokens, position, UNARY_PRIORITY);
+        format!("({token} {operand})")
+    } else {
+        token.to_string()
+    };
+    while let Some((operator, left, right)) = tokens
+        .get(*position)
+        .and_then(|token| BINARY_OPERATORS.iter().find(|(op, _, _)| op == token))
+    {
+        if *left <= limit {
+            break;
+        }
+        *position += 1;
+        let rhs = parenthesize(tokens, position, *right);
+        expression = format!("({expression} {operator} {rhs})");
+    }
+    expression
+}
+
+#[test]
+fn precedence() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Evaluates `expression` with its operands as constants, which are
+    // folded, and as locals, which are not
+    let evaluate = |expression: &str| {
+        [
+            format!("return {expression}\n"),
+            format!(
+                "local a, b, c = 2, 3, 5\nreturn {}\n",
+                expression
+                    .replace('2', "a")
+                    .replace('3', "b")
+                    .replace('5', "c")
+            ),
+        ]
+        .map(|source| {
+            let program = Program::parse(&source).unwrap();
+            Lua::new(Environment::default())
+                .execute(program)
+                .map_err(|_| ())
+        })
+    };
+
+    // Every pair of binary operators, with the operands negated
+    // or not, so unary operators are also mixed with each of them
+    let unary_prefixes = ["", "-", "not", "~"];
+    for (first, _, _) in BINARY_OPERATORS {
+        for (second, _, _) in BINARY_OPERATORS {
+            for (prefix, operand) in unary_prefixes
+                .iter()
+                .flat_map(|prefix| [(prefix, 0), (prefix, 1), (prefix, 2)])
+                .filter(|(prefix, operand)| !prefix.is_empty() || *operand == 0)
+            {
+                let mut tokens = Vec::new();
+                for (i, value) in ["2", "3", "5"].into_iter().enumerate() {
+                    if i == 1 {
+                        tokens.push(*first);
+                    } else if i == 2 {
+                        tokens.push(*second);
+                    }
+                    if i == operand && !prefix.is_empty() {
+                        tokens.push(*prefix);
+                    }
+                    tokens.push(value);
+                }
+
+                let expression = tokens.join(" ");
+                let parenthesized = parenthesize(&tokens, &mut 0, 0);
+                let expected = evaluate(&parenthesized);
+                assert_eq!(
+                    expected[0], expected[1],
+                    "`{parenthesized}` folded differs from evaluated"
+                );
+                assert_eq!(
+                    evaluate(&expression),
+                    expected,
+                    "`{expression}` should be `{parenthesized}`"
+                );
+            }
+        }
+    }
+}
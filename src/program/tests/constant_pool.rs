@@ -0,0 +1,113 @@
+#[cfg(feature = "package")]
+use alloc::format;
+use alloc::rc::Rc;
+
+use crate::{Lua, Program, program::ConstantPool, value::Value};
+#[cfg(feature = "package")]
+use crate::{environment::Environment, value::ValueKey};
+
+const LONG_STRING: &str = "a string that is too long to be stored inline";
+
+#[test]
+fn shared_constants() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let source = r#"
+local a = "a string that is too long to be stored inline"
+local function f()
+    local b = "a string that is too long to be stored inline"
+    local c = "short"
+    return b, c
+end
+print(a, f())
+"#;
+
+    let mut pool = ConstantPool::new();
+    let first = Program::parse_with_pool(source, &mut pool).unwrap();
+    let second = Program::parse_with_pool(source, &mut pool).unwrap();
+
+    assert_eq!(pool.len(), 1);
+    assert_eq!(pool.size(), LONG_STRING.len());
+    assert!(first.diff(&second).is_empty());
+
+    let Value::String(main_string) = &first.constants[0] else {
+        panic!("Expected a long string constant.");
+    };
+    let Value::String(other_string) = &second.constants[0] else {
+        panic!("Expected a long string constant.");
+    };
+    let Value::String(nested_string) = &second.functions[0].program().constants[0] else {
+        panic!("Expected a long string constant.");
+    };
+    assert!(Rc::ptr_eq(main_string, other_string));
+    assert!(Rc::ptr_eq(main_string, nested_string));
+
+    Lua::run_program(first).unwrap();
+    Lua::run_program(second).unwrap();
+
+    pool.shrink();
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn counted_once() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let source =
+        alloc::format!("copies = {{}}\nfor i = 1, 100 do copies[i] = \"{LONG_STRING}\" end\n");
+
+    let mut pooled = Lua::default();
+    let program = Program::parse_with_pool(&source, pooled.constant_pool_mut()).unwrap();
+    pooled.execute(program).unwrap();
+    let mut unpooled = Lua::default();
+    unpooled.execute(Program::parse(&source).unwrap()).unwrap();
+
+    // Every copy references the same string, but only the pooled one is
+    // known to be shared
+    assert_eq!(
+        unpooled.memory_in_use() - pooled.memory_in_use(),
+        99 * LONG_STRING.len()
+    );
+}
+
+#[cfg(feature = "package")]
+#[test]
+fn shared_by_the_vm() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let env = Environment::builder()
+        .module_source(|_: &str| Some(format!("return \"{LONG_STRING}\"")))
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+    lua.eval(&format!("evaluated = \"{LONG_STRING}\"")).unwrap();
+    lua.eval(&format!("again = \"{LONG_STRING}\"")).unwrap();
+    lua.globals()
+        .borrow_mut()
+        .set(
+            ValueKey("chunk".into()),
+            format!("return \"{LONG_STRING}\"").into(),
+        )
+        .unwrap();
+    lua.eval("loaded = load(chunk)()\nrequired = require(\"module\")")
+        .unwrap();
+    assert_eq!(lua.constant_pool().len(), 1);
+
+    let global = |name: &str| match lua.globals().borrow().get(ValueKey(name.into())) {
+        Value::String(string) => string.clone(),
+        other => panic!("Expected a long string, found {other:?}."),
+    };
+    let evaluated = global("evaluated");
+    for name in ["again", "loaded", "required"] {
+        assert!(Rc::ptr_eq(&evaluated, &global(name)));
+    }
+    drop(evaluated);
+
+    // Strings still used by the globals are kept
+    lua.collect_garbage();
+    assert_eq!(lua.constant_pool().len(), 1);
+    lua.eval("evaluated, again, loaded, required = nil\npackage.loaded.module = nil")
+        .unwrap();
+    lua.collect_garbage();
+    assert!(lua.constant_pool().is_empty());
+}
@@ -0,0 +1,116 @@
+use alloc::{string::ToString, vec};
+
+use crate::{
+    Program,
+    bytecode::Bytecode,
+    program::{Difference, Local},
+    value::Value,
+};
+
+#[test]
+fn equal_programs() {
+    let source = r#"
+local a = "hello"
+local function f(x, ...)
+    return x, a
+end
+print(f(1))
+"#;
+    let lhs = Program::parse(source).unwrap();
+    let rhs = Program::parse(source).unwrap();
+
+    let diff = lhs.diff(&rhs);
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "Programs are equal.");
+}
+
+#[test]
+fn top_level_differences() {
+    let lhs = Program::parse(
+        r#"
+local a = "hello"
+local b = 1
+"#,
+    )
+    .unwrap();
+    let rhs = Program::parse(
+        r#"
+local a = "world"
+local c = 2
+"#,
+    )
+    .unwrap();
+
+    let diff = lhs.diff(&rhs);
+    assert_eq!(
+        diff.differences(),
+        &[
+            Difference::ByteCode {
+                path: vec![],
+                index: 2,
+                lhs: Some(Bytecode::load_integer(1, 1i16)),
+                rhs: Some(Bytecode::load_integer(1, 2i16)),
+            },
+            Difference::Constant {
+                path: vec![],
+                index: 0,
+                lhs: Some(Value::from("hello")),
+                rhs: Some(Value::from("world")),
+            },
+            Difference::Local {
+                path: vec![],
+                index: 1,
+                lhs: Some(Local::new("b".into(), 4, 5)),
+                rhs: Some(Local::new("c".into(), 4, 5)),
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "main bytecode #2: LoadInteger(1, 1) != LoadInteger(1, 2)
+main constant #0: ShortString(hello) != ShortString(world)
+main local #1: Local { name: \"b\", scope_start: 4, scope_end: 5 } != Local { name: \"c\", scope_start: 4, scope_end: 5 }"
+    );
+}
+
+#[test]
+fn nested_differences() {
+    let lhs = Program::parse(
+        r#"
+local function f(x)
+    return x
+end
+"#,
+    )
+    .unwrap();
+    let rhs = Program::parse(
+        r#"
+local function f(x, ...)
+    return x
+end
+local function g()
+end
+"#,
+    )
+    .unwrap();
+
+    let diff = lhs.diff(&rhs);
+    let differences = diff.differences();
+    assert!(matches!(
+        &differences[0],
+        Difference::ByteCode { path, lhs: Some(_), rhs: Some(_), .. } if path.is_empty()
+    ));
+    assert!(differences.contains(&Difference::FunctionCount {
+        path: vec![],
+        lhs: 1,
+        rhs: 2,
+    }));
+    assert!(differences.contains(&Difference::Signature {
+        path: vec![0],
+        lhs: (1, false),
+        rhs: (1, true),
+    }));
+    assert!(diff.to_string().contains(
+        "main.functions[0] signature: (args: 1, variadic: false) != (args: 1, variadic: true)"
+    ));
+}
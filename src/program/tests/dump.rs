@@ -0,0 +1,64 @@
+use alloc::{string::ToString, vec::Vec};
+
+use crate::Program;
+
+#[test]
+fn dump() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"local a = "hello"
+local function f(x)
+    return x + 1
+end
+while a do
+    print(a)
+    a = nil
+end
+g = f(2.5)
+"#,
+    )
+    .unwrap();
+    let dump = program.to_string();
+    let lines = dump.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "main <main> (15 bytecodes, 0 params, variadic)");
+    assert_eq!(lines[1], "\t1\t[-]\tVARARGPREP\t0");
+    assert_eq!(lines[2], "\t2\t[1]\tLOADK    \t0 0\t; \"hello\"");
+    assert_eq!(lines[4], "\t4\t[5]\tTEST     \t0 false");
+    assert_eq!(lines[5], "\t5\t[5]\tJMP      \t5\t; to 11");
+    assert_eq!(lines[6], "\t6\t[6]\tGETTABUP \t2 0 1\t; _ENV \"print\"");
+    assert_eq!(lines[10], "\t10\t[7]\tJMP      \t-7\t; to 4");
+    assert_eq!(lines[12], "\t12\t[9]\tLOADK    \t3 3\t; 2.5");
+    assert_eq!(lines[14], "\t14\t[9]\tSETTABUP \t0 2 2\t; _ENV \"g\"");
+    assert_eq!(
+        &lines[16..27],
+        [
+            "constants (4):",
+            "\t0\t\"hello\"",
+            "\t1\t\"print\"",
+            "\t2\t\"g\"",
+            "\t3\t2.5",
+            "locals (2):",
+            "\t0\ta\t3\t16",
+            "\t1\tf\t4\t16",
+            "upvalues (1):",
+            "\t0\t_ENV\t1\t0",
+            "",
+        ]
+    );
+    assert_eq!(
+        &lines[27..],
+        [
+            "function <main.functions[0]> (4 bytecodes, 1 params)",
+            "\t1\t[3]\tADDI     \t1 0 1",
+            "\t2\t[3]\tMMBINI   \t0 1 6",
+            "\t3\t[3]\tRETURN1  \t1",
+            "\t4\t[3]\tRETURN0",
+            "constants (0):",
+            "locals (1):",
+            "\t0\tx\t1\t5",
+            "upvalues (0):",
+        ]
+    );
+}
@@ -0,0 +1,1182 @@
+use core::cell::RefCell;
+
+#[cfg(feature = "float")]
+use alloc::string::String;
+use alloc::{rc::Rc, string::ToString, vec, vec::Vec};
+
+use crate::{
+    Error, ErrorCategory, Lua,
+    bytecode::Bytecode,
+    closure::{NativeClosure, NativeClosureReturn},
+    environment::Environment,
+    table::Table,
+    value::{Value, ValueKey},
+};
+#[cfg(feature = "float")]
+use crate::{closure::Closure, std};
+
+#[test]
+fn call_value_from_native() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local forty_two = 42
+local doubled = apply(function(value) return value * 2 end, 21)
+assert(doubled == forty_two)
+
+local count = 0
+local function increment(by)
+    count = count + by
+    return count, "incremented"
+end
+local a, b = apply(increment, 5)
+local c = apply(increment, 5)
+local five, ten = 5, 10
+assert(a == five)
+assert(b == "incremented")
+assert(c == ten)
+"#,
+    )
+    .unwrap();
+
+    /// Calls the first argument with the remaining arguments
+    fn apply(vm: &mut Lua) -> NativeClosureReturn {
+        let top_stack = vm.get_stack_frame()?;
+        let args = vm.stack[top_stack.stack_frame..].to_vec();
+        let Some((function, args)) = args.split_first() else {
+            return Err(Error::BadArgument(1, "function expected"));
+        };
+
+        let results = vm.call_value(function.clone(), args)?;
+        let returns = results.len();
+        for (dst, value) in results.into_iter().enumerate() {
+            vm.set_stack(u8::try_from(dst)?, value)?;
+        }
+        Ok(returns)
+    }
+
+    let mut env = Environment::default();
+    env.push("apply", apply as NativeClosure).unwrap();
+
+    crate::Lua::run_program_with_env(program, env).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn call_value_from_host() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+
+    let results = lua
+        .call_value(
+            Value::from(std::lib_type as NativeClosure),
+            &[Value::Float(1.5)],
+        )
+        .unwrap();
+    assert_eq!(results, vec![Value::from("float")]);
+
+    let program = crate::Program::parse(
+        r#"
+local a, b = ...
+return b, a
+"#,
+    )
+    .unwrap();
+    let function = Value::Closure(Rc::new(Closure::new_lua(
+        Rc::new(crate::function::Function::new(program, 0, true)),
+        Vec::new(),
+    )));
+    let results = lua
+        .call_value(function, &[Value::Integer(1), Value::Integer(2)])
+        .unwrap();
+    assert_eq!(results, vec![Value::Integer(2), Value::Integer(1)]);
+
+    // Failed calls leave the vm ready for other calls
+    let assert = Value::from(std::lib_assert as NativeClosure);
+    assert!(matches!(
+        lua.call_value(assert.clone(), &[Value::Boolean(false)]),
+        Err(Error::Assertion(_))
+    ));
+    assert!(lua.stack.is_empty());
+    assert!(lua.stack_frame.is_empty());
+    let results = lua.call_value(assert, &[Value::Boolean(true)]).unwrap();
+    assert_eq!(results, vec![Value::Boolean(true)]);
+}
+
+#[test]
+#[cfg(all(feature = "math", feature = "table"))]
+fn environment_builder() {
+    use crate::environment::{EnvironmentBuilder, Library};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    fn double(vm: &mut Lua) -> NativeClosureReturn {
+        let top_stack = vm.get_stack_frame()?;
+        let Some(Value::Integer(value)) = vm.stack.get(top_stack.stack_frame).cloned() else {
+            return Err(Error::BadArgument(1, "integer expected"));
+        };
+        vm.set_stack(0, Value::Integer(value * 2))?;
+        Ok(1)
+    }
+
+    let program = crate::Program::parse(
+        r#"
+local a = answer
+local b = double(21)
+assert(a == b)
+local m = type(math)
+assert(m == "nil")
+local t = type(table)
+assert(t == "table")
+local p = type(print)
+assert(p == "string")
+"#,
+    )
+    .unwrap();
+
+    let env = Environment::builder()
+        .library(Library::Math, false)
+        .global("answer", 42i64)
+        .global("print", "replaced")
+        .function("double", double)
+        .build()
+        .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+
+    let env = EnvironmentBuilder::bare()
+        .function("double", double)
+        .build()
+        .unwrap();
+    assert_eq!(env.borrow().table.len(), 1);
+    assert!(
+        env.borrow()
+            .table
+            .iter()
+            .all(|(key, _)| key.0 == "double".into())
+    );
+    let program = crate::Program::parse("local m = math\nassert(m)\n").unwrap();
+    assert!(crate::Lua::run_program_with_env(program, env).is_err());
+}
+
+#[test]
+#[cfg(feature = "math")]
+fn sandbox() {
+    use crate::environment::SAFE_GLOBALS;
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let env = Environment::builder()
+        .function("secret", std::lib_print)
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+
+    let sandbox = lua.sandbox(SAFE_GLOBALS);
+    let program = crate::Program::parse(
+        r#"
+local floor = math.floor
+math.floor = nil
+_G.x = 1
+local visible = type(x)
+return type(secret), type(load), type(io), type(floor), visible
+"#,
+    )
+    .unwrap();
+    let results = lua.execute_with_env(program, sandbox.clone()).unwrap();
+    assert_eq!(
+        results,
+        vec![
+            "nil".into(),
+            "nil".into(),
+            "nil".into(),
+            "closure".into(),
+            "integer".into()
+        ]
+    );
+
+    // The VM's globals and libraries are left untouched
+    let program = crate::Program::parse("return type(x), type(math.floor)\n").unwrap();
+    assert_eq!(
+        lua.execute(program).unwrap(),
+        vec!["nil".into(), "closure".into()]
+    );
+
+    // Globals set by a sandboxed chunk stay on its environment
+    let program = crate::Program::parse("return x\n").unwrap();
+    assert_eq!(
+        lua.execute_with_env(program, sandbox).unwrap(),
+        vec![Value::Integer(1)]
+    );
+
+    let sandbox = lua.sandbox(&["print"]);
+    assert_eq!(sandbox.borrow().table.len(), 1);
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn typed_functions() {
+    use crate::{FromLuaMulti, IntoLuaMulti, native_function};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    fn rep(string: String, times: i64) -> Result<String, Error> {
+        let times = usize::try_from(times).map_err(|_| Error::BadArgument(2, "negative count"))?;
+        Ok(string.repeat(times))
+    }
+
+    fn stats(values: Vec<f64>) -> (f64, Option<f64>) {
+        let sum = values.iter().sum();
+        (sum, values.iter().copied().reduce(f64::max))
+    }
+
+    let env = Environment::builder()
+        .function("rep", native_function!(rep))
+        .function("stats", native_function!(stats))
+        .function("swap", native_function!(|a: Value, b: Value| (b, a)))
+        .function("nothing", native_function!(|| ()))
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+
+    let program = crate::Program::parse(
+        r#"
+local sum, max = stats({1, 2.5, "3"})
+local a, b = swap(1, "x")
+return rep("ab", "3"), sum, max, a, b, nothing()
+"#,
+    )
+    .unwrap();
+    let results: (String, f64, f64, String, i64, Option<i64>) = lua.call_program(program).unwrap();
+    assert_eq!(results, ("ababab".into(), 6.5, 3.0, "x".into(), 1, None));
+
+    // Arguments that can't be converted fail with their position
+    for (source, position) in [
+        ("rep(\"a\")\n", 2),
+        ("rep({}, 1)\n", 1),
+        ("rep(\"a\", 1.5)\n", 2),
+        ("stats({1, {}})\n", 1),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match lua.execute(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::Expected(arg, _, _)) if arg == position => (),
+            Err(err) => panic!("Should fail with Expected, but failed with `{}`.", err),
+        }
+    }
+    let program = crate::Program::parse("rep(\"a\", -1)\n").unwrap();
+    assert!(matches!(
+        lua.execute(program).map_err(Error::unlocated),
+        Err(Error::BadArgument(2, _))
+    ));
+
+    // The host converts the arguments and results of calls
+    let program = crate::Program::parse("return function(a, b) return a .. b, #a end\n").unwrap();
+    let function: Value = lua.call_program(program).unwrap();
+    let (joined, length): (String, usize) = lua.call(function.clone(), ("ab", 12)).unwrap();
+    assert_eq!((joined.as_str(), length), ("ab12", 2));
+    assert!(matches!(
+        lua.call::<(bool, Vec<i64>)>(function, ("a", "b")),
+        Err(Error::Expected(2, "table", "integer"))
+    ));
+
+    assert_eq!(
+        (1u8, -2i32, 0.5f32, true, "s").into_lua_multi().unwrap(),
+        vec![
+            Value::Integer(1),
+            Value::Integer(-2),
+            Value::Float(0.5),
+            Value::Boolean(true),
+            "s".into()
+        ]
+    );
+    assert_eq!(
+        Vec::<Option<u8>>::from_lua_multi(vec![
+            vec![Some(1u8), None].into_lua_multi().unwrap()[0].clone()
+        ])
+        .unwrap(),
+        vec![Some(1)]
+    );
+    assert!(u8::from_lua_multi(vec![Value::Integer(256)]).is_err());
+}
+
+#[test]
+fn native_context() {
+    use crate::{NativeCtx, native_function};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    /// Calls the first argument with the rest, returning the argument count
+    /// and the results of the call
+    fn apply(ctx: &mut NativeCtx) -> Result<(), Error> {
+        let function = ctx.arg(1).clone();
+        if !matches!(function, Value::Closure(_)) {
+            return Err(ctx.type_error(1, "function"));
+        }
+        let args = ctx.args()[1..].to_vec();
+        ctx.push_return(ctx.arg_count());
+        for result in ctx.vm().call_value(function, &args)? {
+            ctx.push_return(result);
+        }
+        Ok(())
+    }
+
+    fn between(ctx: &mut NativeCtx) -> Result<(), Error> {
+        let value = ctx.check::<i64>(1)?;
+        let low = ctx.check::<Option<i64>>(2)?.unwrap_or(0);
+        if value < low {
+            return Err(ctx.arg_error(1, "below the lower bound"));
+        }
+        ctx.push_return(*ctx.arg(3) == Value::Nil);
+        Ok(())
+    }
+
+    let env = Environment::builder()
+        .function("apply", native_function!(apply))
+        .function("between", native_function!(between))
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+
+    let program = crate::Program::parse(
+        r#"
+local function pair(a, b)
+    return b, a
+end
+return between(2), between(3, 1), apply(pair, 1, "x")
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        lua.execute(program).unwrap(),
+        vec![
+            Value::Boolean(true),
+            Value::Boolean(true),
+            Value::Integer(3),
+            "x".into(),
+            Value::Integer(1)
+        ]
+    );
+
+    for (source, expected) in [
+        ("apply(1)\n", "Expected(1, \"function\", \"integer\")"),
+        ("between()\n", "Expected(1, \"integer\", \"no value\")"),
+        ("between(1, {})\n", "Expected(2, \"integer\", \"table\")"),
+        (
+            "between(1, 2)\n",
+            "BadArgument(1, \"below the lower bound\")",
+        ),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match lua.execute(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(err) => assert_eq!(alloc::format!("{err:?}"), expected),
+        }
+    }
+}
+
+#[test]
+fn native_returns() {
+    use crate::{HookEvent, HookMask, NativeCtx, native_function};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    /// Integers from 1 to the argument
+    fn count(vm: &mut Lua) -> NativeClosureReturn {
+        let Some(Value::Integer(last)) = vm.register(0).cloned() else {
+            return Err(Error::BadArgument(1, "integer expected"));
+        };
+        vm.set_returns((1..=last).map(Value::Integer))
+    }
+
+    fn nothing(_: &mut NativeCtx) -> Result<(), Error> {
+        Ok(())
+    }
+
+    let env = Environment::builder()
+        .function("count", count)
+        .function("nothing", native_function!(nothing))
+        .function("unit", native_function!(|| ()))
+        .function("many", native_function!(|| (1i64, 2i64, 3i64, 4i64)))
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+
+    let program = crate::Program::parse(
+        r#"
+assert(select('#', count(0)) == 0)
+assert(select('#', count(1, 2, 3)) == 1)
+assert(select('#', count(300)) == 300)
+assert(select('#', nothing(1, 2)) == 0)
+assert(select('#', unit()) == 0)
+assert(select('#', many()) == 4)
+
+local a, b, c = count(5)
+assert(a == 1 and b == 2 and c == 3)
+local x, y, z = count(1)
+assert(x == 1 and y == nil and z == nil)
+local n = nothing()
+assert(n == nil)
+
+local t = {count(300)}
+assert(#t == 300 and t[300] == 300)
+t = {count(2), many()}
+assert(#t == 5 and t[1] == 1 and t[2] == 1 and t[5] == 4)
+local function last(...)
+    return select(select('#', ...), ...)
+end
+assert(last(count(257)) == 257)
+count(3)
+return count(3)
+"#,
+    )
+    .unwrap();
+    assert_eq!(lua.execute(program).unwrap(), [1, 2, 3].map(Value::Integer));
+
+    // Results past the expected ones don't stay on the stack
+    let registers = Rc::new(RefCell::new(Vec::new()));
+    let recorded = registers.clone();
+    lua.set_hook(
+        HookMask {
+            line: true,
+            ..Default::default()
+        },
+        move |vm, event| {
+            if event == HookEvent::Line(3) {
+                recorded
+                    .borrow_mut()
+                    .extend((0..4).map(|register| vm.register(register).cloned()));
+            }
+            Ok(())
+        },
+    );
+    let program = crate::Program::parse("local a, b = count(4)\nreturn a, b\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), [1, 2].map(Value::Integer));
+    let program =
+        crate::Program::parse("local a, b = count(4)\nlocal c = many()\nreturn a, b, c\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), [1, 2, 1].map(Value::Integer));
+    assert_eq!(
+        *registers.borrow(),
+        [
+            Some(Value::Integer(1)),
+            Some(Value::Integer(2)),
+            Some(Value::Integer(1)),
+            None
+        ]
+    );
+}
+
+#[test]
+fn table_observer() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+ui.title = "hello"
+local index = 1
+ui[index] = 10
+local key = "width"
+ui[key] = 20
+local none
+ui.title = none
+unobserved.title = "ignored"
+answer = 42
+"#,
+    )
+    .unwrap();
+
+    let changes = Rc::new(RefCell::new(Vec::new()));
+
+    let ui = Rc::new(RefCell::new(Table::new(0, 0)));
+    let weak_ui = Rc::downgrade(&ui);
+    let ui_changes = changes.clone();
+    ui.borrow_mut().set_observer(move |key, value| {
+        // The table can be read from inside the observer
+        let ui = weak_ui.upgrade().unwrap();
+        assert!(ui.borrow().array.len() + ui.borrow().table.len() > 0);
+        ui_changes.borrow_mut().push((key.clone(), value.clone()));
+    });
+
+    let env = Environment::builder()
+        .global("ui", Value::Table(ui.clone()))
+        .global(
+            "unobserved",
+            Value::Table(Rc::new(RefCell::new(Table::new(0, 0)))),
+        )
+        .build()
+        .unwrap();
+    let global_changes = changes.clone();
+    env.borrow_mut().set_observer(move |key, value| {
+        global_changes
+            .borrow_mut()
+            .push((key.clone(), value.clone()));
+    });
+
+    crate::Lua::run_program_with_env(program, env).unwrap();
+
+    assert_eq!(
+        changes.borrow().as_slice(),
+        &[
+            (Value::from("title"), Value::from("hello")),
+            (Value::Integer(1), Value::Integer(10)),
+            (Value::from("width"), Value::Integer(20)),
+            (Value::from("title"), Value::Nil),
+            (Value::from("answer"), Value::Integer(42)),
+        ]
+    );
+}
+
+#[test]
+fn dump_state() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    fn check_state(vm: &mut Lua) -> NativeClosureReturn {
+        let state = vm.dump_state();
+        log::info!("{state}");
+        for expected in [
+            "#0 lua function",
+            "#1 lua function",
+            "#2 native function",
+            "Registers of #1:",
+            "[0] argument = Integer(21)",
+            "[1] doubled = Integer(42)",
+            "[2] captured = ShortString(hello)",
+            "#0 stack[1] = ShortString(hello)",
+        ] {
+            assert!(
+                state.contains(expected),
+                "`{expected}` missing from:\n{state}"
+            );
+        }
+        Ok(0)
+    }
+
+    let program = crate::Program::parse(
+        r#"
+local greeting = "hello"
+local function inner(argument)
+    local doubled = argument * 2
+    local captured = greeting
+    check_state(doubled)
+    return doubled
+end
+local result = inner(21)
+"#,
+    )
+    .unwrap();
+
+    let env = Environment::builder()
+        .function("check_state", check_state)
+        .build()
+        .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+
+    assert_eq!(Lua::default().dump_state(), "No running function.\n");
+}
+
+#[test]
+fn persistent_state() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::new(Environment::default());
+
+    let first = crate::Program::parse("counter = 1\n").unwrap();
+    assert_eq!(lua.execute(first).unwrap(), vec![]);
+
+    let increment = crate::Program::parse(
+        r#"
+local current = counter
+local one = 1
+local next = current + one
+counter = next
+"#,
+    )
+    .unwrap();
+    lua.execute(increment.clone()).unwrap();
+    lua.execute(increment.clone()).unwrap();
+
+    // Failed chunks leave the VM usable
+    let failing = crate::Program::parse("local missing = nothing\nmissing()\n").unwrap();
+    assert!(lua.execute(failing).is_err());
+    lua.execute(increment).unwrap();
+
+    let read = crate::Program::parse("return counter\n").unwrap();
+    assert_eq!(lua.execute(read).unwrap(), vec![Value::Integer(4)]);
+    assert_eq!(
+        lua.globals().borrow().get(ValueKey("counter".into())),
+        &Value::Integer(4)
+    );
+}
+
+#[test]
+fn missing_return() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+
+    let truncated = crate::Program {
+        byte_codes: vec![
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_integer(0, 1i16),
+        ]
+        .into(),
+        ..Default::default()
+    };
+    assert!(matches!(
+        lua.execute(truncated).map_err(Error::unlocated),
+        Err(Error::MissingReturn)
+    ));
+    assert_eq!(lua.dump_state(), "No running function.\n");
+
+    // The VM is still usable after the error
+    let program = crate::Program::parse("local one = 1\nreturn one\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), vec![Value::Integer(1)]);
+}
+
+#[test]
+fn max_call_depth() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    /// Calls the first argument with the remaining arguments
+    fn apply(vm: &mut Lua) -> NativeClosureReturn {
+        let top_stack = vm.get_stack_frame()?;
+        let args = vm.stack[top_stack.stack_frame..].to_vec();
+        let Some((function, args)) = args.split_first() else {
+            return Err(Error::BadArgument(1, "function expected"));
+        };
+
+        let results = vm.call_value(function.clone(), args)?;
+        let returns = results.len();
+        for (dst, value) in results.into_iter().enumerate() {
+            vm.set_stack(u8::try_from(dst)?, value)?;
+        }
+        Ok(returns)
+    }
+
+    let mut env = Environment::default();
+    env.push("apply", apply as NativeClosure).unwrap();
+    let mut lua = Lua::new(env);
+    assert_eq!(lua.max_call_depth(), crate::DEFAULT_MAX_CALL_DEPTH);
+    lua.set_max_call_depth(100);
+
+    let recursion = crate::Program::parse(
+        r#"
+function depth(n)
+    if n == 0 then
+        return 0
+    end
+    local below = depth(n - 1)
+    return below + 1
+end
+"#,
+    )
+    .unwrap();
+    lua.execute(recursion).unwrap();
+
+    let shallow = crate::Program::parse("return depth(90)\n").unwrap();
+    assert_eq!(lua.execute(shallow).unwrap(), vec![Value::Integer(90)]);
+    let deep = crate::Program::parse("return depth(100)\n").unwrap();
+    assert!(matches!(
+        lua.execute(deep).map_err(Error::unlocated),
+        Err(Error::StackOverflow)
+    ));
+
+    // Tail calls reuse the stack frame of the caller
+    let tail = crate::Program::parse(
+        r#"
+local function count(n)
+    if n == 0 then
+        return "done"
+    end
+    return count(n - 1)
+end
+return count(1000)
+"#,
+    )
+    .unwrap();
+    assert_eq!(lua.execute(tail).unwrap(), vec![Value::from("done")]);
+
+    // Calls made from native functions recurse on the host's stack,
+    // and are limited even if the call depth is not
+    lua.set_max_call_depth(usize::MAX);
+    let native = crate::Program::parse(
+        r#"
+local function recurse()
+    apply(recurse)
+end
+recurse()
+"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        lua.execute(native).map_err(Error::unlocated),
+        Err(Error::StackOverflow)
+    ));
+
+    // The VM is still usable after the errors
+    let shallow = crate::Program::parse("return depth(90)\n").unwrap();
+    assert_eq!(lua.execute(shallow).unwrap(), vec![Value::Integer(90)]);
+}
+
+#[test]
+fn userdata() {
+    use crate::userdata::UserData;
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    struct Counter {
+        count: i64,
+    }
+
+    struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    fn counter_arg(vm: &mut Lua) -> Result<Rc<UserData>, Error> {
+        let top_stack = vm.get_stack_frame()?;
+        match vm.stack.get(top_stack.stack_frame) {
+            Some(Value::UserData(userdata)) if userdata.is::<Counter>() => Ok(userdata.clone()),
+            Some(other) => Err(Error::Expected(1, "counter", other.static_type_name())),
+            None => Err(Error::BadArgument(1, "counter expected")),
+        }
+    }
+
+    fn new_counter(vm: &mut Lua) -> NativeClosureReturn {
+        let top_stack = vm.get_stack_frame()?;
+        let Some(Value::Integer(count)) = vm.stack.get(top_stack.stack_frame).cloned() else {
+            return Err(Error::BadArgument(1, "integer expected"));
+        };
+        let counter = UserData::new(Counter { count });
+        counter.set_method("increment", counter_increment as NativeClosure)?;
+        counter.set_method("get", counter_get as NativeClosure)?;
+        vm.set_stack(0, counter.into())?;
+        Ok(1)
+    }
+
+    fn counter_increment(vm: &mut Lua) -> NativeClosureReturn {
+        let counter = counter_arg(vm)?;
+        let top_stack = vm.get_stack_frame()?;
+        let Some(Value::Integer(by)) = vm.stack.get(top_stack.stack_frame + 1).cloned() else {
+            return Err(Error::BadArgument(2, "integer expected"));
+        };
+        let Some(mut counter) = counter.borrow_mut::<Counter>() else {
+            unreachable!("Argument was checked to be a counter.");
+        };
+        counter.count += by;
+        Ok(0)
+    }
+
+    fn counter_get(vm: &mut Lua) -> NativeClosureReturn {
+        let counter = counter_arg(vm)?;
+        let count = counter
+            .borrow::<Counter>()
+            .map_or(0, |counter| counter.count);
+        vm.set_stack(0, Value::Integer(count))?;
+        Ok(1)
+    }
+
+    /// `__index` of points, gives access to their coordinates
+    fn point_index(vm: &mut Lua) -> NativeClosureReturn {
+        let top_stack = vm.get_stack_frame()?;
+        let args = vm.stack[top_stack.stack_frame..].to_vec();
+        let (Some(Value::UserData(point)), Some(key)) = (args.first(), args.get(1)) else {
+            return Err(Error::BadArgument(1, "point expected"));
+        };
+        let Some(point) = point.borrow::<Point>() else {
+            return Err(Error::BadArgument(1, "point expected"));
+        };
+        let value = if *key == "x".into() {
+            Value::Integer(point.x)
+        } else if *key == "y".into() {
+            Value::Integer(point.y)
+        } else {
+            Value::Nil
+        };
+        drop(point);
+        vm.set_stack(0, value)?;
+        Ok(1)
+    }
+
+    let mut point_metatable = Table::new(0, 1);
+    point_metatable
+        .set(
+            ValueKey("__index".into()),
+            Value::from(point_index as NativeClosure),
+        )
+        .unwrap();
+    let point =
+        UserData::with_metatable(Point { x: 3, y: 4 }, Rc::new(RefCell::new(point_metatable)));
+
+    let env = Environment::builder()
+        .function("new_counter", new_counter)
+        .global("point", point)
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+    let results = lua
+        .execute(
+            crate::Program::parse(
+                r#"
+local c = new_counter(10)
+c:increment(5)
+c:increment(1)
+local count = c:get()
+local sixteen = 16
+assert(count == sixteen)
+local t = type(c)
+assert(t == "userdata")
+local other = new_counter(10)
+local same = c
+assert(c ~= other)
+assert(c == same)
+local x, y, z = point.x, point.y, point.z
+local three, four = 3, 4
+assert(x == three)
+assert(y == four)
+assert(z == nil)
+return c
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    let [Value::UserData(counter)] = results.as_slice() else {
+        panic!("Script should return the counter.");
+    };
+    assert!(counter.is::<Counter>());
+    assert!(counter.borrow::<Point>().is_none());
+    assert_eq!(counter.borrow::<Counter>().unwrap().count, 16);
+
+    let program = crate::Program::parse("local u = bare\nlocal f = u.field\n").unwrap();
+    let env = Environment::builder()
+        .global("bare", UserData::new(()))
+        .build()
+        .unwrap();
+    match Lua::run_program_with_env(program, env).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::ExpectedTable("userdata")) => (),
+        Err(err) => panic!("Should fail with ExpectedTable, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn fuel() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    /// Calls the first argument with the remaining arguments
+    fn apply(vm: &mut Lua) -> NativeClosureReturn {
+        let top_stack = vm.get_stack_frame()?;
+        let args = vm.stack[top_stack.stack_frame..].to_vec();
+        let Some((function, args)) = args.split_first() else {
+            return Err(Error::BadArgument(1, "function expected"));
+        };
+
+        let results = vm.call_value(function.clone(), args)?;
+        let returns = results.len();
+        for (dst, value) in results.into_iter().enumerate() {
+            vm.set_stack(u8::try_from(dst)?, value)?;
+        }
+        Ok(returns)
+    }
+
+    let mut env = Environment::default();
+    env.push("apply", apply as NativeClosure).unwrap();
+    let mut lua = Lua::new(env);
+    assert_eq!(lua.fuel(), None);
+    assert!(matches!(lua.resume(), Err(Error::NotSuspended)));
+
+    // `VARARGPREP`, `LOADI`, and `RETURN`
+    let one = crate::Program::parse("local one = 1\nreturn one\n").unwrap();
+    lua.set_fuel(Some(3));
+    assert_eq!(lua.execute(one.clone()).unwrap(), vec![Value::Integer(1)]);
+    assert_eq!(lua.fuel(), Some(0));
+    assert!(matches!(
+        lua.execute(one.clone()),
+        Err(Error::FuelExhausted)
+    ));
+    assert!(lua.is_suspended());
+
+    let sum = crate::Program::parse(
+        r#"
+local total = 0
+for i = 1, 1000 do
+    total = total + i
+end
+return total
+"#,
+    )
+    .unwrap();
+    // Running a new chunk abandons the suspended one
+    lua.refuel(100);
+    assert!(matches!(
+        lua.execute(sum).map_err(Error::unlocated),
+        Err(Error::FuelExhausted)
+    ));
+    assert!(lua.is_suspended());
+    assert_eq!(lua.fuel(), Some(0));
+
+    // The chunk continues from where it stopped
+    let mut refuels = 0;
+    let result = loop {
+        lua.refuel(100);
+        match lua.resume() {
+            Err(Error::FuelExhausted) => refuels += 1,
+            result => break result,
+        }
+    };
+    assert_eq!(result.unwrap(), vec![Value::Integer(500500)]);
+    assert!(refuels > 10);
+    assert!(!lua.is_suspended());
+
+    // Functions called by native functions can't be suspended
+    let native = crate::Program::parse(
+        r#"
+apply(function()
+    for i = 1, 1000 do
+        local last = i
+    end
+end)
+"#,
+    )
+    .unwrap();
+    lua.set_fuel(Some(100));
+    assert!(matches!(
+        lua.execute(native).map_err(Error::unlocated),
+        Err(Error::FuelExhausted)
+    ));
+    assert!(!lua.is_suspended());
+
+    lua.set_fuel(None);
+    lua.refuel(100);
+    assert_eq!(lua.fuel(), None);
+    assert_eq!(lua.execute(one).unwrap(), vec![Value::Integer(1)]);
+}
+
+#[test]
+fn located_errors() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let compile_error = crate::Program::parse_named("local a = 1\nlocal b = 2\nbreak\n", "script")
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(
+        compile_error.to_string(),
+        "script:3: Break outside of loop."
+    );
+    let crate::program::Error::Located { error, .. } = &compile_error else {
+        panic!("Compile error should be located.");
+    };
+    assert_eq!(**error, crate::program::Error::BreakOutsideLoop);
+
+    let parse_error = crate::Program::parse_named("local a = 1\nlocal = 2\n", "script")
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(
+        parse_error.to_string(),
+        "script:2: Could not parse program."
+    );
+
+    let source = r#"
+local function add(a, b)
+    return a + b
+end
+local t = {}
+local x = add(1, 2)
+return add(x, t)
+"#;
+    let program = crate::Program::parse_named(source, "script").unwrap();
+    assert_eq!(program.chunk_name(), Some("script"));
+    let err = Lua::default().execute(program).unwrap_err();
+    assert_eq!(err.to_string(), "script:3: Can't add integer with table.");
+    let source_error = core::error::Error::source(&err).unwrap().to_string();
+    assert_eq!(source_error, "Can't add integer with table.");
+
+    // Errors from native functions are located on the line that called them
+    let program = crate::Program::parse_named("local f = false\nassert(f)\n", "script").unwrap();
+    match Lua::default().execute(program) {
+        Err(Error::Located { chunk, line, error }) => {
+            assert_eq!((chunk.as_ref(), line), ("script", 2));
+            assert!(matches!(*error, Error::Assertion(_)));
+        }
+        other => panic!("Should fail with a located error, but was {other:?}."),
+    }
+
+    // Chunks parsed without a name are named after their first line,
+    // like the reference implementation names chunks loaded from strings
+    let program = crate::Program::parse(source).unwrap();
+    assert_eq!(program.chunk_name(), Some("[string \"...\"]"));
+    let err = Lua::default().execute(program).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "[string \"...\"]:3: Can't add integer with table."
+    );
+    assert!(matches!(err.unlocated(), Error::ArithmeticOperand(..)));
+    let parse_error = crate::Program::parse("local a = 1\nlocal = 2\n")
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(
+        parse_error.to_string(),
+        "[string \"local a = 1...\"]:2: Could not parse program."
+    );
+    let program = crate::Program::parse("return nil .. 1").unwrap();
+    assert_eq!(
+        Lua::default().execute(program).unwrap_err().to_string(),
+        "[string \"return nil .. 1\"]:1: Can't use nil in concatenation."
+    );
+
+    // Strings that are not closed are located on the line they start
+    let parse_error = crate::Program::parse_named("local a = 1\nlocal s = \"abc\n", "script")
+        .map(|_| ())
+        .unwrap_err();
+    assert_eq!(
+        parse_error.to_string(),
+        "script:2: Could not parse program."
+    );
+}
+
+#[test]
+fn error_messages_and_categories() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    for (source, message) in [
+        ("local t = nil\nreturn t.x\n", "Can't index nil."),
+        ("local s = \"a\"\ns.x = 1\n", "Can't index string."),
+        ("local t = {}\nreturn -t\n", "Can't negate table."),
+        (
+            "local b = true\nreturn #b\n",
+            "Can't get the length of boolean.",
+        ),
+        ("local t = {}\nreturn ~t\n", "Can't bitwise not table."),
+        ("local f = nil\nreturn f()\n", "Can't call nil."),
+        (
+            "local t = {}\nreturn 1 < t\n",
+            "Can't compare integer with table.",
+        ),
+        (
+            "for i = 1, {} do end\n",
+            "'for' limit value must be a number.",
+        ),
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        let err = Lua::default().execute(program).unwrap_err();
+        assert_eq!(err.category(), ErrorCategory::Runtime, "{source}");
+        assert_eq!(err.unlocated().to_string(), message, "{source}");
+    }
+
+    let mut lua = Lua::default();
+    let err = lua.eval("local = 1").unwrap_err();
+    assert_eq!(err.category(), ErrorCategory::Syntax);
+    let err = lua.resume().unwrap_err();
+    assert_eq!(err.category(), ErrorCategory::Api);
+
+    // Located errors have the category of the error they wrap
+    let program = crate::Program::parse_named("local t = {}\nreturn t .. 1\n", "script").unwrap();
+    let err = Lua::default().execute(program).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "script:2: Can't use table in concatenation."
+    );
+    assert_eq!(err.category(), ErrorCategory::Runtime);
+
+    // Errors can be boxed like any other error
+    let boxed: alloc::boxed::Box<dyn core::error::Error> = alloc::boxed::Box::new(err);
+    assert_eq!(
+        boxed.source().unwrap().to_string(),
+        "Can't use table in concatenation."
+    );
+}
+
+#[test]
+fn corrupt_bytecode() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    let chunk = |byte_codes: Vec<Bytecode>| crate::Program {
+        byte_codes: byte_codes.into(),
+        ..Default::default()
+    };
+
+    // Register past the top of the stack
+    let past_stack = chunk(vec![
+        Bytecode::variadic_arguments_prepare(0),
+        Bytecode::move_bytecode(0, 200),
+        Bytecode::zero_return(),
+    ]);
+    assert!(matches!(
+        lua.execute(past_stack).map_err(Error::unlocated),
+        Err(Error::CorruptStack)
+    ));
+
+    // Values for the table past the top of the stack
+    let set_list = chunk(vec![
+        Bytecode::variadic_arguments_prepare(0),
+        Bytecode::new_table(0, 0, 0),
+        Bytecode::set_list(0, 10, 0),
+        Bytecode::zero_return(),
+    ]);
+    assert!(matches!(
+        lua.execute(set_list),
+        Err(Error::InvalidBytecode("SETLIST"))
+    ));
+
+    // Returns that start past the top of the stack
+    let returns = chunk(vec![
+        Bytecode::variadic_arguments_prepare(0),
+        Bytecode::return_bytecode(250, 0, 0),
+    ]);
+    assert!(matches!(
+        lua.execute(returns),
+        Err(Error::InvalidBytecode("RETURN"))
+    ));
+
+    // Registers past the last one
+    let concat = chunk(vec![
+        Bytecode::variadic_arguments_prepare(0),
+        Bytecode::concat(250, 10),
+        Bytecode::zero_return(),
+    ]);
+    assert!(matches!(
+        lua.execute(concat),
+        Err(Error::InvalidBytecode("CONCAT"))
+    ));
+    assert_eq!(lua.dump_state(), "No running function.\n");
+
+    // The VM is still usable after the errors
+    let program = crate::Program::parse("local one = 1\nreturn one\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), vec![Value::Integer(1)]);
+}
+
+#[test]
+fn eval() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    assert_eq!(lua.eval("1 + 2").unwrap(), vec![Value::Integer(3)]);
+
+    // Statements return nothing, and their globals are seen by later lines
+    assert_eq!(lua.eval("x = 10").unwrap(), vec![]);
+    assert_eq!(lua.eval("local y = 1").unwrap(), vec![]);
+    assert_eq!(
+        lua.eval("x, x * 2, y\n").unwrap(),
+        vec![Value::Integer(10), Value::Integer(20), Value::Nil]
+    );
+    assert_eq!(
+        lua.eval("function double(n) return n * 2 end").unwrap(),
+        vec![]
+    );
+    assert_eq!(lua.eval("double(x)").unwrap(), vec![Value::Integer(20)]);
+    assert_eq!(lua.eval("return x - 1").unwrap(), vec![Value::Integer(9)]);
+
+    assert!(matches!(lua.eval("x = = 1"), Err(Error::Compile(_))));
+    // Errors are located on a chunk named after the line
+    let err = lua.eval("nil + 1").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "[string \"nil + 1\"]:1: Can't add nil with integer."
+    );
+    assert!(matches!(err.unlocated(), Error::ArithmeticOperand(..)));
+    assert_eq!(lua.eval("x").unwrap(), vec![Value::Integer(10)]);
+}
@@ -0,0 +1,380 @@
+use crate::{
+    Error, Lua, Program,
+    environment::Environment,
+    value::{Value, ValueKey},
+};
+
+#[test]
+fn collect_cycles() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    lua.execute(
+        Program::parse(
+            r#"
+for i = 1, 10 do
+    local t = {}
+    t.self = t
+end
+"#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(lua.collect_garbage(), 10);
+    assert_eq!(lua.collect_garbage(), 0);
+
+    // A table captured by a closure stored on the table
+    lua.execute(
+        Program::parse(
+            r#"
+local t = {}
+t.f = function() return t end
+"#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(lua.collect_garbage(), 3);
+}
+
+#[test]
+fn collect_returned_registers() {
+    use crate::closure::{NativeClosure, NativeClosureReturn};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    fn collect(vm: &mut Lua) -> NativeClosureReturn {
+        let freed = vm.collect_garbage();
+        vm.set_returns([Value::Integer(freed as i64)])
+    }
+
+    // The registers of functions that returned are left past the top of the
+    // stack, and must not keep their values alive
+    let mut env = Environment::default();
+    env.push("collect", collect as NativeClosure).unwrap();
+    let mut lua = Lua::new(env);
+    let results = lua
+        .execute(
+            Program::parse(
+                r#"
+local function make()
+    local t = {}
+    t.self = t
+end
+make()
+return collect()
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(results, [Value::Integer(1)]);
+}
+
+#[test]
+fn keep_reachable_cycles() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    let results = lua
+        .execute(
+            Program::parse(
+                r#"
+local a = {}
+local b = {a = a}
+a.b = b
+kept = {}
+kept.self = kept
+return a
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(lua.collect_garbage(), 0);
+
+    // The host still holds `a`, which holds `b`
+    let Value::Table(a) = &results[0] else {
+        panic!("Script should return a table.");
+    };
+    let b = a.borrow().get(crate::value::ValueKey("b".into())).clone();
+    assert!(matches!(b, Value::Table(_)));
+
+    drop(b);
+    drop(results);
+    assert_eq!(lua.collect_garbage(), 2);
+
+    lua.execute(
+        Program::parse(
+            r#"
+local k = kept
+assert(k.self == k)
+"#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn collectgarbage() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local before = collectgarbage("count")
+local t = {}
+t.self = t
+t = nil
+local stepped = collectgarbage("step")
+assert(stepped == true)
+local collected = collectgarbage()
+local zero = 0
+assert(collected == zero)
+local after = collectgarbage("count")
+assert(after <= before)
+collectgarbage("stop")
+local running = collectgarbage("isrunning")
+assert(running == false)
+collectgarbage("restart")
+running = collectgarbage("isrunning")
+assert(running == true)
+"#,
+    )
+    .unwrap();
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse("collectgarbage(\"everything\")\n").unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn free_globals() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // `_G` holds the globals, which are freed with the VM
+    let mut lua = Lua::default();
+    lua.execute(Program::parse("local f = function() return _G end\n").unwrap())
+        .unwrap();
+    let globals = alloc::rc::Rc::downgrade(lua.globals());
+    drop(lua);
+    assert_eq!(globals.upgrade(), None);
+
+    // Or with the environment, if it was never used
+    let env = Environment::default();
+    let globals = alloc::rc::Rc::downgrade(&env);
+    drop(env);
+    assert_eq!(globals.upgrade(), None);
+
+    // Unless the host holds them
+    let lua = Lua::default();
+    let held = lua.globals().clone();
+    drop(lua);
+    assert!(matches!(
+        held.borrow().get(ValueKey("_G".into())),
+        Value::Table(_)
+    ));
+}
+
+#[test]
+fn memory_limit() {
+    use crate::closure::{NativeClosure, NativeClosureReturn};
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    /// Calls its argument, returning `false` and the message if it failed
+    fn try_call(vm: &mut Lua) -> NativeClosureReturn {
+        let function = vm.register(0).cloned().unwrap_or(Value::Nil);
+        match vm.call_value(function, &[]) {
+            Ok(_) => vm.set_returns([Value::Boolean(true)]),
+            Err(err) => vm.set_returns([
+                Value::Boolean(false),
+                alloc::format!("{err}").as_str().into(),
+            ]),
+        }
+    }
+
+    let mut env = Environment::default();
+    env.push("try", try_call as NativeClosure).unwrap();
+    let mut lua = Lua::new(env);
+    assert_eq!(lua.memory_limit(), None);
+    let in_use = lua.memory_in_use();
+    lua.set_memory_limit(Some(in_use + 64 * 1024));
+    assert_eq!(lua.memory_limit(), Some(in_use + 64 * 1024));
+
+    for source in [
+        "local t = {}\nwhile true do t[#t + 1] = {} end\n",
+        "local t = {}\nlocal i = 0\nwhile true do\n    i = i + 1\n    t[i] = i\nend\n",
+        "local t = {}\nwhile true do t[#t + 1] = function() return t end end\n",
+        "local s = \"0123456789\"\nwhile true do s = s .. s end\n",
+        "grown = {}\nwhile true do grown[#grown + 1] = \"0123456789\" .. #grown end\n",
+    ] {
+        match lua
+            .execute(Program::parse(source).unwrap())
+            .map_err(Error::unlocated)
+        {
+            Err(Error::MemoryLimit) => (),
+            Ok(_) => panic!("`{source}` should fail."),
+            Err(err) => panic!("`{source}` should fail with MemoryLimit, but failed with `{err}`."),
+        }
+        // What the script held is freed, so other scripts can still run
+        lua.execute(Program::parse("grown = nil\nlocal t = {1, 2, 3}\nassert(#t == 3)\n").unwrap())
+            .unwrap();
+    }
+
+    // Garbage doesn't count towards the limit
+    lua.execute(
+        Program::parse(
+            r#"
+for i = 1, 10000 do
+    local t = {i, i + 1}
+    t.self = t
+end
+"#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    // Natives see the failure as an error of the call
+    let results = lua
+        .execute(
+            Program::parse(
+                r#"
+local ok, message = try(function()
+    local t = {}
+    while true do t[#t + 1] = {} end
+end)
+local kept = {}
+for i = 1, 100 do kept[i] = i end
+return ok, message, #kept
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        results,
+        [
+            Value::Boolean(false),
+            "[string \"...\"]:4: Not enough memory.".into(),
+            Value::Integer(100)
+        ]
+    );
+
+    lua.set_memory_limit(None);
+    lua.execute(Program::parse("local t = {}\nfor i = 1, 10000 do t[i] = {} end\n").unwrap())
+        .unwrap();
+}
+
+#[test]
+#[cfg(feature = "string")]
+fn memory_limit_strings() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    let in_use = lua.memory_in_use();
+    lua.set_memory_limit(Some(in_use + 64 * 1024));
+
+    // The string is never built
+    let program = Program::parse("local s = string.rep(\"x\", 1 << 40)\n").unwrap();
+    assert!(matches!(
+        lua.execute(program).map_err(Error::unlocated),
+        Err(Error::MemoryLimit)
+    ));
+    let program = Program::parse("local s = string.rep(\"x\", 128 * 1024)\n").unwrap();
+    assert!(matches!(
+        lua.execute(program).map_err(Error::unlocated),
+        Err(Error::MemoryLimit)
+    ));
+
+    let program = Program::parse("local s = string.rep(\"x\", 16 * 1024)\nreturn #s\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), [Value::Integer(16 * 1024)]);
+    let program =
+        Program::parse("local t = {}\nfor i = 1, 100 do t[i] = string.rep(\"x\", 1024) end\n")
+            .unwrap();
+    assert!(matches!(
+        lua.execute(program).map_err(Error::unlocated),
+        Err(Error::MemoryLimit)
+    ));
+
+    // Without a limit, a string the host can't allocate is still an error
+    lua.set_memory_limit(None);
+    let program = Program::parse("local s = (\"x\"):rep(1 << 50)\n").unwrap();
+    assert!(matches!(
+        lua.execute(program).map_err(Error::unlocated),
+        Err(Error::MemoryLimit)
+    ));
+}
+
+#[test]
+#[cfg(all(feature = "string", feature = "table"))]
+fn memory_limit_string_results() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    lua.execute(
+        Program::parse(
+            "s = string.rep(\"x\", 40 * 1024)\nmany = {}\nfor i = 1, 1 << 20 do many[i] = s end\n",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let in_use = lua.memory_in_use();
+    lua.set_memory_limit(Some(in_use + 32 * 1024));
+
+    // Each result is counted before it is built, the last one would
+    // take 40 GiB
+    for source in [
+        "local r = s:lower()\n",
+        "local r = s:upper()\n",
+        "local r = s:reverse()\n",
+        "local r = s:sub(2)\n",
+        "local r = table.concat({s})\n",
+        "local r = table.concat(many)\n",
+    ] {
+        match lua
+            .execute(Program::parse(source).unwrap())
+            .map_err(Error::unlocated)
+        {
+            Ok(_) => panic!("`{source}` should go over the memory limit."),
+            Err(Error::MemoryLimit) => (),
+            Err(err) => panic!("`{source}` failed with {err:?}."),
+        }
+    }
+
+    let program = Program::parse("return #s:sub(1, 1024)\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), [Value::Integer(1024)]);
+}
+
+#[test]
+#[cfg(feature = "table")]
+fn memory_limit_table_move() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    lua.execute(Program::parse("source = {}\nfor i = 1, 100000 do source[i] = i end\n").unwrap())
+        .unwrap();
+    let in_use = lua.memory_in_use();
+    lua.set_memory_limit(Some(in_use + 64 * 1024));
+
+    // Each copied element counts, the range is never collected at once
+    match lua
+        .execute(Program::parse("table.move(source, 1, #source, 1, {})\n").unwrap())
+        .map_err(Error::unlocated)
+    {
+        Err(Error::MemoryLimit) => (),
+        other => panic!("Should fail with MemoryLimit, but returned {other:?}."),
+    }
+
+    let program = Program::parse(
+        "local t = table.move({1, 2, 3, 4}, 1, 3, 2)\nreturn table.concat(t, \",\")\n",
+    )
+    .unwrap();
+    assert_eq!(lua.execute(program).unwrap(), ["1,1,2,3".into()]);
+}
@@ -0,0 +1,184 @@
+use core::cell::RefCell;
+
+use alloc::{rc::Rc, string::ToString, vec, vec::Vec};
+
+use crate::{
+    Error, HookEvent, HookMask, Lua, Program,
+    closure::{FunctionType, NativeClosure, NativeClosureReturn},
+    environment::Environment,
+    value::Value,
+};
+
+#[test]
+fn line_hook() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local a = 1
+local b = 2
+for i = 1, 2 do
+    a = a + i
+end
+return a
+"#,
+    )
+    .unwrap();
+    assert_eq!(program.line(0), None);
+    assert_eq!(program.line(1), Some(2));
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let mut lua = Lua::default();
+    let recorded = events.clone();
+    lua.set_hook(
+        HookMask {
+            line: true,
+            ..Default::default()
+        },
+        move |vm, event| {
+            assert_eq!(vm.current_line().map(HookEvent::Line), Some(event));
+            let locals = vm
+                .locals()
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect::<Vec<_>>();
+            recorded.borrow_mut().push((event, locals));
+            Ok(())
+        },
+    );
+    assert_eq!(lua.execute(program).unwrap(), vec![Value::Integer(4)]);
+
+    let events = events.borrow();
+    // Lines start again on every iteration of the loop
+    assert_eq!(
+        events.iter().map(|(event, _)| *event).collect::<Vec<_>>(),
+        [2, 3, 4, 5, 4, 5, 4, 7].map(HookEvent::Line)
+    );
+    let a = |value| ("a".to_string(), Value::Integer(value));
+    let b = ("b".to_string(), Value::Integer(2));
+    let i = |value| ("i".to_string(), Value::Integer(value));
+    assert_eq!(events[1].1, [a(1)]);
+    assert_eq!(events[3].1[..2], [a(1), b.clone()]);
+    assert_eq!(events[3].1.last(), Some(&i(1)));
+    assert_eq!(events[5].1.last(), Some(&i(2)));
+    assert_eq!(events[7].1, [a(4), b]);
+}
+
+#[test]
+fn call_hook() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    fn native(_: &mut Lua) -> NativeClosureReturn {
+        Ok(0)
+    }
+
+    let program = Program::parse(
+        r#"
+local function leaf(n)
+    native()
+    return n
+end
+local function tail(n)
+    return leaf(n)
+end
+local result = tail(1)
+return result
+"#,
+    )
+    .unwrap();
+
+    let mut env = Environment::default();
+    env.push("native", native as NativeClosure).unwrap();
+    let mut lua = Lua::new(env);
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let recorded = events.clone();
+    lua.set_hook(
+        HookMask {
+            call: true,
+            ret: true,
+            ..Default::default()
+        },
+        move |vm, event| {
+            let native = match vm.running_function() {
+                Some(Value::Closure(closure)) => {
+                    matches!(closure.closure_type(), FunctionType::Native(_))
+                }
+                other => panic!("Hooks should run inside of a function, but was {other:?}."),
+            };
+            assert_eq!(native, vm.program_counter().is_none());
+            recorded.borrow_mut().push((event, native));
+            Ok(())
+        },
+    );
+    assert_eq!(lua.execute(program).unwrap(), vec![Value::Integer(1)]);
+
+    // `tail` does not return, `leaf` returns in its place
+    assert_eq!(
+        events.borrow().as_slice(),
+        [
+            (HookEvent::Call, false),
+            (HookEvent::Call, false),
+            (HookEvent::TailCall, false),
+            (HookEvent::Call, true),
+            (HookEvent::Return, true),
+            (HookEvent::Return, false),
+            (HookEvent::Return, false),
+        ]
+    );
+}
+
+#[test]
+fn count_hook() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local total = 0
+for i = 1, 100 do
+    total = total + i
+end
+return total
+"#,
+    )
+    .unwrap();
+
+    let mut lua = Lua::default();
+    let counts = Rc::new(RefCell::new(0));
+    let recorded = counts.clone();
+    lua.set_hook(
+        HookMask {
+            count: 10,
+            ..Default::default()
+        },
+        move |_, event| {
+            assert_eq!(event, HookEvent::Count);
+            *recorded.borrow_mut() += 1;
+            Ok(())
+        },
+    );
+    assert_eq!(
+        lua.execute(program.clone()).unwrap(),
+        vec![Value::Integer(5050)]
+    );
+    // `VARARGPREP`, `LOADI`, 3 `LOADI`s and `FORPREP`, 100 `ADD`s and
+    // `FORLOOP`s, and `RETURN`
+    assert_eq!(*counts.borrow(), 206 / 10);
+
+    // Errors raised by the hook stop the script
+    lua.set_hook(
+        HookMask {
+            count: 50,
+            ..Default::default()
+        },
+        |vm, _| {
+            let line = vm.current_line().unwrap_or_default();
+            Err(Error::BadArgument(line, "stopped by hook"))
+        },
+    );
+    // The 50th bytecode is the `FORLOOP` of the 22nd iteration
+    let err = lua.execute(program.clone()).unwrap_err().unlocated();
+    assert!(matches!(err, Error::BadArgument(3, _)), "{err}");
+
+    lua.clear_hook();
+    assert_eq!(lua.execute(program).unwrap(), vec![Value::Integer(5050)]);
+}
@@ -0,0 +1,43 @@
+use alloc::vec::Vec;
+
+use crate::{Local, Program, Value};
+
+#[test]
+fn accessors() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"local a = "hello"
+local function f(x, ...)
+    return a .. x
+end
+print(f(2.5))
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        program.constants().collect::<Vec<_>>(),
+        [
+            &Value::from("hello"),
+            &Value::from("print"),
+            &Value::Float(2.5)
+        ]
+    );
+    assert_eq!(
+        program.locals(),
+        [Local::new("a".into(), 3, 10), Local::new("f".into(), 4, 10),]
+    );
+    assert_eq!(program.upvalues().collect::<Vec<_>>(), ["_ENV"]);
+    assert_eq!(program.functions().len(), 1);
+
+    let function = program.functions().next().unwrap();
+    assert_eq!(function.arg_count(), 1);
+    assert!(function.variadic_args());
+    let f = function.program();
+    assert_eq!(f.constants().len(), 0);
+    assert_eq!(f.locals()[0].name(), "x");
+    assert!(f.locals()[0].active(f.locals()[0].scope_start()));
+    assert_eq!(f.upvalues().collect::<Vec<_>>(), ["a"]);
+    assert_eq!(f.functions().len(), 0);
+}
@@ -0,0 +1,148 @@
+use alloc::{
+    collections::VecDeque,
+    format,
+    rc::Rc,
+    string::{String, ToString},
+};
+use core::cell::RefCell;
+
+use crate::{Error, environment::Environment};
+
+/// Environment whose standard input has `input`, and whose standard
+/// output is written to the returned string
+fn console(input: &str) -> (Environment, Rc<RefCell<String>>) {
+    let lines = RefCell::new(
+        input
+            .split_inclusive('\n')
+            .map(String::from)
+            .collect::<VecDeque<_>>(),
+    );
+    let output = Rc::new(RefCell::new(String::new()));
+    let stdout = output.clone();
+    let env = Environment::builder()
+        .stdin(move || lines.borrow_mut().pop_front())
+        .stdout(move |string: &str| {
+            stdout.borrow_mut().push_str(string);
+            Ok(())
+        })
+        .build()
+        .unwrap();
+    (env, output)
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn write() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let (env, output) = console("");
+    let program = crate::Program::parse(
+        r#"
+local ok = io.write("a", 1, " ", 2.5, "\n")
+assert(ok == true)
+io.write()
+io.write("b")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+    assert_eq!(output.borrow().as_str(), "a1 2.5\nb");
+
+    // Without a standard output, `io.write` fails
+    let program = crate::Program::parse(
+        r#"
+local ok, message = io.write("a")
+assert(ok == nil)
+assert(message == "no standard output")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse("io.write({})\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::Expected(1, "string", "table")) => (),
+        Err(err) => panic!("Should fail with Expected, but failed with `{}`.", err),
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn read() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let (env, _) = console("first\nsecond\n 42 \n1.5\nrest\nof the input");
+    let program = crate::Program::parse(
+        r#"
+local second, rest = "second\n", "rest\nof the input"
+local a = io.read()
+assert(a == "first")
+local b = io.read("L")
+assert(b == second)
+local c, d = io.read("n", "*n")
+assert(c == 42)
+assert(d == 1.5)
+local e = io.read("a")
+assert(e == rest)
+local f = io.read("l")
+assert(f == nil)
+local g = io.read("a")
+assert(g == "")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+
+    let (env, _) = console("not a number\nline\n");
+    let program = crate::Program::parse(
+        r#"
+local a, b = io.read("n", "l")
+assert(a == nil)
+assert(b == nil)
+local c = io.read()
+assert(c == "line")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+
+    let program = crate::Program::parse("io.read(\"x\")\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn lines() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let (env, output) = console("x\ny\n");
+    let mut env = env;
+    env.set_file_system(|path: &str| match path {
+        "numbers.txt" => Ok("one\ntwo\n\nfour".to_string()),
+        _ => Err(format!("{path}: not found")),
+    });
+    let program = crate::Program::parse(
+        r#"
+for line in io.lines("numbers.txt") do
+    io.write("[", line, "]")
+end
+for line in io.lines() do
+    io.write("<", line, ">")
+end
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+    assert_eq!(output.borrow().as_str(), "[one][two][][four]<x><y>");
+
+    let program = crate::Program::parse("io.lines(\"missing.txt\")\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::Io(message)) => assert_eq!(message, "missing.txt: no file system"),
+        Err(err) => panic!("Should fail with Io, but failed with `{}`.", err),
+    }
+}
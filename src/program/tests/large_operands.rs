@@ -0,0 +1,271 @@
+#[cfg(feature = "float")]
+use alloc::string::String;
+use alloc::{format, vec, vec::Vec};
+
+use crate::{
+    Error, Lua, Program,
+    bytecode::{Bytecode, OpCode, arguments::Ax},
+    value::{Value, ValueKey},
+};
+
+#[cfg(feature = "float")]
+#[test]
+fn many_constants() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Each field adds 3 constants, so the last ones don't fit the 8 bits
+    // operands of `SETFIELD`, `GETFIELD`, `GETTABUP` and `SELF`
+    let mut source = String::from("local t = {\n");
+    for i in 0..200i64 {
+        source.push_str(&format!(
+            "    k{i} = \"v{i}\", [{}] = {i}.5, [\"s{i}\"] = {},\n",
+            i * 1000,
+            i + 1_000_000_000_000
+        ));
+    }
+    source.push_str(
+        r#"}
+assert(t.k199 == "v199")
+assert(t[199000] == 199.5)
+assert(t.s199 == 1000000000199)
+local count = 0
+for _ in pairs(t) do
+    count = count + 1
+end
+assert(count == 600)
+
+local key = "k1" .. "99"
+t[key] = 7
+assert(t.k199 == 7)
+t.k198 = key
+assert(t["k198"] == "k199")
+t[key] = 1000000000200
+assert(t[key] == 1000000000200)
+
+late_global = "late"
+assert(late_global == "late")
+late_global = t.k0
+assert(late_global == "v0")
+
+local object = {}
+function object:late_method(value)
+    return self == object and value
+end
+assert(object:late_method(3) == 3)
+local outer = {inner = object}
+assert(outer.inner:late_method(4) == 4)
+"#,
+    );
+
+    let program = Program::parse(&source).unwrap();
+    assert!(program.constants.len() > 256);
+    Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn extra_arguments() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    let extra = |ax: u32| Bytecode::extra_arguments(Ax::try_from(ax).unwrap());
+
+    // `LOADKX` reads the id of the constant from the `EXTRAARG`
+    let program = Program {
+        byte_codes: vec![
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::load_constant_extra_args(0),
+            extra(2),
+            Bytecode::return_bytecode(0, 2, 0),
+        ]
+        .into(),
+        constants: vec![Value::Integer(1), Value::Integer(2), "third".into()].into(),
+        ..Default::default()
+    };
+    assert!(format!("{program}").contains("LOADKX   \t0\t; \"third\""));
+    assert_eq!(lua.execute(program).unwrap(), vec!["third".into()]);
+
+    // `SETLIST` with `k` stores after 256 times the `EXTRAARG` plus `C` items
+    let program = Program {
+        byte_codes: vec![
+            Bytecode::variadic_arguments_prepare(0),
+            Bytecode::new_table(0, 0, 0),
+            Bytecode::load_integer(1, 10i16),
+            Bytecode::load_integer(2, 20i16),
+            Bytecode::set_list_extra_args(0, 2, 4),
+            extra(1),
+            Bytecode::load_integer(1, 261i16),
+            Bytecode::get_table(1, 0, 1),
+            Bytecode::load_integer(2, 262i16),
+            Bytecode::get_table(2, 0, 2),
+            Bytecode::return_bytecode(1, 3, 0),
+        ]
+        .into(),
+        ..Default::default()
+    };
+    assert_eq!(
+        lua.execute(program).unwrap(),
+        vec![Value::Integer(10), Value::Integer(20)]
+    );
+
+    // `LOADKX` and `SETLIST` with `k` need an `EXTRAARG`, which never
+    // runs by itself
+    for (opcode, byte_codes) in [
+        (
+            "LOADKX",
+            vec![
+                Bytecode::variadic_arguments_prepare(0),
+                Bytecode::load_constant_extra_args(0),
+                Bytecode::zero_return(),
+            ],
+        ),
+        (
+            "SETLIST",
+            vec![
+                Bytecode::variadic_arguments_prepare(0),
+                Bytecode::new_table(0, 0, 0),
+                Bytecode::set_list_extra_args(0, 1, 0),
+                Bytecode::zero_return(),
+            ],
+        ),
+        (
+            "EXTRAARG",
+            vec![
+                Bytecode::variadic_arguments_prepare(0),
+                extra(0),
+                Bytecode::zero_return(),
+            ],
+        ),
+    ] {
+        let program = Program {
+            byte_codes: byte_codes.into(),
+            constants: vec![Value::Nil].into(),
+            ..Default::default()
+        };
+        match lua.execute(program) {
+            Err(Error::InvalidBytecode(name)) => assert_eq!(name, opcode),
+            other => panic!("Should fail with InvalidBytecode, but returned {other:?}."),
+        }
+    }
+
+    // The VM is still usable after the errors
+    let program = Program::parse("local one = 1\nreturn one\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), vec![Value::Integer(1)]);
+}
+
+#[test]
+fn long_constructors() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Items are stored 50 at a time, past 255 stored items the count
+    // goes on an `EXTRAARG`
+    let items = (0..300)
+        .map(|i| format!("\"s{i}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let program = Program::parse(&format!("return {{{items}, ...}}\n")).unwrap();
+    let set_lists = program
+        .byte_codes
+        .iter()
+        .filter(|bytecode| {
+            matches!(
+                bytecode.opcode(),
+                Some(OpCode::SetList | OpCode::ExtraArguments)
+            )
+        })
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        set_lists,
+        [
+            Bytecode::set_list(0, 50, 0),
+            Bytecode::set_list(0, 50, 50),
+            Bytecode::set_list(0, 50, 100),
+            Bytecode::set_list(0, 50, 150),
+            Bytecode::set_list(0, 50, 200),
+            Bytecode::set_list(0, 50, 250),
+            Bytecode::set_list_extra_args(0, 0, 44),
+            Bytecode::extra_arguments(Ax::try_from(1u32).unwrap()),
+        ]
+    );
+
+    let program = Program::parse(
+        r#"
+local function three()
+    return 1, 2, 3
+end
+local function pack(...)
+    return {...}
+end
+
+local t = {three()}
+assert(#t == 3)
+t = {three(), three()}
+assert(#t == 4)
+-- Only the last field adds all values
+t = {three(), x = 1}
+assert(#t == 1)
+t = pack(1, 2, 3, 4)
+assert(#t == 4)
+t = pack()
+assert(#t == 0)
+
+local items = {}
+for i = 1, 120 do
+    items[#items + 1] = i
+end
+-- `t` is below other locals, the items go above the table
+t = {
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+    21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+    41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
+    k = "v", [200] = 200,
+    61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80,
+    81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100,
+    101, 102, 103, 104, 105, 106, 107, 108, 109, 110, three(),
+}
+assert(#t == 113)
+for i = 1, 110 do
+    assert(t[i] == items[i])
+end
+assert(t[111] == 1 and t[113] == 3)
+assert(t.k == "v" and t[200] == 200)
+"#,
+    )
+    .unwrap();
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse(&format!("return {{{items}, ...}}\n")).unwrap();
+    let mut lua = Lua::default();
+    let Value::Table(table) = lua.execute(program).unwrap().remove(0) else {
+        panic!("Should return a table.");
+    };
+    let table = table.borrow();
+    assert_eq!(table.border(), 300);
+    assert_eq!(*table.get(ValueKey(Value::Integer(300))), "s299".into());
+}
+
+#[test]
+fn huge_constructors() {
+    let items = "1, ".repeat(100_000);
+    let program = Program::parse(&format!("local t = {{{items}1}}\nreturn #t\n")).unwrap();
+    let mut lua = Lua::default();
+    assert_eq!(lua.execute(program).unwrap(), [Value::Integer(100_001)]);
+}
+
+#[test]
+fn huge_lists() {
+    let program = Program::parse(&format!(
+        "local a = 0\ndo\n{}end\nreturn a\n",
+        "a = a + 1\n".repeat(100_000)
+    ))
+    .unwrap();
+    let mut lua = Lua::default();
+    assert_eq!(lua.execute(program).unwrap(), [Value::Integer(100_000)]);
+
+    // Every argument takes a register
+    let source = format!("return select('#', {}1)\n", "nil, ".repeat(100_000));
+    assert_eq!(
+        Program::parse(&source).unwrap_err().unlocated(),
+        crate::program::Error::StackOverflow
+    );
+}
@@ -0,0 +1,234 @@
+use crate::Error;
+
+#[test]
+fn rounding() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local zero, three, four, five = 0, 3, 4, 5
+local a = math.floor(3.7)
+local a_type = math.type(a)
+assert(a == three)
+assert(a_type == "integer")
+local b = math.floor(-3.2)
+local c = math.ceil(3.2)
+assert(b < zero)
+assert(c == four)
+local huge = math.huge
+local d = math.floor(huge)
+local d_type = math.type(d)
+assert(d_type == "float")
+local e = math.abs(-5)
+assert(e == five)
+local mininteger = math.mininteger
+local f = math.abs(mininteger)
+assert(f == mininteger)
+local half = 2.5
+local g = math.abs(-2.5)
+assert(g == half)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn numbers() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local zero, one, three, five, minus_one = 0, 1, 3, 5, -1
+local a = math.max(1, 5, 3)
+assert(a == five)
+local b = math.min(4, 2.5, 3)
+local b_expected = 2.5
+assert(b == b_expected)
+local c = math.max(1, 2.0)
+local c_type = math.type(c)
+assert(c_type == "float")
+local d = math.fmod(7, 3)
+assert(d == one)
+local e = math.fmod(-7, 3)
+assert(e == minus_one)
+local f = math.fmod(7.5, 2)
+local f_expected = 1.5
+assert(f == f_expected)
+local mininteger = math.mininteger
+local g = math.fmod(mininteger, -1)
+assert(g == zero)
+local h = math.sqrt(16)
+local h_expected = 4.0
+assert(h == h_expected)
+local huge = math.huge
+local maxinteger = math.maxinteger
+assert(huge > maxinteger)
+local i = math.tointeger(3.0)
+assert(i == three)
+local j = math.tointeger(3.5)
+local j_type = type(j)
+assert(j_type == "nil")
+local k = math.type(1)
+assert(k == "integer")
+local l = math.type(1.0)
+assert(l == "float")
+local m = math.type("1")
+local m_type = type(m)
+assert(m_type == "nil")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse(
+        r#"
+math.fmod(1, 0)
+"#,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(2, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn random() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local zero, one, three, ten, minus_five, five = 0, 1, 3, 10, -5, 5
+local a = math.random()
+assert(a >= zero)
+assert(a < one)
+local b = math.random(10)
+local b_type = math.type(b)
+assert(b >= one)
+assert(b <= ten)
+assert(b_type == "integer")
+local c = math.random(-5, 5)
+assert(c >= minus_five)
+assert(c <= five)
+local d = math.random(3, 3)
+assert(d == three)
+
+math.randomseed(42)
+local e = math.random(1000)
+local f = math.random(1000)
+math.randomseed(42)
+local g = math.random(1000)
+local h = math.random(1000)
+assert(e == g)
+assert(f == h)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse(
+        r#"
+math.random(2, 1)
+"#,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(2, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn random_entropy_source() {
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    use crate::environment::Environment;
+
+    static ENTROPY_CALLS: AtomicU64 = AtomicU64::new(0);
+
+    fn entropy() -> u64 {
+        ENTROPY_CALLS
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+    }
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Without an entropy source, `randomseed` falls back to the default seed
+    let program = crate::Program::parse(
+        r#"
+local a = math.random(1000)
+math.randomseed()
+local b = math.random(1000)
+assert(a == b)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    let mut env = Environment::default();
+    env.set_entropy_source(entropy);
+    let program = crate::Program::parse(
+        r#"
+local a = math.random(1000)
+math.randomseed()
+local b = math.random(1000)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+    // Two calls for the initial seeding, and two for `randomseed`
+    assert_eq!(ENTROPY_CALLS.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn integer_arithmetic() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local sum = 0
+for i = 1, 100 do
+    sum = sum + i
+end
+local expected = 5050
+assert(sum == expected)
+local product = 1
+for i = 1, 10 do
+    product = product + product
+end
+local kibi = 1024
+assert(product == kibi)
+local max = math.maxinteger
+local one = 1
+local wrapped = max + one
+local min = math.mininteger
+assert(wrapped == min)
+local a = 1
+local b = 2
+local c = 2
+local lt = a < b
+assert(lt)
+local le = b <= c
+assert(le)
+local nlt = b < a
+assert(not nlt)
+local half = 0.5
+local mixed = a + half
+local expected_mixed = 1.5
+assert(mixed == expected_mixed)
+local diff = a - b
+local minus_one = -1
+assert(diff == minus_one)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
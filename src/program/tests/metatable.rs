@@ -0,0 +1,309 @@
+use alloc::string::ToString;
+
+use crate::{
+    Error, Lua, Program,
+    bytecode::Bytecode,
+    program,
+    value::{Value, ValueKey},
+};
+
+#[test]
+fn call() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local Point = setmetatable({}, {__call = function(self, x, y)
+    local p = {x = x, y = y}
+    return p
+end})
+local p = Point(1, 2)
+local one, two = 1, 2
+assert(p.x == one)
+assert(p.y == two)
+local counter = setmetatable({n = 0}, {__call = function(self, a, b)
+    self.n = self.n + 1
+    return a, b
+end})
+local a, b = counter("a", "b")
+assert(a == "a")
+assert(b == "b")
+local function tail(x, y) return counter(x, y) end
+local c, d = tail(7, 8)
+local seven, eight = 7, 8
+assert(c == seven)
+assert(d == eight)
+local calls = counter.n
+assert(calls == two)
+local iterator = setmetatable({}, {__call = function(self, state, control)
+    if control < 3 then return control + 1, self end
+end})
+local sum = 0
+for i, _ in iterator, nil, 0 do
+    sum = sum + i
+end
+local six = 6
+assert(sum == six)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse("local t = setmetatable({}, {})\nt()\n").unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::InvalidFunction(Value::Table(_))) => (),
+        Err(err) => panic!(
+            "Should fail with InvalidFunction, but failed with `{}`.",
+            err
+        ),
+    }
+}
+
+#[test]
+fn call_from_host() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    let results = lua
+        .execute(
+            Program::parse(
+                r#"
+local double = setmetatable({}, {__call = function(self, x) return x * 2 end})
+return double
+"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+    let results = lua
+        .call_value(results[0].clone(), &[Value::Integer(21)])
+        .unwrap();
+    assert_eq!(results, [Value::Integer(42)]);
+}
+
+#[test]
+fn tostring() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local point = setmetatable({x = 1, y = 2}, {__tostring = function(p)
+    return "(" .. p.x .. ", " .. p.y .. ")"
+end})
+local s = tostring(point)
+assert(s == "(1, 2)")
+print(point)
+local n = tostring(12)
+assert(n == "12")
+local b = tostring(true)
+assert(b == "true")
+local nothing = tostring(nil)
+assert(nothing == "nil")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse(
+        "local t = setmetatable({}, {__tostring = function() return 1 end})\nprint(t)\n",
+    )
+    .unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::InvalidToString(_)) => (),
+        Err(err) => panic!(
+            "Should fail with InvalidToString, but failed with `{}`.",
+            err
+        ),
+    }
+}
+
+#[test]
+fn concat() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local order = ""
+local mt = {}
+mt.__concat = function(lhs, rhs)
+    local l, r = lhs, rhs
+    if type(l) == "table" then l = l.name end
+    if type(r) == "table" then r = r.name end
+    order = order .. "(" .. l .. r .. ")"
+    return l .. r
+end
+local a = setmetatable({name = "A"}, mt)
+local b = setmetatable({name = "B"}, mt)
+local s = "x" .. a .. "y" .. b
+assert(s == "xAyB")
+assert(order == "(yB)(AyB)")
+local t = a .. 1
+assert(t == "A1")
+local function g() return "G" end
+local u = g() .. s .. g()
+assert(u == "GxAyBG")
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse("local t = {}\nlocal s = \"a\" .. t\n").unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::ConcatOperand("table")) => (),
+        Err(err) => panic!("Should fail with ConcatOperand, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn len() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local t = {}
+for i = 1, 5 do
+    t[#t + 1] = i * 2
+end
+assert(#t == 5)
+assert(t[5] == 10)
+t[5] = nil
+assert(#t == 4)
+t[2] = nil
+local n = #t
+assert(n == 4 or n == 1)
+local s = "hello"
+assert(#s == 5)
+assert(rawlen(s) == 5)
+local m = setmetatable({1, 2}, {__len = function(v) return 42 end})
+assert(#m == 42)
+assert(rawlen(m) == 2)
+local e = {}
+assert(#e == 0)
+"#,
+    )
+    .unwrap();
+
+    Lua::run_program(program).unwrap();
+
+    let program = Program::parse("local a = 1\nlocal b = #a\n").unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::InvalidLenOperand("integer")) => (),
+        Err(err) => panic!(
+            "Should fail with InvalidLenOperand, but failed with `{}`.",
+            err
+        ),
+    }
+}
+
+#[test]
+fn close() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = Program::parse(
+        r#"
+local v = nil
+do
+    local x <close> = v
+end
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        &program.byte_codes[1..5],
+        &[
+            // local v = nil
+            Bytecode::load_nil(0, 0),
+            // local x <close> = v
+            Bytecode::move_bytecode(1, 0),
+            Bytecode::to_be_closed(1),
+            // end
+            Bytecode::close(1),
+        ]
+    );
+
+    let program = Program::parse(
+        r#"
+local log = ""
+local mt = {__close = function(v, e) log = log .. v.name .. ";" end}
+do
+    local a <close> = setmetatable({name = "a"}, mt)
+    local b <close> = setmetatable({name = "b"}, mt)
+    local c <close> = nil
+    log = log .. "body;"
+end
+assert(log == "body;b;a;")
+local function f()
+    local x <close> = setmetatable({name = "x"}, mt)
+    return 1
+end
+local r = f()
+assert(r == 1)
+assert(log == "body;b;a;x;")
+local function g()
+    local y <close> = setmetatable({name = "y"}, mt)
+    return f()
+end
+local s = g()
+assert(s == 1)
+assert(log == "body;b;a;x;x;y;")
+"#,
+    )
+    .unwrap();
+    Lua::run_program(program).unwrap();
+
+    // Variables are closed with the error when it unwinds the function
+    let mut lua = Lua::default();
+    let program = Program::parse(
+        r#"
+closed = "no"
+local function h()
+    local z <close> = setmetatable({}, {__close = function(v, e) closed = e end})
+    local t = {}
+    t()
+end
+h()
+"#,
+    )
+    .unwrap();
+    let Err(err) = lua.execute(program) else {
+        panic!("Should fail with InvalidFunction.");
+    };
+    let closed = lua
+        .globals()
+        .borrow()
+        .get(ValueKey("closed".into()))
+        .clone();
+    assert_eq!(closed.to_string(), err.to_string());
+    assert!(matches!(err.unlocated(), Error::InvalidFunction(_)));
+
+    let program = Program::parse("local t = {}\nlocal x <close> = t\n").unwrap();
+    match Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::NonClosableValue("table")) => (),
+        Err(err) => panic!(
+            "Should fail with NonClosableValue, but failed with `{}`.",
+            err
+        ),
+    }
+
+    assert_eq!(
+        Program::parse("local x <constant> = 1\n")
+            .unwrap_err()
+            .unlocated(),
+        program::Error::UnknownAttribute
+    );
+    assert_eq!(
+        Program::parse("local x <close>, y <close> = nil, nil\n")
+            .unwrap_err()
+            .unlocated(),
+        program::Error::MultipleToBeClosed
+    );
+}
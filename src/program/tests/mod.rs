@@ -6,7 +6,11 @@ use crate::{bytecode::Bytecode, value::Value};
 
 use super::{Local, Program};
 
+mod adjustment;
+mod arithmetic;
+mod attribute;
 mod basic;
+mod binary_chunk;
 mod chapter1;
 mod chapter2;
 mod chapter3;
@@ -16,6 +20,37 @@ mod chapter6;
 mod chapter7;
 mod chapter8;
 mod chapter9;
+mod check;
+#[cfg(feature = "float")]
+mod comparison;
+mod conformance;
+mod constant_pool;
+mod diff;
+#[cfg(feature = "float")]
+mod dump;
+mod embedding;
+mod gc;
+mod hook;
+#[cfg(feature = "float")]
+mod inspect;
+#[cfg(feature = "io")]
+mod io;
+mod large_operands;
+#[cfg(feature = "math")]
+mod math;
+mod metatable;
+#[cfg(feature = "os")]
+mod os;
+#[cfg(feature = "package")]
+mod package;
+#[cfg(any(feature = "float", feature = "opcode_counts"))]
+mod profile;
+#[cfg(feature = "float")]
+mod state_hash;
+#[cfg(feature = "string")]
+mod string;
+#[cfg(feature = "table")]
+mod table;
 
 fn compare_program(
     program: &Program,
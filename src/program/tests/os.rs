@@ -0,0 +1,262 @@
+use crate::Error;
+
+#[test]
+fn date() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local a = os.date("%Y-%m-%d %H:%M:%S", 0)
+assert(a == "1970-01-01 00:00:00")
+local b = os.date("!%Y-%m-%d %H:%M:%S", 1709210096)
+assert(b == "2024-02-29 12:34:56")
+local c = os.date("%c", 1709210096)
+assert(c == "Thu Feb 29 12:34:56 2024")
+local d = os.date("%A %B %j %y %I%p %%", 1709210096)
+assert(d == "Thursday February 060 24 12PM %")
+local e = os.date("%F %T", -1)
+assert(e == "1969-12-31 23:59:59")
+local f = os.date("%x", 951782400)
+assert(f == "02/29/00")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse("os.date(\"%Q\", 0)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn date_table() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local t = os.date("*t", 1735689599)
+local year, month, day, hour, min, sec = 2024, 12, 31, 23, 59, 59
+local wday, yday = 3, 366
+assert(t.year == year)
+assert(t.month == month)
+assert(t.day == day)
+assert(t.hour == hour)
+assert(t.min == min)
+assert(t.sec == sec)
+assert(t.wday == wday)
+assert(t.yday == yday)
+assert(t.isdst == false)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn date_clock() {
+    use crate::environment::Environment;
+
+    fn clock() -> i64 {
+        1_000_000_000
+    }
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    // Without a clock, the current time is the Unix epoch
+    let program = crate::Program::parse(
+        r#"
+local a = os.date("%F %T")
+assert(a == "1970-01-01 00:00:00")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    let env = Environment::builder().clock(clock).build().unwrap();
+    let program = crate::Program::parse(
+        r#"
+local a = os.date("%F %T")
+assert(a == "2001-09-09 01:46:40")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn time_clock() {
+    use crate::environment::{Clock, Environment};
+
+    struct FixedClock;
+
+    impl Clock for FixedClock {
+        fn time(&self) -> i64 {
+            1_000_000_000
+        }
+
+        fn processor_time(&self) -> f64 {
+            0.25
+        }
+    }
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local a = os.time()
+assert(a == 0)
+local b = os.clock()
+assert(b == 0.0)
+local c = os.time({year = 2024, month = 2, day = 29, hour = 12, min = 34, sec = 56})
+assert(c == 1709210096)
+local d = os.time({year = 1970, month = 1, day = 1})
+assert(d == 43200)
+local e = os.time({year = 2023, month = 14, day = 29, hour = 12, min = 34, sec = 56})
+assert(e == c)
+local f = os.time({year = 1969, month = 12, day = 31, hour = 23, min = 59, sec = 59})
+assert(f == -1)
+local g = os.date("%F %T", os.time({year = 2000, month = 2, day = 30, hour = 0}))
+assert(g == "2000-03-01 00:00:00")
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse("os.time({year = 2000, month = 1})\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+
+    // Fields that don't fit a C `int` are out of bounds
+    for (source, message) in [
+        (
+            "os.time({year = 9223372036854775807, month = 1, day = 1})\n",
+            "field 'year' is out-of-bound",
+        ),
+        (
+            "os.time({year = -9223372036854775807 - 1, month = 1, day = 1})\n",
+            "field 'year' is out-of-bound",
+        ),
+        (
+            "os.time({year = 2000, month = -9223372036854775807 - 1, day = 1})\n",
+            "field 'month' is out-of-bound",
+        ),
+        (
+            "os.time({year = 2000, month = 1, day = 9223372036854775807})\n",
+            "field 'day' is out-of-bound",
+        ),
+        (
+            "os.time({year = 2000, month = 1, day = 1, sec = -9223372036854775807 - 1})\n",
+            "field 'sec' is out-of-bound",
+        ),
+        (
+            "os.time({year = 2147483647 + 1901, month = 1, day = 1})\n",
+            "field 'year' is out-of-bound",
+        ),
+    ] {
+        match crate::Lua::run_program(crate::Program::parse(source).unwrap())
+            .map_err(Error::unlocated)
+        {
+            Ok(_) => panic!("`{source}` should fail."),
+            Err(Error::BadArgument(1, reason)) => assert_eq!(reason, message),
+            Err(err) => panic!("`{source}` should fail with BadArgument, but failed with `{err}`."),
+        }
+    }
+    let program = crate::Program::parse(
+        "local t = os.time({year = 2147483647 + 1900, month = 2147483647 + 1, day = -2147483647 - 1})\nassert(t > 0)\n",
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    let env = Environment::builder().clock(FixedClock).build().unwrap();
+    let program = crate::Program::parse(
+        r#"
+local a = os.time()
+assert(a == 1000000000)
+local b = os.clock()
+assert(b == 0.25)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn difftime_monotonic() {
+    use core::cell::Cell;
+
+    use alloc::rc::Rc;
+
+    use crate::environment::{Clock, Environment};
+
+    /// Timer of a device without a calendar, advanced by the test
+    struct Timer(Rc<Cell<u64>>);
+
+    impl Clock for Timer {
+        fn time(&self) -> i64 {
+            0
+        }
+
+        fn monotonic_ns(&self) -> u64 {
+            self.0.get()
+        }
+    }
+
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local a = os.difftime(1709210096, 1709210000)
+assert(a == 96.0)
+assert(tostring(a) == "96.0")
+local b = os.difftime(10)
+assert(b == 10.0)
+local c = os.difftime(5.0, 15)
+assert(c == -10.0)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    for failing in [
+        "os.difftime()\n",
+        "os.difftime(1.5)\n",
+        "os.difftime(1, {})\n",
+    ] {
+        let program = crate::Program::parse(failing).unwrap();
+        match crate::Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::BadArgument(..) | Error::Expected(..)) => (),
+            Err(err) => panic!(
+                "Should fail with BadArgument or Expected, but failed with `{}`.",
+                err
+            ),
+        }
+    }
+
+    let nanos = Rc::new(Cell::new(1_500_000_000));
+    let env = Environment::builder()
+        .clock(Timer(nanos.clone()))
+        .build()
+        .unwrap();
+    let mut lua = crate::Lua::new(env);
+    let program = crate::Program::parse("return os.clock()\n").unwrap();
+    assert_eq!(
+        lua.execute(program.clone()).unwrap(),
+        [crate::value::Value::Float(1.5)]
+    );
+    nanos.set(4_000_000_000);
+    assert_eq!(
+        lua.execute(program).unwrap(),
+        [crate::value::Value::Float(4.0)]
+    );
+}
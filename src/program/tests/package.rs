@@ -0,0 +1,141 @@
+use alloc::string::{String, ToString};
+
+use crate::{Error, Lua, environment::Environment};
+
+fn module_source(name: &str) -> Option<String> {
+    let source = match name {
+        "counter" => {
+            r#"
+local name, extra = ...
+local count = 0
+local M = {}
+M.name = name
+function M.increment()
+    count = count + 1
+    return count
+end
+return M
+"#
+        }
+        "double" => "return function(x) return x * 2 end",
+        "side_effect" => "local before = loads\nloads = before + 1",
+        "broken" => "local = 1",
+        _ => return None,
+    };
+    Some(source.to_string())
+}
+
+#[test]
+fn require_preload() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local preload = package.preload
+preload.greet = function(name, extra)
+    return {name = name, extra = extra}
+end
+local m = require("greet")
+assert(m.name == "greet")
+assert(m.extra == ":preload:")
+local again = require("greet")
+assert(again == m)
+local loaded = package.loaded
+assert(loaded.greet == m)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn require_source() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let env = Environment::builder()
+        .module_source(module_source)
+        .build()
+        .unwrap();
+    let program = crate::Program::parse(
+        r#"
+local counter = require("counter")
+assert(counter.name == "counter")
+local one = counter.increment()
+assert(one == 1)
+local same = require("counter")
+assert(same == counter)
+local two = same.increment()
+assert(two == 2)
+local double = require("double")
+local four = double(2)
+assert(four == 4)
+loads = 0
+local loaded = require("side_effect")
+assert(loaded == true)
+require("side_effect")
+assert(loads == 1)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program_with_env(program, env).unwrap();
+}
+
+#[test]
+fn require_per_vm() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+loads = 0
+require("side_effect")
+require("side_effect")
+return loads
+"#,
+    )
+    .unwrap();
+
+    for _ in 0..2 {
+        let env = Environment::builder()
+            .module_source(module_source)
+            .build()
+            .unwrap();
+        let mut lua = Lua::new(env);
+        assert_eq!(lua.execute(program.clone()).unwrap(), [1i64.into()]);
+    }
+}
+
+#[test]
+fn require_failures() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let env = Environment::builder()
+        .module_source(module_source)
+        .build()
+        .unwrap();
+    let program = crate::Program::parse("require(\"missing\")\n").unwrap();
+    match crate::Lua::run_program_with_env(program, env).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::ModuleNotFound(name, tried)) => {
+            assert_eq!(name, "missing");
+            assert_eq!(
+                tried,
+                "\n\tno field package.preload['missing']\n\tno source for module 'missing'"
+            );
+        }
+        Err(err) => panic!(
+            "Should fail with ModuleNotFound, but failed with `{}`.",
+            err
+        ),
+    }
+
+    let env = Environment::builder()
+        .module_source(module_source)
+        .build()
+        .unwrap();
+    let program = crate::Program::parse("require(\"broken\")\n").unwrap();
+    match crate::Lua::run_program_with_env(program, env).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::ModuleLoad(name, _)) => assert_eq!(name, "broken"),
+        Err(err) => panic!("Should fail with ModuleLoad, but failed with `{}`.", err),
+    }
+}
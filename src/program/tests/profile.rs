@@ -0,0 +1,137 @@
+use alloc::{string::ToString, vec, vec::Vec};
+
+use crate::{Lua, Program, value::Value};
+
+#[cfg(feature = "float")]
+#[test]
+fn call_profile() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    lua.execute(Program::parse("local a = 1\n").unwrap())
+        .unwrap();
+    assert!(lua.call_profile().is_none());
+
+    lua.set_profiling(true);
+    lua.execute(
+        Program::parse(
+            r#"
+local function id(a)
+    return a
+end
+local values = {1, "a", 2}
+for i = 1, 3 do
+    id(values[i])
+end
+local function pair(a, b)
+    local r = id(b)
+    return r
+end
+pair(1, 2)
+pair(3, 4)
+"#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let profile = lua.call_profile().unwrap();
+    let summary = profile
+        .sites()
+        .iter()
+        .map(|site| {
+            (
+                site.chunk,
+                site.path.clone(),
+                site.calls,
+                site.argument_counts.clone(),
+                site.argument_types.clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                0,
+                vec![],
+                3,
+                vec![(1, 3)],
+                vec![vec![("integer", 2), ("string", 1)]]
+            ),
+            (0, vec![1], 2, vec![(1, 2)], vec![vec![("integer", 2)]]),
+            (
+                0,
+                vec![],
+                1,
+                vec![(2, 1)],
+                vec![vec![("integer", 1)], vec![("integer", 1)]]
+            ),
+            (
+                0,
+                vec![],
+                1,
+                vec![(2, 1)],
+                vec![vec![("integer", 1)], vec![("integer", 1)]]
+            ),
+        ]
+    );
+    assert_eq!(profile.polymorphic().count(), 1);
+
+    // Profiles keep accumulating across chunks until profiling stops
+    lua.execute(Program::parse("print(\"a\", 1.5)\n").unwrap())
+        .unwrap();
+    let profile = lua.call_profile().unwrap();
+    let print = profile.sites().iter().find(|site| site.chunk == 1).unwrap();
+    assert_eq!(
+        print.argument_types,
+        vec![vec![("string", 1)], vec![("float", 1)]]
+    );
+    assert!(profile.to_string().contains("chunk 1 main byte_codes["));
+
+    lua.set_profiling(false);
+    assert!(lua.call_profile().is_none());
+    assert_eq!(
+        lua.execute(Program::parse("return 1\n").unwrap()).unwrap(),
+        vec![Value::Integer(1)]
+    );
+}
+
+#[cfg(feature = "opcode_counts")]
+#[test]
+fn opcode_counts() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut lua = Lua::default();
+    assert_eq!(lua.opcode_counts().total(), 0);
+
+    lua.execute(
+        Program::parse(
+            r#"
+local sum = 0
+for i = 1, 10 do
+    sum = sum + i
+end
+return sum
+"#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let counts = lua.opcode_counts();
+    assert_eq!(counts.count("ADD"), 10);
+    assert_eq!(counts.count("FORLOOP"), 10);
+    assert_eq!(counts.count("FORPREP"), 1);
+    assert_eq!(counts.count("GETTABUP"), 0);
+    assert_eq!(counts.custom(), 0);
+    assert_eq!(
+        counts.total(),
+        counts.iter().map(|(_, count)| count).sum::<u64>()
+    );
+    assert_eq!(counts.iter().next().map(|(_, count)| count), Some(10));
+    assert!(counts.to_string().contains("FORLOOP"));
+
+    lua.reset_opcode_counts();
+    assert_eq!(lua.opcode_counts().total(), 0);
+}
@@ -0,0 +1,188 @@
+//! Regression snapshots of the observable state left by some scripts.
+//!
+//! Each script under `state_hash/` is run on the VM, and the observable
+//! state it leaves behind, its return values, the globals it sets, and what
+//! it prints, is serialized and hashed. The hashes are compared with the ones
+//! on `state_hash/hashes.txt`, which were recorded by this crate, so they only
+//! catch changes of behavior, not divergences from Lua 5.4.
+//!
+//! `state_hash/record.lua` serializes the state on the same format, running
+//! it with Lua 5.4 replaces the snapshots with the ones of Lua 5.4.
+
+use core::{cell::RefCell, fmt::Write};
+
+use alloc::{
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    Lua, Program,
+    closure::{NativeClosure, NativeClosureReturn},
+    environment::Environment,
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+const SCRIPTS: &[(&str, &str)] = &[
+    ("arithmetic", include_str!("state_hash/arithmetic.lua")),
+    ("closures", include_str!("state_hash/closures.lua")),
+    ("control_flow", include_str!("state_hash/control_flow.lua")),
+    ("metatables", include_str!("state_hash/metatables.lua")),
+    ("strings", include_str!("state_hash/strings.lua")),
+    ("tables", include_str!("state_hash/tables.lua")),
+];
+
+const RECORDED_HASHES: &str = include_str!("state_hash/hashes.txt");
+
+/// Global holding the lines printed by the script
+const OUTPUT: &str = "print output";
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+fn capture_print(vm: &mut Lua) -> NativeClosureReturn {
+    let top_stack = vm.get_stack_frame()?;
+    let args = vm.stack[top_stack.stack_frame..].to_vec();
+
+    let globals = vm.globals().clone();
+    let tostring = globals.borrow().get(ValueKey("tostring".into())).clone();
+    let mut parts = Vec::with_capacity(args.len());
+    for arg in args {
+        let string = vm.call_value(tostring.clone(), &[arg])?;
+        parts.push(string.first().map(Value::to_string).unwrap_or_default());
+    }
+
+    let Value::Table(output) = globals.borrow().get(ValueKey(OUTPUT.into())).clone() else {
+        unreachable!("Output table should not be removed by the scripts.");
+    };
+    let mut output = output.borrow_mut();
+    let line = parts.join("\t");
+    output.array.push(line.as_str().into());
+    Ok(0)
+}
+
+/// Runs `source` and serializes the state it leaves on the same format
+/// as `record.lua`
+fn observable_state(source: &str) -> Result<String, String> {
+    let output = Rc::new(RefCell::new(Table::new(0, 0)));
+    let env = Environment::builder()
+        .function("print", capture_print as NativeClosure)
+        .global(OUTPUT, Value::Table(output.clone()))
+        .build()
+        .unwrap();
+    let initial_globals = env.borrow().table.clone();
+
+    let mut lua = Lua::new(env);
+    let program = Program::parse(source).map_err(|err| err.to_string())?;
+    let results = lua.execute(program).map_err(|err| err.to_string())?;
+
+    let mut state = String::new();
+    for (i, result) in results.iter().enumerate() {
+        let _ = writeln!(state, "return {} {}", i + 1, serialize(result));
+    }
+    let mut globals = lua
+        .globals()
+        .borrow()
+        .table
+        .iter()
+        .filter(|entry| !initial_globals.contains(entry))
+        .map(|(key, value)| format!("global {} {}\n", serialize(&key.0), serialize(value)))
+        .collect::<Vec<_>>();
+    globals.sort();
+    state.extend(globals);
+    for line in output.borrow().array.iter() {
+        let _ = writeln!(state, "output {}", line);
+    }
+
+    Ok(state)
+}
+
+fn serialize(value: &Value) -> String {
+    let mut visiting = Vec::new();
+    serialize_value(value, &mut visiting)
+}
+
+fn serialize_value(value: &Value, visiting: &mut Vec<*const RefCell<Table>>) -> String {
+    match value {
+        Value::Nil => "nil".to_string(),
+        Value::Boolean(boolean) => boolean.to_string(),
+        Value::Integer(integer) => format!("i:{}", integer),
+        Value::Float(float) => format!("f:{}", float.to_bits() as i64),
+        Value::ShortString(_) | Value::String(_) => {
+            let string = value.to_string();
+            format!("s{}:{}", string.len(), string)
+        }
+        Value::Table(table) => {
+            if visiting.contains(&Rc::as_ptr(table)) {
+                return "cycle".to_string();
+            }
+            visiting.push(Rc::as_ptr(table));
+            let table = table.borrow();
+            let mut entries = table
+                .array
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (Value::Integer(i as i64 + 1), item))
+                .chain(table.table.iter().map(|(key, item)| (key.0.clone(), item)))
+                .filter(|(_, item)| !matches!(item, Value::Nil))
+                .map(|(key, item)| {
+                    (
+                        serialize_value(&key, visiting),
+                        serialize_value(item, visiting),
+                    )
+                })
+                .collect::<Vec<_>>();
+            entries.sort();
+            visiting.pop();
+            let mut serialized = String::from("{");
+            for (key, item) in entries {
+                let _ = write!(serialized, "{}={};", key, item);
+            }
+            serialized.push('}');
+            serialized
+        }
+        Value::Closure(_) => "function".to_string(),
+        Value::UserData(_) => "userdata".to_string(),
+    }
+}
+
+/// FNV-1a, which is simple enough to be implemented on Lua
+fn hash(state: &str) -> u64 {
+    state.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[test]
+fn recorded_hashes() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let mut failures = Vec::new();
+    for (name, source) in SCRIPTS {
+        let Some(recorded) = RECORDED_HASHES.lines().find_map(|line| {
+            line.strip_prefix(name)
+                .and_then(|hash| hash.strip_prefix(' '))
+                .and_then(|hash| u64::from_str_radix(hash, 16).ok())
+        }) else {
+            failures.push(format!("`{}` has no recorded hash.", name));
+            continue;
+        };
+
+        match observable_state(source) {
+            Ok(state) if hash(&state) == recorded => (),
+            Ok(state) => failures.push(format!(
+                "`{}` hashed to {:016x} instead of {:016x}, with state:\n{}",
+                name,
+                hash(&state),
+                recorded,
+                state
+            )),
+            Err(err) => failures.push(format!("`{}` failed with `{}`.", name, err)),
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
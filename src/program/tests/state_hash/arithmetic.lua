@@ -0,0 +1,19 @@
+local a, b = 7, 2
+sum = a + b
+difference = a - b
+product = a * b
+quotient = a / b
+power = a ^ b
+local negated = -a
+negative = negated
+local half = 1.5
+float_sum = half + 2.25
+mixed = a + 0.5
+local low, high = a & 3, b << 4
+bits = low | high
+xor = a ~ b
+local big = 256
+shifted = big >> 3
+print(sum, quotient, power)
+local area = a * b
+return area + 1, b / 4
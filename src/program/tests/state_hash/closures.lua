@@ -0,0 +1,33 @@
+local function counter()
+    local count = 0
+    return function()
+        count = count + 1
+        return count
+    end
+end
+local c1 = counter()
+local c2 = counter()
+c1()
+c1()
+c2()
+local a, b = c1(), c2()
+first = a
+second = b
+local function fib(n)
+    local a, b = 0, 1
+    for _ = 1, n do
+        local next = a + b
+        a = b
+        b = next
+    end
+    return a
+end
+local f = fib(15)
+fibonacci = f
+local function varargs(...)
+    local a, b, c = ...
+    return c, b, a
+end
+print(varargs(1, 2, 3))
+local p, q, r = varargs("x", "y")
+return p, q, r
@@ -0,0 +1,34 @@
+local total = 0
+for i = 1, 10 do
+    total = total + i
+end
+local countdown = ""
+for i = 10, 1, -3 do
+    countdown = countdown .. i .. ","
+end
+local n = 0
+while n < 5 do
+    n = n + 1
+end
+local m = 0
+repeat
+    m = m + 2
+until m > 7
+local steps = 0
+for i = 1, 100 do
+    if i <= 4 then
+        steps = steps + 1
+    end
+end
+local label
+if total > 50 then
+    label = "big"
+elseif total > 10 then
+    label = "medium"
+else
+    label = "small"
+end
+loops = {total, n, m, steps}
+result = {label = label, countdown = countdown}
+print(total, n, m, steps, label)
+return total > 50 and "yes" or "no"
@@ -0,0 +1,20 @@
+local mt = {}
+mt.__eq = function(a, b) return a.v == b.v end
+mt.__lt = function(a, b) return a.v < b.v end
+mt.__le = function(a, b) return a.v <= b.v end
+mt.__tostring = function(a) return "V" .. a.v end
+mt.__call = function(self, x) return self.v * x end
+local x = setmetatable({v = 1}, mt)
+local y = setmetatable({v = 2}, mt)
+local z = setmetatable({v = 1}, mt)
+equal = x == z
+less = x < y
+less_equal = y <= x
+local product = y(21)
+called = product
+print(x, y)
+local text = tostring(x) .. "|" .. tostring(y)
+labels = text
+local locked = getmetatable(setmetatable({}, {__metatable = "locked"}))
+protected = locked
+return x == y, x ~= z
@@ -0,0 +1,102 @@
+-- Records the hashes checked by `state_hash.rs` with Lua 5.4, run it from
+-- this directory:
+--
+--     lua5.4 record.lua *.lua > hashes.txt
+--
+-- The hashes on `hashes.txt` were recorded by no_deps_lua, its first line
+-- says which interpreter recorded them. The serialization must be kept in
+-- sync with `state_hash.rs`.
+
+assert(_VERSION == "Lua 5.4", "hashes must be recorded with Lua 5.4, not " .. _VERSION)
+
+local FNV_OFFSET_BASIS = 0xcbf29ce484222325
+local FNV_PRIME = 0x100000001b3
+
+local serialize
+
+local function serialize_table(value, visiting)
+    if visiting[value] then
+        return "cycle"
+    end
+    visiting[value] = true
+    local entries = {}
+    for key, item in pairs(value) do
+        entries[#entries + 1] = {serialize(key, visiting), serialize(item, visiting)}
+    end
+    table.sort(entries, function(lhs, rhs) return lhs[1] < rhs[1] end)
+    local parts = {}
+    for _, entry in ipairs(entries) do
+        parts[#parts + 1] = entry[1] .. "=" .. entry[2] .. ";"
+    end
+    visiting[value] = nil
+    return "{" .. table.concat(parts) .. "}"
+end
+
+function serialize(value, visiting)
+    local value_type = math.type(value) or type(value)
+    if value_type == "nil" or value_type == "boolean" then
+        return tostring(value)
+    elseif value_type == "integer" then
+        return "i:" .. string.format("%d", value)
+    elseif value_type == "float" then
+        return "f:" .. string.format("%d", string.unpack("<i8", string.pack("<d", value)))
+    elseif value_type == "string" then
+        return "s" .. #value .. ":" .. value
+    elseif value_type == "table" then
+        return serialize_table(value, visiting)
+    else
+        return value_type
+    end
+end
+
+local function hash(state)
+    local result = FNV_OFFSET_BASIS
+    for i = 1, #state do
+        result = (result ~ string.byte(state, i)) * FNV_PRIME
+    end
+    return string.format("%016x", result)
+end
+
+local function record(path)
+    local file = assert(io.open(path, "rb"))
+    local source = file:read("a")
+    file:close()
+
+    local output = {}
+    local function capture_print(...)
+        local parts = table.pack(...)
+        for i = 1, parts.n do
+            parts[i] = tostring(parts[i])
+        end
+        output[#output + 1] = table.concat(parts, "\t", 1, parts.n)
+    end
+    local env = setmetatable({print = capture_print}, {__index = _G})
+
+    local chunk = assert(load(source, "=" .. path, "t", env))
+    local results = table.pack(chunk())
+
+    local lines = {}
+    for i = 1, results.n do
+        lines[#lines + 1] = "return " .. i .. " " .. serialize(results[i], {}) .. "\n"
+    end
+    local globals = {}
+    for key, value in pairs(env) do
+        if value ~= capture_print then
+            globals[#globals + 1] = "global " .. serialize(key, {}) .. " " .. serialize(value, {}) .. "\n"
+        end
+    end
+    table.sort(globals)
+    table.move(globals, 1, #globals, #lines + 1, lines)
+    for _, line in ipairs(output) do
+        lines[#lines + 1] = "output " .. line .. "\n"
+    end
+
+    print(path:match("([^/\\]+)%.lua$") .. " " .. hash(table.concat(lines)))
+end
+
+print("# recorded with " .. _VERSION)
+for _, path in ipairs(arg) do
+    if not path:match("record%.lua$") then
+        record(path)
+    end
+end
@@ -0,0 +1,11 @@
+local greeting = "hello"
+local name = "world"
+message = greeting .. ", " .. name .. "!"
+local size = #message
+length = size
+local float, negative = 2.5, -3
+numbers = 1 .. "|" .. float .. "|" .. negative
+long = "a string that is too long to fit on a short string"
+print(message, size)
+print(long)
+return #long, greeting == "hello", name < greeting
@@ -0,0 +1,17 @@
+local list = {10, 20, 30, 40, 50}
+local record = {name = "lua", version = 54, nested = {deep = "yes"}}
+record.extra = "field"
+record.name = "renamed"
+local sum = 0
+local size = 5
+for i = 1, size do
+    sum = sum + list[i]
+end
+items = list
+info = record
+counts = {sum, size}
+local cyclic = {}
+cyclic.self = cyclic
+loop = cyclic
+print(size, sum)
+return list[2], record.nested.deep
@@ -0,0 +1,174 @@
+use alloc::vec;
+
+use crate::{
+    Error, Lua,
+    environment::{Environment, Library},
+};
+
+#[test]
+fn functions() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+assert(string.len("hello") == 5)
+assert(string.upper("Hello, World") == "HELLO, WORLD")
+assert(string.lower("Hello, World") == "hello, world")
+assert(string.rep("ab", 3) == "ababab")
+assert(string.rep("ab", 3, ", ") == "ab, ab, ab")
+assert(string.rep("ab", 0) == "")
+assert(string.rep("ab", -1) == "")
+assert(string.reverse("abc") == "cba")
+assert(string.sub("hello", 2, 4) == "ell")
+assert(string.sub("hello", -3) == "llo")
+assert(string.sub("hello", 0) == "hello")
+assert(string.sub("hello", 2, -2) == "ell")
+assert(string.sub("hello", 4, 2) == "")
+assert(string.sub("hello", -10, 10) == "hello")
+assert(string.byte("A") == 65)
+local a, b, c = string.byte("abc", 1, -1)
+assert(a == 97 and b == 98 and c == 99)
+assert(string.byte("abc", 10) == nil)
+assert(string.char(72, 105) == "Hi")
+assert(string.char() == "")
+assert(string.len(12) == 2)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse("string.char(256)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn methods() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local s = "Hello"
+assert(s:upper() == "HELLO")
+assert(("abc"):upper() == "ABC")
+local tail = s:sub(2)
+assert(tail:len() == 4)
+assert(s.len == string.len)
+assert(s["upper"] == string.upper)
+assert(getmetatable("").__index == string)
+
+function string.shout(s)
+    return s:upper() .. "!"
+end
+assert(s:shout() == "HELLO!")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    // Only strings have the metatable
+    let program = crate::Program::parse("local n = 5\nreturn n:upper()\n").unwrap();
+    assert!(matches!(
+        crate::Lua::run_program(program).map_err(Error::unlocated),
+        Err(Error::ExpectedTable("integer"))
+    ));
+
+    // Without the library strings have no methods
+    let env = Environment::builder()
+        .library(Library::String, false)
+        .build()
+        .unwrap();
+    let mut lua = Lua::new(env);
+    let program = crate::Program::parse("return getmetatable(\"\")\n").unwrap();
+    assert_eq!(lua.execute(program).unwrap(), vec![crate::Value::Nil]);
+    let program = crate::Program::parse("return (\"abc\"):upper()\n").unwrap();
+    assert!(matches!(
+        lua.execute(program).map_err(Error::unlocated),
+        Err(Error::ExpectedTable("string"))
+    ));
+}
+
+#[test]
+fn bytes() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+assert(#"\200" == 1)
+assert(#"\xff\xfe" == 2)
+assert(string.byte("\xff") == 255)
+assert(string.char(255, 0, 128) == "\xff\0\x80")
+assert("\xff" ~= "\u{FFFD}")
+assert("a\0" ~= "a")
+assert("a\0" ~= "a\0\0")
+assert("\x80" < "\xff")
+
+local t = {}
+t["\xff"] = 1
+t["\xfe"] = 2
+t["\xff\0"] = 3
+local key = string.char(255)
+t[key] = t[key] + 10
+assert(t["\xff"] == 11 and t["\xfe"] == 2 and t["\xff\0"] == 3)
+
+local long = string.rep("\xff", 20)
+t[long] = "long"
+assert(t[string.rep("\xff", 10) .. string.rep("\xff", 10)] == "long")
+assert(#long == 20 and long:byte(20) == 255)
+
+local x = "\x80"
+assert(#(x .. "\x81" .. 1) == 3)
+assert(("\x80" .. "\x81"):byte(2) == 129)
+assert(table.concat({"\xff", "\xfe"}, "\0") == "\xff\0\xfe")
+assert(tostring("\xff") == "\xff")
+assert(("\xff\xfe"):reverse() == "\xfe\xff")
+assert(("\xff\xfe\xfd"):sub(2, 2) == "\xfe")
+assert(("\xe4"):upper() == "\xe4")
+return "\xff\0\xfe", long
+"#,
+    )
+    .unwrap();
+
+    let values = crate::Lua::default().execute(program).unwrap();
+    assert_eq!(values[0].as_bytes(), Some(b"\xff\0\xfe".as_slice()));
+    assert_eq!(values[1].as_bytes(), Some([0xff; 20].as_slice()));
+}
+
+#[test]
+fn dump() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r##"
+local function add(a, b) return a + b end
+local loaded = load(string.dump(add))
+assert(loaded(1, 2) == 3)
+
+local function count(...) return select("#", ...) end
+local counter = load(string.dump(count, true), "counter", "b")
+assert(counter(1, nil, 3) == 3)
+
+local function nested(n)
+    local function square(x) return x * x end
+    return square(n) + 1
+end
+assert(load(string.dump(nested))(4) == 17)
+assert(string.dump(nested, true) ~= string.dump(nested))
+"##,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse("return string.dump(print)\n").unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(1, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+}
@@ -0,0 +1,265 @@
+use crate::Error;
+
+#[cfg(feature = "float")]
+#[test]
+fn insert_remove_concat() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local t = {1, 2, 3}
+table.insert(t, 4)
+table.insert(t, 1, 0)
+local a = table.concat(t, ", ")
+assert(a == "0, 1, 2, 3, 4")
+local zero, four = 0, 4
+local last = table.remove(t)
+assert(last == four)
+local first = table.remove(t, 1)
+assert(first == zero)
+local b = table.concat(t)
+assert(b == "123")
+local c = table.concat(t, "-", 2, 3)
+assert(c == "2-3")
+local d = table.concat({}, "-")
+assert(d == "")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse(
+        r#"
+table.insert({1, 2}, 5, 3)
+"#,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::BadArgument(2, _)) => (),
+        Err(err) => panic!("Should fail with BadArgument, but failed with `{}`.", err),
+    }
+
+    // Fails on the first missing value, without going through the range
+    for source in [
+        "table.concat({}, \"\", 1, 1e11)\n",
+        "table.concat({\"a\", 1, {}}, \"\", 1, 1e11)\n",
+    ] {
+        match crate::Lua::run_program(crate::Program::parse(source).unwrap())
+            .map_err(Error::unlocated)
+        {
+            Ok(_) => panic!("`{source}` should fail."),
+            Err(Error::ConcatOperand(_)) => (),
+            Err(err) => {
+                panic!("`{source}` should fail with ConcatOperand, but failed with `{err}`.")
+            }
+        }
+    }
+}
+
+#[test]
+fn pack_unpack_move() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local three = 3
+local p = table.pack("a", "b", "c")
+local n = p.n
+assert(n == three)
+local s = table.concat(p, "")
+assert(s == "abc")
+local a, b, c = table.unpack({"x", "y", "z"})
+assert(a == "x")
+assert(b == "y")
+assert(c == "z")
+local d, e = table.unpack({"x", "y", "z"}, 2)
+assert(d == "y")
+assert(e == "z")
+local m = table.move({1, 2, 3}, 1, 3, 3)
+local m_concat = table.concat(m, ",")
+assert(m_concat == "1,2,1,2,3")
+local other = table.move({1, 2, 3}, 2, 3, 1, {})
+local other_concat = table.concat(other, ",")
+assert(other_concat == "2,3")
+local far = table.move({1, 2, 3}, 1, 3, 1000000000, {})
+assert(#far == 0)
+assert(far[1000000002] == 3)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    // Ranges wider than an integer fail without overflowing
+    for source in [
+        "table.unpack({}, -9223372036854775807 - 1, 9223372036854775807)\n",
+        "table.unpack({}, -1, 9223372036854775807)\n",
+        "table.unpack({}, 0, 1000000)\n",
+    ] {
+        match crate::Lua::run_program(crate::Program::parse(source).unwrap())
+            .map_err(Error::unlocated)
+        {
+            Ok(_) => panic!("`{source}` should fail."),
+            Err(Error::BadArgument(3, _)) => (),
+            Err(err) => panic!("`{source}` should fail with BadArgument, but failed with `{err}`."),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn sort() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local t = {5, 2, 8, 1, 9, 3}
+table.sort(t)
+local a = table.concat(t, ",")
+assert(a == "1,2,3,5,8,9")
+
+local calls = 0
+table.sort(t, function(lhs, rhs)
+    calls = calls + 1
+    return lhs > rhs
+end)
+local b = table.concat(t, ",")
+assert(b == "9,8,5,3,2,1")
+local zero = 0
+assert(calls > zero)
+
+local names = {"banana", "cherry", "apple"}
+table.sort(names)
+local c = table.concat(names, " ")
+assert(c == "apple banana cherry")
+
+-- NaN is unordered, but still a number
+local nan = 0.0 / 0.0
+local mixed = {3, nan, 1.5, 2}
+table.sort(mixed)
+assert(#mixed == 4)
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+
+    let program = crate::Program::parse(
+        r#"
+table.sort({1, "a", 2})
+"#,
+    )
+    .unwrap();
+    match crate::Lua::run_program(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::RelationalOperand(_, _)) => (),
+        Err(err) => panic!(
+            "Should fail with RelationalOperand, but failed with `{}`.",
+            err
+        ),
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn concat_long_strings() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local part = "a string longer than fifteen bytes"
+local parts = {}
+for i = 1, 1000 do
+    table.insert(parts, part)
+end
+local joined = table.concat(parts, ", ")
+assert(#joined == 35998)
+local mixed = table.concat({part, 1, "!"}, " ")
+assert(mixed == "a string longer than fifteen bytes 1 !")
+local s = part .. 1 .. ", " .. 2.5 .. "!"
+assert(s == "a string longer than fifteen bytes1, 2.5!")
+"#,
+    )
+    .unwrap();
+
+    crate::Lua::run_program(program).unwrap();
+}
+
+#[test]
+fn freeze() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    let program = crate::Program::parse(
+        r#"
+local t = {1, 2, x = 3}
+local before = table.isfrozen(t)
+assert(before == false)
+local frozen = table.freeze(t)
+assert(frozen == t)
+local after = table.isfrozen(t)
+assert(after)
+local three = 3
+assert(t.x == three)
+"#,
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+
+    for source in [
+        "local t = table.freeze({})\nt.x = 1\n",
+        "local t = table.freeze({})\nlocal k = \"x\"\nt[k] = 1\n",
+        "local t = table.freeze({})\nt[1] = 1\n",
+        "local t = table.freeze({})\ntable.insert(t, 1)\n",
+        "local t = table.freeze({1})\ntable.remove(t)\n",
+        "local t = table.freeze({2, 1})\ntable.sort(t)\n",
+        "local t = table.freeze({})\ntable.move({1}, 1, 1, 1, t)\n",
+        "local t = table.freeze({})\nsetmetatable(t, {})\n",
+    ] {
+        let program = crate::Program::parse(source).unwrap();
+        match crate::Lua::run_program(program).map_err(Error::unlocated) {
+            Ok(_) => panic!("Should fail."),
+            Err(Error::FrozenTable) => (),
+            Err(err) => panic!("Should fail with FrozenTable, but failed with `{}`.", err),
+        }
+    }
+
+    // Globals frozen by the host
+    let mut lua = crate::Lua::default();
+    lua.globals().borrow_mut().freeze();
+    let program = crate::Program::parse("x = 1\n").unwrap();
+    match lua.execute(program).map_err(Error::unlocated) {
+        Ok(_) => panic!("Should fail."),
+        Err(Error::FrozenTable) => (),
+        Err(err) => panic!("Should fail with FrozenTable, but failed with `{}`.", err),
+    }
+}
+
+#[test]
+fn invalid_keys() {
+    let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+    for (source, key) in [
+        ("local t = {}\nt[nil] = 1\n", "nil"),
+        ("local t = {[nil] = 1}\n", "nil"),
+        ("local t = {}\nt[...] = 1\n", "nil"),
+        #[cfg(feature = "float")]
+        ("local t, zero = {}, 0\nt[zero / zero] = 1\n", "NaN"),
+    ] {
+        match crate::Lua::run_program(crate::Program::parse(source).unwrap())
+            .map_err(Error::unlocated)
+        {
+            Ok(_) => panic!("`{source}` should fail."),
+            Err(Error::InvalidKey(invalid)) => assert_eq!(invalid, key),
+            Err(err) => panic!("`{source}` should fail with InvalidKey, but failed with `{err}`."),
+        }
+    }
+
+    // Reading them is not an error
+    let program = crate::Program::parse(
+        "local t = {}\nlocal a, b = t[nil], t[...]\nassert(a == nil and b == nil)\n",
+    )
+    .unwrap();
+    crate::Lua::run_program(program).unwrap();
+}
@@ -0,0 +1,42 @@
+/// Where a closure finds an upvalue when it is created, the same
+/// information `luac` keeps for each upvalue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpvalueDescriptor {
+    in_stack: bool,
+    index: u8,
+}
+
+impl UpvalueDescriptor {
+    /// Upvalue that captures the local on `register` of the function
+    /// creating the closure
+    pub fn local(register: u8) -> Self {
+        Self {
+            in_stack: true,
+            index: register,
+        }
+    }
+
+    /// Upvalue shared with the upvalue at `index` of the function
+    /// creating the closure
+    pub fn upvalue(index: u8) -> Self {
+        Self {
+            in_stack: false,
+            index,
+        }
+    }
+
+    /// `_ENV` of a main function, which is provided by the VM
+    pub(crate) fn environment() -> Self {
+        Self::local(0)
+    }
+
+    /// If the upvalue captures a local of the function creating the closure
+    pub fn in_stack(&self) -> bool {
+        self.in_stack
+    }
+
+    /// Register of the local captured, or index of the upvalue shared
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::{vec, vec::Vec};
+use core::{
+    mem,
+    ops::{Deref, DerefMut, Range},
+};
+
+use crate::value::Value;
+
+/// Registers of the running functions
+///
+/// The values live on a buffer that only grows, and `top` marks where the
+/// registers in use end, so calls and returns only move `top` and the
+/// values they pass. Values past `top` are left by functions that returned,
+/// and are only dropped when they are overwritten, or by
+/// [`Stack::clear_unused`].
+#[derive(Debug)]
+pub struct Stack {
+    values: Vec<Value>,
+    top: usize,
+}
+
+impl Stack {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: vec![Value::Nil; capacity],
+            top: 0,
+        }
+    }
+
+    /// Moves the top of the stack, registers above the old top
+    /// become `nil`
+    pub fn set_top(&mut self, top: usize) {
+        if top > self.values.len() {
+            let len = top.max(self.values.len() * 2);
+            self.values.resize(len, Value::Nil);
+        }
+        if let Some(exposed) = self.values.get_mut(self.top..top) {
+            exposed.fill(Value::Nil);
+        }
+        self.top = top;
+    }
+
+    pub fn push(&mut self, value: Value) {
+        let top = self.top;
+        if top == self.values.len() {
+            self.set_top(top + 1);
+        } else {
+            self.top += 1;
+        }
+        self.values[top] = value;
+    }
+
+    pub fn extend(&mut self, values: impl IntoIterator<Item = Value>) {
+        for value in values {
+            self.push(value);
+        }
+    }
+
+    /// Pushes copies of the values on `range`
+    pub fn extend_from_within(&mut self, range: Range<usize>) {
+        for index in range {
+            let value = self.values[index].clone();
+            self.push(value);
+        }
+    }
+
+    /// Moves `count` values from `from` to `to`, leaving `nil` behind
+    pub fn move_values(&mut self, from: usize, to: usize, count: usize) {
+        if from == to {
+            return;
+        }
+        let mut move_value = |offset| {
+            let value = mem::replace(&mut self.values[from + offset], Value::Nil);
+            self.values[to + offset] = value;
+        };
+        // Overlapping ranges are moved from the side that is overwritten
+        if to < from {
+            (0..count).for_each(&mut move_value);
+        } else {
+            (0..count).rev().for_each(&mut move_value);
+        }
+    }
+
+    /// Inserts `value` at `index`, moving the values above it up
+    pub fn insert(&mut self, index: usize, value: Value) {
+        let top = self.top;
+        self.push(Value::Nil);
+        self.move_values(index, index + 1, top - index);
+        self.values[index] = value;
+    }
+
+    /// Removes the values from `start` on, returning them
+    pub fn split_off(&mut self, start: usize) -> Vec<Value> {
+        let values = self.values[start..self.top]
+            .iter_mut()
+            .map(|value| mem::replace(value, Value::Nil))
+            .collect();
+        self.top = start;
+        values
+    }
+
+    /// Drops the values left past the top of the stack, which would
+    /// keep the objects they reference alive
+    pub fn clear_unused(&mut self) {
+        self.values[self.top..].fill(Value::Nil);
+    }
+
+    /// Drops all values
+    pub fn clear(&mut self) {
+        self.top = 0;
+        self.clear_unused();
+    }
+}
+
+impl Deref for Stack {
+    type Target = [Value];
+
+    fn deref(&self) -> &Self::Target {
+        &self.values[..self.top]
+    }
+}
+
+impl DerefMut for Stack {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values[..self.top]
+    }
+}
@@ -7,9 +7,12 @@ use crate::closure::Upvalue;
 pub struct StackFrame {
     /// Function index
     pub function_index: usize,
+    /// Location on the stack of the function
+    pub function: usize,
     /// Program counter of the current program
     pub program_counter: usize,
-    /// The location on stack immediately after the function's location.
+    /// Location on the stack of the variadic arguments, which are followed
+    /// by the registers
     pub stack_frame: usize,
     /// Number of variadic arguments in each function stack.
     pub variadic_arguments: usize,
@@ -17,4 +20,11 @@ pub struct StackFrame {
     pub out_params: usize,
     /// Upvalues that target locals from this stack frame
     pub open_upvalues: Vec<Rc<RefCell<Upvalue>>>,
+    /// Locations on the stack of the variables declared with `<close>`,
+    /// in order of declaration
+    pub to_be_closed: Vec<usize>,
+    /// The function was called by a tail call, replacing its caller
+    pub tail_call: bool,
+    /// Last bytecode seen by the line hook
+    pub line_hooked_at: Option<usize>,
 }
@@ -1,42 +1,36 @@
-use core::{fmt::Display, ops::Deref};
+use core::{cmp::Ordering, fmt::Display, ops::Deref};
 
 use alloc::string::String;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// Bytes of a short string stored inline, strings can have any byte,
+/// including `\0`, so their length is kept apart
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StackStr<const N: usize> {
+    len: u8,
     buffer: [u8; N],
 }
 
 impl<const N: usize> StackStr<N> {
-    pub fn new(string: impl Deref<Target = str>) -> Result<Self, Error> {
-        if string.len() <= N {
-            let mut iter = string.as_bytes().iter();
-            let this = Self {
-                buffer: core::array::from_fn(|_| iter.next().copied().unwrap_or(b'\0')),
-            };
-            assert!(iter.next().is_none());
-            Ok(this)
-        } else {
-            Err(Error::StringTooBig)
+    pub fn new(string: impl AsRef<[u8]>) -> Result<Self, Error> {
+        let bytes = string.as_ref();
+        match u8::try_from(bytes.len()) {
+            Ok(len) if bytes.len() <= N => {
+                let mut buffer = [0; N];
+                buffer[..bytes.len()].copy_from_slice(bytes);
+                Ok(Self { len, buffer })
+            }
+            _ => Err(Error::StringTooBig),
         }
     }
 
     pub fn len(&self) -> usize {
-        self.buffer
-            .iter()
-            .rposition(|c| *c != b'\0')
-            .map(|pos| pos + 1)
-            .unwrap_or_default()
+        usize::from(self.len)
     }
 }
 
 impl<const N: usize> Display for StackStr<N> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}",
-            String::from_utf8_lossy(&self.buffer).trim_end_matches("\0")
-        )
+        write!(f, "{}", String::from_utf8_lossy(self))
     }
 }
 
@@ -44,13 +38,20 @@ impl<const N: usize> Deref for StackStr<N> {
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
-        &self.buffer
+        &self.buffer[..self.len()]
     }
 }
 
-impl<const N: usize> From<[u8; N]> for StackStr<N> {
-    fn from(buffer: [u8; N]) -> Self {
-        Self { buffer }
+/// Ordered by bytes, like strings stored on the heap
+impl<const N: usize> PartialOrd for StackStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for StackStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
     }
 }
 
@@ -106,6 +107,10 @@ mod tests {
 
         let stack_str = StackStr::<15>::new("aaaaaaaa\0aaaaaa").unwrap();
         assert_eq!(stack_str.len(), 15);
+
+        let stack_str = StackStr::<15>::new(b"\xff\0\0").unwrap();
+        assert_eq!(stack_str.len(), 3);
+        assert_eq!(&*stack_str, b"\xff\0\0");
     }
 
     #[test]
@@ -1,39 +1,418 @@
-use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use core::cell::RefCell;
 
-use crate::{Error, Lua, closure::NativeClosureReturn, value::Value};
+use alloc::{
+    borrow::ToOwned,
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    vec::Vec,
+};
 
-fn get_args(vm: &mut Lua) -> &[Value] {
-    let top_stack = vm.get_stack_frame();
-    let args_start = top_stack.stack_frame;
-    &vm.stack[args_start..]
-}
+use crate::{
+    Error, Lua, Program,
+    bytecode::Bytecode,
+    closure::{Closure, NativeClosure, NativeClosureReturn, Upvalue},
+    ext::ParseNumeral,
+    function::Function,
+    value::{Value, ValueKey},
+};
+
+use super::{get_args, return_args_from};
 
+/// `assert(v [, message, ...])`, fails with `message` as the error object
+/// if `v` is false, otherwise returns all of its arguments
 pub fn lib_assert(vm: &mut Lua) -> NativeClosureReturn {
+    match get_args(vm) {
+        [] => Err(Error::Expected(1, "value", "no value")),
+        [Value::Boolean(false) | Value::Nil] => Err(Error::Assertion("assertion failed!".into())),
+        // The message can be any value, and is the error object as it is
+        [Value::Boolean(false) | Value::Nil, message, ..] => Err(Error::Assertion(message.clone())),
+        // All arguments are returned on success
+        args => Ok(args.len()),
+    }
+}
+
+/// `collectgarbage([opt])`, collections are always stop-the-world,
+/// so `step` runs a full collection
+pub fn lib_collectgarbage(vm: &mut Lua) -> NativeClosureReturn {
+    let option = match get_args(vm).first() {
+        None | Some(Value::Nil) => String::from("collect"),
+        Some(option @ (Value::ShortString(_) | Value::String(_))) => option.to_string(),
+        Some(other) => {
+            return Err(Error::Expected(1, "string", other.static_type_name()));
+        }
+    };
+
+    let result = match option.as_str() {
+        "collect" => {
+            vm.collect_garbage();
+            Value::Integer(0)
+        }
+        #[cfg(feature = "float")]
+        "count" => Value::Float(
+            (vm.gc.memory_in_use(&vm.constant_pool) + vm.constant_pool.size()) as f64 / 1024.0,
+        ),
+        // Whole kilobytes on builds without floats
+        #[cfg(not(feature = "float"))]
+        "count" => Value::Integer(
+            i64::try_from(
+                (vm.gc.memory_in_use(&vm.constant_pool) + vm.constant_pool.size()) / 1024,
+            )
+            .unwrap_or(i64::MAX),
+        ),
+        "step" => {
+            vm.collect_garbage();
+            Value::Boolean(true)
+        }
+        "isrunning" => Value::Boolean(vm.gc.is_running()),
+        "stop" => {
+            vm.gc.set_running(false);
+            Value::Integer(0)
+        }
+        "restart" => {
+            vm.gc.set_running(true);
+            Value::Integer(0)
+        }
+        _ => return Err(Error::BadArgument(1, "invalid option")),
+    };
+    vm.set_stack(0, result)?;
+    Ok(1)
+}
+
+pub fn lib_getmetatable(vm: &mut Lua) -> NativeClosureReturn {
+    let metatable = match get_args(vm).first().and_then(|value| vm.metatable(value)) {
+        Some(metatable) => {
+            let protected = metatable
+                .borrow()
+                .get(ValueKey("__metatable".into()))
+                .clone();
+            if matches!(protected, Value::Nil) {
+                Value::Table(metatable)
+            } else {
+                protected
+            }
+        }
+        None => Value::Nil,
+    };
+    vm.set_stack(0, metatable)?;
+    Ok(1)
+}
+
+/// `ipairs(t)`, the iterator over `t[1]`, `t[2]`, ... up to the first
+/// `nil`, `t`, and `0`, to traverse `t` with a generic `for`
+pub fn lib_ipairs(vm: &mut Lua) -> NativeClosureReturn {
+    let Some(value) = get_args(vm).first().cloned() else {
+        return Err(Error::Expected(1, "table", "no value"));
+    };
+    vm.set_returns([
+        Value::from(ipairs_next as NativeClosure),
+        value,
+        Value::Integer(0),
+    ])
+}
+
+/// Iterator returned by `ipairs`, the index that follows `i` and the
+/// value of `t` on it, or `nil` if the value is `nil`
+///
+/// Generic `for`s over a table step it in place without calling it,
+/// unless the VM has a hook
+pub(crate) fn ipairs_next(vm: &mut Lua) -> NativeClosureReturn {
     let args = get_args(vm);
-    if matches!(args[0], Value::Boolean(false) | Value::Nil) {
-        let message = if let Some(message) = args.get(1) {
-            message.to_string()
-        } else {
-            "assertion failed!".to_owned()
-        };
-        log::error!("{message}");
-        Err(Error::Assertion)
-    } else {
-        Ok(args.len())
+    let value = args.first().cloned().unwrap_or(Value::Nil);
+    let index = match args.get(1) {
+        Some(Value::Integer(index)) => index.wrapping_add(1),
+        Some(other) => return Err(Error::Expected(2, "integer", other.static_type_name())),
+        None => return Err(Error::Expected(2, "integer", "no value")),
+    };
+    let next = match value {
+        Value::Table(table) => table.borrow().get(ValueKey(Value::Integer(index))).clone(),
+        value => Bytecode::index_metamethod(vm, value, Value::Integer(index))?,
+    };
+    match next {
+        Value::Nil => vm.set_returns([Value::Nil]),
+        next => vm.set_returns([Value::Integer(index), next]),
+    }
+}
+
+pub fn lib_load(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm).to_vec();
+
+    let source = match args.first() {
+        Some(chunk @ (Value::ShortString(_) | Value::String(_))) => {
+            chunk.as_bytes().unwrap_or_default().to_vec()
+        }
+        Some(reader @ Value::Closure(_)) => {
+            let mut source = Vec::new();
+            loop {
+                match vm.call_value(reader.clone(), &[])?.into_iter().next() {
+                    None | Some(Value::Nil) => break,
+                    Some(piece @ (Value::ShortString(_) | Value::String(_))) => {
+                        let piece = piece.as_bytes().unwrap_or_default();
+                        if piece.is_empty() {
+                            break;
+                        }
+                        source.extend_from_slice(piece);
+                    }
+                    Some(_) => return load_failure(vm, "reader function must return a string"),
+                }
+            }
+            source
+        }
+        _ => return Err(Error::BadArgument(1, "string or function expected")),
+    };
+    let mode = match args.get(2) {
+        None | Some(Value::Nil) => "bt".to_owned(),
+        Some(mode @ (Value::ShortString(_) | Value::String(_))) => mode.to_string(),
+        Some(_) => return Err(Error::BadArgument(3, "string expected")),
+    };
+
+    let binary = Program::is_binary_chunk(&source);
+    let function = match (binary, mode.contains('b'), mode.contains('t')) {
+        // Chunks written by `string.dump` keep the parameters of the function
+        (true, true, _) => Function::from_bytecode(&source),
+        (false, _, true) => {
+            let mut source = String::from_utf8_lossy(&source).into_owned();
+            // The parser expects statements to be terminated by a new line
+            if !source.ends_with('\n') {
+                source.push('\n');
+            }
+            match args.get(1) {
+                Some(name @ (Value::ShortString(_) | Value::String(_))) => {
+                    Program::parse_named_with_pool(
+                        &source,
+                        &name.to_string(),
+                        &mut vm.constant_pool,
+                    )
+                }
+                _ => Program::parse_with_pool(&source, &mut vm.constant_pool),
+            }
+            .map(|program| Function::new(program, 0, true))
+        }
+        (true, false, _) => {
+            return load_failure(
+                vm,
+                &format!("attempt to load a binary chunk (mode is '{mode}')"),
+            );
+        }
+        (false, _, false) => {
+            return load_failure(
+                vm,
+                &format!("attempt to load a text chunk (mode is '{mode}')"),
+            );
+        }
+    };
+    let function = match function {
+        Ok(function) => function,
+        Err(err) => return load_failure(vm, &err.to_string()),
+    };
+
+    // An explicit `nil` environment is kept, only a missing one
+    // defaults to the globals
+    let env = args
+        .get(3)
+        .cloned()
+        .unwrap_or_else(|| Value::Table(vm.globals.clone()));
+    if let Some(profiler) = vm.profiler.as_mut() {
+        profiler.add_chunk(function.program());
+    }
+    // The first upvalue is the environment, functions written by
+    // `string.dump` can have others, which start as `nil`
+    let upvalues = (0..function.program().upvalues().len().max(1))
+        .map(|upvalue| {
+            let value = if upvalue == 0 {
+                env.clone()
+            } else {
+                Value::Nil
+            };
+            Rc::new(RefCell::new(Upvalue::Closed(value)))
+        })
+        .collect();
+    let closure = Closure::new_lua(Rc::new(function), upvalues);
+    vm.set_stack(0, Value::Closure(Rc::new(closure)))?;
+    Ok(1)
+}
+
+/// Returns `nil` and the reason `load` failed
+fn load_failure(vm: &mut Lua, message: &str) -> NativeClosureReturn {
+    vm.set_stack(0, Value::Nil)?;
+    vm.set_stack(1, message.into())?;
+    Ok(2)
+}
+
+/// `next(table [, key])`, the key that follows `key` on a traversal of
+/// `table` and its value, or `nil` after the last key, see [`Table::next`]
+///
+/// [`Table::next`]: crate::Table::next
+pub fn lib_next(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let table = match args.first() {
+        Some(Value::Table(table)) => table.clone(),
+        Some(other) => return Err(Error::Expected(1, "table", other.static_type_name())),
+        None => return Err(Error::Expected(1, "table", "no value")),
+    };
+    let key = args.get(1).cloned().unwrap_or(Value::Nil);
+    let next = table.borrow().next(&key)?;
+    match next {
+        Some((key, value)) => vm.set_returns([key, value]),
+        None => vm.set_returns([Value::Nil]),
+    }
+}
+
+/// `pairs(t)`, the results of `__pairs` called with `t`, or `next`, `t`,
+/// and `nil` to traverse all fields of `t` with a generic `for`
+pub fn lib_pairs(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let value = args.first().cloned().unwrap_or(Value::Nil);
+    if let Some(metamethod) = value.metamethod("__pairs") {
+        let mut results = vm.call_value(metamethod, &[value])?;
+        results.resize(3, Value::Nil);
+        return vm.set_returns(results);
+    }
+    match value {
+        Value::Table(_) => {
+            vm.set_returns([Value::from(lib_next as NativeClosure), value, Value::Nil])
+        }
+        Value::Nil if args.is_empty() => Err(Error::Expected(1, "table", "no value")),
+        other => Err(Error::Expected(1, "table", other.static_type_name())),
     }
 }
 
 pub fn lib_print(vm: &mut Lua) -> NativeClosureReturn {
-    let print_string = get_args(vm)
-        .iter()
-        .map(|value| value.to_string())
-        .collect::<Vec<_>>()
-        .join("\t");
+    let mut print_bytes = Vec::new();
+    for (i, value) in get_args(vm).to_vec().into_iter().enumerate() {
+        if i > 0 {
+            print_bytes.push(b'\t');
+        }
+        tostring(vm, value)?.push_string_bytes(&mut print_bytes);
+    }
+    // The backend writes text, bytes that are not valid UTF-8 are
+    // written as `U+FFFD`
+    let print_string = String::from_utf8_lossy(&print_bytes);
 
-    log::info!(target: "no_deps_lua::vm", "{}", print_string);
+    if let Some(stdout) = &vm.backends.stdout {
+        stdout
+            .write(&print_string)
+            .and_then(|()| stdout.write("\n"))
+            .map_err(Error::Io)?;
+    } else {
+        log::info!(target: "no_deps_lua::vm", "{}", print_string);
+    }
     Ok(0)
 }
 
+/// `rawlen(v)`, length of a table or string without calling `__len`
+pub fn lib_rawlen(vm: &mut Lua) -> NativeClosureReturn {
+    let length = match get_args(vm).first() {
+        Some(Value::Table(table)) => table.borrow().border(),
+        Some(Value::ShortString(string)) => i64::try_from(string.len())?,
+        Some(Value::String(string)) => i64::try_from(string.len())?,
+        _ => return Err(Error::BadArgument(1, "table or string expected")),
+    };
+    vm.set_stack(0, Value::Integer(length))?;
+    Ok(1)
+}
+
+/// `select(n, ...)`, the arguments after the `n`th, counting from the end
+/// if `n` is negative, or `select("#", ...)`, the number of arguments
+pub fn lib_select(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let top = i64::try_from(args.len())?;
+    let n = match args.first() {
+        Some(hash @ (Value::ShortString(_) | Value::String(_))) if hash.to_string() == "#" => {
+            vm.set_stack(0, Value::Integer(top - 1))?;
+            return Ok(1);
+        }
+        Some(n) => match n.to_number().map(Value::try_int) {
+            Some(Value::Integer(n)) => n,
+            _ => return Err(Error::Expected(1, "integer", n.static_type_name())),
+        },
+        None => return Err(Error::Expected(1, "integer", "no value")),
+    };
+
+    // Position of the first argument returned, `n` itself is at 0
+    let first = if n < 0 { top + n } else { n.min(top) };
+    if first < 1 {
+        return Err(Error::BadArgument(1, "index out of range"));
+    }
+    Ok(return_args_from(vm, usize::try_from(first)?))
+}
+
+pub fn lib_setmetatable(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let Some(Value::Table(table)) = args.first() else {
+        return Err(Error::BadArgument(1, "table expected"));
+    };
+    let metatable = match args.get(1) {
+        Some(Value::Table(metatable)) => Some(metatable.clone()),
+        Some(Value::Nil) => None,
+        _ => return Err(Error::BadArgument(2, "nil or table expected")),
+    };
+
+    if Value::Table(table.clone())
+        .metamethod("__metatable")
+        .is_some()
+    {
+        return Err(Error::ProtectedMetatable);
+    }
+    if table.borrow().is_frozen() {
+        return Err(Error::FrozenTable);
+    }
+    table.borrow_mut().set_metatable(metatable);
+    Ok(1)
+}
+
+/// `tonumber(e [, base])`, numbers and strings with numerals converted to
+/// numbers, with a `base` from 2 to 36, `e` must be a string with an integer
+/// in that base, `nil` if it can't be converted
+pub fn lib_tonumber(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let number = match (args.first(), args.get(1)) {
+        (None, _) => return Err(Error::BadArgument(1, "value expected")),
+        (Some(value), None | Some(Value::Nil)) => value.to_number().unwrap_or(Value::Nil),
+        (Some(value), Some(base)) => {
+            let base = match base.to_number().map(Value::try_int) {
+                Some(Value::Integer(base @ 2..=36)) => u32::try_from(base)?,
+                Some(Value::Integer(_)) => return Err(Error::BadArgument(2, "base out of range")),
+                _ => return Err(Error::Expected(2, "integer", base.static_type_name())),
+            };
+            let integer = match value {
+                Value::ShortString(string) => string[..string.len()].parse_integer_in_base(base),
+                Value::String(string) => string.parse_integer_in_base(base),
+                other => return Err(Error::Expected(1, "string", other.static_type_name())),
+            };
+            integer.map_or(Value::Nil, Value::Integer)
+        }
+    };
+    vm.set_stack(0, number)?;
+    Ok(1)
+}
+
+pub fn lib_tostring(vm: &mut Lua) -> NativeClosureReturn {
+    let Some(value) = get_args(vm).first().cloned() else {
+        return Err(Error::BadArgument(1, "value expected"));
+    };
+    let string = tostring(vm, value)?;
+    vm.set_stack(0, string)?;
+    Ok(1)
+}
+
+/// Converts `value` to a string, using its `__tostring` metamethod if it has one,
+/// strings are kept as they are
+fn tostring(vm: &mut Lua, value: Value) -> Result<Value, Error> {
+    let Some(metamethod) = value.metamethod("__tostring") else {
+        return Ok(match value {
+            Value::ShortString(_) | Value::String(_) => value,
+            other => other.to_string().into(),
+        });
+    };
+    match vm.call_value(metamethod, &[value])?.into_iter().next() {
+        Some(string @ (Value::ShortString(_) | Value::String(_))) => Ok(string),
+        other => Err(Error::InvalidToString(
+            other.as_ref().map_or("no value", Value::static_type_name),
+        )),
+    }
+}
+
 pub fn lib_type(vm: &mut crate::Lua) -> NativeClosureReturn {
     let args = get_args(vm);
     let type_name = args[0].static_type_name();
@@ -71,7 +450,16 @@ pub fn lib_warn(vm: &mut Lua) -> NativeClosureReturn {
         }
         (args, true) => {
             let print_string = args.join("\t");
-            log::warn!(target: "no_deps_lua::vm", "{}", print_string);
+            if let Some(stdout) = &vm.backends.stdout {
+                // Same format as the warnings of the standalone interpreter
+                stdout
+                    .write("Lua warning: ")
+                    .and_then(|()| stdout.write(&print_string))
+                    .and_then(|()| stdout.write("\n"))
+                    .map_err(Error::Io)?;
+            } else {
+                log::warn!(target: "no_deps_lua::vm", "{}", print_string);
+            }
         }
         (_, false) => (),
     }
@@ -0,0 +1,226 @@
+use core::cell::RefCell;
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    Error, Lua,
+    closure::{Closure, NativeClosure, NativeClosureReturn, Upvalue},
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+use super::get_args;
+
+/// Builds the `io` table, which reads and writes through the host's
+/// [backends](crate::environment::StdOut), there are no file handles
+pub fn io_library() -> Table {
+    let mut table = Table::new(0, 3);
+
+    table.table.extend([
+        (
+            ValueKey("lines".into()),
+            Value::from(io_lines as NativeClosure),
+        ),
+        (
+            ValueKey("read".into()),
+            Value::from(io_read as NativeClosure),
+        ),
+        (
+            ValueKey("write".into()),
+            Value::from(io_write as NativeClosure),
+        ),
+    ]);
+
+    table.table.sort_by_key(|val| val.0.clone());
+
+    table
+}
+
+/// `io.write(...)`, writes strings and numbers to the standard output,
+/// returns `true` on success, or `nil` and the reason it failed
+fn io_write(vm: &mut Lua) -> NativeClosureReturn {
+    let mut output = Vec::new();
+    for (i, arg) in get_args(vm).iter().enumerate() {
+        if !arg.push_string_bytes(&mut output) {
+            return Err(Error::Expected(i + 1, "string", arg.static_type_name()));
+        }
+    }
+
+    let result = match &vm.backends.stdout {
+        // The backend writes text, bytes that are not valid UTF-8 are
+        // written as `U+FFFD`
+        Some(stdout) => stdout.write(&String::from_utf8_lossy(&output)),
+        None => Err(String::from("no standard output")),
+    };
+    match result {
+        Ok(()) => {
+            vm.set_stack(0, Value::Boolean(true))?;
+            Ok(1)
+        }
+        Err(message) => {
+            vm.set_stack(0, Value::Nil)?;
+            vm.set_stack(1, message.into())?;
+            Ok(2)
+        }
+    }
+}
+
+/// `io.read(...)`, reads from the standard input in each of the formats,
+/// `"l"` (the default), `"L"`, `"n"`, and `"a"`, stopping at the
+/// first one that fails.
+///
+/// The input is read by lines, so `"n"` reads a whole line and converts it
+/// to a number.
+fn io_read(vm: &mut Lua) -> NativeClosureReturn {
+    let formats = match get_args(vm) {
+        [] => vec![Format::Line],
+        args => args
+            .iter()
+            .enumerate()
+            .map(|(i, arg)| {
+                Format::try_from(arg).map_err(|()| Error::BadArgument(i + 1, "invalid format"))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+
+    let mut results = Vec::with_capacity(formats.len());
+    for format in formats {
+        let result = format.read(vm);
+        let failed = result == Value::Nil;
+        results.push(result);
+        if failed {
+            break;
+        }
+    }
+
+    vm.set_returns(results)
+}
+
+/// `io.lines([filename])`, iterator over the lines of the file, read through
+/// the host's [`FileSystem`](crate::environment::FileSystem), or of the
+/// standard input
+fn io_lines(vm: &mut Lua) -> NativeClosureReturn {
+    let iterator = match get_args(vm).first() {
+        None | Some(Value::Nil) => Value::from(io_lines_stdin as NativeClosure),
+        Some(path @ (Value::ShortString(_) | Value::String(_))) => {
+            let path = path.to_string();
+            let contents = match &vm.backends.file_system {
+                Some(file_system) => file_system.read_to_string(&path),
+                None => Err(alloc::format!("{path}: no file system")),
+            }
+            .map_err(Error::Io)?;
+            Value::Closure(Rc::new(Closure::new_native(
+                io_lines_file,
+                vec![
+                    Rc::new(RefCell::new(Upvalue::Closed(contents.into()))),
+                    Rc::new(RefCell::new(Upvalue::Closed(Value::Integer(0)))),
+                ],
+            )))
+        }
+        Some(other) => return Err(Error::Expected(1, "string", other.static_type_name())),
+    };
+
+    vm.set_stack(0, iterator)?;
+    Ok(1)
+}
+
+/// Next line of the standard input for `io.lines`
+fn io_lines_stdin(vm: &mut Lua) -> NativeClosureReturn {
+    let line = Format::Line.read(vm);
+    vm.set_stack(0, line)?;
+    Ok(1)
+}
+
+/// Next line of a file for `io.lines`, the upvalues hold the contents of
+/// the file and where the next line starts
+fn io_lines_file(vm: &mut Lua) -> NativeClosureReturn {
+    let contents = vm.get_upvalue(0)?;
+    let start = match vm.get_upvalue(1)? {
+        Value::Integer(start) => usize::try_from(start)?,
+        _ => 0,
+    };
+
+    let contents = contents.as_bytes().unwrap_or_default();
+    let Some(rest) = contents.get(start..).filter(|rest| !rest.is_empty()) else {
+        vm.set_stack(0, Value::Nil)?;
+        return Ok(1);
+    };
+    let (line, next) = match rest.iter().position(|byte| *byte == b'\n') {
+        Some(end) => (&rest[..end], start + end + 1),
+        None => (rest, contents.len()),
+    };
+
+    let line = Value::from(line);
+    vm.set_upvalue(1, i64::try_from(next)?)?;
+    vm.set_stack(0, line)?;
+    Ok(1)
+}
+
+/// Format of `io.read`
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    /// `"l"`, a line without its `\n`
+    Line,
+    /// `"L"`, a line with its `\n`
+    LineWithEnd,
+    /// `"n"`, a line converted to a number
+    Number,
+    /// `"a"`, the rest of the input
+    All,
+}
+
+impl Format {
+    /// Reads from the standard input, `nil` if it fails
+    fn read(self, vm: &Lua) -> Value {
+        let Some(stdin) = &vm.backends.stdin else {
+            return match self {
+                Self::All => Value::from(""),
+                _ => Value::Nil,
+            };
+        };
+
+        match self {
+            Self::Line => stdin.read_line().map_or(Value::Nil, |mut line| {
+                if line.ends_with('\n') {
+                    line.pop();
+                }
+                line.into()
+            }),
+            Self::LineWithEnd => stdin.read_line().map_or(Value::Nil, Value::from),
+            Self::Number => stdin.read_line().map_or(Value::Nil, |line| {
+                Value::from(line).to_number().unwrap_or(Value::Nil)
+            }),
+            Self::All => {
+                let mut all = String::new();
+                while let Some(line) = stdin.read_line() {
+                    all.push_str(&line);
+                }
+                all.into()
+            }
+        }
+    }
+}
+
+impl TryFrom<&Value> for Format {
+    type Error = ();
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let format = match value {
+            Value::ShortString(_) | Value::String(_) => value.to_string(),
+            _ => return Err(()),
+        };
+        // Lua 5.3 formats started with `*`, which is still accepted
+        match format.strip_prefix('*').unwrap_or(&format) {
+            "l" => Ok(Self::Line),
+            "L" => Ok(Self::LineWithEnd),
+            "n" => Ok(Self::Number),
+            "a" => Ok(Self::All),
+            _ => Err(()),
+        }
+    }
+}
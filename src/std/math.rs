@@ -0,0 +1,372 @@
+use core::cell::RefCell;
+
+use alloc::{rc::Rc, vec::Vec};
+
+use crate::{
+    Error, Lua,
+    closure::{Closure, NativeClosure, NativeClosureReturn, Upvalue},
+    environment::DEFAULT_RANDOM_SEED,
+    ext::FloatExt,
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+use super::get_args;
+
+/// Builds the `math` table
+pub fn math_library() -> Table {
+    let mut table = Table::new(0, 15);
+
+    // `random` and `randomseed` share the generator's state, which
+    // is seeded on the first call to `random`
+    let random_state: [_; 4] =
+        core::array::from_fn(|_| Rc::new(RefCell::new(Upvalue::Closed(Value::Nil))));
+
+    table.table.extend([
+        (
+            ValueKey("abs".into()),
+            Value::from(math_abs as NativeClosure),
+        ),
+        (
+            ValueKey("ceil".into()),
+            Value::from(math_ceil as NativeClosure),
+        ),
+        (
+            ValueKey("floor".into()),
+            Value::from(math_floor as NativeClosure),
+        ),
+        (
+            ValueKey("fmod".into()),
+            Value::from(math_fmod as NativeClosure),
+        ),
+        (ValueKey("huge".into()), Value::Float(f64::INFINITY)),
+        (
+            ValueKey("max".into()),
+            Value::from(math_max as NativeClosure),
+        ),
+        (ValueKey("maxinteger".into()), Value::Integer(i64::MAX)),
+        (
+            ValueKey("min".into()),
+            Value::from(math_min as NativeClosure),
+        ),
+        (ValueKey("mininteger".into()), Value::Integer(i64::MIN)),
+        (
+            ValueKey("random".into()),
+            Value::Closure(Rc::new(Closure::new_native(
+                math_random,
+                Vec::from(random_state.clone()),
+            ))),
+        ),
+        (
+            ValueKey("randomseed".into()),
+            Value::Closure(Rc::new(Closure::new_native(
+                math_randomseed,
+                Vec::from(random_state),
+            ))),
+        ),
+        (
+            ValueKey("sqrt".into()),
+            Value::from(math_sqrt as NativeClosure),
+        ),
+        (
+            ValueKey("tointeger".into()),
+            Value::from(math_tointeger as NativeClosure),
+        ),
+        (
+            ValueKey("type".into()),
+            Value::from(math_type as NativeClosure),
+        ),
+    ]);
+
+    table.table.sort_by_key(|val| val.0.clone());
+
+    table
+}
+
+fn math_abs(vm: &mut Lua) -> NativeClosureReturn {
+    let abs = match get_number(get_args(vm), 0)? {
+        Value::Integer(integer) => Value::Integer(integer.wrapping_abs()),
+        Value::Float(float) => Value::Float(float.abs()),
+        _ => unreachable!("`get_number` only returns integers or floats."),
+    };
+    vm.set_stack(0, abs)?;
+    Ok(1)
+}
+
+fn math_ceil(vm: &mut Lua) -> NativeClosureReturn {
+    let ceil = match get_number(get_args(vm), 0)? {
+        integer @ Value::Integer(_) => integer,
+        Value::Float(float) => float_to_integer(float.round_up()),
+        _ => unreachable!("`get_number` only returns integers or floats."),
+    };
+    vm.set_stack(0, ceil)?;
+    Ok(1)
+}
+
+fn math_floor(vm: &mut Lua) -> NativeClosureReturn {
+    let floor = match get_number(get_args(vm), 0)? {
+        integer @ Value::Integer(_) => integer,
+        Value::Float(float) => float_to_integer(float.round_down()),
+        _ => unreachable!("`get_number` only returns integers or floats."),
+    };
+    vm.set_stack(0, floor)?;
+    Ok(1)
+}
+
+fn math_fmod(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let fmod = match (get_number(args, 0)?, get_number(args, 1)?) {
+        (Value::Integer(_), Value::Integer(0)) => {
+            return Err(Error::BadArgument(2, "zero"));
+        }
+        // Avoids overflow of `i64::MIN % -1`
+        (Value::Integer(_), Value::Integer(-1)) => Value::Integer(0),
+        (Value::Integer(lhs), Value::Integer(rhs)) => Value::Integer(lhs % rhs),
+        (lhs, rhs) => {
+            let (Some(Value::Float(lhs)), Some(Value::Float(rhs))) =
+                (lhs.try_float(), rhs.try_float())
+            else {
+                unreachable!("`get_number` only returns integers or floats.");
+            };
+            Value::Float(lhs % rhs)
+        }
+    };
+    vm.set_stack(0, fmod)?;
+    Ok(1)
+}
+
+fn math_max(vm: &mut Lua) -> NativeClosureReturn {
+    let max = fold_numbers(get_args(vm), |current, candidate| candidate > current)?;
+    vm.set_stack(0, max)?;
+    Ok(1)
+}
+
+fn math_min(vm: &mut Lua) -> NativeClosureReturn {
+    let min = fold_numbers(get_args(vm), |current, candidate| candidate < current)?;
+    vm.set_stack(0, min)?;
+    Ok(1)
+}
+
+fn math_random(vm: &mut Lua) -> NativeClosureReturn {
+    let mut generator = Xoshiro256StarStar::load(vm)?;
+    let random = generator.next_u64();
+
+    let args = get_args(vm);
+    let (low, high) = match args.len() {
+        0 => {
+            // 53 random bits in the mantissa give a float in [0, 1)
+            let float = (random >> 11) as f64 * (0.5 / (1u64 << 52) as f64);
+            generator.store(vm)?;
+            vm.set_stack(0, float.into())?;
+            return Ok(1);
+        }
+        1 => match get_integer(args, 0)? {
+            // `math.random(0)` produces an integer with all bits random
+            0 => {
+                generator.store(vm)?;
+                vm.set_stack(0, Value::Integer(random as i64))?;
+                return Ok(1);
+            }
+            high => (1, high),
+        },
+        2 => (get_integer(args, 0)?, get_integer(args, 1)?),
+        _ => return Err(Error::BadArgument(3, "wrong number of arguments")),
+    };
+
+    if low > high {
+        return Err(Error::BadArgument(args.len(), "interval is empty"));
+    }
+
+    let projected = generator.project(random, high.wrapping_sub(low) as u64);
+    generator.store(vm)?;
+    vm.set_stack(0, Value::Integer((projected as i64).wrapping_add(low)))?;
+    Ok(1)
+}
+
+fn math_randomseed(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let generator = if args.is_empty() {
+        Xoshiro256StarStar::from_host_entropy(vm)
+    } else {
+        let high = get_integer(args, 0)?;
+        let low = if args.len() > 1 {
+            get_integer(args, 1)?
+        } else {
+            0
+        };
+        Xoshiro256StarStar::from_seed(high, low)
+    };
+    generator.store(vm)?;
+    Ok(0)
+}
+
+fn math_sqrt(vm: &mut Lua) -> NativeClosureReturn {
+    let Some(Value::Float(float)) = get_number(get_args(vm), 0)?.try_float() else {
+        unreachable!("`get_number` only returns integers or floats.");
+    };
+    vm.set_stack(0, Value::Float(float.square_root()))?;
+    Ok(1)
+}
+
+fn math_tointeger(vm: &mut Lua) -> NativeClosureReturn {
+    let integer = match get_args(vm).first() {
+        Some(integer @ Value::Integer(_)) => integer.clone(),
+        Some(Value::Float(float)) if float.zero_frac() => match float_to_integer(*float) {
+            integer @ Value::Integer(_) => integer,
+            _ => Value::Nil,
+        },
+        _ => Value::Nil,
+    };
+    vm.set_stack(0, integer)?;
+    Ok(1)
+}
+
+fn math_type(vm: &mut Lua) -> NativeClosureReturn {
+    let number_type = match get_args(vm).first() {
+        Some(Value::Integer(_)) => Value::from("integer"),
+        Some(Value::Float(_)) => Value::from("float"),
+        Some(_) => Value::Nil,
+        None => return Err(Error::BadArgument(1, "value expected")),
+    };
+    vm.set_stack(0, number_type)?;
+    Ok(1)
+}
+
+fn get_number(args: &[Value], position: usize) -> Result<Value, Error> {
+    match args.get(position) {
+        Some(number @ (Value::Integer(_) | Value::Float(_))) => Ok(number.clone()),
+        Some(other) => Err(Error::Expected(
+            position + 1,
+            "number",
+            other.static_type_name(),
+        )),
+        None => Err(Error::Expected(position + 1, "number", "no value")),
+    }
+}
+
+fn get_integer(args: &[Value], position: usize) -> Result<i64, Error> {
+    match get_number(args, position)? {
+        Value::Integer(integer) => Ok(integer),
+        Value::Float(float) => match float_to_integer(float) {
+            Value::Integer(integer) if float.zero_frac() => Ok(integer),
+            _ => Err(Error::BadArgument(
+                position + 1,
+                "number has no integer representation",
+            )),
+        },
+        _ => unreachable!("`get_number` only returns integers or floats."),
+    }
+}
+
+/// Converts a float with no fractional part into an integer if it fits in one
+fn float_to_integer(float: f64) -> Value {
+    // `i64::MIN` is a power of 2, so it can be exactly represented as a float
+    if float >= i64::MIN as f64 && float < -(i64::MIN as f64) {
+        Value::Integer(float as i64)
+    } else {
+        Value::Float(float)
+    }
+}
+
+fn fold_numbers(args: &[Value], replace: impl Fn(&Value, &Value) -> bool) -> Result<Value, Error> {
+    let mut selected = get_number(args, 0)?;
+    for position in 1..args.len() {
+        let candidate = get_number(args, position)?;
+        if replace(&selected, &candidate) {
+            selected = candidate;
+        }
+    }
+    Ok(selected)
+}
+
+/// `xoshiro256**` pseudo-random number generator, the same used by the
+/// reference implementation of Lua
+///
+/// The state is stored on the upvalues of `math.random` and `math.randomseed`
+/// as 4 integers.
+struct Xoshiro256StarStar {
+    state: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    fn from_seed(high: i64, low: i64) -> Self {
+        let mut generator = Self {
+            state: [high as u64, 0xff, low as u64, 0],
+        };
+        // Discard initial values to spread the seed
+        for _ in 0..16 {
+            generator.next_u64();
+        }
+        generator
+    }
+
+    /// Seeds the generator with the host's [`EntropySource`](crate::environment::EntropySource),
+    /// falling back to [`DEFAULT_RANDOM_SEED`]
+    fn from_host_entropy(vm: &Lua) -> Self {
+        if let Some(entropy_source) = vm.entropy_source {
+            Self::from_seed(entropy_source() as i64, entropy_source() as i64)
+        } else {
+            log::trace!("No entropy source, seeding `math.random` with default seed.");
+            Self::from_seed(DEFAULT_RANDOM_SEED, 0)
+        }
+    }
+
+    fn load(vm: &Lua) -> Result<Self, Error> {
+        let mut state = [0; 4];
+        for (i, word) in state.iter_mut().enumerate() {
+            match vm.get_upvalue(i)? {
+                Value::Integer(integer) => *word = integer as u64,
+                Value::Nil => return Ok(Self::from_host_entropy(vm)),
+                other => {
+                    log::error!(
+                        "`math.random`'s upvalue should be an integer, but was {}.",
+                        other
+                    );
+                    return Err(Error::Expected(i, "integer", other.static_type_name()));
+                }
+            }
+        }
+        Ok(Self { state })
+    }
+
+    fn store(&self, vm: &mut Lua) -> Result<(), Error> {
+        for (i, word) in self.state.iter().enumerate() {
+            vm.set_upvalue(i, Value::Integer(*word as i64))
+                .inspect_err(|err| {
+                    log::error!(
+                        "Failed to update `math.random`'s upvalue due to `{:?}`.",
+                        err
+                    );
+                })?;
+        }
+        Ok(())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let state = &mut self.state;
+        let result = state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = state[1] << 17;
+        state[2] ^= state[0];
+        state[3] ^= state[1];
+        state[1] ^= state[2];
+        state[0] ^= state[3];
+        state[2] ^= t;
+        state[3] = state[3].rotate_left(45);
+        result
+    }
+
+    /// Projects a random number into `[0, limit]`
+    fn project(&mut self, random: u64, limit: u64) -> u64 {
+        if limit & limit.wrapping_add(1) == 0 {
+            random & limit
+        } else {
+            // Smallest `2^b - 1` not smaller than `limit`
+            let mask = u64::MAX >> limit.leading_zeros();
+            let mut random = random & mask;
+            while random > limit {
+                random = self.next_u64() & mask;
+            }
+            random
+        }
+    }
+}
@@ -1,3 +1,79 @@
 mod basic;
+#[cfg(feature = "io")]
+mod io;
+#[cfg(feature = "math")]
+mod math;
+#[cfg(feature = "os")]
+mod os;
+#[cfg(feature = "package")]
+mod package;
+#[cfg(feature = "string")]
+mod string;
+#[cfg(feature = "table")]
+mod table;
 
 pub use basic::*;
+#[cfg(feature = "io")]
+pub use io::*;
+#[cfg(feature = "math")]
+pub use math::*;
+#[cfg(feature = "os")]
+pub use os::*;
+#[cfg(feature = "package")]
+pub use package::*;
+#[cfg(feature = "string")]
+pub use string::*;
+#[cfg(feature = "table")]
+pub use table::*;
+
+#[cfg(any(feature = "string", feature = "table"))]
+use crate::Error;
+use crate::{Lua, value::Value};
+
+pub(crate) fn get_args(vm: &Lua) -> &[Value] {
+    let args_start = vm.running_frame_start().0.min(vm.stack.len());
+    &vm.stack[args_start..]
+}
+
+/// Reads argument `position` as an integer, converting floats with an
+/// exact integer value
+#[cfg(any(feature = "string", feature = "table"))]
+fn get_integer(args: &[Value], position: usize) -> Result<i64, Error> {
+    match args.get(position) {
+        Some(Value::Integer(integer)) => Ok(*integer),
+        #[cfg(feature = "float")]
+        Some(float @ Value::Float(_)) => match float.clone().try_int() {
+            Value::Integer(integer) => Ok(integer),
+            _ => Err(Error::BadArgument(
+                position + 1,
+                "number has no integer representation",
+            )),
+        },
+        Some(other) => Err(Error::Expected(
+            position + 1,
+            "integer",
+            other.static_type_name(),
+        )),
+        None => Err(Error::Expected(position + 1, "integer", "no value")),
+    }
+}
+
+/// Reads argument `position` as an integer, or `default` if it is absent or `nil`
+#[cfg(any(feature = "string", feature = "table"))]
+fn get_optional_integer(args: &[Value], position: usize, default: i64) -> Result<i64, Error> {
+    match args.get(position) {
+        None | Some(Value::Nil) => Ok(default),
+        Some(_) => get_integer(args, position),
+    }
+}
+
+/// Returns the arguments from `first` on, moving them down to where the
+/// returns start, so any number of them can be returned
+fn return_args_from(vm: &mut Lua, first: usize) -> usize {
+    let args_start = vm.running_frame_start().0.min(vm.stack.len());
+    let first = (args_start + first).min(vm.stack.len());
+    let returns = vm.stack.len() - first;
+    vm.stack.move_values(first, args_start, returns);
+    vm.stack.set_top(args_start + returns);
+    returns
+}
@@ -0,0 +1,384 @@
+use core::{cell::RefCell, fmt::Write};
+
+use alloc::{
+    rc::Rc,
+    string::{String, ToString},
+};
+
+use crate::{
+    Error, Lua,
+    closure::{NativeClosure, NativeClosureReturn},
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+use super::get_args;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Builds the `os` table
+pub fn os_library() -> Table {
+    let mut table = Table::new(0, 4);
+
+    table.table.extend([
+        (
+            ValueKey("date".into()),
+            Value::from(os_date as NativeClosure),
+        ),
+        (
+            ValueKey("time".into()),
+            Value::from(os_time as NativeClosure),
+        ),
+    ]);
+    // Both return floats
+    #[cfg(feature = "float")]
+    table.table.extend([
+        (
+            ValueKey("clock".into()),
+            Value::from(os_clock as NativeClosure),
+        ),
+        (
+            ValueKey("difftime".into()),
+            Value::from(os_difftime as NativeClosure),
+        ),
+    ]);
+
+    table.table.sort_by_key(|val| val.0.clone());
+
+    table
+}
+
+/// `os.clock()`, processor time given by the host's
+/// [`Clock`](crate::environment::Clock), or `0.0` without one
+#[cfg(feature = "float")]
+fn os_clock(vm: &mut Lua) -> NativeClosureReturn {
+    let time = vm
+        .backends
+        .clock
+        .as_ref()
+        .map_or(0.0, |clock| clock.processor_time());
+    vm.set_stack(0, Value::Float(time))?;
+    Ok(1)
+}
+
+/// `os.difftime(t2 [, t1])`, seconds from `t1` to `t2`, as a float
+#[cfg(feature = "float")]
+fn os_difftime(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let end = get_time(args, 0)?;
+    let start = match args.get(1) {
+        None | Some(Value::Nil) => 0,
+        Some(_) => get_time(args, 1)?,
+    };
+    vm.set_stack(0, Value::Float(end as f64 - start as f64))?;
+    Ok(1)
+}
+
+/// Time given to `os.difftime` and `os.date`, integers and floats with
+/// an integer representation
+fn get_time(args: &[Value], position: usize) -> Result<i64, Error> {
+    match args.get(position).cloned().map(Value::try_int) {
+        Some(Value::Integer(time)) => Ok(time),
+        #[cfg(feature = "float")]
+        Some(Value::Float(_)) => Err(Error::BadArgument(
+            position + 1,
+            "number has no integer representation",
+        )),
+        Some(other) => Err(Error::Expected(
+            position + 1,
+            "integer",
+            other.static_type_name(),
+        )),
+        None => Err(Error::Expected(position + 1, "integer", "no value")),
+    }
+}
+
+/// `os.time([table])`, the current time, or the time of the UTC date on
+/// `table`, whose `hour` defaults to 12, and `min` and `sec` to 0
+fn os_time(vm: &mut Lua) -> NativeClosureReturn {
+    let time = match get_args(vm).first() {
+        None | Some(Value::Nil) => current_time(vm),
+        Some(Value::Table(table)) => {
+            let table = table.borrow();
+            CivilTime::to_unix(
+                date_field(&table, "year", None, 1900)?,
+                date_field(&table, "month", None, 1)?,
+                date_field(&table, "day", None, 0)?,
+                date_field(&table, "hour", Some(12), 0)?,
+                date_field(&table, "min", Some(0), 0)?,
+                date_field(&table, "sec", Some(0), 0)?,
+            )
+        }
+        Some(other) => {
+            return Err(Error::Expected(1, "table", other.static_type_name()));
+        }
+    };
+
+    vm.set_stack(0, Value::Integer(time))?;
+    Ok(1)
+}
+
+/// Integer field `key` of a date table given to `os.time`
+///
+/// Fields must fit the C `int` fields of `struct tm`, where years count
+/// from 1900 and months from 0, `delta` is what is taken from the field
+/// to store it there.
+fn date_field(
+    table: &Table,
+    key: &'static str,
+    default: Option<i64>,
+    delta: i64,
+) -> Result<i64, Error> {
+    let integer = match table.get(ValueKey(key.into())) {
+        Value::Integer(integer) => *integer,
+        #[cfg(feature = "float")]
+        float @ Value::Float(_) => match float.clone().try_int() {
+            Value::Integer(integer) => integer,
+            _ => return Err(Error::BadArgument(1, "date field is not an integer")),
+        },
+        Value::Nil => return default.ok_or(Error::BadArgument(1, "date field missing")),
+        _ => return Err(Error::BadArgument(1, "date field is not an integer")),
+    };
+    let fits = if integer >= 0 {
+        integer - delta <= i64::from(i32::MAX)
+    } else {
+        i64::from(i32::MIN) + delta <= integer
+    };
+    if !fits {
+        return Err(Error::BadArgument(
+            1,
+            match key {
+                "year" => "field 'year' is out-of-bound",
+                "month" => "field 'month' is out-of-bound",
+                "day" => "field 'day' is out-of-bound",
+                "hour" => "field 'hour' is out-of-bound",
+                "min" => "field 'min' is out-of-bound",
+                _ => "field 'sec' is out-of-bound",
+            },
+        ));
+    }
+    Ok(integer)
+}
+
+/// `os.date([format [, time]])`, times are always in UTC as there is
+/// no timezone information on `no_std`
+fn os_date(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let format = match args.first() {
+        None | Some(Value::Nil) => String::from("%c"),
+        Some(format @ (Value::ShortString(_) | Value::String(_))) => format.to_string(),
+        Some(other) => {
+            return Err(Error::Expected(1, "string", other.static_type_name()));
+        }
+    };
+    let time = match args.get(1) {
+        None | Some(Value::Nil) => current_time(vm),
+        Some(_) => get_time(args, 1)?,
+    };
+
+    let date = CivilTime::from_unix(time);
+    // `!` asks for UTC, which is the only timezone available
+    let format = format.strip_prefix('!').unwrap_or(&format);
+    let result = if format.starts_with("*t") {
+        Value::Table(Rc::new(RefCell::new(date.to_table()?)))
+    } else {
+        let mut formatted = String::new();
+        date.format(format, &mut formatted)?;
+        formatted.as_str().into()
+    };
+
+    vm.set_stack(0, result)?;
+    Ok(1)
+}
+
+/// Time given by the host's [`Clock`](crate::environment::Clock),
+/// falling back to the Unix epoch
+fn current_time(vm: &Lua) -> i64 {
+    if let Some(clock) = &vm.backends.clock {
+        clock.time()
+    } else {
+        log::trace!("No clock, the current time is the Unix epoch.");
+        0
+    }
+}
+
+/// Broken down UTC time
+struct CivilTime {
+    year: i64,
+    /// Month of the year, from 1 to 12
+    month: i64,
+    /// Day of the month, from 1 to 31
+    day: i64,
+    hour: i64,
+    min: i64,
+    sec: i64,
+    /// Day of the week, from 0 to 6, starting on Sunday
+    weekday: i64,
+    /// Day of the year, from 1 to 366
+    yearday: i64,
+}
+
+impl CivilTime {
+    /// Converts seconds since the Unix epoch into a civil date, using the
+    /// proleptic Gregorian calendar
+    fn from_unix(time: i64) -> Self {
+        let days = time.div_euclid(SECONDS_PER_DAY);
+        let seconds = time.rem_euclid(SECONDS_PER_DAY);
+
+        // Counts from 0000-03-01, so leap days fall at the end of the year,
+        // and splits the count into 400 year eras of 146097 days each
+        let days_since_march = days + 719_468;
+        let era = days_since_march.div_euclid(146_097);
+        let day_of_era = days_since_march.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        const DAYS_BEFORE_MONTH: [i64; 12] =
+            [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+        let leap_day = i64::from(month > 2 && is_leap_year(year));
+        let yearday = DAYS_BEFORE_MONTH[(month - 1) as usize] + day + leap_day;
+
+        Self {
+            year,
+            month,
+            day,
+            hour: seconds / 3600,
+            min: seconds % 3600 / 60,
+            sec: seconds % 60,
+            // 1970-01-01 was a Thursday
+            weekday: (days + 4).rem_euclid(7),
+            yearday,
+        }
+    }
+
+    /// Seconds since the Unix epoch of a date, fields out of their
+    /// range carry over, like the 32nd of a month being on the next month
+    ///
+    /// Fields must fit an `i32`, as checked by `date_field`, so the
+    /// count of days can't overflow
+    fn to_unix(year: i64, month: i64, day: i64, hour: i64, min: i64, sec: i64) -> i64 {
+        let year = year.saturating_add((month - 1).div_euclid(12));
+        let month = (month - 1).rem_euclid(12) + 1;
+
+        // Inverse of `from_unix`, counting years from March
+        let year = year - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = (month + 9) % 12;
+        let day_of_year = (153 * shifted_month + 2) / 5;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        let days = era * 146_097 + day_of_era - 719_468;
+
+        days.saturating_add(day - 1)
+            .saturating_mul(SECONDS_PER_DAY)
+            .saturating_add(hour.saturating_mul(3600))
+            .saturating_add(min.saturating_mul(60))
+            .saturating_add(sec)
+    }
+
+    fn to_table(&self) -> Result<Table, Error> {
+        let mut table = Table::new(0, 9);
+        for (key, value) in [
+            ("year", Value::Integer(self.year)),
+            ("month", Value::Integer(self.month)),
+            ("day", Value::Integer(self.day)),
+            ("hour", Value::Integer(self.hour)),
+            ("min", Value::Integer(self.min)),
+            ("sec", Value::Integer(self.sec)),
+            ("wday", Value::Integer(self.weekday + 1)),
+            ("yday", Value::Integer(self.yearday)),
+            ("isdst", Value::Boolean(false)),
+        ] {
+            table.set(ValueKey(key.into()), value)?;
+        }
+        Ok(table)
+    }
+
+    /// Formats the time following `strftime`'s conversion specifiers
+    /// on the `C` locale
+    fn format(&self, format: &str, out: &mut String) -> Result<(), Error> {
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            let Some(specifier) = chars.next() else {
+                return Err(Error::BadArgument(1, "invalid conversion specifier"));
+            };
+            self.format_specifier(specifier, out)?;
+        }
+        Ok(())
+    }
+
+    fn format_specifier(&self, specifier: char, out: &mut String) -> Result<(), Error> {
+        let weekday = WEEKDAYS[self.weekday as usize];
+        let month = MONTHS[(self.month - 1) as usize];
+        // Writing to a `String` never fails
+        let _ = match specifier {
+            'a' => write!(out, "{}", &weekday[..3]),
+            'A' => write!(out, "{}", weekday),
+            'b' | 'h' => write!(out, "{}", &month[..3]),
+            'B' => write!(out, "{}", month),
+            'c' => return self.format("%a %b %e %H:%M:%S %Y", out),
+            'd' => write!(out, "{:02}", self.day),
+            'D' | 'x' => return self.format("%m/%d/%y", out),
+            'e' => write!(out, "{:2}", self.day),
+            'F' => return self.format("%Y-%m-%d", out),
+            'H' => write!(out, "{:02}", self.hour),
+            'I' => write!(out, "{:02}", (self.hour + 11) % 12 + 1),
+            'j' => write!(out, "{:03}", self.yearday),
+            'm' => write!(out, "{:02}", self.month),
+            'M' => write!(out, "{:02}", self.min),
+            'n' => out.write_char('\n'),
+            'p' => write!(out, "{}", if self.hour < 12 { "AM" } else { "PM" }),
+            'S' => write!(out, "{:02}", self.sec),
+            't' => out.write_char('\t'),
+            'T' | 'X' => return self.format("%H:%M:%S", out),
+            'w' => write!(out, "{}", self.weekday),
+            'y' => write!(out, "{:02}", self.year.rem_euclid(100)),
+            'Y' => write!(out, "{}", self.year),
+            '%' => out.write_char('%'),
+            _ => return Err(Error::BadArgument(1, "invalid conversion specifier")),
+        };
+        Ok(())
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
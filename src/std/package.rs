@@ -0,0 +1,205 @@
+use core::cell::RefCell;
+
+use alloc::{
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    Error, Lua, Program,
+    closure::{Closure, NativeClosure, NativeClosureReturn, Upvalue},
+    function::Function,
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+use super::get_args;
+
+/// Builds the `package` table, and `require`, which caches the modules
+/// it loads on `package.loaded`.
+///
+/// `package.searchers` starts with a searcher for `package.preload`, and
+/// one that asks the host's [`ModuleSource`](crate::environment::ModuleSource)
+/// for the source of the module.
+pub fn package_library() -> (Rc<RefCell<Table>>, Value) {
+    let package = Rc::new(RefCell::new(Table::new(0, 3)));
+
+    let mut searchers = Table::new(2, 0);
+    searchers.array.extend([
+        with_package(search_preload, &package),
+        with_package(search_source, &package),
+    ]);
+    package.borrow_mut().table.extend([
+        (
+            ValueKey("loaded".into()),
+            Value::Table(Rc::new(RefCell::new(Table::new(0, 0)))),
+        ),
+        (
+            ValueKey("preload".into()),
+            Value::Table(Rc::new(RefCell::new(Table::new(0, 0)))),
+        ),
+        (
+            ValueKey("searchers".into()),
+            Value::Table(Rc::new(RefCell::new(searchers))),
+        ),
+    ]);
+    package.borrow_mut().table.sort_by_key(|val| val.0.clone());
+
+    let require = with_package(lib_require, &package);
+    (package, require)
+}
+
+/// Native closure with the `package` table as its upvalue
+fn with_package(function: NativeClosure, package: &Rc<RefCell<Table>>) -> Value {
+    Value::Closure(Rc::new(Closure::new_native(
+        function,
+        vec![Rc::new(RefCell::new(Upvalue::Closed(Value::Table(
+            package.clone(),
+        ))))],
+    )))
+}
+
+/// `require(modname)`, returns `package.loaded[modname]`, loading the
+/// module with the first of `package.searchers` that finds it if it
+/// was not loaded yet
+fn lib_require(vm: &mut Lua) -> NativeClosureReturn {
+    let name = match get_args(vm).first() {
+        Some(name @ (Value::ShortString(_) | Value::String(_))) => name.clone(),
+        Some(other) => return Err(Error::Expected(1, "string", other.static_type_name())),
+        None => return Err(Error::Expected(1, "string", "no value")),
+    };
+    let package = package(vm)?;
+
+    let loaded = package_field(&package, "loaded")?;
+    let module = loaded.borrow().get(ValueKey(name.clone())).clone();
+    if !matches!(module, Value::Nil | Value::Boolean(false)) {
+        vm.set_stack(0, module)?;
+        return Ok(1);
+    }
+
+    let searchers = package_field(&package, "searchers")?.borrow().array.clone();
+    let mut not_found = String::new();
+    for searcher in searchers {
+        if searcher == Value::Nil {
+            break;
+        }
+        let mut found = vm
+            .call_value(searcher, core::slice::from_ref(&name))?
+            .into_iter();
+        match found.next() {
+            Some(loader @ Value::Closure(_)) => {
+                let extra = found.next().unwrap_or(Value::Nil);
+                let module = vm
+                    .call_value(loader, &[name.clone(), extra.clone()])?
+                    .into_iter()
+                    .next()
+                    .unwrap_or(Value::Nil);
+
+                // The module may have set its entry on `package.loaded` itself
+                let mut loaded = loaded.borrow_mut();
+                if module != Value::Nil {
+                    loaded.set(ValueKey(name.clone()), module)?;
+                } else if *loaded.get(ValueKey(name.clone())) == Value::Nil {
+                    loaded.set(ValueKey(name.clone()), Value::Boolean(true))?;
+                }
+                let module = loaded.get(ValueKey(name)).clone();
+                drop(loaded);
+
+                vm.set_stack(0, module)?;
+                vm.set_stack(1, extra)?;
+                return Ok(2);
+            }
+            Some(message @ (Value::ShortString(_) | Value::String(_))) => {
+                not_found.push_str(&message.to_string());
+            }
+            _ => (),
+        }
+    }
+
+    Err(Error::ModuleNotFound(name.to_string(), not_found))
+}
+
+/// Searcher that finds the loaders on `package.preload`
+fn search_preload(vm: &mut Lua) -> NativeClosureReturn {
+    let name = module_name(vm)?;
+    let preload = package_field(&package(vm)?, "preload")?;
+    let loader = preload.borrow().get(ValueKey(name.as_str().into())).clone();
+
+    if loader == Value::Nil {
+        vm.set_stack(0, format!("\n\tno field package.preload['{name}']").into())?;
+        Ok(1)
+    } else {
+        vm.set_stack(0, loader)?;
+        vm.set_stack(1, ":preload:".into())?;
+        Ok(2)
+    }
+}
+
+/// Searcher that compiles the source given by the host's
+/// [`ModuleSource`](crate::environment::ModuleSource), the chunk receives
+/// the name of the module as its argument
+fn search_source(vm: &mut Lua) -> NativeClosureReturn {
+    let name = module_name(vm)?;
+    let Some(mut source) = vm
+        .backends
+        .module_source
+        .as_ref()
+        .and_then(|module_source| module_source.find(&name))
+    else {
+        vm.set_stack(0, format!("\n\tno source for module '{name}'").into())?;
+        return Ok(1);
+    };
+
+    // The parser expects statements to be terminated by a new line
+    if !source.ends_with('\n') {
+        source.push('\n');
+    }
+    let program = Program::parse_named_with_pool(&source, &name, &mut vm.constant_pool)
+        .map_err(|err| Error::ModuleLoad(name.clone(), err.to_string()))?;
+    if let Some(profiler) = vm.profiler.as_mut() {
+        profiler.add_chunk(&program);
+    }
+    let loader = Closure::new_lua(
+        Rc::new(Function::new(program, 0, true)),
+        Vec::from_iter([Rc::new(RefCell::new(Upvalue::Closed(Value::Table(
+            vm.globals.clone(),
+        ))))]),
+    );
+
+    vm.set_stack(0, Value::Closure(Rc::new(loader)))?;
+    vm.set_stack(1, name.as_str().into())?;
+    Ok(2)
+}
+
+fn module_name(vm: &mut Lua) -> Result<String, Error> {
+    match get_args(vm).first() {
+        Some(name @ (Value::ShortString(_) | Value::String(_))) => Ok(name.to_string()),
+        Some(other) => Err(Error::Expected(1, "string", other.static_type_name())),
+        None => Err(Error::Expected(1, "string", "no value")),
+    }
+}
+
+/// The `package` table, kept as the upvalue of `require` and the searchers
+fn package(vm: &Lua) -> Result<Rc<RefCell<Table>>, Error> {
+    match vm.get_upvalue(0)? {
+        Value::Table(package) => Ok(package),
+        other => {
+            log::error!("`package` upvalue should be a table, but was {}.", other);
+            Err(Error::ExpectedTable(other.static_type_name()))
+        }
+    }
+}
+
+/// Field of `package` that `require` needs to be a table
+fn package_field(
+    package: &Rc<RefCell<Table>>,
+    field: &'static str,
+) -> Result<Rc<RefCell<Table>>, Error> {
+    match package.borrow().get(ValueKey(field.into())) {
+        Value::Table(table) => Ok(table.clone()),
+        _ => Err(Error::PackageField(field)),
+    }
+}
@@ -0,0 +1,244 @@
+use alloc::vec::Vec;
+
+use crate::{
+    Error, Lua,
+    closure::{FunctionType, NativeClosure, NativeClosureReturn},
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+use super::{get_args, get_integer, get_optional_integer};
+
+/// Builds the `string` table, which is also the `__index` of the
+/// metatable shared by all strings, so `s:upper()` calls `string.upper(s)`.
+///
+/// Functions work on the bytes of the strings, which don't need to be
+/// valid UTF-8.
+pub fn string_library() -> Table {
+    let mut table = Table::new(0, 9);
+
+    table.table.extend([
+        (
+            ValueKey("byte".into()),
+            Value::from(string_byte as NativeClosure),
+        ),
+        (
+            ValueKey("char".into()),
+            Value::from(string_char as NativeClosure),
+        ),
+        (
+            ValueKey("dump".into()),
+            Value::from(string_dump as NativeClosure),
+        ),
+        (
+            ValueKey("len".into()),
+            Value::from(string_len as NativeClosure),
+        ),
+        (
+            ValueKey("lower".into()),
+            Value::from(string_lower as NativeClosure),
+        ),
+        (
+            ValueKey("rep".into()),
+            Value::from(string_rep as NativeClosure),
+        ),
+        (
+            ValueKey("reverse".into()),
+            Value::from(string_reverse as NativeClosure),
+        ),
+        (
+            ValueKey("sub".into()),
+            Value::from(string_sub as NativeClosure),
+        ),
+        (
+            ValueKey("upper".into()),
+            Value::from(string_upper as NativeClosure),
+        ),
+    ]);
+
+    table.table.sort_by_key(|val| val.0.clone());
+
+    table
+}
+
+/// `string.byte(s [, i [, j]])`, codes of the bytes from `i` to `j`
+fn string_byte(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let string = get_string(args, 0)?;
+    let start = get_optional_integer(args, 1, 1)?;
+    let end = get_optional_integer(args, 2, start)?;
+
+    let bytes = substring(&string, start, end)
+        .iter()
+        .map(|byte| Value::Integer(i64::from(*byte)))
+        .collect::<Vec<_>>();
+    vm.set_returns(bytes)
+}
+
+/// `string.char(...)`, string with the bytes of each code
+fn string_char(vm: &mut Lua) -> NativeClosureReturn {
+    // One byte for each code
+    let length = get_args(vm).len();
+    vm.allocate(length)?;
+    let args = get_args(vm);
+    let bytes = (0..args.len())
+        .map(|i| {
+            let code = get_integer(args, i)?;
+            u8::try_from(code).map_err(|_| Error::BadArgument(i + 1, "value out of range"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    vm.set_stack(0, bytes.into())?;
+    Ok(1)
+}
+
+/// `string.len(s)`, number of bytes of the string
+fn string_len(vm: &mut Lua) -> NativeClosureReturn {
+    let length = get_string(get_args(vm), 0)?.len();
+    vm.set_stack(0, Value::Integer(i64::try_from(length)?))?;
+    Ok(1)
+}
+
+/// `string.dump(f [, strip])`, binary chunk with the function `f`, which
+/// `load` turns back into a function, see [`Function::to_bytecode`]
+///
+/// [`Function::to_bytecode`]: crate::function::Function::to_bytecode
+fn string_dump(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let strip = !matches!(args.get(1), None | Some(Value::Nil | Value::Boolean(false)));
+    let chunk = match args.first() {
+        Some(Value::Closure(closure)) => match closure.closure_type() {
+            FunctionType::Lua(function) => function.to_bytecode(strip),
+            FunctionType::Native(_) => {
+                return Err(Error::BadArgument(1, "unable to dump given function"));
+            }
+        },
+        Some(other) => return Err(Error::Expected(1, "function", other.static_type_name())),
+        None => return Err(Error::Expected(1, "function", "no value")),
+    };
+    vm.allocate(chunk.len())?;
+    vm.set_stack(0, chunk.into())?;
+    Ok(1)
+}
+
+/// `string.lower(s)`, only ASCII letters are changed
+fn string_lower(vm: &mut Lua) -> NativeClosureReturn {
+    let mut lower = get_string(get_args(vm), 0)?;
+    vm.allocate(lower.len())?;
+    lower.make_ascii_lowercase();
+    vm.set_stack(0, lower.into())?;
+    Ok(1)
+}
+
+/// `string.rep(s, n [, sep])`, `n` copies of the string separated by `sep`
+fn string_rep(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let string = get_string(args, 0)?;
+    let count = get_integer(args, 1)?;
+    let separator = match args.get(2) {
+        None | Some(Value::Nil) => Vec::new(),
+        Some(_) => get_string(args, 2)?,
+    };
+
+    let repeated = match usize::try_from(count) {
+        Ok(0) | Err(_) => Vec::new(),
+        Ok(count) => {
+            let length = (string.len() + separator.len())
+                .checked_mul(count)
+                .filter(|length| isize::try_from(*length).is_ok())
+                .ok_or(Error::BadArgument(2, "resulting string too large"))?;
+            // Checked before the string is built, it would take the
+            // memory before failing otherwise
+            vm.allocate(length)?;
+            // Without a memory limit, a string too large for the host
+            // fails instead of aborting it
+            let mut repeated = Vec::new();
+            repeated
+                .try_reserve_exact(length)
+                .map_err(|_| Error::MemoryLimit)?;
+            for i in 0..count {
+                if i > 0 {
+                    repeated.extend_from_slice(&separator);
+                }
+                repeated.extend_from_slice(&string);
+            }
+            repeated
+        }
+    };
+    vm.set_stack(0, repeated.into())?;
+    Ok(1)
+}
+
+/// `string.reverse(s)`, the bytes of the string in reverse order
+fn string_reverse(vm: &mut Lua) -> NativeClosureReturn {
+    let mut bytes = get_string(get_args(vm), 0)?;
+    vm.allocate(bytes.len())?;
+    bytes.reverse();
+    vm.set_stack(0, bytes.into())?;
+    Ok(1)
+}
+
+/// `string.sub(s, i [, j])`, bytes from `i` to `j`, negative positions
+/// count from the end of the string
+fn string_sub(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let string = get_string(args, 0)?;
+    let start = get_integer(args, 1)?;
+    let end = get_optional_integer(args, 2, -1)?;
+
+    let sub = substring(&string, start, end);
+    vm.allocate(sub.len())?;
+    vm.set_stack(0, Value::from(sub))?;
+    Ok(1)
+}
+
+/// `string.upper(s)`, only ASCII letters are changed
+fn string_upper(vm: &mut Lua) -> NativeClosureReturn {
+    let mut upper = get_string(get_args(vm), 0)?;
+    vm.allocate(upper.len())?;
+    upper.make_ascii_uppercase();
+    vm.set_stack(0, upper.into())?;
+    Ok(1)
+}
+
+/// Bytes from `start` to `end`, both inclusive and counted from 1, negative
+/// positions count from the end, and positions are clamped to the string
+fn substring(bytes: &[u8], start: i64, end: i64) -> &[u8] {
+    let length = i64::try_from(bytes.len()).unwrap_or(i64::MAX);
+    let start = match start {
+        1.. => start,
+        0 => 1,
+        _ => length.saturating_add(start).saturating_add(1).max(1),
+    };
+    let end = match end {
+        0.. => end.min(length),
+        _ => length.saturating_add(end).saturating_add(1),
+    };
+    if start > end {
+        return &[];
+    }
+    // Both are between 1 and the length of the string
+    match (usize::try_from(start - 1), usize::try_from(end)) {
+        (Ok(start), Ok(end)) => &bytes[start..end],
+        _ => &[],
+    }
+}
+
+/// Bytes of strings and numbers, which are converted to strings
+fn get_string(args: &[Value], position: usize) -> Result<Vec<u8>, Error> {
+    match args.get(position) {
+        Some(value) => {
+            let mut bytes = Vec::new();
+            if value.push_string_bytes(&mut bytes) {
+                Ok(bytes)
+            } else {
+                Err(Error::Expected(
+                    position + 1,
+                    "string",
+                    value.static_type_name(),
+                ))
+            }
+        }
+        None => Err(Error::Expected(position + 1, "string", "no value")),
+    }
+}
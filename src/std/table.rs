@@ -0,0 +1,383 @@
+use core::cell::RefCell;
+
+use alloc::{rc::Rc, vec::Vec};
+
+use crate::{
+    Error, Lua,
+    closure::{NativeClosure, NativeClosureReturn},
+    table::Table,
+    value::{Value, ValueKey},
+};
+
+use super::{get_args, get_integer, get_optional_integer};
+
+/// Builds the `table` table
+pub fn table_library() -> Table {
+    let mut table = Table::new(0, 9);
+
+    table.table.extend([
+        (
+            ValueKey("concat".into()),
+            Value::from(table_concat as NativeClosure),
+        ),
+        (
+            ValueKey("freeze".into()),
+            Value::from(table_freeze as NativeClosure),
+        ),
+        (
+            ValueKey("insert".into()),
+            Value::from(table_insert as NativeClosure),
+        ),
+        (
+            ValueKey("isfrozen".into()),
+            Value::from(table_isfrozen as NativeClosure),
+        ),
+        (
+            ValueKey("move".into()),
+            Value::from(table_move as NativeClosure),
+        ),
+        (
+            ValueKey("pack".into()),
+            Value::from(table_pack as NativeClosure),
+        ),
+        (
+            ValueKey("remove".into()),
+            Value::from(table_remove as NativeClosure),
+        ),
+        (
+            ValueKey("sort".into()),
+            Value::from(table_sort as NativeClosure),
+        ),
+        (
+            ValueKey("unpack".into()),
+            Value::from(table_unpack as NativeClosure),
+        ),
+    ]);
+
+    table.table.sort_by_key(|val| val.0.clone());
+
+    table
+}
+
+fn table_concat(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let table = get_table(args, 0)?;
+    let separator = match args.get(1) {
+        None | Some(Value::Nil) => Vec::new(),
+        Some(separator) => separator
+            .as_bytes()
+            .ok_or(Error::Expected(2, "string", separator.static_type_name()))?
+            .to_vec(),
+    };
+    let start = get_optional_integer(args, 2, 1)?;
+    let end = get_optional_integer(args, 3, table.borrow().border())?;
+
+    // The output is allocated once, with room for all strings and
+    // separators, and enough for most numbers, like `CONCAT`. The first
+    // value that can't be concatenated ends the count, so a range past
+    // the end of the table fails without going through all of it
+    let borrowed = table.borrow();
+    let mut capacity = 0usize;
+    for index in start..=end {
+        if index != start {
+            capacity = capacity.saturating_add(separator.len());
+        }
+        capacity = capacity.saturating_add(match get_index(&borrowed, index) {
+            Value::ShortString(string) => string.len(),
+            Value::String(string) => string.len(),
+            Value::Integer(_) => 24,
+            #[cfg(feature = "float")]
+            Value::Float(_) => 24,
+            other => return Err(Error::ConcatOperand(other.static_type_name())),
+        });
+    }
+    drop(borrowed);
+    // Checked before the string is built, it would take the memory
+    // before failing otherwise
+    vm.allocate(capacity)?;
+
+    let table = table.borrow();
+    let mut concatenated = Vec::with_capacity(capacity);
+    for index in start..=end {
+        if index != start {
+            concatenated.extend_from_slice(&separator);
+        }
+        let value = get_index(&table, index);
+        if !value.push_string_bytes(&mut concatenated) {
+            return Err(Error::ConcatOperand(value.static_type_name()));
+        }
+    }
+    drop(table);
+
+    vm.set_stack(0, concatenated.into())?;
+    Ok(1)
+}
+
+/// `table.freeze(t)`, freezes `t` and returns it, see [`Table::freeze`]
+fn table_freeze(vm: &mut Lua) -> NativeClosureReturn {
+    let table = get_table(get_args(vm), 0)?;
+    table.borrow_mut().freeze();
+
+    vm.set_stack(0, Value::Table(table))?;
+    Ok(1)
+}
+
+fn table_insert(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let table = get_mutable_table(args, 0)?;
+    let mut table = table.borrow_mut();
+    let length = table.border();
+    // Trailing `nil`s are outside of the sequence
+    table.array.truncate(usize::try_from(length)?);
+
+    match args.len() {
+        2 => table.array.push(args[1].clone()),
+        3 => {
+            let position = get_integer(args, 1)?;
+            if !(1..=length + 1).contains(&position) {
+                return Err(Error::BadArgument(2, "position out of bounds"));
+            }
+            table
+                .array
+                .insert(usize::try_from(position - 1)?, args[2].clone());
+        }
+        _ => {
+            return Err(Error::BadArgument(
+                2,
+                "wrong number of arguments to 'insert'",
+            ));
+        }
+    }
+    table.migrate_to_array();
+
+    Ok(0)
+}
+
+/// `table.isfrozen(t)`, whether `t` was frozen
+fn table_isfrozen(vm: &mut Lua) -> NativeClosureReturn {
+    let frozen = get_table(get_args(vm), 0)?.borrow().is_frozen();
+
+    vm.set_stack(0, Value::Boolean(frozen))?;
+    Ok(1)
+}
+
+fn table_move(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let source = get_table(args, 0)?;
+    let start = get_integer(args, 1)?;
+    let end = get_integer(args, 2)?;
+    let target_start = get_integer(args, 3)?;
+    let destination = match args.get(4) {
+        None | Some(Value::Nil) => get_mutable_table(args, 0)?,
+        Some(_) => get_mutable_table(args, 4)?,
+    };
+
+    if end >= start {
+        if start <= 0 && end >= i64::MAX + start {
+            return Err(Error::BadArgument(3, "too many elements to move"));
+        }
+        if target_start > i64::MAX - (end - start) {
+            return Err(Error::BadArgument(4, "destination wrap around"));
+        }
+
+        // Like `lua_move`, the elements are copied one at a time, backwards
+        // if the ranges overlap and a forward copy would overwrite the
+        // elements before they are read
+        let backwards =
+            target_start > start && target_start <= end && Rc::ptr_eq(&source, &destination);
+        for offset in 0..=(end - start) {
+            let offset = if backwards {
+                end - start - offset
+            } else {
+                offset
+            };
+            let value = get_index(&source.borrow(), start + offset);
+            vm.allocate(size_of::<Value>())?;
+            set_index(&mut destination.borrow_mut(), target_start + offset, value);
+        }
+    }
+
+    vm.set_stack(0, Value::Table(destination))?;
+    Ok(1)
+}
+
+fn table_pack(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let mut table = Table::new(args.len(), 1);
+    table.array.extend_from_slice(args);
+    table.set(
+        ValueKey("n".into()),
+        Value::Integer(i64::try_from(args.len())?),
+    )?;
+
+    vm.set_stack(0, Value::Table(Rc::new(RefCell::new(table))))?;
+    Ok(1)
+}
+
+fn table_remove(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let table = get_mutable_table(args, 0)?;
+    let mut table = table.borrow_mut();
+    let length = table.border();
+    table.array.truncate(usize::try_from(length)?);
+
+    let removed = match args.get(1) {
+        None | Some(Value::Nil) if length == 0 => Value::Nil,
+        None | Some(Value::Nil) => table.array.pop().unwrap_or(Value::Nil),
+        Some(_) => {
+            let position = get_integer(args, 1)?;
+            if length == 0 && position == 0 {
+                Value::Nil
+            } else if position == length + 1 {
+                get_index(&table, position)
+            } else if (1..=length).contains(&position) {
+                table.array.remove(usize::try_from(position - 1)?)
+            } else {
+                return Err(Error::BadArgument(2, "position out of bounds"));
+            }
+        }
+    };
+    drop(table);
+
+    vm.set_stack(0, removed)?;
+    Ok(1)
+}
+
+fn table_sort(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let table = get_mutable_table(args, 0)?;
+    let comparator = match args.get(1) {
+        None | Some(Value::Nil) => None,
+        Some(comparator @ Value::Closure(_)) => Some(comparator.clone()),
+        Some(other) => return Err(Error::Expected(2, "function", other.static_type_name())),
+    };
+
+    // The table is not borrowed while sorting, so the comparator
+    // can access it
+    let mut values = {
+        let table = table.borrow();
+        let length = usize::try_from(table.border())?;
+        table.array[..length].to_vec()
+    };
+
+    merge_sort(&mut values, &mut |lhs, rhs| match &comparator {
+        Some(comparator) => {
+            let result = vm.call_value(comparator.clone(), &[lhs.clone(), rhs.clone()])?;
+            Ok(!matches!(
+                result.first(),
+                None | Some(Value::Nil | Value::Boolean(false))
+            ))
+        }
+        None => match lhs.partial_cmp(rhs) {
+            Some(ordering) => Ok(ordering.is_lt()),
+            // Only NaN leaves numbers unordered
+            #[cfg(feature = "float")]
+            None if matches!(
+                (lhs, rhs),
+                (
+                    Value::Integer(_) | Value::Float(_),
+                    Value::Integer(_) | Value::Float(_)
+                )
+            ) =>
+            {
+                Ok(false)
+            }
+            None => Err(Error::RelationalOperand(
+                lhs.static_type_name(),
+                rhs.static_type_name(),
+            )),
+        },
+    })?;
+
+    let mut table = table.borrow_mut();
+    for (slot, value) in table.array.iter_mut().zip(values) {
+        *slot = value;
+    }
+
+    Ok(0)
+}
+
+/// Most values `table.unpack` returns, the size of the stack of the
+/// reference implementation
+const MAX_UNPACKED: i64 = 1_000_000;
+
+fn table_unpack(vm: &mut Lua) -> NativeClosureReturn {
+    let args = get_args(vm);
+    let table = get_table(args, 0)?;
+    let unpacked = {
+        let table = table.borrow();
+        let start = get_optional_integer(args, 1, 1)?;
+        let end = get_optional_integer(args, 2, table.border())?;
+        // The range can be wider than `i64::MAX`, but its count always
+        // fits on an `u64`
+        if start <= end && (end as u64).wrapping_sub(start as u64) >= MAX_UNPACKED as u64 {
+            return Err(Error::BadArgument(3, "too many results to unpack"));
+        }
+        (start..=end)
+            .map(|index| get_index(&table, index))
+            .collect::<Vec<_>>()
+    };
+
+    vm.set_returns(unpacked)
+}
+
+fn get_table(args: &[Value], position: usize) -> Result<Rc<RefCell<Table>>, Error> {
+    match args.get(position) {
+        Some(Value::Table(table)) => Ok(table.clone()),
+        Some(other) => Err(Error::Expected(
+            position + 1,
+            "table",
+            other.static_type_name(),
+        )),
+        None => Err(Error::Expected(position + 1, "table", "no value")),
+    }
+}
+
+/// Table that the `table` library will change, which can't be frozen
+fn get_mutable_table(args: &[Value], position: usize) -> Result<Rc<RefCell<Table>>, Error> {
+    let table = get_table(args, position)?;
+    if table.borrow().is_frozen() {
+        return Err(Error::FrozenTable);
+    }
+    Ok(table)
+}
+
+fn get_index(table: &Table, index: i64) -> Value {
+    table.get(ValueKey(Value::Integer(index))).clone()
+}
+
+fn set_index(table: &mut Table, index: i64, value: Value) {
+    table.raw_set(ValueKey(Value::Integer(index)), value);
+}
+
+/// Stable merge sort with a fallible `less than` comparison
+fn merge_sort(
+    values: &mut [Value],
+    less_than: &mut impl FnMut(&Value, &Value) -> Result<bool, Error>,
+) -> Result<(), Error> {
+    if values.len() <= 1 {
+        return Ok(());
+    }
+
+    let middle = values.len() / 2;
+    merge_sort(&mut values[..middle], less_than)?;
+    merge_sort(&mut values[middle..], less_than)?;
+
+    let mut merged = Vec::with_capacity(values.len());
+    let (mut left, mut right) = (0, middle);
+    while left < middle && right < values.len() {
+        // Takes from the right only if strictly smaller, keeping the sort stable
+        if less_than(&values[right], &values[left])? {
+            merged.push(values[right].clone());
+            right += 1;
+        } else {
+            merged.push(values[left].clone());
+            left += 1;
+        }
+    }
+    merged.extend_from_slice(&values[left..middle]);
+    merged.extend_from_slice(&values[right..]);
+
+    values.clone_from_slice(&merged);
+    Ok(())
+}
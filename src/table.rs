@@ -1,14 +1,35 @@
-use alloc::vec::Vec;
+use core::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+};
+
+use alloc::{rc::Rc, vec::Vec};
 
 use crate::{
     Error,
-    value::{Value, ValueKey},
+    value::{Value, ValueKey, next_reference_id},
 };
 
-#[derive(Debug, PartialEq)]
+/// Callback fired with the key and the new value when a script assigns
+/// to an observed [`Table`], the value is `nil` when the key is deleted
+pub type TableObserver = Rc<dyn Fn(&Value, &Value)>;
+
 pub struct Table {
     pub array: Vec<Value>,
+    /// Entries sorted by key type and then by key, strings by their bytes,
+    /// and tables and closures by order of creation, so the order does not
+    /// depend on the allocator
     pub table: Vec<(ValueKey, Value)>,
+    /// Counts the keys added to and removed from the map, which move the
+    /// keys that follow them, see [`Table::next`]
+    map_changes: usize,
+    /// Value of `map_changes` when the last traversal started, or `None`
+    /// if it reached the end
+    traversal: Cell<Option<usize>>,
+    observer: Option<TableObserver>,
+    metatable: Option<Rc<RefCell<Table>>>,
+    frozen: bool,
+    id: usize,
 }
 
 impl Table {
@@ -16,16 +37,251 @@ impl Table {
         Self {
             array: Vec::with_capacity(array_initial_size),
             table: Vec::with_capacity(table_initial_size),
+            map_changes: 0,
+            traversal: Cell::new(None),
+            observer: None,
+            metatable: None,
+            frozen: false,
+            id: next_reference_id(),
         }
     }
 
+    /// Order of creation of the table, used to order tables used as keys
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Registers a callback that is fired after every assignment made
+    /// by a script to this table, replacing the previous one
+    ///
+    /// Changes made by the host or by the standard library are not notified.
+    pub fn set_observer(&mut self, observer: impl Fn(&Value, &Value) + 'static) {
+        self.observer = Some(Rc::new(observer));
+    }
+
+    pub fn clear_observer(&mut self) {
+        self.observer = None;
+    }
+
+    pub(crate) fn observer(&self) -> Option<TableObserver> {
+        self.observer.clone()
+    }
+
+    /// Makes scripts fail with [`Error::FrozenTable`] when they assign to
+    /// this table, change it with the `table` library, or set its metatable
+    ///
+    /// The host can still change a frozen table, but can't unfreeze it,
+    /// so libraries can be shared between sandboxes without being patched.
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// Table holding the metamethods of this table
+    pub fn metatable(&self) -> Option<Rc<RefCell<Table>>> {
+        self.metatable.clone()
+    }
+
+    pub fn set_metatable(&mut self, metatable: Option<Rc<RefCell<Table>>>) {
+        self.metatable = metatable;
+    }
+
+    /// Length of the table as given by `#` without `__len`, a positive
+    /// integer key whose value is not `nil` and that is followed by a `nil`,
+    /// or `0` if `t[1]` is `nil`
+    ///
+    /// The key after the end of the array part is never on the map, see
+    /// [`Table::raw_set`], so the last value of the array part that is not
+    /// `nil` is a border.
+    pub fn border(&self) -> i64 {
+        let length = self
+            .array
+            .iter()
+            .rposition(|value| !matches!(value, Value::Nil))
+            .map_or(0, |last| last + 1);
+        // The array part can't be bigger than `i64::MAX`
+        length as i64
+    }
+
+    /// Value of `key`, without going through `__index`
     pub fn get(&self, key: ValueKey) -> &Value {
+        let key = Self::normalize(key);
+        if let Some(value) = self.array_slot(&key) {
+            return value;
+        }
         match self.table.binary_search_by_key(&&key, |(key, _)| key) {
             Ok(found) => &self.table[found].1,
             Err(_) => &Value::Nil,
         }
     }
 
+    /// Stores `value` on `key`, without going through `__newindex`.
+    ///
+    /// Positive integer keys are stored on the array part if they are on
+    /// it or right after its end, other keys go to the map. When the array
+    /// part grows, the keys that follow it are moved from the map, so
+    /// filling a sequence in any order ends with it on the array part,
+    /// and sparse keys, like `t[1e9]`, don't allocate the keys before them.
+    pub fn raw_set(&mut self, key: ValueKey, value: Value) {
+        let key = Self::normalize(key);
+        if let Some(slot) = self.array_slot_mut(&key) {
+            *slot = value;
+            return;
+        }
+        if let ValueKey(Value::Integer(index)) = key
+            && usize::try_from(index).is_ok_and(|index| index == self.array.len() + 1)
+        {
+            if !matches!(value, Value::Nil) {
+                self.array.push(value);
+                self.migrate_to_array();
+            }
+            return;
+        }
+
+        match self.table.binary_search_by_key(&&key, |(key, _)| key) {
+            Ok(index) => self.table[index].1 = value,
+            Err(index) => self.insert_entry(index, key, value),
+        }
+    }
+
+    /// Adds `key`, which is not on the map, at `index`, where a binary
+    /// search for it ended
+    ///
+    /// Keys set to `nil` stay on the map, so [`Table::next`] can go on
+    /// from them while a traversal clears the table. They are only dropped
+    /// when a new key finds the map full, like on a rehash of the
+    /// reference implementation, which is why new keys can't be added
+    /// while the table is traversed.
+    pub(crate) fn insert_entry(&mut self, index: usize, key: ValueKey, value: Value) {
+        if matches!(value, Value::Nil) {
+            return;
+        }
+        self.map_changes = self.map_changes.wrapping_add(1);
+        let index = if self.table.len() == self.table.capacity()
+            && self
+                .table
+                .iter()
+                .any(|(_, value)| matches!(value, Value::Nil))
+        {
+            self.table.retain(|(_, value)| !matches!(value, Value::Nil));
+            self.table
+                .binary_search_by_key(&&key, |(key, _)| key)
+                .unwrap_err()
+        } else {
+            index
+        };
+        self.table.insert(index, (key, value));
+    }
+
+    /// Key and value that follow `key` on a traversal of the table, or
+    /// `None` after the last one, with the order used by `next`
+    ///
+    /// `nil` starts the traversal, which goes over the array part in
+    /// order and then over the map, skipping the keys whose values are
+    /// `nil`. Keys only carry the position of the traversal, so existing
+    /// fields can be changed or cleared while the table is traversed, and
+    /// traversing from a key that is not on the table fails with
+    /// [`Error::InvalidNextKey`].
+    ///
+    /// Adding keys to the map moves the keys after them, which the
+    /// traversal would visit twice or skip, so going on with a traversal
+    /// after a key was added fails with [`Error::KeyAddedDuringTraversal`].
+    /// Only the last traversal started is checked, until it reaches the end.
+    pub fn next(&self, key: &Value) -> Result<Option<(Value, Value)>, Error> {
+        let key = Self::normalize(ValueKey(key.clone()));
+        let entry = if matches!(key.0, Value::Nil) {
+            self.traversal.set(Some(self.map_changes));
+            0
+        } else if self
+            .traversal
+            .get()
+            .is_some_and(|started| started != self.map_changes)
+        {
+            return Err(Error::KeyAddedDuringTraversal);
+        } else if let Some(index) = self.array_index(&key) {
+            index + 1
+        } else {
+            match self.table.binary_search_by_key(&&key, |(key, _)| key) {
+                Ok(entry) => self.array.len() + entry + 1,
+                Err(_) => return Err(Error::InvalidNextKey(key.0)),
+            }
+        };
+
+        if let Some((index, value)) = self
+            .array
+            .iter()
+            .enumerate()
+            .skip(entry)
+            .find(|(_, value)| !matches!(value, Value::Nil))
+        {
+            return Ok(Some((
+                Value::Integer(i64::try_from(index + 1)?),
+                value.clone(),
+            )));
+        }
+        let next = self
+            .table
+            .iter()
+            .skip(entry.saturating_sub(self.array.len()))
+            .find(|(_, value)| !matches!(value, Value::Nil))
+            .map(|(key, value)| (key.0.clone(), value.clone()));
+        if next.is_none() {
+            self.traversal.set(None);
+        }
+        Ok(next)
+    }
+
+    /// Moves the integer keys that follow the array part from the map
+    pub(crate) fn migrate_to_array(&mut self) {
+        loop {
+            let Ok(next) = i64::try_from(self.array.len() + 1) else {
+                return;
+            };
+            let key = ValueKey(Value::Integer(next));
+            let Ok(index) = self.table.binary_search_by_key(&&key, |(key, _)| key) else {
+                return;
+            };
+            let (_, value) = self.table.remove(index);
+            self.map_changes = self.map_changes.wrapping_add(1);
+            if matches!(value, Value::Nil) {
+                return;
+            }
+            self.array.push(value);
+        }
+    }
+
+    /// Floats with an exact integer representation are the same key
+    /// as the integer, like `t[1.0]` and `t[1]`
+    fn normalize(key: ValueKey) -> ValueKey {
+        match key {
+            #[cfg(feature = "float")]
+            ValueKey(float @ Value::Float(_)) => ValueKey(float.try_int()),
+            key => key,
+        }
+    }
+
+    fn array_index(&self, key: &ValueKey) -> Option<usize> {
+        let ValueKey(Value::Integer(index)) = key else {
+            return None;
+        };
+        usize::try_from(*index)
+            .ok()?
+            .checked_sub(1)
+            .filter(|index| *index < self.array.len())
+    }
+
+    fn array_slot(&self, key: &ValueKey) -> Option<&Value> {
+        self.array.get(self.array_index(key)?)
+    }
+
+    fn array_slot_mut(&mut self, key: &ValueKey) -> Option<&mut Value> {
+        let index = self.array_index(key)?;
+        self.array.get_mut(index)
+    }
+
     pub fn set(&mut self, key: ValueKey, value: Value) -> Result<(), Error> {
         match self.table.binary_search_by_key(&&key, |(key, _)| key) {
             Ok(index) => {
@@ -34,7 +290,7 @@ impl Table {
             }
             Err(index) => {
                 if matches!(key, ValueKey(Value::ShortString(_) | Value::String(_))) {
-                    self.table.insert(index, (key, value));
+                    self.insert_entry(index, key, value);
                     Ok(())
                 } else {
                     Err(Error::ExpectedName)
@@ -43,3 +299,53 @@ impl Table {
         }
     }
 }
+
+impl Debug for Table {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Table")
+            .field("array", &self.array)
+            .field("table", &self.table)
+            .field("observed", &self.observer.is_some())
+            .field("metatable", &self.metatable.is_some())
+            .field("frozen", &self.frozen)
+            .finish()
+    }
+}
+
+impl Drop for Table {
+    fn drop(&mut self) {
+        // Dropping nested tables recursively can overflow the host's stack,
+        // so tables only owned by this one are emptied into a work stack
+        // before being dropped
+        let mut pending = core::mem::take(&mut self.array);
+        pending.extend(
+            core::mem::take(&mut self.table)
+                .into_iter()
+                .flat_map(|(key, value)| [key.0, value]),
+        );
+        pending.extend(self.metatable.take().map(Value::Table));
+
+        while let Some(value) = pending.pop() {
+            if let Value::Table(table) = value
+                && let Ok(table) = Rc::try_unwrap(table)
+            {
+                let mut table = table.into_inner();
+                pending.append(&mut table.array);
+                pending.extend(
+                    table
+                        .table
+                        .drain(..)
+                        .flat_map(|(key, value)| [key.0, value]),
+                );
+                pending.extend(table.metatable.take().map(Value::Table));
+            }
+        }
+    }
+}
+
+impl PartialEq for Table {
+    fn eq(&self, other: &Self) -> bool {
+        // Observers are not part of the table's contents
+        self.array == other.array && self.table == other.table
+    }
+}
@@ -0,0 +1,223 @@
+//! Trace of the bytecodes run by the VM
+//!
+//! With the `trace` feature, each bytecode is logged at the `trace` level
+//! with the target `no_deps_lua::trace`, first with the depth of the call
+//! stack, its position on the running function, its opcode and operands,
+//! and the values of the registers it uses, then again with the values of
+//! those registers after it ran, or the depth of the call stack if it
+//! called or returned from a function. Tracing costs nothing while the
+//! logger has the `trace` level disabled for the target.
+
+use core::fmt::Display;
+
+use alloc::vec::Vec;
+
+use crate::{
+    Error, Lua,
+    bytecode::{Bytecode, DecodedInstruction, Instruction, OpCode},
+};
+
+/// Target of the logs of the trace
+pub(crate) const TARGET: &str = "no_deps_lua::trace";
+
+/// Bytecode about to run
+pub(crate) struct Step {
+    depth: usize,
+    pc: usize,
+    bytecode: Bytecode,
+    registers: Vec<u8>,
+    frame_changes: usize,
+}
+
+impl Step {
+    pub(crate) fn new(depth: usize, pc: usize, bytecode: Bytecode, frame_changes: usize) -> Self {
+        Self {
+            depth,
+            pc,
+            bytecode,
+            registers: registers(Instruction::decode(*bytecode)),
+            frame_changes,
+        }
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Positions start at 1, like on listings
+        write!(f, "{:>3} {:>5} ", self.depth, self.pc + 1)?;
+        match self.bytecode.opcode() {
+            Some(op) => {
+                write!(f, "{:<9} ", op.name())?;
+                self.bytecode.fmt_operands(f, " ")
+            }
+            None => write!(f, "{:?}", self.bytecode),
+        }
+    }
+}
+
+/// Values of registers of the running function
+struct Registers<'a>(&'a Lua, &'a [u8]);
+
+impl Display for Registers<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, register) in self.1.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            match self.0.register(*register) {
+                Some(value) => write!(f, "R{register}={value:?}")?,
+                None => write!(f, "R{register}=-")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Lua {
+    /// Logs the bytecode at `pc` of the running function before it runs,
+    /// `None` if the logger doesn't trace
+    pub(crate) fn trace_before(&self, pc: usize, bytecode: Bytecode) -> Option<Step> {
+        if !log::log_enabled!(target: TARGET, log::Level::Trace) {
+            return None;
+        }
+        let step = Step::new(self.stack_frame.len(), pc, bytecode, self.frame_changes);
+        log::trace!(target: TARGET, "{step} | {}", Registers(self, &step.registers));
+        Some(step)
+    }
+
+    /// Logs the registers used by the bytecode of `step` after it ran
+    pub(crate) fn trace_after(&self, step: Step, result: &Result<(), Error>) {
+        let indent = "";
+        match result {
+            Err(err) => log::trace!(target: TARGET, "{indent:>9} failed with {err:?}"),
+            Ok(()) if step.frame_changes != self.frame_changes => log::trace!(
+                target: TARGET,
+                "{indent:>9} -> depth {}",
+                self.stack_frame.len()
+            ),
+            Ok(()) => log::trace!(
+                target: TARGET,
+                "{indent:>9} -> {}",
+                Registers(self, &step.registers)
+            ),
+        }
+    }
+}
+
+/// Registers the instruction reads or writes, constants, upvalues and
+/// counts are left out
+fn registers(instruction: DecodedInstruction) -> Vec<u8> {
+    let mut registers = Vec::with_capacity(4);
+    match instruction {
+        DecodedInstruction::Abck { opcode, a, b, c, k } => match opcode {
+            OpCode::Move
+            | OpCode::GetIndex
+            | OpCode::GetField
+            | OpCode::TableSelf
+            | OpCode::AddConstant
+            | OpCode::SubConstant
+            | OpCode::MulConstant
+            | OpCode::ModConstant
+            | OpCode::PowConstant
+            | OpCode::DivConstant
+            | OpCode::IDivConstant
+            | OpCode::BitAndConstant
+            | OpCode::BitOrConstant
+            | OpCode::BitXorConstant
+            | OpCode::MetaMethod
+            | OpCode::Neg
+            | OpCode::BitNot
+            | OpCode::Not
+            | OpCode::Len
+            | OpCode::Equal
+            | OpCode::LessThan
+            | OpCode::LessEqual
+            | OpCode::TestSet => registers.extend([a, b]),
+            OpCode::GetTable
+            | OpCode::Add
+            | OpCode::Sub
+            | OpCode::Mul
+            | OpCode::Mod
+            | OpCode::Pow
+            | OpCode::Div
+            | OpCode::IDiv
+            | OpCode::BitAnd
+            | OpCode::BitOr
+            | OpCode::BitXor
+            | OpCode::ShiftLeft
+            | OpCode::ShiftRight => registers.extend([a, b, c]),
+            // `C` is a register unless `k` says it is a constant
+            OpCode::SetTable => {
+                registers.extend([a, b]);
+                if !k {
+                    registers.push(c);
+                }
+            }
+            OpCode::SetIndex | OpCode::SetField => {
+                registers.push(a);
+                if !k {
+                    registers.push(c);
+                }
+            }
+            // `A` is an upvalue
+            OpCode::SetUpTable => {
+                if !k {
+                    registers.push(c);
+                }
+            }
+            OpCode::LoadNil => registers.extend(a..=a.saturating_add(b)),
+            OpCode::Concat => registers.extend(a..a.saturating_add(b)),
+            OpCode::VariadicArgumentsPrepare | OpCode::ZeroReturn => (),
+            _ => registers.push(a),
+        },
+        DecodedInstruction::Abx {
+            opcode:
+                OpCode::ForPrepare
+                | OpCode::ForLoop
+                | OpCode::GenericForPrepare
+                | OpCode::GenericForLoop,
+            a,
+            ..
+        } => registers.extend(a..a.saturating_add(4)),
+        DecodedInstruction::Asbck { a, .. }
+        | DecodedInstruction::Abx { a, .. }
+        | DecodedInstruction::Asbx { a, .. } => registers.push(a),
+        DecodedInstruction::Absck { a, b, .. } => registers.extend([a, b]),
+        DecodedInstruction::Ax { .. }
+        | DecodedInstruction::Sj { .. }
+        | DecodedInstruction::Custom { .. } => (),
+    }
+    registers
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec};
+
+    use super::*;
+
+    fn registers_of(bytecode: Bytecode) -> Vec<u8> {
+        registers(Instruction::decode(*bytecode))
+    }
+
+    #[test]
+    fn step_registers() {
+        assert_eq!(registers_of(Bytecode::move_bytecode(1, 4)), vec![1, 4]);
+        assert_eq!(registers_of(Bytecode::add(2, 0, 1)), vec![2, 0, 1]);
+        assert_eq!(
+            registers_of(Bytecode::set_field(0, 1, 3, false)),
+            vec![0, 3]
+        );
+        assert_eq!(registers_of(Bytecode::set_field(0, 1, 3, true)), vec![0]);
+        assert_eq!(registers_of(Bytecode::load_nil(2, 2)), vec![2, 3, 4]);
+        assert_eq!(registers_of(Bytecode::for_loop(1, 3u8)), vec![1, 2, 3, 4]);
+        assert_eq!(registers_of(Bytecode::jump(-3i8)), vec![]);
+        assert_eq!(registers_of(Bytecode::zero_return()), vec![]);
+    }
+
+    #[test]
+    fn step_display() {
+        let step = Step::new(1, 4, Bytecode::add(2, 0, 1), 0);
+        assert_eq!(format!("{step}"), "  1     5 ADD       2 0 1");
+    }
+}
@@ -0,0 +1,115 @@
+use core::{
+    any::Any,
+    cell::{Ref, RefCell, RefMut},
+    fmt::Debug,
+};
+
+use alloc::{boxed::Box, rc::Rc};
+
+use crate::{
+    Error,
+    table::Table,
+    value::{Value, ValueKey, next_reference_id},
+};
+
+/// Host object exposed to scripts
+///
+/// Scripts can only pass userdata around and compare them by reference,
+/// everything else goes through the userdata's metatable, its `__index`
+/// field gives access to methods and fields, and the other metamethods
+/// work the same as they do on tables.
+pub struct UserData {
+    value: RefCell<Box<dyn Any>>,
+    metatable: RefCell<Option<Rc<RefCell<Table>>>>,
+    id: usize,
+}
+
+impl UserData {
+    pub fn new<T: Any>(value: T) -> Self {
+        Self {
+            value: RefCell::new(Box::new(value)),
+            metatable: RefCell::new(None),
+            id: next_reference_id(),
+        }
+    }
+
+    /// Creates a userdata that shares `metatable` with other userdata
+    /// of the same kind
+    pub fn with_metatable<T: Any>(value: T, metatable: Rc<RefCell<Table>>) -> Self {
+        let userdata = Self::new(value);
+        userdata.set_metatable(Some(metatable));
+        userdata
+    }
+
+    /// Order of creation of the userdata, used to order userdata used as keys
+    pub(crate) fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns `true` if the wrapped value is a `T`
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.borrow().is::<T>()
+    }
+
+    /// Borrows the wrapped value, returns `None` if it is not a `T`
+    /// or if it is mutably borrowed
+    pub fn borrow<T: Any>(&self) -> Option<Ref<'_, T>> {
+        let value = self.value.try_borrow().ok()?;
+        Ref::filter_map(value, |value| value.downcast_ref::<T>()).ok()
+    }
+
+    /// Mutably borrows the wrapped value, returns `None` if it is not a `T`
+    /// or if it is already borrowed
+    pub fn borrow_mut<T: Any>(&self) -> Option<RefMut<'_, T>> {
+        let value = self.value.try_borrow_mut().ok()?;
+        RefMut::filter_map(value, |value| value.downcast_mut::<T>()).ok()
+    }
+
+    /// Table holding the metamethods of this userdata
+    pub fn metatable(&self) -> Option<Rc<RefCell<Table>>> {
+        self.metatable.borrow().clone()
+    }
+
+    pub fn set_metatable(&self, metatable: Option<Rc<RefCell<Table>>>) {
+        *self.metatable.borrow_mut() = metatable;
+    }
+
+    /// Adds `method` to the `__index` table of the userdata's metatable,
+    /// creating both if needed, so scripts can call it with `userdata:name()`
+    ///
+    /// The metatable may be shared, in which case the method is
+    /// added to all userdata sharing it.
+    pub fn set_method(&self, name: &str, method: impl Into<Value>) -> Result<(), Error> {
+        let metatable = self
+            .metatable
+            .borrow_mut()
+            .get_or_insert_with(|| Rc::new(RefCell::new(Table::new(0, 1))))
+            .clone();
+
+        let index_key = ValueKey("__index".into());
+        let index = metatable.borrow().get(index_key.clone()).clone();
+        let methods = match index {
+            Value::Table(methods) => methods,
+            Value::Nil => {
+                let methods = Rc::new(RefCell::new(Table::new(0, 1)));
+                metatable
+                    .borrow_mut()
+                    .set(index_key, Value::Table(methods.clone()))?;
+                methods
+            }
+            other => return Err(Error::ExpectedTable(other.static_type_name())),
+        };
+        methods
+            .borrow_mut()
+            .set(ValueKey(name.into()), method.into())
+    }
+}
+
+impl Debug for UserData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UserData")
+            .field("id", &self.id)
+            .field("metatable", &self.metatable.borrow().is_some())
+            .finish()
+    }
+}
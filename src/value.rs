@@ -1,39 +1,75 @@
 use core::{
     cell::RefCell,
     cmp::Ordering,
-    fmt::{Debug, Display},
+    fmt::{Debug, Display, Write},
 };
 
-use alloc::{rc::Rc, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
+use alloc::{boxed::Box, rc::Rc, string::String, vec::Vec};
+
+#[cfg(feature = "float")]
+use crate::ext::{FloatExt, LuaFloat};
 use crate::{
     closure::{Closure, FunctionType, NativeClosure},
-    ext::FloatExt,
+    ext::{Numeral, ParseNumeral},
     function::Function,
     stack_str::StackStr,
     table::Table,
+    userdata::UserData,
 };
 
-const SHORT_STRING_LEN: usize = 23;
+/// Longest string stored inline, a [`Value`] with the tag and the length
+/// is 16 bytes
+const SHORT_STRING_LEN: usize = 14;
+
+/// Returns a new id for tables, closures and userdata, ids are given in order of
+/// creation and are used to order them when used as table keys
+pub(crate) fn next_reference_id() -> usize {
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    #[cfg(target_has_atomic = "ptr")]
+    {
+        NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed)
+    }
+    #[cfg(not(target_has_atomic = "ptr"))]
+    {
+        // Targets without atomic read-modify-write are single threaded
+        let id = NEXT_ID.load(AtomicOrdering::Relaxed);
+        NEXT_ID.store(id.wrapping_add(1), AtomicOrdering::Relaxed);
+        id
+    }
+}
 
 #[derive(Clone)]
 pub enum Value {
     Nil,
     Boolean(bool),
     Integer(i64),
+    #[cfg(feature = "float")]
     Float(f64),
+    /// Strings are bytes, which don't have to be valid UTF-8
     ShortString(StackStr<SHORT_STRING_LEN>),
-    String(Rc<str>),
+    /// `Box<[u8]>` keeps the `Rc` a thin pointer, which keeps [`Value`] small
+    String(Rc<Box<[u8]>>),
     Table(Rc<RefCell<Table>>),
     /// Closure with captured environment
     Closure(Rc<Closure>),
+    /// Object owned by the host
+    UserData(Rc<UserData>),
 }
 
 impl Value {
+    /// Floats with an exact integer representation turned into integers,
+    /// other values are kept as they are
     pub fn try_int(self) -> Value {
         match self {
+            #[cfg(feature = "float")]
             val @ Value::Float(float) => {
-                if float.zero_frac() {
+                // `i64::MAX` can't be represented, `2^63` is the first float past it
+                if float.zero_frac()
+                    && (-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&float)
+                {
                     Value::Integer(float as i64)
                 } else {
                     val
@@ -43,6 +79,7 @@ impl Value {
         }
     }
 
+    #[cfg(feature = "float")]
     pub fn try_float(&self) -> Option<Value> {
         match self {
             Value::Integer(i) => Some(Value::Float(*i as f64)),
@@ -51,37 +88,145 @@ impl Value {
         }
     }
 
+    /// Number the value stands for in arithmetic, numbers are kept as they
+    /// are and strings are read as numerals, `None` for anything else
+    pub fn to_number(&self) -> Option<Value> {
+        let numeral = match self {
+            Value::Integer(_) => return Some(self.clone()),
+            #[cfg(feature = "float")]
+            Value::Float(_) => return Some(self.clone()),
+            _ => self.as_bytes()?.parse_numeral(),
+        };
+        match numeral? {
+            Numeral::Integer(integer) => Some(Value::Integer(integer)),
+            #[cfg(feature = "float")]
+            Numeral::Float(float) => Some(Value::Float(float)),
+            #[cfg(not(feature = "float"))]
+            Numeral::Float(_) => None,
+        }
+    }
+
+    /// Equality as defined by Lua, integers and floats are equal if they
+    /// have the same mathematical value, strings are compared by contents,
+    /// and tables, closures and userdata by reference
+    pub fn raw_equal(&self, other: &Value) -> bool {
+        match (self, other) {
+            #[cfg(feature = "float")]
+            (Value::Integer(i), Value::Float(f)) | (Value::Float(f), Value::Integer(i)) => {
+                compare_integer_float(*i, *f) == Some(Ordering::Equal)
+            }
+            (Value::ShortString(l), Value::String(r))
+            | (Value::String(r), Value::ShortString(l)) => **l == ***r,
+            (lhs, rhs) => lhs == rhs,
+        }
+    }
+
+    /// Bytes of a string, `None` for other values
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::ShortString(string) => Some(string),
+            Self::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Appends the bytes of a string, or of a number written as `tostring`
+    /// writes it, to `buffer`, `false` for other values
+    pub(crate) fn push_string_bytes(&self, buffer: &mut Vec<u8>) -> bool {
+        // Writing to a `Vec` does not fail
+        let _ = match self {
+            Self::Integer(integer) => write!(ByteWriter(buffer), "{integer}"),
+            #[cfg(feature = "float")]
+            Self::Float(float) => write!(ByteWriter(buffer), "{}", LuaFloat(*float)),
+            Self::ShortString(string) => {
+                buffer.extend_from_slice(string);
+                Ok(())
+            }
+            Self::String(string) => {
+                buffer.extend_from_slice(string);
+                Ok(())
+            }
+            _ => return false,
+        };
+        true
+    }
+
     pub fn static_type_name(&self) -> &'static str {
         match self {
             Self::Nil => "nil",
             Self::Boolean(_) => "boolean",
             Self::Integer(_) => "integer",
+            #[cfg(feature = "float")]
             Self::Float(_) => "float",
             Self::ShortString(_) | Self::String(_) => "string",
             Self::Table(_) => "table",
             Self::Closure(_) => "closure",
+            Self::UserData(_) => "userdata",
+        }
+    }
+
+    /// Metatable of the value, only tables and userdata can have metatables
+    pub fn metatable(&self) -> Option<Rc<RefCell<Table>>> {
+        match self {
+            Self::Table(table) => table.borrow().metatable(),
+            Self::UserData(userdata) => userdata.metatable(),
+            _ => None,
         }
     }
+
+    /// Field `event` of the value's metatable, if it is not `nil`
+    pub(crate) fn metamethod(&self, event: &str) -> Option<Value> {
+        let metatable = self.metatable()?;
+        let metamethod = metatable.borrow().get(ValueKey(event.into())).clone();
+        (!matches!(metamethod, Value::Nil)).then_some(metamethod)
+    }
 }
 
 impl PartialOrd for Value {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self, other) {
             (Value::Integer(l), Value::Integer(r)) => Some(l.cmp(r)),
-            (Value::Integer(l), Value::Float(r)) => (*l as f64).partial_cmp(r),
-            (Value::Float(l), Value::Integer(r)) => l.partial_cmp(&(*r as f64)),
+            #[cfg(feature = "float")]
+            (Value::Integer(l), Value::Float(r)) => compare_integer_float(*l, *r),
+            #[cfg(feature = "float")]
+            (Value::Float(l), Value::Integer(r)) => {
+                compare_integer_float(*r, *l).map(Ordering::reverse)
+            }
+            #[cfg(feature = "float")]
             (Value::Float(l), Value::Float(r)) => l.partial_cmp(r),
 
-            (Value::ShortString(l), Value::ShortString(r)) => Some(l.cmp(r)),
-            (Value::ShortString(l), Value::String(r)) => Some(l.as_ref().cmp(r.as_bytes())),
-            (Value::String(l), Value::ShortString(r)) => Some(l.as_bytes().cmp(r.as_ref())),
-            (Value::String(l), Value::String(r)) => Some(l.cmp(r)),
+            (
+                Value::ShortString(_) | Value::String(_),
+                Value::ShortString(_) | Value::String(_),
+            ) => Some(self.as_bytes()?.cmp(other.as_bytes()?)),
 
             _ => None,
         }
     }
 }
 
+/// Compares an integer with a float without losing precision on integers
+/// that can't be represented exactly as floats
+#[cfg(feature = "float")]
+fn compare_integer_float(integer: i64, float: f64) -> Option<Ordering> {
+    // 2^63, the first float past `i64::MAX`
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+
+    if float.is_nan() {
+        None
+    } else if float >= LIMIT {
+        Some(Ordering::Less)
+    } else if float < -LIMIT {
+        Some(Ordering::Greater)
+    } else {
+        let truncated = float.truncate();
+        match integer.cmp(&(truncated as i64)) {
+            Ordering::Equal => 0.0.partial_cmp(&(float - truncated)),
+            ordering => Some(ordering),
+        }
+    }
+}
+
 impl From<()> for Value {
     fn from(_value: ()) -> Self {
         Value::Nil
@@ -100,6 +245,7 @@ impl From<i64> for Value {
     }
 }
 
+#[cfg(feature = "float")]
 impl From<f64> for Value {
     fn from(value: f64) -> Self {
         Value::Float(value)
@@ -112,11 +258,43 @@ impl From<bool> for Value {
     }
 }
 
+/// Formats numbers straight into the bytes of a string
+struct ByteWriter<'a>(&'a mut Vec<u8>);
+
+impl Write for ByteWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
 impl From<&str> for Value {
     fn from(string: &str) -> Self {
-        match StackStr::new(string) {
+        Value::from(string.as_bytes())
+    }
+}
+
+impl From<String> for Value {
+    fn from(string: String) -> Self {
+        Value::from(string.into_bytes())
+    }
+}
+
+impl From<&[u8]> for Value {
+    fn from(bytes: &[u8]) -> Self {
+        match StackStr::new(bytes) {
             Ok(stack_str) => Value::ShortString(stack_str),
-            Err(_) => Value::String(string.into()),
+            Err(_) => Value::String(Rc::new(bytes.into())),
+        }
+    }
+}
+
+impl From<Vec<u8>> for Value {
+    fn from(bytes: Vec<u8>) -> Self {
+        match StackStr::new(bytes.as_slice()) {
+            Ok(stack_str) => Value::ShortString(stack_str),
+            // Reuses the allocation of `bytes` when it has no spare capacity
+            Err(_) => Value::String(Rc::new(bytes.into_boxed_slice())),
         }
     }
 }
@@ -133,15 +311,22 @@ impl From<NativeClosure> for Value {
     }
 }
 
+impl From<UserData> for Value {
+    fn from(userdata: UserData) -> Self {
+        Self::UserData(Rc::new(userdata))
+    }
+}
+
 impl Debug for Value {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Nil => write!(f, "Nil"),
             Self::Boolean(b) => write!(f, "Boolean({b})"),
             Self::Integer(i) => write!(f, "Integer({i})"),
+            #[cfg(feature = "float")]
             Self::Float(n) => write!(f, "Float({n:?})"),
             Self::ShortString(s) => write!(f, "ShortString({s})"),
-            Self::String(s) => write!(f, "String({s})"),
+            Self::String(s) => write!(f, "String({})", String::from_utf8_lossy(s)),
             Self::Table(table) => {
                 let t = table.borrow();
                 write!(f, "Table({}:{})", t.array.len(), t.table.len())
@@ -167,6 +352,7 @@ impl Debug for Value {
                     )
                 }
             },
+            Self::UserData(userdata) => write!(f, "UserData({:?})", Rc::as_ptr(userdata)),
         }
     }
 }
@@ -177,11 +363,14 @@ impl Display for Value {
             Self::Nil => write!(f, "nil"),
             Self::Boolean(b) => write!(f, "{b}"),
             Self::Integer(i) => write!(f, "{i}"),
-            Self::Float(n) => write!(f, "{n:?}"),
+            #[cfg(feature = "float")]
+            Self::Float(n) => write!(f, "{}", LuaFloat(*n)),
+            // Bytes that are not valid UTF-8 are written as `U+FFFD`
             Self::ShortString(s) => write!(f, "{s}"),
-            Self::String(s) => write!(f, "{s}"),
+            Self::String(s) => write!(f, "{}", String::from_utf8_lossy(s)),
             Self::Table(table) => write!(f, "table:{:?}", table.as_ptr()),
             Self::Closure(_) => write!(f, "closure"),
+            Self::UserData(userdata) => write!(f, "userdata:{:?}", Rc::as_ptr(userdata)),
         }
     }
 }
@@ -193,10 +382,15 @@ impl PartialEq for Value {
             (Self::Nil, Self::Nil) => true,
             (Self::Boolean(b1), Self::Boolean(b2)) => b1 == b2,
             (Self::Integer(i1), Self::Integer(i2)) => i1 == i2,
+            #[cfg(feature = "float")]
             (Self::Float(f1), Self::Float(f2)) => f1 == f2,
             (Self::ShortString(s1), Self::ShortString(s2)) => s1 == s2,
             (Self::String(s1), Self::String(s2)) => s1 == s2,
-            (Self::Table(t1), Self::Table(t2)) => t1 == t2,
+            // Tables are compared by reference, which also avoids walking
+            // nested or self-referencing tables
+            (Self::Table(t1), Self::Table(t2)) => Rc::ptr_eq(t1, t2),
+            (Self::Closure(c1), Self::Closure(c2)) => Rc::ptr_eq(c1, c2),
+            (Self::UserData(u1), Self::UserData(u2)) => Rc::ptr_eq(u1, u2),
             (_, _) => false,
         }
     }
@@ -208,9 +402,129 @@ impl Eq for Value {}
 mod tests {
     use super::*;
 
+    use alloc::format;
+
+    use crate::{Lua, Program};
+
+    const DEPTH: usize = 100_000;
+
+    fn nested_table(depth: usize) -> Value {
+        let mut value = Value::Table(Rc::new(RefCell::new(Table::new(0, 0))));
+        for _ in 0..depth {
+            let mut table = Table::new(1, 0);
+            table.array.push(value);
+            value = Value::Table(Rc::new(RefCell::new(table)));
+        }
+        value
+    }
+
     #[test]
     fn value_short_string_static_assert() {
-        assert_eq!(size_of::<Value>(), 24);
+        assert_eq!(size_of::<Value>(), 16);
+        assert!(matches!(
+            Value::from("fourteen bytes"),
+            Value::ShortString(_)
+        ));
+        assert!(matches!(Value::from("fifteen bytes!!"), Value::String(_)));
+    }
+
+    #[test]
+    fn key_ordering() {
+        let long = "a string that is too long to be stored inline";
+        let mut keys = alloc::vec![
+            ValueKey::from(Value::from("b")),
+            ValueKey::from(Value::from(long)),
+            ValueKey::from(Value::from("a")),
+        ];
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                ValueKey::from(Value::from("a")),
+                ValueKey::from(Value::from(long)),
+                ValueKey::from(Value::from("b")),
+            ]
+        );
+
+        // Tables and closures are ordered by creation, not by address
+        let first = Value::Table(Rc::new(RefCell::new(Table::new(0, 0))));
+        let second = Value::Table(Rc::new(RefCell::new(Table::new(0, 0))));
+        assert!(ValueKey::from(first.clone()) < ValueKey::from(second.clone()));
+        assert_eq!(
+            ValueKey::from(first.clone()).cmp(&ValueKey::from(first)),
+            Ordering::Equal
+        );
+
+        fn native(_: &mut Lua) -> crate::closure::NativeClosureReturn {
+            Ok(0)
+        }
+        let first = Value::from(native as crate::closure::NativeClosure);
+        let second = Value::from(native as crate::closure::NativeClosure);
+        assert!(ValueKey::from(first) < ValueKey::from(second));
+    }
+
+    #[test]
+    fn deeply_nested_table() {
+        let value = nested_table(DEPTH);
+        let other = nested_table(DEPTH);
+
+        let clone = value.clone();
+        assert_eq!(value, clone);
+        assert_ne!(value, other);
+        assert!(format!("{value}").starts_with("table:"));
+        assert_eq!(format!("{value:?}"), "Table(1:0)");
+
+        drop(clone);
+        drop(value);
+        drop(other);
+    }
+
+    #[test]
+    fn self_referencing_table() {
+        let table = Rc::new(RefCell::new(Table::new(1, 1)));
+        let value = Value::Table(table.clone());
+        table.borrow_mut().array.push(value.clone());
+        table
+            .borrow_mut()
+            .set(ValueKey::from(Value::from("self")), value.clone())
+            .unwrap();
+
+        let other = Rc::new(RefCell::new(Table::new(1, 0)));
+        other.borrow_mut().array.push(Value::Table(other.clone()));
+
+        assert_eq!(value, table.borrow().array[0]);
+        assert_ne!(value, Value::Table(other.clone()));
+        assert!(format!("{value}").starts_with("table:"));
+        assert_eq!(format!("{value:?}"), "Table(1:1)");
+
+        // Break the cycles so the tables can be freed
+        table.borrow_mut().array.clear();
+        table
+            .borrow_mut()
+            .set(ValueKey::from(Value::from("self")), Value::Nil)
+            .unwrap();
+        other.borrow_mut().array.clear();
+    }
+
+    #[test]
+    fn deeply_nested_table_script() {
+        let _ = simplelog::SimpleLogger::init(log::LevelFilter::Info, simplelog::Config::default());
+
+        let program = Program::parse(
+            r#"
+local t = {}
+for i = 1, 100000 do
+    local n = {t}
+    t = n
+end
+local s = {}
+s.me = s
+print(t, s)
+"#,
+        )
+        .unwrap();
+
+        Lua::run_program(program).unwrap();
     }
 }
 
@@ -225,11 +539,29 @@ impl ValueKey {
             Value::Nil => 0,
             Value::Boolean(_) => 1,
             Value::Integer(_) => 2,
+            #[cfg(feature = "float")]
             Value::Float(_) => 3,
-            Value::ShortString(_) => 4,
-            Value::String(_) => 5,
-            Value::Table(_) => 6,
-            Value::Closure(_) => 7,
+            Value::ShortString(_) | Value::String(_) => 4,
+            Value::Table(_) => 5,
+            Value::Closure(_) => 6,
+            Value::UserData(_) => 7,
+        }
+    }
+
+    fn string_bytes(&self) -> &[u8] {
+        let Some(bytes) = self.0.as_bytes() else {
+            unreachable!("`string_bytes` should only be called on strings");
+        };
+        bytes
+    }
+
+    /// Creation order of a table, falls back to its address if the table
+    /// is being modified, which can only happen if a table is a key of
+    /// itself while the host sets a value on it
+    fn table_order(table: &Rc<RefCell<Table>>) -> (usize, *const RefCell<Table>) {
+        match table.try_borrow() {
+            Ok(table_ref) => (table_ref.id(), core::ptr::null()),
+            Err(_) => (usize::MAX, Rc::as_ptr(table)),
         }
     }
 }
@@ -240,6 +572,10 @@ impl PartialOrd for ValueKey {
     }
 }
 
+/// Keys are ordered by type, and then by value, strings are ordered by
+/// their bytes regardless of how they are stored, and tables, closures and
+/// userdata by their order of creation, so the order of keys does not depend on
+/// addresses given by the allocator
 impl Ord for ValueKey {
     fn cmp(&self, other: &Self) -> Ordering {
         match self.ord_priority().cmp(&other.ord_priority()) {
@@ -247,11 +583,21 @@ impl Ord for ValueKey {
                 (Value::Nil, Value::Nil) => Ordering::Equal,
                 (Value::Boolean(lhs), Value::Boolean(rhs)) => lhs.cmp(rhs),
                 (Value::Integer(lhs), Value::Integer(rhs)) => lhs.cmp(rhs),
+                #[cfg(feature = "float")]
                 (Value::Float(lhs), Value::Float(rhs)) => lhs.total_cmp(rhs),
-                (Value::ShortString(lhs), Value::ShortString(rhs)) => lhs.cmp(rhs),
-                (Value::String(lhs), Value::String(rhs)) => lhs.cmp(rhs),
-                (Value::Table(lhs), Value::Table(rhs)) => Rc::as_ptr(lhs).cmp(&Rc::as_ptr(rhs)),
-                (Value::Closure(lhs), Value::Closure(rhs)) => Rc::as_ptr(lhs).cmp(&Rc::as_ptr(rhs)),
+                (
+                    Value::ShortString(_) | Value::String(_),
+                    Value::ShortString(_) | Value::String(_),
+                ) => self.string_bytes().cmp(other.string_bytes()),
+                (Value::Table(lhs), Value::Table(rhs)) => {
+                    if Rc::ptr_eq(lhs, rhs) {
+                        Ordering::Equal
+                    } else {
+                        Self::table_order(lhs).cmp(&Self::table_order(rhs))
+                    }
+                }
+                (Value::Closure(lhs), Value::Closure(rhs)) => lhs.id().cmp(&rhs.id()),
+                (Value::UserData(lhs), Value::UserData(rhs)) => lhs.id().cmp(&rhs.id()),
                 _ => unreachable!("Equal `ord_priority` means equal types"),
             },
             other => other,